@@ -0,0 +1,94 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use bevy_math::{
+    bounding::{Aabb3d, Bvh3d, IntersectsVolume, RayCast3d},
+    Dir3, Vec3,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const ENTITY_COUNT: usize = 100_000;
+
+fn random_aabbs() -> Vec<(usize, Aabb3d)> {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    (0..ENTITY_COUNT)
+        .map(|index| {
+            let center = Vec3::new(
+                rng.gen_range(-500.0..500.0),
+                rng.gen_range(-500.0..500.0),
+                rng.gen_range(-500.0..500.0),
+            );
+            (
+                index,
+                Aabb3d::new(center, Vec3::splat(rng.gen_range(0.5..2.0))),
+            )
+        })
+        .collect()
+}
+
+fn build(c: &mut Criterion) {
+    let aabbs = random_aabbs();
+    c.bench_function("bvh_build_100k", |b| {
+        b.iter(|| black_box(Bvh3d::new(black_box(aabbs.iter().copied()))));
+    });
+}
+
+fn refit(c: &mut Criterion) {
+    let aabbs = random_aabbs();
+    let mut bvh = Bvh3d::new(aabbs.iter().copied());
+    c.bench_function("bvh_refit_100k", |b| {
+        b.iter(|| bvh.refit(|index| Some(aabbs[*index].1)));
+    });
+}
+
+fn overlap(c: &mut Criterion) {
+    let aabbs = random_aabbs();
+    let bvh = Bvh3d::new(aabbs.iter().copied());
+    let query = Aabb3d::new(Vec3::new(10.0, -20.0, 30.0), Vec3::splat(50.0));
+
+    let mut group = c.benchmark_group("aabb_overlap_100k");
+    group.bench_function(BenchmarkId::new("bvh", ENTITY_COUNT), |b| {
+        b.iter(|| black_box(bvh.intersecting(black_box(&query))));
+    });
+    group.bench_function(BenchmarkId::new("naive", ENTITY_COUNT), |b| {
+        b.iter(|| {
+            black_box(
+                aabbs
+                    .iter()
+                    .filter(|(_, aabb)| black_box(&query).intersects(aabb))
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>(),
+            )
+        });
+    });
+    group.finish();
+}
+
+fn ray_cast(c: &mut Criterion) {
+    let aabbs = random_aabbs();
+    let bvh = Bvh3d::new(aabbs.iter().copied());
+    let ray = RayCast3d::new(Vec3::new(-600.0, 1.0, 2.0), Dir3::X, 1200.0);
+
+    let mut group = c.benchmark_group("ray_cast_100k");
+    group.bench_function(BenchmarkId::new("bvh", ENTITY_COUNT), |b| {
+        b.iter(|| black_box(bvh.cast_ray(black_box(&ray))));
+    });
+    group.bench_function(BenchmarkId::new("bvh_first", ENTITY_COUNT), |b| {
+        b.iter(|| black_box(bvh.cast_ray_first(black_box(&ray))));
+    });
+    group.bench_function(BenchmarkId::new("naive", ENTITY_COUNT), |b| {
+        b.iter(|| {
+            let ray = black_box(&ray);
+            let mut hits: Vec<_> = aabbs
+                .iter()
+                .filter_map(|(index, aabb)| Some((index, ray.aabb_intersection_at(aabb)?)))
+                .collect();
+            hits.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+            black_box(hits)
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, build, refit, overlap, ray_cast);
+criterion_main!(benches);
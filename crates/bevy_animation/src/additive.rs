@@ -0,0 +1,206 @@
+//! Additive blending of animation clips, on top of the pose blended from the
+//! other clips.
+
+use std::mem;
+
+use bevy_math::Quat;
+
+use crate::sampling::{CurvePosition, CurveSample};
+use crate::{AnimationTargetContext, VariableCurve};
+
+impl AnimationTargetContext<'_> {
+    /// Adds the difference between the pose of `curves` at `seek_time` and the
+    /// reference pose, scaled by `weight`, to the target.
+    ///
+    /// The reference pose is the first keyframe of `reference_curves`, or of
+    /// `curves` themselves if `None`.
+    pub(crate) fn apply_additive(
+        &mut self,
+        curves: &[VariableCurve],
+        reference_curves: Option<&[VariableCurve]>,
+        weight: f32,
+        seek_time: f32,
+    ) {
+        let morph_target_count = self
+            .morph_weights
+            .as_ref()
+            .map_or(0, |morphs| morphs.weights().len());
+
+        for curve in curves {
+            let reference_curve = match reference_curves {
+                None => curve,
+                Some(reference_curves) => {
+                    // The curve of the reference clip animating the same property
+                    let Some(reference_curve) = reference_curves.iter().find(|reference| {
+                        mem::discriminant(&reference.keyframes)
+                            == mem::discriminant(&curve.keyframes)
+                    }) else {
+                        continue;
+                    };
+                    reference_curve
+                }
+            };
+
+            let Some(value) = curve
+                .position(seek_time)
+                .and_then(|position| curve.sample(position, morph_target_count))
+            else {
+                continue;
+            };
+            let Some(reference) = reference_curve.sample(CurvePosition::FIRST, morph_target_count)
+            else {
+                continue;
+            };
+            self.add_delta(value, reference, weight);
+        }
+    }
+
+    fn add_delta(&mut self, value: CurveSample, reference: CurveSample, weight: f32) {
+        match (value, reference) {
+            (CurveSample::Translation(value), CurveSample::Translation(reference)) => {
+                if let Some(ref mut transform) = self.transform {
+                    transform.translation += (value - reference) * weight;
+                }
+            }
+
+            (CurveSample::Rotation(value), CurveSample::Rotation(reference)) => {
+                let Some(ref mut transform) = self.transform else {
+                    return;
+                };
+
+                // The rotation from the reference pose to the pose of the
+                // clip, which is scaled along the shortest arc and composed
+                // with the rotation of the target rather than lerped.
+                let mut delta = reference.inverse() * value;
+                if delta.w < 0.0 {
+                    delta = -delta;
+                }
+                let delta = Quat::from_scaled_axis(delta.to_scaled_axis() * weight);
+                transform.rotation = (transform.rotation * delta).normalize();
+            }
+
+            (CurveSample::Scale(value), CurveSample::Scale(reference)) => {
+                let Some(ref mut transform) = self.transform else {
+                    return;
+                };
+
+                // Scales are multiplicative, so their difference is a ratio
+                let ratio = value / reference;
+                if ratio.is_finite() {
+                    transform.scale *= ratio.powf(weight);
+                }
+            }
+
+            (CurveSample::Weights(value), CurveSample::Weights(reference)) => {
+                let Some(ref mut morphs) = self.morph_weights else {
+                    return;
+                };
+
+                for ((morph_weight, value), reference) in
+                    morphs.weights_mut().iter_mut().zip(value).zip(reference)
+                {
+                    *morph_weight += (value - reference) * weight;
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use bevy_asset::Assets;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_math::{Quat, Vec3};
+    use bevy_transform::components::Transform;
+
+    use crate::{
+        animate_targets,
+        graph::{AdditiveReference, ComputedAnimationGraphs},
+        AnimationClip, AnimationGraph, AnimationPlayer, AnimationTarget, AnimationTargetId,
+        Interpolation, Keyframes, VariableCurve,
+    };
+
+    fn curve(keyframes: Keyframes) -> VariableCurve {
+        VariableCurve {
+            keyframe_timestamps: vec![0.0, 1.0],
+            keyframes,
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    #[test]
+    fn additive_layer_over_base_pose() {
+        let upper_body = AnimationTargetId::from_name(&"Spine".into());
+        let lower_body = AnimationTargetId::from_name(&"Legs".into());
+
+        // The base layer walks forward
+        let mut walk = AnimationClip::default();
+        for target in [upper_body, lower_body] {
+            walk.add_curve_to_target(
+                target,
+                curve(Keyframes::Translation(vec![Vec3::ZERO, Vec3::X * 10.0])),
+            );
+        }
+        // The additive layer aims up and rises
+        let mut aim = AnimationClip::default();
+        for target in [upper_body, lower_body] {
+            aim.add_curve_to_target(
+                target,
+                curve(Keyframes::Rotation(vec![
+                    Quat::IDENTITY,
+                    Quat::from_rotation_x(FRAC_PI_2),
+                ])),
+            );
+            aim.add_curve_to_target(
+                target,
+                curve(Keyframes::Translation(vec![Vec3::Y, Vec3::Y * 3.0])),
+            );
+        }
+
+        let mut clips = Assets::<AnimationClip>::default();
+        let mut graph = AnimationGraph::new();
+        let walk = graph.add_clip(clips.add(walk), 1.0, graph.root);
+        let aim_layer = graph.add_additive_blend(AdditiveReference::FirstFrame, 1.0, graph.root);
+        graph[aim_layer].mask.set_weight(lower_body, 0.0);
+        let aim = graph.add_clip(clips.add(aim), 1.0, aim_layer);
+
+        let mut graphs = Assets::<AnimationGraph>::default();
+        let graph = graphs.add(graph);
+        let mut world = World::new();
+        world.insert_resource(clips);
+        world.insert_resource(graphs);
+        world.init_resource::<ComputedAnimationGraphs>();
+
+        let mut player = AnimationPlayer::default();
+        player.play(walk).seek_to(0.5);
+        player.play(aim).seek_to(0.5).computed_weight = 0.5;
+        let player = world.spawn((player, graph)).id();
+        let [upper_body, lower_body] = [upper_body, lower_body].map(|id| {
+            world
+                .spawn((Transform::default(), AnimationTarget { id, player }))
+                .id()
+        });
+
+        world.run_system_once(animate_targets);
+
+        // Half of the delta from the first frame, on top of the base pose
+        let upper_body = world.get::<Transform>(upper_body).unwrap();
+        assert!(upper_body
+            .translation
+            .abs_diff_eq(Vec3::new(5.0, 0.5, 0.0), 1e-5));
+        assert!(upper_body
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(FRAC_PI_2 / 4.0), 1e-5));
+
+        // The mask limits the additive layer to the upper body
+        let lower_body = world.get::<Transform>(lower_body).unwrap();
+        assert!(lower_body
+            .translation
+            .abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), 1e-5));
+        assert_eq!(lower_body.rotation, Quat::IDENTITY);
+    }
+}
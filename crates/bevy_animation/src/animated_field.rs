@@ -0,0 +1,685 @@
+//! Animation of the reflected fields of arbitrary components.
+
+use std::ops::{Add, Mul};
+
+use bevy_asset::{Assets, Handle};
+use bevy_color::{Color, Mix};
+use bevy_ecs::{
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    system::SystemState,
+};
+use bevy_math::{FloatExt, Vec2, Vec3, Vec4};
+use bevy_reflect::{GetPath, Reflect, TypePath, TypeRegistry};
+#[cfg(feature = "bevy_ui")]
+use bevy_ui::Val;
+use bevy_utils::warn_once;
+use thiserror::Error;
+
+use crate::{
+    cubic_spline_interpolation, graph::AnimationGraph, graph::AnimationNodeIndex,
+    graph::ComputedAnimationGraphs, AnimationClip, AnimationPlayer, AnimationTarget, Interpolation,
+    Keyframes, VariableCurve,
+};
+
+/// A field of a component, animated by a [`VariableCurve`] with [`Keyframes::Field`] keyframes.
+///
+/// The field is addressed by the [type path] of the component and a [reflection path]
+/// from the component to the field, such as `"left"` for [`Style::left`] or
+/// `"translation.x"` for the `X` translation of a [`Transform`].
+///
+/// ```
+/// # use bevy_animation::{AnimatedField, Interpolation, Keyframes, VariableCurve};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Fade {
+///     opacity: f32,
+/// }
+///
+/// let field = AnimatedField::new::<Fade>("opacity", vec![0.0, 1.0]).unwrap();
+/// let curve = VariableCurve {
+///     keyframe_timestamps: vec![0.0, 1.0],
+///     keyframes: Keyframes::Field(field),
+///     interpolation: Interpolation::Linear,
+/// };
+/// ```
+///
+/// The component must be registered in the [`AppTypeRegistry`] with [`ReflectComponent`].
+///
+/// [type path]: TypePath
+/// [reflection path]: bevy_reflect::ParsedPath
+/// [`Style::left`]: https://docs.rs/bevy/latest/bevy/ui/struct.Style.html#structfield.left
+/// [`Transform`]: bevy_transform::components::Transform
+#[derive(Reflect, Clone, Debug)]
+pub struct AnimatedField {
+    component: String,
+    path: String,
+    keyframes: FieldKeyframes,
+}
+
+/// The keyframes of an [`AnimatedField`], of the type of the field.
+///
+/// With [`Interpolation::CubicSpline`], each keyframe is made of three values, as in
+/// [`VariableCurve::keyframes`]. Colors and `Val`s aren't cubic splines, and are
+/// interpolated linearly between the keyframe values instead.
+#[derive(Reflect, Clone, Debug)]
+pub enum FieldKeyframes {
+    /// Keyframes of an `f32` field.
+    F32(Vec<f32>),
+    /// Keyframes of a [`Vec2`] field.
+    Vec2(Vec<Vec2>),
+    /// Keyframes of a [`Vec3`] field.
+    Vec3(Vec<Vec3>),
+    /// Keyframes of a [`Vec4`] field.
+    Vec4(Vec<Vec4>),
+    /// Keyframes of a [`Color`] field, mixed in the color space of the keyframes.
+    Color(Vec<Color>),
+    /// Keyframes of a [`Val`] field, such as the position of a UI node.
+    ///
+    /// Values in the same unit are interpolated, while values in different units step from one
+    /// to the next.
+    #[cfg(feature = "bevy_ui")]
+    Val(Vec<Val>),
+}
+
+/// An error building an [`AnimatedField`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AnimatedFieldError {
+    /// The path doesn't lead to a field of the component.
+    #[error("`{path}` isn't a field of `{component}`: {error}")]
+    InvalidPath {
+        /// The type path of the component.
+        component: &'static str,
+        /// The path to the field.
+        path: String,
+        /// Why the path is invalid.
+        error: String,
+    },
+    /// The keyframes don't have the type of the field.
+    #[error("`{component}::{path}` is a `{found}`, but its keyframes are `{expected}`s")]
+    TypeMismatch {
+        /// The type path of the component.
+        component: &'static str,
+        /// The path to the field.
+        path: String,
+        /// The type path of the keyframes.
+        expected: &'static str,
+        /// The type path of the field.
+        found: String,
+    },
+}
+
+impl AnimatedField {
+    /// Creates an [`AnimatedField`] animating the field at `path` in the component `C`.
+    ///
+    /// Returns an error if `path` doesn't lead to a field of `C`, or if the field doesn't have
+    /// the type of the `keyframes`, which is checked on the default value of `C`.
+    pub fn new<C: Component + Reflect + TypePath + Default>(
+        path: impl Into<String>,
+        keyframes: impl Into<FieldKeyframes>,
+    ) -> Result<Self, AnimatedFieldError> {
+        let path = path.into();
+        let keyframes = keyframes.into();
+        let component = C::default();
+        let field = match component.reflect_path(path.as_str()) {
+            Ok(field) => field,
+            Err(error) => {
+                return Err(AnimatedFieldError::InvalidPath {
+                    component: C::type_path(),
+                    error: error.to_string(),
+                    path,
+                });
+            }
+        };
+        if !keyframes.matches(field) {
+            return Err(AnimatedFieldError::TypeMismatch {
+                component: C::type_path(),
+                expected: keyframes.value_type_path(),
+                found: field.reflect_type_path().to_string(),
+                path,
+            });
+        }
+
+        Ok(Self::new_unchecked(C::type_path(), path, keyframes))
+    }
+
+    /// Creates an [`AnimatedField`] animating the field at `path` in the component with the type
+    /// path `component`, without checking that the field exists and has the type of the
+    /// `keyframes`.
+    ///
+    /// Fields that can't be animated are reported when the animation plays.
+    pub fn new_unchecked(
+        component: impl Into<String>,
+        path: impl Into<String>,
+        keyframes: impl Into<FieldKeyframes>,
+    ) -> Self {
+        Self {
+            component: component.into(),
+            path: path.into(),
+            keyframes: keyframes.into(),
+        }
+    }
+
+    /// The type path of the animated component.
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    /// The path from the component to the animated field.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The keyframes of the field.
+    pub fn keyframes(&self) -> &FieldKeyframes {
+        &self.keyframes
+    }
+
+    fn is_same_field(&self, other: &AnimatedField) -> bool {
+        self.component == other.component && self.path == other.path
+    }
+
+    /// Samples the value of the field in `curve` at `seek_time`, or returns `None` if the curve
+    /// isn't playing at that time.
+    fn sample(&self, curve: &VariableCurve, seek_time: f32) -> Option<AnimatedValue> {
+        let step = if curve.keyframe_timestamps.len() == 1 {
+            None
+        } else {
+            let step_start = curve.find_current_keyframe(seek_time)?;
+            let timestamp_start = curve.keyframe_timestamps[step_start];
+            let timestamp_end = curve.keyframe_timestamps[step_start + 1];
+            Some(Step {
+                start: step_start,
+                lerp: f32::inverse_lerp(timestamp_start, timestamp_end, seek_time),
+                duration: timestamp_end - timestamp_start,
+            })
+        };
+
+        let interpolation = &curve.interpolation;
+        Some(match &self.keyframes {
+            FieldKeyframes::F32(keyframes) => AnimatedValue::F32(sample_spline(
+                keyframes,
+                interpolation,
+                step,
+                FloatExt::lerp,
+            )),
+            FieldKeyframes::Vec2(keyframes) => {
+                AnimatedValue::Vec2(sample_spline(keyframes, interpolation, step, Vec2::lerp))
+            }
+            FieldKeyframes::Vec3(keyframes) => {
+                AnimatedValue::Vec3(sample_spline(keyframes, interpolation, step, Vec3::lerp))
+            }
+            FieldKeyframes::Vec4(keyframes) => {
+                AnimatedValue::Vec4(sample_spline(keyframes, interpolation, step, Vec4::lerp))
+            }
+            FieldKeyframes::Color(keyframes) => {
+                AnimatedValue::Color(sample(keyframes, interpolation, step, |a, b, t| {
+                    a.mix(&b, t)
+                }))
+            }
+            #[cfg(feature = "bevy_ui")]
+            FieldKeyframes::Val(keyframes) => {
+                AnimatedValue::Val(sample(keyframes, interpolation, step, lerp_val))
+            }
+        })
+    }
+
+    /// Writes `value` to the field of the component of `entity`, triggering change detection only
+    /// if the field changed.
+    fn apply(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        type_registry: &TypeRegistry,
+        value: AnimatedValue,
+    ) {
+        let Some(reflect_component) = type_registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        else {
+            warn_once!(
+                "Tried to animate `{}`, which isn't a registered component with `#[reflect(Component)]`",
+                self.component,
+            );
+            return;
+        };
+        let Some(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+            return;
+        };
+
+        let changed = match component
+            .bypass_change_detection()
+            .reflect_path_mut(self.path.as_str())
+        {
+            Ok(field) => value.write(field),
+            Err(error) => {
+                warn_once!(
+                    "Tried to animate `{}::{}`, which isn't a field: {error}",
+                    self.component,
+                    self.path,
+                );
+                return;
+            }
+        };
+        match changed {
+            Some(true) => component.set_changed(),
+            Some(false) => {}
+            None => warn_once!(
+                "Tried to animate `{}::{}` with `{}` keyframes, which don't have its type",
+                self.component,
+                self.path,
+                self.keyframes.value_type_path(),
+            ),
+        }
+    }
+}
+
+impl FieldKeyframes {
+    /// Returns the number of keyframes.
+    pub fn len(&self) -> usize {
+        match self {
+            FieldKeyframes::F32(keyframes) => keyframes.len(),
+            FieldKeyframes::Vec2(keyframes) => keyframes.len(),
+            FieldKeyframes::Vec3(keyframes) => keyframes.len(),
+            FieldKeyframes::Vec4(keyframes) => keyframes.len(),
+            FieldKeyframes::Color(keyframes) => keyframes.len(),
+            #[cfg(feature = "bevy_ui")]
+            FieldKeyframes::Val(keyframes) => keyframes.len(),
+        }
+    }
+
+    /// Returns true if the number of keyframes is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if `field` has the type of the keyframes.
+    pub fn matches(&self, field: &dyn Reflect) -> bool {
+        match self {
+            FieldKeyframes::F32(_) => field.is::<f32>(),
+            FieldKeyframes::Vec2(_) => field.is::<Vec2>(),
+            FieldKeyframes::Vec3(_) => field.is::<Vec3>(),
+            FieldKeyframes::Vec4(_) => field.is::<Vec4>(),
+            FieldKeyframes::Color(_) => field.is::<Color>(),
+            #[cfg(feature = "bevy_ui")]
+            FieldKeyframes::Val(_) => field.is::<Val>(),
+        }
+    }
+
+    /// Returns the type path of the keyframes.
+    pub fn value_type_path(&self) -> &'static str {
+        match self {
+            FieldKeyframes::F32(_) => f32::type_path(),
+            FieldKeyframes::Vec2(_) => Vec2::type_path(),
+            FieldKeyframes::Vec3(_) => Vec3::type_path(),
+            FieldKeyframes::Vec4(_) => Vec4::type_path(),
+            FieldKeyframes::Color(_) => Color::type_path(),
+            #[cfg(feature = "bevy_ui")]
+            FieldKeyframes::Val(_) => Val::type_path(),
+        }
+    }
+}
+
+macro_rules! impl_from_keyframes {
+    ($($ty:ident => $variant:ident),*) => {
+        $(
+            impl From<Vec<$ty>> for FieldKeyframes {
+                fn from(keyframes: Vec<$ty>) -> Self {
+                    FieldKeyframes::$variant(keyframes)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_keyframes!(f32 => F32, Vec2 => Vec2, Vec3 => Vec3, Vec4 => Vec4, Color => Color);
+#[cfg(feature = "bevy_ui")]
+impl_from_keyframes!(Val => Val);
+
+/// The value of an [`AnimatedField`] at some time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AnimatedValue {
+    F32(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4(Vec4),
+    Color(Color),
+    #[cfg(feature = "bevy_ui")]
+    Val(Val),
+}
+
+impl AnimatedValue {
+    /// Interpolates between `self` and `other`, which should be values of the same field.
+    fn lerp(self, other: Self, t: f32) -> Self {
+        match (self, other) {
+            (AnimatedValue::F32(a), AnimatedValue::F32(b)) => AnimatedValue::F32(a.lerp(b, t)),
+            (AnimatedValue::Vec2(a), AnimatedValue::Vec2(b)) => AnimatedValue::Vec2(a.lerp(b, t)),
+            (AnimatedValue::Vec3(a), AnimatedValue::Vec3(b)) => AnimatedValue::Vec3(a.lerp(b, t)),
+            (AnimatedValue::Vec4(a), AnimatedValue::Vec4(b)) => AnimatedValue::Vec4(a.lerp(b, t)),
+            (AnimatedValue::Color(a), AnimatedValue::Color(b)) => {
+                AnimatedValue::Color(a.mix(&b, t))
+            }
+            #[cfg(feature = "bevy_ui")]
+            (AnimatedValue::Val(a), AnimatedValue::Val(b)) => AnimatedValue::Val(lerp_val(a, b, t)),
+            // Keyframes of different types for the same field, which can't all be applied
+            (_, other) => other,
+        }
+    }
+
+    /// Writes this value to `field`, returning whether it changed, or `None` if the field
+    /// doesn't have the type of the value.
+    fn write(self, field: &mut dyn Reflect) -> Option<bool> {
+        fn set<T: Reflect + PartialEq>(field: &mut dyn Reflect, value: T) -> Option<bool> {
+            let field = field.downcast_mut::<T>()?;
+            let changed = *field != value;
+            if changed {
+                *field = value;
+            }
+            Some(changed)
+        }
+
+        match self {
+            AnimatedValue::F32(value) => set(field, value),
+            AnimatedValue::Vec2(value) => set(field, value),
+            AnimatedValue::Vec3(value) => set(field, value),
+            AnimatedValue::Vec4(value) => set(field, value),
+            AnimatedValue::Color(value) => set(field, value),
+            #[cfg(feature = "bevy_ui")]
+            AnimatedValue::Val(value) => set(field, value),
+        }
+    }
+}
+
+/// The keyframes between which a curve is interpolated.
+#[derive(Clone, Copy)]
+struct Step {
+    start: usize,
+    lerp: f32,
+    duration: f32,
+}
+
+/// Samples `keyframes` at `step`, or their only keyframe if `step` is `None`, interpolating
+/// cubic splines linearly between their values with `mix`.
+fn sample<T: Copy>(
+    keyframes: &[T],
+    interpolation: &Interpolation,
+    step: Option<Step>,
+    mix: impl Fn(T, T, f32) -> T,
+) -> T {
+    let value = |index: usize| match interpolation {
+        Interpolation::CubicSpline => keyframes[index * 3 + 1],
+        Interpolation::Linear | Interpolation::Step => keyframes[index],
+    };
+    let Some(step) = step else {
+        return value(0);
+    };
+    match interpolation {
+        Interpolation::Step => value(step.start),
+        Interpolation::Linear | Interpolation::CubicSpline => {
+            mix(value(step.start), value(step.start + 1), step.lerp)
+        }
+    }
+}
+
+/// Samples `keyframes` like [`sample`], with cubic splines.
+fn sample_spline<T>(
+    keyframes: &[T],
+    interpolation: &Interpolation,
+    step: Option<Step>,
+    mix: impl Fn(T, T, f32) -> T,
+) -> T
+where
+    T: Copy + Mul<f32, Output = T> + Add<Output = T>,
+{
+    match (interpolation, step) {
+        (Interpolation::CubicSpline, Some(step)) => cubic_spline_interpolation(
+            keyframes[step.start * 3 + 1],
+            keyframes[step.start * 3 + 2],
+            keyframes[(step.start + 1) * 3],
+            keyframes[(step.start + 1) * 3 + 1],
+            step.lerp,
+            step.duration,
+        ),
+        _ => sample(keyframes, interpolation, step, mix),
+    }
+}
+
+#[cfg(feature = "bevy_ui")]
+fn lerp_val(a: Val, b: Val, t: f32) -> Val {
+    match (a, b) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a.lerp(b, t)),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a.lerp(b, t)),
+        (Val::Vw(a), Val::Vw(b)) => Val::Vw(a.lerp(b, t)),
+        (Val::Vh(a), Val::Vh(b)) => Val::Vh(a.lerp(b, t)),
+        (Val::VMin(a), Val::VMin(b)) => Val::VMin(a.lerp(b, t)),
+        (Val::VMax(a), Val::VMax(b)) => Val::VMax(a.lerp(b, t)),
+        _ if t < 1.0 => a,
+        _ => b,
+    }
+}
+
+/// A system that modifies the [`AnimatedField`]s of animation targets according to the
+/// currently-playing animation.
+///
+/// Blended animations are weighted like in [`animate_targets`](crate::animate_targets).
+/// Components are only marked as changed when an animated field changes.
+///
+/// This system is exclusive since the animated fields can be on any reflected component, which
+/// are only known from the clips at runtime. Its buffers are kept between frames.
+pub fn animate_fields(
+    world: &mut World,
+    targets_query: &mut SystemState<Query<(Entity, &AnimationTarget)>>,
+    mut targets: Local<Vec<(Entity, AnimationTarget)>>,
+    mut animations: Local<Vec<(AnimationNodeIndex, f32, f32)>>,
+    mut blended: Local<Vec<BlendedField>>,
+) {
+    let Some(type_registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    targets.clear();
+    targets.extend(
+        targets_query
+            .get(world)
+            .iter()
+            .map(|(entity, target)| (entity, *target)),
+    );
+
+    world.resource_scope(|world, clips: Mut<Assets<AnimationClip>>| {
+        world.resource_scope(|world, graphs: Mut<Assets<AnimationGraph>>| {
+            let type_registry = type_registry.read();
+            for &(entity, target) in targets.iter() {
+                let Some((graph_id, graph)) = world
+                    .get::<Handle<AnimationGraph>>(target.player)
+                    .and_then(|handle| Some((handle.id(), graphs.get(handle)?)))
+                else {
+                    continue;
+                };
+                let computed_graph = world
+                    .resource::<ComputedAnimationGraphs>()
+                    .get_or_compute(graph_id, graph);
+                let Some(player) = world.get::<AnimationPlayer>(target.player) else {
+                    continue;
+                };
+                animations.clear();
+                animations.extend(
+                    player
+                        .active_animations
+                        .iter()
+                        // Fields aren't animated additively
+                        .filter(|(&node, animation)| {
+                            animation.computed_weight > 0.0
+                                && computed_graph.additive_reference(node).is_none()
+                        })
+                        .map(|(&node, animation)| {
+                            let weight = animation.computed_weight
+                                * computed_graph.mask_weight(node, target.id);
+                            (node, weight, animation.seek_time)
+                        })
+                        .filter(|&(_, weight, _)| weight > 0.0),
+                );
+
+                let curves_of = |node: AnimationNodeIndex| {
+                    graph
+                        .get(node)
+                        .and_then(|node| node.clip.as_ref())
+                        .and_then(|handle| clips.get(handle))
+                        .and_then(|clip| clip.curves_for_target(target.id))
+                };
+                let field_of = |node, curve: usize| match &curves_of(node)?[curve].keyframes {
+                    Keyframes::Field(field) => Some(field),
+                    _ => None,
+                };
+
+                blended.clear();
+                for &(node, weight, seek_time) in animations.iter() {
+                    let Some(curves) = curves_of(node) else {
+                        continue;
+                    };
+                    for (index, curve) in curves.iter().enumerate() {
+                        let Keyframes::Field(field) = &curve.keyframes else {
+                            continue;
+                        };
+                        let Some(value) = field.sample(curve, seek_time) else {
+                            continue;
+                        };
+                        match blended.iter_mut().find(|other| {
+                            field_of(other.node, other.curve)
+                                .is_some_and(|other| other.is_same_field(field))
+                        }) {
+                            Some(other) => {
+                                other.weight += weight;
+                                other.value = other.value.lerp(value, weight / other.weight);
+                            }
+                            None => blended.push(BlendedField {
+                                node,
+                                curve: index,
+                                value,
+                                weight,
+                            }),
+                        }
+                    }
+                }
+
+                for value in blended.drain(..) {
+                    if let Some(field) = field_of(value.node, value.curve) {
+                        field.apply(world, entity, &type_registry, value.value);
+                    }
+                }
+            }
+        });
+    });
+}
+
+/// The value of an [`AnimatedField`] blended by [`animate_fields`], from the first curve of the
+/// field among the playing animations.
+///
+/// This is the buffer [`animate_fields`] keeps between frames, which has to be public since it's
+/// one of the parameters of the system. It has no public API, and can't be constructed outside of
+/// this crate.
+pub struct BlendedField {
+    node: AnimationNodeIndex,
+    curve: usize,
+    value: AnimatedValue,
+    weight: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationTargetId;
+    use bevy_color::palettes::basic::{BLACK, WHITE};
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Panel {
+        offset: Vec2,
+        color: Color,
+    }
+
+    #[test]
+    fn animated_field_type_mismatch() {
+        assert!(AnimatedField::new::<Panel>("offset", vec![Vec2::ZERO]).is_ok());
+        assert!(matches!(
+            AnimatedField::new::<Panel>("offset", vec![0.0]),
+            Err(AnimatedFieldError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            AnimatedField::new::<Panel>("missing", vec![0.0]),
+            Err(AnimatedFieldError::InvalidPath { .. })
+        ));
+    }
+
+    #[test]
+    fn animate_component_fields() {
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Panel>();
+        world.insert_resource(type_registry);
+
+        let target_id = AnimationTargetId(uuid::Uuid::nil());
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_target(
+            target_id,
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                keyframes: Keyframes::Field(
+                    AnimatedField::new::<Panel>("offset", vec![Vec2::ZERO, Vec2::new(100.0, 0.0)])
+                        .unwrap(),
+                ),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        clip.add_curve_to_target(
+            target_id,
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                keyframes: Keyframes::Field(
+                    AnimatedField::new::<Panel>("color", vec![Color::from(BLACK), WHITE.into()])
+                        .unwrap(),
+                ),
+                interpolation: Interpolation::Step,
+            },
+        );
+
+        let mut clips = Assets::<AnimationClip>::default();
+        let (graph, node) = AnimationGraph::from_clip(clips.add(clip));
+        let mut graphs = Assets::<AnimationGraph>::default();
+        let graph = graphs.add(graph);
+        world.insert_resource(clips);
+        world.insert_resource(graphs);
+        world.init_resource::<ComputedAnimationGraphs>();
+
+        let mut player = AnimationPlayer::default();
+        player.play(node).seek_to(0.25);
+        let player = world.spawn((player, graph)).id();
+        let panel = world
+            .spawn((
+                Panel::default(),
+                AnimationTarget {
+                    id: target_id,
+                    player,
+                },
+            ))
+            .id();
+
+        world.run_system_once(animate_fields);
+        assert_eq!(
+            world.get::<Panel>(panel),
+            Some(&Panel {
+                offset: Vec2::new(25.0, 0.0),
+                color: BLACK.into(),
+            })
+        );
+
+        // Unchanged fields don't trigger change detection
+        world.clear_trackers();
+        world.run_system_once(animate_fields);
+        assert!(!world.entity(panel).get_ref::<Panel>().unwrap().is_changed());
+    }
+}
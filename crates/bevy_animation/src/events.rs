@@ -0,0 +1,273 @@
+//! Events triggered when the playback of an [`AnimationClip`] crosses their time.
+
+use std::{fmt, sync::Arc};
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::Reflect;
+
+use crate::AnimationClip;
+
+/// An event of an [`AnimationClip`], triggered when its playback crosses [`AnimationEvent::time`].
+///
+/// See [`AnimationClip::add_event`].
+#[derive(Reflect, Clone, Debug)]
+pub struct AnimationEvent {
+    /// The time of the event in the clip, in seconds.
+    pub time: f32,
+    /// What the event does when it's triggered.
+    pub trigger: AnimationEventFn,
+}
+
+/// A function run when an [`AnimationEvent`] is triggered, with the entity of the
+/// [`AnimationPlayer`](crate::AnimationPlayer) playing the clip.
+#[derive(Reflect, Clone)]
+#[reflect_value]
+pub struct AnimationEventFn(Arc<dyn Fn(&mut Commands, Entity) + Send + Sync>);
+
+impl AnimationEventFn {
+    /// Creates an [`AnimationEventFn`] running `f`.
+    pub fn new(f: impl Fn(&mut Commands, Entity) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Creates an [`AnimationEventFn`] triggering `event` for the observers of the entity.
+    pub fn trigger<E: Event + Clone>(event: E) -> Self {
+        Self::new(move |commands, entity| commands.trigger_targets(event.clone(), entity))
+    }
+
+    /// Runs the function for `entity`.
+    pub fn run(&self, commands: &mut Commands, entity: Entity) {
+        (self.0)(commands, entity);
+    }
+}
+
+impl fmt::Debug for AnimationEventFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnimationEventFn").finish_non_exhaustive()
+    }
+}
+
+/// An event triggered by a named marker of an animation, such as those imported from glTF files.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_animation::AnimationMarker;
+/// fn play_footsteps(trigger: Trigger<AnimationMarker>) {
+///     if trigger.event().name == "footstep" {
+///         // Play a sound at `trigger.entity()`
+///     }
+/// }
+/// ```
+#[derive(Event, Reflect, Clone, Debug, PartialEq, Eq)]
+pub struct AnimationMarker {
+    /// The name of the marker.
+    pub name: String,
+}
+
+impl AnimationMarker {
+    /// Creates an [`AnimationMarker`] named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl AnimationClip {
+    /// Calls `f` with the events crossed by a playback starting at `seek_time` and moving by
+    /// `travel` seconds, unrolled over the loops of the clip, in the order they are crossed.
+    ///
+    /// The events at `seek_time` are crossed, but not the ones at the end of the playback, so
+    /// that consecutive playbacks cross each event once. If the animation `finished`, the events
+    /// at the end of its last loop are crossed too.
+    pub(crate) fn for_each_crossed_event(
+        &self,
+        seek_time: f32,
+        travel: f32,
+        finished: bool,
+        mut f: impl FnMut(&AnimationEvent),
+    ) {
+        let duration = self.duration();
+        let events = self.events();
+        if events.is_empty() || duration <= 0.0 || travel == 0.0 {
+            return;
+        }
+
+        let end = seek_time + travel;
+        let start_lap = (seek_time / duration).floor() as i32;
+        if travel > 0.0 {
+            // A finished animation ends right at the end of its last loop
+            let end_lap = if finished {
+                (end / duration).round() as i32 - 1
+            } else {
+                (end / duration).floor() as i32
+            };
+            for lap in start_lap..=end_lap {
+                let offset = lap as f32 * duration;
+                for event in events {
+                    let time = offset + event.time;
+                    if time >= seek_time && (finished || time < end) {
+                        f(event);
+                    }
+                }
+            }
+        } else {
+            // A finished animation ends right at the start of its last loop
+            let end_lap = if finished {
+                (end / duration).round() as i32
+            } else {
+                (end / duration).floor() as i32
+            };
+            for lap in (end_lap..=start_lap).rev() {
+                let offset = lap as f32 * duration;
+                for event in events.iter().rev() {
+                    let time = offset + event.time;
+                    if time <= seek_time && (finished || time > end) {
+                        f(event);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_ecs::{prelude::*, system::RunSystemOnce};
+    use bevy_time::Time;
+
+    use crate::{
+        advance_animations, ActiveAnimation, AnimationClip, AnimationGraph, AnimationMarker,
+        AnimationPlayer, RepeatAnimation,
+    };
+
+    fn clip() -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        clip.set_duration(1.0);
+        for time in [0.75, 0.0, 0.25] {
+            clip.add_event_fn(time, |_, _| {});
+        }
+        clip
+    }
+
+    /// Plays `clip` in steps of `delta`, returning the times of the triggered events.
+    fn play(clip: &AnimationClip, animation: &mut ActiveAnimation, deltas: &[f32]) -> Vec<f32> {
+        let mut times = Vec::new();
+        for &delta in deltas {
+            let seek_time = animation.seek_time();
+            let travel = animation.update(delta, clip.duration());
+            let finished = animation.is_finished();
+            clip.for_each_crossed_event(seek_time, travel, finished, |event| {
+                times.push(event.time);
+            });
+        }
+        times
+    }
+
+    #[test]
+    fn events_crossed_in_one_frame() {
+        let clip = clip();
+        let mut animation = ActiveAnimation::default();
+        assert_eq!(play(&clip, &mut animation, &[0.1]), [0.0]);
+        assert_eq!(play(&clip, &mut animation, &[0.8]), [0.25, 0.75]);
+        assert_eq!(play(&clip, &mut animation, &[0.05]), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn looping_events_fire_each_loop() {
+        let clip = clip();
+        let mut animation = ActiveAnimation::default();
+        animation.repeat();
+        assert_eq!(
+            play(&clip, &mut animation, &[0.5, 0.5, 0.5, 0.5]),
+            [0.0, 0.25, 0.75, 0.0, 0.25, 0.75]
+        );
+
+        // Playing fast enough to skip over a whole loop in a single frame
+        let mut animation = ActiveAnimation::default();
+        animation.repeat().set_speed(4.0);
+        assert_eq!(
+            play(&clip, &mut animation, &[0.3125]),
+            [0.0, 0.25, 0.75, 0.0]
+        );
+        assert_eq!(animation.completions(), 1);
+        assert_eq!(animation.seek_time(), 0.25);
+    }
+
+    #[test]
+    fn events_stop_when_the_animation_finishes() {
+        let clip = clip();
+        let mut animation = ActiveAnimation::default();
+        animation
+            .set_repeat(RepeatAnimation::Count(2))
+            .set_speed(10.0);
+        assert_eq!(
+            play(&clip, &mut animation, &[1.0]),
+            [0.0, 0.25, 0.75, 0.0, 0.25, 0.75]
+        );
+        assert!(animation.is_finished());
+        assert_eq!(animation.completions(), 2);
+    }
+
+    #[test]
+    fn reversed_events_fire_in_reverse_order() {
+        let clip = clip();
+        let mut animation = ActiveAnimation::default();
+        animation.repeat().set_speed(-1.0);
+        assert_eq!(play(&clip, &mut animation, &[0.5]), [0.0, 0.75]);
+        assert_eq!(play(&clip, &mut animation, &[0.5]), [0.25]);
+        assert_eq!(play(&clip, &mut animation, &[0.5]), [0.0, 0.75]);
+    }
+
+    #[derive(Resource, Default)]
+    struct Markers(Vec<String>);
+
+    #[test]
+    fn seeking_triggers_events_only_if_requested() {
+        let mut clip = AnimationClip::default();
+        clip.set_duration(1.0);
+        clip.add_event(0.5, AnimationMarker::new("step"));
+        let mut clips = Assets::<AnimationClip>::default();
+        let (graph, animation) = AnimationGraph::from_clip(clips.add(clip));
+        let mut graphs = Assets::<AnimationGraph>::default();
+        let graph = graphs.add(graph);
+
+        let mut world = World::new();
+        world.insert_resource(clips);
+        world.insert_resource(graphs);
+        world.init_resource::<Time>();
+        world.init_resource::<Markers>();
+        world.observe(
+            |trigger: Trigger<AnimationMarker>, mut markers: ResMut<Markers>| {
+                markers.0.push(trigger.event().name.clone());
+            },
+        );
+
+        let mut player = AnimationPlayer::default();
+        player.play(animation).pause();
+        let player = world.spawn((player, graph)).id();
+        let seek = |world: &mut World, seek_time| {
+            let mut animation_player = world.get_mut::<AnimationPlayer>(player).unwrap();
+            animation_player.seek_to(animation, seek_time);
+            world.run_system_once(advance_animations);
+            std::mem::take(&mut world.resource_mut::<Markers>().0)
+        };
+
+        // Scrubbing back and forth doesn't trigger the event again
+        assert!(seek(&mut world, 0.75).is_empty());
+        assert!(seek(&mut world, 0.25).is_empty());
+        let animation_player = world.get::<AnimationPlayer>(player).unwrap();
+        assert_eq!(
+            animation_player.animation(animation).unwrap().seek_time(),
+            0.25
+        );
+
+        // Unless requested, in both directions
+        world
+            .get_mut::<AnimationPlayer>(player)
+            .unwrap()
+            .set_fire_events_on_seek(true);
+        assert_eq!(seek(&mut world, 0.75), ["step"]);
+        assert_eq!(seek(&mut world, 0.25), ["step"]);
+        assert!(seek(&mut world, 0.4).is_empty());
+    }
+}
@@ -1,17 +1,25 @@
 //! The animation graph, which allows animations to be blended together.
 
-use std::io::{self, Write};
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
 use std::ops::{Index, IndexMut};
+use std::path::Path;
 
 use bevy_asset::io::Reader;
 use bevy_asset::{Asset, AssetId, AssetLoader, AssetPath, AsyncReadExt as _, Handle, LoadContext};
+use bevy_core::Name;
+use bevy_ecs::system::Resource;
 use bevy_reflect::{Reflect, ReflectSerialize};
+use bevy_utils::HashMap;
 use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::{Bfs, Reversed};
 use ron::de::SpannedError;
+use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::AnimationClip;
+use crate::{AnimationClip, AnimationTargetId};
 
 /// A graph structure that describes how animation clips are to be blended
 /// together.
@@ -58,7 +66,10 @@ use crate::AnimationClip;
 /// Thus the weight of Run and Walk are effectively half of the weight of Idle.
 ///
 /// Animation graphs are assets and can be serialized to and loaded from [RON]
-/// files. Canonically, such files have an `.animgraph.ron` extension.
+/// files. Canonically, such files have an `.animgraph.ron` extension. When a
+/// graph asset is hot-reloaded, the [`crate::AnimationPlayer`]s keep playing
+/// its nodes with the same [`AnimationGraphNode::name`], even if their indices
+/// changed.
 ///
 /// The animation graph implements [RFC 51]. See that document for more
 /// information.
@@ -93,6 +104,14 @@ pub type AnimationNodeIndex = NodeIndex<u32>;
 /// down to descendants.
 #[derive(Clone, Reflect, Debug)]
 pub struct AnimationGraphNode {
+    /// The name of this node, if any.
+    ///
+    /// Names identify the nodes across edits of a graph asset: when it's
+    /// reloaded, the animations playing named nodes keep their times and
+    /// weights, while the animations playing unnamed nodes are only kept if
+    /// the node at their index is unnamed too.
+    pub name: Option<Name>,
+
     /// The animation clip associated with this node, if any.
     ///
     /// If the clip is present, this node is an *animation clip node*.
@@ -105,6 +124,205 @@ pub struct AnimationGraphNode {
     /// has weight 0.3 and its parent blend node has weight 0.6, the computed
     /// weight of the animation clip is 0.18.
     pub weight: f32,
+
+    /// The reference pose of this node, if it's additive.
+    ///
+    /// Additive clips, or the clips of additive blend nodes, aren't
+    /// interpolated with the other clips. Instead, the difference between
+    /// their pose and the reference pose is scaled by their weight and added
+    /// on top of the pose blended from the other clips. This layers an aim
+    /// offset or breathing over a locomotion animation, for example.
+    pub additive: Option<AdditiveReference>,
+
+    /// The weights of this node for each animation target.
+    ///
+    /// Like weights, masks are propagated down to descendants. They limit the
+    /// influence of a layer to some bones, such as the upper body.
+    pub mask: AnimationMask,
+
+    /// Whether this node is disabled, which makes its weight zero.
+    ///
+    /// The nodes whose clip failed to load are disabled, so that the rest of
+    /// the graph keeps playing.
+    pub disabled: bool,
+}
+
+/// The pose that an additive [`AnimationGraphNode`] is relative to.
+///
+/// The clip of an additive node contributes `clip_pose - reference_pose`,
+/// where rotations are composed as quaternions rather than subtracted.
+#[derive(Clone, Reflect, Debug, Default)]
+pub enum AdditiveReference {
+    /// The first keyframe of each curve of the clip itself.
+    #[default]
+    FirstFrame,
+    /// The first keyframe of each curve of another clip, such as a neutral
+    /// pose.
+    Clip(Handle<AnimationClip>),
+}
+
+/// Per-target weights of an [`AnimationGraphNode`], limiting its influence to
+/// some animation targets.
+///
+/// The targets that aren't in the mask have its default weight, which is 1.0
+/// unless created with [`AnimationMask::new`].
+///
+/// Masks can be authored by bone names with the paths of the targets, such as
+/// `Gltf::animation_target_paths` for glTF files:
+///
+/// ```ignore
+/// // The spine, and the chest, arms and head parented to it
+/// let mut upper_body = AnimationMask::new(0.0);
+/// upper_body.set_weight_by_prefix(&gltf.animation_target_paths, "mixamorig:Spine", 1.0);
+/// animation_graph[aim_layer].mask = upper_body;
+/// ```
+#[derive(Clone, Reflect, Debug, Serialize, Deserialize)]
+pub struct AnimationMask {
+    weights: HashMap<AnimationTargetId, f32>,
+    default_weight: f32,
+}
+
+impl AnimationMask {
+    /// Creates a mask where all targets have `default_weight`.
+    pub fn new(default_weight: f32) -> Self {
+        Self {
+            weights: HashMap::default(),
+            default_weight,
+        }
+    }
+
+    /// The weight of `target`.
+    pub fn weight(&self, target: AnimationTargetId) -> f32 {
+        self.weights
+            .get(&target)
+            .copied()
+            .unwrap_or(self.default_weight)
+    }
+
+    /// The mask applying both this mask and `other`, whose weights are the
+    /// products of their weights.
+    fn combined(&self, other: &AnimationMask) -> AnimationMask {
+        let mut combined = AnimationMask::new(self.default_weight * other.default_weight);
+        for &target in self.weights.keys().chain(other.weights.keys()) {
+            combined
+                .weights
+                .insert(target, self.weight(target) * other.weight(target));
+        }
+        combined
+    }
+
+    /// Sets the weight of `target`.
+    pub fn set_weight(&mut self, target: AnimationTargetId, weight: f32) -> &mut Self {
+        self.weights.insert(target, weight);
+        self
+    }
+
+    /// Sets the weight of the targets whose name starts with `prefix`, and of
+    /// all their descendants.
+    ///
+    /// `target_paths` are the names of the targets and of their ancestors,
+    /// from which their [`AnimationTargetId`] is generated.
+    pub fn set_weight_by_prefix<'a>(
+        &mut self,
+        target_paths: impl IntoIterator<Item = (&'a AnimationTargetId, &'a Vec<Name>)>,
+        prefix: &str,
+        weight: f32,
+    ) -> &mut Self {
+        for (&target, path) in target_paths {
+            if path.iter().any(|name| name.as_str().starts_with(prefix)) {
+                self.weights.insert(target, weight);
+            }
+        }
+        self
+    }
+}
+
+impl Default for AnimationMask {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// The masks and additive references that the nodes of an [`AnimationGraph`]
+/// inherit from their ancestors, computed once rather than by walking the
+/// ancestors of the nodes for every animation target.
+///
+/// See [`ComputedAnimationGraphs`].
+#[derive(Clone, Debug, Default)]
+pub struct ComputedAnimationGraph {
+    nodes: Vec<ComputedAnimationNode>,
+}
+
+#[derive(Clone, Debug)]
+struct ComputedAnimationNode {
+    /// The masks of the node and of its ancestors, combined.
+    mask: AnimationMask,
+    additive: Option<AdditiveReference>,
+}
+
+impl ComputedAnimationGraph {
+    /// Computes the data that the nodes of `graph` inherit.
+    pub fn new(graph: &AnimationGraph) -> Self {
+        let nodes = graph
+            .graph
+            .node_indices()
+            .map(|node| ComputedAnimationNode {
+                mask: graph
+                    .ancestors(node)
+                    .fold(AnimationMask::default(), |mask, ancestor| {
+                        mask.combined(&graph.graph[ancestor].mask)
+                    }),
+                additive: graph.additive_reference(node).cloned(),
+            })
+            .collect();
+        Self { nodes }
+    }
+
+    /// Whether this was computed from a graph with the nodes of `graph`.
+    ///
+    /// Nodes are only ever appended or removed, so the number of nodes tells
+    /// whether they changed.
+    fn matches(&self, graph: &AnimationGraph) -> bool {
+        self.nodes.len() == graph.graph.node_count()
+    }
+
+    /// Same as [`AnimationGraph::additive_reference`].
+    pub fn additive_reference(&self, node: AnimationNodeIndex) -> Option<&AdditiveReference> {
+        self.nodes.get(node.index())?.additive.as_ref()
+    }
+
+    /// Same as [`AnimationGraph::mask_weight`].
+    pub fn mask_weight(&self, node: AnimationNodeIndex, target: AnimationTargetId) -> f32 {
+        self.nodes
+            .get(node.index())
+            .map_or(1.0, |node| node.mask.weight(target))
+    }
+}
+
+/// The [`ComputedAnimationGraph`] of every [`AnimationGraph`] asset, updated
+/// when the graphs are added or modified.
+///
+/// Like the remapping of the nodes of modified graphs, the update happens the
+/// frame after the modification.
+#[derive(Resource, Debug, Default)]
+pub struct ComputedAnimationGraphs(
+    pub(crate) HashMap<AssetId<AnimationGraph>, ComputedAnimationGraph>,
+);
+
+impl ComputedAnimationGraphs {
+    /// The computed data of the graph `id`, or the data computed for this
+    /// frame only if `graph` was added or had nodes added since the last
+    /// update.
+    pub fn get_or_compute<'a>(
+        &'a self,
+        id: AssetId<AnimationGraph>,
+        graph: &AnimationGraph,
+    ) -> Cow<'a, ComputedAnimationGraph> {
+        match self.0.get(&id) {
+            Some(computed) if computed.matches(graph) => Cow::Borrowed(computed),
+            _ => Cow::Owned(ComputedAnimationGraph::new(graph)),
+        }
+    }
 }
 
 /// An [`AssetLoader`] that can load [`AnimationGraph`]s as assets.
@@ -152,10 +370,28 @@ pub struct SerializedAnimationGraph {
 /// See the comments in [`SerializedAnimationGraph`] for more information.
 #[derive(Serialize, Deserialize)]
 pub struct SerializedAnimationGraphNode {
+    /// Corresponds to the `name` field on [`AnimationGraphNode`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /// Corresponds to the `clip` field on [`AnimationGraphNode`].
     pub clip: Option<SerializedAnimationClip>,
     /// Corresponds to the `weight` field on [`AnimationGraphNode`].
     pub weight: f32,
+    /// Corresponds to the `additive` field on [`AnimationGraphNode`].
+    #[serde(default)]
+    pub additive: Option<SerializedAdditiveReference>,
+    /// Corresponds to the `mask` field on [`AnimationGraphNode`].
+    #[serde(default)]
+    pub mask: AnimationMask,
+}
+
+/// A version of [`AdditiveReference`] suitable for serializing as an asset.
+#[derive(Serialize, Deserialize)]
+pub enum SerializedAdditiveReference {
+    /// Corresponds to [`AdditiveReference::FirstFrame`].
+    FirstFrame,
+    /// Corresponds to [`AdditiveReference::Clip`].
+    Clip(SerializedAnimationClip),
 }
 
 /// A version of `Handle<AnimationClip>` suitable for serializing as an asset.
@@ -205,11 +441,30 @@ impl AnimationGraph {
         let node_index = self.graph.add_node(AnimationGraphNode {
             clip: Some(clip),
             weight,
+            ..AnimationGraphNode::default()
         });
         self.graph.add_edge(parent, node_index, ());
         node_index
     }
 
+    /// Adds an additive [`AnimationClip`] to the animation graph with the
+    /// given weight and returns its index.
+    ///
+    /// The difference between the pose of the clip and the `reference` pose
+    /// is added on top of the other animations, scaled by the weight. See
+    /// [`AnimationGraphNode::additive`].
+    pub fn add_additive_clip(
+        &mut self,
+        clip: Handle<AnimationClip>,
+        reference: AdditiveReference,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        let node_index = self.add_clip(clip, weight, parent);
+        self.graph[node_index].additive = Some(reference);
+        node_index
+    }
+
     /// A convenience method to add multiple [`AnimationClip`]s to the animation
     /// graph.
     ///
@@ -239,13 +494,62 @@ impl AnimationGraph {
     /// animation evaluation, the descendants of this blend node will have their
     /// weights multiplied by the weight of the blend.
     pub fn add_blend(&mut self, weight: f32, parent: AnimationNodeIndex) -> AnimationNodeIndex {
-        let node_index = self
-            .graph
-            .add_node(AnimationGraphNode { clip: None, weight });
+        let node_index = self.graph.add_node(AnimationGraphNode {
+            clip: None,
+            weight,
+            ..AnimationGraphNode::default()
+        });
         self.graph.add_edge(parent, node_index, ());
         node_index
     }
 
+    /// Adds an additive blend node to the animation graph with the given
+    /// weight and returns its index.
+    ///
+    /// The clips that are children of this blend node are additive, relative
+    /// to the `reference` pose. Set the [`AnimationGraphNode::mask`] of the
+    /// blend node to limit the layer to some bones.
+    pub fn add_additive_blend(
+        &mut self,
+        reference: AdditiveReference,
+        weight: f32,
+        parent: AnimationNodeIndex,
+    ) -> AnimationNodeIndex {
+        let node_index = self.add_blend(weight, parent);
+        self.graph[node_index].additive = Some(reference);
+        node_index
+    }
+
+    /// The reference pose of the clip of `node`, if it or one of its ancestor
+    /// blend nodes is additive.
+    ///
+    /// The closest additive node to `node` gives the reference pose. This walks
+    /// the ancestors of `node`: the animation systems look it up in the
+    /// [`ComputedAnimationGraphs`] instead.
+    pub fn additive_reference(&self, node: AnimationNodeIndex) -> Option<&AdditiveReference> {
+        self.ancestors(node)
+            .find_map(|ancestor| self.graph[ancestor].additive.as_ref())
+    }
+
+    /// The weight of the masks of `node` and all its ancestors up to the root
+    /// for `target`.
+    ///
+    /// This walks the ancestors of `node`: the animation systems look it up in
+    /// the [`ComputedAnimationGraphs`] instead.
+    pub fn mask_weight(&self, node: AnimationNodeIndex, target: AnimationTargetId) -> f32 {
+        self.ancestors(node)
+            .map(|ancestor| self.graph[ancestor].mask.weight(target))
+            .product()
+    }
+
+    /// Iterates over `node` and its ancestors, closest first, visiting each
+    /// node once even if it can be reached through several parents.
+    fn ancestors(&self, node: AnimationNodeIndex) -> impl Iterator<Item = AnimationNodeIndex> + '_ {
+        let graph = Reversed(&self.graph);
+        let mut bfs = Bfs::new(graph, node);
+        std::iter::from_fn(move || bfs.next(graph))
+    }
+
     /// Adds an edge from the edge `from` to `to`, making `to` a child of
     /// `from`.
     ///
@@ -286,6 +590,16 @@ impl AnimationGraph {
         self.graph.node_indices()
     }
 
+    /// Returns the index of the node named `name`, if any.
+    pub fn node_by_name(&self, name: &str) -> Option<AnimationNodeIndex> {
+        self.graph.node_indices().find(|&node| {
+            self.graph[node]
+                .name
+                .as_ref()
+                .is_some_and(|node_name| node_name.as_str() == name)
+        })
+    }
+
     /// Serializes the animation graph to the given [`Write`]r in RON format.
     ///
     /// If writing to a file, it can later be loaded with the
@@ -297,6 +611,19 @@ impl AnimationGraph {
         let mut ron_serializer = ron::ser::Serializer::new(writer, None)?;
         Ok(self.serialize(&mut ron_serializer)?)
     }
+
+    /// Saves the animation graph to the file at `path`, in pretty-printed RON
+    /// format, such as from tools editing the graph.
+    ///
+    /// The clips are saved with their asset paths, so the file can be loaded
+    /// back with the [`AnimationGraphAssetLoader`].
+    pub fn save_to_path(&self, path: impl AsRef<Path>) -> Result<(), AnimationGraphLoadError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let mut ron_serializer =
+            ron::ser::Serializer::new(&mut writer, Some(PrettyConfig::default()))?;
+        self.serialize(&mut ron_serializer)?;
+        Ok(writer.flush()?)
+    }
 }
 
 impl Index<AnimationNodeIndex> for AnimationGraph {
@@ -316,8 +643,12 @@ impl IndexMut<AnimationNodeIndex> for AnimationGraph {
 impl Default for AnimationGraphNode {
     fn default() -> Self {
         Self {
+            name: None,
             clip: None,
             weight: 1.0,
+            additive: None,
+            mask: AnimationMask::default(),
+            disabled: false,
         }
     }
 }
@@ -352,16 +683,29 @@ impl AssetLoader for AnimationGraphAssetLoader {
 
         // Load all `AssetPath`s to convert from a
         // `SerializedAnimationGraph` to a real `AnimationGraph`.
+        let mut load_clip = |clip: &SerializedAnimationClip| match clip {
+            SerializedAnimationClip::AssetId(asset_id) => Handle::Weak(*asset_id),
+            SerializedAnimationClip::AssetPath(asset_path) => load_context.load(asset_path),
+        };
         Ok(AnimationGraph {
             graph: serialized_animation_graph.graph.map(
                 |_, serialized_node| AnimationGraphNode {
-                    clip: serialized_node.clip.as_ref().map(|clip| match clip {
-                        SerializedAnimationClip::AssetId(asset_id) => Handle::Weak(*asset_id),
-                        SerializedAnimationClip::AssetPath(asset_path) => {
-                            load_context.load(asset_path)
-                        }
-                    }),
+                    name: serialized_node.name.clone().map(Name::new),
+                    clip: serialized_node.clip.as_ref().map(&mut load_clip),
                     weight: serialized_node.weight,
+                    additive: serialized_node
+                        .additive
+                        .as_ref()
+                        .map(|reference| match reference {
+                            SerializedAdditiveReference::FirstFrame => {
+                                AdditiveReference::FirstFrame
+                            }
+                            SerializedAdditiveReference::Clip(clip) => {
+                                AdditiveReference::Clip(load_clip(clip))
+                            }
+                        }),
+                    mask: serialized_node.mask.clone(),
+                    disabled: false,
                 },
                 |_, _| (),
             ),
@@ -382,11 +726,16 @@ impl From<AnimationGraph> for SerializedAnimationGraph {
         Self {
             graph: animation_graph.graph.map(
                 |_, node| SerializedAnimationGraphNode {
+                    name: node.name.as_ref().map(ToString::to_string),
                     weight: node.weight,
-                    clip: node.clip.as_ref().map(|clip| match clip.path() {
-                        Some(path) => SerializedAnimationClip::AssetPath(path.clone()),
-                        None => SerializedAnimationClip::AssetId(clip.id()),
+                    clip: node.clip.as_ref().map(SerializedAnimationClip::from),
+                    additive: node.additive.as_ref().map(|reference| match reference {
+                        AdditiveReference::FirstFrame => SerializedAdditiveReference::FirstFrame,
+                        AdditiveReference::Clip(clip) => {
+                            SerializedAdditiveReference::Clip(clip.into())
+                        }
                     }),
+                    mask: node.mask.clone(),
                 },
                 |_, _| (),
             ),
@@ -394,3 +743,44 @@ impl From<AnimationGraph> for SerializedAnimationGraph {
         }
     }
 }
+
+impl From<&Handle<AnimationClip>> for SerializedAnimationClip {
+    fn from(clip: &Handle<AnimationClip>) -> Self {
+        match clip.path() {
+            Some(path) => SerializedAnimationClip::AssetPath(path.clone()),
+            None => SerializedAnimationClip::AssetId(clip.id()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_and_additive_references_apply_to_all_descendants() {
+        let target = AnimationTargetId::from_name(&Name::new("spine"));
+        let mut graph = AnimationGraph::new();
+        let layer = graph.add_additive_blend(AdditiveReference::FirstFrame, 1.0, graph.root);
+        let blend = graph.add_blend(1.0, layer);
+        let clip = graph.add_clip(Handle::default(), 1.0, blend);
+        let other = graph.add_clip(Handle::default(), 1.0, graph.root);
+        for node in [graph.root, layer, blend] {
+            graph[node].mask.set_weight(target, 0.5);
+        }
+
+        // The clip is two levels below the additive layer, and three below the root
+        assert!(graph.additive_reference(clip).is_some());
+        assert!(graph.additive_reference(other).is_none());
+        assert_eq!(graph.mask_weight(clip, target), 0.125);
+        assert_eq!(graph.mask_weight(other, target), 0.5);
+
+        let computed = ComputedAnimationGraph::new(&graph);
+        assert!(computed.additive_reference(clip).is_some());
+        assert!(computed.additive_reference(other).is_none());
+        assert_eq!(computed.mask_weight(clip, target), 0.125);
+        assert_eq!(computed.mask_weight(other, target), 0.5);
+        let untouched = AnimationTargetId::from_name(&Name::new("head"));
+        assert_eq!(computed.mask_weight(clip, untouched), 1.0);
+    }
+}
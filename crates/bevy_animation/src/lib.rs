@@ -7,8 +7,15 @@
 
 //! Animation for the game engine Bevy
 
+mod additive;
 mod animatable;
+mod animated_field;
+mod events;
 mod graph;
+mod look_at;
+mod reload;
+mod root_motion;
+mod sampling;
 mod transition;
 mod util;
 
@@ -16,6 +23,7 @@ use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher};
 use std::iter;
+use std::mem;
 use std::ops::{Add, Mul};
 
 use bevy_app::{App, Plugin, PostUpdate};
@@ -24,10 +32,11 @@ use bevy_core::Name;
 use bevy_ecs::entity::MapEntities;
 use bevy_ecs::prelude::*;
 use bevy_ecs::reflect::ReflectMapEntities;
+use bevy_ecs::system::ParallelCommands;
 use bevy_math::{FloatExt, Quat, Vec3};
 use bevy_reflect::Reflect;
 use bevy_render::mesh::morph::MorphWeights;
-use bevy_time::Time;
+use bevy_time::{Clocks, Time, UseClock};
 use bevy_transform::{prelude::Transform, TransformSystem};
 use bevy_utils::hashbrown::HashMap;
 use bevy_utils::{
@@ -35,10 +44,14 @@ use bevy_utils::{
     NoOpHash,
 };
 use fixedbitset::FixedBitSet;
-use graph::{AnimationGraph, AnimationNodeIndex};
+use graph::{
+    AdditiveReference, AnimationGraph, AnimationGraphNode, AnimationNodeIndex,
+    ComputedAnimationGraphs,
+};
 use petgraph::graph::NodeIndex;
 use petgraph::Direction;
 use prelude::{AnimationGraphAssetLoader, AnimationTransitions};
+use serde::{Deserialize, Serialize};
 use thread_local::ThreadLocal;
 use uuid::Uuid;
 
@@ -46,13 +59,25 @@ use uuid::Uuid;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        animatable::*, graph::*, transition::*, AnimationClip, AnimationPlayer, AnimationPlugin,
-        Interpolation, Keyframes, VariableCurve,
+        animatable::*, graph::*, transition::*, AnimationClip, AnimationMarker, AnimationPlayer,
+        AnimationPlugin, Interpolation, Keyframes, LookAtMode, LookAtTarget, VariableCurve,
     };
 }
 
 use crate::transition::{advance_transitions, expire_completed_transitions};
 
+pub use animated_field::{
+    animate_fields, AnimatedField, AnimatedFieldError, BlendedField, FieldKeyframes,
+};
+pub use events::{AnimationEvent, AnimationEventFn, AnimationMarker};
+pub use look_at::{look_at_target_system, LookAtMode, LookAtTarget};
+pub use reload::{
+    compute_modified_animation_graphs, disable_failed_animation_clips,
+    remap_modified_animation_graphs,
+};
+pub use root_motion::{extract_root_motion, RootMotion};
+pub use sampling::{sample_pose, Pose};
+
 /// The [UUID namespace] of animation targets (e.g. bones).
 ///
 /// [UUID namespace]: https://en.wikipedia.org/wiki/Universally_unique_identifier#Versions_3_and_5_(namespace_name-based)
@@ -76,6 +101,8 @@ pub enum Keyframes {
     ///
     /// [glTF design]: https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#animations
     Weights(Vec<f32>),
+    /// Keyframes for a reflected field of any component, such as the position of a UI node.
+    Field(AnimatedField),
 }
 
 impl Keyframes {
@@ -85,6 +112,7 @@ impl Keyframes {
             Keyframes::Weights(vec) => vec.len(),
             Keyframes::Translation(vec) | Keyframes::Scale(vec) => vec.len(),
             Keyframes::Rotation(vec) => vec.len(),
+            Keyframes::Field(field) => field.keyframes().len(),
         }
     }
 
@@ -94,7 +122,8 @@ impl Keyframes {
     }
 }
 
-/// Describes how an attribute of a [`Transform`] or [`MorphWeights`] should be animated.
+/// Describes how an attribute of a [`Transform`], [`MorphWeights`] or another component should be
+/// animated.
 ///
 /// `keyframe_timestamps` and `keyframes` should have the same length.
 #[derive(Reflect, Clone, Debug)]
@@ -177,6 +206,8 @@ pub enum Interpolation {
 #[derive(Asset, Reflect, Clone, Debug, Default)]
 pub struct AnimationClip {
     curves: AnimationCurves,
+    /// The events of the clip, sorted by time.
+    events: Vec<AnimationEvent>,
     duration: f32,
 }
 
@@ -205,7 +236,7 @@ pub type AnimationCurves = HashMap<AnimationTargetId, Vec<VariableCurve>, NoOpHa
 /// connected to a bone named `Stomach`.
 ///
 /// [UUID]: https://en.wikipedia.org/wiki/Universally_unique_identifier
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect, Debug, Serialize, Deserialize)]
 pub struct AnimationTargetId(pub Uuid);
 
 impl Hash for AnimationTargetId {
@@ -308,6 +339,45 @@ impl AnimationClip {
             .max(*curve.keyframe_timestamps.last().unwrap_or(&0.0));
         self.curves.entry(target_id).or_default().push(curve);
     }
+
+    /// The [`AnimationEvent`]s of this clip, sorted by time.
+    #[inline]
+    pub fn events(&self) -> &[AnimationEvent] {
+        &self.events
+    }
+
+    /// Adds an event triggered for the observers of the [`AnimationPlayer`] entity when the
+    /// playback crosses `time`, in seconds.
+    ///
+    /// The event is triggered each time a repeating animation crosses `time`, including when
+    /// playing in reverse, and even if a frame skips over several events.
+    ///
+    /// ```
+    /// # use bevy_animation::{AnimationClip, AnimationMarker};
+    /// let mut clip = AnimationClip::default();
+    /// clip.add_event(0.25, AnimationMarker::new("footstep"));
+    /// ```
+    pub fn add_event(&mut self, time: f32, event: impl Event + Clone) {
+        self.add_event_trigger(time, AnimationEventFn::trigger(event));
+    }
+
+    /// Adds an event running `f` with the [`AnimationPlayer`] entity when the playback crosses
+    /// `time`, in seconds.
+    ///
+    /// See [`AnimationClip::add_event`].
+    pub fn add_event_fn(
+        &mut self,
+        time: f32,
+        f: impl Fn(&mut Commands, Entity) + Send + Sync + 'static,
+    ) {
+        self.add_event_trigger(time, AnimationEventFn::new(f));
+    }
+
+    /// Adds an event running `trigger` when the playback crosses `time`, in seconds.
+    pub fn add_event_trigger(&mut self, time: f32, trigger: AnimationEventFn) {
+        let index = self.events.partition_point(|event| event.time <= time);
+        self.events.insert(index, AnimationEvent { time, trigger });
+    }
 }
 
 /// Repetition behavior of an animation.
@@ -347,6 +417,12 @@ pub struct ActiveAnimation {
     /// If the animation is playing in reverse, this increments when the animation passes the start.
     completions: u32,
     paused: bool,
+    /// The seek time before the last update.
+    last_seek_time: f32,
+    /// The time traveled in the clip during the last update, unrolled over its loops.
+    last_travel: f32,
+    /// The seek time before the animation was seeked since the last update, if it was.
+    seek_origin: Option<f32>,
 }
 
 impl Default for ActiveAnimation {
@@ -360,6 +436,9 @@ impl Default for ActiveAnimation {
             seek_time: 0.0,
             completions: 0,
             paused: false,
+            last_seek_time: 0.0,
+            last_travel: 0.0,
+            seek_origin: None,
         }
     }
 }
@@ -378,32 +457,63 @@ impl ActiveAnimation {
     }
 
     /// Update the animation given the delta time and the duration of the clip being played.
+    ///
+    /// Returns how far the seek time moved, unrolled over the loops of the clip and stopping
+    /// where the animation finished.
     #[inline]
-    fn update(&mut self, delta: f32, clip_duration: f32) {
+    fn update(&mut self, delta: f32, clip_duration: f32) -> f32 {
+        self.last_seek_time = self.seek_time;
+        self.last_travel = self.advance(delta, clip_duration);
+        self.last_travel
+    }
+
+    /// Advances the seek time, returning the travel of [`Self::update`].
+    fn advance(&mut self, delta: f32, clip_duration: f32) -> f32 {
         if self.is_finished() {
-            return;
+            return 0.0;
         }
 
+        let start = self.seek_time;
+        let travel = delta * self.speed;
         self.elapsed += delta;
-        self.seek_time += delta * self.speed;
+        self.seek_time += travel;
 
-        let over_time = self.speed > 0.0 && self.seek_time >= clip_duration;
-        let under_time = self.speed < 0.0 && self.seek_time < 0.0;
-
-        if over_time || under_time {
-            self.completions += 1;
-
-            if self.is_finished() {
-                return;
+        if clip_duration <= 0.0 {
+            if travel != 0.0 {
+                self.completions += 1;
             }
+            self.seek_time = 0.0;
+            return 0.0;
         }
-        if self.seek_time >= clip_duration {
-            self.seek_time %= clip_duration;
-        }
-        // Note: assumes delta is never lower than -clip_duration
-        if self.seek_time < 0.0 {
-            self.seek_time += clip_duration;
+
+        // The number of times the animation looped, which is more than once at high speeds
+        let loops = if self.speed > 0.0 {
+            (self.seek_time / clip_duration).floor()
+        } else if self.speed < 0.0 {
+            (-self.seek_time / clip_duration).ceil()
+        } else {
+            0.0
+        } as u32;
+
+        if loops > 0 {
+            let remaining = match self.repeat {
+                RepeatAnimation::Forever => u32::MAX,
+                RepeatAnimation::Never => 1u32.saturating_sub(self.completions),
+                RepeatAnimation::Count(n) => n.saturating_sub(self.completions),
+            };
+            if loops >= remaining {
+                self.completions += remaining;
+                // Stop at the end of the last loop
+                return if self.speed > 0.0 {
+                    remaining as f32 * clip_duration - start
+                } else {
+                    -(start + (remaining - 1) as f32 * clip_duration)
+                };
+            }
+            self.completions += loops;
         }
+        self.seek_time = self.seek_time.rem_euclid(clip_duration);
+        travel
     }
 
     /// Reset back to the initial state as if no time has elapsed.
@@ -493,15 +603,18 @@ impl ActiveAnimation {
     }
 
     /// Seeks to a specific time in the animation.
+    ///
+    /// The events crossed by the seek are only triggered if the [`AnimationPlayer`] has
+    /// [`AnimationPlayer::set_fire_events_on_seek`].
     pub fn seek_to(&mut self, seek_time: f32) -> &mut Self {
+        self.seek_origin.get_or_insert(self.seek_time);
         self.seek_time = seek_time;
         self
     }
 
     /// Seeks to the beginning of the animation.
     pub fn rewind(&mut self) -> &mut Self {
-        self.seek_time = 0.0;
-        self
+        self.seek_to(0.0)
     }
 }
 
@@ -513,6 +626,8 @@ pub struct AnimationPlayer {
     /// ordering when applying the animations.
     active_animations: BTreeMap<AnimationNodeIndex, ActiveAnimation>,
     blend_weights: HashMap<AnimationNodeIndex, f32>,
+    /// Whether seeking triggers the events crossed by the seek.
+    fire_events_on_seek: bool,
 }
 
 // This is needed since `#[derive(Clone)]` does not generate optimized `clone_from`.
@@ -521,12 +636,14 @@ impl Clone for AnimationPlayer {
         Self {
             active_animations: self.active_animations.clone(),
             blend_weights: self.blend_weights.clone(),
+            fire_events_on_seek: self.fire_events_on_seek,
         }
     }
 
     fn clone_from(&mut self, source: &Self) {
         self.active_animations.clone_from(&source.active_animations);
         self.blend_weights.clone_from(&source.blend_weights);
+        self.fire_events_on_seek = source.fire_events_on_seek;
     }
 }
 
@@ -574,6 +691,48 @@ impl AnimationPlayer {
         playing_animation
     }
 
+    /// Seeks the animation `animation` to `seek_time`, if it's playing.
+    ///
+    /// The seek is honored in the next update even if the animation is paused, so that tools can
+    /// scrub through a clip without the player advancing time.
+    pub fn seek_to(&mut self, animation: AnimationNodeIndex, seek_time: f32) -> &mut Self {
+        if let Some(active_animation) = self.active_animations.get_mut(&animation) {
+            active_animation.seek_to(seek_time);
+        }
+        self
+    }
+
+    /// Whether seeking triggers the events crossed by the seek.
+    pub fn fires_events_on_seek(&self) -> bool {
+        self.fire_events_on_seek
+    }
+
+    /// Sets whether seeking triggers the events crossed by the seek, forwards or backwards, as if
+    /// the animation played through them.
+    ///
+    /// Seeking doesn't trigger events by default, so that scrubbing back and forth through a clip
+    /// doesn't trigger its events again.
+    pub fn set_fire_events_on_seek(&mut self, fire_events_on_seek: bool) -> &mut Self {
+        self.fire_events_on_seek = fire_events_on_seek;
+        self
+    }
+
+    /// Moves the animations and blend weights of the nodes to the nodes returned by `remap`,
+    /// stopping the animations of the nodes it returns `None` for.
+    pub(crate) fn remap_nodes(
+        &mut self,
+        remap: impl Fn(AnimationNodeIndex) -> Option<AnimationNodeIndex>,
+    ) {
+        self.active_animations = mem::take(&mut self.active_animations)
+            .into_iter()
+            .filter_map(|(node, active_animation)| Some((remap(node)?, active_animation)))
+            .collect();
+        self.blend_weights = mem::take(&mut self.blend_weights)
+            .into_iter()
+            .filter_map(|(node, blend_weight)| Some((remap(node)?, blend_weight)))
+            .collect();
+    }
+
     /// Stops playing the given animation, removing it from the list of playing
     /// animations.
     pub fn stop(&mut self, animation: AnimationNodeIndex) -> &mut Self {
@@ -700,19 +859,31 @@ impl AnimationPlayer {
 /// A system that advances the time for all playing animations.
 pub fn advance_animations(
     time: Res<Time>,
+    clocks: Option<Res<Clocks>>,
     animation_clips: Res<Assets<AnimationClip>>,
     animation_graphs: Res<Assets<AnimationGraph>>,
-    mut players: Query<(&mut AnimationPlayer, &Handle<AnimationGraph>)>,
+    mut players: Query<(
+        Entity,
+        &mut AnimationPlayer,
+        &Handle<AnimationGraph>,
+        Option<&UseClock>,
+    )>,
     animation_graph_evaluator: Local<ThreadLocal<RefCell<AnimationGraphEvaluator>>>,
+    par_commands: ParallelCommands,
 ) {
-    let delta_seconds = time.delta_seconds();
     players
         .par_iter_mut()
-        .for_each(|(mut player, graph_handle)| {
+        .for_each(|(entity, mut player, graph_handle, use_clock)| {
             let Some(animation_graph) = animation_graphs.get(graph_handle) else {
                 return;
             };
 
+            // Players following an auxiliary clock advance at its speed.
+            let delta_seconds = clocks.as_ref().map_or(time.delta(), |clocks| {
+                clocks.delta_for(use_clock, time.delta())
+            });
+            let delta_seconds = delta_seconds.as_secs_f32();
+
             // Tick animations, and schedule them.
             //
             // We use a thread-local here so we can reuse allocations across
@@ -722,9 +893,21 @@ pub fn advance_animations(
             let AnimationPlayer {
                 ref mut active_animations,
                 ref blend_weights,
-                ..
+                fire_events_on_seek,
             } = *player;
 
+            let trigger_crossed_events =
+                |clip: &AnimationClip, seek_time: f32, travel: f32, finished: bool| {
+                    if clip.events.is_empty() {
+                        return;
+                    }
+                    par_commands.command_scope(|mut commands| {
+                        clip.for_each_crossed_event(seek_time, travel, finished, |event| {
+                            event.trigger.run(&mut commands, entity);
+                        });
+                    });
+                };
+
             // Reset our state.
             evaluator.reset(animation_graph.root, animation_graph.graph.node_count());
 
@@ -737,22 +920,49 @@ pub fn advance_animations(
                 let node = &animation_graph[node_index];
 
                 // Calculate weight from the graph.
-                let mut weight = node.weight;
+                // Disabled nodes, and their children, have no weight.
+                let node_weight = |node: &AnimationGraphNode| {
+                    if node.disabled {
+                        0.0
+                    } else {
+                        node.weight
+                    }
+                };
+                let mut weight = node_weight(node);
                 for parent_index in animation_graph
                     .graph
                     .neighbors_directed(node_index, Direction::Incoming)
                 {
-                    weight *= animation_graph[parent_index].weight;
+                    weight *= node_weight(&animation_graph[parent_index]);
                 }
                 evaluator.weights[node_index.index()] = weight;
 
                 if let Some(active_animation) = active_animations.get_mut(&node_index) {
+                    // Paused animations don't travel, nor move their root
+                    active_animation.last_seek_time = active_animation.seek_time;
+                    active_animation.last_travel = 0.0;
+
+                    let clip = node
+                        .clip
+                        .as_ref()
+                        .and_then(|clip_handle| animation_clips.get(clip_handle));
+
+                    // A seek only triggers the events it crossed if requested, including while
+                    // paused.
+                    if let Some(seek_origin) = active_animation.seek_origin.take() {
+                        if let Some(clip) = clip.filter(|_| fire_events_on_seek) {
+                            let travel = active_animation.seek_time - seek_origin;
+                            trigger_crossed_events(clip, seek_origin, travel, false);
+                        }
+                    }
+
                     // Tick the animation if necessary.
                     if !active_animation.paused {
-                        if let Some(ref clip_handle) = node.clip {
-                            if let Some(clip) = animation_clips.get(clip_handle) {
-                                active_animation.update(delta_seconds, clip.duration);
-                            }
+                        if let Some(clip) = clip {
+                            let seek_time = active_animation.seek_time;
+                            let travel = active_animation.update(delta_seconds, clip.duration);
+                            let finished = active_animation.is_finished();
+                            trigger_crossed_events(clip, seek_time, travel, finished);
                         }
                     }
 
@@ -781,7 +991,12 @@ pub fn advance_animations(
 pub fn animate_targets(
     clips: Res<Assets<AnimationClip>>,
     graphs: Res<Assets<AnimationGraph>>,
-    players: Query<(&AnimationPlayer, &Handle<AnimationGraph>)>,
+    computed_graphs: Res<ComputedAnimationGraphs>,
+    players: Query<(
+        &AnimationPlayer,
+        &Handle<AnimationGraph>,
+        Option<&RootMotion>,
+    )>,
     mut targets: Query<(
         Entity,
         &AnimationTarget,
@@ -798,7 +1013,9 @@ pub fn animate_targets(
     targets
         .par_iter_mut()
         .for_each(|(id, target, name, (transform, morph_weights))| {
-            let Ok((animation_player, animation_graph_handle)) = players.get(target.player) else {
+            let Ok((animation_player, animation_graph_handle, root_motion)) =
+                players.get(target.player)
+            else {
                 trace!(
                     "Either an animation player {:?} or a graph was missing for the target \
                      entity {:?} ({:?}); no animations will play this frame",
@@ -813,6 +1030,8 @@ pub fn animate_targets(
             let Some(animation_graph) = graphs.get(animation_graph_handle) else {
                 return;
             };
+            let computed_graph =
+                computed_graphs.get_or_compute(animation_graph_handle.id(), animation_graph);
 
             let mut target_context = AnimationTargetContext {
                 entity: id,
@@ -822,7 +1041,8 @@ pub fn animate_targets(
                 morph_weights,
             };
 
-            // Apply the animations one after another. The way we accumulate
+            // Apply the animations one after another, except the additive
+            // ones, which are added on top below. The way we accumulate
             // weights ensures that the order we apply them in doesn't matter.
             //
             // Proof: Consider three animations A₀, A₁, A₂, … with weights w₀,
@@ -842,7 +1062,16 @@ pub fn animate_targets(
             for (&animation_graph_node_index, active_animation) in
                 animation_player.active_animations.iter()
             {
-                if active_animation.weight == 0.0 {
+                // Skipping animations without weight also avoids dividing by a
+                // total weight of zero, which would collapse the target.
+                if active_animation.computed_weight <= 0.0 {
+                    continue;
+                }
+
+                if computed_graph
+                    .additive_reference(animation_graph_node_index)
+                    .is_some()
+                {
                     continue;
                 }
 
@@ -858,11 +1087,69 @@ pub fn animate_targets(
                     continue;
                 };
 
-                let weight = active_animation.computed_weight;
+                let weight = active_animation.computed_weight
+                    * computed_graph.mask_weight(animation_graph_node_index, target.id);
+                if weight <= 0.0 {
+                    continue;
+                }
                 total_weight += weight;
 
                 target_context.apply(curves, weight / total_weight, active_animation.seek_time);
             }
+
+            // The root keeps its place while its motion moves the character
+            if let Some(root_motion) =
+                root_motion.filter(|root_motion| root_motion.root == target.id)
+            {
+                target_context.strip_root_motion(root_motion);
+            }
+
+            // Add the additive animations on top of the blended pose, as the
+            // difference between their pose and their reference pose.
+            for (&animation_graph_node_index, active_animation) in
+                animation_player.active_animations.iter()
+            {
+                let Some(reference) = computed_graph.additive_reference(animation_graph_node_index)
+                else {
+                    continue;
+                };
+
+                let weight = active_animation.computed_weight
+                    * computed_graph.mask_weight(animation_graph_node_index, target.id);
+                if weight <= 0.0 {
+                    continue;
+                }
+
+                let Some(curves) = animation_graph
+                    .get(animation_graph_node_index)
+                    .and_then(|animation_graph_node| animation_graph_node.clip.as_ref())
+                    .and_then(|animation_clip_handle| clips.get(animation_clip_handle))
+                    .and_then(|clip| clip.curves_for_target(target.id))
+                else {
+                    continue;
+                };
+
+                let reference_curves = match reference {
+                    AdditiveReference::FirstFrame => None,
+                    AdditiveReference::Clip(reference_clip_handle) => {
+                        // The reference clip might not have loaded yet
+                        let Some(reference_curves) = clips
+                            .get(reference_clip_handle)
+                            .and_then(|reference_clip| reference_clip.curves_for_target(target.id))
+                        else {
+                            continue;
+                        };
+                        Some(reference_curves)
+                    }
+                };
+
+                target_context.apply_additive(
+                    curves,
+                    reference_curves,
+                    weight,
+                    active_animation.seek_time,
+                );
+            }
         });
 }
 
@@ -933,6 +1220,9 @@ impl AnimationTargetContext<'_> {
                     weight,
                 );
             }
+
+            // Applied by `animate_fields`
+            Keyframes::Field(_) => {}
         }
     }
 
@@ -1116,6 +1406,9 @@ impl AnimationTargetContext<'_> {
                     );
                 lerp_morph_weights(morphs.weights_mut(), result, weight);
             }
+
+            // Applied by `animate_fields`
+            (_, Keyframes::Field(_)) => {}
         }
     }
 }
@@ -1168,6 +1461,10 @@ pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
+        // Animated UI nodes are laid out in the same frame
+        #[cfg(feature = "bevy_ui")]
+        let animate_fields = animate_fields.before(bevy_ui::UiSystem::Layout);
+
         app.init_asset::<AnimationClip>()
             .init_asset::<AnimationGraph>()
             .init_asset_loader::<AnimationGraphAssetLoader>()
@@ -1175,15 +1472,27 @@ impl Plugin for AnimationPlugin {
             .register_asset_reflect::<AnimationGraph>()
             .register_type::<AnimationPlayer>()
             .register_type::<AnimationTarget>()
+            .register_type::<AnimationMarker>()
+            .register_type::<RootMotion>()
             .register_type::<AnimationTransitions>()
             .register_type::<NodeIndex>()
+            .register_type::<LookAtTarget>()
+            .init_resource::<ComputedAnimationGraphs>()
+            .register_type::<LookAtMode>()
             .add_systems(
                 PostUpdate,
                 (
+                    disable_failed_animation_clips,
+                    remap_modified_animation_graphs,
+                    compute_modified_animation_graphs,
                     advance_transitions,
                     advance_animations,
+                    extract_root_motion,
                     animate_targets,
+                    animate_fields,
                     expire_completed_transitions,
+                    // Constraints override the animated rotations
+                    look_at_target_system,
                 )
                     .chain()
                     .before(TransformSystem::TransformPropagate),
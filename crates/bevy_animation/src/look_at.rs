@@ -0,0 +1,256 @@
+//! Constraints rotating entities to face other entities, such as turrets or eyes.
+
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::Parent;
+use bevy_math::{Quat, Vec3};
+use bevy_reflect::prelude::*;
+use bevy_time::Time;
+use bevy_transform::{helper::TransformHelper, prelude::Transform};
+use bevy_utils::warn_once;
+
+/// Rotates the [`Transform`] of an entity every frame so that it faces the `target` entity.
+///
+/// The rotation is updated in [`PostUpdate`](bevy_app::PostUpdate), before the transforms are
+/// propagated, from the up-to-date global transforms of the entity and its target. The forward
+/// direction of the entity, [`Transform::forward`], is the one pointed at the target.
+///
+/// If the target is despawned or has no [`Transform`], the constraint is ignored and a warning is
+/// logged once.
+///
+/// ```
+/// # use bevy_animation::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// fn aim_turret(mut commands: Commands, turret: Entity, player: Entity) {
+///     commands.entity(turret).insert(
+///         LookAtTarget::new(player)
+///             .with_mode(LookAtMode::YawOnly)
+///             .with_max_degrees_per_second(90.0),
+///     );
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct LookAtTarget {
+    /// The entity to face.
+    pub target: Entity,
+    /// How the entity is rotated to face its target.
+    pub mode: LookAtMode,
+    /// The maximum angle the entity rotates by per second, in degrees.
+    ///
+    /// The rotation is advanced by the delta of [`Time`]. If `None`, the entity snaps to face its
+    /// target every frame.
+    pub max_degrees_per_second: Option<f32>,
+}
+
+impl LookAtTarget {
+    /// Creates a [`LookAtTarget`] facing `target` with [`LookAtMode::Full`], without smoothing.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            mode: LookAtMode::Full,
+            max_degrees_per_second: None,
+        }
+    }
+
+    /// Returns this [`LookAtTarget`] with a new [`LookAtMode`].
+    pub fn with_mode(mut self, mode: LookAtMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns this [`LookAtTarget`] rotating by at most `degrees` per second.
+    pub fn with_max_degrees_per_second(mut self, degrees: f32) -> Self {
+        self.max_degrees_per_second = Some(degrees);
+        self
+    }
+}
+
+/// How a [`LookAtTarget`] rotates its entity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum LookAtMode {
+    /// Points the forward direction at the target, keeping the up direction of the parent as
+    /// close to up as possible.
+    #[default]
+    Full,
+    /// Only rotates around the up axis of the parent, like a turret or a character turning
+    /// toward the target.
+    YawOnly,
+}
+
+/// Rotates the entities with a [`LookAtTarget`] toward their targets.
+///
+/// This runs in [`PostUpdate`](bevy_app::PostUpdate), after the animations are applied and before
+/// [`TransformPropagate`](bevy_transform::TransformSystem::TransformPropagate).
+pub fn look_at_target_system(
+    mut query: ParamSet<(
+        (
+            Query<(Entity, &LookAtTarget, Option<&Parent>)>,
+            TransformHelper,
+        ),
+        Query<&mut Transform, With<LookAtTarget>>,
+    )>,
+    time: Res<Time>,
+    mut rotations: Local<Vec<(Entity, Quat, Option<f32>)>>,
+) {
+    rotations.clear();
+    let (targets, helper) = query.p0();
+    for (entity, look_at, parent) in &targets {
+        let (Ok(global), Ok(target_global)) = (
+            helper.compute_global_transform(entity),
+            helper.compute_global_transform(look_at.target),
+        ) else {
+            warn_once!(
+                "The target {:?} of the `LookAtTarget` of {entity:?} doesn't exist or has no `Transform`, \
+                the constraint is ignored.",
+                look_at.target
+            );
+            continue;
+        };
+        // Work in the space of the parent, where the `Transform` is expressed
+        let parent_global =
+            parent.and_then(|parent| helper.compute_global_transform(parent.get()).ok());
+        let to_parent = |point: Vec3| {
+            parent_global.map_or(point, |parent| {
+                parent.affine().inverse().transform_point3(point)
+            })
+        };
+        let position = to_parent(global.translation());
+        let target = to_parent(target_global.translation());
+
+        let rotation = match look_at.mode {
+            LookAtMode::Full => {
+                if position.abs_diff_eq(target, f32::EPSILON) {
+                    continue;
+                }
+                Transform::from_translation(position)
+                    .looking_at(target, Vec3::Y)
+                    .rotation
+            }
+            LookAtMode::YawOnly => {
+                let direction = target - position;
+                if direction.x.abs() < f32::EPSILON && direction.z.abs() < f32::EPSILON {
+                    continue;
+                }
+                // The forward direction is -Z
+                Quat::from_rotation_y(f32::atan2(-direction.x, -direction.z))
+            }
+        };
+        let max_angle = look_at
+            .max_degrees_per_second
+            .map(|degrees| degrees.to_radians() * time.delta_seconds());
+        rotations.push((entity, rotation, max_angle));
+    }
+
+    let mut transforms = query.p1();
+    for &(entity, rotation, max_angle) in rotations.iter() {
+        let Ok(mut transform) = transforms.get_mut(entity) else {
+            continue;
+        };
+        transform.rotation = match max_angle {
+            Some(max_angle) => rotate_towards(transform.rotation, rotation, max_angle),
+            None => rotation,
+        };
+    }
+}
+
+/// Rotates `from` toward `to` by at most `max_angle` radians.
+fn rotate_towards(from: Quat, to: Quat, max_angle: f32) -> Quat {
+    let angle = from.angle_between(to);
+    if angle <= max_angle {
+        to
+    } else {
+        from.slerp(to, max_angle / angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::{App, PostUpdate};
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_time::{TimePlugin, TimeUpdateStrategy};
+    use bevy_transform::{
+        bundles::TransformBundle, components::GlobalTransform, TransformPlugin, TransformSystem,
+    };
+    use bevy_utils::Duration;
+
+    fn look_at_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((TransformPlugin, TimePlugin)).add_systems(
+            PostUpdate,
+            look_at_target_system.before(TransformSystem::TransformPropagate),
+        );
+        app
+    }
+
+    fn forward(app: &App, entity: Entity) -> Vec3 {
+        *app.world()
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .forward()
+    }
+
+    #[test]
+    fn look_at_modes() {
+        let mut app = look_at_app();
+        let parent = app
+            .world_mut()
+            .spawn(TransformBundle::from(Transform::from_rotation(
+                Quat::from_rotation_y(1.0),
+            )))
+            .id();
+        let target = app
+            .world_mut()
+            .spawn(TransformBundle::from(Transform::from_xyz(10.0, 10.0, 0.0)))
+            .id();
+        let full = app
+            .world_mut()
+            .spawn((TransformBundle::default(), LookAtTarget::new(target)))
+            .set_parent(parent)
+            .id();
+        let yaw = app
+            .world_mut()
+            .spawn((
+                TransformBundle::default(),
+                LookAtTarget::new(target).with_mode(LookAtMode::YawOnly),
+            ))
+            .id();
+        app.update();
+
+        assert!(forward(&app, full).abs_diff_eq(Vec3::new(1.0, 1.0, 0.0).normalize(), 1e-4));
+        assert!(forward(&app, yaw).abs_diff_eq(Vec3::X, 1e-4));
+
+        // Despawned targets are ignored
+        app.world_mut().despawn(target);
+        app.update();
+        assert!(forward(&app, yaw).abs_diff_eq(Vec3::X, 1e-4));
+    }
+
+    #[test]
+    fn look_at_smoothing() {
+        let mut app = look_at_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let target = app
+            .world_mut()
+            .spawn(TransformBundle::from(Transform::from_xyz(10.0, 0.0, 0.0)))
+            .id();
+        let entity = app
+            .world_mut()
+            .spawn((
+                TransformBundle::default(),
+                LookAtTarget::new(target).with_max_degrees_per_second(450.0),
+            ))
+            .id();
+
+        // The first update only starts the clock, then each one rotates by 45 degrees
+        app.update();
+        app.update();
+        let angle = forward(&app, entity).angle_between(Vec3::NEG_Z);
+        assert!((angle.to_degrees() - 45.0).abs() < 1e-3);
+        app.update();
+        assert!(forward(&app, entity).abs_diff_eq(Vec3::X, 1e-4));
+    }
+}
@@ -0,0 +1,216 @@
+//! Keeping the animations playing when their graphs are modified, such as when
+//! they're hot-reloaded, or when their clips fail to load.
+
+use bevy_asset::{AssetEvent, AssetId, AssetLoadFailedEvent, Assets, Handle};
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_utils::{tracing::warn, HashMap};
+
+use crate::graph::{
+    AnimationGraph, AnimationNodeIndex, ComputedAnimationGraph, ComputedAnimationGraphs,
+};
+use crate::prelude::AnimationTransitions;
+use crate::{AnimationClip, AnimationPlayer};
+
+/// The names of the nodes of a graph, by index.
+type NodeNames = Vec<Option<Name>>;
+
+fn node_names(graph: &AnimationGraph) -> NodeNames {
+    graph
+        .graph
+        .node_weights()
+        .map(|node| node.name.clone())
+        .collect()
+}
+
+/// The node of `new_names` matching the node `node` of `old_names`: the node
+/// with the same name, or the node with the same index if both are unnamed.
+fn remap_node(
+    old_names: &NodeNames,
+    new_names: &NodeNames,
+    node: AnimationNodeIndex,
+) -> Option<AnimationNodeIndex> {
+    match old_names.get(node.index())? {
+        Some(name) => new_names
+            .iter()
+            .position(|new_name| new_name.as_ref() == Some(name))
+            .map(AnimationNodeIndex::new),
+        None => new_names.get(node.index())?.is_none().then_some(node),
+    }
+}
+
+/// A system that keeps the [`AnimationPlayer`]s and [`AnimationTransitions`]
+/// playing the same nodes of their [`AnimationGraph`]s when the graphs are
+/// modified, such as when they're hot-reloaded.
+///
+/// The nodes are matched by their [`name`](crate::graph::AnimationGraphNode::name),
+/// so the animations keep their times and weights even if the indices of their
+/// nodes changed. The animations of the nodes that were removed are stopped.
+pub fn remap_modified_animation_graphs(
+    mut events: EventReader<AssetEvent<AnimationGraph>>,
+    graphs: Res<Assets<AnimationGraph>>,
+    mut graph_node_names: Local<HashMap<AssetId<AnimationGraph>, NodeNames>>,
+    mut players: Query<(
+        &mut AnimationPlayer,
+        &Handle<AnimationGraph>,
+        Option<&mut AnimationTransitions>,
+    )>,
+) {
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                let Some(graph) = graphs.get(id) else {
+                    continue;
+                };
+                let new_names = node_names(graph);
+                let Some(old_names) = graph_node_names.get(&id) else {
+                    graph_node_names.insert(id, new_names);
+                    continue;
+                };
+                if *old_names == new_names {
+                    continue;
+                }
+
+                let remap = |node| remap_node(old_names, &new_names, node);
+                for (mut player, graph_handle, transitions) in &mut players {
+                    if graph_handle.id() != id {
+                        continue;
+                    }
+                    player.remap_nodes(remap);
+                    if let Some(mut transitions) = transitions {
+                        transitions.remap_nodes(remap);
+                    }
+                }
+                graph_node_names.insert(id, new_names);
+            }
+            AssetEvent::Removed { id } => {
+                graph_node_names.remove(&id);
+            }
+            AssetEvent::LoadedWithDependencies { .. } | AssetEvent::Unused { .. } => {}
+        }
+    }
+}
+
+/// A system that updates the [`ComputedAnimationGraphs`] of the
+/// [`AnimationGraph`]s that were added or modified.
+pub fn compute_modified_animation_graphs(
+    mut events: EventReader<AssetEvent<AnimationGraph>>,
+    graphs: Res<Assets<AnimationGraph>>,
+    mut computed_graphs: ResMut<ComputedAnimationGraphs>,
+) {
+    for event in events.read() {
+        match *event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                if let Some(graph) = graphs.get(id) {
+                    computed_graphs
+                        .0
+                        .insert(id, ComputedAnimationGraph::new(graph));
+                }
+            }
+            AssetEvent::Removed { id } => {
+                computed_graphs.0.remove(&id);
+            }
+            AssetEvent::LoadedWithDependencies { .. } | AssetEvent::Unused { .. } => {}
+        }
+    }
+}
+
+/// A system that disables the nodes of the [`AnimationGraph`]s whose clip
+/// failed to load, with a warning, so that the rest of their graph keeps
+/// playing.
+///
+/// See [`AnimationGraphNode::disabled`](crate::graph::AnimationGraphNode::disabled).
+pub fn disable_failed_animation_clips(
+    mut events: EventReader<AssetLoadFailedEvent<AnimationClip>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    for event in events.read() {
+        // Only mark the graphs using the clip as modified
+        let graph_ids: Vec<_> = graphs
+            .iter()
+            .filter(|(_, graph)| {
+                graph
+                    .graph
+                    .node_weights()
+                    .any(|node| node.clip.as_ref().is_some_and(|clip| clip.id() == event.id))
+            })
+            .map(|(id, _)| id)
+            .collect();
+
+        for graph_id in graph_ids {
+            let Some(graph) = graphs.get_mut(graph_id) else {
+                continue;
+            };
+            for node_index in graph.graph.node_indices() {
+                let node = &mut graph.graph[node_index];
+                if node.disabled || node.clip.as_ref().map(Handle::id) != Some(event.id) {
+                    continue;
+                }
+                warn!(
+                    "Disabling the node {} of the animation graph {graph_id:?}: its clip {} failed to load: {}",
+                    node.name
+                        .as_ref()
+                        .map_or_else(|| node_index.index().to_string(), ToString::to_string),
+                    event.path,
+                    event.error,
+                );
+                node.disabled = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+
+    use crate::{
+        graph::AnimationGraph, remap_modified_animation_graphs, AnimationClip, AnimationPlayer,
+    };
+
+    #[test]
+    fn reloaded_graph_keeps_playing_named_nodes() {
+        let mut clips = Assets::<AnimationClip>::default();
+        let walk = clips.add(AnimationClip::default());
+        let run = clips.add(AnimationClip::default());
+
+        let mut graph = AnimationGraph::new();
+        let walk_node = graph.add_clip(walk.clone(), 1.0, graph.root);
+        graph[walk_node].name = Some("walk".into());
+        let run_node = graph.add_clip(run.clone(), 1.0, graph.root);
+        graph[run_node].name = Some("run".into());
+
+        let mut world = World::new();
+        world.init_resource::<Events<AssetEvent<AnimationGraph>>>();
+        world.insert_resource(Assets::<AnimationGraph>::default());
+        let graph_handle = world.resource_mut::<Assets<AnimationGraph>>().add(graph);
+        let mut player = AnimationPlayer::default();
+        player.play(run_node).seek_to(0.5).set_weight(0.25);
+        let player = world.spawn((player, graph_handle.clone())).id();
+        // Registered once, so that it remembers the names of the nodes across runs
+        let remap_system = world.register_system(remap_modified_animation_graphs);
+        let update = |world: &mut World| {
+            world.run_system_once(Assets::<AnimationGraph>::asset_events);
+            world.run_system(remap_system).unwrap();
+        };
+        update(&mut world);
+
+        // The reloaded graph has a new blend node, which shifts the indices
+        let mut reloaded = AnimationGraph::new();
+        let blend = reloaded.add_blend(0.5, reloaded.root);
+        let run_node = reloaded.add_clip(run, 1.0, blend);
+        reloaded[run_node].name = Some("run".into());
+        reloaded.add_clip(walk, 1.0, blend);
+        world
+            .resource_mut::<Assets<AnimationGraph>>()
+            .insert(&graph_handle, reloaded);
+        update(&mut world);
+
+        let player = world.get::<AnimationPlayer>(player).unwrap();
+        let run = player.animation(run_node).unwrap();
+        assert_eq!(run.seek_time(), 0.5);
+        assert_eq!(run.weight(), 0.25);
+        assert_eq!(player.playing_animations().count(), 1);
+    }
+}
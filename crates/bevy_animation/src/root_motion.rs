@@ -0,0 +1,300 @@
+//! Root motion, which moves a character with the motion of the root bone of its
+//! animations rather than moving its skeleton away from it.
+
+use std::f32::consts::{PI, TAU};
+
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::prelude::*;
+use bevy_math::{Quat, Vec3};
+use bevy_reflect::Reflect;
+
+use crate::graph::{AnimationGraph, ComputedAnimationGraphs};
+use crate::sampling::CurveSample;
+use crate::{
+    AnimationClip, AnimationPlayer, AnimationTargetContext, AnimationTargetId, VariableCurve,
+};
+
+/// Extracts the motion of the root bone from the animations of an
+/// [`AnimationPlayer`], so that movement systems can move the character with
+/// it.
+///
+/// Add it to the entity of the [`AnimationPlayer`]. The horizontal translation
+/// of the [`RootMotion::root`] target, and its rotation around the Y axis with
+/// [`RootMotion::extract_yaw`], are stripped from the animated pose: the
+/// skeleton stays in place while [`RootMotion::translation`] and
+/// [`RootMotion::yaw`] hold how far the root moved in the last update.
+///
+/// The motion is continuous across the loops of the clips, and the motion of
+/// blended clips is blended with their weights, like their poses.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_animation::RootMotion;
+/// # use bevy_transform::prelude::Transform;
+/// fn move_characters(mut characters: Query<(&RootMotion, &mut Transform)>) {
+///     for (root_motion, mut transform) in &mut characters {
+///         let translation = transform.rotation * root_motion.translation;
+///         transform.translation += translation;
+///         transform.rotate_y(root_motion.yaw);
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct RootMotion {
+    /// The root bone, whose motion is extracted.
+    pub root: AnimationTargetId,
+    /// Whether the rotation of the root around the Y axis is extracted too.
+    pub extract_yaw: bool,
+    /// The horizontal translation of the root during the last update, in the
+    /// space of its parent.
+    pub translation: Vec3,
+    /// The rotation of the root around the Y axis during the last update, in
+    /// radians.
+    pub yaw: f32,
+    /// The horizontal translation of the root from the start of the clips,
+    /// stripped from the pose.
+    offset: Vec3,
+    /// The rotation of the root around the Y axis from the start of the clips,
+    /// stripped from the pose.
+    yaw_offset: f32,
+}
+
+impl RootMotion {
+    /// Extracts the horizontal translation of `root`.
+    pub fn new(root: AnimationTargetId) -> Self {
+        Self {
+            root,
+            extract_yaw: false,
+            translation: Vec3::ZERO,
+            yaw: 0.0,
+            offset: Vec3::ZERO,
+            yaw_offset: 0.0,
+        }
+    }
+
+    /// Extracts the rotation of the root around the Y axis too.
+    pub fn with_yaw(mut self) -> Self {
+        self.extract_yaw = true;
+        self
+    }
+}
+
+/// The horizontal translation and the yaw of a root bone, relative to its
+/// first keyframe.
+#[derive(Clone, Copy, Default)]
+struct RootPose {
+    translation: Vec3,
+    yaw: f32,
+}
+
+impl RootPose {
+    /// The pose of the root animated by `curves` at `time`, clamped to the
+    /// keyframes of the curves.
+    fn sample(curves: &[VariableCurve], time: f32) -> Self {
+        let mut pose = Self::default();
+        for curve in curves {
+            let sample = |time| curve.sample(curve.clamped_position(time), 0);
+            match (sample(0.0), sample(time)) {
+                (Some(CurveSample::Translation(start)), Some(CurveSample::Translation(value))) => {
+                    pose.translation = (value - start) * Vec3::new(1.0, 0.0, 1.0);
+                }
+                (Some(CurveSample::Rotation(start)), Some(CurveSample::Rotation(value))) => {
+                    pose.yaw = wrap_angle(yaw(value) - yaw(start));
+                }
+                _ => {}
+            }
+        }
+        pose
+    }
+
+    /// The pose of the root animated by `curves` at `time`, unrolled over the
+    /// loops of a clip lasting `duration`.
+    ///
+    /// Each loop starts where the previous one ended, so that the root keeps
+    /// moving forward across the loop seam.
+    fn sample_unrolled(curves: &[VariableCurve], time: f32, duration: f32) -> Self {
+        let lap = (time / duration).floor();
+        let end = Self::sample(curves, duration);
+        let pose = Self::sample(curves, time - lap * duration);
+        Self {
+            translation: pose.translation + end.translation * lap,
+            yaw: pose.yaw + end.yaw * lap,
+        }
+    }
+}
+
+/// The rotation of `rotation` around the Y axis, in radians.
+fn yaw(rotation: Quat) -> f32 {
+    // The twist of the rotation around the Y axis
+    2.0 * rotation.y.atan2(rotation.w)
+}
+
+/// Wraps `angle` to `[-PI, PI)`.
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+/// A system that extracts the [`RootMotion`] of the animations of the
+/// [`AnimationPlayer`]s, after they advanced.
+pub fn extract_root_motion(
+    clips: Res<Assets<AnimationClip>>,
+    graphs: Res<Assets<AnimationGraph>>,
+    computed_graphs: Res<ComputedAnimationGraphs>,
+    mut players: Query<(&AnimationPlayer, &Handle<AnimationGraph>, &mut RootMotion)>,
+) {
+    for (player, graph_handle, mut root_motion) in &mut players {
+        let Some(graph) = graphs.get(graph_handle) else {
+            continue;
+        };
+        let computed_graph = computed_graphs.get_or_compute(graph_handle.id(), graph);
+
+        // The motions of the clips are blended like their poses, with the
+        // average of their motions weighted by their weights
+        let mut total_weight = 0.0;
+        let mut offset = RootPose::default();
+        let mut delta = RootPose::default();
+        for (&node_index, active_animation) in player.active_animations.iter() {
+            // Additive layers, such as aim offsets, don't move the root
+            if computed_graph.additive_reference(node_index).is_some() {
+                continue;
+            }
+            let weight = active_animation.computed_weight
+                * computed_graph.mask_weight(node_index, root_motion.root);
+            if weight <= 0.0 {
+                continue;
+            }
+            let Some(clip) = graph
+                .get(node_index)
+                .and_then(|node| node.clip.as_ref())
+                .and_then(|clip_handle| clips.get(clip_handle))
+            else {
+                continue;
+            };
+            let Some(curves) = clip.curves_for_target(root_motion.root) else {
+                continue;
+            };
+            if clip.duration() <= 0.0 {
+                continue;
+            }
+
+            let pose = RootPose::sample(curves, active_animation.seek_time);
+            let start_time = active_animation.last_seek_time;
+            let end_time = start_time + active_animation.last_travel;
+            let start = RootPose::sample_unrolled(curves, start_time, clip.duration());
+            let end = RootPose::sample_unrolled(curves, end_time, clip.duration());
+
+            total_weight += weight;
+            offset.translation += pose.translation * weight;
+            offset.yaw += pose.yaw * weight;
+            delta.translation += (end.translation - start.translation) * weight;
+            delta.yaw += (end.yaw - start.yaw) * weight;
+        }
+
+        let inverse_weight = if total_weight > 0.0 {
+            total_weight.recip()
+        } else {
+            0.0
+        };
+        let root_motion = &mut *root_motion;
+        root_motion.translation = delta.translation * inverse_weight;
+        root_motion.offset = offset.translation * inverse_weight;
+        (root_motion.yaw, root_motion.yaw_offset) = if root_motion.extract_yaw {
+            (delta.yaw * inverse_weight, offset.yaw * inverse_weight)
+        } else {
+            (0.0, 0.0)
+        };
+    }
+}
+
+impl AnimationTargetContext<'_> {
+    /// Strips the motion extracted by `root_motion` from the pose of the root.
+    pub(crate) fn strip_root_motion(&mut self, root_motion: &RootMotion) {
+        if let Some(ref mut transform) = self.transform {
+            transform.translation -= root_motion.offset;
+            if root_motion.yaw_offset != 0.0 {
+                transform.rotation =
+                    Quat::from_rotation_y(-root_motion.yaw_offset) * transform.rotation;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_math::Vec3;
+    use bevy_transform::components::Transform;
+
+    use crate::{
+        animate_targets, extract_root_motion, graph::ComputedAnimationGraphs, AnimationClip,
+        AnimationGraph, AnimationPlayer, AnimationTarget, AnimationTargetId, Interpolation,
+        Keyframes, RootMotion, VariableCurve,
+    };
+
+    /// A clip lasting a second, moving the root along `direction` and bobbing
+    /// it up and down.
+    fn walk_clip(root: AnimationTargetId, direction: Vec3) -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_target(
+            root,
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 0.5, 1.0],
+                keyframes: Keyframes::Translation(vec![
+                    Vec3::ZERO,
+                    direction + Vec3::Y,
+                    direction * 2.0,
+                ]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        clip
+    }
+
+    #[test]
+    fn root_motion_is_continuous_across_loops() {
+        let root = AnimationTargetId::from_name(&"Hips".into());
+        let mut clips = Assets::<AnimationClip>::default();
+        let mut graph = AnimationGraph::new();
+        let walk = graph.add_clip(clips.add(walk_clip(root, Vec3::X)), 1.0, graph.root);
+        let strafe = graph.add_clip(clips.add(walk_clip(root, Vec3::Z)), 1.0, graph.root);
+        let mut graphs = Assets::<AnimationGraph>::default();
+        let graph = graphs.add(graph);
+
+        let mut world = World::new();
+        world.insert_resource(clips);
+        world.insert_resource(graphs);
+        world.init_resource::<ComputedAnimationGraphs>();
+
+        // Crossing the loop seam
+        let mut player = AnimationPlayer::default();
+        player.play(walk).repeat().seek_to(0.75).update(0.5, 1.0);
+        let player = world.spawn((player, graph, RootMotion::new(root))).id();
+        let hips = world
+            .spawn((Transform::default(), AnimationTarget { id: root, player }))
+            .id();
+
+        world.run_system_once(extract_root_motion);
+        world.run_system_once(animate_targets);
+        let root_motion = world.get::<RootMotion>(player).unwrap();
+        assert!(root_motion
+            .translation
+            .abs_diff_eq(Vec3::new(1.0, 0.0, 0.0), 1e-5));
+        // The skeleton stays in place, but keeps bobbing
+        let hips_translation = world.get::<Transform>(hips).unwrap().translation;
+        assert!(hips_translation.abs_diff_eq(Vec3::new(0.0, 0.5, 0.0), 1e-5));
+
+        // Blending with a clip three times heavier
+        let mut player_state = world.get_mut::<AnimationPlayer>(player).unwrap();
+        player_state.play(walk).seek_to(0.0).update(0.25, 1.0);
+        let strafe_animation = player_state.play(strafe).seek_to(0.0);
+        strafe_animation.update(0.25, 1.0);
+        strafe_animation.computed_weight = 3.0;
+        world.run_system_once(extract_root_motion);
+        let root_motion = world.get::<RootMotion>(player).unwrap();
+        assert!(root_motion
+            .translation
+            .abs_diff_eq(Vec3::new(0.125, 0.0, 0.375), 1e-5));
+    }
+}
@@ -0,0 +1,296 @@
+//! Sampling of the values of [`VariableCurve`]s.
+
+use std::ops::{Add, Mul};
+
+use bevy_math::{FloatExt, Quat, Vec3};
+use bevy_transform::components::Transform;
+
+use crate::{
+    cubic_spline_interpolation, AnimationClip, AnimationTargetId, Interpolation, Keyframes,
+    VariableCurve,
+};
+
+/// The pose of an animation target sampled by [`sample_pose`].
+///
+/// The properties that the clip doesn't animate are `None`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Pose {
+    /// The translation of the target.
+    pub translation: Option<Vec3>,
+    /// The rotation of the target.
+    pub rotation: Option<Quat>,
+    /// The scale of the target.
+    pub scale: Option<Vec3>,
+    /// The weights of the morph targets of the target.
+    pub morph_weights: Option<Vec<f32>>,
+}
+
+impl Pose {
+    /// Sets the properties of `transform` animated by the pose.
+    pub fn apply_to(&self, transform: &mut Transform) {
+        if let Some(translation) = self.translation {
+            transform.translation = translation;
+        }
+        if let Some(rotation) = self.rotation {
+            transform.rotation = rotation;
+        }
+        if let Some(scale) = self.scale {
+            transform.scale = scale;
+        }
+    }
+}
+
+/// Samples the pose of each target of `clip` at `time`, in seconds, and calls `f` with it.
+///
+/// This evaluates the curves of the clip without an [`AnimationPlayer`](crate::AnimationPlayer),
+/// for tools and external consumers such as an inverse kinematics pre-pass or the rendering of
+/// thumbnails. The curves hold their first keyframe before they start, and their last one after
+/// they end. The curves of fields are skipped.
+///
+/// ```
+/// # use bevy_animation::{sample_pose, AnimationClip};
+/// # use bevy_transform::components::Transform;
+/// # let clip = AnimationClip::default();
+/// sample_pose(&clip, 0.5, &mut |target, pose| {
+///     let mut transform = Transform::default();
+///     pose.apply_to(&mut transform);
+/// });
+/// ```
+pub fn sample_pose(clip: &AnimationClip, time: f32, f: &mut impl FnMut(AnimationTargetId, &Pose)) {
+    for (&target, curves) in clip.curves() {
+        let mut pose = Pose::default();
+        for curve in curves {
+            if curve.keyframe_timestamps.is_empty() {
+                continue;
+            }
+            let position = curve.clamped_position(time);
+            match curve.sample(position, curve.morph_target_count()) {
+                Some(CurveSample::Translation(translation)) => pose.translation = Some(translation),
+                Some(CurveSample::Rotation(rotation)) => pose.rotation = Some(rotation),
+                Some(CurveSample::Scale(scale)) => pose.scale = Some(scale),
+                Some(CurveSample::Weights(weights)) => pose.morph_weights = Some(weights),
+                None => {}
+            }
+        }
+        f(target, &pose);
+    }
+}
+
+/// A value of a [`VariableCurve`] at some time.
+pub(crate) enum CurveSample {
+    Translation(Vec3),
+    Rotation(Quat),
+    Scale(Vec3),
+    Weights(Vec<f32>),
+}
+
+/// Where a [`VariableCurve`] is sampled: between the keyframe `step_start` and
+/// the next one, `lerp` of the way through a step lasting `duration` seconds.
+#[derive(Clone, Copy)]
+pub(crate) struct CurvePosition {
+    step_start: usize,
+    lerp: f32,
+    duration: f32,
+}
+
+impl CurvePosition {
+    /// The first keyframe of a curve.
+    pub(crate) const FIRST: Self = Self {
+        step_start: 0,
+        lerp: 0.0,
+        duration: 0.0,
+    };
+}
+
+impl VariableCurve {
+    /// Where the curve is sampled at `seek_time`, or `None` before its first keyframe or after
+    /// its last one.
+    pub(crate) fn position(&self, seek_time: f32) -> Option<CurvePosition> {
+        if self.keyframe_timestamps.len() == 1 {
+            return Some(CurvePosition::FIRST);
+        }
+
+        let step_start = self.find_current_keyframe(seek_time)?;
+        let timestamp_start = self.keyframe_timestamps[step_start];
+        let timestamp_end = self.keyframe_timestamps[step_start + 1];
+        Some(CurvePosition {
+            step_start,
+            lerp: f32::inverse_lerp(timestamp_start, timestamp_end, seek_time),
+            duration: timestamp_end - timestamp_start,
+        })
+    }
+
+    /// Where the curve is sampled at `seek_time`, holding its first keyframe before it starts
+    /// and its last keyframe after it ends.
+    pub(crate) fn clamped_position(&self, seek_time: f32) -> CurvePosition {
+        let last_keyframe = self.keyframe_timestamps.len() - 1;
+        match self.position(seek_time) {
+            Some(position) => position,
+            None if seek_time >= self.keyframe_timestamps[last_keyframe] => CurvePosition {
+                step_start: last_keyframe,
+                ..CurvePosition::FIRST
+            },
+            None => CurvePosition::FIRST,
+        }
+    }
+
+    /// The number of morph targets animated by the curve, deduced from its keyframes.
+    fn morph_target_count(&self) -> usize {
+        let Keyframes::Weights(ref keyframes) = self.keyframes else {
+            return 0;
+        };
+        let keys_per_keyframe = match self.interpolation {
+            // The in-tangent, value and out-tangent of each keyframe
+            Interpolation::CubicSpline => 3,
+            Interpolation::Linear | Interpolation::Step => 1,
+        };
+        let key_count = self.keyframe_timestamps.len() * keys_per_keyframe;
+        if key_count == 0 {
+            0
+        } else {
+            keyframes.len() / key_count
+        }
+    }
+
+    /// Samples the curve at `position`, for a target with `morph_target_count`
+    /// morph targets.
+    ///
+    /// Returns `None` for the curves of fields, which are sampled by
+    /// [`animate_fields`](crate::animate_fields).
+    pub(crate) fn sample(
+        &self,
+        position: CurvePosition,
+        morph_target_count: usize,
+    ) -> Option<CurveSample> {
+        let interpolation = &self.interpolation;
+        match &self.keyframes {
+            Keyframes::Translation(keyframes) => Some(CurveSample::Translation(sample_keyframes(
+                |key| keyframes[key],
+                interpolation,
+                position,
+                Vec3::lerp,
+            ))),
+            Keyframes::Rotation(keyframes) => {
+                let rotation = sample_keyframes(
+                    |key| keyframes[key],
+                    interpolation,
+                    position,
+                    |start, mut end, lerp| {
+                        // Choose the smallest angle for the rotation
+                        if end.dot(start) < 0.0 {
+                            end = -end;
+                        }
+                        start.normalize().slerp(end.normalize(), lerp)
+                    },
+                );
+                Some(CurveSample::Rotation(rotation.normalize()))
+            }
+            Keyframes::Scale(keyframes) => Some(CurveSample::Scale(sample_keyframes(
+                |key| keyframes[key],
+                interpolation,
+                position,
+                Vec3::lerp,
+            ))),
+            Keyframes::Weights(keyframes) if morph_target_count > 0 => {
+                let weights = (0..morph_target_count)
+                    .map(|morph_target| {
+                        sample_keyframes(
+                            |key| keyframes[key * morph_target_count + morph_target],
+                            interpolation,
+                            position,
+                            f32::lerp,
+                        )
+                    })
+                    .collect();
+                Some(CurveSample::Weights(weights))
+            }
+            Keyframes::Weights(_) | Keyframes::Field(_) => None,
+        }
+    }
+}
+
+/// Samples the keyframes returned by `keyframe` at `position`, interpolating
+/// the linear steps with `lerp`.
+fn sample_keyframes<T>(
+    keyframe: impl Fn(usize) -> T,
+    interpolation: &Interpolation,
+    position: CurvePosition,
+    lerp: impl Fn(T, T, f32) -> T,
+) -> T
+where
+    T: Mul<f32, Output = T> + Add<Output = T>,
+{
+    let CurvePosition {
+        step_start,
+        lerp: step_lerp,
+        duration,
+    } = position;
+    match interpolation {
+        Interpolation::Step => keyframe(step_start),
+        // A single keyframe has no next one to interpolate with
+        Interpolation::Linear if step_lerp == 0.0 => keyframe(step_start),
+        Interpolation::Linear => lerp(keyframe(step_start), keyframe(step_start + 1), step_lerp),
+        Interpolation::CubicSpline if step_lerp == 0.0 => keyframe(step_start * 3 + 1),
+        Interpolation::CubicSpline => cubic_spline_interpolation(
+            keyframe(step_start * 3 + 1),
+            keyframe(step_start * 3 + 2),
+            keyframe((step_start + 1) * 3),
+            keyframe((step_start + 1) * 3 + 1),
+            step_lerp,
+            duration,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec3;
+
+    use crate::{
+        sample_pose, AnimationClip, AnimationTargetId, Interpolation, Keyframes, Pose,
+        VariableCurve,
+    };
+
+    #[test]
+    fn sample_pose_at_time() {
+        let target = AnimationTargetId::from_name(&"Arm".into());
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_target(
+            target,
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                keyframes: Keyframes::Translation(vec![Vec3::ZERO, Vec3::X * 2.0]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        clip.add_curve_to_target(
+            target,
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                // Two morph targets
+                keyframes: Keyframes::Weights(vec![0.0, 1.0, 1.0, 0.0]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+
+        let sample = |time| {
+            let mut poses = Vec::new();
+            sample_pose(&clip, time, &mut |target, pose: &Pose| {
+                poses.push((target, pose.clone()));
+            });
+            poses
+        };
+
+        let poses = sample(0.25);
+        assert_eq!(poses.len(), 1);
+        let (sampled_target, pose) = &poses[0];
+        assert_eq!(*sampled_target, target);
+        assert_eq!(pose.translation, Some(Vec3::X * 0.5));
+        assert_eq!(pose.morph_weights, Some(vec![0.25, 0.75]));
+        assert_eq!(pose.rotation, None);
+
+        // The last keyframe is held after the end of the clip
+        let (_, pose) = &sample(3.0)[0];
+        assert_eq!(pose.translation, Some(Vec3::X * 2.0));
+    }
+}
@@ -51,7 +51,8 @@ impl Clone for AnimationTransitions {
 /// An animation that is being faded out as part of a transition
 #[derive(Debug, Clone, Copy, Reflect)]
 pub struct AnimationTransition {
-    /// The current weight. Starts at 1.0 and goes to 0.0 during the fade-out.
+    /// The current weight. Starts at the weight of the animation when the transition started,
+    /// and goes to 0.0 during the fade-out.
     current_weight: f32,
     /// How much to decrease `current_weight` per second
     weight_decline_per_sec: f32,
@@ -68,7 +69,12 @@ impl AnimationTransitions {
 
     /// Plays a new animation on the given [`AnimationPlayer`], fading out any
     /// existing animations that were already playing over the
-    /// `transition_duration`.
+    /// `transition_duration`, while the new animation fades in.
+    ///
+    /// Transitions can be interrupted by playing another animation: the
+    /// animations that were fading in or out fade out from their current
+    /// weight, so the blended pose doesn't jump. Playing an animation that is
+    /// fading out fades it back in from its current weight.
     ///
     /// Pass [`Duration::ZERO`] to instantly switch to a new animation, avoiding
     /// any transition.
@@ -78,20 +84,73 @@ impl AnimationTransitions {
         new_animation: AnimationNodeIndex,
         transition_duration: Duration,
     ) -> &'p mut ActiveAnimation {
+        if transition_duration.is_zero() {
+            for transition in self.transitions.drain(..) {
+                if transition.animation != new_animation {
+                    player.stop(transition.animation);
+                }
+            }
+            if let Some(old_animation_index) = self.main_animation {
+                if old_animation_index != new_animation {
+                    player.stop(old_animation_index);
+                }
+            }
+            self.main_animation = Some(new_animation);
+            let animation = player.start(new_animation);
+            animation.weight = 1.0;
+            return animation;
+        }
+
+        // An animation fading out that is played again becomes the main animation,
+        // fading in from its current weight
+        self.transitions
+            .retain(|transition| transition.animation != new_animation);
+
         if let Some(old_animation_index) = self.main_animation.replace(new_animation) {
-            if let Some(old_animation) = player.animation_mut(old_animation_index) {
-                if !old_animation.is_paused() {
-                    self.transitions.push(AnimationTransition {
-                        current_weight: old_animation.weight,
-                        weight_decline_per_sec: 1.0 / transition_duration.as_secs_f32(),
-                        animation: old_animation_index,
-                    });
+            if old_animation_index != new_animation {
+                if let Some(old_animation) = player.animation_mut(old_animation_index) {
+                    if !old_animation.is_paused() {
+                        self.transitions.push(AnimationTransition {
+                            current_weight: old_animation.weight,
+                            weight_decline_per_sec: old_animation.weight
+                                / transition_duration.as_secs_f32(),
+                            animation: old_animation_index,
+                        });
+                    }
                 }
             }
         }
 
-        self.main_animation = Some(new_animation);
-        player.start(new_animation)
+        let fading_weight: f32 = self
+            .transitions
+            .iter()
+            .map(|transition| transition.current_weight)
+            .sum();
+        let animation = player.start(new_animation);
+        animation.weight = (1.0 - fading_weight).max(0.0);
+        animation
+    }
+
+    /// Moves the transitions of the nodes to the nodes returned by `remap`,
+    /// dropping the transitions of the nodes it returns `None` for.
+    pub(crate) fn remap_nodes(
+        &mut self,
+        remap: impl Fn(AnimationNodeIndex) -> Option<AnimationNodeIndex>,
+    ) {
+        self.main_animation = self.main_animation.and_then(&remap);
+        self.transitions
+            .retain_mut(|transition| match remap(transition.animation) {
+                Some(animation) => {
+                    transition.animation = animation;
+                    true
+                }
+                None => false,
+            });
+    }
+
+    /// Returns the animation that is playing, or fading in, if any.
+    pub fn main_animation(&self) -> Option<AnimationNodeIndex> {
+        self.main_animation
     }
 }
 
@@ -101,30 +160,36 @@ pub fn advance_transitions(
     mut query: Query<(&mut AnimationTransitions, &mut AnimationPlayer)>,
     time: Res<Time>,
 ) {
-    // We use a "greedy layer" system here. The top layer (most recent
-    // transition) gets as much as weight as it wants, and the remaining amount
-    // is divided between all the other layers, eventually culminating in the
-    // currently-playing animation receiving whatever's left. This results in a
-    // nicely normalized weight.
-    let mut remaining_weight = 1.0;
+    // Each animation fading out declines linearly from the weight it had when
+    // its transition started, and the main animation receives whatever's
+    // left. The weights always sum to 1, so the blended pose never collapses
+    // towards zero, even when transitions are interrupted.
     for (mut animation_transitions, mut player) in query.iter_mut() {
-        for transition in &mut animation_transitions.transitions.iter_mut().rev() {
+        let mut fading_weight = 0.0;
+        for transition in &mut animation_transitions.transitions {
             // Decrease weight.
             transition.current_weight = (transition.current_weight
                 - transition.weight_decline_per_sec * time.delta_seconds())
             .max(0.0);
+            fading_weight += transition.current_weight;
+        }
 
-            // Update weight.
-            let Some(ref mut animation) = player.animation_mut(transition.animation) else {
-                continue;
-            };
-            animation.weight = transition.current_weight * remaining_weight;
-            remaining_weight -= animation.weight;
+        // Normalize the weights of the fading animations if they exceed 1, such
+        // as when their weights were changed through the `AnimationPlayer`.
+        let normalization = if fading_weight > 1.0 {
+            fading_weight.recip()
+        } else {
+            1.0
+        };
+        for transition in &animation_transitions.transitions {
+            if let Some(animation) = player.animation_mut(transition.animation) {
+                animation.weight = transition.current_weight * normalization;
+            }
         }
 
         if let Some(main_animation_index) = animation_transitions.main_animation {
-            if let Some(ref mut animation) = player.animation_mut(main_animation_index) {
-                animation.weight = remaining_weight;
+            if let Some(animation) = player.animation_mut(main_animation_index) {
+                animation.weight = (1.0 - fading_weight).max(0.0);
             }
         }
     }
@@ -145,3 +210,54 @@ pub fn expire_completed_transitions(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::AnimationGraph;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+
+    fn weights(world: &World, entity: bevy_ecs::entity::Entity) -> Vec<f32> {
+        let player = world.get::<AnimationPlayer>(entity).unwrap();
+        let mut weights: Vec<_> = player
+            .playing_animations()
+            .map(|(index, animation)| (index.index(), animation.weight()))
+            .collect();
+        weights.sort_by_key(|&(index, _)| index);
+        weights.into_iter().map(|(_, weight)| weight).collect()
+    }
+
+    #[test]
+    fn interrupted_transitions_keep_weights_normalized() {
+        let mut graph = AnimationGraph::new();
+        let [a, b, c] = [(); 3].map(|_| graph.add_clip(Default::default(), 1.0, graph.root));
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(250));
+        world.insert_resource(time);
+
+        let mut player = AnimationPlayer::default();
+        let mut transitions = AnimationTransitions::new();
+        transitions.play(&mut player, a, Duration::ZERO);
+        transitions.play(&mut player, b, Duration::from_secs(1));
+        let entity = world.spawn((player, transitions)).id();
+
+        // A fades out while B fades in
+        world.run_system_once(advance_transitions);
+        assert_eq!(weights(&world, entity), [0.75, 0.25]);
+
+        // Interrupt the transition: A and B fade out from their current weights
+        let mut entity_mut = world.entity_mut(entity);
+        let mut transitions = entity_mut.take::<AnimationTransitions>().unwrap();
+        let mut player = entity_mut.get_mut::<AnimationPlayer>().unwrap();
+        transitions.play(&mut player, c, Duration::from_secs(1));
+        entity_mut.insert(transitions);
+        assert_eq!(weights(&world, entity), [0.75, 0.25, 0.0]);
+
+        world.run_system_once(advance_transitions);
+        let current = weights(&world, entity);
+        assert_eq!(current, [0.5, 0.1875, 0.3125]);
+        assert_eq!(current.iter().sum::<f32>(), 1.0);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, PluginSet, Plugins, PluginsState,
+    Shutdown, SubApp, SubApps,
 };
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
@@ -154,6 +154,10 @@ impl App {
     ///
     /// By default, *Bevy* uses the `winit` crate for window creation.
     ///
+    /// The runners of Bevy run the [`Shutdown`] schedule once before this returns, which can
+    /// change the returned [`AppExit`]. Since the runner owns the [`App`], custom runners have to
+    /// call [`App::shutdown`] themselves, see [`App::set_runner`].
+    ///
     /// # Panics
     ///
     /// Panics if not all plugins have been built.
@@ -178,6 +182,10 @@ impl App {
     /// The runner function is usually not set manually, but by Bevy integrated plugins
     /// (e.g. `WinitPlugin`).
     ///
+    /// Once it stops updating the app, the runner function is responsible for running the
+    /// [`Shutdown`] schedule by calling [`App::shutdown`] and returning its result. [`App::run`]
+    /// can't do it, since the [`App`] is moved into the runner function.
+    ///
     /// # Examples
     ///
     /// ```
@@ -188,7 +196,7 @@ impl App {
     ///         println!("In main loop");
     ///         app.update();
     ///         if let Some(exit) = app.should_exit() {
-    ///             return exit;
+    ///             return app.shutdown(exit);
     ///         }
     ///     }
     /// }
@@ -233,11 +241,14 @@ impl App {
 
     /// Runs [`Plugin::finish`] for each plugin. This is usually called by the event loop once all
     /// plugins are ready, but can be useful for situations where you want to use [`App::update`].
+    ///
+    /// The systems a plugin adds in [`Plugin::finish`] are part of its [`PluginSet`], like those
+    /// added in [`Plugin::build`].
     pub fn finish(&mut self) {
         // plugins installed to main should see all sub-apps
         let plugins = std::mem::take(&mut self.main_mut().plugin_registry);
         for plugin in &plugins {
-            plugin.finish(self);
+            self.in_plugin_set(PluginSet::of_plugin(&**plugin), |app| plugin.finish(app));
         }
         let main = self.main_mut();
         main.plugin_registry = plugins;
@@ -251,7 +262,7 @@ impl App {
         // plugins installed to main should see all sub-apps
         let plugins = std::mem::take(&mut self.main_mut().plugin_registry);
         for plugin in &plugins {
-            plugin.cleanup(self);
+            self.in_plugin_set(PluginSet::of_plugin(&**plugin), |app| plugin.cleanup(app));
         }
         let main = self.main_mut();
         main.plugin_registry = plugins;
@@ -259,6 +270,14 @@ impl App {
         self.sub_apps.iter_mut().skip(1).for_each(|s| s.cleanup());
     }
 
+    /// Runs `f` with the systems it adds to the main app put in `set`, as while its plugin is built.
+    fn in_plugin_set(&mut self, set: PluginSet, f: impl FnOnce(&mut Self)) {
+        let path = self.main().plugin_set_path(set);
+        let building = std::mem::replace(&mut self.main_mut().building_plugin_sets, path);
+        f(self);
+        self.main_mut().building_plugin_sets = building;
+    }
+
     /// Returns `true` if any of the sub-apps are building plugins.
     pub(crate) fn is_building_plugins(&self) -> bool {
         self.sub_apps.iter().any(|s| s.is_building_plugins())
@@ -453,10 +472,13 @@ impl App {
             .push(Box::new(PlaceholderPlugin));
 
         self.main_mut().plugin_build_depth += 1;
+        self.main_mut()
+            .push_plugin_set(PluginSet::of_plugin(&*plugin));
         let result = catch_unwind(AssertUnwindSafe(|| plugin.build(self)));
         self.main_mut()
             .plugin_names
             .insert(plugin.name().to_string());
+        self.main_mut().building_plugin_sets.pop();
         self.main_mut().plugin_build_depth -= 1;
 
         if let Err(payload) = result {
@@ -475,6 +497,108 @@ impl App {
         self.main().is_plugin_added::<T>()
     }
 
+    /// Returns `true` if the [`Plugin`] has been added, and neither it nor the plugin that added it
+    /// is [disabled](App::disable_plugin).
+    pub fn is_plugin_enabled<T>(&self) -> bool
+    where
+        T: Plugin,
+    {
+        self.main().is_plugin_enabled::<T>()
+    }
+
+    /// Disables the [`Plugin`]s of type `T`: the systems in their [`PluginSet`] stop running, and
+    /// their [`Plugin::cleanup_on_disable`] is called.
+    ///
+    /// This also disables the plugins they added, whose sets are nested in theirs: their
+    /// [`Plugin::cleanup_on_disable`] is called too, and [`App::is_plugin_enabled`] returns
+    /// `false` for them until `T` is [enabled](App::enable_plugin) again.
+    ///
+    /// This can be called once the plugins are added, such as to disable the plugins of a
+    /// [`PluginGroup`](crate::PluginGroup) depending on command line flags, or between two
+    /// [updates](App::update) to toggle them at runtime. Disabling plugins that are already
+    /// disabled does nothing.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # struct DebugOverlayPlugin;
+    /// # impl Plugin for DebugOverlayPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// let mut app = App::new();
+    /// app.add_plugins(DebugOverlayPlugin);
+    /// if !std::env::args().any(|arg| arg == "--debug") {
+    ///     app.disable_plugin::<DebugOverlayPlugin>();
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no plugin of type `T` has been added.
+    pub fn disable_plugin<T>(&mut self) -> &mut Self
+    where
+        T: Plugin,
+    {
+        assert!(
+            self.is_plugin_added::<T>(),
+            "Cannot disable the plugin {}, which hasn't been added.",
+            std::any::type_name::<T>()
+        );
+        let set = PluginSet::of::<T>();
+        let main = self.main();
+        // The plugins of type `T` and those they added, unless they were disabled already
+        let disabled: Vec<usize> = main
+            .plugin_registry
+            .iter()
+            .enumerate()
+            .filter(|(_, plugin)| {
+                let plugin_set = PluginSet::of_plugin(&***plugin);
+                main.is_plugin_set_enabled(plugin_set)
+                    && main.plugin_set_path(plugin_set).contains(&set)
+            })
+            .map(|(index, _)| index)
+            .collect();
+        if !self.main_mut().set_plugin_enabled(set, false) {
+            return self;
+        }
+
+        let plugins = std::mem::take(&mut self.main_mut().plugin_registry);
+        for index in disabled {
+            plugins[index].cleanup_on_disable(self);
+        }
+        self.main_mut().plugin_registry = plugins;
+        self
+    }
+
+    /// Enables the [`Plugin`]s of type `T` again after [disabling](App::disable_plugin) them, so
+    /// that the systems in their [`PluginSet`] run again.
+    ///
+    /// The plugins aren't built again, so what they removed in [`Plugin::cleanup_on_disable`]
+    /// isn't added back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no plugin of type `T` has been added.
+    pub fn enable_plugin<T>(&mut self) -> &mut Self
+    where
+        T: Plugin,
+    {
+        assert!(
+            self.is_plugin_added::<T>(),
+            "Cannot enable the plugin {}, which hasn't been added.",
+            std::any::type_name::<T>()
+        );
+        self.main_mut()
+            .set_plugin_enabled(PluginSet::of::<T>(), true);
+        self
+    }
+
+    /// Returns the names of all plugins that have been added, in no particular order.
+    ///
+    /// The name of a plugin is given by [`Plugin::name`], which is its type name by default.
+    pub fn plugin_names(&self) -> impl Iterator<Item = &str> {
+        self.main().plugin_names()
+    }
+
     /// Returns a vector of references to all plugins of type `T` that have been added.
     ///
     /// This can be used to read the settings of any existing plugins.
@@ -830,6 +954,30 @@ impl App {
         None
     }
 
+    /// Runs the [`Shutdown`] schedule once the [runner](App::set_runner) stopped updating the app
+    /// because of `exit`, and returns the [`AppExit`] that [`App::run`] should return.
+    ///
+    /// If `exit` is a success, the first error sent by the systems of the [`Shutdown`] schedule is
+    /// returned instead, so that for example failing to flush a save file makes the app exit with
+    /// an error.
+    ///
+    /// Custom runners should call this after their last update, before clearing the world.
+    /// The schedule is removed from the world when it runs, so it only ever runs once.
+    pub fn shutdown(&mut self, exit: AppExit) -> AppExit {
+        let world = self.world_mut();
+        if let Some(mut schedule) = world
+            .get_resource_mut::<Schedules>()
+            .and_then(|mut schedules| schedules.remove(Shutdown))
+        {
+            schedule.run(world);
+        }
+
+        match exit {
+            AppExit::Error(_) => exit,
+            AppExit::Success => self.should_exit().unwrap_or(exit),
+        }
+    }
+
     /// Spawns an [`Observer`] entity, which will watch for and respond to the given event.
     pub fn observe<E: Event, B: Bundle, M>(
         &mut self,
@@ -852,7 +1000,8 @@ fn run_once(mut app: App) -> AppExit {
 
     app.update();
 
-    app.should_exit().unwrap_or(AppExit::Success)
+    let exit = app.should_exit().unwrap_or(AppExit::Success);
+    app.shutdown(exit)
 }
 
 /// An event that indicates the [`App`] should exit. If one or more of these are present at the end of an update,
@@ -935,12 +1084,12 @@ mod tests {
         event::{Event, EventWriter, Events},
         query::With,
         removal_detection::RemovedComponents,
-        schedule::{IntoSystemConfigs, ScheduleLabel},
-        system::{Commands, Query, Resource},
+        schedule::{IntoSystemConfigs, IntoSystemSetConfigs, ScheduleLabel},
+        system::{Commands, Query, Res, Resource},
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Plugin, PluginSet, Shutdown, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1040,6 +1189,144 @@ mod tests {
         app.finish();
     }
 
+    #[derive(Resource, Default)]
+    struct UpdateCount(usize);
+
+    #[derive(Resource, Default)]
+    struct DisableCount(usize);
+
+    struct CountingPlugin;
+    impl Plugin for CountingPlugin {
+        fn build(&self, app: &mut App) {
+            app.init_resource::<UpdateCount>()
+                .add_systems(Update, |mut count: ResMut<UpdateCount>| count.0 += 1);
+        }
+
+        fn cleanup_on_disable(&self, app: &mut App) {
+            app.world_mut().resource_mut::<DisableCount>().0 += 1;
+        }
+    }
+
+    #[test]
+    fn disabled_plugins_do_not_run_their_systems() {
+        let mut app = App::new();
+        app.init_resource::<DisableCount>()
+            .add_plugins(CountingPlugin);
+        app.update();
+        assert!(app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 1);
+
+        app.disable_plugin::<CountingPlugin>();
+        app.disable_plugin::<CountingPlugin>();
+        app.update();
+        assert!(!app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 1);
+        assert_eq!(app.world().resource::<DisableCount>().0, 1);
+
+        app.enable_plugin::<CountingPlugin>();
+        app.update();
+        assert!(app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 2);
+    }
+
+    #[test]
+    fn plugin_sets_hold_the_systems_of_nested_plugins() {
+        struct ParentPlugin;
+        impl Plugin for ParentPlugin {
+            fn build(&self, app: &mut App) {
+                app.add_plugins(CountingPlugin);
+            }
+        }
+
+        #[derive(Resource)]
+        struct Gate(bool);
+
+        let mut app = App::new();
+        app.init_resource::<DisableCount>()
+            .insert_resource(Gate(false))
+            .add_plugins(ParentPlugin)
+            .configure_sets(
+                Update,
+                PluginSet::of::<ParentPlugin>().run_if(|gate: Res<Gate>| gate.0),
+            );
+        app.update();
+        assert_eq!(app.world().resource::<UpdateCount>().0, 0);
+
+        app.world_mut().resource_mut::<Gate>().0 = true;
+        app.update();
+        assert_eq!(app.world().resource::<UpdateCount>().0, 1);
+
+        app.disable_plugin::<ParentPlugin>();
+        app.update();
+        assert!(!app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 1);
+    }
+
+    #[test]
+    fn disabling_a_plugin_disables_the_plugins_it_added() {
+        struct ParentPlugin;
+        impl Plugin for ParentPlugin {
+            fn build(&self, app: &mut App) {
+                app.add_plugins(CountingPlugin);
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<DisableCount>()
+            .add_plugins(ParentPlugin);
+        app.update();
+
+        app.disable_plugin::<ParentPlugin>();
+        app.update();
+        assert!(!app.is_plugin_enabled::<ParentPlugin>());
+        assert!(!app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 1);
+        assert_eq!(app.world().resource::<DisableCount>().0, 1);
+
+        // The child is already disabled through its parent
+        app.disable_plugin::<CountingPlugin>();
+        assert_eq!(app.world().resource::<DisableCount>().0, 1);
+
+        app.enable_plugin::<ParentPlugin>();
+        app.update();
+        assert!(!app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 1);
+
+        app.enable_plugin::<CountingPlugin>();
+        app.update();
+        assert!(app.is_plugin_enabled::<CountingPlugin>());
+        assert_eq!(app.world().resource::<UpdateCount>().0, 2);
+    }
+
+    #[test]
+    fn plugin_sets_hold_the_systems_added_in_finish() {
+        #[derive(Resource, Default)]
+        struct FinishCount(usize);
+
+        struct FinishingPlugin;
+        impl Plugin for FinishingPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<FinishCount>();
+            }
+
+            fn finish(&self, app: &mut App) {
+                app.add_systems(Update, |mut count: ResMut<FinishCount>| count.0 += 1);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(FinishingPlugin);
+        app.disable_plugin::<FinishingPlugin>();
+        app.finish();
+        app.cleanup();
+        app.update();
+        assert_eq!(app.world().resource::<FinishCount>().0, 0);
+
+        app.enable_plugin::<FinishingPlugin>();
+        app.update();
+        assert_eq!(app.world().resource::<FinishCount>().0, 1);
+    }
+
     #[test]
     fn test_derive_app_label() {
         use super::AppLabel;
@@ -1214,6 +1501,51 @@ mod tests {
         assert_eq!(exit, AppExit::from_code(4));
     }
 
+    #[test]
+    fn shutdown_runs_once_before_run_returns() {
+        use std::path::PathBuf;
+
+        #[derive(Resource)]
+        struct SavePath(PathBuf);
+
+        fn exit_with_code(mut exits: EventWriter<AppExit>) {
+            exits.send(AppExit::from_code(3));
+        }
+
+        fn save(path: Res<SavePath>) {
+            // Fails if the schedule runs more than once
+            std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path.0)
+                .unwrap();
+        }
+
+        let path = std::env::temp_dir().join(format!("bevy_app_shutdown_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let exit = App::new()
+            .insert_resource(SavePath(path.clone()))
+            .add_systems(Update, exit_with_code)
+            .add_systems(Shutdown, save)
+            .run();
+
+        assert_eq!(exit, AppExit::from_code(3));
+        assert!(path.exists());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn shutdown_can_fail_a_successful_exit() {
+        fn fail_to_save(mut exits: EventWriter<AppExit>) {
+            exits.send(AppExit::from_code(2));
+        }
+
+        let mut app = App::new();
+        app.add_systems(Shutdown, fail_to_save);
+        assert_eq!(app.shutdown(AppExit::Success), AppExit::from_code(2));
+    }
+
     /// Custom runners should be in charge of when `app::update` gets called as they may need to
     /// coordinate some state.
     /// bug: <https://github.com/bevyengine/bevy/issues/10385>
@@ -1234,7 +1566,7 @@ mod tests {
                 app.update();
             }
 
-            AppExit::Success
+            app.shutdown(AppExit::Success)
         }
 
         fn my_system(_: Res<MyState>) {
@@ -157,6 +157,25 @@ pub struct PostUpdate;
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Last;
 
+/// The schedule that runs once when the app exits, after the last run of the [`Main`] schedule.
+///
+/// Use it for cleanup that must complete before [`App::run`] returns, such as flushing a save
+/// file or closing a network connection. It runs on the final [`World`], once the
+/// [runner](App::set_runner) stopped updating the app because of an [`AppExit`](crate::AppExit),
+/// and systems in it can send their own [`AppExit`](crate::AppExit) to change the exit code.
+///
+/// The app exits in this order:
+/// 1. The last update of the [`Main`] schedule, in which the [`AppExit`](crate::AppExit) is sent.
+///     Windows receive a `WindowClosing` event, but are still open.
+/// 2. This schedule, with all resources still in the world, including non-send ones.
+///     With `WinitPlugin`, it runs before the event loop is torn down.
+/// 3. The world is cleared, closing the windows and dropping the resources, including non-send
+///     ones.
+///
+/// Runners call [`App::shutdown`] to run it.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Shutdown;
+
 /// Defines the schedules to be run for the [`Main`] schedule, including
 /// their order.
 #[derive(Resource, Debug)]
@@ -1,7 +1,8 @@
+use bevy_ecs::schedule::SystemSet;
 use downcast_rs::{impl_downcast, Downcast};
 
 use crate::App;
-use std::any::Any;
+use std::any::{Any, TypeId};
 
 /// A collection of Bevy app logic and configuration.
 ///
@@ -22,6 +23,10 @@ use std::any::Any;
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// The systems added while a plugin is built are part of its [`PluginSet`]. Once added, the plugin
+/// can be disabled with [`App::disable_plugin`], which stops them from running and calls
+/// [`Plugin::cleanup_on_disable`].
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -79,6 +84,17 @@ pub trait Plugin: Downcast + Any + Send + Sync {
         // do nothing
     }
 
+    /// Removes what this plugin added to the [`App`] that shouldn't outlive it once it's
+    /// [disabled](App::disable_plugin), such as its resources.
+    ///
+    /// Disabling a plugin stops its systems, but keeps its resources unless they're removed here:
+    /// plugins inserting resources should document which ones are removed when they're disabled.
+    /// The plugin isn't built again when it's [enabled](App::enable_plugin) again, so the
+    /// resources removed here aren't inserted back.
+    fn cleanup_on_disable(&self, _app: &mut App) {
+        // do nothing
+    }
+
     /// Configures a name for the [`Plugin`] which is primarily used for checking plugin
     /// uniqueness and debugging.
     fn name(&self) -> &str {
@@ -100,6 +116,43 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// A [`SystemSet`] holding the systems added to an [`App`] while a [`Plugin`] of a given type is
+/// built, including the systems of the plugins it adds itself.
+///
+/// Each plugin gets its own set in the schedules it adds systems to, which can be configured like
+/// any other set, such as to gate all the systems of a plugin behind a run condition:
+///
+/// ```
+/// # use bevy_app::{prelude::*, PluginSet};
+/// # use bevy_ecs::prelude::*;
+/// # struct NetworkingPlugin;
+/// # impl Plugin for NetworkingPlugin {
+/// #     fn build(&self, app: &mut App) {}
+/// # }
+/// # fn is_online() -> bool { true }
+/// # let mut app = App::new();
+/// app.add_plugins(NetworkingPlugin).configure_sets(
+///     Update,
+///     PluginSet::of::<NetworkingPlugin>().run_if(is_online),
+/// );
+/// ```
+///
+/// Only the systems added to the app the plugin is added to are part of the set: the systems it
+/// adds directly to another [`SubApp`](crate::SubApp), such as the render app, aren't.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PluginSet(TypeId);
+
+impl PluginSet {
+    /// Returns the set of the systems added by the plugins of type `T`.
+    pub fn of<T: Plugin>() -> Self {
+        Self(TypeId::of::<T>())
+    }
+
+    pub(crate) fn of_plugin(plugin: &dyn Plugin) -> Self {
+        Self(plugin.as_any().type_id())
+    }
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
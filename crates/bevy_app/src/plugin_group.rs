@@ -198,6 +198,17 @@ impl PluginGroupBuilder {
         self
     }
 
+    /// [Disables](Self::disable) a [`Plugin`] if `condition` is `true`, such as a plugin turned
+    /// off with a command line flag. If there are no plugins of type `T` in this group, it will
+    /// panic.
+    pub fn disable_if<T: Plugin>(self, condition: bool) -> Self {
+        if condition {
+            self.disable::<T>()
+        } else {
+            self
+        }
+    }
+
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
     /// in the order specified.
     ///
@@ -280,6 +291,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn disable_if() {
+        let mut app = App::new();
+        PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginB)
+            .disable_if::<PluginA>(true)
+            .disable_if::<PluginB>(false)
+            .finish(&mut app);
+
+        assert!(!app.is_plugin_added::<PluginA>());
+        assert!(app.is_plugin_added::<PluginB>());
+    }
+
     #[test]
     fn add_after() {
         let group = PluginGroupBuilder::start::<NoopPluginGroup>()
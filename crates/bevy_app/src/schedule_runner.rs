@@ -85,11 +85,8 @@ impl Plugin for ScheduleRunnerPlugin {
                 RunMode::Once => {
                     app.update();
 
-                    if let Some(exit) = app.should_exit() {
-                        return exit;
-                    }
-
-                    AppExit::Success
+                    let exit = app.should_exit().unwrap_or(AppExit::Success);
+                    app.shutdown(exit)
                 }
                 RunMode::Loop { wait } => {
                     let tick = move |app: &mut App,
@@ -121,7 +118,7 @@ impl Plugin for ScheduleRunnerPlugin {
                             match tick(&mut app, wait) {
                                 Ok(Some(delay)) => std::thread::sleep(delay),
                                 Ok(None) => continue,
-                                Err(exit) => return exit,
+                                Err(exit) => return app.shutdown(exit),
                             }
                         }
                     }
@@ -155,7 +152,7 @@ impl Plugin for ScheduleRunnerPlugin {
                                     delay.unwrap_or(asap),
                                 ),
                                 Err(code) => {
-                                    closure_exit.replace(code);
+                                    closure_exit.replace(app.shutdown(code));
                                 }
                             }
                         };
@@ -1,4 +1,4 @@
-use crate::{App, InternedAppLabel, Plugin, Plugins, PluginsState};
+use crate::{App, InternedAppLabel, Plugin, PluginSet, Plugins, PluginsState};
 use bevy_ecs::{
     event::EventRegistry,
     prelude::*,
@@ -9,7 +9,13 @@ use bevy_ecs::{
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
 use bevy_utils::{HashMap, HashSet};
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 type ExtractFn = Box<dyn Fn(&mut World, &mut World) + Send>;
 
@@ -66,6 +72,11 @@ pub struct SubApp {
     pub(crate) plugin_names: HashSet<String>,
     /// Panics if an update is attempted while plugins are building.
     pub(crate) plugin_build_depth: usize,
+    /// The sets of the plugins being built, from the outermost plugin. The systems added to this
+    /// app are part of the innermost set, which is nested in the sets of the plugins above it.
+    pub(crate) building_plugin_sets: Vec<PluginSet>,
+    /// The schedules the systems of each plugin set were added to, and whether they're enabled.
+    plugin_sets: HashMap<PluginSet, PluginSetState>,
     pub(crate) plugins_state: PluginsState,
     /// The schedule that will be run by [`update`](Self::update).
     pub update_schedule: Option<InternedScheduleLabel>,
@@ -74,6 +85,15 @@ pub struct SubApp {
     extract: Option<ExtractFn>,
 }
 
+#[derive(Default)]
+struct PluginSetState {
+    /// The sets of the plugins that added this plugin, from the outermost one.
+    parents: Vec<PluginSet>,
+    schedules: HashSet<InternedScheduleLabel>,
+    /// Toggled to disable the systems of the set, once its plugin has been disabled.
+    enabled: Option<Arc<AtomicBool>>,
+}
+
 impl Debug for SubApp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "SubApp")
@@ -89,6 +109,8 @@ impl Default for SubApp {
             plugin_registry: Vec::default(),
             plugin_names: HashSet::default(),
             plugin_build_depth: 0,
+            building_plugin_sets: Vec::new(),
+            plugin_sets: HashMap::default(),
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
@@ -182,7 +204,37 @@ impl SubApp {
         schedule: impl ScheduleLabel,
         systems: impl IntoSystemConfigs<M>,
     ) -> &mut Self {
+        let schedule = schedule.intern();
+        let mut systems = systems.into_configs();
+        if let Some(set) = self.building_plugin_sets.last() {
+            systems = systems.in_set(*set);
+        }
+
         let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut parent = None;
+        for set in &self.building_plugin_sets {
+            let added = self
+                .plugin_sets
+                .entry(*set)
+                .or_default()
+                .schedules
+                .insert(schedule);
+            if added {
+                // The set of a plugin added by another plugin is nested in the set of the latter
+                if let Some(parent) = parent {
+                    schedules.configure_sets(schedule, set.in_set(parent));
+                }
+                // Systems can be added to a disabled set, such as from `Plugin::finish`
+                if let Some(enabled) = &self.plugin_sets[set].enabled {
+                    let enabled = enabled.clone();
+                    schedules.configure_sets(
+                        schedule,
+                        set.run_if(move || enabled.load(Ordering::Relaxed)),
+                    );
+                }
+            }
+            parent = Some(*set);
+        }
         schedules.add_systems(schedule, systems);
 
         self
@@ -326,6 +378,75 @@ impl SubApp {
         self.plugin_names.contains(std::any::type_name::<T>())
     }
 
+    /// See [`App::is_plugin_enabled`].
+    pub fn is_plugin_enabled<T>(&self) -> bool
+    where
+        T: Plugin,
+    {
+        self.is_plugin_added::<T>() && self.is_plugin_set_enabled(PluginSet::of::<T>())
+    }
+
+    /// Starts building a plugin with the given set, nested in the sets of the plugins being built.
+    pub(crate) fn push_plugin_set(&mut self, set: PluginSet) {
+        let parents = self.building_plugin_sets.clone();
+        self.plugin_sets
+            .entry(set)
+            .or_insert_with(|| PluginSetState {
+                parents,
+                ..Default::default()
+            });
+        self.building_plugin_sets.push(set);
+    }
+
+    /// Returns the sets of the plugins that added the plugin with the given set, from the
+    /// outermost one, followed by the set itself.
+    pub(crate) fn plugin_set_path(&self, set: PluginSet) -> Vec<PluginSet> {
+        let mut path = self
+            .plugin_sets
+            .get(&set)
+            .map(|state| state.parents.clone())
+            .unwrap_or_default();
+        path.push(set);
+        path
+    }
+
+    /// Returns `true` if neither the given set nor the sets it's nested in are disabled.
+    pub(crate) fn is_plugin_set_enabled(&self, set: PluginSet) -> bool {
+        self.plugin_set_path(set).iter().all(|set| {
+            self.plugin_sets
+                .get(set)
+                .and_then(|state| state.enabled.as_ref())
+                .map_or(true, |enabled| enabled.load(Ordering::Relaxed))
+        })
+    }
+
+    /// Sets whether the systems of `set` run, and returns `true` if it changed.
+    pub(crate) fn set_plugin_enabled(&mut self, set: PluginSet, enabled: bool) -> bool {
+        let state = self.plugin_sets.entry(set).or_default();
+        if state.enabled.is_none() && enabled {
+            return false;
+        }
+        let world = &mut self.world;
+        let flag = state.enabled.get_or_insert_with(|| {
+            // The run condition is only added once the plugin is disabled, so that the plugins
+            // that are never disabled don't pay for it
+            let flag = Arc::new(AtomicBool::new(true));
+            let mut schedules = world.resource_mut::<Schedules>();
+            for schedule in &state.schedules {
+                let flag = flag.clone();
+                schedules
+                    .configure_sets(*schedule, set.run_if(move || flag.load(Ordering::Relaxed)));
+            }
+            flag
+        });
+        flag.swap(enabled, Ordering::Relaxed) != enabled
+    }
+
+    /// See [`App::plugin_names`].
+    pub fn plugin_names(&self) -> impl Iterator<Item = &str> {
+        self.plugin_names.iter().map(String::as_str)
+    }
+
     /// See [`App::get_added_plugins`].
     pub fn get_added_plugins<T>(&self) -> Vec<&T>
     where
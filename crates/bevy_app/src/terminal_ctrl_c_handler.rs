@@ -0,0 +1,69 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bevy_ecs::event::EventWriter;
+
+use crate::{App, AppExit, Plugin, Update};
+
+/// Set when a `SIGINT` (Ctrl+C) or `SIGTERM` is received.
+static SHOULD_EXIT: AtomicBool = AtomicBool::new(false);
+
+/// The exit code sent with the [`AppExit`] raised by [`TerminalCtrlCHandlerPlugin`], following the
+/// shell convention of `128` plus the number of `SIGINT`.
+pub const TERMINAL_INTERRUPT_EXIT_CODE: u8 = 130;
+
+/// Gracefully exits the [`App`] when the process receives a `SIGINT` (Ctrl+C) or a `SIGTERM`,
+/// instead of killing it right away.
+///
+/// The signal only raises a flag, which a system turns into an [`AppExit`] during [`Update`].
+/// The rest of that update still runs, so systems in [`Last`](crate::Last) can for example save
+/// the state of the world before the app exits.
+///
+/// This plugin is part of the `ServerPlugins`, and is only available on native platforms.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as MinimalPlugins, PluginGroup, TerminalCtrlCHandlerPlugin};
+/// fn main() {
+///     App::new()
+///         .add_plugins(MinimalPlugins)
+///         .add_plugins(TerminalCtrlCHandlerPlugin)
+///         .run();
+/// }
+/// ```
+///
+/// Only one handler can be set per process: if another one has already been set, this plugin
+/// leaves it in place and does nothing.
+#[derive(Default)]
+pub struct TerminalCtrlCHandlerPlugin;
+
+impl TerminalCtrlCHandlerPlugin {
+    /// Requests the [`App`] to exit at its next update, as if the process had received a
+    /// `SIGINT`.
+    pub fn gracefully_exit() {
+        SHOULD_EXIT.store(true, Ordering::Relaxed);
+    }
+
+    /// Sends an [`AppExit`] once an exit has been requested.
+    pub fn exit_on_flag(mut events: EventWriter<AppExit>) {
+        if SHOULD_EXIT.load(Ordering::Relaxed) {
+            events.send(AppExit::from_code(TERMINAL_INTERRUPT_EXIT_CODE));
+        }
+    }
+}
+
+impl Plugin for TerminalCtrlCHandlerPlugin {
+    fn build(&self, app: &mut App) {
+        match ctrlc::try_set_handler(TerminalCtrlCHandlerPlugin::gracefully_exit) {
+            Ok(()) => {}
+            Err(ctrlc::Error::MultipleHandlers) => {
+                bevy_utils::tracing::info!(
+                    "Skipping installing the Ctrl+C handler as one was already installed. \
+                    Please call `TerminalCtrlCHandlerPlugin::gracefully_exit` in your own handler \
+                    to still exit gracefully."
+                );
+            }
+            Err(err) => bevy_utils::tracing::warn!("Failed to set the Ctrl+C handler: {err}"),
+        }
+
+        app.add_systems(Update, TerminalCtrlCHandlerPlugin::exit_on_flag);
+    }
+}
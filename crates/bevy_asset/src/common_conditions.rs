@@ -0,0 +1,82 @@
+use crate::{AssetServer, UntypedHandle};
+use bevy_ecs::system::{Local, Res};
+#[cfg(feature = "bevy_state")]
+use bevy_state::state::States;
+
+/// Run condition that becomes active once, on the first run after all of the given assets
+/// (and their dependencies) have finished loading. It never becomes active again after that.
+///
+/// This is useful to defer "startup" work, such as spawning text that uses a custom font,
+/// until the assets it relies on are ready, avoiding a frame where they are missing.
+///
+/// The handles are kept alive by the condition, so the assets won't be unloaded while waiting.
+/// They must have been created by the [`AssetServer`]: assets added directly to
+/// [`Assets`](crate::Assets) are never reported as loaded.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
+/// # use bevy_asset::{AssetServer, Handle, common_conditions::assets_loaded};
+/// # use bevy_ecs::schedule::IntoSystemConfigs;
+/// # #[derive(bevy_asset::Asset, bevy_reflect::TypePath)]
+/// # struct Font;
+/// fn main() {
+///     let mut app = App::new();
+///     app.add_plugins(DefaultPlugins);
+///     let font: Handle<Font> = app
+///         .world()
+///         .resource::<AssetServer>()
+///         .load("fonts/FiraSans-Bold.ttf");
+///     app.add_systems(Update, setup.run_if(assets_loaded(&[font.untyped()])))
+///         .run();
+/// }
+/// fn setup() {
+///     // ran exactly once, after the font has loaded
+/// }
+/// ```
+pub fn assets_loaded(
+    handles: &[UntypedHandle],
+) -> impl FnMut(Res<AssetServer>, Local<bool>) -> bool + Clone {
+    let handles = handles.to_vec();
+    move |asset_server: Res<AssetServer>, mut fired: Local<bool>| {
+        if *fired {
+            return false;
+        }
+        *fired = handles
+            .iter()
+            .all(|handle| asset_server.is_loaded_with_dependencies(handle.id()));
+        *fired
+    }
+}
+
+/// Run condition that is active while all the assets of `state`, added with
+/// [`StateAssetApp::load_on_enter`](crate::StateAssetApp::load_on_enter), and their dependencies
+/// are loaded.
+///
+/// This is already the case when `state` is entered again while its assets are still loaded, so
+/// the [`OnEnter`](bevy_state::state::OnEnter) systems gated by it then run. Otherwise, gate
+/// systems running in [`Update`](bevy_app::Update) to set up the state once it's ready.
+///
+/// See [`StateAssets::is_ready`](crate::StateAssets::is_ready).
+#[cfg(feature = "bevy_state")]
+pub fn assets_ready_for<S: States>(
+    state: S,
+) -> impl FnMut(Option<Res<crate::StateAssets<S>>>, Res<AssetServer>) -> bool + Clone {
+    move |state_assets: Option<Res<crate::StateAssets<S>>>, asset_server: Res<AssetServer>| {
+        state_assets.is_some_and(|state_assets| state_assets.is_ready(&state, &asset_server))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::schedule::{IntoSystemConfigs, Schedule};
+
+    fn test_system() {}
+
+    // Ensure distributive_run_if compiles with the common conditions.
+    #[test]
+    fn distributive_run_if_compiles() {
+        Schedule::default()
+            .add_systems((test_system, test_system).distributive_run_if(assets_loaded(&[])));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{AssetServer, LoadState, LoadedFolder, RecursiveDependencyLoadState, UntypedHandle};
+use bevy_ecs::{
+    event::{Event, EventWriter},
+    system::{Res, ResMut, Resource},
+};
+use bevy_utils::HashMap;
+
+/// A group of assets that are waited for together, such as a font and its fallbacks.
+///
+/// Add it to the [`AssetGroups`] to receive a single [`AssetGroupEvent`] once all of its assets
+/// and their dependencies finished loading, telling which of them failed if any.
+///
+/// ```
+/// # use bevy_asset::{AssetGroup, AssetGroupEvent, AssetGroups, AssetServer};
+/// # use bevy_ecs::prelude::*;
+/// fn load_fonts(server: Res<AssetServer>, mut groups: ResMut<AssetGroups>) {
+///     let fonts = AssetGroup::new()
+///         .with(server.load_untyped("fonts/FiraSans-Bold.ttf"))
+///         .with(server.load_untyped("fonts/FiraMono-Medium.ttf"));
+///     groups.add(fonts);
+/// }
+///
+/// fn spawn_text(mut events: EventReader<AssetGroupEvent>) {
+///     for event in events.read() {
+///         match event {
+///             AssetGroupEvent::Loaded { .. } => {
+///                 // All the fonts are ready
+///             }
+///             AssetGroupEvent::Failed { failed, .. } => {
+///                 // The fonts in `failed` are missing
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct AssetGroup {
+    handles: Vec<UntypedHandle>,
+}
+
+impl AssetGroup {
+    /// Creates an empty [`AssetGroup`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `handle` to the group.
+    pub fn with(mut self, handle: impl Into<UntypedHandle>) -> Self {
+        self.add(handle);
+        self
+    }
+
+    /// Adds `handle` to the group.
+    pub fn add(&mut self, handle: impl Into<UntypedHandle>) {
+        self.handles.push(handle.into());
+    }
+
+    /// The handles of the assets in the group.
+    pub fn handles(&self) -> &[UntypedHandle] {
+        &self.handles
+    }
+}
+
+impl<H: Into<UntypedHandle>> FromIterator<H> for AssetGroup {
+    fn from_iter<T: IntoIterator<Item = H>>(handles: T) -> Self {
+        Self {
+            handles: handles.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&LoadedFolder> for AssetGroup {
+    fn from(folder: &LoadedFolder) -> Self {
+        folder.handles.iter().cloned().collect()
+    }
+}
+
+/// The identifier of an [`AssetGroup`] added to the [`AssetGroups`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AssetGroupId(u32);
+
+/// The [`AssetGroup`]s that are loading.
+///
+/// A group is removed once its [`AssetGroupEvent`] is sent.
+#[derive(Resource, Default)]
+pub struct AssetGroups {
+    groups: HashMap<AssetGroupId, AssetGroup>,
+    next_id: u32,
+}
+
+impl AssetGroups {
+    /// Adds `group`, returning the id of its [`AssetGroupEvent`].
+    pub fn add(&mut self, group: AssetGroup) -> AssetGroupId {
+        let id = AssetGroupId(self.next_id);
+        self.next_id += 1;
+        self.groups.insert(id, group);
+        id
+    }
+
+    /// Gets the group `id`, if it's still loading.
+    pub fn get(&self, id: AssetGroupId) -> Option<&AssetGroup> {
+        self.groups.get(&id)
+    }
+
+    /// Removes the group `id`, so that no event is sent for it.
+    pub fn remove(&mut self, id: AssetGroupId) -> Option<AssetGroup> {
+        self.groups.remove(&id)
+    }
+}
+
+/// An event sent once for each of the [`AssetGroups`].
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub enum AssetGroupEvent {
+    /// All the assets of the group, and all their recursive dependencies, are loaded.
+    Loaded { id: AssetGroupId },
+    /// All the assets of the group finished loading, but some of them, or some of their
+    /// recursive dependencies, failed to load.
+    Failed {
+        id: AssetGroupId,
+        /// The assets of the group that failed.
+        failed: Vec<UntypedHandle>,
+    },
+}
+
+impl AssetGroupEvent {
+    /// The id of the group of this event.
+    pub fn id(&self) -> AssetGroupId {
+        match self {
+            AssetGroupEvent::Loaded { id } | AssetGroupEvent::Failed { id, .. } => *id,
+        }
+    }
+}
+
+/// Sends an [`AssetGroupEvent`] for the [`AssetGroups`] that finished loading.
+pub fn track_asset_groups(
+    server: Res<AssetServer>,
+    mut groups: ResMut<AssetGroups>,
+    mut events: EventWriter<AssetGroupEvent>,
+) {
+    groups.groups.retain(|&id, group| {
+        let mut failed = Vec::new();
+        for handle in &group.handles {
+            // Assets added directly rather than loaded aren't tracked, and are ready
+            let Some((load_state, _, recursive_dependency_load_state)) =
+                server.get_load_states(handle.id())
+            else {
+                continue;
+            };
+            if matches!(load_state, LoadState::Failed(_))
+                || recursive_dependency_load_state == RecursiveDependencyLoadState::Failed
+            {
+                failed.push(handle.clone());
+            } else if recursive_dependency_load_state != RecursiveDependencyLoadState::Loaded {
+                // Still loading
+                return true;
+            }
+        }
+
+        events.send(if failed.is_empty() {
+            AssetGroupEvent::Loaded { id }
+        } else {
+            AssetGroupEvent::Failed { id, failed }
+        });
+        false
+    });
+}
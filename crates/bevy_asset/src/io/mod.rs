@@ -13,12 +13,14 @@ pub mod file;
 pub mod gated;
 pub mod memory;
 pub mod processor_gated;
+mod scale_variant;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
 
 mod source;
 
 pub use futures_lite::{AsyncReadExt, AsyncWriteExt};
+pub use scale_variant::*;
 pub use source::*;
 
 use bevy_utils::{BoxedFuture, ConditionalSendFuture};
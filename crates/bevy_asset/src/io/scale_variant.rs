@@ -0,0 +1,222 @@
+use crate::{
+    io::{
+        AssetReader, AssetReaderError, AssetSourceId, AsyncReadExt, ErasedAssetReader, PathStream,
+        Reader, VecReader,
+    },
+    AssetPath, AssetServer,
+};
+use bevy_ecs::system::Resource;
+use bevy_utils::HashSet;
+use parking_lot::RwLock;
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+/// Selects the `@2x`, `@3x`, ... variants of assets for high-DPI displays.
+///
+/// When enabled with [`AssetPlugin::max_scale_variant`](crate::AssetPlugin::max_scale_variant),
+/// reading `fonts/FiraSans.ttf` with a scale factor of 2 or more reads `fonts/FiraSans@2x.ttf`
+/// instead, if it exists. The largest variant that doesn't exceed the scale factor is selected,
+/// and the base asset is read when no variant exists.
+///
+/// The scale factor is set with [`ScaleVariants::set_scale_factor`], which `bevy_render` calls with
+/// the scale factor of the primary window. The loaded assets that have variants are reloaded when
+/// the scale of the selected variants changes.
+#[derive(Resource, Clone, Debug)]
+pub struct ScaleVariants(Arc<ScaleVariantsData>);
+
+#[derive(Debug)]
+struct ScaleVariantsData {
+    max_scale: u32,
+    scale: AtomicU32,
+    /// The paths of the assets read so far that have variants, reloaded when the scale changes.
+    scaled_paths: RwLock<HashSet<AssetPath<'static>>>,
+}
+
+impl ScaleVariants {
+    /// Creates a [`ScaleVariants`] selecting variants up to `@{max_scale}x`, for a scale factor of 1.
+    pub fn new(max_scale: u32) -> Self {
+        Self(Arc::new(ScaleVariantsData {
+            max_scale,
+            scale: AtomicU32::new(1),
+            scaled_paths: Default::default(),
+        }))
+    }
+
+    /// Returns the scale of the variants read, or 1 if base assets are read.
+    pub fn scale(&self) -> u32 {
+        self.0.scale.load(Ordering::Relaxed)
+    }
+
+    /// Sets the scale factor of the display, reloading the loaded assets that have variants if the
+    /// scale of the selected variants changed.
+    ///
+    /// The assets that aren't loaded anymore, since all their handles were dropped, are forgotten
+    /// instead of being reloaded.
+    pub fn set_scale_factor(&self, scale_factor: f32, asset_server: &AssetServer) {
+        let scale = (scale_factor.floor() as u32).clamp(1, self.0.max_scale.max(1));
+        if self.0.scale.swap(scale, Ordering::Relaxed) == scale {
+            return;
+        }
+        let mut scaled_paths = self.0.scaled_paths.write();
+        scaled_paths.retain(|path| asset_server.get_path_id(path).is_some());
+        for path in scaled_paths.iter() {
+            asset_server.reload(path.clone());
+        }
+    }
+
+    /// Wraps `reader` in a [`ScaleVariantReader`] reading the variants of the assets of `source`.
+    pub fn wrap_reader(
+        &self,
+        source: AssetSourceId<'static>,
+        reader: Box<dyn ErasedAssetReader>,
+    ) -> ScaleVariantReader {
+        ScaleVariantReader {
+            reader,
+            source,
+            variants: self.clone(),
+        }
+    }
+}
+
+/// Returns the path of the `@{scale}x` variant of `path`, such as `fonts/FiraSans@2x.ttf`.
+pub fn scale_variant_path(path: &Path, scale: u32) -> PathBuf {
+    let mut file_name = path.file_stem().unwrap_or_default().to_os_string();
+    file_name.push(format!("@{scale}x"));
+    if let Some(extension) = path.extension() {
+        file_name.push(".");
+        file_name.push(extension);
+    }
+    path.with_file_name(file_name)
+}
+
+/// An [`AssetReader`] that reads the variant of an asset matching the scale of its [`ScaleVariants`].
+///
+/// Variants are read in full before being returned, so that the base asset can be read instead
+/// when no variant exists. Asset metadata is always read from the base asset.
+///
+/// To only reload the assets with variants when the scale changes, the variants above the current
+/// scale are also looked up, until one of them is found.
+pub struct ScaleVariantReader {
+    reader: Box<dyn ErasedAssetReader>,
+    source: AssetSourceId<'static>,
+    variants: ScaleVariants,
+}
+
+impl AssetReader for ScaleVariantReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        let current_scale = self.variants.scale();
+        let mut selected = None;
+        // Whether a variant exists at any scale, even if it isn't selected at the current scale
+        let mut has_variants = false;
+        for scale in (2..=self.variants.0.max_scale).rev() {
+            let variant = scale_variant_path(path, scale);
+            let found = if scale <= current_scale && selected.is_none() {
+                read_to_vec(&*self.reader, &variant)
+                    .await
+                    .map(|bytes| selected = Some(bytes))
+            } else if !has_variants {
+                self.reader.read(&variant).await.map(drop)
+            } else {
+                continue;
+            };
+            match found {
+                Ok(()) => has_variants = true,
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        // Remember the assets with variants, to reload them when the scale changes
+        let asset_path = AssetPath::from(path.to_path_buf()).with_source(self.source.clone());
+        {
+            // The lock isn't held across the read below, which would make this future `!Send`
+            let mut scaled_paths = self.variants.0.scaled_paths.write();
+            if has_variants {
+                scaled_paths.insert(asset_path);
+            } else {
+                scaled_paths.remove(&asset_path);
+            }
+        }
+
+        match selected {
+            Some(bytes) => Ok(Box::new(VecReader::new(bytes))),
+            None => self.reader.read(path).await,
+        }
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        self.reader.read_meta(path).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        self.reader.read_directory(path).await
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        self.reader.is_directory(path).await
+    }
+}
+
+/// Reads the asset at `path` in full, so that the returned bytes don't borrow `path`.
+async fn read_to_vec(
+    reader: &dyn ErasedAssetReader,
+    path: &Path,
+) -> Result<Vec<u8>, AssetReaderError> {
+    let mut reader = reader.read(path).await?;
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::memory::{Dir, MemoryAssetReader};
+
+    #[test]
+    fn variants_fall_back_to_base_asset() {
+        assert_eq!(
+            scale_variant_path(Path::new("fonts/FiraSans.ttf"), 2),
+            Path::new("fonts/FiraSans@2x.ttf")
+        );
+        assert_eq!(
+            scale_variant_path(Path::new("icon"), 3),
+            Path::new("icon@3x")
+        );
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.png"), "a");
+        dir.insert_asset_text(Path::new("a@2x.png"), "a@2x");
+        dir.insert_asset_text(Path::new("b.png"), "b");
+        let variants = ScaleVariants::new(3);
+        let reader = variants.wrap_reader(
+            AssetSourceId::Default,
+            Box::new(MemoryAssetReader { root: dir }),
+        );
+        let read = |path: &str| {
+            bevy_tasks::block_on(async {
+                let mut bytes = Vec::new();
+                let mut asset = AssetReader::read(&reader, Path::new(path)).await.unwrap();
+                asset.read_to_end(&mut bytes).await.unwrap();
+                String::from_utf8(bytes).unwrap()
+            })
+        };
+
+        // Only the assets with variants are reloaded when the scale changes, whatever the scale
+        // they were read at
+        assert_eq!(read("a.png"), "a");
+        assert_eq!(variants.0.scaled_paths.read().len(), 1);
+        variants.0.scale.store(3, Ordering::Relaxed);
+        assert_eq!(read("a.png"), "a@2x");
+        assert_eq!(read("b.png"), "b");
+        assert_eq!(variants.0.scaled_paths.read().len(), 1);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    io::{processor_gated::ProcessorGatedReader, AssetSourceEvent, AssetWatcher},
+    io::{
+        memory::MemoryAssetReader, processor_gated::ProcessorGatedReader, AssetSourceEvent,
+        AssetWatcher, ScaleVariants,
+    },
     processor::AssetProcessorData,
 };
 use bevy_ecs::system::Resource;
@@ -541,6 +544,18 @@ impl AssetSource {
             )));
         }
     }
+
+    /// This will cause [`AssetReader`] futures (such as [`AssetReader::read`]) to read the `@2x`, `@3x`, ...
+    /// variant of assets matching the scale of `variants`, if it exists. See [`ScaleVariants`].
+    pub fn select_scale_variants(&mut self, variants: &ScaleVariants) {
+        // The reader is only taken to be wrapped, so this placeholder is never read from
+        let placeholder: Box<dyn ErasedAssetReader> = Box::<MemoryAssetReader>::default();
+        let reader = std::mem::replace(&mut self.reader, placeholder);
+        self.reader = Box::new(variants.wrap_reader(self.id(), reader));
+        if let Some(reader) = self.processed_reader.take() {
+            self.processed_reader = Some(Box::new(variants.wrap_reader(self.id(), reader)));
+        }
+    }
 }
 
 /// A collection of [`AssetSource`]s.
@@ -599,6 +614,14 @@ impl AssetSources {
             source.gate_on_processor(processor_data.clone());
         }
     }
+
+    /// This will cause the [`AssetReader`]s of all sources to read the variants of assets matching the
+    /// scale of `variants`. See [`ScaleVariants`].
+    pub fn select_scale_variants(&mut self, variants: &ScaleVariants) {
+        for source in self.iter_mut() {
+            source.select_scale_variants(variants);
+        }
+    }
 }
 
 /// An error returned when an [`AssetSource`] does not exist for a given id.
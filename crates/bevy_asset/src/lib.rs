@@ -6,6 +6,7 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
+pub mod common_conditions;
 pub mod io;
 pub mod meta;
 pub mod processor;
@@ -18,12 +19,17 @@ pub mod prelude {
         Asset, AssetApp, AssetEvent, AssetId, AssetMode, AssetPlugin, AssetServer, Assets,
         DirectAssetAccessExt, Handle, UntypedHandle,
     };
+
+    #[cfg(feature = "bevy_state")]
+    #[doc(hidden)]
+    pub use crate::StateAssetApp;
 }
 
 mod assets;
 mod direct_access_ext;
 mod event;
 mod folder;
+mod group;
 mod handle;
 mod id;
 mod loader;
@@ -31,6 +37,8 @@ mod loader_builders;
 mod path;
 mod reflect;
 mod server;
+#[cfg(feature = "bevy_state")]
+mod state;
 
 pub use assets::*;
 pub use bevy_asset_macros::Asset;
@@ -38,6 +46,7 @@ pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
 pub use futures_lite::{AsyncReadExt, AsyncWriteExt};
+pub use group::*;
 pub use handle::*;
 pub use id::*;
 pub use loader::*;
@@ -47,12 +56,17 @@ pub use loader_builders::{
 pub use path::*;
 pub use reflect::*;
 pub use server::*;
+#[cfg(feature = "bevy_state")]
+pub use state::*;
 
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy assets.
 pub use ron;
 
 use crate::{
-    io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
+    io::{
+        embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId,
+        AssetSources, ScaleVariants,
+    },
     processor::{AssetProcessor, Process},
 };
 use bevy_app::{App, Last, Plugin, PreUpdate};
@@ -95,6 +109,9 @@ pub struct AssetPlugin {
     pub mode: AssetMode,
     /// How/If asset meta files should be checked.
     pub meta_check: AssetMetaCheck,
+    /// If set, assets are read from their `@2x`, `@3x`, ... variants up to this scale, when the scale
+    /// factor of the primary window is high enough and the variant exists. See [`ScaleVariants`](io::ScaleVariants).
+    pub max_scale_variant: Option<u32>,
 }
 
 #[derive(Debug)]
@@ -142,6 +159,7 @@ impl Default for AssetPlugin {
             processed_file_path: Self::DEFAULT_PROCESSED_FILE_PATH.to_string(),
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
+            max_scale_variant: None,
         }
     }
 }
@@ -151,6 +169,14 @@ impl AssetPlugin {
     /// NOTE: this is in the Default sub-folder to make this forward compatible with "import profiles"
     /// and to allow us to put the "processor transaction log" at `imported_assets/log`
     const DEFAULT_PROCESSED_FILE_PATH: &'static str = "imported_assets/Default";
+
+    fn select_scale_variants(&self, app: &mut App, sources: &mut AssetSources) {
+        if let Some(max_scale) = self.max_scale_variant {
+            let variants = ScaleVariants::new(max_scale);
+            sources.select_scale_variants(&variants);
+            app.insert_resource(variants);
+        }
+    }
 }
 
 impl Plugin for AssetPlugin {
@@ -175,7 +201,8 @@ impl Plugin for AssetPlugin {
             match self.mode {
                 AssetMode::Unprocessed => {
                     let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
-                    let sources = builders.build_sources(watch, false);
+                    let mut sources = builders.build_sources(watch, false);
+                    self.select_scale_variants(app, &mut sources);
 
                     app.insert_resource(AssetServer::new_with_meta_check(
                         sources,
@@ -191,6 +218,7 @@ impl Plugin for AssetPlugin {
                         let processor = AssetProcessor::new(&mut builders);
                         let mut sources = builders.build_sources(false, watch);
                         sources.gate_on_processor(processor.data.clone());
+                        self.select_scale_variants(app, &mut sources);
                         // the main asset server shares loaders with the processor asset server
                         app.insert_resource(AssetServer::new_with_loaders(
                             sources,
@@ -205,7 +233,8 @@ impl Plugin for AssetPlugin {
                     #[cfg(not(feature = "asset_processor"))]
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
-                        let sources = builders.build_sources(false, watch);
+                        let mut sources = builders.build_sources(false, watch);
+                        self.select_scale_variants(app, &mut sources);
                         app.insert_resource(AssetServer::new_with_meta_check(
                             sources,
                             AssetServerMode::Processed,
@@ -220,9 +249,17 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
+            .init_resource::<AssetGroups>()
             .add_event::<UntypedAssetLoadFailedEvent>()
+            .add_event::<AssetGroupEvent>()
             .configure_sets(PreUpdate, TrackAssets.after(handle_internal_asset_events))
-            .add_systems(PreUpdate, handle_internal_asset_events)
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_internal_asset_events,
+                    track_asset_groups.in_set(TrackAssets),
+                ),
+            )
             .register_type::<AssetPath>();
     }
 }
@@ -437,7 +474,7 @@ pub struct AssetEvents;
 #[cfg(test)]
 mod tests {
     use crate::{
-        self as bevy_asset,
+        self as bevy_asset, common_conditions,
         folder::LoadedFolder,
         handle::Handle,
         io::{
@@ -446,9 +483,9 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, DependencyLoadState, LoadState,
-        RecursiveDependencyLoadState,
+        Asset, AssetApp, AssetEvent, AssetGroup, AssetGroupEvent, AssetGroups, AssetId,
+        AssetLoadError, AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetServer, Assets,
+        DependencyLoadState, LoadState, RecursiveDependencyLoadState,
     };
     use bevy_app::{App, Update};
     use bevy_core::TaskPoolPlugin;
@@ -1115,6 +1152,45 @@ mod tests {
     embedded_dependencies: [],
     sub_texts: [],
 )"#;
+
+    #[test]
+    fn assets_loaded_condition_fires_once() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        #[derive(Resource, Default)]
+        struct Runs(usize);
+
+        let dir = Dir::default();
+        let path = "a.cool.ron";
+        dir.insert_asset_text(Path::new(path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<Runs>()
+            .register_asset_loader(CoolTextLoader);
+        let handle: Handle<CoolText> = app.world().resource::<AssetServer>().load(path);
+        app.add_systems(
+            Update,
+            (|mut runs: ResMut<Runs>| runs.0 += 1)
+                .run_if(common_conditions::assets_loaded(&[handle.untyped()])),
+        );
+
+        app.update();
+        assert_eq!(app.world().resource::<Runs>().0, 0);
+
+        gate_opener.open(path);
+        run_app_until(&mut app, |world| {
+            (world.resource::<Runs>().0 == 1).then_some(())
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Runs>().0, 1);
+    }
+
     #[test]
     fn keep_gotten_strong_handles() {
         let dir = Dir::default();
@@ -1472,6 +1548,156 @@ mod tests {
         });
     }
 
+    #[test]
+    fn asset_group_events() {
+        #[derive(Resource, Default)]
+        struct GroupEvents(Vec<AssetGroupEvent>);
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.cool.ron"), SIMPLE_TEXT);
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader)
+        .init_resource::<GroupEvents>()
+        .add_systems(
+            Update,
+            |mut reader: EventReader<AssetGroupEvent>, mut events: ResMut<GroupEvents>| {
+                events.0.extend(reader.read().cloned());
+            },
+        );
+
+        let server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = server.load("a.cool.ron");
+        let missing: Handle<CoolText> = server.load("missing.cool.ron");
+        let mut groups = app.world_mut().resource_mut::<AssetGroups>();
+        let loaded = groups.add(AssetGroup::new().with(a.clone()));
+        let failed = groups.add([a, missing.clone()].into_iter().collect());
+
+        run_app_until(&mut app, |world| {
+            (world.resource::<GroupEvents>().0.len() == 2).then_some(())
+        });
+        let mut events = app.world_mut().resource_mut::<GroupEvents>();
+        events.0.sort_by_key(AssetGroupEvent::id);
+        assert_eq!(
+            events.0,
+            [
+                AssetGroupEvent::Loaded { id: loaded },
+                AssetGroupEvent::Failed {
+                    id: failed,
+                    failed: vec![missing.untyped()],
+                },
+            ]
+        );
+        assert!(app.world().resource::<AssetGroups>().get(failed).is_none());
+    }
+
+    #[cfg(feature = "bevy_state")]
+    #[test]
+    fn state_assets() {
+        use crate::{
+            common_conditions::assets_ready_for, StateAssetApp, StateAssets, StateAssetsFailed,
+        };
+        use bevy_app::Last;
+        use bevy_ecs::system::RunSystemOnce;
+        use bevy_state::{app::StatesPlugin, prelude::*};
+
+        #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+        enum Level {
+            #[default]
+            Menu,
+            Level1,
+            Level2,
+        }
+
+        #[derive(Resource, Default)]
+        struct Failures(Vec<StateAssetsFailed<Level>>);
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.cool.ron"), SIMPLE_TEXT);
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader)
+        .init_state::<Level>()
+        .load_on_enter(Level::Level1, ["a.cool.ron"])
+        .load_on_enter(Level::Level2, ["a.cool.ron", "missing.cool.ron"])
+        .unload_on_exit(Level::Level2)
+        .init_resource::<Failures>()
+        .add_systems(
+            Update,
+            |mut reader: EventReader<StateAssetsFailed<Level>>, mut failures: ResMut<Failures>| {
+                failures.0.extend(reader.read().cloned());
+            },
+        );
+
+        let is_ready =
+            |world: &mut World, level: Level| world.run_system_once(assets_ready_for(level));
+        let set_level = |app: &mut App, level: Level| {
+            app.world_mut()
+                .resource_mut::<NextState<Level>>()
+                .set(level);
+        };
+
+        // The assets start loading as soon as the transition is scheduled
+        set_level(&mut app, Level::Level1);
+        app.world_mut().run_schedule(Last);
+        let state_assets = app.world().resource::<StateAssets<Level>>();
+        assert_eq!(state_assets.handles(&Level::Level1).len(), 1);
+        assert!(state_assets.handles(&Level::Level2).is_empty());
+
+        run_app_until(&mut app, |world| {
+            is_ready(world, Level::Level1).then_some(())
+        });
+
+        set_level(&mut app, Level::Level2);
+        run_app_until(&mut app, |world| {
+            (!world.resource::<Failures>().0.is_empty()).then_some(())
+        });
+        assert_eq!(
+            app.world().resource::<Failures>().0,
+            [StateAssetsFailed {
+                state: Level::Level2,
+                failed: vec![AssetPath::from("missing.cool.ron")],
+            }]
+        );
+        assert!(!is_ready(app.world_mut(), Level::Level2));
+
+        // The assets of the first level were kept loaded, so it's ready as soon as it's entered
+        set_level(&mut app, Level::Level1);
+        app.update();
+        assert_eq!(app.world().resource::<State<Level>>().get(), &Level::Level1);
+        assert!(is_ready(app.world_mut(), Level::Level1));
+        assert!(app
+            .world()
+            .resource::<StateAssets<Level>>()
+            .handles(&Level::Level2)
+            .is_empty());
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
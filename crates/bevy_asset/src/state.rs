@@ -0,0 +1,261 @@
+use crate::{
+    AssetGroup, AssetGroupEvent, AssetGroupId, AssetGroups, AssetPath, AssetServer, TrackAssets,
+    UntypedHandle,
+};
+use bevy_app::{App, Last, PreUpdate};
+use bevy_ecs::{
+    event::{Event, EventReader, EventWriter},
+    schedule::IntoSystemConfigs,
+    system::{Res, ResMut, Resource},
+};
+use bevy_state::state::{FreelyMutableState, NextState, OnEnter, OnExit, States};
+use bevy_utils::HashMap;
+
+/// Adds methods to load the assets of a [`States`] when it's entered.
+pub trait StateAssetApp {
+    /// Loads the assets at `paths` for `state`, keeping them loaded once it's exited.
+    ///
+    /// The assets start loading as soon as a transition to `state` is scheduled with
+    /// [`NextState`], or when `state` is entered otherwise. Use
+    /// [`assets_ready_for`](crate::common_conditions::assets_ready_for) to run systems once they
+    /// are loaded, and read [`StateAssetsFailed`] to know which of them failed to load.
+    ///
+    /// Calling this again for the same state adds to its assets.
+    ///
+    /// ```no_run
+    /// # use bevy_app::{App, Update};
+    /// # use bevy_asset::{common_conditions::assets_ready_for, StateAssetApp};
+    /// # use bevy_ecs::schedule::{Condition, IntoSystemConfigs};
+    /// # use bevy_state::prelude::*;
+    /// #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+    /// enum Level {
+    ///     #[default]
+    ///     Menu,
+    ///     Level1,
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// app.init_state::<Level>()
+    ///     .load_on_enter(Level::Level1, ["maps/level1.glb", "music/level1.ogg"])
+    ///     .add_systems(
+    ///         Update,
+    ///         play_level.run_if(in_state(Level::Level1).and_then(assets_ready_for(Level::Level1))),
+    ///     );
+    /// # fn play_level() {}
+    /// ```
+    fn load_on_enter<S: FreelyMutableState>(
+        &mut self,
+        state: S,
+        paths: impl IntoIterator<Item = impl Into<AssetPath<'static>>>,
+    ) -> &mut Self;
+
+    /// Drops the handles to the assets of `state`, added with
+    /// [`load_on_enter`](StateAssetApp::load_on_enter), when it's exited.
+    ///
+    /// The assets are then unloaded unless they are still used elsewhere, in which case entering
+    /// `state` again doesn't have to wait for them.
+    fn unload_on_exit<S: FreelyMutableState>(&mut self, state: S) -> &mut Self;
+}
+
+impl StateAssetApp for App {
+    fn load_on_enter<S: FreelyMutableState>(
+        &mut self,
+        state: S,
+        paths: impl IntoIterator<Item = impl Into<AssetPath<'static>>>,
+    ) -> &mut Self {
+        add_state_assets(self, state)
+            .paths
+            .extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    fn unload_on_exit<S: FreelyMutableState>(&mut self, state: S) -> &mut Self {
+        add_state_assets(self, state).unload_on_exit = true;
+        self
+    }
+}
+
+/// Returns the assets of `state`, adding the systems loading and unloading them the first time.
+fn add_state_assets<S: FreelyMutableState>(app: &mut App, state: S) -> &mut AssetsOfState {
+    if !app.world().contains_resource::<StateAssets<S>>() {
+        app.init_resource::<StateAssets<S>>()
+            .add_event::<StateAssetsFailed<S>>()
+            .add_systems(
+                PreUpdate,
+                report_failed_state_assets::<S>.after(TrackAssets),
+            )
+            .add_systems(Last, load_scheduled_state_assets::<S>);
+    }
+
+    let new_state = !app
+        .world()
+        .resource::<StateAssets<S>>()
+        .states
+        .contains_key(&state);
+    if new_state {
+        let entered = state.clone();
+        let exited = state.clone();
+        app.add_systems(
+            OnEnter(state.clone()),
+            move |server: Res<AssetServer>,
+                  mut state_assets: ResMut<StateAssets<S>>,
+                  mut groups: ResMut<AssetGroups>| {
+                state_assets.load(&entered, &server, &mut groups);
+            },
+        )
+        .add_systems(
+            OnExit(state.clone()),
+            move |mut state_assets: ResMut<StateAssets<S>>, mut groups: ResMut<AssetGroups>| {
+                state_assets.exit(&exited, &mut groups);
+            },
+        );
+    }
+
+    app.world_mut()
+        .resource_mut::<StateAssets<S>>()
+        .into_inner()
+        .states
+        .entry(state)
+        .or_default()
+}
+
+/// Sent when some assets of a state, added with [`StateAssetApp::load_on_enter`], failed to
+/// load.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct StateAssetsFailed<S: States> {
+    /// The state the assets were loaded for.
+    pub state: S,
+    /// The paths of the assets that failed to load, or whose dependencies failed to load.
+    pub failed: Vec<AssetPath<'static>>,
+}
+
+/// The assets loaded for each state `S`, added with [`StateAssetApp::load_on_enter`].
+#[derive(Resource)]
+pub struct StateAssets<S: States> {
+    states: HashMap<S, AssetsOfState>,
+}
+
+impl<S: States> Default for StateAssets<S> {
+    fn default() -> Self {
+        Self {
+            states: HashMap::default(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct AssetsOfState {
+    paths: Vec<AssetPath<'static>>,
+    unload_on_exit: bool,
+    /// The handles to the assets at `paths`, in the same order, while they're loaded.
+    handles: Vec<UntypedHandle>,
+    /// The group waited for to report failures, while the assets are loading.
+    group: Option<AssetGroupId>,
+}
+
+impl<S: States> StateAssets<S> {
+    /// The handles to the assets of `state` while they're loading or loaded, in the order of their
+    /// paths.
+    ///
+    /// Assets still loading are [`LoadedUntypedAsset`](crate::LoadedUntypedAsset)s, which give
+    /// the handle to the asset once it's loaded.
+    pub fn handles(&self, state: &S) -> &[UntypedHandle] {
+        self.states
+            .get(state)
+            .map_or(&[], |assets| assets.handles.as_slice())
+    }
+
+    /// Returns `true` if all the assets of `state`, and their dependencies, are loaded.
+    ///
+    /// States without any asset are always ready.
+    pub fn is_ready(&self, state: &S, server: &AssetServer) -> bool {
+        let Some(assets) = self.states.get(state) else {
+            return true;
+        };
+        assets.handles.len() == assets.paths.len()
+            && assets
+                .handles
+                .iter()
+                .all(|handle| server.is_loaded_with_dependencies(handle.id()))
+    }
+
+    /// Starts loading the assets of `state`, unless they already are.
+    fn load(&mut self, state: &S, server: &AssetServer, groups: &mut AssetGroups) {
+        let Some(assets) = self.states.get_mut(state) else {
+            return;
+        };
+        if assets.handles.len() == assets.paths.len() {
+            return;
+        }
+        // Assets still alive, such as the ones of a state entered again, are reused as they are
+        assets.handles = assets
+            .paths
+            .iter()
+            .map(|path| {
+                server
+                    .get_handle_untyped(path.clone())
+                    .unwrap_or_else(|| server.load_untyped(path.clone()).untyped())
+            })
+            .collect();
+        if let Some(group) = assets.group.take() {
+            groups.remove(group);
+        }
+        assets.group = Some(groups.add(assets.handles.iter().cloned().collect::<AssetGroup>()));
+    }
+
+    fn exit(&mut self, state: &S, groups: &mut AssetGroups) {
+        let Some(assets) = self.states.get_mut(state) else {
+            return;
+        };
+        if !assets.unload_on_exit {
+            return;
+        }
+        assets.handles.clear();
+        if let Some(group) = assets.group.take() {
+            groups.remove(group);
+        }
+    }
+}
+
+/// Starts loading the assets of the state a transition is scheduled to, before the transition.
+fn load_scheduled_state_assets<S: FreelyMutableState>(
+    next_state: Option<Res<NextState<S>>>,
+    server: Res<AssetServer>,
+    mut state_assets: ResMut<StateAssets<S>>,
+    mut groups: ResMut<AssetGroups>,
+) {
+    if let Some(NextState::Pending(state)) = next_state.as_deref() {
+        state_assets.load(state, &server, &mut groups);
+    }
+}
+
+/// Sends a [`StateAssetsFailed`] for each state whose assets finished loading with failures.
+fn report_failed_state_assets<S: FreelyMutableState>(
+    mut group_events: EventReader<AssetGroupEvent>,
+    mut state_assets: ResMut<StateAssets<S>>,
+    mut events: EventWriter<StateAssetsFailed<S>>,
+) {
+    for event in group_events.read() {
+        let Some((state, assets)) = state_assets
+            .states
+            .iter_mut()
+            .find(|(_, assets)| assets.group == Some(event.id()))
+        else {
+            continue;
+        };
+        assets.group = None;
+        let AssetGroupEvent::Failed { failed, .. } = event else {
+            continue;
+        };
+        events.send(StateAssetsFailed {
+            state: state.clone(),
+            failed: assets
+                .handles
+                .iter()
+                .zip(&assets.paths)
+                .filter(|(handle, _)| failed.contains(handle))
+                .map(|(_, path)| path.clone())
+                .collect(),
+        });
+    }
+}
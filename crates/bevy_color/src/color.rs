@@ -794,6 +794,18 @@ impl Mix for Color {
     }
 }
 
+/// Interpolates the colors in the [`Oklaba`] color space, whatever their color spaces, so that
+/// easing from one color to another looks perceptually uniform. The interpolated color is an
+/// [`Color::Oklaba`].
+///
+/// This is what [`bevy_math::easing::ease`] and [`EasingCurve`](bevy_math::easing::EasingCurve)
+/// interpolate colors with.
+impl bevy_math::StableInterpolate for Color {
+    fn interpolate_stable(&self, other: &Self, t: f32) -> Self {
+        Oklaba::from(*self).mix(&Oklaba::from(*other), t).into()
+    }
+}
+
 impl EuclideanDistance for Color {
     fn distance_squared(&self, other: &Self) -> f32 {
         match self {
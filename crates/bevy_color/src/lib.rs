@@ -72,6 +72,9 @@
 //! types in this crate. This is useful when you need to store a color in a data structure
 //! that can't be generic over the color type.
 //!
+//! [`Color`] also has perceptual adjustments made in Oklch, such as [`Color::lighten`] and
+//! [`Color::with_contrast_ratio_against`], and [`Palette`] generates harmonious colors from a base.
+//!
 //! Color types that are either physically or perceptually linear also implement `Add<Self>`, `Sub<Self>`, `Mul<f32>` and `Div<f32>`
 //! allowing you to use them with splines.
 //!
@@ -103,6 +106,7 @@ mod linear_rgba;
 mod oklaba;
 mod oklcha;
 pub mod palettes;
+mod perceptual;
 mod srgba;
 #[cfg(test)]
 mod test_colors;
@@ -137,6 +141,7 @@ pub use lcha::*;
 pub use linear_rgba::*;
 pub use oklaba::*;
 pub use oklcha::*;
+pub use perceptual::*;
 pub use srgba::*;
 pub use xyza::*;
 
@@ -0,0 +1,458 @@
+//! Perceptual color adjustments and palettes, computed in Oklch.
+
+use std::ops::Deref;
+
+use crate::{Color, LinearRgba, Luminance, Oklcha};
+
+/// How far outside of `0.0..=1.0` the linear RGB channels of a color can be, to account for the
+/// rounding errors of the conversions, while still being in the sRGB gamut.
+const SRGB_GAMUT_EPSILON: f32 = 1e-5;
+
+/// The Oklch chroma below which a color is considered a gray, whose hue is meaningless.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// The number of bisection steps used to search for a chroma or a lightness.
+const SEARCH_STEPS: usize = 24;
+
+/// The hue difference between neighboring colors of [`Palette::analogous`], in degrees.
+const ANALOGOUS_HUE_STEP: f32 = 30.0;
+
+/// The lightness difference between the shades of [`Palette::complementary`].
+const SHADE_LIGHTNESS_STEP: f32 = 0.1;
+
+impl Oklcha {
+    /// Returns `true` if this color can be displayed in sRGB, that is, if its linear RGB channels
+    /// are all between `0.0` and `1.0`.
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        let LinearRgba {
+            red, green, blue, ..
+        } = (*self).into();
+        [red, green, blue]
+            .iter()
+            .all(|channel| (-SRGB_GAMUT_EPSILON..=1.0 + SRGB_GAMUT_EPSILON).contains(channel))
+    }
+
+    /// Maps this color into the sRGB gamut, by reducing its chroma until it fits.
+    ///
+    /// The lightness, clamped between `0.0` and `1.0`, and the hue are kept, so the color looks
+    /// as close as possible to the original one. Clamping the RGB channels instead would change
+    /// the hue and the lightness of saturated colors, such as turning a bright blue into purple.
+    pub fn map_to_srgb_gamut(&self) -> Self {
+        let color = Self {
+            lightness: self.lightness.clamp(0.0, 1.0),
+            chroma: self.chroma.max(0.0),
+            ..*self
+        };
+        if color.is_in_srgb_gamut() {
+            return color;
+        }
+
+        // Grays are all in the gamut, so there is a chroma in the gamut between them and the color
+        let (mut in_gamut, mut out_of_gamut) = (0.0, color.chroma);
+        for _ in 0..SEARCH_STEPS {
+            let chroma = (in_gamut + out_of_gamut) * 0.5;
+            if color.with_chroma(chroma).is_in_srgb_gamut() {
+                in_gamut = chroma;
+            } else {
+                out_of_gamut = chroma;
+            }
+        }
+        color.with_chroma(in_gamut)
+    }
+}
+
+/// Perceptual adjustments, made in [`Oklch`](Oklcha).
+///
+/// Each adjustment converts the color to [`Oklch`](Oklcha), where the same change of lightness,
+/// chroma or hue looks alike for every color, and converts the result back to the color space
+/// the color was in. The result is [mapped into the sRGB gamut](Oklcha::map_to_srgb_gamut) by
+/// reducing its chroma, so that it can be displayed as it is.
+impl Color {
+    /// Returns this color with its Oklch lightness increased by `amount`, between `0.0` and `1.0`.
+    ///
+    /// ```
+    /// # use bevy_color::Color;
+    /// let gray = Color::srgb(0.5, 0.5, 0.5);
+    /// // The result is in the same color space as the input
+    /// assert!(matches!(gray.lighten(0.1), Color::Srgba(_)));
+    /// ```
+    pub fn lighten(&self, amount: f32) -> Self {
+        self.map_oklch(|color| color.with_lightness(color.lightness + amount))
+    }
+
+    /// Returns this color with its Oklch lightness decreased by `amount`, between `0.0` and `1.0`.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns this color with its Oklch chroma increased by `amount`, or decreased for a
+    /// negative `amount`.
+    ///
+    /// The chroma of colors in the sRGB gamut is at most about `0.32`, and much less for some
+    /// hues and lightnesses.
+    pub fn saturate(&self, amount: f32) -> Self {
+        self.map_oklch(|color| color.with_chroma(color.chroma + amount))
+    }
+
+    /// Returns this color with its Oklch hue rotated by `degrees`.
+    pub fn shift_hue(&self, degrees: f32) -> Self {
+        self.map_oklch(|color| Oklcha {
+            hue: (color.hue + degrees).rem_euclid(360.0),
+            ..color
+        })
+    }
+
+    /// Returns the [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)
+    /// between this color and `other`, from `1.0` for the same luminance to `21.0` for black and
+    /// white. The alpha of both colors is ignored.
+    pub fn contrast_ratio(&self, other: Color) -> f32 {
+        contrast_ratio(relative_luminance(*self), relative_luminance(other))
+    }
+
+    /// Returns this color with the smallest change of its Oklch lightness that gives it at least
+    /// a [contrast ratio](Self::contrast_ratio) of `target` against `background`.
+    ///
+    /// The color is made lighter if it is lighter than the background, and darker otherwise,
+    /// unless the target can only be reached the other way. If it can't be reached at all, the
+    /// lightness with the highest contrast is used. WCAG recommends a contrast ratio of at least
+    /// `4.5` for text.
+    pub fn with_contrast_ratio_against(&self, background: Color, target: f32) -> Self {
+        if self.contrast_ratio(background) >= target {
+            return *self;
+        }
+
+        let color = Oklcha::from(*self);
+        let background = relative_luminance(background);
+        let contrast = |lightness: f32| {
+            let color = color.with_lightness(lightness).map_to_srgb_gamut();
+            (color, contrast_ratio(relative_luminance(color), background))
+        };
+        let reach = |end: f32| {
+            if contrast(end).1 < target {
+                return None;
+            }
+            let (mut failing, mut reaching) = (color.lightness, end);
+            for _ in 0..SEARCH_STEPS {
+                let lightness = (failing + reaching) * 0.5;
+                if contrast(lightness).1 >= target {
+                    reaching = lightness;
+                } else {
+                    failing = lightness;
+                }
+            }
+            Some(contrast(reaching).0)
+        };
+
+        let ends = if relative_luminance(*self) >= background {
+            [1.0, 0.0]
+        } else {
+            [0.0, 1.0]
+        };
+        let result = ends.into_iter().find_map(reach).unwrap_or_else(|| {
+            let (lighter, darker) = (contrast(1.0), contrast(0.0));
+            if lighter.1 >= darker.1 {
+                lighter.0
+            } else {
+                darker.0
+            }
+        });
+        self.with_oklch(result)
+    }
+
+    /// Applies `f` to this color in Oklch, and converts the result back to the color space of
+    /// this color, in the sRGB gamut.
+    fn map_oklch(&self, f: impl FnOnce(Oklcha) -> Oklcha) -> Self {
+        self.with_oklch(f(Oklcha::from(*self)).map_to_srgb_gamut())
+    }
+
+    /// Converts `color` to the color space of this color.
+    ///
+    /// Grays and black keep the hue of this color, since the hue given by the conversion of a
+    /// color without chroma or lightness is arbitrary.
+    fn with_oklch(&self, color: Oklcha) -> Self {
+        let mut result = match self {
+            Color::Srgba(_) => Color::Srgba(color.into()),
+            Color::LinearRgba(_) => Color::LinearRgba(color.into()),
+            Color::Hsla(_) => Color::Hsla(color.into()),
+            Color::Hsva(_) => Color::Hsva(color.into()),
+            Color::Hwba(_) => Color::Hwba(color.into()),
+            Color::Laba(_) => Color::Laba(color.into()),
+            Color::Lcha(_) => Color::Lcha(color.into()),
+            Color::Oklaba(_) => Color::Oklaba(color.into()),
+            Color::Oklcha(_) => Color::Oklcha(color),
+            Color::Xyza(_) => Color::Xyza(color.into()),
+        };
+        if color.chroma <= ACHROMATIC_CHROMA || color.lightness <= 0.0 {
+            match (&mut result, self) {
+                (Color::Hsla(result), Color::Hsla(source)) => result.hue = source.hue,
+                (Color::Hsva(result), Color::Hsva(source)) => result.hue = source.hue,
+                (Color::Hwba(result), Color::Hwba(source)) => result.hue = source.hue,
+                (Color::Lcha(result), Color::Lcha(source)) => result.hue = source.hue,
+                (Color::Oklcha(result), Color::Oklcha(source)) => result.hue = source.hue,
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+/// The relative luminance of a color, as defined by WCAG.
+fn relative_luminance(color: impl Into<LinearRgba>) -> f32 {
+    color.into().luminance().clamp(0.0, 1.0)
+}
+
+fn contrast_ratio(a: f32, b: f32) -> f32 {
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// A set of colors in harmony with a base color, generated in [`Oklch`](Oklcha).
+///
+/// The colors are in the color space of the base color, and in the sRGB gamut.
+///
+/// ```
+/// # use bevy_color::{color_difference::EuclideanDistance, Color, Palette};
+/// let palette = Palette::analogous(Color::srgb(0.2, 0.4, 0.8), 3);
+/// assert_eq!(palette.len(), 3);
+/// // The base color is in the middle
+/// assert!(palette[1].to_srgba().distance(&Color::srgb(0.2, 0.4, 0.8).to_srgba()) < 1e-3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Palette(pub Vec<Color>);
+
+impl Palette {
+    /// Creates a palette of `count` colors with hues around the hue of `base`, 30 degrees apart,
+    /// from the lowest hue to the highest.
+    ///
+    /// The hues are centered on the hue of `base`, so `base` is in the middle of the palette for
+    /// an odd `count`.
+    pub fn analogous(base: Color, count: usize) -> Self {
+        let middle = count.saturating_sub(1) as f32 * 0.5;
+        let colors = (0..count)
+            .map(|index| base.shift_hue((index as f32 - middle) * ANALOGOUS_HUE_STEP))
+            .collect();
+        Self(colors)
+    }
+
+    /// Creates a palette of `count` colors alternating between the hue of `base` and its
+    /// complement, the opposite hue.
+    ///
+    /// The palette starts with `base` and its complement, and each further pair is lighter or
+    /// darker than the first one, alternately and by steps of `0.1` of Oklch lightness.
+    pub fn complementary(base: Color, count: usize) -> Self {
+        let colors = (0..count)
+            .map(|index| {
+                let shade = index / 2;
+                let sign = if shade % 2 == 1 { 1.0 } else { -1.0 };
+                let lightness = sign * shade.div_ceil(2) as f32 * SHADE_LIGHTNESS_STEP;
+                let hue = if index % 2 == 1 { 180.0 } else { 0.0 };
+                base.map_oklch(|color| Oklcha {
+                    lightness: color.lightness + lightness,
+                    hue: (color.hue + hue).rem_euclid(360.0),
+                    ..color
+                })
+            })
+            .collect();
+        Self(colors)
+    }
+}
+
+impl Deref for Palette {
+    type Target = [Color];
+
+    fn deref(&self) -> &[Color] {
+        &self.0
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = Color;
+    type IntoIter = std::vec::IntoIter<Color>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        color_difference::EuclideanDistance, test_colors::TEST_COLORS, testing::assert_approx_eq,
+        Srgba,
+    };
+
+    // The reference values were computed in double precision, with the same Oklab matrices
+
+    /// The difference between two hues in degrees, across the wrap-around at 360 degrees.
+    fn hue_difference(a: f32, b: f32) -> f32 {
+        let difference = (a - b).rem_euclid(360.0);
+        difference.min(360.0 - difference)
+    }
+
+    #[test]
+    fn lighten_gray() {
+        let lighter = Color::srgb(0.5, 0.5, 0.5).lighten(0.1);
+        let Color::Srgba(lighter) = lighter else {
+            panic!("the color space changed: {lighter:?}");
+        };
+        assert_approx_eq!(lighter.red, 0.618306, 1e-4);
+        assert_approx_eq!(lighter.green, 0.618306, 1e-4);
+        assert_approx_eq!(lighter.blue, 0.618306, 1e-4);
+
+        let darker = Color::hsl(0.0, 0.0, 0.5).lighten(0.1).darken(0.1);
+        assert!(matches!(darker, Color::Hsla(_)));
+        assert_approx_eq!(darker.to_srgba().red, 0.5, 1e-4);
+
+        // The lightness stops at white
+        let white = Color::srgb(0.9, 0.2, 0.2).lighten(2.0).to_srgba();
+        assert!(white.distance(&Srgba::WHITE) < 1e-3);
+    }
+
+    #[test]
+    fn shift_hue_reduces_chroma() {
+        // The complement of sRGB red at the same chroma and lightness is out of the gamut
+        let shifted = Color::srgb(1.0, 0.0, 0.0).shift_hue(180.0);
+        let Color::Srgba(srgba) = shifted else {
+            panic!("the color space changed: {shifted:?}");
+        };
+        assert_approx_eq!(srgba.red, 0.0, 1e-3);
+        assert_approx_eq!(srgba.green, 0.603471, 1e-3);
+        assert_approx_eq!(srgba.blue, 0.673691, 1e-3);
+
+        let oklch = Oklcha::from(shifted);
+        assert_approx_eq!(oklch.lightness, 0.627955, 1e-3);
+        assert_approx_eq!(oklch.hue, 209.233885, 0.5);
+    }
+
+    #[test]
+    fn gamut_mapping_keeps_lightness_and_hue() {
+        let mapped = Oklcha::lch(0.7, 0.4, 30.0).map_to_srgb_gamut();
+        assert!(mapped.is_in_srgb_gamut());
+        assert_approx_eq!(mapped.lightness, 0.7, 1e-6);
+        assert_approx_eq!(hue_difference(mapped.hue, 30.0), 0.0, 1e-4);
+        assert_approx_eq!(mapped.chroma, 0.191539, 1e-4);
+
+        // Clamping the channels instead shifts the hue
+        let linear = LinearRgba::from(Oklcha::lch(0.7, 0.4, 30.0));
+        let clamped = Oklcha::from(LinearRgba::rgb(
+            linear.red.clamp(0.0, 1.0),
+            linear.green.clamp(0.0, 1.0),
+            linear.blue.clamp(0.0, 1.0),
+        ));
+        assert!(hue_difference(clamped.hue, 30.0) > 1.0);
+
+        assert_eq!(
+            Oklcha::lch(1.5, 0.2, 100.0).map_to_srgb_gamut().lightness,
+            1.0
+        );
+    }
+
+    #[test]
+    fn adjustments_are_stable() {
+        for color in TEST_COLORS.iter() {
+            for input in [
+                Color::from(color.rgb),
+                Color::from(color.linear_rgb),
+                Color::from(color.hsl),
+                Color::from(color.oklch),
+            ] {
+                for output in [
+                    input.lighten(0.0),
+                    input.saturate(0.0),
+                    input.shift_hue(0.0),
+                    input.shift_hue(360.0),
+                ] {
+                    assert_eq!(
+                        std::mem::discriminant(&input),
+                        std::mem::discriminant(&output)
+                    );
+                    let hues = match (input, output) {
+                        (Color::Hsla(input), Color::Hsla(output)) => Some((input.hue, output.hue)),
+                        (Color::Oklcha(input), Color::Oklcha(output)) => {
+                            Some((input.hue, output.hue))
+                        }
+                        _ => None,
+                    };
+                    if let Some((input_hue, output_hue)) = hues {
+                        assert!(
+                            hue_difference(input_hue, output_hue) < 0.5,
+                            "{}: the hue {input_hue} became {output_hue}",
+                            color.name
+                        );
+                    }
+                    let (input, output) = (input.to_srgba(), output.to_srgba());
+                    assert!(
+                        input.distance(&output) < 1e-3,
+                        "{}: {input:?} became {output:?}",
+                        color.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let white = Color::WHITE;
+        assert_approx_eq!(Color::BLACK.contrast_ratio(white), 21.0, 1e-4);
+        assert_approx_eq!(
+            Color::srgb_u8(0x76, 0x76, 0x76).contrast_ratio(white),
+            4.542225,
+            1e-3
+        );
+
+        // Light gray text is darkened just enough to be readable on white
+        let text = Color::srgb(0.7, 0.7, 0.7).with_contrast_ratio_against(white, 4.5);
+        assert!(matches!(text, Color::Srgba(_)));
+        let ratio = text.contrast_ratio(white);
+        assert!((4.499..4.51).contains(&ratio), "{ratio}");
+
+        // Dark text is lightened against a dark background
+        let background = Color::srgb(0.1, 0.1, 0.2);
+        let text = Color::srgb(0.2, 0.2, 0.4).with_contrast_ratio_against(background, 7.0);
+        assert!(text.contrast_ratio(background) >= 7.0);
+        assert!(text.luminance() > Color::srgb(0.2, 0.2, 0.4).luminance());
+
+        // A color reaching the target is left as is, and an unreachable target gives the most
+        // contrasted color
+        let black = Color::srgb(0.0, 0.0, 0.0);
+        assert_eq!(black.with_contrast_ratio_against(white, 4.5), black);
+        let gray = Color::srgb(0.5, 0.5, 0.5)
+            .with_contrast_ratio_against(Color::srgb(0.5, 0.5, 0.5), 30.0);
+        assert!(gray.to_srgba().distance(&Srgba::BLACK) < 1e-3);
+    }
+
+    #[test]
+    fn palettes() {
+        let base = Color::oklch(0.6, 0.1, 100.0);
+        let analogous = Palette::analogous(base, 3);
+        let hues: Vec<f32> = analogous
+            .iter()
+            .map(|color| Oklcha::from(*color).hue)
+            .collect();
+        for (hue, expected) in hues.iter().zip([70.0, 100.0, 130.0]) {
+            assert_approx_eq!(*hue, expected, 1e-3);
+        }
+        assert!(analogous
+            .iter()
+            .all(|color| matches!(color, Color::Oklcha(_))));
+
+        let complementary = Palette::complementary(base, 5);
+        let expected = [
+            (0.6, 100.0),
+            (0.6, 280.0),
+            (0.7, 100.0),
+            (0.7, 280.0),
+            (0.5, 100.0),
+        ];
+        for (color, (lightness, hue)) in complementary.into_iter().zip(expected) {
+            let Color::Oklcha(color) = color else {
+                panic!("the color space changed: {color:?}");
+            };
+            assert_approx_eq!(color.lightness, lightness, 1e-5);
+            assert_approx_eq!(color.hue, hue, 1e-3);
+        }
+
+        assert!(Palette::analogous(base, 0).is_empty());
+    }
+}
@@ -10,17 +10,20 @@
 mod name;
 #[cfg(feature = "serialize")]
 mod serde;
+mod task_component;
 mod task_pool_options;
 
 use bevy_ecs::system::Resource;
 pub use name::*;
+pub use task_component::*;
 pub use task_pool_options::*;
 
 pub mod prelude {
     //! The Bevy Core Prelude.
     #[doc(hidden)]
     pub use crate::{
-        DebugName, FrameCountPlugin, Name, TaskPoolOptions, TaskPoolPlugin, TypeRegistrationPlugin,
+        DebugName, FrameCountPlugin, Name, SpawnTaskExt, TaskAppExt, TaskComponent,
+        TaskPoolOptions, TaskPoolPlugin, TypeRegistrationPlugin,
     };
 }
 
@@ -0,0 +1,286 @@
+use std::{future::Future, pin::Pin};
+
+use bevy_app::{App, PreUpdate};
+use bevy_ecs::{prelude::*, system::EntityCommands};
+use bevy_tasks::{block_on, futures_lite::future::poll_once};
+use bevy_utils::synccell::SyncCell;
+
+type BoxedTask<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type OnComplete<T> = Box<dyn FnOnce(&mut EntityCommands, T) + Send + Sync>;
+
+/// A task stored on an entity, polled every frame by the system added with
+/// [`TaskAppExt::handle_tasks`].
+///
+/// The task is any future, usually a [`Task`](bevy_tasks::Task) running on a task pool. It's only
+/// polled once per frame, so a future that isn't a task is run a step at a time on the thread of
+/// [`poll_tasks`].
+///
+/// When the task completes, this component is removed from its entity and the output of the task
+/// is handled as chosen when creating the component: inserted on the entity, sent as an event,
+/// or triggered on the entity for its observers.
+///
+/// Despawning the entity, or removing this component, drops the task, which cancels it: its future
+/// is dropped by the executor without being polled again.
+///
+/// Tasks are usually spawned with [`SpawnTaskExt::spawn_task`].
+#[derive(Component)]
+pub struct TaskComponent<T: Send + 'static> {
+    task: SyncCell<BoxedTask<T>>,
+    on_complete: Option<OnComplete<T>>,
+}
+
+impl<T: Send + 'static> TaskComponent<T> {
+    /// Creates a [`TaskComponent`] calling `on_complete` with the commands of its entity and the
+    /// output of `task` when it completes.
+    pub fn new(
+        task: impl Future<Output = T> + Send + 'static,
+        on_complete: impl FnOnce(&mut EntityCommands, T) + Send + Sync + 'static,
+    ) -> Self {
+        Self::from_boxed(Box::pin(task), on_complete)
+    }
+
+    fn from_boxed(
+        task: BoxedTask<T>,
+        on_complete: impl FnOnce(&mut EntityCommands, T) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            task: SyncCell::new(task),
+            on_complete: Some(Box::new(on_complete)),
+        }
+    }
+
+    /// Creates a [`TaskComponent`] inserting the output of `task` on its entity when it completes.
+    pub fn insert_on_complete(task: impl Future<Output = T> + Send + 'static) -> Self
+    where
+        T: Bundle,
+    {
+        Self::new(task, insert_output)
+    }
+
+    /// Creates a [`TaskComponent`] sending the output of `task` as an event when it completes.
+    ///
+    /// The event must have been added with [`App::add_event`].
+    pub fn send_on_complete(task: impl Future<Output = T> + Send + 'static) -> Self
+    where
+        T: Event,
+    {
+        Self::new(task, send_output)
+    }
+
+    /// Creates a [`TaskComponent`] triggering the output of `task` on its entity when it
+    /// completes, running the observers watching the entity.
+    pub fn trigger_on_complete(task: impl Future<Output = T> + Send + 'static) -> Self
+    where
+        T: Event,
+    {
+        Self::new(task, trigger_output)
+    }
+}
+
+fn insert_output<T: Bundle>(entity: &mut EntityCommands, output: T) {
+    entity.insert(output);
+}
+
+fn send_output<T: Event>(entity: &mut EntityCommands, output: T) {
+    entity.commands().add(move |world: &mut World| {
+        world.send_event(output);
+    });
+}
+
+fn trigger_output<T: Event>(entity: &mut EntityCommands, output: T) {
+    let target = entity.id();
+    entity.commands().trigger_targets(output, target);
+}
+
+/// Polls the [`TaskComponent<T>`] of every entity, handling the output of the completed tasks.
+///
+/// Added to [`PreUpdate`] by [`TaskAppExt::handle_tasks`], so that the outputs of the tasks
+/// completed at the start of a frame are available in [`Update`](bevy_app::Update).
+pub fn poll_tasks<T: Send + 'static>(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut TaskComponent<T>)>,
+) {
+    for (entity, mut task) in &mut tasks {
+        let Some(output) = block_on(poll_once(task.task.get())) else {
+            continue;
+        };
+        let mut entity = commands.entity(entity);
+        entity.remove::<TaskComponent<T>>();
+        if let Some(on_complete) = task.on_complete.take() {
+            on_complete(&mut entity, output);
+        }
+    }
+}
+
+/// Methods handling [`TaskComponent`] for [`App`].
+pub trait TaskAppExt {
+    /// Polls the tasks of the entities with a [`TaskComponent<T>`] every frame, with
+    /// [`poll_tasks`].
+    fn handle_tasks<T: Send + 'static>(&mut self) -> &mut Self;
+}
+
+impl TaskAppExt for App {
+    fn handle_tasks<T: Send + 'static>(&mut self) -> &mut Self {
+        self.add_systems(PreUpdate, poll_tasks::<T>)
+    }
+}
+
+/// Task-related extension methods for [`Commands`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_core::SpawnTaskExt;
+/// #[derive(Component)]
+/// struct Path(Vec<[i32; 2]>);
+///
+/// fn find_path(mut commands: Commands) {
+///     commands
+///         .spawn_task(async move {
+///             // Expensive computation
+///             Path(vec![[0, 0], [1, 2]])
+///         })
+///         .on_complete_insert();
+/// }
+/// ```
+pub trait SpawnTaskExt {
+    /// Spawns `future` on the [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool), and an
+    /// entity to hold it in a [`TaskComponent`].
+    ///
+    /// The single-threaded task pools, such as on the web, don't run the future in the
+    /// background: it's polled on the main thread by [`poll_tasks`] instead.
+    ///
+    /// How the output of the task is handled is chosen with the returned [`TaskBuilder`].
+    fn spawn_task<T: Send + 'static>(
+        &mut self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> TaskBuilder<'_, T>;
+}
+
+impl SpawnTaskExt for Commands<'_, '_> {
+    fn spawn_task<T: Send + 'static>(
+        &mut self,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> TaskBuilder<'_, T> {
+        #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+        let task = Box::pin(bevy_tasks::AsyncComputeTaskPool::get().spawn(future));
+        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
+        let task = Box::pin(future);
+        TaskBuilder {
+            entity: self.spawn_empty(),
+            task,
+        }
+    }
+}
+
+/// Chooses how the output of a task spawned with [`SpawnTaskExt::spawn_task`] is handled.
+///
+/// Each method inserts the [`TaskComponent`] on the spawned entity, and returns its commands.
+#[must_use = "The task is canceled and its entity left empty unless its output is handled."]
+pub struct TaskBuilder<'a, T: Send + 'static> {
+    entity: EntityCommands<'a>,
+    task: BoxedTask<T>,
+}
+
+impl<'a, T: Send + 'static> TaskBuilder<'a, T> {
+    /// Calls `on_complete` with the commands of the entity and the output of the task when it
+    /// completes. See [`TaskComponent::new`].
+    pub fn on_complete(
+        self,
+        on_complete: impl FnOnce(&mut EntityCommands, T) + Send + Sync + 'static,
+    ) -> EntityCommands<'a> {
+        let Self { mut entity, task } = self;
+        entity.insert(TaskComponent::from_boxed(task, on_complete));
+        entity
+    }
+
+    /// Inserts the output of the task on the entity when it completes.
+    /// See [`TaskComponent::insert_on_complete`].
+    pub fn on_complete_insert(self) -> EntityCommands<'a>
+    where
+        T: Bundle,
+    {
+        self.on_complete(insert_output)
+    }
+
+    /// Sends the output of the task as an event when it completes.
+    /// See [`TaskComponent::send_on_complete`].
+    pub fn on_complete_send(self) -> EntityCommands<'a>
+    where
+        T: Event,
+    {
+        self.on_complete(send_output)
+    }
+
+    /// Triggers the output of the task on the entity when it completes.
+    /// See [`TaskComponent::trigger_on_complete`].
+    pub fn on_complete_trigger(self) -> EntityCommands<'a>
+    where
+        T: Event,
+    {
+        self.on_complete(trigger_output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TaskPoolPlugin;
+    use std::{sync::Arc, time::Duration};
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Output(u32);
+
+    /// Updates `app` until `done` returns `true`, giving up after a while.
+    fn update_until(app: &mut App, done: impl Fn(&mut App) -> bool) {
+        for _ in 0..1000 {
+            app.update();
+            if done(app) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("timed out");
+    }
+
+    #[test]
+    fn inserts_output_on_completion() {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default())
+            .handle_tasks::<Output>();
+        let entity = app
+            .world_mut()
+            .commands()
+            .spawn_task(async { Output(5) })
+            .on_complete_insert()
+            .id();
+        app.world_mut().flush();
+
+        update_until(&mut app, |app| app.world().get::<Output>(entity).is_some());
+        assert_eq!(app.world().get::<Output>(entity), Some(&Output(5)));
+        assert!(app.world().get::<TaskComponent<Output>>(entity).is_none());
+    }
+
+    #[test]
+    fn despawning_cancels_task() {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default())
+            .handle_tasks::<()>();
+        let guard = Arc::new(());
+        let held = guard.clone();
+        let entity = app
+            .world_mut()
+            .commands()
+            .spawn_task(async move {
+                let _held = held;
+                std::future::pending::<()>().await;
+            })
+            .on_complete(|_, _| unreachable!())
+            .id();
+        app.world_mut().flush();
+        app.update();
+
+        app.world_mut().despawn(entity);
+        // The future, and what it holds, is dropped once the task is canceled
+        update_until(&mut app, |_| Arc::strong_count(&guard) == 1);
+    }
+}
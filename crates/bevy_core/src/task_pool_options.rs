@@ -45,6 +45,11 @@ pub struct TaskPoolOptions {
     pub io: TaskPoolThreadAssignmentPolicy,
     /// Used to determine number of async compute threads to allocate
     pub async_compute: TaskPoolThreadAssignmentPolicy,
+    /// Number of the async compute threads running only the tasks spawned with
+    /// [`TaskPriority::Background`](bevy_tasks::TaskPriority::Background), so that long-running
+    /// tasks leave the other async compute threads to the rest. At least one async compute thread
+    /// is left to the other tasks. Defaults to 0, running the background tasks on every thread.
+    pub async_compute_background_threads: usize,
     /// Used to determine number of compute threads to allocate
     pub compute: TaskPoolThreadAssignmentPolicy,
 }
@@ -70,6 +75,9 @@ impl Default for TaskPoolOptions {
                 percent: 0.25,
             },
 
+            // Run background tasks like the other async compute tasks
+            async_compute_background_threads: 0,
+
             // Use all remaining cores for compute (at least 1)
             compute: TaskPoolThreadAssignmentPolicy {
                 min_threads: 1,
@@ -127,6 +135,7 @@ impl TaskPoolOptions {
             AsyncComputeTaskPool::get_or_init(|| {
                 TaskPoolBuilder::default()
                     .num_threads(async_compute_threads)
+                    .background_threads(self.async_compute_background_threads)
                     .thread_name("Async Compute Task Pool".to_string())
                     .build()
             });
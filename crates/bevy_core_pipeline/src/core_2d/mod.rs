@@ -34,7 +34,6 @@ pub use main_transparent_pass_2d_node::*;
 
 use bevy_app::{App, Plugin};
 use bevy_ecs::{entity::EntityHashSet, prelude::*};
-use bevy_math::FloatOrd;
 use bevy_render::{
     camera::Camera,
     extract_component::ExtractComponentPlugin,
@@ -95,8 +94,49 @@ impl Plugin for Core2dPlugin {
     }
 }
 
+/// The key sorting [`Transparent2d`] items, which are drawn in ascending order of their sorting
+/// layer, then of their order in the layer, then of their depth.
+///
+/// Items sorted by depth only are in the layer `0`, with an order of `0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sort2dKey(u128);
+
+impl Sort2dKey {
+    /// The key of items drawn after all the others, such as gizmos.
+    pub const MAX: Self = Self(u128::MAX);
+
+    /// Creates a key sorting an item by its `layer`, its `order` in the layer, then its `depth`.
+    ///
+    /// Items with equal layers, orders and depths are sorted by their `tie_breaker`,
+    /// such as their entity index, so that they keep a stable order across frames.
+    pub fn new(layer: i16, order: f32, depth: f32, tie_breaker: u32) -> Self {
+        let layer = (layer as u16 ^ 0x8000) as u128;
+        Self(
+            layer << 96
+                | (sortable_bits(order) as u128) << 64
+                | (sortable_bits(depth) as u128) << 32
+                | tie_breaker as u128,
+        )
+    }
+
+    /// Creates a key sorting an item by its `depth` only.
+    pub fn from_depth(depth: f32) -> Self {
+        Self::new(0, 0.0, depth, 0)
+    }
+}
+
+/// Maps `value` to bits that compare like the value, with negative values before positive ones.
+fn sortable_bits(value: f32) -> u32 {
+    let bits = value.to_bits();
+    if bits & 0x8000_0000 != 0 {
+        !bits
+    } else {
+        bits | 0x8000_0000
+    }
+}
+
 pub struct Transparent2d {
-    pub sort_key: FloatOrd,
+    pub sort_key: Sort2dKey,
     pub entity: Entity,
     pub pipeline: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
@@ -137,7 +177,7 @@ impl PhaseItem for Transparent2d {
 }
 
 impl SortedPhaseItem for Transparent2d {
-    type SortKey = FloatOrd;
+    type SortKey = Sort2dKey;
 
     #[inline]
     fn sort_key(&self) -> Self::SortKey {
@@ -180,3 +220,33 @@ pub fn extract_core_2d_camera_phases(
     // Clear out all dead views.
     transparent_2d_phases.retain(|camera_entity, _| live_entities.contains(camera_entity));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Sort2dKey;
+
+    #[test]
+    fn sort_2d_key_order() {
+        let mut keys = [
+            Sort2dKey::new(1, -5.0, -10.0, 0),
+            Sort2dKey::new(0, 2.0, 0.0, 0),
+            Sort2dKey::from_depth(-3.5),
+            Sort2dKey::new(-1, 100.0, 50.0, 0),
+            Sort2dKey::new(0, 2.0, 0.0, 1),
+            Sort2dKey::from_depth(3.5),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                Sort2dKey::new(-1, 100.0, 50.0, 0),
+                Sort2dKey::from_depth(-3.5),
+                Sort2dKey::from_depth(3.5),
+                Sort2dKey::new(0, 2.0, 0.0, 0),
+                Sort2dKey::new(0, 2.0, 0.0, 1),
+                Sort2dKey::new(1, -5.0, -10.0, 0),
+            ]
+        );
+        assert!(Sort2dKey::new(i16::MAX, f32::INFINITY, f32::INFINITY, u32::MAX) <= Sort2dKey::MAX);
+    }
+}
@@ -0,0 +1,42 @@
+// Color blindness simulation, after Viénot, Brettel and Mollon, "Digital video colourmaps for
+// checking the legibility of displays by dichromats" (1999).
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+
+// The rows of the matrix from linear RGB to the LMS cone responses
+const RGB_TO_LMS: mat3x3<f32> = mat3x3<f32>(
+    vec3<f32>(17.8824, 43.5161, 4.11935),
+    vec3<f32>(3.45565, 27.1554, 3.86714),
+    vec3<f32>(0.0299566, 0.184309, 1.46709),
+);
+
+// The rows of the matrix from the LMS cone responses to linear RGB
+const LMS_TO_RGB: mat3x3<f32> = mat3x3<f32>(
+    vec3<f32>(0.0809444479, -0.130504409, 0.116721066),
+    vec3<f32>(-0.0102485335, 0.0540193266, -0.113614708),
+    vec3<f32>(-0.000365296938, -0.00412161469, 0.693511405),
+);
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(screen_texture, screen_sampler, in.uv);
+
+    // Multiplying a row vector by the matrix of the rows transforms it
+    var lms = color.rgb * RGB_TO_LMS;
+
+    // The response of the missing cones is reconstructed from the other two
+#ifdef PROTANOPIA
+    lms.x = 2.02344 * lms.y - 2.52581 * lms.z;
+#endif
+#ifdef DEUTERANOPIA
+    lms.y = 0.494207 * lms.x + 1.24827 * lms.z;
+#endif
+#ifdef TRITANOPIA
+    lms.z = -0.395913 * lms.x + 0.801109 * lms.y;
+#endif
+
+    return vec4<f32>(max(lms * LMS_TO_RGB, vec3<f32>(0.0)), color.a);
+}
@@ -0,0 +1,243 @@
+//! A post-process simulating color blindness over the whole frame, including the UI, to test
+//! the legibility of an application.
+
+use bevy_app::{App, Plugin};
+use bevy_asset::{load_internal_asset, Handle};
+use bevy_core_pipeline::{
+    core_2d::graph::{Core2d, Node2d},
+    core_3d::graph::{Core3d, Node3d},
+    fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+};
+use bevy_ecs::prelude::*;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    camera::Camera,
+    extract_component::{ExtractComponent, ExtractComponentPlugin},
+    render_graph::{RenderGraphApp, RenderLabel, ViewNodeRunner},
+    render_resource::{
+        binding_types::{sampler, texture_2d},
+        *,
+    },
+    renderer::RenderDevice,
+    texture::BevyDefault,
+    view::{ExtractedView, ViewTarget},
+    Render, RenderApp, RenderSet,
+};
+use bevy_ui::graph::NodeUi;
+
+mod node;
+
+pub use node::ColorBlindnessNode;
+
+/// A kind of color blindness simulated by [`ColorBlindnessSimulation`].
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Default, PartialEq, Hash)]
+pub enum ColorBlindnessMode {
+    /// Normal vision: the frame is left as is.
+    #[default]
+    None,
+    /// No red cones.
+    Protanopia,
+    /// No green cones.
+    Deuteranopia,
+    /// No blue cones.
+    Tritanopia,
+}
+
+impl ColorBlindnessMode {
+    fn shader_def(self) -> Option<&'static str> {
+        match self {
+            ColorBlindnessMode::None => None,
+            ColorBlindnessMode::Protanopia => Some("PROTANOPIA"),
+            ColorBlindnessMode::Deuteranopia => Some("DEUTERANOPIA"),
+            ColorBlindnessMode::Tritanopia => Some("TRITANOPIA"),
+        }
+    }
+}
+
+/// Simulates a kind of color blindness over the whole frame rendered by a camera, after its UI.
+///
+/// The colors are transformed to the LMS color space of the cone responses, where the response
+/// of the missing cones is reconstructed from the other two, and back to RGB.
+///
+/// Requires the [`ColorBlindnessPlugin`].
+///
+/// ```
+/// # use bevy_core_pipeline::core_2d::Camera2dBundle;
+/// # use bevy_dev_tools::color_blindness::{ColorBlindnessMode, ColorBlindnessSimulation};
+/// # use bevy_ecs::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Camera2dBundle::default(),
+///         ColorBlindnessSimulation {
+///             mode: ColorBlindnessMode::Deuteranopia,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Default, Debug, ExtractComponent)]
+#[reflect(Component, Default)]
+#[extract_component_filter(With<Camera>)]
+pub struct ColorBlindnessSimulation {
+    /// The kind of color blindness simulated, if any.
+    pub mode: ColorBlindnessMode,
+}
+
+/// The label of the [`ColorBlindnessNode`] in the 2D and 3D render graphs, between the UI pass
+/// and the upscaling.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct ColorBlindnessLabel;
+
+const COLOR_BLINDNESS_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(10496313748315210458);
+
+/// Adds support for [`ColorBlindnessSimulation`].
+///
+/// It must be added after the `UiPlugin`, whose pass it follows.
+pub struct ColorBlindnessPlugin;
+
+impl Plugin for ColorBlindnessPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            COLOR_BLINDNESS_SHADER_HANDLE,
+            "color_blindness.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.register_type::<ColorBlindnessSimulation>()
+            .add_plugins(ExtractComponentPlugin::<ColorBlindnessSimulation>::default());
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .init_resource::<SpecializedRenderPipelines<ColorBlindnessPipeline>>()
+            .add_systems(
+                Render,
+                prepare_color_blindness_pipelines.in_set(RenderSet::Prepare),
+            )
+            .add_render_graph_node::<ViewNodeRunner<ColorBlindnessNode>>(
+                Core3d,
+                ColorBlindnessLabel,
+            )
+            .add_render_graph_edges(
+                Core3d,
+                (NodeUi::UiPass, ColorBlindnessLabel, Node3d::Upscaling),
+            )
+            .add_render_graph_node::<ViewNodeRunner<ColorBlindnessNode>>(
+                Core2d,
+                ColorBlindnessLabel,
+            )
+            .add_render_graph_edges(
+                Core2d,
+                (NodeUi::UiPass, ColorBlindnessLabel, Node2d::Upscaling),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app.init_resource::<ColorBlindnessPipeline>();
+    }
+}
+
+/// The render pipeline of the [`ColorBlindnessNode`].
+#[derive(Resource)]
+pub struct ColorBlindnessPipeline {
+    texture_bind_group: BindGroupLayout,
+    sampler: Sampler,
+}
+
+impl FromWorld for ColorBlindnessPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let render_device = render_world.resource::<RenderDevice>();
+        let texture_bind_group = render_device.create_bind_group_layout(
+            "color_blindness_texture_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                ),
+            ),
+        );
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+
+        ColorBlindnessPipeline {
+            texture_bind_group,
+            sampler,
+        }
+    }
+}
+
+/// The id of the specialized [`ColorBlindnessPipeline`] of a view.
+#[derive(Component)]
+pub struct CameraColorBlindnessPipeline {
+    /// The id of the pipeline.
+    pub pipeline_id: CachedRenderPipelineId,
+}
+
+/// The key specializing the [`ColorBlindnessPipeline`].
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct ColorBlindnessPipelineKey {
+    mode: ColorBlindnessMode,
+    texture_format: TextureFormat,
+}
+
+impl SpecializedRenderPipeline for ColorBlindnessPipeline {
+    type Key = ColorBlindnessPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        RenderPipelineDescriptor {
+            label: Some("color_blindness".into()),
+            layout: vec![self.texture_bind_group.clone()],
+            vertex: fullscreen_shader_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: COLOR_BLINDNESS_SHADER_HANDLE,
+                shader_defs: key.mode.shader_def().into_iter().map(Into::into).collect(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: key.texture_format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: Vec::new(),
+        }
+    }
+}
+
+/// Specializes the [`ColorBlindnessPipeline`] of the views simulating color blindness.
+pub fn prepare_color_blindness_pipelines(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<ColorBlindnessPipeline>>,
+    color_blindness_pipeline: Res<ColorBlindnessPipeline>,
+    views: Query<(Entity, &ExtractedView, &ColorBlindnessSimulation)>,
+) {
+    for (entity, view, simulation) in &views {
+        if simulation.mode == ColorBlindnessMode::None {
+            continue;
+        }
+        let pipeline_id = pipelines.specialize(
+            &pipeline_cache,
+            &color_blindness_pipeline,
+            ColorBlindnessPipelineKey {
+                mode: simulation.mode,
+                texture_format: if view.hdr {
+                    ViewTarget::TEXTURE_FORMAT_HDR
+                } else {
+                    TextureFormat::bevy_default()
+                },
+            },
+        );
+
+        commands
+            .entity(entity)
+            .insert(CameraColorBlindnessPipeline { pipeline_id });
+    }
+}
@@ -0,0 +1,67 @@
+// Draws the samples of a diagnostic graph as an antialiased line over a faint fill.
+
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+struct DiagnosticGraph {
+    color: vec4<f32>,
+    count: u32,
+    // The position of the hovered sample along the graph, from 0 to 1, or -1 if none is hovered
+    hovered: f32,
+    // The width of the line, in pixels
+    line_width: f32,
+    // The samples, scaled between 0 at the bottom and 1 at the top, packed by four
+    samples: array<vec4<f32>, 64>,
+};
+
+@group(1) @binding(0) var<uniform> graph: DiagnosticGraph;
+
+// The alpha of the area under the line
+const FILL_ALPHA: f32 = 0.2;
+
+fn sample_point(index: u32, size: vec2<f32>) -> vec2<f32> {
+    let value = graph.samples[index / 4u][index % 4u];
+    let x = f32(index) / f32(graph.count - 1u);
+    return vec2(x, 1.0 - value) * size;
+}
+
+fn segment_distance(point: vec2<f32>, start: vec2<f32>, end: vec2<f32>) -> f32 {
+    let segment = end - start;
+    let t = clamp(dot(point - start, segment) / max(dot(segment, segment), 1e-6), 0.0, 1.0);
+    return length(point - start - segment * t);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let point = in.uv * in.size;
+    var color = vec4(graph.color.rgb, 0.0);
+
+    if graph.count >= 2u {
+        let last_segment = graph.count - 2u;
+        let segment = min(u32(in.uv.x * f32(graph.count - 1u)), last_segment);
+
+        // A steep neighbouring segment may be closer to the point than the one under it
+        var distance = 1e9;
+        for (var i = max(segment, 1u) - 1u; i <= min(segment + 1u, last_segment); i++) {
+            distance = min(
+                distance,
+                segment_distance(point, sample_point(i, in.size), sample_point(i + 1u, in.size)),
+            );
+        }
+        let line = clamp(graph.line_width * 0.5 + 0.5 - distance, 0.0, 1.0);
+
+        let start = sample_point(segment, in.size);
+        let end = sample_point(segment + 1u, in.size);
+        let t = clamp((point.x - start.x) / max(end.x - start.x, 1e-6), 0.0, 1.0);
+        let fill = select(0.0, FILL_ALPHA, point.y > mix(start.y, end.y, t));
+
+        color.a = graph.color.a * max(line, fill);
+    }
+
+    // A vertical marker on the hovered sample
+    if graph.hovered >= 0.0 {
+        let marker = clamp(1.0 - abs(point.x - graph.hovered * in.size.x), 0.0, 1.0) * 0.8;
+        color = vec4(mix(color.rgb, vec3(1.0), marker), max(color.a, marker));
+    }
+
+    return color;
+}
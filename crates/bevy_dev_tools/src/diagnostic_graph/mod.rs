@@ -0,0 +1,304 @@
+//! A UI widget drawing the recent history of a diagnostic as a graph.
+
+use bevy_app::{App, Plugin, Update};
+use bevy_asset::{load_internal_asset, Asset, Assets, Handle};
+use bevy_color::{Color, LinearRgba};
+use bevy_diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::BuildChildren;
+use bevy_math::Vec4;
+use bevy_reflect::TypePath;
+use bevy_render::{
+    render_resource::{AsBindGroup, Shader, ShaderRef, ShaderType},
+    view::Visibility,
+};
+use bevy_text::{Text, TextStyle};
+use bevy_ui::{
+    node_bundles::TextBundle, PositionType, RelativeCursorPosition, Style, UiMaterial,
+    UiMaterialPlugin, Val,
+};
+use bevy_utils::default;
+
+/// The largest number of samples a [`DiagnosticGraph`] can draw.
+pub const MAX_DIAGNOSTIC_GRAPH_SAMPLES: usize = 256;
+
+const DIAGNOSTIC_GRAPH_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(13872395612094356613);
+
+/// Adds support for [`DiagnosticGraph`] widgets.
+#[derive(Default)]
+pub struct DiagnosticGraphPlugin;
+
+impl Plugin for DiagnosticGraphPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            DIAGNOSTIC_GRAPH_SHADER_HANDLE,
+            "diagnostic_graph.wgsl",
+            Shader::from_wgsl
+        );
+
+        app.add_plugins(UiMaterialPlugin::<DiagnosticGraphMaterial>::default())
+            .add_systems(Update, (setup_graphs, update_graphs).chain());
+    }
+}
+
+/// A UI node drawing the latest measurements of a diagnostic of the [`DiagnosticsStore`] as a
+/// line graph, filling the node.
+///
+/// The Y axis is scaled to the range of the drawn measurements. When hovered, the graph marks
+/// the measurement under the cursor and shows its value.
+///
+/// Add it to a [`NodeBundle`](bevy_ui::node_bundles::NodeBundle) with a size. Requires the
+/// [`DiagnosticGraphPlugin`].
+///
+/// ```
+/// # use bevy_diagnostic::FrameTimeDiagnosticsPlugin;
+/// # use bevy_dev_tools::diagnostic_graph::DiagnosticGraph;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{node_bundles::NodeBundle, Style, Val};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle {
+///             style: Style {
+///                 width: Val::Px(240.0),
+///                 height: Val::Px(80.0),
+///                 ..Default::default()
+///             },
+///             ..Default::default()
+///         },
+///         DiagnosticGraph::new(FrameTimeDiagnosticsPlugin::FPS, 120),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct DiagnosticGraph {
+    /// The path of the diagnostic to draw.
+    pub path: DiagnosticPath,
+    /// How many of the latest measurements are drawn, up to [`MAX_DIAGNOSTIC_GRAPH_SAMPLES`].
+    ///
+    /// Fewer are drawn while the history of the diagnostic is shorter.
+    pub samples: usize,
+    /// The color of the line.
+    pub color: Color,
+    /// The style of the text showing the hovered value.
+    pub text_style: TextStyle,
+}
+
+impl DiagnosticGraph {
+    /// Creates a graph of the latest `samples` measurements of the diagnostic at `path`.
+    pub fn new(path: DiagnosticPath, samples: usize) -> Self {
+        Self {
+            path,
+            samples,
+            color: Color::srgb(0.4, 0.9, 0.4),
+            text_style: TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        }
+    }
+
+    /// Returns this graph with a new line [`color`](Self::color).
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// The material drawing a [`DiagnosticGraph`], managed by the [`DiagnosticGraphPlugin`].
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub struct DiagnosticGraphMaterial {
+    #[uniform(0)]
+    graph: DiagnosticGraphUniform,
+}
+
+impl UiMaterial for DiagnosticGraphMaterial {
+    fn fragment_shader() -> ShaderRef {
+        DIAGNOSTIC_GRAPH_SHADER_HANDLE.into()
+    }
+}
+
+#[derive(ShaderType, Debug, Clone, PartialEq)]
+struct DiagnosticGraphUniform {
+    color: LinearRgba,
+    count: u32,
+    /// The position of the hovered sample along the graph, from 0 to 1, or -1 if none is hovered.
+    hovered: f32,
+    /// The width of the line, in pixels.
+    line_width: f32,
+    /// The samples, scaled between 0 and 1, packed by four.
+    samples: [Vec4; MAX_DIAGNOSTIC_GRAPH_SAMPLES / 4],
+}
+
+impl Default for DiagnosticGraphUniform {
+    fn default() -> Self {
+        Self {
+            color: LinearRgba::WHITE,
+            count: 0,
+            hovered: -1.0,
+            line_width: 1.5,
+            samples: [Vec4::ZERO; MAX_DIAGNOSTIC_GRAPH_SAMPLES / 4],
+        }
+    }
+}
+
+/// The text showing the hovered value of a graph.
+#[derive(Component)]
+struct GraphReadout(Entity);
+
+fn setup_graphs(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<DiagnosticGraphMaterial>>,
+    graphs: Query<(Entity, &DiagnosticGraph), Without<GraphReadout>>,
+) {
+    for (entity, graph) in &graphs {
+        let readout = commands
+            .spawn(TextBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::ZERO,
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                ..TextBundle::from_section("", graph.text_style.clone())
+            })
+            .id();
+        commands
+            .entity(entity)
+            .insert((
+                materials.add(DiagnosticGraphMaterial::default()),
+                RelativeCursorPosition::default(),
+                GraphReadout(readout),
+            ))
+            .add_child(readout);
+    }
+}
+
+fn update_graphs(
+    diagnostics: Res<DiagnosticsStore>,
+    mut materials: ResMut<Assets<DiagnosticGraphMaterial>>,
+    graphs: Query<(
+        &DiagnosticGraph,
+        &Handle<DiagnosticGraphMaterial>,
+        &RelativeCursorPosition,
+        &GraphReadout,
+    )>,
+    mut readouts: Query<(&mut Text, &mut Style, &mut Visibility)>,
+) {
+    for (graph, material, cursor, readout) in &graphs {
+        let Ok((mut text, mut style, mut visibility)) = readouts.get_mut(readout.0) else {
+            continue;
+        };
+
+        let mut uniform = DiagnosticGraphUniform {
+            color: graph.color.into(),
+            ..default()
+        };
+        // The position of the hovered sample along the graph, and its value
+        let mut hovered = None;
+        if let Some(diagnostic) = diagnostics.get(&graph.path) {
+            let history = diagnostic.history();
+            let count = graph
+                .samples
+                .min(MAX_DIAGNOSTIC_GRAPH_SAMPLES)
+                .min(history.len());
+            let values: Vec<f64> = history
+                .range(history.len() - count..)
+                .map(|measurement| measurement.value)
+                .collect();
+
+            let (min, max) = auto_scale(&values);
+            for (index, value) in values.iter().enumerate() {
+                let scaled = if value.is_finite() {
+                    ((value - min) / (max - min)) as f32
+                } else {
+                    0.0
+                };
+                uniform.samples[index / 4][index % 4] = scaled;
+            }
+            uniform.count = count as u32;
+
+            if let Some(position) = cursor
+                .normalized
+                .filter(|_| cursor.mouse_over() && count >= 2)
+            {
+                let index = (position.x.clamp(0.0, 1.0) * (count - 1) as f32).round() as usize;
+                let x = index as f32 / (count - 1) as f32;
+                uniform.hovered = x;
+                hovered = Some((x, format!("{:.2}{}", values[index], diagnostic.suffix)));
+            }
+        }
+
+        // Only touch what changed, so that the material and the readout aren't prepared again
+        // every frame
+        if materials
+            .get(material)
+            .is_some_and(|material| material.graph != uniform)
+        {
+            if let Some(material) = materials.get_mut(material) {
+                material.graph = uniform;
+            }
+        }
+
+        let Some((x, value)) = hovered else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+        if text.sections[0].style != graph.text_style {
+            text.sections[0].style = graph.text_style.clone();
+        }
+        // Keep the readout on the side of the marker with the most room
+        let (left, right) = if x < 0.5 {
+            (Val::Percent(x * 100.0), Val::Auto)
+        } else {
+            (Val::Auto, Val::Percent((1.0 - x) * 100.0))
+        };
+        if style.left != left || style.right != right {
+            style.left = left;
+            style.right = right;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+/// Returns the range of the Y axis of a graph of `values`, with some room above and below them.
+fn auto_scale(values: &[f64]) -> (f64, f64) {
+    let finite = values.iter().copied().filter(|value| value.is_finite());
+    let Some((min, max)) = finite.fold(None, |range, value| match range {
+        None => Some((value, value)),
+        Some((min, max)) => Some((value.min(min), value.max(max))),
+    }) else {
+        return (0.0, 1.0);
+    };
+    let padding = if max > min {
+        (max - min) * 0.1
+    } else {
+        // Keep a flat line in the middle of the graph
+        max.abs().max(1.0) * 0.1
+    };
+    (min - padding, max + padding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::auto_scale;
+
+    #[test]
+    fn auto_scale_pads_the_range() {
+        let assert_range = |values: &[f64], expected: (f64, f64)| {
+            let (min, max) = auto_scale(values);
+            assert!((min - expected.0).abs() < 1e-9 && (max - expected.1).abs() < 1e-9);
+        };
+        assert_range(&[30.0, 60.0, 50.0], (27.0, 63.0));
+
+        // Flat or empty histories still get a usable range
+        assert_range(&[60.0, 60.0], (54.0, 66.0));
+        assert_eq!(auto_scale(&[0.0]), (-0.1, 0.1));
+        assert_eq!(auto_scale(&[]), (0.0, 1.0));
+        assert_eq!(auto_scale(&[f64::NAN]), (0.0, 1.0));
+    }
+}
@@ -0,0 +1,285 @@
+//! Module containing logic for the diagnostics overlay.
+
+use std::fmt::Write;
+
+use bevy_app::{Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::{Alpha, Color};
+use bevy_diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    query::With,
+    schedule::{common_conditions::resource_changed, IntoSystemConfigs},
+    system::{Commands, Local, Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::BuildChildren;
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_render::view::Visibility;
+use bevy_text::{Font, Text, TextStyle};
+use bevy_time::{Real, Time};
+use bevy_ui::{
+    node_bundles::{NodeBundle, TextBundle},
+    BackgroundColor, PositionType, Style, UiRect, Val, ZIndex,
+};
+use bevy_utils::{default, Duration};
+
+/// Global [`ZIndex`] used to render the diagnostics overlay.
+///
+/// We use a number slightly under `i32::MAX` so you can render on top of it if you really need to.
+pub const DIAGNOSTICS_OVERLAY_ZINDEX: i32 = i32::MAX - 33;
+
+/// A plugin that displays the values of the [`DiagnosticsStore`] in a text panel.
+///
+/// This plugin will add the [`FrameTimeDiagnosticsPlugin`] and the [`EntityCountDiagnosticsPlugin`]
+/// if they weren't added before, as their diagnostics are shown by default.
+/// Any other registered diagnostic, such as render statistics, can be shown by adding its path
+/// to [`DiagnosticsOverlayConfig::paths`].
+#[derive(Default)]
+pub struct DiagnosticsOverlayPlugin {
+    /// Starting configuration of overlay, this can be later be changed through [`DiagnosticsOverlayConfig`] resource.
+    pub config: DiagnosticsOverlayConfig,
+}
+
+impl Plugin for DiagnosticsOverlayPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        // TODO: Use plugin dependencies, see https://github.com/bevyengine/bevy/issues/69
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+        app.insert_resource(self.config.clone())
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    toggle_overlay,
+                    customize_overlay.run_if(resource_changed::<DiagnosticsOverlayConfig>),
+                    update_text,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// The corner of the window the diagnostics overlay is anchored to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayCorner {
+    /// The top left corner.
+    #[default]
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+/// Configuration options for the diagnostics overlay.
+#[derive(Resource, Clone)]
+pub struct DiagnosticsOverlayConfig {
+    /// Whether the overlay is shown.
+    pub enabled: bool,
+    /// The corner of the window the overlay is anchored to.
+    pub corner: OverlayCorner,
+    /// Configuration of text in the overlay.
+    ///
+    /// With the default font handle, the default font is used when the `default_font` feature is enabled.
+    pub text_config: TextStyle,
+    /// The diagnostics to show, in order. Diagnostics missing from the [`DiagnosticsStore`] are skipped.
+    pub paths: Vec<DiagnosticPath>,
+    /// How often the text is updated.
+    pub update_interval: Duration,
+    /// The keys that toggle [`enabled`](Self::enabled) when pressed together.
+    ///
+    /// An empty chord disables toggling from the keyboard.
+    pub toggle_chord: Vec<KeyCode>,
+}
+
+impl Default for DiagnosticsOverlayConfig {
+    fn default() -> Self {
+        DiagnosticsOverlayConfig {
+            enabled: true,
+            corner: OverlayCorner::TopLeft,
+            text_config: TextStyle {
+                font: Handle::<Font>::default(),
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            paths: vec![
+                FrameTimeDiagnosticsPlugin::FPS,
+                FrameTimeDiagnosticsPlugin::FRAME_TIME,
+                EntityCountDiagnosticsPlugin::ENTITY_COUNT,
+            ],
+            update_interval: Duration::from_millis(250),
+            toggle_chord: vec![KeyCode::F12],
+        }
+    }
+}
+
+#[derive(Component)]
+struct DiagnosticsOverlayRoot;
+
+#[derive(Component)]
+struct DiagnosticsOverlayText;
+
+fn setup(mut commands: Commands, overlay_config: Res<DiagnosticsOverlayConfig>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    // We need to make sure the overlay doesn't affect the position of other UI nodes
+                    position_type: PositionType::Absolute,
+                    padding: UiRect::all(Val::Px(4.0)),
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::BLACK.with_alpha(0.5)),
+                // Render overlay on top of everything
+                z_index: ZIndex::Global(DIAGNOSTICS_OVERLAY_ZINDEX),
+                ..default()
+            },
+            DiagnosticsOverlayRoot,
+        ))
+        .with_children(|c| {
+            c.spawn((
+                TextBundle::from_section("", overlay_config.text_config.clone()),
+                DiagnosticsOverlayText,
+            ));
+        });
+}
+
+fn toggle_overlay(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut overlay_config: ResMut<DiagnosticsOverlayConfig>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    let chord = &overlay_config.toggle_chord;
+    if !chord.is_empty()
+        && keys.all_pressed(chord.iter().copied())
+        && keys.any_just_pressed(chord.iter().copied())
+    {
+        overlay_config.enabled = !overlay_config.enabled;
+    }
+}
+
+fn customize_overlay(
+    overlay_config: Res<DiagnosticsOverlayConfig>,
+    mut roots: Query<(&mut Style, &mut Visibility), With<DiagnosticsOverlayRoot>>,
+    mut texts: Query<&mut Text, With<DiagnosticsOverlayText>>,
+) {
+    for (mut style, mut visibility) in &mut roots {
+        let (top, bottom, left, right) = match overlay_config.corner {
+            OverlayCorner::TopLeft => (Val::ZERO, Val::Auto, Val::ZERO, Val::Auto),
+            OverlayCorner::TopRight => (Val::ZERO, Val::Auto, Val::Auto, Val::ZERO),
+            OverlayCorner::BottomLeft => (Val::Auto, Val::ZERO, Val::ZERO, Val::Auto),
+            OverlayCorner::BottomRight => (Val::Auto, Val::ZERO, Val::Auto, Val::ZERO),
+        };
+        style.top = top;
+        style.bottom = bottom;
+        style.left = left;
+        style.right = right;
+        *visibility = if overlay_config.enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+    for mut text in &mut texts {
+        for section in text.sections.iter_mut() {
+            section.style = overlay_config.text_config.clone();
+        }
+    }
+}
+
+fn update_text(
+    overlay_config: Res<DiagnosticsOverlayConfig>,
+    diagnostics: Res<DiagnosticsStore>,
+    time: Res<Time<Real>>,
+    mut since_update: Local<Option<Duration>>,
+    mut query: Query<&mut Text, With<DiagnosticsOverlayText>>,
+) {
+    if !overlay_config.enabled {
+        return;
+    }
+
+    // Format all lines at once, and only every `update_interval`, so that the overlay stays cheap
+    let elapsed = since_update.map_or(Duration::MAX, |since| since + time.delta());
+    if elapsed < overlay_config.update_interval && !overlay_config.is_changed() {
+        *since_update = Some(elapsed);
+        return;
+    }
+    *since_update = Some(Duration::ZERO);
+
+    for mut text in &mut query {
+        let Some(section) = text.sections.first_mut() else {
+            continue;
+        };
+        // Reuse the allocation of the previous text
+        section.value.clear();
+        write_diagnostics(&mut section.value, &diagnostics, &overlay_config.paths);
+    }
+}
+
+/// Writes one `path: value` line per diagnostic of `paths` that has a value.
+fn write_diagnostics(
+    output: &mut String,
+    diagnostics: &DiagnosticsStore,
+    paths: &[DiagnosticPath],
+) {
+    for diagnostic in paths.iter().filter_map(|path| diagnostics.get(path)) {
+        let Some(value) = diagnostic.smoothed() else {
+            continue;
+        };
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        // Writing to a `String` can't fail
+        let _ = write!(
+            output,
+            "{}: {value:.2}{}",
+            diagnostic.path(),
+            diagnostic.suffix
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_diagnostic::{Diagnostic, DiagnosticMeasurement};
+    use bevy_utils::Instant;
+
+    use super::*;
+
+    #[test]
+    fn missing_diagnostics_are_skipped() {
+        let mut diagnostics = DiagnosticsStore::default();
+        let mut frame_time =
+            Diagnostic::new(FrameTimeDiagnosticsPlugin::FRAME_TIME).with_suffix("ms");
+        frame_time.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: 16.0,
+        });
+        diagnostics.add(frame_time);
+        diagnostics.add(Diagnostic::new(FrameTimeDiagnosticsPlugin::FPS));
+
+        let mut output = String::new();
+        write_diagnostics(
+            &mut output,
+            &diagnostics,
+            &[
+                FrameTimeDiagnosticsPlugin::FPS,
+                FrameTimeDiagnosticsPlugin::FRAME_TIME,
+                EntityCountDiagnosticsPlugin::ENTITY_COUNT,
+            ],
+        );
+        assert_eq!(output, "frame_time: 16.00ms");
+    }
+}
@@ -1,20 +1,21 @@
 //! Module containing logic for FPS overlay.
 
-use bevy_app::{Plugin, Startup, Update};
+use bevy_app::{App, Plugin, Startup, Update};
 use bevy_asset::Handle;
 use bevy_color::Color;
 use bevy_diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use bevy_ecs::{
     component::Component,
+    entity::Entity,
     query::With,
     schedule::{common_conditions::resource_changed, IntoSystemConfigs},
     system::{Commands, Query, Res, Resource},
 };
-use bevy_hierarchy::BuildChildren;
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
 use bevy_text::{Font, Text, TextSection, TextStyle};
 use bevy_ui::{
     node_bundles::{NodeBundle, TextBundle},
-    PositionType, Style, ZIndex,
+    PositionType, Style, UiRect, Val, ZIndex,
 };
 use bevy_utils::default;
 
@@ -27,6 +28,9 @@ pub const FPS_OVERLAY_ZINDEX: i32 = i32::MAX - 32;
 ///
 /// This plugin will add the [`FrameTimeDiagnosticsPlugin`] if it wasn't added before.
 ///
+/// [Disabling](App::disable_plugin) this plugin despawns the overlay, which isn't spawned again if
+/// it's enabled again, and keeps the [`FpsOverlayConfig`] resource.
+///
 /// Note: It is recommended to use native overlay of rendering statistics when possible for lower overhead and more accurate results.
 /// The correct way to do this will vary by platform:
 /// - **Metal**: setting env variable `MTL_HUD_ENABLED=1`
@@ -37,7 +41,7 @@ pub struct FpsOverlayPlugin {
 }
 
 impl Plugin for FpsOverlayPlugin {
-    fn build(&self, app: &mut bevy_app::App) {
+    fn build(&self, app: &mut App) {
         // TODO: Use plugin dependencies, see https://github.com/bevyengine/bevy/issues/69
         if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
             app.add_plugins(FrameTimeDiagnosticsPlugin);
@@ -52,6 +56,17 @@ impl Plugin for FpsOverlayPlugin {
                 ),
             );
     }
+
+    fn cleanup_on_disable(&self, app: &mut App) {
+        let world = app.world_mut();
+        let overlays: Vec<Entity> = world
+            .query_filtered::<Entity, With<FpsOverlay>>()
+            .iter(world)
+            .collect();
+        for overlay in overlays {
+            world.entity_mut(overlay).despawn_recursive();
+        }
+    }
 }
 
 /// Configuration options for the FPS overlay.
@@ -68,32 +83,44 @@ impl Default for FpsOverlayConfig {
                 font: Handle::<Font>::default(),
                 font_size: 32.0,
                 color: Color::WHITE,
+                ..Default::default()
             },
         }
     }
 }
 
+#[derive(Component)]
+struct FpsOverlay;
+
 #[derive(Component)]
 struct FpsText;
 
 fn setup(mut commands: Commands, overlay_config: Res<FpsOverlayConfig>) {
     commands
-        .spawn(NodeBundle {
-            style: Style {
-                // We need to make sure the overlay doesn't affect the position of other UI nodes
-                position_type: PositionType::Absolute,
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    // We need to make sure the overlay doesn't affect the position of other UI nodes
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                // Render overlay on top of everything
+                z_index: ZIndex::Global(FPS_OVERLAY_ZINDEX),
                 ..default()
             },
-            // Render overlay on top of everything
-            z_index: ZIndex::Global(FPS_OVERLAY_ZINDEX),
-            ..default()
-        })
+            FpsOverlay,
+        ))
         .with_children(|c| {
             c.spawn((
                 TextBundle::from_sections([
                     TextSection::new("FPS: ", overlay_config.text_config.clone()),
                     TextSection::from_style(overlay_config.text_config.clone()),
-                ]),
+                ])
+                .with_style(Style {
+                    // Keep the text off the edges of the window
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                }),
                 FpsText,
             ));
         });
@@ -13,8 +13,17 @@ use bevy_app::prelude::*;
 #[cfg(feature = "bevy_ci_testing")]
 pub mod ci_testing;
 
+pub mod color_blindness;
+
+pub mod diagnostic_graph;
+
+pub mod diagnostics_overlay;
+
 pub mod fps_overlay;
 
+#[cfg(feature = "bevy_replay")]
+pub mod replay;
+
 #[cfg(feature = "bevy_ui_debug")]
 pub mod ui_debug_overlay;
 
@@ -0,0 +1,692 @@
+//! Recording and deterministic playback of input and frame times.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use bevy_app::{App, AppExit, First, Last, Plugin, PreUpdate};
+use bevy_ecs::{event::ManualEventReader, prelude::*, schedule::Schedules};
+use bevy_input::{
+    gamepad::{gamepad_event_system, Gamepad, GamepadEvent},
+    keyboard::{keyboard_input_system, KeyboardInput},
+    mouse::{mouse_button_input_system, MouseButtonInput, MouseMotion, MouseWheel},
+    InputSystem,
+};
+use bevy_time::{Real, Time, TimeSystem, TimeUpdateStrategy};
+use bevy_utils::{
+    tracing::{error, info},
+    Duration, HashSet,
+};
+use bevy_window::CursorMoved;
+use serde::{Deserialize, Serialize};
+
+/// A plugin that records a session, or plays a recorded session back deterministically.
+///
+/// While recording, the real time delta and the raw input events of every frame are kept in the
+/// [`Replay`] resource, and written to a file when the app exits. Recorded input events are
+/// [`KeyboardInput`], [`MouseButtonInput`], [`MouseMotion`], [`MouseWheel`], [`CursorMoved`]
+/// and [`GamepadEvent`], so the app needs the `InputPlugin` and the `WindowPlugin`.
+///
+/// During playback, the time of each frame is set from the recording through
+/// [`TimeUpdateStrategy::ManualDuration`], and the recorded input events replace the ones sent by
+/// the windowing and gamepad backends for the devices with recorded input, so that a gamepad
+/// that wasn't used while recording still works. Replayed cursor movements don't move the cursor
+/// of the OS, only the [`CursorMoved`] events are sent. Playback refuses to start if the app doesn't have the same
+/// plugins and systems as the recorded one, see [`app_fingerprint`]. Once all recorded frames are
+/// played, time and input go back to normal.
+///
+/// Anything random in the app should be seeded from the [`ReplaySeed`] resource, which is
+/// recorded too. For divergences to be diagnosable, set [`ReplayPlugin::world_hash`] to a function
+/// hashing the state that matters: its result is recorded at intervals, and checked during
+/// playback, sending a [`ReplayDiverged`] event on mismatch.
+///
+/// ```no_run
+/// # use bevy_app::prelude::*;
+/// # use bevy_dev_tools::replay::ReplayPlugin;
+/// App::new()
+///     .add_plugins(if std::env::args().any(|arg| arg == "--replay") {
+///         ReplayPlugin::playback("session.replay.ron")
+///     } else {
+///         ReplayPlugin::record("session.replay.ron")
+///     })
+///     .run();
+/// ```
+pub struct ReplayPlugin {
+    /// Whether to record or play back, and the file of the recording.
+    pub mode: ReplayMode,
+    /// The seed to record. A random seed is used if `None`.
+    ///
+    /// This is ignored during playback, which uses the recorded seed.
+    pub seed: Option<u64>,
+    /// A function hashing the state of the [`World`], to detect divergences during playback.
+    pub world_hash: Option<fn(&mut World) -> u64>,
+    /// How many frames to wait between two world hashes. Zero disables hashing.
+    ///
+    /// This is ignored during playback, which uses the recorded interval.
+    pub hash_interval: u32,
+}
+
+impl ReplayPlugin {
+    /// Records a session, to write it to the file at `path` when the app exits.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            mode: ReplayMode::Record(path.into()),
+            seed: None,
+            world_hash: None,
+            hash_interval: 60,
+        }
+    }
+
+    /// Plays back the session recorded in the file at `path`.
+    pub fn playback(path: impl Into<PathBuf>) -> Self {
+        Self {
+            mode: ReplayMode::Playback(path.into()),
+            ..Self::record(PathBuf::new())
+        }
+    }
+}
+
+/// Whether a [`ReplayPlugin`] records or plays back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayMode {
+    /// Record a session, to write it to the file at this path when the app exits.
+    Record(PathBuf),
+    /// Play back the session recorded in the file at this path.
+    Playback(PathBuf),
+}
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        let (state, recording) = match &self.mode {
+            ReplayMode::Record(_) => (
+                ReplayState::Recording,
+                ReplayRecording {
+                    seed: self
+                        .seed
+                        .unwrap_or_else(|| RandomState::new().build_hasher().finish()),
+                    hash_interval: self.hash_interval,
+                    ..Default::default()
+                },
+            ),
+            ReplayMode::Playback(path) => match ReplayRecording::load(path) {
+                Ok(recording) => (ReplayState::Playing, recording),
+                Err(err) => {
+                    error!(
+                        "Failed to read the replay file {}, playback is disabled: {err}",
+                        path.display()
+                    );
+                    (ReplayState::Finished, ReplayRecording::default())
+                }
+            },
+        };
+        let devices = ReplayedDevices::new(&recording);
+
+        app.insert_resource(ReplaySeed(recording.seed))
+            .insert_resource(Replay {
+                mode: self.mode.clone(),
+                state,
+                recording,
+                devices,
+                frame: 0,
+                world_hash: self.world_hash,
+            })
+            .add_event::<ReplayDiverged>()
+            .add_systems(First, set_frame_time.before(TimeSystem))
+            .add_systems(
+                PreUpdate,
+                (
+                    record_input.run_if(is_recording),
+                    play_input.run_if(is_playing),
+                )
+                    // After the backends sent their events, before they are processed
+                    .in_set(InputSystem)
+                    .before(keyboard_input_system)
+                    .before(mouse_button_input_system)
+                    .before(gamepad_event_system),
+            )
+            .add_systems(Last, (check_world_hash, save_recording_on_exit).chain());
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        // Every plugin has added its systems at this point
+        let fingerprint = app_fingerprint(app);
+        let mut replay = app.world_mut().resource_mut::<Replay>();
+        match &self.mode {
+            ReplayMode::Record(_) => replay.recording.fingerprint = fingerprint,
+            ReplayMode::Playback(path) => {
+                if replay.is_playing() && replay.recording.fingerprint != fingerprint {
+                    panic!(
+                        "the replay file {} was recorded by an app with different plugins or systems, refusing to play it back",
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The seed of a [`Replay`], to seed the random number generators of the app with.
+///
+/// It's the same when playing a session back as when recording it.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplaySeed(pub u64);
+
+/// Sent when the world hash of a frame differs between playback and recording.
+///
+/// See [`ReplayPlugin::world_hash`].
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayDiverged {
+    /// The index of the frame, starting from zero.
+    pub frame: usize,
+    /// The recorded hash.
+    pub expected: u64,
+    /// The hash during playback.
+    pub actual: u64,
+}
+
+/// The state of the session recorded or played back by the [`ReplayPlugin`].
+#[derive(Resource, Debug)]
+pub struct Replay {
+    mode: ReplayMode,
+    state: ReplayState,
+    recording: ReplayRecording,
+    devices: ReplayedDevices,
+    frame: usize,
+    world_hash: Option<fn(&mut World) -> u64>,
+}
+
+/// The input devices with recorded events, whose live input is ignored during playback.
+#[derive(Debug, Default)]
+struct ReplayedDevices {
+    keyboard: bool,
+    mouse: bool,
+    gamepads: HashSet<Gamepad>,
+}
+
+impl ReplayedDevices {
+    fn new(recording: &ReplayRecording) -> Self {
+        let mut devices = Self::default();
+        for event in recording.frames.iter().flat_map(|frame| &frame.events) {
+            match event {
+                ReplayInput::Keyboard(_) => devices.keyboard = true,
+                ReplayInput::MouseButton(_)
+                | ReplayInput::MouseMotion(_)
+                | ReplayInput::MouseWheel(_)
+                | ReplayInput::CursorMoved(_) => devices.mouse = true,
+                ReplayInput::Gamepad(event) => {
+                    devices.gamepads.insert(gamepad_of(event));
+                }
+            }
+        }
+        devices
+    }
+}
+
+fn gamepad_of(event: &GamepadEvent) -> Gamepad {
+    match event {
+        GamepadEvent::Connection(event) => event.gamepad,
+        GamepadEvent::Button(event) => event.gamepad,
+        GamepadEvent::Axis(event) => event.gamepad,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReplayState {
+    Recording,
+    Playing,
+    Finished,
+}
+
+impl Replay {
+    /// Returns `true` while recording.
+    pub fn is_recording(&self) -> bool {
+        self.state == ReplayState::Recording
+    }
+
+    /// Returns `true` while playing a recording back.
+    pub fn is_playing(&self) -> bool {
+        self.state == ReplayState::Playing
+    }
+
+    /// Returns the index of the current frame of the recording.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns the recorded session.
+    pub fn recording(&self) -> &ReplayRecording {
+        &self.recording
+    }
+
+    /// Writes the recorded session to the file given to [`ReplayPlugin::record`].
+    ///
+    /// This is done automatically when the app exits.
+    pub fn save(&self) -> io::Result<()> {
+        match &self.mode {
+            ReplayMode::Record(path) => self.recording.save(path),
+            ReplayMode::Playback(_) => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "can't save a replay while playing it back",
+            )),
+        }
+    }
+}
+
+/// A recorded session, as written to replay files.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReplayRecording {
+    /// The [`app_fingerprint`] of the recorded app.
+    pub fingerprint: u64,
+    /// The [`ReplaySeed`] of the recorded app.
+    pub seed: u64,
+    /// How many frames to wait between two world hashes.
+    pub hash_interval: u32,
+    /// The recorded frames.
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl ReplayRecording {
+    /// Reads a recording from the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        ron::from_str(&std::fs::read_to_string(path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Writes the recording to the file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let recording =
+            ron::to_string(self).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        std::fs::write(path, recording)
+    }
+}
+
+/// A recorded frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReplayFrame {
+    /// The real time delta of the frame.
+    pub delta: Duration,
+    /// The input events of the frame, in the order they were sent for each event type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<ReplayInput>,
+    /// The world hash at the end of the frame, if it was computed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub world_hash: Option<u64>,
+}
+
+/// A recorded input event.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ReplayInput {
+    /// A [`KeyboardInput`] event.
+    Keyboard(KeyboardInput),
+    /// A [`MouseButtonInput`] event.
+    MouseButton(MouseButtonInput),
+    /// A [`MouseMotion`] event.
+    MouseMotion(MouseMotion),
+    /// A [`MouseWheel`] event.
+    MouseWheel(MouseWheel),
+    /// A [`CursorMoved`] event.
+    CursorMoved(CursorMoved),
+    /// A [`GamepadEvent`].
+    Gamepad(GamepadEvent),
+}
+
+/// Hashes the names of the plugins of `app`, and of the systems of each of its schedules.
+///
+/// A recording can only be played back by an app with the same fingerprint, since different
+/// plugins or systems would make the playback diverge.
+pub fn app_fingerprint(app: &App) -> u64 {
+    let mut names: Vec<String> = app.plugin_names().map(str::to_string).collect();
+    if let Some(schedules) = app.world().get_resource::<Schedules>() {
+        for (label, schedule) in schedules.iter() {
+            for (_, system, _) in schedule.graph().systems() {
+                names.push(format!("{label:?}/{}", system.name()));
+            }
+        }
+    }
+    // Schedules and plugin names aren't iterated in a stable order
+    names.sort_unstable();
+
+    // FNV-1a, which is stable across runs and platforms, unlike the default hasher
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in names.iter().flat_map(|name| name.bytes().chain([0])) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+fn is_recording(replay: Res<Replay>) -> bool {
+    replay.is_recording()
+}
+
+fn is_playing(replay: Res<Replay>) -> bool {
+    replay.is_playing()
+}
+
+fn set_frame_time(mut replay: ResMut<Replay>, mut strategy: ResMut<TimeUpdateStrategy>) {
+    if !replay.is_playing() {
+        return;
+    }
+    match replay.recording.frames.get(replay.frame) {
+        Some(frame) => *strategy = TimeUpdateStrategy::ManualDuration(frame.delta),
+        None => {
+            info!("Replay finished after {} frames.", replay.frame);
+            replay.state = ReplayState::Finished;
+            *strategy = TimeUpdateStrategy::Automatic;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn record_input(
+    mut replay: ResMut<Replay>,
+    time: Res<Time<Real>>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse_button: EventReader<MouseButtonInput>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut gamepad: EventReader<GamepadEvent>,
+) {
+    let mut events = Vec::new();
+    events.extend(keyboard.read().cloned().map(ReplayInput::Keyboard));
+    events.extend(mouse_button.read().copied().map(ReplayInput::MouseButton));
+    events.extend(mouse_motion.read().copied().map(ReplayInput::MouseMotion));
+    events.extend(mouse_wheel.read().copied().map(ReplayInput::MouseWheel));
+    events.extend(cursor_moved.read().cloned().map(ReplayInput::CursorMoved));
+    events.extend(gamepad.read().cloned().map(ReplayInput::Gamepad));
+    replay.recording.frames.push(ReplayFrame {
+        delta: time.delta(),
+        events,
+        world_hash: None,
+    });
+}
+
+/// Readers of the live input events, which are the ones sent after the replayed events.
+#[derive(Default)]
+struct LiveInput {
+    keyboard: ManualEventReader<KeyboardInput>,
+    mouse_button: ManualEventReader<MouseButtonInput>,
+    mouse_motion: ManualEventReader<MouseMotion>,
+    mouse_wheel: ManualEventReader<MouseWheel>,
+    cursor_moved: ManualEventReader<CursorMoved>,
+    gamepad: ManualEventReader<GamepadEvent>,
+}
+
+impl LiveInput {
+    /// Marks the events sent so far as read, so that only the events sent afterwards are live.
+    fn mark_read(&mut self, world: &World) {
+        fn mark<E: Event>(world: &World, reader: &mut ManualEventReader<E>) {
+            if let Some(events) = world.get_resource::<Events<E>>() {
+                reader.clear(events);
+            }
+        }
+        mark(world, &mut self.keyboard);
+        mark(world, &mut self.mouse_button);
+        mark(world, &mut self.mouse_motion);
+        mark(world, &mut self.mouse_wheel);
+        mark(world, &mut self.cursor_moved);
+        mark(world, &mut self.gamepad);
+    }
+}
+
+fn play_input(world: &mut World, mut live: Local<LiveInput>) {
+    world.resource_scope(|world, replay: Mut<Replay>| {
+        // Drop the live input of the replayed devices, so that it doesn't interfere with the replay
+        let devices = &replay.devices;
+        if devices.keyboard {
+            suppress_live_events(world, &mut live.keyboard, |_| true);
+        }
+        if devices.mouse {
+            suppress_live_events(world, &mut live.mouse_button, |_| true);
+            suppress_live_events(world, &mut live.mouse_motion, |_| true);
+            suppress_live_events(world, &mut live.mouse_wheel, |_| true);
+            suppress_live_events(world, &mut live.cursor_moved, |_| true);
+        }
+        suppress_live_events(world, &mut live.gamepad, |event| {
+            devices.gamepads.contains(&gamepad_of(event))
+        });
+
+        if let Some(frame) = replay.recording.frames.get(replay.frame) {
+            for event in frame.events.iter().cloned() {
+                send_replayed_input(world, event);
+            }
+        }
+        live.mark_read(world);
+    });
+}
+
+fn send_replayed_input(world: &mut World, event: ReplayInput) {
+    match event {
+        ReplayInput::Keyboard(event) => {
+            world.send_event(event);
+        }
+        ReplayInput::MouseButton(event) => {
+            world.send_event(event);
+        }
+        ReplayInput::MouseMotion(event) => {
+            world.send_event(event);
+        }
+        ReplayInput::MouseWheel(event) => {
+            world.send_event(event);
+        }
+        ReplayInput::CursorMoved(event) => {
+            world.send_event(event);
+        }
+        ReplayInput::Gamepad(event) => {
+            world.send_event(event);
+        }
+    }
+}
+
+/// Removes the live events not read by `live` yet for which `suppress` returns `true`.
+///
+/// The backends send their events before the update of the app, so the events may have been
+/// swapped to the previous buffer in [`First`]: both buffers are rebuilt. The events read before,
+/// such as the replayed events of the previous frame, are dropped, since sending them again would
+/// make them read twice.
+fn suppress_live_events<E: Event + Clone>(
+    world: &mut World,
+    live: &mut ManualEventReader<E>,
+    suppress: impl Fn(&E) -> bool,
+) {
+    let Some(mut events) = world.get_resource_mut::<Events<E>>() else {
+        return;
+    };
+    let live_events: Vec<E> = live.read(&events).cloned().collect();
+    if !live_events.iter().any(&suppress) {
+        return;
+    }
+    events.clear();
+    events.send_batch(live_events.into_iter().filter(|event| !suppress(event)));
+}
+
+fn check_world_hash(world: &mut World) {
+    let replay = world.resource::<Replay>();
+    let (state, frame, world_hash) = (replay.state, replay.frame, replay.world_hash);
+    let interval = replay.recording.hash_interval as usize;
+    if state == ReplayState::Finished {
+        return;
+    }
+    let hash = match world_hash {
+        Some(world_hash) if interval > 0 && frame % interval == 0 => Some(world_hash(world)),
+        _ => None,
+    };
+
+    let mut replay = world.resource_mut::<Replay>();
+    replay.frame += 1;
+    if state == ReplayState::Recording {
+        if let Some(recorded) = replay.recording.frames.last_mut() {
+            recorded.world_hash = hash;
+        }
+        return;
+    }
+
+    let expected = replay.recording.frames[frame].world_hash;
+    if let (Some(expected), Some(actual)) = (expected, hash) {
+        if expected != actual {
+            error!("Replay diverged at frame {frame}: expected world hash {expected:x}, got {actual:x}.");
+            world.send_event(ReplayDiverged {
+                frame,
+                expected,
+                actual,
+            });
+        }
+    }
+}
+
+fn save_recording_on_exit(mut exits: EventReader<AppExit>, replay: Res<Replay>) {
+    if exits.read().next().is_none() || !replay.is_recording() {
+        return;
+    }
+    match replay.save() {
+        Ok(()) => info!("Saved replay of {} frames.", replay.recording.frames.len()),
+        Err(err) => error!("Failed to save replay: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_input::{
+        gamepad::{GamepadButtonChangedEvent, GamepadButtonType},
+        keyboard::{Key, KeyCode, NativeKey},
+        mouse::MouseButton,
+        ButtonInput, ButtonState, InputPlugin,
+    };
+    use bevy_time::TimePlugin;
+
+    use super::*;
+
+    #[test]
+    fn only_live_input_of_replayed_gamepads_is_suppressed() {
+        let press = |id| {
+            GamepadEvent::Button(GamepadButtonChangedEvent::new(
+                Gamepad::new(id),
+                GamepadButtonType::South,
+                1.0,
+            ))
+        };
+        let recording = ReplayRecording {
+            frames: vec![ReplayFrame {
+                events: vec![ReplayInput::Gamepad(press(0))],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let devices = ReplayedDevices::new(&recording);
+        assert!(!devices.keyboard && !devices.mouse);
+
+        let mut world = World::new();
+        world.init_resource::<Events<GamepadEvent>>();
+        world.send_event(press(0));
+        world.send_event(press(1));
+        suppress_live_events(&mut world, &mut ManualEventReader::default(), |event| {
+            devices.gamepads.contains(&gamepad_of(event))
+        });
+
+        let events = world.resource::<Events<GamepadEvent>>();
+        let live: Vec<_> = events.get_reader().read(events).map(gamepad_of).collect();
+        assert_eq!(live, vec![Gamepad::new(1)]);
+    }
+
+    #[derive(Resource, Default)]
+    struct ReadKeys(Vec<KeyCode>);
+
+    fn read_keys(mut keyboard: EventReader<KeyboardInput>, mut read: ResMut<ReadKeys>) {
+        read.0.extend(keyboard.read().map(|event| event.key_code));
+    }
+
+    fn replay_app(plugin: ReplayPlugin) -> App {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, InputPlugin, plugin))
+            .init_resource::<ReadKeys>()
+            .add_systems(Update, read_keys);
+        app.finish();
+        app.cleanup();
+        app
+    }
+
+    #[test]
+    fn live_input_is_not_read_during_playback() {
+        let key = |key_code| KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        };
+        let path =
+            std::env::temp_dir().join(format!("bevy_live_input_replay_{}.ron", std::process::id()));
+        let frame = ReplayFrame {
+            delta: Duration::from_millis(16),
+            ..Default::default()
+        };
+        let recording = ReplayRecording {
+            fingerprint: app_fingerprint(&replay_app(ReplayPlugin::record(&path))),
+            frames: vec![
+                ReplayFrame {
+                    events: vec![ReplayInput::Keyboard(key(KeyCode::KeyA))],
+                    ..frame.clone()
+                },
+                frame.clone(),
+                frame,
+            ],
+            ..Default::default()
+        };
+        recording.save(&path).unwrap();
+        let mut app = replay_app(ReplayPlugin::playback(&path));
+        std::fs::remove_file(&path).unwrap();
+
+        app.update();
+        // The windowing backend sends its events between updates
+        app.world_mut().send_event(key(KeyCode::KeyB));
+        app.update();
+        app.world_mut().send_event(key(KeyCode::KeyC));
+        app.update();
+        assert!(app.world().resource::<Replay>().is_playing());
+        assert_eq!(app.world().resource::<ReadKeys>().0, [KeyCode::KeyA]);
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(!keys.pressed(KeyCode::KeyB) && !keys.pressed(KeyCode::KeyC));
+
+        // Live input is back once the replay is finished
+        app.world_mut().send_event(key(KeyCode::KeyD));
+        app.update();
+        assert!(!app.world().resource::<Replay>().is_playing());
+        assert_eq!(
+            app.world().resource::<ReadKeys>().0,
+            [KeyCode::KeyA, KeyCode::KeyD]
+        );
+    }
+
+    #[test]
+    fn recording_round_trip() {
+        let recording = ReplayRecording {
+            fingerprint: 1,
+            seed: 2,
+            hash_interval: 60,
+            frames: vec![
+                ReplayFrame {
+                    delta: Duration::from_millis(16),
+                    events: vec![ReplayInput::MouseButton(MouseButtonInput {
+                        button: MouseButton::Left,
+                        state: ButtonState::Pressed,
+                        window: Entity::PLACEHOLDER,
+                    })],
+                    world_hash: Some(3),
+                },
+                ReplayFrame {
+                    delta: Duration::from_millis(17),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let serialized = ron::to_string(&recording).unwrap();
+        // Frames without events or hash are kept compact
+        assert!(serialized.ends_with("(delta:(secs:0,nanos:17000000))])"));
+        assert_eq!(
+            ron::from_str::<ReplayRecording>(&serialized).unwrap(),
+            recording
+        );
+    }
+}
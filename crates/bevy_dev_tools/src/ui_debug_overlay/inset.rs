@@ -153,6 +153,11 @@ impl<'w, 's> InsetGizmo<'w, 's> {
         let (start, end) = (self.relative(start), self.relative(end));
         self.draw.line_2d(start, end, color);
     }
+    /// Draws a line from `start` to `end`, without offsetting it from the lines already drawn.
+    pub(super) fn segment_2d(&mut self, start: Vec2, end: Vec2, color: Color) {
+        let (start, end) = (self.relative(start), self.relative(end));
+        self.draw.line_2d(start, end, color);
+    }
     pub(super) fn set_scope(&mut self, rect: LayoutRect) {
         let (left, right, top, bottom) = rect_border_axis(rect);
         self.known_x.add(left, 1);
@@ -167,6 +172,19 @@ impl<'w, 's> InsetGizmo<'w, 's> {
         self.known_y.remove(top, 1);
         self.known_y.remove(bottom, -1);
     }
+    /// Draws the outline of `rect`, without offsetting it from the lines already drawn.
+    pub(super) fn outline_2d(&mut self, rect: LayoutRect, color: Color) {
+        let (left, right, top, bottom) = rect_border_axis(rect);
+        let strip = [
+            Vec2::new(left, top),
+            Vec2::new(left, bottom),
+            Vec2::new(right, bottom),
+            Vec2::new(right, top),
+            Vec2::new(left, top),
+        ];
+        self.draw
+            .linestrip_2d(strip.map(|v| self.relative(v)), color);
+    }
     pub(super) fn rect_2d(&mut self, rect: LayoutRect, color: Color) {
         let (left, right, top, bottom) = rect_border_axis(rect);
         if approx_eq(left, right) {
@@ -2,7 +2,10 @@
 use std::any::{Any, TypeId};
 
 use bevy_app::{App, Plugin, PostUpdate};
-use bevy_color::Hsla;
+use bevy_color::{
+    palettes::css::{DEEP_SKY_BLUE, HOT_PINK, LIMEGREEN, ORANGE},
+    Color, Hsla,
+};
 use bevy_core::Name;
 use bevy_core_pipeline::core_2d::Camera2dBundle;
 use bevy_ecs::{prelude::*, system::SystemParam};
@@ -14,8 +17,13 @@ use bevy_render::{
     prelude::*,
     view::{RenderLayers, VisibilitySystems},
 };
-use bevy_transform::{prelude::GlobalTransform, TransformSystem};
-use bevy_ui::{DefaultUiCamera, Display, Node, Style, TargetCamera, UiScale};
+use bevy_sprite::Anchor;
+use bevy_text::{Text, Text2dBundle, TextLayoutInfo, TextStyle};
+use bevy_transform::{
+    prelude::{GlobalTransform, Transform},
+    TransformSystem,
+};
+use bevy_ui::{DefaultUiCamera, Display, Node, Style, TargetCamera, UiRect, UiScale, Val};
 use bevy_utils::{default, warn_once};
 use bevy_window::{PrimaryWindow, Window, WindowRef};
 
@@ -45,17 +53,71 @@ impl LayoutRect {
         this.pos -= this.size / 2.;
         this
     }
+
+    /// Grows the rect by the given `[left, right, top, bottom]` insets, or shrinks it if they're negative.
+    fn grow(self, [left, right, top, bottom]: [f32; 4]) -> Self {
+        Self {
+            pos: self.pos - Vec2::new(left, top),
+            size: self.size + Vec2::new(left + right, top + bottom),
+        }
+    }
+
+    fn contains(&self, point: Vec2) -> bool {
+        let offset = point - self.pos;
+        offset.cmpge(Vec2::ZERO).all() && offset.cmple(self.size).all()
+    }
 }
 
+#[derive(Component, Debug, Clone, Default)]
+struct DebugSizeLabel;
+
+#[derive(Component, Debug, Clone, Default)]
+struct DebugLineLabel;
+
+/// The node under the cursor with the highest stack index, found while outlining the nodes.
+#[derive(Resource, Default)]
+struct HoveredNode(Option<(LayoutRect, Vec2)>);
+
+/// The labels of the lines of text outlined with [`UiDebugOptions::show_text_lines`], and where
+/// to show them.
+#[derive(Resource, Default)]
+struct TextLineLabels(Vec<(Vec2, String)>);
+
 #[derive(Component, Debug, Clone, Default)]
 struct DebugOverlayCamera;
 
 /// The debug overlay options.
-#[derive(Resource, Clone, Default)]
+#[derive(Resource, Clone)]
 pub struct UiDebugOptions {
     /// Whether the overlay is enabled.
     pub enabled: bool,
+    /// Whether to outline the rect of each node.
+    pub show_rects: bool,
+    /// Whether to outline the margin and the padding of each node.
+    ///
+    /// The margin is drawn in orange outside of the node, and the padding in green inside of it.
+    pub show_margins: bool,
+    /// Whether to outline the lines of each text.
+    ///
+    /// The box of each line, from its ascent to its descent, is drawn in blue, and its baseline in
+    /// pink. The height of each line is shown next to it, followed by its ascent and descent.
+    pub show_text_lines: bool,
     layout_gizmos_camera: Option<Entity>,
+    size_label: Option<Entity>,
+    line_labels: Vec<Entity>,
+}
+impl Default for UiDebugOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            show_rects: true,
+            show_margins: false,
+            show_text_lines: false,
+            layout_gizmos_camera: None,
+            size_label: None,
+            line_labels: Vec::new(),
+        }
+    }
 }
 impl UiDebugOptions {
     /// This will toggle the enabled field, setting it to false if true and true if false.
@@ -119,26 +181,48 @@ fn update_debug_camera(
     }
 }
 
+/// The state shared while outlining the nodes.
+struct OutlineContext<'a> {
+    options: &'a UiDebugOptions,
+    /// The UI scale.
+    scale: f32,
+    /// The scale factor of the primary window.
+    window_scale: f32,
+    /// The logical size of the viewport, used to resolve viewport-relative margins.
+    viewport_size: Vec2,
+    /// The logical position of the cursor in the primary window.
+    cursor: Option<Vec2>,
+    hovered: Option<(u32, LayoutRect, Vec2)>,
+    line_labels: Vec<(Vec2, String)>,
+}
+
 /// The function that goes over every children of given [`Entity`], skipping the not visible ones and drawing the gizmos outlines.
-fn outline_nodes(outline: &OutlineParam, draw: &mut InsetGizmo, this_entity: Entity, scale: f32) {
+fn outline_nodes(
+    outline: &OutlineParam,
+    draw: &mut InsetGizmo,
+    context: &mut OutlineContext,
+    this_entity: Entity,
+    parent_width: f32,
+) {
     let Ok(to_iter) = outline.children.get(this_entity) else {
         return;
     };
 
     for (entity, trans, node, style, children) in outline.nodes.iter_many(to_iter) {
-        if style.is_none() || style.is_some_and(|s| matches!(s.display, Display::None)) {
+        let Some(style) = style.filter(|s| !matches!(s.display, Display::None)) else {
             continue;
-        }
+        };
 
         if let Ok(view_visibility) = outline.view_visibility.get(entity) {
             if !view_visibility.get() {
                 continue;
             }
         }
-        let rect = LayoutRect::new(trans, node, scale);
-        outline_node(entity, rect, draw);
+        let rect = LayoutRect::new(trans, node, context.scale);
+        outline_node(entity, rect, node, Some(style), parent_width, context, draw);
+        outline_text_lines(outline, entity, rect, node, context, draw);
         if children.is_some() {
-            outline_nodes(outline, draw, entity, scale);
+            outline_nodes(outline, draw, context, entity, node.size().x);
         }
         draw.clear_scope(rect);
     }
@@ -158,6 +242,7 @@ struct OutlineParam<'w, 's> {
     children: Query<'w, 's, &'static Children>,
     nodes: Query<'w, 's, NodesQuery>,
     view_visibility: Query<'w, 's, &'static ViewVisibility>,
+    text_layouts: Query<'w, 's, &'static TextLayoutInfo, With<Text>>,
     ui_scale: Res<'w, UiScale>,
 }
 
@@ -181,15 +266,19 @@ fn outline_roots(
             Entity,
             &GlobalTransform,
             &Node,
+            Option<&Style>,
             Option<&ViewVisibility>,
             Option<&TargetCamera>,
         ),
         Without<Parent>,
     >,
-    window: Query<&Window, With<PrimaryWindow>>,
     nonprimary_windows: Query<&Window, Without<PrimaryWindow>>,
     options: Res<UiDebugOptions>,
+    mut hovered: ResMut<HoveredNode>,
+    mut line_labels: ResMut<TextLineLabels>,
 ) {
+    hovered.0 = None;
+    line_labels.0.clear();
     if !options.enabled {
         return;
     }
@@ -199,8 +288,18 @@ fn outline_roots(
             you might notice gaps between container lines"
         );
     }
-    let window_scale = window.get_single().map_or(1., Window::scale_factor);
+    let window = cam.primary_window.get_single().ok();
+    let window_scale = window.map_or(1., Window::scale_factor);
     let scale_factor = outline.ui_scale.0;
+    let mut context = OutlineContext {
+        options: &options,
+        scale: scale_factor,
+        window_scale,
+        viewport_size: window.map_or(Vec2::ZERO, Window::size) / scale_factor,
+        cursor: window.and_then(Window::cursor_position),
+        hovered: None,
+        line_labels: Vec::new(),
+    };
 
     // We let the line be defined by the window scale alone
     let line_width = outline
@@ -209,7 +308,7 @@ fn outline_roots(
         .map_or(2., |(config, _)| config.line_width)
         / window_scale;
     let mut draw = InsetGizmo::new(draw, cam.debug_camera, line_width);
-    for (entity, trans, node, view_visibility, maybe_target_camera) in &roots {
+    for (entity, trans, node, style, view_visibility, maybe_target_camera) in &roots {
         if let Some(view_visibility) = view_visibility {
             // If the entity isn't visible, we will not draw any lines.
             if !view_visibility.get() {
@@ -241,17 +340,226 @@ fn outline_roots(
         }
 
         let rect = LayoutRect::new(trans, node, scale_factor);
-        outline_node(entity, rect, &mut draw);
-        outline_nodes(&outline, &mut draw, entity, scale_factor);
+        let viewport_width = context.viewport_size.x;
+        outline_node(
+            entity,
+            rect,
+            node,
+            style,
+            viewport_width,
+            &mut context,
+            &mut draw,
+        );
+        outline_text_lines(&outline, entity, rect, node, &mut context, &mut draw);
+        outline_nodes(&outline, &mut draw, &mut context, entity, node.size().x);
     }
+    hovered.0 = context.hovered.map(|(_, rect, size)| (rect, size));
+    line_labels.0 = context.line_labels;
 }
 
 /// Function responsible for drawing the gizmos lines around the given Entity
-fn outline_node(entity: Entity, rect: LayoutRect, draw: &mut InsetGizmo) {
-    let color = Hsla::sequential_dispersed(entity.index());
-
-    draw.rect_2d(rect, color.into());
+fn outline_node(
+    entity: Entity,
+    rect: LayoutRect,
+    node: &Node,
+    style: Option<&Style>,
+    parent_width: f32,
+    context: &mut OutlineContext,
+    draw: &mut InsetGizmo,
+) {
+    if context.options.show_rects {
+        let color = Hsla::sequential_dispersed(entity.index());
+        draw.rect_2d(rect, color.into());
+    }
+    if let Some(style) = style.filter(|_| context.options.show_margins) {
+        let resolve =
+            |inset| resolve_insets(inset, parent_width, context.viewport_size, context.scale);
+        draw.outline_2d(rect.grow(resolve(style.margin)), ORANGE.into());
+        let padding = resolve(style.padding).map(|value| -value);
+        draw.outline_2d(rect.grow(padding), LIMEGREEN.into());
+    }
     draw.set_scope(rect);
+
+    let is_on_top = context
+        .hovered
+        .map_or(true, |(stack_index, ..)| node.stack_index() >= stack_index);
+    if is_on_top && context.cursor.is_some_and(|cursor| rect.contains(cursor)) {
+        context.hovered = Some((node.stack_index(), rect, node.size()));
+    }
+}
+
+/// Outlines the lines of the text of `entity` in `rect`, if it has any, with their baselines.
+fn outline_text_lines(
+    outline: &OutlineParam,
+    entity: Entity,
+    rect: LayoutRect,
+    node: &Node,
+    context: &mut OutlineContext,
+    draw: &mut InsetGizmo,
+) {
+    if !context.options.show_text_lines {
+        return;
+    }
+    let Ok(layout) = outline.text_layouts.get(entity) else {
+        return;
+    };
+    // The lines are laid out in physical pixels, from the top left corner of the content box of
+    // the node, inside its border and padding
+    let window_scale = context.window_scale;
+    let inset = node.content_inset();
+    let origin = rect.pos + Vec2::new(inset.left, inset.top) * context.scale;
+    for line in &layout.lines {
+        let line_rect = LayoutRect {
+            pos: origin + line.rect.min / window_scale,
+            size: line.rect.size() / window_scale,
+        };
+        draw.outline_2d(line_rect, DEEP_SKY_BLUE.into());
+        let baseline = origin.y + line.baseline / window_scale;
+        let (left, right) = (line_rect.pos.x, line_rect.pos.x + line_rect.size.x);
+        draw.segment_2d(
+            Vec2::new(left, baseline),
+            Vec2::new(right, baseline),
+            HOT_PINK.into(),
+        );
+
+        // In logical pixels, like the size of the hovered node
+        let to_logical = (window_scale * context.scale).recip();
+        let label = format!(
+            "{:.1} ({:.1} + {:.1})",
+            line.rect.height() * to_logical,
+            line.ascent * to_logical,
+            line.descent * to_logical
+        );
+        context
+            .line_labels
+            .push((Vec2::new(right, line_rect.pos.y), label));
+    }
+}
+
+/// Resolves a margin or a padding to `[left, right, top, bottom]`, in scaled logical pixels.
+///
+/// Like in CSS, percentages are relative to the width of the parent on every side.
+/// `Auto` margins can't be resolved from the style, and are drawn empty.
+fn resolve_insets(inset: UiRect, parent_width: f32, viewport_size: Vec2, scale: f32) -> [f32; 4] {
+    [inset.left, inset.right, inset.top, inset.bottom]
+        .map(|value: Val| value.resolve(parent_width, viewport_size).unwrap_or(0.) * scale)
+}
+
+/// The system that shows the size of the hovered node next to it.
+fn update_size_label(
+    mut commands: Commands,
+    mut options: ResMut<UiDebugOptions>,
+    hovered: Res<HoveredNode>,
+    debug_camera: Query<&Camera, With<DebugOverlayCamera>>,
+    mut labels: Query<(&mut Text, &mut Transform, &mut Visibility), With<DebugSizeLabel>>,
+) {
+    let label = *options
+        .bypass_change_detection()
+        .size_label
+        .get_or_insert_with(|| {
+            commands
+                .spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 14.,
+                                color: Color::WHITE,
+                                ..default()
+                            },
+                        ),
+                        text_anchor: Anchor::BottomLeft,
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                    LAYOUT_DEBUG_LAYERS.clone(),
+                    DebugSizeLabel,
+                    Name::new("Layout Debug Size Label"),
+                ))
+                .id()
+        });
+    let Ok((mut text, mut transform, mut visibility)) = labels.get_mut(label) else {
+        return;
+    };
+    let hovered = hovered.0.filter(|_| options.enabled);
+    let Some(((rect, size), camera)) = hovered.zip(debug_camera.get_single().ok()) else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+
+    // Above the top left corner of the node, in the coordinates of the debug camera
+    let Some(position) = camera.world_to_viewport(&GlobalTransform::IDENTITY, rect.pos.extend(0.))
+    else {
+        return;
+    };
+    visibility.set_if_neq(Visibility::Inherited);
+    transform.translation = position.extend(transform.translation.z);
+    let value = format!("{:.1} x {:.1}", size.x, size.y);
+    if text.sections[0].value != value {
+        text.sections[0].value = value;
+    }
+}
+
+/// The system that shows the heights of the outlined lines of text next to them.
+fn update_line_labels(
+    mut commands: Commands,
+    mut options: ResMut<UiDebugOptions>,
+    line_labels: Res<TextLineLabels>,
+    debug_camera: Query<&Camera, With<DebugOverlayCamera>>,
+    mut labels: Query<(&mut Text, &mut Transform, &mut Visibility), With<DebugLineLabel>>,
+) {
+    // The labels are kept for the next frames, and hidden while there are fewer lines
+    let spawned = &mut options.bypass_change_detection().line_labels;
+    while spawned.len() < line_labels.0.len() {
+        let label = commands
+            .spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font_size: 12.,
+                            color: Color::WHITE,
+                            ..default()
+                        },
+                    ),
+                    text_anchor: Anchor::TopLeft,
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                LAYOUT_DEBUG_LAYERS.clone(),
+                DebugLineLabel,
+                Name::new("Layout Debug Line Label"),
+            ))
+            .id();
+        spawned.push(label);
+    }
+
+    let camera = debug_camera.get_single().ok();
+    let mut shown = line_labels.0.iter();
+    for &label in spawned.iter() {
+        let Ok((mut text, mut transform, mut visibility)) = labels.get_mut(label) else {
+            continue;
+        };
+        // Next to the top right corner of the line, in the coordinates of the debug camera
+        let Some((position, value)) =
+            shown
+                .next()
+                .zip(camera)
+                .and_then(|((corner, value), camera)| {
+                    let position =
+                        camera.world_to_viewport(&GlobalTransform::IDENTITY, corner.extend(0.))?;
+                    Some((position, value))
+                })
+        else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+        transform.translation = position.extend(transform.translation.z);
+        if text.sections[0].value != *value {
+            text.sections[0].value.clone_from(value);
+        }
+    }
 }
 
 /// The debug overlay plugin.
@@ -260,10 +568,15 @@ fn outline_node(entity: Entity, rect: LayoutRect, draw: &mut InsetGizmo) {
 ///
 /// Note that due to limitation with [`bevy_gizmos`], multiple windows with this feature
 /// enabled isn't supported and the lines are only drawn in the [`PrimaryWindow`]
+///
+/// [Disabling](App::disable_plugin) this plugin hides the overlay and keeps its resources, such as
+/// the [`UiDebugOptions`]: the overlay is shown again once the plugin is enabled again.
 pub struct DebugUiPlugin;
 impl Plugin for DebugUiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<UiDebugOptions>()
+            .init_resource::<HoveredNode>()
+            .init_resource::<TextLineLabels>()
             .init_gizmo_group::<UiGizmosDebug>()
             .add_systems(
                 PostUpdate,
@@ -273,8 +586,39 @@ impl Plugin for DebugUiPlugin {
                         .after(TransformSystem::TransformPropagate)
                         // This needs to run before VisibilityPropagate so it can relies on ViewVisibility
                         .before(VisibilitySystems::VisibilityPropagate),
+                    update_size_label.before(VisibilitySystems::VisibilityPropagate),
+                    update_line_labels.before(VisibilitySystems::VisibilityPropagate),
                 )
                     .chain(),
             );
     }
+
+    fn cleanup_on_disable(&self, app: &mut App) {
+        let world = app.world_mut();
+        // Mark the options as changed, so that the debug camera is activated again once the
+        // plugin is enabled again
+        let mut options = world.resource_mut::<UiDebugOptions>();
+        options.set_changed();
+        let camera = options.layout_gizmos_camera;
+        let labels: Vec<Entity> = options
+            .size_label
+            .iter()
+            .chain(&options.line_labels)
+            .copied()
+            .collect();
+        if let Some(mut camera) = camera.and_then(|camera| world.get_mut::<Camera>(camera)) {
+            camera.is_active = false;
+        }
+        if let Some((config, _)) = world
+            .resource_mut::<GizmoConfigStore>()
+            .get_config_mut_dyn(&TypeId::of::<UiGizmosDebug>())
+        {
+            config.enabled = false;
+        }
+        for label in labels {
+            if let Some(mut visibility) = world.get_mut::<Visibility>(label) {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
 }
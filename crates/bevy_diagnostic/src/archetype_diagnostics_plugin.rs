@@ -0,0 +1,276 @@
+use std::{borrow::Cow, mem::size_of};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{archetype::ArchetypeId, component::Tick, entity::Entity, prelude::*};
+use bevy_time::{Real, Time, Timer, TimerMode};
+use bevy_utils::{get_short_name, Duration, Instant};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+
+/// Adds "archetype count", "table memory" and "top archetypes" diagnostics to an App.
+///
+/// The top archetypes are the [`top_archetypes`](Self::top_archetypes) archetypes with the most
+/// entities, published as their entity count, with their components listed in the suffix of the
+/// [`Diagnostic`]. Collecting them iterates over every archetype, so they are only
+/// collected every [`interval`](Self::interval).
+///
+/// # See also
+///
+/// [`ArchetypeStats::collect`] to get the same statistics from a [`World`], for instance in tests.
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct ArchetypeDiagnosticsPlugin {
+    /// The number of archetypes with the most entities to publish.
+    pub top_archetypes: usize,
+    /// The maximum number of components listed for each top archetype.
+    pub max_listed_components: usize,
+    /// The time between two collections of the statistics.
+    pub interval: Duration,
+}
+
+impl Default for ArchetypeDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            top_archetypes: 5,
+            max_listed_components: 6,
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Plugin for ArchetypeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT).with_smoothing_factor(0.0))
+            .register_diagnostic(
+                Diagnostic::new(Self::TABLE_MEMORY)
+                    .with_suffix("KiB")
+                    .with_smoothing_factor(0.0),
+            );
+        for rank in 0..self.top_archetypes {
+            app.register_diagnostic(
+                Diagnostic::new(Self::top_archetype(rank)).with_smoothing_factor(0.0),
+            );
+        }
+        app.insert_resource(ArchetypeDiagnosticsState {
+            timer: Timer::new(self.interval, TimerMode::Repeating),
+            top_archetypes: self.top_archetypes,
+            max_listed_components: self.max_listed_components,
+        })
+        .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl ArchetypeDiagnosticsPlugin {
+    /// The number of archetypes, including the empty ones.
+    pub const ARCHETYPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("archetype/count");
+    /// An estimate of the memory allocated by the tables storing the components, in KiB.
+    pub const TABLE_MEMORY: DiagnosticPath = DiagnosticPath::const_new("archetype/table_memory");
+
+    /// The path of the diagnostic of the archetype with the `rank`-th most entities, starting at 0.
+    pub fn top_archetype(rank: usize) -> DiagnosticPath {
+        DiagnosticPath::new(format!("archetype/top/{rank}"))
+    }
+
+    pub fn diagnostic_system(world: &mut World) {
+        let delta = world
+            .get_resource::<Time<Real>>()
+            .map_or(Duration::ZERO, Time::delta);
+        let mut state = world.resource_mut::<ArchetypeDiagnosticsState>();
+        if !state.timer.tick(delta).just_finished() {
+            return;
+        }
+        let (top_archetypes, max_listed_components) =
+            (state.top_archetypes, state.max_listed_components);
+
+        let stats = ArchetypeStats::collect(world);
+        let time = Instant::now();
+        let mut store = world.resource_mut::<DiagnosticsStore>();
+        let mut measure = |path: &DiagnosticPath, value: f64, suffix: Option<String>| {
+            if let Some(diagnostic) = store
+                .get_mut(path)
+                .filter(|diagnostic| diagnostic.is_enabled)
+            {
+                if let Some(suffix) = suffix {
+                    diagnostic.suffix = Cow::Owned(suffix);
+                }
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        };
+
+        measure(&Self::ARCHETYPE_COUNT, stats.archetype_count as f64, None);
+        measure(
+            &Self::TABLE_MEMORY,
+            stats.table_memory as f64 / 1024.0,
+            None,
+        );
+        for rank in 0..top_archetypes {
+            // Ranks past the number of archetypes with entities are published as empty
+            let (entities, suffix) =
+                stats
+                    .archetypes
+                    .get(rank)
+                    .map_or((0, String::new()), |archetype| {
+                        let components = archetype.component_list(max_listed_components);
+                        (archetype.entity_count, format!(" entities: {components}"))
+                    });
+            measure(&Self::top_archetype(rank), entities as f64, Some(suffix));
+        }
+    }
+}
+
+#[derive(Resource)]
+struct ArchetypeDiagnosticsState {
+    timer: Timer,
+    top_archetypes: usize,
+    max_listed_components: usize,
+}
+
+/// Statistics about the archetypes and tables of a [`World`].
+///
+/// ```
+/// # use bevy_diagnostic::ArchetypeStats;
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(Component)]
+/// # struct Enemy;
+/// let mut world = World::new();
+/// world.spawn_batch((0..10).map(|_| Enemy));
+///
+/// let stats = ArchetypeStats::collect(&world);
+/// assert!(stats.archetype_count <= 2);
+/// assert_eq!(stats.archetypes[0].entity_count, 10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArchetypeStats {
+    /// The number of archetypes, including the empty ones.
+    pub archetype_count: usize,
+    /// An estimate of the memory allocated by the tables storing the components, in bytes.
+    ///
+    /// This counts the capacity of the columns and their change detection ticks, but not the
+    /// memory allocated by the components themselves, nor by sparse sets.
+    pub table_memory: usize,
+    /// The archetypes with entities, from the one with the most entities to the one with the
+    /// least, in the order of their ids when they have as many entities.
+    pub archetypes: Vec<ArchetypeStat>,
+}
+
+/// The statistics of an archetype, from [`ArchetypeStats`].
+#[derive(Debug, Clone)]
+pub struct ArchetypeStat {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The short names of the components of the archetype, sorted alphabetically.
+    pub components: Vec<String>,
+}
+
+impl ArchetypeStat {
+    /// Returns the components of the archetype, separated by commas.
+    ///
+    /// Only the first `max_components` components in alphabetical order are listed,
+    /// followed by the number of omitted components.
+    pub fn component_list(&self, max_components: usize) -> String {
+        let mut list = self
+            .components
+            .iter()
+            .take(max_components)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let omitted = self.components.len().saturating_sub(max_components);
+        if omitted > 0 {
+            if !list.is_empty() {
+                list.push_str(", ");
+            }
+            list.push_str(&format!("+{omitted} more"));
+        }
+        list
+    }
+}
+
+impl ArchetypeStats {
+    /// Collects the statistics of the archetypes and tables of `world`.
+    pub fn collect(world: &World) -> Self {
+        let components = world.components();
+        let mut archetypes: Vec<_> = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| !archetype.is_empty())
+            .map(|archetype| {
+                let mut names: Vec<_> = archetype
+                    .components()
+                    .filter_map(|id| components.get_name(id))
+                    .map(get_short_name)
+                    .collect();
+                names.sort_unstable();
+                ArchetypeStat {
+                    id: archetype.id(),
+                    entity_count: archetype.len(),
+                    components: names,
+                }
+            })
+            .collect();
+        archetypes.sort_by(|a, b| {
+            b.entity_count
+                .cmp(&a.entity_count)
+                .then(a.id.index().cmp(&b.id.index()))
+        });
+
+        let table_memory = world
+            .storages()
+            .tables
+            .iter()
+            .map(|table| {
+                let row_size = size_of::<Entity>()
+                    + table
+                        .iter()
+                        .map(|column| column.item_layout().size() + 2 * size_of::<Tick>())
+                        .sum::<usize>();
+                table.entity_capacity() * row_size
+            })
+            .sum();
+
+        Self {
+            archetype_count: world.archetypes().len(),
+            table_memory,
+            archetypes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct A;
+    #[derive(Component)]
+    struct B;
+    #[derive(Component)]
+    struct C;
+
+    #[test]
+    fn archetypes_sorted_by_entity_count() {
+        let mut world = World::new();
+        world.spawn_batch((0..3).map(|_| (C, A)));
+        world.spawn_batch((0..5).map(|_| B));
+        world.spawn((A, B, C));
+
+        let stats = ArchetypeStats::collect(&world);
+        let archetypes: Vec<_> = stats
+            .archetypes
+            .iter()
+            .map(|archetype| (archetype.entity_count, archetype.component_list(2)))
+            .collect();
+        assert_eq!(
+            archetypes,
+            [
+                (5, "B".to_string()),
+                (3, "A, C".to_string()),
+                (1, "A, B, +1 more".to_string()),
+            ]
+        );
+        assert!(stats.table_memory > 0);
+    }
+}
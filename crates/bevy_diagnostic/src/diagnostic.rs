@@ -286,6 +286,14 @@ impl Diagnostic {
         self.history.iter()
     }
 
+    /// Returns the history of measurements of this diagnostic, from the oldest to the latest.
+    ///
+    /// The history keeps up to [`get_max_history_length`](Self::get_max_history_length)
+    /// measurements, so a range of the latest ones can be read, such as to draw them in a graph.
+    pub fn history(&self) -> &VecDeque<DiagnosticMeasurement> {
+        &self.history
+    }
+
     /// Clear the history of this diagnostic.
     pub fn clear_history(&mut self) {
         self.history.clear();
@@ -0,0 +1,354 @@
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::{Duration, HashMap, Instant};
+
+use crate::{DiagnosticPath, DiagnosticsStore};
+
+/// Adds a [`DiagnosticsRecorder`] to an App, and records diagnostics into it while it's started.
+///
+/// Recording happens in [`Last`], after all diagnostics of the frame have been measured.
+#[derive(Default)]
+pub struct DiagnosticsRecorderPlugin;
+
+impl Plugin for DiagnosticsRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsRecorder>()
+            .add_systems(Last, DiagnosticsRecorder::record_system);
+    }
+}
+
+/// A single raw measurement recorded by a [`DiagnosticsRecorder`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedMeasurement {
+    /// The index of the diagnostic path in [`DiagnosticsRecorder::paths`].
+    pub path_index: usize,
+    /// The time of the measurement, relative to the start of the recording.
+    pub time: Duration,
+    /// The raw (not smoothed) value of the measurement.
+    pub value: f64,
+}
+
+/// Records the raw measurements of every diagnostic of the [`DiagnosticsStore`] for some time,
+/// to export them to CSV or JSON, for instance to track performance regressions.
+///
+/// Recording doesn't change the history length of the diagnostics. The buffer of measurements
+/// is allocated when the recording starts, from the expected number of frames: once it's full,
+/// further measurements are dropped and counted in [`dropped`](Self::dropped).
+///
+/// ```no_run
+/// # use bevy_diagnostic::DiagnosticsRecorder;
+/// # use bevy_ecs::system::{Res, ResMut};
+/// # use bevy_utils::Duration;
+/// fn start_recording(mut recorder: ResMut<DiagnosticsRecorder>) {
+///     recorder.start(Duration::from_secs(10));
+/// }
+///
+/// fn write_recording(recorder: Res<DiagnosticsRecorder>) {
+///     if !recorder.is_recording() {
+///         recorder.write_csv("diagnostics.csv").unwrap();
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug)]
+pub struct DiagnosticsRecorder {
+    /// The frame rate used to compute the capacity of the buffer when starting a recording.
+    pub expected_fps: f64,
+    metadata: Vec<(Cow<'static, str>, String)>,
+    recording: Option<Recording>,
+    paths: Vec<DiagnosticPath>,
+    path_indices: HashMap<DiagnosticPath, usize>,
+    last_recorded: Vec<Instant>,
+    measurements: Vec<RecordedMeasurement>,
+    dropped: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Recording {
+    start: Instant,
+    duration: Duration,
+}
+
+impl Default for DiagnosticsRecorder {
+    fn default() -> Self {
+        Self {
+            expected_fps: 60.0,
+            metadata: vec![(
+                Cow::Borrowed("bevy_version"),
+                env!("CARGO_PKG_VERSION").to_string(),
+            )],
+            recording: None,
+            paths: Vec::new(),
+            path_indices: HashMap::default(),
+            last_recorded: Vec::new(),
+            measurements: Vec::new(),
+            dropped: 0,
+        }
+    }
+}
+
+impl DiagnosticsRecorder {
+    /// Starts a new recording, lasting for `duration`, and clears the previous one.
+    ///
+    /// The diagnostics are the ones of the [`DiagnosticsStore`] when the first frame is recorded.
+    pub fn start(&mut self, duration: Duration) {
+        self.recording = Some(Recording {
+            start: Instant::now(),
+            duration,
+        });
+        self.paths.clear();
+        self.path_indices.clear();
+        self.last_recorded.clear();
+        self.measurements.clear();
+        self.dropped = 0;
+    }
+
+    /// Stops the recording, keeping the recorded measurements.
+    pub fn stop(&mut self) {
+        self.recording = None;
+    }
+
+    /// Returns `true` while recording.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Sets a metadata entry, written in the header of the exported files.
+    ///
+    /// The bevy version is set by default, and the name of the graphics adapter is set by the renderer.
+    pub fn set_metadata(&mut self, key: impl Into<Cow<'static, str>>, value: impl Into<String>) {
+        let key = key.into();
+        let value = value.into();
+        match self.metadata.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.metadata.push((key, value)),
+        }
+    }
+
+    /// Returns the metadata entries, in insertion order.
+    pub fn metadata(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.metadata.iter().map(|(k, v)| (k.as_ref(), v.as_str()))
+    }
+
+    /// Returns the paths of the recorded diagnostics.
+    pub fn paths(&self) -> &[DiagnosticPath] {
+        &self.paths
+    }
+
+    /// Returns the recorded measurements, in recording order.
+    pub fn measurements(&self) -> &[RecordedMeasurement] {
+        &self.measurements
+    }
+
+    /// Returns the number of measurements that were dropped because the buffer was full.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Records the measurements of `diagnostics` that are new since the last recorded frame.
+    pub fn record(&mut self, diagnostics: &DiagnosticsStore) {
+        let Some(recording) = self.recording else {
+            return;
+        };
+        if recording.start.elapsed() > recording.duration {
+            self.recording = None;
+            return;
+        }
+
+        if self.paths.is_empty() {
+            self.paths.extend(
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.path().clone()),
+            );
+            self.path_indices.extend(
+                self.paths
+                    .iter()
+                    .enumerate()
+                    .map(|(index, path)| (path.clone(), index)),
+            );
+            self.last_recorded = vec![recording.start; self.paths.len()];
+
+            let expected_frames = (recording.duration.as_secs_f64() * self.expected_fps).ceil();
+            self.measurements
+                .reserve_exact(expected_frames as usize * self.paths.len());
+        }
+
+        for diagnostic in diagnostics.iter() {
+            let Some(&path_index) = self.path_indices.get(diagnostic.path()) else {
+                continue;
+            };
+            let Some(measurement) = diagnostic.measurement() else {
+                continue;
+            };
+            // Only record each measurement once, even if the diagnostic isn't updated every frame
+            if measurement.time <= self.last_recorded[path_index] {
+                continue;
+            }
+            self.last_recorded[path_index] = measurement.time;
+
+            if self.measurements.len() == self.measurements.capacity() {
+                self.dropped += 1;
+                continue;
+            }
+            self.measurements.push(RecordedMeasurement {
+                path_index,
+                time: measurement.time - recording.start,
+                value: measurement.value,
+            });
+        }
+    }
+
+    /// Records the measurements of the [`DiagnosticsStore`] while recording.
+    pub fn record_system(mut recorder: ResMut<Self>, diagnostics: Res<DiagnosticsStore>) {
+        if recorder.is_recording() {
+            recorder.record(&diagnostics);
+        }
+    }
+
+    /// Writes the recorded measurements to the file at `path`, as CSV.
+    pub fn write_csv(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Writes the recorded measurements as CSV, with one `path,time,value` row per measurement.
+    ///
+    /// The metadata is written first, as `# key: value` comment lines. Times are in seconds.
+    pub fn write_csv_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        for (key, value) in self.metadata() {
+            writeln!(writer, "# {key}: {value}")?;
+        }
+        writeln!(writer, "path,time,value")?;
+        for measurement in &self.measurements {
+            writeln!(
+                writer,
+                "{},{},{}",
+                self.paths[measurement.path_index],
+                measurement.time.as_secs_f64(),
+                measurement.value
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the recorded measurements to the file at `path`, as JSON.
+    pub fn write_json(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_json_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Writes the recorded measurements as a JSON object, with a `metadata` object
+    /// and a `measurements` array of `{ "path", "time", "value" }` objects.
+    ///
+    /// Times are in seconds, and values that aren't finite are written as `null`.
+    pub fn write_json_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        write!(writer, "{{\"metadata\":{{")?;
+        for (index, (key, value)) in self.metadata().enumerate() {
+            if index > 0 {
+                write!(writer, ",")?;
+            }
+            write_json_string(writer, key)?;
+            write!(writer, ":")?;
+            write_json_string(writer, value)?;
+        }
+        write!(writer, "}},\"measurements\":[")?;
+        for (index, measurement) in self.measurements.iter().enumerate() {
+            if index > 0 {
+                write!(writer, ",")?;
+            }
+            write!(writer, "{{\"path\":")?;
+            write_json_string(writer, self.paths[measurement.path_index].as_str())?;
+            write!(
+                writer,
+                ",\"time\":{},\"value\":",
+                measurement.time.as_secs_f64()
+            )?;
+            if measurement.value.is_finite() {
+                write!(writer, "{}", measurement.value)?;
+            } else {
+                write!(writer, "null")?;
+            }
+            write!(writer, "}}")?;
+        }
+        write!(writer, "]}}")
+    }
+}
+
+fn write_json_string(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    write!(writer, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => write!(writer, "\\\"")?,
+            '\\' => write!(writer, "\\\\")?,
+            '\n' => write!(writer, "\\n")?,
+            '\r' => write!(writer, "\\r")?,
+            '\t' => write!(writer, "\\t")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", c as u32)?,
+            c => write!(writer, "{c}")?,
+        }
+    }
+    write!(writer, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Diagnostic, DiagnosticMeasurement};
+
+    #[test]
+    fn records_each_measurement_once() {
+        const PATH: DiagnosticPath = DiagnosticPath::const_new("test");
+
+        let mut store = DiagnosticsStore::default();
+        store.add(Diagnostic::new(PATH).with_max_history_length(1));
+
+        let mut recorder = DiagnosticsRecorder {
+            expected_fps: 2.0,
+            ..Default::default()
+        };
+        recorder.set_metadata("adapter", "test \"adapter\"");
+        recorder.start(Duration::from_secs(1));
+
+        let start = Instant::now();
+        for (frame, value) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            store
+                .get_mut(&PATH)
+                .unwrap()
+                .add_measurement(DiagnosticMeasurement {
+                    time: start + Duration::from_millis(frame as u64 + 1),
+                    value,
+                });
+            recorder.record(&store);
+            // The diagnostic isn't updated on this frame
+            recorder.record(&store);
+        }
+
+        // The buffer was allocated for two frames, and isn't grown
+        assert_eq!(
+            recorder
+                .measurements()
+                .iter()
+                .map(|measurement| measurement.value)
+                .collect::<Vec<_>>(),
+            vec![1.0, 2.0]
+        );
+        assert_eq!(recorder.dropped(), 1);
+        assert_eq!(store.get(&PATH).unwrap().history_len(), 1);
+
+        let mut json = Vec::new();
+        recorder.write_json_to(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.starts_with(&format!(
+            "{{\"metadata\":{{\"bevy_version\":\"{}\",\"adapter\":\"test \\\"adapter\\\"\"}}",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(json.contains("{\"path\":\"test\",\"time\":"));
+    }
+}
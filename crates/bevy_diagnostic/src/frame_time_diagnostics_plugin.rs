@@ -1,11 +1,33 @@
+use std::collections::VecDeque;
+
 use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
 use bevy_app::prelude::*;
 use bevy_core::FrameCount;
 use bevy_ecs::prelude::*;
 use bevy_time::{Real, Time};
+use bevy_utils::Duration;
 
 /// Adds "frame time" diagnostic to an App, specifically "frame time", "fps" and "frame count"
 ///
+/// Frame time percentiles ("p95", "p99") and the "1% low" fps are also computed over a rolling
+/// [`FrameTimeWindow`], to expose stutter that the smoothed values hide. The window defaults to
+/// the last 300 frames: insert a [`FrameTimeHistory`] to choose another one.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_diagnostic::{FrameTimeDiagnosticsPlugin, FrameTimeHistory, FrameTimeWindow};
+/// # use bevy_utils::Duration;
+/// App::new()
+///     .insert_resource(FrameTimeHistory::new(FrameTimeWindow::Duration(
+///         Duration::from_secs(10),
+///     )))
+///     .add_plugins(FrameTimeDiagnosticsPlugin);
+/// ```
+///
+/// [Disabling](App::disable_plugin) this plugin stops measuring the frame times and empties the
+/// [`FrameTimeHistory`], so that the percentiles don't span the time it was disabled once it's
+/// enabled again. The diagnostics keep their last measurements.
+///
 /// # See also
 ///
 /// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
@@ -17,7 +39,27 @@ impl Plugin for FrameTimeDiagnosticsPlugin {
         app.register_diagnostic(Diagnostic::new(Self::FRAME_TIME).with_suffix("ms"))
             .register_diagnostic(Diagnostic::new(Self::FPS))
             .register_diagnostic(Diagnostic::new(Self::FRAME_COUNT).with_smoothing_factor(0.0))
-            .add_systems(Update, Self::diagnostic_system);
+            .register_diagnostic(
+                Diagnostic::new(Self::FRAME_TIME_P95)
+                    .with_suffix("ms")
+                    .with_smoothing_factor(0.0),
+            )
+            .register_diagnostic(
+                Diagnostic::new(Self::FRAME_TIME_P99)
+                    .with_suffix("ms")
+                    .with_smoothing_factor(0.0),
+            )
+            .register_diagnostic(Diagnostic::new(Self::FPS_1PERCENT_LOW).with_smoothing_factor(0.0))
+            .init_resource::<FrameTimeHistory>()
+            .add_systems(
+                Update,
+                (Self::diagnostic_system, Self::percentile_diagnostic_system),
+            );
+    }
+
+    fn cleanup_on_disable(&self, app: &mut App) {
+        let mut history = app.world_mut().resource_mut::<FrameTimeHistory>();
+        *history = FrameTimeHistory::new(history.window());
     }
 }
 
@@ -25,6 +67,12 @@ impl FrameTimeDiagnosticsPlugin {
     pub const FPS: DiagnosticPath = DiagnosticPath::const_new("fps");
     pub const FRAME_COUNT: DiagnosticPath = DiagnosticPath::const_new("frame_count");
     pub const FRAME_TIME: DiagnosticPath = DiagnosticPath::const_new("frame_time");
+    /// The 95th percentile of the frame times in the [`FrameTimeWindow`], in milliseconds.
+    pub const FRAME_TIME_P95: DiagnosticPath = DiagnosticPath::const_new("frame_time/p95");
+    /// The 99th percentile of the frame times in the [`FrameTimeWindow`], in milliseconds.
+    pub const FRAME_TIME_P99: DiagnosticPath = DiagnosticPath::const_new("frame_time/p99");
+    /// The average fps of the slowest 1% of the frames in the [`FrameTimeWindow`].
+    pub const FPS_1PERCENT_LOW: DiagnosticPath = DiagnosticPath::const_new("fps/1_percent_low");
 
     pub fn diagnostic_system(
         mut diagnostics: Diagnostics,
@@ -42,4 +90,221 @@ impl FrameTimeDiagnosticsPlugin {
 
         diagnostics.add_measurement(&Self::FPS, || 1.0 / delta_seconds);
     }
+
+    /// Records the frame time into the [`FrameTimeHistory`] and publishes its percentiles.
+    pub fn percentile_diagnostic_system(
+        mut diagnostics: Diagnostics,
+        time: Res<Time<Real>>,
+        mut history: ResMut<FrameTimeHistory>,
+    ) {
+        let delta = time.delta();
+        if delta.is_zero() {
+            return;
+        }
+
+        history.push(delta);
+        let Some(percentiles) = history.percentiles() else {
+            return;
+        };
+
+        diagnostics.add_measurement(&Self::FRAME_TIME_P95, || percentiles.p95 * 1000.0);
+        diagnostics.add_measurement(&Self::FRAME_TIME_P99, || percentiles.p99 * 1000.0);
+        diagnostics.add_measurement(&Self::FPS_1PERCENT_LOW, || {
+            1.0 / percentiles.one_percent_low
+        });
+    }
+}
+
+/// The rolling window over which [`FrameTimeDiagnosticsPlugin`] computes frame time percentiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameTimeWindow {
+    /// The last `n` frames.
+    Frames(usize),
+    /// The frames of the last given duration, up to [`FrameTimeWindow::MAX_FRAMES`].
+    Duration(Duration),
+}
+
+impl FrameTimeWindow {
+    /// The maximum number of frames kept by a [`FrameTimeWindow::Duration`] window,
+    /// so that its memory stays bounded at high frame rates.
+    pub const MAX_FRAMES: usize = 4096;
+
+    fn capacity(&self) -> usize {
+        match *self {
+            FrameTimeWindow::Frames(frames) => frames.max(1),
+            FrameTimeWindow::Duration(_) => Self::MAX_FRAMES,
+        }
+    }
+}
+
+impl Default for FrameTimeWindow {
+    fn default() -> Self {
+        FrameTimeWindow::Frames(300)
+    }
+}
+
+/// Frame time percentiles over a [`FrameTimeWindow`], in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameTimePercentiles {
+    /// The 95th percentile frame time.
+    pub p95: f64,
+    /// The 99th percentile frame time.
+    pub p99: f64,
+    /// The average frame time of the slowest 1% of the frames.
+    pub one_percent_low: f64,
+}
+
+/// The recent frame times used by [`FrameTimeDiagnosticsPlugin`] to compute percentiles.
+///
+/// Both buffers are allocated once, with the capacity of the [`FrameTimeWindow`].
+///
+/// Defaults to an empty history over the default [`FrameTimeWindow`].
+#[derive(Resource, Debug)]
+pub struct FrameTimeHistory {
+    window: FrameTimeWindow,
+    frame_times: VecDeque<f64>,
+    total: f64,
+    scratch: Vec<f64>,
+}
+
+impl Default for FrameTimeHistory {
+    fn default() -> Self {
+        Self::new(FrameTimeWindow::default())
+    }
+}
+
+impl FrameTimeHistory {
+    /// Creates an empty history for `window`.
+    pub fn new(window: FrameTimeWindow) -> Self {
+        let capacity = window.capacity();
+        Self {
+            window,
+            frame_times: VecDeque::with_capacity(capacity),
+            total: 0.0,
+            scratch: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// The window of this history.
+    pub fn window(&self) -> FrameTimeWindow {
+        self.window
+    }
+
+    /// The number of frames currently in the window.
+    pub fn len(&self) -> usize {
+        self.frame_times.len()
+    }
+
+    /// Returns `true` if no frame has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.frame_times.is_empty()
+    }
+
+    /// Records a frame time, evicting the frames that fell out of the window.
+    pub fn push(&mut self, frame_time: Duration) {
+        let capacity = self.window.capacity();
+        while self.frame_times.len() >= capacity {
+            self.pop_front();
+        }
+
+        let frame_time = frame_time.as_secs_f64();
+        self.frame_times.push_back(frame_time);
+        self.total += frame_time;
+
+        if let FrameTimeWindow::Duration(duration) = self.window {
+            // Always keep the latest frame, even if it's longer than the window.
+            while self.frame_times.len() > 1 && self.total > duration.as_secs_f64() {
+                self.pop_front();
+            }
+        }
+    }
+
+    fn pop_front(&mut self) {
+        if let Some(removed) = self.frame_times.pop_front() {
+            self.total -= removed;
+        }
+    }
+
+    /// Computes the percentiles of the frame times in the window,
+    /// or `None` if no frame has been recorded.
+    pub fn percentiles(&mut self) -> Option<FrameTimePercentiles> {
+        let len = self.frame_times.len();
+        if len == 0 {
+            return None;
+        }
+
+        self.scratch.clear();
+        self.scratch.extend(self.frame_times.iter().copied());
+
+        let mut nth = |index: usize| *self.scratch.select_nth_unstable_by(index, f64::total_cmp).1;
+        // Nearest-rank percentiles.
+        let p95 = nth(percentile_index(len, 0.95));
+        let p99 = nth(percentile_index(len, 0.99));
+
+        let slowest = (len / 100).max(1);
+        let start = len - slowest;
+        self.scratch.select_nth_unstable_by(start, f64::total_cmp);
+        let one_percent_low = self.scratch[start..].iter().sum::<f64>() / slowest as f64;
+
+        Some(FrameTimePercentiles {
+            p95,
+            p99,
+            one_percent_low,
+        })
+    }
+}
+
+fn percentile_index(len: usize, percentile: f64) -> usize {
+    ((len as f64 * percentile).ceil() as usize)
+        .saturating_sub(1)
+        .min(len - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(history: &mut FrameTimeHistory, frame_times: impl IntoIterator<Item = u64>) {
+        for frame_time in frame_times {
+            history.push(Duration::from_millis(frame_time));
+        }
+    }
+
+    #[test]
+    fn percentiles_over_frame_window() {
+        let mut history = FrameTimeHistory::new(FrameTimeWindow::Frames(100));
+        assert_eq!(history.percentiles(), None);
+
+        // 1..=100 ms, shuffled.
+        millis(&mut history, (0..100).map(|i| (i * 37) % 100 + 1));
+        let percentiles = history.percentiles().unwrap();
+        assert!((percentiles.p95 - 0.095).abs() < 1e-9);
+        assert!((percentiles.p99 - 0.099).abs() < 1e-9);
+        assert!((percentiles.one_percent_low - 0.100).abs() < 1e-9);
+
+        // Older frames are evicted, without growing the buffer.
+        let capacity = history.frame_times.capacity();
+        millis(&mut history, [1; 100]);
+        assert_eq!(history.len(), 100);
+        assert_eq!(history.frame_times.capacity(), capacity);
+        assert!((history.percentiles().unwrap().p99 - 0.001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn duration_window_evicts_old_frames() {
+        let mut history =
+            FrameTimeHistory::new(FrameTimeWindow::Duration(Duration::from_millis(100)));
+        millis(&mut history, [40; 10]);
+        assert_eq!(history.len(), 2);
+
+        millis(&mut history, [500]);
+        assert_eq!(history.len(), 1);
+        assert!((history.percentiles().unwrap().one_percent_low - 0.5).abs() < 1e-9);
+
+        millis(
+            &mut history,
+            std::iter::repeat(0).take(FrameTimeWindow::MAX_FRAMES * 2),
+        );
+        assert_eq!(history.len(), FrameTimeWindow::MAX_FRAMES);
+    }
 }
@@ -11,20 +11,31 @@
 //! It allows users to easily add diagnostic functionality to their Bevy applications, enhancing
 //! their ability to monitor and optimize their game's.
 
+mod archetype_diagnostics_plugin;
 mod diagnostic;
+mod diagnostics_recorder;
 mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod task_pool_diagnostics_plugin;
 
+pub use archetype_diagnostics_plugin::{ArchetypeDiagnosticsPlugin, ArchetypeStat, ArchetypeStats};
 pub use diagnostic::*;
 
+pub use diagnostics_recorder::{
+    DiagnosticsRecorder, DiagnosticsRecorderPlugin, RecordedMeasurement,
+};
+
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
-pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
+pub use frame_time_diagnostics_plugin::{
+    FrameTimeDiagnosticsPlugin, FrameTimeHistory, FrameTimePercentiles, FrameTimeWindow,
+};
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+pub use task_pool_diagnostics_plugin::TaskPoolDiagnosticsPlugin;
 
 use bevy_app::prelude::*;
 
@@ -12,6 +12,8 @@ use bevy_utils::Duration;
 /// or can be provided by the user.
 ///
 /// When no diagnostics are provided, this plugin does nothing.
+///
+/// [Disabling](App::disable_plugin) this plugin stops logging, and nothing needs to be removed.
 pub struct LogDiagnosticsPlugin {
     pub debug: bool,
     pub wait_duration: Duration,
@@ -0,0 +1,91 @@
+use bevy_app::prelude::*;
+use bevy_tasks::{AsyncComputeTaskPool, IoTaskPool, TaskPool, TaskPriority};
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds "task pool" diagnostics to an App, reporting the number of tasks waiting to be started,
+/// and the mean time they waited, for the [`AsyncComputeTaskPool`] and the [`IoTaskPool`].
+///
+/// The tasks spawned with [`TaskPriority::Background`] on the [`AsyncComputeTaskPool`] are
+/// reported separately. Only the tasks spawned with [`TaskPool::spawn`] and
+/// [`TaskPool::spawn_with_priority`] are counted, not the ones spawned on a scope, and nothing is
+/// reported on the single threaded task pool used on wasm.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct TaskPoolDiagnosticsPlugin;
+
+impl Plugin for TaskPoolDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for (queued, latency) in Self::LANES {
+            app.register_diagnostic(Diagnostic::new(queued).with_smoothing_factor(0.0))
+                .register_diagnostic(Diagnostic::new(latency).with_suffix("ms"));
+        }
+        app.add_systems(Update, Self::diagnostic_system);
+    }
+
+    fn finish(&self, _app: &mut App) {
+        // The task pools are created by the `TaskPoolPlugin`, which has been built by now
+        for (pool, priority) in Self::lanes() {
+            if let Some(pool) = pool {
+                pool.stats(priority).enable();
+            }
+        }
+    }
+}
+
+impl TaskPoolDiagnosticsPlugin {
+    /// The number of tasks waiting to be started on the [`AsyncComputeTaskPool`].
+    pub const ASYNC_COMPUTE_QUEUED: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/async_compute/queued");
+    /// The mean time the tasks started on the [`AsyncComputeTaskPool`] waited, in milliseconds.
+    pub const ASYNC_COMPUTE_LATENCY: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/async_compute/latency");
+    /// The number of [`TaskPriority::Background`] tasks waiting to be started on the
+    /// [`AsyncComputeTaskPool`].
+    pub const ASYNC_COMPUTE_BACKGROUND_QUEUED: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/async_compute/background/queued");
+    /// The mean time the [`TaskPriority::Background`] tasks started on the
+    /// [`AsyncComputeTaskPool`] waited, in milliseconds.
+    pub const ASYNC_COMPUTE_BACKGROUND_LATENCY: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/async_compute/background/latency");
+    /// The number of tasks waiting to be started on the [`IoTaskPool`].
+    pub const IO_QUEUED: DiagnosticPath = DiagnosticPath::const_new("task_pool/io/queued");
+    /// The mean time the tasks started on the [`IoTaskPool`] waited, in milliseconds.
+    pub const IO_LATENCY: DiagnosticPath = DiagnosticPath::const_new("task_pool/io/latency");
+
+    const LANES: [(DiagnosticPath, DiagnosticPath); 3] = [
+        (Self::ASYNC_COMPUTE_QUEUED, Self::ASYNC_COMPUTE_LATENCY),
+        (
+            Self::ASYNC_COMPUTE_BACKGROUND_QUEUED,
+            Self::ASYNC_COMPUTE_BACKGROUND_LATENCY,
+        ),
+        (Self::IO_QUEUED, Self::IO_LATENCY),
+    ];
+
+    /// The task pool and priority of each of the [`Self::LANES`], if the pool is initialized.
+    fn lanes() -> [(Option<&'static TaskPool>, TaskPriority); 3] {
+        let async_compute = AsyncComputeTaskPool::try_get().map(|pool| &**pool);
+        let io = IoTaskPool::try_get().map(|pool| &**pool);
+        [
+            (async_compute, TaskPriority::Normal),
+            (async_compute, TaskPriority::Background),
+            (io, TaskPriority::Normal),
+        ]
+    }
+
+    pub fn diagnostic_system(mut diagnostics: Diagnostics) {
+        for ((queued, latency), (pool, priority)) in Self::LANES.iter().zip(Self::lanes()) {
+            let Some(pool) = pool else {
+                continue;
+            };
+            let stats = pool.stats(priority);
+            diagnostics.add_measurement(queued, || stats.queued() as f64);
+            if let Some(mean_latency) = stats.take_mean_latency() {
+                diagnostics.add_measurement(latency, || mean_latency.as_secs_f64() * 1000.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,574 @@
+//! A reflection-based data model for building runtime world inspectors.
+//!
+//! [`inspect_entity`] walks every component of an entity and produces an [`InspectedEntity`]:
+//! a tree of reflected values annotated with the metadata an editor UI needs
+//! (type paths, field names and numeric ranges declared with custom attributes).
+//! Components that aren't registered with [`ReflectComponent`] are still listed, by name only.
+//!
+//! Edits made in such a UI are written back with [`apply_inspector_edit`], which goes through
+//! [`ReflectComponent`] so that change detection is triggered as usual.
+
+use std::any::TypeId;
+use std::ops::{Range, RangeInclusive};
+
+use bevy_reflect::attributes::CustomAttributes;
+use bevy_reflect::{
+    ApplyError, GetPath, Reflect, ReflectRef, TypeInfo, TypeRegistry, VariantInfo, VariantType,
+};
+use thiserror::Error;
+
+use crate::{
+    change_detection::DetectChangesMut,
+    component::ComponentId,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::World,
+};
+
+/// A snapshot of all components of a single entity, produced by [`inspect_entity`].
+#[derive(Debug)]
+pub struct InspectedEntity {
+    /// The inspected entity.
+    ///
+    /// This includes the entity's generation, so edits built from this snapshot
+    /// will be rejected if the entity is despawned in the meantime.
+    pub entity: Entity,
+    /// The components of the entity, in archetype order.
+    pub components: Vec<InspectedComponent>,
+}
+
+impl InspectedEntity {
+    /// Returns the inspected component with the given [`TypeId`], if present.
+    pub fn component(&self, type_id: TypeId) -> Option<&InspectedComponent> {
+        self.components
+            .iter()
+            .find(|component| component.type_id == Some(type_id))
+    }
+}
+
+/// A single component of an [`InspectedEntity`].
+#[derive(Debug)]
+pub struct InspectedComponent {
+    /// The id of the component in the inspected [`World`].
+    pub id: ComponentId,
+    /// The name of the component, as reported by its [`ComponentInfo`](crate::component::ComponentInfo).
+    pub name: String,
+    /// The [`TypeId`] of the component, if it is backed by a Rust type.
+    pub type_id: Option<TypeId>,
+    /// The reflected value of the component.
+    ///
+    /// This is `None` for components that aren't registered with [`ReflectComponent`].
+    pub value: Option<InspectedValue>,
+}
+
+/// A node in the tree of reflected values of an [`InspectedComponent`].
+#[derive(Debug)]
+pub struct InspectedValue {
+    /// The [reflection path](bevy_reflect::GetPath) of this value, relative to the component.
+    ///
+    /// The component itself has an empty path.
+    /// Pass this to [`InspectorEdit::new`] to write back a modified value.
+    pub path: String,
+    /// The field name or index of this value in its parent, if any.
+    pub label: Option<String>,
+    /// The [type path](bevy_reflect::TypePath) of this value.
+    pub type_path: String,
+    /// The numeric range this value is constrained to, if the field declares one.
+    ///
+    /// This is read from a `Range` or `RangeInclusive` custom attribute on the field,
+    /// e.g. `#[reflect(@RangeInclusive::<f32>::new(0.0, 1.0))]`.
+    pub range: Option<RangeInclusive<f64>>,
+    /// The kind of this value, along with its children.
+    pub kind: InspectedValueKind,
+}
+
+impl InspectedValue {
+    /// Returns the children of this value, if it is a container.
+    pub fn children(&self) -> &[InspectedValue] {
+        match &self.kind {
+            InspectedValueKind::Struct(fields)
+            | InspectedValueKind::TupleStruct(fields)
+            | InspectedValueKind::Tuple(fields)
+            | InspectedValueKind::List(fields)
+            | InspectedValueKind::Array(fields)
+            | InspectedValueKind::Enum { fields, .. } => fields,
+            InspectedValueKind::Map { .. } | InspectedValueKind::Value(_) => &[],
+        }
+    }
+
+    /// Finds the descendant of this value (or this value itself) with the given path.
+    pub fn find(&self, path: &str) -> Option<&InspectedValue> {
+        if self.path == path {
+            return Some(self);
+        }
+        self.children()
+            .iter()
+            .filter(|child| path.starts_with(child.path.as_str()))
+            .find_map(|child| child.find(path))
+    }
+}
+
+/// The kind of an [`InspectedValue`], mirroring [`ReflectRef`].
+#[derive(Debug)]
+pub enum InspectedValueKind {
+    /// A struct, with one child per named field.
+    Struct(Vec<InspectedValue>),
+    /// A tuple struct, with one child per field.
+    TupleStruct(Vec<InspectedValue>),
+    /// A tuple, with one child per field.
+    Tuple(Vec<InspectedValue>),
+    /// A list, with one child per element.
+    List(Vec<InspectedValue>),
+    /// An array, with one child per element.
+    Array(Vec<InspectedValue>),
+    /// A map.
+    ///
+    /// Map entries can't be addressed by a reflection path, so they are not inspected.
+    Map {
+        /// The number of entries in the map.
+        len: usize,
+    },
+    /// An enum, with one child per field of the active variant.
+    Enum {
+        /// The name of the active variant.
+        variant: String,
+        /// The fields of the active variant.
+        fields: Vec<InspectedValue>,
+    },
+    /// A leaf value, such as a number or a string.
+    Value(Box<dyn Reflect>),
+}
+
+/// A change to a single value of a component, to be applied with [`apply_inspector_edit`].
+#[derive(Debug)]
+pub struct InspectorEdit {
+    /// The entity to modify.
+    pub entity: Entity,
+    /// The [`TypeId`] of the component to modify.
+    pub component: TypeId,
+    /// The [reflection path](bevy_reflect::GetPath) of the value to modify,
+    /// relative to the component. An empty path replaces the whole component.
+    pub path: String,
+    /// The new value.
+    pub value: Box<dyn Reflect>,
+}
+
+impl InspectorEdit {
+    /// Creates an edit setting the value at `path` of the `component` of `entity` to `value`.
+    pub fn new(
+        entity: Entity,
+        component: TypeId,
+        path: impl Into<String>,
+        value: impl Reflect,
+    ) -> Self {
+        Self {
+            entity,
+            component,
+            path: path.into(),
+            value: Box::new(value),
+        }
+    }
+}
+
+/// An error returned by [`apply_inspector_edit`].
+#[derive(Error, Debug)]
+pub enum InspectorEditError {
+    /// The entity was despawned since the snapshot the edit was made from.
+    #[error("The entity {0:?} no longer exists")]
+    EntityDespawned(Entity),
+    /// The component type isn't registered with [`ReflectComponent`] in the [`AppTypeRegistry`].
+    #[error("The component type {0:?} is not registered with `ReflectComponent`")]
+    UnregisteredComponent(TypeId),
+    /// The entity doesn't have the component.
+    #[error("The entity {entity:?} does not have the component {type_path}")]
+    MissingComponent {
+        /// The edited entity.
+        entity: Entity,
+        /// The type path of the missing component.
+        type_path: &'static str,
+    },
+    /// The path doesn't point to a value inside the component.
+    #[error("Invalid path `{path}`: {message}")]
+    InvalidPath {
+        /// The path of the edit.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The new value couldn't be applied to the existing one.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+}
+
+/// Walks all components of `entity` and produces a tree of their reflected values.
+///
+/// Reflection data is read from the [`AppTypeRegistry`] resource, if present.
+/// Components whose type isn't registered with [`ReflectComponent`] are listed by name only.
+///
+/// # Panics
+///
+/// Panics if `entity` doesn't exist.
+pub fn inspect_entity(world: &World, entity: Entity) -> InspectedEntity {
+    let registry = world.get_resource::<AppTypeRegistry>().map(|r| r.read());
+    let entity_ref = world
+        .get_entity(entity)
+        .unwrap_or_else(|| panic!("Entity {entity:?} does not exist"));
+
+    let components = world
+        .inspect_entity(entity)
+        .map(|info| {
+            let value = info
+                .type_id()
+                .zip(registry.as_deref())
+                .and_then(|(type_id, registry)| reflect_component(registry, type_id))
+                .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+                .map(|value| inspect_value(value, String::new(), None, None));
+
+            InspectedComponent {
+                id: info.id(),
+                name: info.name().to_string(),
+                type_id: info.type_id(),
+                value,
+            }
+        })
+        .collect();
+
+    InspectedEntity { entity, components }
+}
+
+/// Writes the value of an [`InspectorEdit`] back into the world.
+///
+/// The component is only marked as changed if the edit succeeds.
+///
+/// # Errors
+///
+/// Returns an error if the entity was despawned since it was inspected, if the component
+/// is missing or not reflectable, if the path is invalid, or if the value has the wrong type.
+pub fn apply_inspector_edit(
+    world: &mut World,
+    edit: InspectorEdit,
+) -> Result<(), InspectorEditError> {
+    let registry = world
+        .get_resource::<AppTypeRegistry>()
+        .ok_or(InspectorEditError::UnregisteredComponent(edit.component))?
+        .clone();
+    let registry = registry.read();
+    let reflect_component = reflect_component(&registry, edit.component)
+        .ok_or(InspectorEditError::UnregisteredComponent(edit.component))?;
+
+    let mut entity_mut = world
+        .get_entity_mut(edit.entity)
+        .ok_or(InspectorEditError::EntityDespawned(edit.entity))?;
+    let mut component = reflect_component
+        .reflect_mut(&mut entity_mut)
+        .ok_or_else(|| InspectorEditError::MissingComponent {
+            entity: edit.entity,
+            type_path: registry
+                .get(edit.component)
+                .map(|registration| registration.type_info().type_path())
+                .unwrap_or_default(),
+        })?;
+
+    let root = component.bypass_change_detection();
+    let target = if edit.path.is_empty() {
+        root
+    } else {
+        root.reflect_path_mut(edit.path.as_str()).map_err(|error| {
+            InspectorEditError::InvalidPath {
+                path: edit.path.clone(),
+                message: error.to_string(),
+            }
+        })?
+    };
+    target.try_apply(edit.value.as_ref())?;
+
+    component.set_changed();
+    Ok(())
+}
+
+fn reflect_component(registry: &TypeRegistry, type_id: TypeId) -> Option<&ReflectComponent> {
+    registry.get_type_data::<ReflectComponent>(type_id)
+}
+
+fn inspect_value(
+    value: &dyn Reflect,
+    path: String,
+    label: Option<String>,
+    attributes: Option<&CustomAttributes>,
+) -> InspectedValue {
+    let type_info = value.get_represented_type_info();
+
+    let kind = match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let info = match type_info {
+                Some(TypeInfo::Struct(info)) => Some(info),
+                _ => None,
+            };
+            InspectedValueKind::Struct(
+                (0..value.field_len())
+                    .map(|i| {
+                        let name = value.name_at(i).unwrap_or_default();
+                        inspect_value(
+                            value.field_at(i).unwrap(),
+                            format!("{path}.{name}"),
+                            Some(name.to_string()),
+                            info.and_then(|info| info.field_at(i))
+                                .map(|field| field.custom_attributes()),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+        ReflectRef::TupleStruct(value) => {
+            let info = match type_info {
+                Some(TypeInfo::TupleStruct(info)) => Some(info),
+                _ => None,
+            };
+            InspectedValueKind::TupleStruct(
+                (0..value.field_len())
+                    .map(|i| {
+                        inspect_value(
+                            value.field(i).unwrap(),
+                            format!("{path}.{i}"),
+                            Some(i.to_string()),
+                            info.and_then(|info| info.field_at(i))
+                                .map(|field| field.custom_attributes()),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+        ReflectRef::Tuple(value) => InspectedValueKind::Tuple(
+            (0..value.field_len())
+                .map(|i| {
+                    inspect_value(
+                        value.field(i).unwrap(),
+                        format!("{path}.{i}"),
+                        Some(i.to_string()),
+                        None,
+                    )
+                })
+                .collect(),
+        ),
+        ReflectRef::List(value) => InspectedValueKind::List(
+            value
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    inspect_value(item, format!("{path}[{i}]"), Some(i.to_string()), None)
+                })
+                .collect(),
+        ),
+        ReflectRef::Array(value) => InspectedValueKind::Array(
+            value
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    inspect_value(item, format!("{path}[{i}]"), Some(i.to_string()), None)
+                })
+                .collect(),
+        ),
+        ReflectRef::Map(value) => InspectedValueKind::Map { len: value.len() },
+        ReflectRef::Enum(value) => {
+            let variant_info = match type_info {
+                Some(TypeInfo::Enum(info)) => info.variant(value.variant_name()),
+                _ => None,
+            };
+            let fields = (0..value.field_len())
+                .map(|i| {
+                    let (child_path, child_label) = match value.variant_type() {
+                        VariantType::Struct => {
+                            let name = value.name_at(i).unwrap_or_default();
+                            (format!("{path}.{name}"), name.to_string())
+                        }
+                        VariantType::Tuple | VariantType::Unit => {
+                            (format!("{path}.{i}"), i.to_string())
+                        }
+                    };
+                    let attributes = match variant_info {
+                        Some(VariantInfo::Struct(info)) => {
+                            info.field_at(i).map(|field| field.custom_attributes())
+                        }
+                        Some(VariantInfo::Tuple(info)) => {
+                            info.field_at(i).map(|field| field.custom_attributes())
+                        }
+                        _ => None,
+                    };
+                    inspect_value(
+                        value.field_at(i).unwrap(),
+                        child_path,
+                        Some(child_label),
+                        attributes,
+                    )
+                })
+                .collect();
+            InspectedValueKind::Enum {
+                variant: value.variant_name().to_string(),
+                fields,
+            }
+        }
+        ReflectRef::Value(value) => InspectedValueKind::Value(value.clone_value()),
+    };
+
+    InspectedValue {
+        path,
+        label,
+        type_path: value.reflect_type_path().to_string(),
+        range: attributes.and_then(numeric_range),
+        kind,
+    }
+}
+
+/// Reads a numeric range from a `Range` or `RangeInclusive` custom attribute.
+fn numeric_range(attributes: &CustomAttributes) -> Option<RangeInclusive<f64>> {
+    macro_rules! try_ranges {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(range) = attributes.get::<RangeInclusive<$ty>>() {
+                    return Some(*range.start() as f64..=*range.end() as f64);
+                }
+                if let Some(range) = attributes.get::<Range<$ty>>() {
+                    return Some(range.start as f64..=range.end as f64);
+                }
+            )*
+        };
+    }
+
+    if let Some(range) = attributes.get::<RangeInclusive<f64>>() {
+        return Some(range.clone());
+    }
+    if let Some(range) = attributes.get::<Range<f64>>() {
+        return Some(range.start..=range.end);
+    }
+    try_ranges!(f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+    use std::ops::RangeInclusive;
+
+    use bevy_reflect::Reflect;
+
+    use super::{apply_inspector_edit, inspect_entity, InspectedValueKind, InspectorEdit};
+    use crate::prelude::{AppTypeRegistry, ReflectComponent};
+    use crate::reflect::InspectorEditError;
+    use crate::{
+        self as bevy_ecs, change_detection::DetectChanges, component::Component, world::World,
+    };
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        #[reflect(@RangeInclusive::<f32>::new(0.0, 100.0))]
+        current: f32,
+        regen: (u8, bool),
+    }
+
+    #[derive(Component)]
+    struct Opaque;
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Health>();
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn inspect_reflected_and_opaque_components() {
+        let mut world = setup();
+        let entity = world
+            .spawn((
+                Health {
+                    current: 42.0,
+                    regen: (1, true),
+                },
+                Opaque,
+            ))
+            .id();
+
+        let inspected = inspect_entity(&world, entity);
+        assert_eq!(inspected.components.len(), 2);
+
+        let opaque = inspected
+            .components
+            .iter()
+            .find(|component| component.type_id == Some(TypeId::of::<Opaque>()))
+            .unwrap();
+        assert!(opaque.name.ends_with("Opaque"));
+        assert!(opaque.value.is_none());
+
+        let health = inspected
+            .component(TypeId::of::<Health>())
+            .unwrap()
+            .value
+            .as_ref()
+            .unwrap();
+        let current = health.find(".current").unwrap();
+        assert_eq!(current.label.as_deref(), Some("current"));
+        assert_eq!(current.type_path, "f32");
+        assert_eq!(current.range, Some(0.0..=100.0));
+        let InspectedValueKind::Value(value) = &current.kind else {
+            panic!("expected a leaf value");
+        };
+        assert_eq!(value.downcast_ref::<f32>(), Some(&42.0));
+
+        let regen_flag = health.find(".regen.1").unwrap();
+        assert_eq!(regen_flag.range, None);
+        assert_eq!(regen_flag.type_path, "bool");
+    }
+
+    #[test]
+    fn apply_edit_triggers_change_detection() {
+        let mut world = setup();
+        let entity = world.spawn(Health::default()).id();
+        let tick = world.change_tick();
+        world.increment_change_tick();
+
+        apply_inspector_edit(
+            &mut world,
+            InspectorEdit::new(entity, TypeId::of::<Health>(), ".current", 10.0_f32),
+        )
+        .unwrap();
+
+        let health = world.entity(entity).get_ref::<Health>().unwrap();
+        assert_eq!(health.current, 10.0);
+        assert!(health
+            .last_changed()
+            .is_newer_than(tick, world.change_tick()));
+    }
+
+    #[test]
+    fn apply_edit_rejects_invalid_edits() {
+        let mut world = setup();
+        let entity = world.spawn(Health::default()).id();
+
+        let result = apply_inspector_edit(
+            &mut world,
+            InspectorEdit::new(entity, TypeId::of::<Health>(), ".current", true),
+        );
+        assert!(matches!(result, Err(InspectorEditError::Apply(_))));
+
+        let result = apply_inspector_edit(
+            &mut world,
+            InspectorEdit::new(entity, TypeId::of::<Health>(), ".missing", 1.0_f32),
+        );
+        assert!(matches!(
+            result,
+            Err(InspectorEditError::InvalidPath { .. })
+        ));
+
+        world.despawn(entity);
+        let result = apply_inspector_edit(
+            &mut world,
+            InspectorEdit::new(entity, TypeId::of::<Health>(), ".current", 1.0_f32),
+        );
+        assert!(matches!(
+            result,
+            Err(InspectorEditError::EntityDespawned(e)) if e == entity
+        ));
+    }
+}
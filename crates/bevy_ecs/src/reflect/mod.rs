@@ -12,6 +12,7 @@ mod bundle;
 mod component;
 mod entity_commands;
 mod from_world;
+mod inspector;
 mod map_entities;
 mod resource;
 
@@ -19,6 +20,10 @@ pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
+pub use inspector::{
+    apply_inspector_edit, inspect_entity, InspectedComponent, InspectedEntity, InspectedValue,
+    InspectedValueKind, InspectorEdit, InspectorEditError,
+};
 pub use map_entities::{ReflectMapEntities, ReflectMapEntitiesResource};
 pub use resource::{ReflectResource, ReflectResourceFns};
 
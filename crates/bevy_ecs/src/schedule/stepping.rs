@@ -1,5 +1,6 @@
 use fixedbitset::FixedBitSet;
 use std::any::TypeId;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use crate::{
@@ -64,9 +65,10 @@ struct Cursor {
     pub system: usize,
 }
 
-// Two methods of referring to Systems, via TypeId, or per-Schedule NodeId
+// Three methods of referring to Systems, via TypeId, name, or per-Schedule NodeId
 enum SystemIdentifier {
     Type(TypeId),
+    Name(Cow<'static, str>),
     Node(NodeId),
 }
 
@@ -182,6 +184,24 @@ impl Stepping {
             .map(|node_id| (*label, *node_id))
     }
 
+    /// Return the name of the system at our current position within the
+    /// stepping frame, which is the next system to be stepped.
+    ///
+    /// NOTE: Like [`Stepping::cursor()`], this returns `None` at the end of
+    /// the stepping frame, and until the schedule of the cursor has run once
+    /// with stepping enabled.
+    pub fn cursor_system_name(&self) -> Option<&str> {
+        if self.action == Action::RunAll {
+            return None;
+        }
+        let label = self.schedule_order.get(self.cursor.schedule)?;
+        let state = self.schedule_states.get(label)?;
+        state
+            .system_names
+            .get(self.cursor.system)
+            .map(|name| name.as_ref())
+    }
+
     /// Enable stepping for the provided schedule
     pub fn add_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
         self.updates.push(Update::AddSchedule(schedule.intern()));
@@ -324,6 +344,39 @@ impl Stepping {
         self
     }
 
+    /// Add a breakpoint for the systems named `name`
+    ///
+    /// The name matches either the full name of a system, such as
+    /// `my_game::physics::move_bodies`, or its last path segments, such as
+    /// `move_bodies` or `physics::move_bodies`.
+    pub fn set_breakpoint_by_name(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.updates.push(Update::SetBehavior(
+            schedule.intern(),
+            SystemIdentifier::Name(name.into()),
+            SystemBehavior::Break,
+        ));
+        self
+    }
+
+    /// Clear a breakpoint for the systems named `name`
+    ///
+    /// See [`Stepping::set_breakpoint_by_name()`] for how names are matched.
+    pub fn clear_breakpoint_by_name(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.updates.push(Update::ClearBehavior(
+            schedule.intern(),
+            SystemIdentifier::Name(name.into()),
+        ));
+        self
+    }
+
     /// Clear a breakpoint for the system
     pub fn clear_breakpoint<Marker>(
         &mut self,
@@ -601,6 +654,15 @@ impl Stepping {
     }
 }
 
+/// Returns `true` if `name` is the full name of the system, or its last path
+/// segments.
+fn system_name_matches(system_name: &str, name: &str) -> bool {
+    system_name == name
+        || system_name
+            .strip_suffix(name)
+            .is_some_and(|prefix| prefix.ends_with("::"))
+}
+
 #[derive(Default)]
 struct ScheduleState {
     /// per-system [`SystemBehavior`]
@@ -613,10 +675,17 @@ struct ScheduleState {
     /// [`NodeId`]s to the caller.
     node_ids: Vec<NodeId>,
 
+    /// names of the systems in the schedule, in the same order as `node_ids`
+    system_names: Vec<Cow<'static, str>>,
+
     /// changes to system behavior that should be applied the next time
     /// [`ScheduleState::skipped_systems()`] is called
     behavior_updates: TypeIdMap<Option<SystemBehavior>>,
 
+    /// changes to system behavior by system name, applied along with
+    /// `behavior_updates`
+    name_behavior_updates: HashMap<Cow<'static, str>, Option<SystemBehavior>>,
+
     /// This field contains the first steppable system in the schedule.
     first: Option<usize>,
 }
@@ -635,6 +704,9 @@ impl ScheduleState {
             SystemIdentifier::Type(type_id) => {
                 self.behavior_updates.insert(type_id, Some(behavior));
             }
+            SystemIdentifier::Name(name) => {
+                self.name_behavior_updates.insert(name, Some(behavior));
+            }
         }
     }
 
@@ -645,10 +717,13 @@ impl ScheduleState {
             SystemIdentifier::Node(node_id) => {
                 self.behaviors.remove(&node_id);
             }
-            // queue TypeId updates to be processed later when we have Schedule
+            // queue TypeId & name updates to be processed later when we have Schedule
             SystemIdentifier::Type(type_id) => {
                 self.behavior_updates.insert(type_id, None);
             }
+            SystemIdentifier::Name(name) => {
+                self.name_behavior_updates.insert(name, None);
+            }
         }
     }
 
@@ -656,6 +731,7 @@ impl ScheduleState {
     fn clear_behaviors(&mut self) {
         self.behaviors.clear();
         self.behavior_updates.clear();
+        self.name_behavior_updates.clear();
         self.first = None;
     }
 
@@ -668,7 +744,13 @@ impl ScheduleState {
         // PERF: If we add a way to efficiently query schedule systems by their TypeId, we could remove the full
         // system scan here
         for (node_id, system) in schedule.systems().unwrap() {
-            let behavior = self.behavior_updates.get(&system.type_id());
+            let behavior = self.behavior_updates.get(&system.type_id()).or_else(|| {
+                let system_name = system.name();
+                self.name_behavior_updates
+                    .iter()
+                    .find(|(name, _)| system_name_matches(&system_name, name))
+                    .map(|(_, behavior)| behavior)
+            });
             match behavior {
                 None => continue,
                 Some(None) => {
@@ -680,6 +762,7 @@ impl ScheduleState {
             }
         }
         self.behavior_updates.clear();
+        self.name_behavior_updates.clear();
 
         #[cfg(test)]
         debug!("apply_updates(): {:?}", self.behaviors);
@@ -697,12 +780,15 @@ impl ScheduleState {
         // schedule
         if self.node_ids.len() != schedule.systems_len() {
             self.node_ids.clone_from(&schedule.executable().system_ids);
+            self.system_names.clear();
+            self.system_names
+                .extend(schedule.systems().unwrap().map(|(_, system)| system.name()));
         }
 
         // Now that we have the schedule, apply any pending system behavior
         // updates.  The schedule is required to map from system `TypeId` to
         // `NodeId`.
-        if !self.behavior_updates.is_empty() {
+        if !self.behavior_updates.is_empty() || !self.name_behavior_updates.is_empty() {
             self.apply_behavior_updates(schedule);
         }
 
@@ -1557,4 +1643,41 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn breakpoint_by_name() {
+        let (schedule, _world) = setup();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .set_breakpoint_by_name(TestSchedule, "tests::second_system")
+            .continue_frame();
+
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+        assert!(stepping
+            .cursor_system_name()
+            .is_some_and(|name| system_name_matches(name, "second_system")));
+
+        stepping.continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, second_system);
+
+        stepping.clear_breakpoint_by_name(TestSchedule, "second_system");
+        stepping.continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system, second_system);
+    }
+
+    #[test]
+    fn system_names_match_path_segments() {
+        assert!(system_name_matches(
+            "game::physics::move_bodies",
+            "move_bodies"
+        ));
+        assert!(system_name_matches(
+            "game::physics::move_bodies",
+            "physics::move_bodies"
+        ));
+        assert!(!system_name_matches("game::physics::move_bodies", "bodies"));
+    }
 }
@@ -1,9 +1,19 @@
-use bevy_input::gamepad::{Gamepad, GamepadAxisType, GamepadButtonType};
+use bevy_input::gamepad::{Gamepad, GamepadAxisType, GamepadButtonType, GamepadInfo};
 
 pub fn convert_gamepad_id(gamepad_id: gilrs::GamepadId) -> Gamepad {
     Gamepad::new(gamepad_id.into())
 }
 
+pub fn convert_gamepad_info(gamepad: &gilrs::Gamepad) -> GamepadInfo {
+    GamepadInfo {
+        name: gamepad.name().into(),
+        vendor_id: gamepad.vendor_id(),
+        product_id: gamepad.product_id(),
+        // gilrs doesn't expose the serial numbers of the devices
+        serial: None,
+    }
+}
+
 pub fn convert_button(button: gilrs::Button) -> Option<GamepadButtonType> {
     match button {
         gilrs::Button::South => Some(GamepadButtonType::South),
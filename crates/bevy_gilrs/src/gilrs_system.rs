@@ -1,16 +1,16 @@
 use crate::{
-    converter::{convert_axis, convert_button, convert_gamepad_id},
+    converter::{convert_axis, convert_button, convert_gamepad_id, convert_gamepad_info},
     Gilrs,
 };
 use bevy_ecs::event::EventWriter;
 #[cfg(target_arch = "wasm32")]
 use bevy_ecs::system::NonSendMut;
 use bevy_ecs::system::{Res, ResMut};
+use bevy_input::gamepad::GamepadEvent;
 use bevy_input::gamepad::{
     GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadConnection, GamepadConnectionEvent,
     GamepadSettings,
 };
-use bevy_input::gamepad::{GamepadEvent, GamepadInfo};
 use bevy_input::prelude::{GamepadAxis, GamepadButton};
 use bevy_input::Axis;
 use gilrs::{ev::filter::axis_dpad_to_button, EventType, Filter};
@@ -21,9 +21,7 @@ pub fn gilrs_event_startup_system(
     mut events: EventWriter<GamepadEvent>,
 ) {
     for (id, gamepad) in gilrs.0.get().gamepads() {
-        let info = GamepadInfo {
-            name: gamepad.name().into(),
-        };
+        let info = convert_gamepad_info(&gamepad);
 
         events.send(
             GamepadConnectionEvent {
@@ -50,10 +48,7 @@ pub fn gilrs_event_system(
         let gamepad = convert_gamepad_id(gilrs_event.id);
         match gilrs_event.event {
             EventType::Connected => {
-                let pad = gilrs.gamepad(gilrs_event.id);
-                let info = GamepadInfo {
-                    name: pad.name().into(),
-                };
+                let info = convert_gamepad_info(&gilrs.gamepad(gilrs_event.id));
 
                 events.send(
                     GamepadConnectionEvent::new(gamepad, GamepadConnection::Connected(info)).into(),
@@ -119,7 +119,7 @@ where
     }
 }
 
-fn arc_2d_inner(
+pub(crate) fn arc_2d_inner(
     direction_angle: f32,
     arc_angle: f32,
     radius: f32,
@@ -429,6 +429,6 @@ fn arc_3d_inner(
 }
 
 // helper function for getting a default value for the resolution parameter
-fn resolution_from_angle(angle: f32) -> u32 {
+pub(crate) fn resolution_from_angle(angle: f32) -> u32 {
     ((angle.abs() / TAU) * DEFAULT_CIRCLE_RESOLUTION as f32).ceil() as u32
 }
@@ -3,7 +3,7 @@
 use crate as bevy_gizmos;
 pub use bevy_gizmos_macros::GizmoConfigGroup;
 
-use bevy_ecs::{component::Component, reflect::ReflectResource, system::Resource};
+use bevy_ecs::{component::Component, entity::Entity, reflect::ReflectResource, system::Resource};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath};
 use bevy_render::view::RenderLayers;
 use bevy_utils::TypeIdMap;
@@ -41,6 +41,19 @@ pub enum GizmoLineStyle {
     Dotted,
 }
 
+/// An enum configuring how the size of gizmo text changes with its distance to the camera.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+pub enum GizmoTextScale {
+    /// The text keeps its size in pixels, however far it is from the camera.
+    #[default]
+    Fixed,
+    /// The text has its size in pixels at the given distance from the camera, in world units. It
+    /// shrinks further away, and grows closer.
+    ///
+    /// This setting only affects 3D, non-orthographic cameras.
+    Perspective(f32),
+}
+
 /// A trait used to create gizmo configs groups.
 ///
 /// Here you can store additional configuration for you gizmo group not covered by [`GizmoConfig`]
@@ -160,14 +173,47 @@ pub struct GizmoConfig {
     /// This is typically useful if you are drawing wireframes on top of polygons
     /// and your wireframe is z-fighting (flickering on/off) with your main model.
     /// You would set this value to a negative number close to 0.
+    ///
+    /// Filled gizmos, such as [`Gizmos::sphere_filled`](crate::gizmos::Gizmos::sphere_filled),
+    /// are offset the same way.
     pub depth_bias: f32,
     /// Describes which rendering layers gizmos will be rendered to.
     ///
     /// Gizmos will only be rendered to cameras with intersecting layers.
     pub render_layers: RenderLayers,
+    /// The cameras gizmos will be rendered to, if they also have intersecting layers.
+    ///
+    /// When `None`, gizmos are rendered to all the cameras with intersecting layers. This allows
+    /// for example keeping the gizmos of an editor out of a minimap camera.
+    ///
+    /// Defaults to `None`.
+    pub target_cameras: Option<Vec<Entity>>,
 
     /// Describe how lines should join
     pub line_joints: GizmoLineJoint,
+
+    /// Hide gizmo text behind geometry, by testing it against the depth buffer.
+    ///
+    /// When `false`, gizmo text is drawn on top of everything.
+    ///
+    /// Defaults to `false`.
+    pub text_occluded: bool,
+    /// The distance from the camera in world units past which gizmo text isn't drawn.
+    ///
+    /// Text fades out over the last quarter of this distance. When `None`, text is drawn at any
+    /// distance.
+    ///
+    /// Defaults to `None`.
+    pub text_max_distance: Option<f32>,
+    /// How the size of gizmo text changes with its distance to the camera.
+    pub text_scale: GizmoTextScale,
+    /// The maximum number of draws retained with [`Gizmos::retain`](crate::gizmos::Gizmos::retain)
+    /// at once.
+    ///
+    /// Past it, the oldest retained draws are dropped before they expire.
+    ///
+    /// Defaults to `1024`.
+    pub max_retained: usize,
 }
 
 impl Default for GizmoConfig {
@@ -179,8 +225,14 @@ impl Default for GizmoConfig {
             line_style: GizmoLineStyle::Solid,
             depth_bias: 0.,
             render_layers: Default::default(),
+            target_cameras: None,
 
             line_joints: GizmoLineJoint::None,
+
+            text_occluded: false,
+            text_max_distance: None,
+            text_scale: GizmoTextScale::Fixed,
+            max_retained: 1024,
         }
     }
 }
@@ -190,6 +242,23 @@ pub(crate) struct GizmoMeshConfig {
     pub line_perspective: bool,
     pub line_style: GizmoLineStyle,
     pub render_layers: RenderLayers,
+    pub target_cameras: Option<Vec<Entity>>,
+    pub text_occluded: bool,
+}
+
+impl GizmoMeshConfig {
+    /// Returns `true` if the gizmos are rendered to the view of `view_entity`, which has the
+    /// `render_layers`.
+    ///
+    /// The views of cameras are extracted to the render world with the entities of the cameras.
+    #[inline]
+    pub fn is_visible_in(&self, view_entity: Entity, render_layers: &RenderLayers) -> bool {
+        self.render_layers.intersects(render_layers)
+            && self
+                .target_cameras
+                .as_ref()
+                .map_or(true, |cameras| cameras.contains(&view_entity))
+    }
 }
 
 impl From<&GizmoConfig> for GizmoMeshConfig {
@@ -198,6 +267,36 @@ impl From<&GizmoConfig> for GizmoMeshConfig {
             line_perspective: item.line_perspective,
             line_style: item.line_style,
             render_layers: item.render_layers.clone(),
+            target_cameras: item.target_cameras.clone(),
+            text_occluded: item.text_occluded,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gizmos_are_visible_in_target_cameras_with_intersecting_layers() {
+        let editor_camera = Entity::from_raw(1);
+        let minimap_camera = Entity::from_raw(2);
+        let default_layers = RenderLayers::default();
+
+        let mut config = GizmoConfig::default();
+        let mesh_config = GizmoMeshConfig::from(&config);
+        assert!(mesh_config.is_visible_in(editor_camera, &default_layers));
+        assert!(mesh_config.is_visible_in(minimap_camera, &default_layers));
+
+        config.target_cameras = Some(vec![editor_camera]);
+        let mesh_config = GizmoMeshConfig::from(&config);
+        assert!(mesh_config.is_visible_in(editor_camera, &default_layers));
+        assert!(!mesh_config.is_visible_in(minimap_camera, &default_layers));
+
+        // The target cameras must still have intersecting layers
+        config.render_layers = RenderLayers::layer(1);
+        let mesh_config = GizmoMeshConfig::from(&config);
+        assert!(!mesh_config.is_visible_in(editor_camera, &default_layers));
+        assert!(mesh_config.is_visible_in(editor_camera, &RenderLayers::layer(1)));
+    }
+}
@@ -0,0 +1,840 @@
+//! Additional [`Gizmos`] Functions -- Filled
+//!
+//! Includes the implementation of the filled shapes, such as [`Gizmos::sphere_filled`] and
+//! [`Gizmos::arc_2d_filled`], of [`Gizmos::mesh`], and assorted support items.
+//!
+//! Unlike lines, these are drawn as triangles, blended with what is behind them according to the
+//! alpha of their color. The triangles of a [`GizmoConfigGroup`] are drawn with a single draw call.
+
+use std::{any::TypeId, f32::consts::TAU, mem};
+
+use bevy_app::{App, Plugin};
+use bevy_asset::{load_internal_asset, Asset, AssetApp, AssetId, Assets, Handle};
+use bevy_color::{Color, ColorToComponents, LinearRgba};
+use bevy_ecs::{
+    query::ROQueryItem,
+    system::{
+        lifetimeless::{Read, SRes},
+        Commands, Res, ResMut, Resource, SystemParamItem,
+    },
+};
+use bevy_math::{Dir3, Quat, Vec2, Vec3};
+use bevy_reflect::TypePath;
+use bevy_render::{
+    mesh::{Mesh, VertexAttributeValues},
+    render_asset::{PrepareAssetError, RenderAsset, RenderAssetPlugin, RenderAssets},
+    render_phase::{PhaseItem, RenderCommand, RenderCommandResult, TrackedRenderPass},
+    render_resource::{
+        Buffer, BufferInitDescriptor, BufferUsages, PrimitiveTopology, Shader, VertexBufferLayout,
+        VertexFormat, VertexStepMode,
+    },
+    renderer::RenderDevice,
+    Extract, ExtractSchedule, RenderApp,
+};
+use bevy_transform::{components::GlobalTransform, TransformPoint};
+use bevy_utils::{warn_once, TypeIdMap};
+use bytemuck::{cast_slice, Pod, Zeroable};
+
+use crate::{
+    arcs::{arc_2d_inner, resolution_from_angle},
+    circles::DEFAULT_CIRCLE_RESOLUTION,
+    config::{GizmoConfigGroup, GizmoConfigStore, GizmoMeshConfig},
+    gizmos::{GizmoStorage, Gizmos},
+    LineGizmoUniform,
+};
+
+pub(crate) const FILLED_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(5290358470628153925);
+
+impl<'w, 's, Config, Clear> Gizmos<'w, 's, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw a filled sphere in 3D.
+    ///
+    /// This should be called for each frame the sphere needs to be rendered.
+    ///
+    /// The sphere is blended with what is behind it according to the alpha of `color`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{palettes::basic::RED, Alpha};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.sphere_filled(Vec3::ZERO, Quat::IDENTITY, 5., RED.with_alpha(0.2));
+    ///
+    ///     // Spheres have 32 segments around their equator by default.
+    ///     // You may want to increase this for larger spheres.
+    ///     gizmos
+    ///         .sphere_filled(Vec3::ZERO, Quat::IDENTITY, 20., RED.with_alpha(0.2))
+    ///         .resolution(64);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn sphere_filled(
+        &mut self,
+        position: Vec3,
+        rotation: Quat,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> SphereFilledBuilder<'_, 'w, 's, Config, Clear> {
+        SphereFilledBuilder {
+            gizmos: self,
+            position,
+            rotation,
+            radius,
+            color: color.into(),
+            resolution: DEFAULT_CIRCLE_RESOLUTION,
+        }
+    }
+
+    /// Draw a filled circle in 3D at `position` with the flat side facing `normal`.
+    ///
+    /// This should be called for each frame the circle needs to be rendered.
+    ///
+    /// The circle is blended with what is behind it according to the alpha of `color`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{palettes::basic::GREEN, Alpha};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.circle_filled(Vec3::ZERO, Dir3::Y, 1., GREEN.with_alpha(0.5));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn circle_filled(
+        &mut self,
+        position: Vec3,
+        normal: Dir3,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> CircleFilledBuilder<'_, 'w, 's, Config, Clear> {
+        CircleFilledBuilder {
+            gizmos: self,
+            position,
+            rotation: Quat::from_rotation_arc(Vec3::Z, *normal),
+            radius,
+            color: color.into(),
+            resolution: DEFAULT_CIRCLE_RESOLUTION,
+        }
+    }
+
+    /// Draw a filled circle in 2D.
+    ///
+    /// This should be called for each frame the circle needs to be rendered.
+    ///
+    /// The circle is blended with what is behind it according to the alpha of `color`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::{palettes::basic::GREEN, Alpha};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.circle_2d_filled(Vec2::ZERO, 100., GREEN.with_alpha(0.5));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn circle_2d_filled(
+        &mut self,
+        position: Vec2,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> CircleFilledBuilder<'_, 'w, 's, Config, Clear> {
+        self.circle_filled(position.extend(0.), Dir3::Z, radius, color)
+    }
+
+    /// Draw a filled cuboid in 3D.
+    ///
+    /// This should be called for each frame the cuboid needs to be rendered.
+    ///
+    /// The cuboid is blended with what is behind it according to the alpha of `color`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_color::{palettes::basic::GREEN, Alpha};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.cuboid_filled(Transform::IDENTITY, GREEN.with_alpha(0.5));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn cuboid_filled(&mut self, transform: impl TransformPoint, color: impl Into<Color>) {
+        if !self.enabled {
+            return;
+        }
+        let corners = [
+            Vec3::new(-0.5, -0.5, -0.5),
+            Vec3::new(0.5, -0.5, -0.5),
+            Vec3::new(0.5, 0.5, -0.5),
+            Vec3::new(-0.5, 0.5, -0.5),
+            Vec3::new(-0.5, -0.5, 0.5),
+            Vec3::new(0.5, -0.5, 0.5),
+            Vec3::new(0.5, 0.5, 0.5),
+            Vec3::new(-0.5, 0.5, 0.5),
+        ]
+        .map(|corner| transform.transform_point(corner));
+        // Two triangles per face
+        const FACES: [[usize; 4]; 6] = [
+            [0, 1, 2, 3],
+            [5, 4, 7, 6],
+            [4, 0, 3, 7],
+            [1, 5, 6, 2],
+            [3, 2, 6, 7],
+            [4, 5, 1, 0],
+        ];
+        let positions = FACES
+            .into_iter()
+            .flat_map(|[a, b, c, d]| [a, b, c, a, c, d])
+            .map(|index| corners[index]);
+        self.add_triangles(positions, color);
+    }
+
+    /// Draw a filled arc in 2D, which is a slice of a circle between its center and the arc drawn
+    /// by [`Gizmos::arc_2d`] with the same arguments.
+    ///
+    /// This should be called for each frame the arc needs to be rendered.
+    ///
+    /// The arc is blended with what is behind it according to the alpha of `color`.
+    ///
+    /// # Arguments
+    /// - `position` sets the center of this circle.
+    /// - `direction_angle` sets the counter-clockwise  angle in radians between `Vec2::Y` and
+    ///     the vector from `position` to the midpoint of the arc.
+    /// - `arc_angle` sets the length of this arc, in radians.
+    /// - `radius` controls the distance from `position` to this arc, and thus its curvature.
+    /// - `color` sets the color to fill the arc.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use std::f32::consts::PI;
+    /// # use bevy_color::{palettes::basic::YELLOW, Alpha};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     // A field of view of 90 degrees, looking up
+    ///     gizmos.arc_2d_filled(Vec2::ZERO, 0., PI / 2., 200., YELLOW.with_alpha(0.3));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn arc_2d_filled(
+        &mut self,
+        position: Vec2,
+        direction_angle: f32,
+        arc_angle: f32,
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> Arc2dFilledBuilder<'_, 'w, 's, Config, Clear> {
+        Arc2dFilledBuilder {
+            gizmos: self,
+            position,
+            direction_angle,
+            arc_angle,
+            radius,
+            color: color.into(),
+            resolution: None,
+        }
+    }
+
+    /// Draw the triangles of a mesh, transformed by `transform`, in a single color.
+    ///
+    /// This should be called for each frame the mesh needs to be rendered.
+    ///
+    /// The mesh doesn't need a material: it's drawn with the other filled gizmos, blended with
+    /// what is behind it according to the alpha of `color`. Only meshes with a
+    /// [`PrimitiveTopology::TriangleList`] or [`PrimitiveTopology::TriangleStrip`] topology, whose
+    /// data is kept in the main world, can be drawn. The mesh isn't drawn while it's loading.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_asset::Handle;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_render::mesh::Mesh;
+    /// # use bevy_transform::prelude::*;
+    /// # use bevy_color::{palettes::basic::AQUA, Alpha};
+    /// # #[derive(Resource)]
+    /// # struct Collider(Handle<Mesh>);
+    /// fn system(mut gizmos: Gizmos, collider: Res<Collider>) {
+    ///     gizmos.mesh(&collider.0, Transform::IDENTITY, AQUA.with_alpha(0.3));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn mesh(
+        &mut self,
+        mesh: impl Into<AssetId<Mesh>>,
+        transform: impl Into<GlobalTransform>,
+        color: impl Into<Color>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        self.add_mesh(GizmoMesh {
+            mesh: mesh.into(),
+            transform: transform.into(),
+            color: LinearRgba::from(color.into()),
+        });
+    }
+}
+
+/// A builder returned by [`Gizmos::sphere_filled`].
+pub struct SphereFilledBuilder<'a, 'w, 's, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut Gizmos<'w, 's, Config, Clear>,
+    position: Vec3,
+    rotation: Quat,
+    radius: f32,
+    color: Color,
+    resolution: u32,
+}
+
+impl<Config, Clear> SphereFilledBuilder<'_, '_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of segments around the equator of the sphere, which has half as many
+    /// segments from pole to pole.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for SphereFilledBuilder<'_, '_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let segments = self.resolution.max(3);
+        let rings = (segments / 2).max(2);
+        let point = |ring: u32, segment: u32| {
+            let polar = ring as f32 / rings as f32 * TAU / 2.;
+            let azimuth = segment as f32 / segments as f32 * TAU;
+            let unit = Vec3::new(
+                polar.sin() * azimuth.cos(),
+                polar.cos(),
+                polar.sin() * azimuth.sin(),
+            );
+            self.position + self.rotation * (unit * self.radius)
+        };
+        let positions: Vec<Vec3> = (0..rings)
+            .flat_map(|ring| (0..segments).map(move |segment| (ring, segment)))
+            .flat_map(|(ring, segment)| {
+                let top_left = point(ring, segment);
+                let top_right = point(ring, segment + 1);
+                let bottom_left = point(ring + 1, segment);
+                let bottom_right = point(ring + 1, segment + 1);
+                [
+                    top_left,
+                    bottom_left,
+                    bottom_right,
+                    top_left,
+                    bottom_right,
+                    top_right,
+                ]
+            })
+            .collect();
+        self.gizmos.add_triangles(positions, self.color);
+    }
+}
+
+/// A builder returned by [`Gizmos::circle_filled`] and [`Gizmos::circle_2d_filled`].
+pub struct CircleFilledBuilder<'a, 'w, 's, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut Gizmos<'w, 's, Config, Clear>,
+    position: Vec3,
+    rotation: Quat,
+    radius: f32,
+    color: Color,
+    resolution: u32,
+}
+
+impl<Config, Clear> CircleFilledBuilder<'_, '_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of triangles used to approximate the geometry of this circle.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<Config, Clear> Drop for CircleFilledBuilder<'_, '_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let resolution = self.resolution.max(3);
+        let rim: Vec<Vec3> = (0..=resolution)
+            .map(|i| {
+                let angle = i as f32 * TAU / resolution as f32;
+                let point = Vec2::from_angle(angle) * self.radius;
+                self.position + self.rotation * point.extend(0.)
+            })
+            .collect();
+        let positions = fan(self.position, &rim);
+        self.gizmos.add_triangles(positions, self.color);
+    }
+}
+
+/// A builder returned by [`Gizmos::arc_2d_filled`].
+pub struct Arc2dFilledBuilder<'a, 'w, 's, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut Gizmos<'w, 's, Config, Clear>,
+    position: Vec2,
+    direction_angle: f32,
+    arc_angle: f32,
+    radius: f32,
+    color: Color,
+    resolution: Option<u32>,
+}
+
+impl<Config, Clear> Arc2dFilledBuilder<'_, '_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of triangles used to approximate the geometry of this arc.
+    pub fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution.replace(resolution);
+        self
+    }
+}
+
+impl<Config, Clear> Drop for Arc2dFilledBuilder<'_, '_, '_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        let resolution = self
+            .resolution
+            .unwrap_or_else(|| resolution_from_angle(self.arc_angle))
+            .max(1);
+
+        let rim: Vec<Vec3> = arc_2d_inner(
+            self.direction_angle,
+            self.arc_angle,
+            self.radius,
+            resolution,
+        )
+        .map(|vec2| (vec2 + self.position).extend(0.))
+        .collect();
+        let positions = fan(self.position.extend(0.), &rim);
+        self.gizmos.add_triangles(positions, self.color);
+    }
+}
+
+/// The triangles between `center` and each pair of consecutive points of `rim`.
+fn fan(center: Vec3, rim: &[Vec3]) -> impl Iterator<Item = Vec3> + '_ {
+    rim.windows(2)
+        .flat_map(move |edge| [center, edge[0], edge[1]])
+}
+
+/// A mesh requested with [`Gizmos::mesh`].
+#[derive(Clone, Debug)]
+pub(crate) struct GizmoMesh {
+    pub(crate) mesh: AssetId<Mesh>,
+    pub(crate) transform: GlobalTransform,
+    pub(crate) color: LinearRgba,
+}
+
+/// The positions of the corners of the triangles of `mesh`, three per triangle, or `None` if it
+/// can't be drawn as a gizmo.
+fn mesh_triangles(mesh: &Mesh) -> Option<Vec<Vec3>> {
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return None;
+    };
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..positions.len()).collect(),
+    };
+    let corners: Vec<usize> = match mesh.primitive_topology() {
+        PrimitiveTopology::TriangleList => indices.chunks_exact(3).flatten().copied().collect(),
+        // The winding order alternates along a strip, which doesn't matter without culling
+        PrimitiveTopology::TriangleStrip => indices.windows(3).flatten().copied().collect(),
+        _ => return None,
+    };
+    corners
+        .into_iter()
+        .map(|index| positions.get(index).copied().map(Vec3::from))
+        .collect()
+}
+
+/// A [`Plugin`] that renders the filled shapes and the meshes drawn with [`Gizmos`].
+pub(crate) struct FilledGizmoPlugin;
+
+impl Plugin for FilledGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, FILLED_SHADER_HANDLE, "filled.wgsl", Shader::from_wgsl);
+
+        app.init_asset::<FilledGizmo>()
+            .add_plugins(RenderAssetPlugin::<GpuFilledGizmo>::default())
+            .init_resource::<FilledGizmoHandles>();
+
+        #[cfg(feature = "bevy_sprite")]
+        if app.is_plugin_added::<bevy_sprite::SpritePlugin>() {
+            app.add_plugins(crate::pipeline_filled_2d::FilledGizmo2dPlugin);
+        }
+        #[cfg(feature = "bevy_pbr")]
+        if app.is_plugin_added::<bevy_pbr::PbrPlugin>() {
+            app.add_plugins(crate::pipeline_filled_3d::FilledGizmo3dPlugin);
+        }
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.add_systems(ExtractSchedule, extract_filled_gizmo_data);
+    }
+}
+
+/// Holds handles to the filled gizmos for each gizmo configuration group
+// See `LineGizmoHandles` for why the handles are optional.
+#[derive(Resource, Default)]
+pub(crate) struct FilledGizmoHandles {
+    pub(crate) filled: TypeIdMap<Option<Handle<FilledGizmo>>>,
+}
+
+/// Prepares the filled shapes and the meshes of the gizmos for rendering.
+///
+/// This also clears them from the default `GizmoStorage`.
+pub(crate) fn update_filled_gizmos<Config: GizmoConfigGroup>(
+    mut filled_gizmos: ResMut<Assets<FilledGizmo>>,
+    mut handles: ResMut<FilledGizmoHandles>,
+    mut storage: ResMut<GizmoStorage<Config, ()>>,
+    meshes: Option<Res<Assets<Mesh>>>,
+) {
+    let positions = mem::take(&mut storage.triangle_positions);
+    let colors = mem::take(&mut storage.triangle_colors);
+    let mut vertices: Vec<FilledGizmoVertex> = positions
+        .into_iter()
+        .zip(colors)
+        .map(|(position, color)| FilledGizmoVertex::new(position, color))
+        .collect();
+
+    for GizmoMesh {
+        mesh,
+        transform,
+        color,
+    } in mem::take(&mut storage.meshes)
+    {
+        // The mesh is drawn once it's loaded
+        let Some(mesh) = meshes.as_ref().and_then(|meshes| meshes.get(mesh)) else {
+            continue;
+        };
+        let Some(triangles) = mesh_triangles(mesh) else {
+            // Gizmos are drawn every frame, so this is only logged once
+            warn_once!(
+                "Failed to draw a mesh with `Gizmos::mesh`: only triangle meshes with `Float32x3` positions are supported, found a {:?} mesh.",
+                mesh.primitive_topology()
+            );
+            continue;
+        };
+        vertices.extend(
+            triangles
+                .into_iter()
+                .map(|position| FilledGizmoVertex::new(transform.transform_point(position), color)),
+        );
+    }
+
+    if vertices.is_empty() {
+        handles.filled.insert(TypeId::of::<Config>(), None);
+        return;
+    }
+
+    if let Some(handle) = handles.filled.get_mut(&TypeId::of::<Config>()) {
+        if let Some(handle) = handle {
+            filled_gizmos.get_mut(handle.id()).unwrap().vertices = vertices;
+        } else {
+            *handle = Some(filled_gizmos.add(FilledGizmo { vertices }));
+        }
+    }
+}
+
+fn extract_filled_gizmo_data(
+    mut commands: Commands,
+    handles: Extract<Res<FilledGizmoHandles>>,
+    config: Extract<Res<GizmoConfigStore>>,
+) {
+    for (group_type_id, handle) in handles.filled.iter() {
+        let Some((config, _)) = config.get_config_dyn(group_type_id) else {
+            continue;
+        };
+
+        if !config.enabled {
+            continue;
+        }
+
+        let Some(handle) = handle else {
+            continue;
+        };
+
+        // Filled gizmos share the uniform of the lines, so that they're offset with the same
+        // depth bias
+        commands.spawn((
+            LineGizmoUniform {
+                line_width: config.line_width,
+                depth_bias: config.depth_bias,
+                joints_resolution: 0,
+                #[cfg(feature = "webgl")]
+                _padding: Default::default(),
+            },
+            (*handle).clone_weak(),
+            GizmoMeshConfig::from(config),
+        ));
+    }
+}
+
+/// A corner of a filled gizmo triangle.
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub(crate) struct FilledGizmoVertex {
+    position: [f32; 3],
+    color: [f32; 4],
+}
+
+impl FilledGizmoVertex {
+    fn new(position: Vec3, color: LinearRgba) -> Self {
+        Self {
+            position: position.to_array(),
+            color: color.to_f32_array(),
+        }
+    }
+}
+
+#[derive(Asset, Debug, Default, Clone, TypePath)]
+pub(crate) struct FilledGizmo {
+    /// The corners of the triangles, three per triangle.
+    vertices: Vec<FilledGizmoVertex>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GpuFilledGizmo {
+    vertex_buffer: Buffer,
+    vertex_count: u32,
+}
+
+impl RenderAsset for GpuFilledGizmo {
+    type SourceAsset = FilledGizmo;
+    type Param = SRes<RenderDevice>;
+
+    fn prepare_asset(
+        gizmo: Self::SourceAsset,
+        render_device: &mut SystemParamItem<Self::Param>,
+    ) -> Result<Self, PrepareAssetError<Self::SourceAsset>> {
+        let vertex_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            usage: BufferUsages::VERTEX,
+            label: Some("FilledGizmo Vertex Buffer"),
+            contents: cast_slice(&gizmo.vertices),
+        });
+
+        Ok(GpuFilledGizmo {
+            vertex_buffer,
+            vertex_count: gizmo.vertices.len() as u32,
+        })
+    }
+}
+
+/// Draws all the triangles of a filled gizmo with a single draw call.
+pub(crate) struct DrawFilledGizmo;
+impl<P: PhaseItem> RenderCommand<P> for DrawFilledGizmo {
+    type Param = SRes<RenderAssets<GpuFilledGizmo>>;
+    type ViewQuery = ();
+    type ItemQuery = Read<Handle<FilledGizmo>>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        _view: ROQueryItem<'w, Self::ViewQuery>,
+        handle: Option<ROQueryItem<'w, Self::ItemQuery>>,
+        filled_gizmos: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(handle) = handle else {
+            return RenderCommandResult::Failure;
+        };
+        let Some(filled_gizmo) = filled_gizmos.into_inner().get(handle) else {
+            return RenderCommandResult::Failure;
+        };
+
+        pass.set_vertex_buffer(0, filled_gizmo.vertex_buffer.slice(..));
+        pass.draw(0..filled_gizmo.vertex_count, 0..1);
+
+        RenderCommandResult::Success
+    }
+}
+
+pub(crate) fn filled_gizmo_vertex_buffer_layout() -> VertexBufferLayout {
+    use VertexFormat::*;
+    VertexBufferLayout::from_vertex_formats(VertexStepMode::Vertex, [Float32x3, Float32x4])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use bevy_color::palettes::basic::{BLUE, RED};
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_render::{mesh::Indices, render_asset::RenderAssetUsages};
+    use bevy_transform::components::Transform;
+
+    use super::*;
+    use crate::config::{DefaultGizmoConfigGroup, GizmoConfigStore};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let mut config_store = GizmoConfigStore::default();
+        config_store.register::<DefaultGizmoConfigGroup>();
+        world.insert_resource(config_store);
+        world.init_resource::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+        world
+    }
+
+    /// Draws the gizmos of `system`, and returns the number of triangles and colors they added.
+    fn draw(system: impl FnMut(Gizmos) + Send + Sync + 'static) -> (usize, usize) {
+        let mut world = setup();
+        world.run_system_once(system);
+        let storage = world.resource::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+        (
+            storage.triangle_positions.len() / 3,
+            storage.triangle_colors.len() / 3,
+        )
+    }
+
+    #[test]
+    fn filled_builders_add_one_color_per_corner() {
+        // Two triangles per segment of each ring, with half as many rings as segments
+        let sphere = draw(|mut gizmos| {
+            gizmos
+                .sphere_filled(Vec3::ZERO, Quat::IDENTITY, 1., RED)
+                .resolution(8);
+        });
+        assert_eq!(sphere, (64, 64));
+
+        let circle = draw(|mut gizmos| {
+            gizmos
+                .circle_filled(Vec3::ZERO, Dir3::Y, 1., RED)
+                .resolution(8);
+        });
+        assert_eq!(circle, (8, 8));
+        let circle_2d = draw(|mut gizmos| {
+            gizmos.circle_2d_filled(Vec2::ZERO, 1., RED).resolution(5);
+        });
+        assert_eq!(circle_2d, (5, 5));
+
+        let cuboid = draw(|mut gizmos| gizmos.cuboid_filled(Transform::IDENTITY, RED));
+        assert_eq!(cuboid, (12, 12));
+
+        let arc = draw(|mut gizmos| {
+            gizmos
+                .arc_2d_filled(Vec2::ZERO, 0., PI / 2., 1., RED)
+                .resolution(4);
+        });
+        assert_eq!(arc, (4, 4));
+
+        // Each shape keeps its own color
+        let mut world = setup();
+        world.run_system_once(|mut gizmos: Gizmos| {
+            gizmos.cuboid_filled(Transform::IDENTITY, RED);
+            gizmos.circle_2d_filled(Vec2::ZERO, 1., BLUE).resolution(3);
+        });
+        let storage = world.resource::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+        assert_eq!(storage.triangle_colors.len(), 45);
+        assert_eq!(storage.triangle_colors[35], LinearRgba::from(RED));
+        assert_eq!(storage.triangle_colors[36], LinearRgba::from(BLUE));
+    }
+
+    fn quad(topology: PrimitiveTopology, indices: Option<Vec<u32>>) -> Mesh {
+        let mesh = Mesh::new(topology, RenderAssetUsages::default()).with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [1., 1., 0.]],
+        );
+        match indices {
+            Some(indices) => mesh.with_inserted_indices(Indices::U32(indices)),
+            None => mesh,
+        }
+    }
+
+    #[test]
+    fn mesh_triangles_of_each_topology() {
+        let list = quad(
+            PrimitiveTopology::TriangleList,
+            Some(vec![0, 1, 2, 2, 1, 3]),
+        );
+        assert_eq!(
+            mesh_triangles(&list).unwrap(),
+            [0, 1, 2, 2, 1, 3].map(|index| position(&list, index))
+        );
+        // Without indices, the vertices are used in order, and an incomplete triangle is dropped
+        let unindexed = quad(PrimitiveTopology::TriangleList, None);
+        assert_eq!(mesh_triangles(&unindexed).unwrap().len(), 3);
+
+        // Each vertex after the first two adds a triangle to a strip
+        let strip = quad(PrimitiveTopology::TriangleStrip, None);
+        assert_eq!(
+            mesh_triangles(&strip).unwrap(),
+            [0, 1, 2, 1, 2, 3].map(|index| position(&strip, index))
+        );
+        let indexed_strip = quad(PrimitiveTopology::TriangleStrip, Some(vec![3, 2, 1]));
+        assert_eq!(mesh_triangles(&indexed_strip).unwrap().len(), 3);
+
+        for topology in [
+            PrimitiveTopology::PointList,
+            PrimitiveTopology::LineList,
+            PrimitiveTopology::LineStrip,
+        ] {
+            assert!(mesh_triangles(&quad(topology, None)).is_none());
+        }
+        // Nor can a mesh with indices out of bounds
+        let out_of_bounds = quad(PrimitiveTopology::TriangleList, Some(vec![0, 1, 4]));
+        assert!(mesh_triangles(&out_of_bounds).is_none());
+    }
+
+    fn position(mesh: &Mesh, index: usize) -> Vec3 {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            unreachable!();
+        };
+        Vec3::from(positions[index])
+    }
+}
@@ -0,0 +1,50 @@
+// TODO use common view binding
+#import bevy_render::view::View
+
+@group(0) @binding(0) var<uniform> view: View;
+
+
+// Filled gizmos share the uniform of the lines, of which they only use the depth bias
+struct LineGizmoUniform {
+    line_width: f32,
+    depth_bias: f32,
+    joints_resolution: u32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    // WebGL2 structs must be 16 byte aligned.
+    _padding: f32,
+#endif
+}
+
+@group(1) @binding(0) var<uniform> line_gizmo: LineGizmoUniform;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+const EPSILON: f32 = 4.88e-04;
+
+@vertex
+fn vertex(vertex: VertexInput) -> VertexOutput {
+    let clip = view.clip_from_world * vec4(vertex.position, 1.);
+
+    var depth: f32;
+    if line_gizmo.depth_bias >= 0. {
+        depth = clip.z * (1. - line_gizmo.depth_bias);
+    } else {
+        // See `lines.wgsl`
+        depth = clip.z * exp2(-line_gizmo.depth_bias * log2(clip.w / clip.z - EPSILON));
+    }
+
+    return VertexOutput(vec4(clip.xy, depth, clip.w), vertex.color);
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
@@ -1,6 +1,6 @@
 //! A module for the [`Gizmos`] [`SystemParam`].
 
-use std::{iter, marker::PhantomData, mem};
+use std::{iter, marker::PhantomData, mem, time::Duration};
 
 use bevy_color::{Color, LinearRgba};
 use bevy_ecs::{
@@ -9,13 +9,18 @@ use bevy_ecs::{
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
 use bevy_math::{Quat, Rot2, Vec2, Vec3};
+use bevy_time::{Time, Virtual};
 use bevy_transform::TransformPoint;
 use bevy_utils::default;
 
+#[cfg(feature = "bevy_text")]
+use crate::text::GizmoText;
 use crate::{
     config::GizmoConfigGroup,
     config::{DefaultGizmoConfigGroup, GizmoConfigStore},
+    filled::GizmoMesh,
     prelude::GizmoConfig,
+    retained::RetainedGizmos,
 };
 
 /// Storage of gizmo primitives.
@@ -25,6 +30,11 @@ pub struct GizmoStorage<Config, Clear> {
     pub(crate) list_colors: Vec<LinearRgba>,
     pub(crate) strip_positions: Vec<Vec3>,
     pub(crate) strip_colors: Vec<LinearRgba>,
+    pub(crate) triangle_positions: Vec<Vec3>,
+    pub(crate) triangle_colors: Vec<LinearRgba>,
+    pub(crate) meshes: Vec<GizmoMesh>,
+    #[cfg(feature = "bevy_text")]
+    pub(crate) texts: Vec<GizmoText>,
     marker: PhantomData<(Config, Clear)>,
 }
 
@@ -35,6 +45,11 @@ impl<Config, Clear> Default for GizmoStorage<Config, Clear> {
             list_colors: default(),
             strip_positions: default(),
             strip_colors: default(),
+            triangle_positions: default(),
+            triangle_colors: default(),
+            meshes: default(),
+            #[cfg(feature = "bevy_text")]
+            texts: default(),
             marker: PhantomData,
         }
     }
@@ -54,6 +69,12 @@ where
         self.list_colors.extend(other.list_colors.iter());
         self.strip_positions.extend(other.strip_positions.iter());
         self.strip_colors.extend(other.strip_colors.iter());
+        self.triangle_positions
+            .extend(other.triangle_positions.iter());
+        self.triangle_colors.extend(other.triangle_colors.iter());
+        self.meshes.extend(other.meshes.iter().cloned());
+        #[cfg(feature = "bevy_text")]
+        self.texts.extend(other.texts.iter().cloned());
     }
 
     pub(crate) fn swap<OtherConfig, OtherClear>(
@@ -64,6 +85,11 @@ where
         mem::swap(&mut self.list_colors, &mut other.list_colors);
         mem::swap(&mut self.strip_positions, &mut other.strip_positions);
         mem::swap(&mut self.strip_colors, &mut other.strip_colors);
+        mem::swap(&mut self.triangle_positions, &mut other.triangle_positions);
+        mem::swap(&mut self.triangle_colors, &mut other.triangle_colors);
+        mem::swap(&mut self.meshes, &mut other.meshes);
+        #[cfg(feature = "bevy_text")]
+        mem::swap(&mut self.texts, &mut other.texts);
     }
 
     /// Clear this gizmo storage of any requested gizmos.
@@ -72,6 +98,11 @@ where
         self.list_colors.clear();
         self.strip_positions.clear();
         self.strip_colors.clear();
+        self.triangle_positions.clear();
+        self.triangle_colors.clear();
+        self.meshes.clear();
+        #[cfg(feature = "bevy_text")]
+        self.texts.clear();
     }
 }
 
@@ -235,6 +266,14 @@ where
     list_colors: Vec<LinearRgba>,
     strip_positions: Vec<Vec3>,
     strip_colors: Vec<LinearRgba>,
+    triangle_positions: Vec<Vec3>,
+    triangle_colors: Vec<LinearRgba>,
+    meshes: Vec<GizmoMesh>,
+    #[cfg(feature = "bevy_text")]
+    texts: Vec<GizmoText>,
+    /// The gizmos drawn with [`Gizmos::retain`], with how long each of them is retained.
+    retained: Vec<(Duration, GizmoStorage<Config, ()>)>,
+    clear_retained: bool,
     marker: PhantomData<(Config, Clear)>,
 }
 
@@ -249,6 +288,13 @@ where
             list_colors: default(),
             strip_positions: default(),
             strip_colors: default(),
+            triangle_positions: default(),
+            triangle_colors: default(),
+            meshes: default(),
+            #[cfg(feature = "bevy_text")]
+            texts: default(),
+            retained: default(),
+            clear_retained: false,
             marker: PhantomData,
         }
     }
@@ -265,6 +311,32 @@ where
         storage.list_colors.append(&mut self.list_colors);
         storage.strip_positions.append(&mut self.strip_positions);
         storage.strip_colors.append(&mut self.strip_colors);
+        storage
+            .triangle_positions
+            .append(&mut self.triangle_positions);
+        storage.triangle_colors.append(&mut self.triangle_colors);
+        storage.meshes.append(&mut self.meshes);
+        #[cfg(feature = "bevy_text")]
+        storage.texts.append(&mut self.texts);
+
+        if !self.clear_retained && self.retained.is_empty() {
+            return;
+        }
+        let elapsed = world
+            .get_resource::<Time<Virtual>>()
+            .map_or(Duration::ZERO, Time::elapsed);
+        let max_retained = world
+            .resource::<GizmoConfigStore>()
+            .config::<Config>()
+            .0
+            .max_retained;
+        let mut retained = world.resource_mut::<RetainedGizmos<Config>>();
+        if mem::take(&mut self.clear_retained) {
+            retained.clear();
+        }
+        for (duration, storage) in self.retained.drain(..) {
+            retained.push(elapsed + duration, storage, max_retained);
+        }
     }
 }
 
@@ -719,6 +791,101 @@ where
         self.linestrip_2d([tl, tr, br, bl, tl], color);
     }
 
+    /// Keep drawing the gizmos drawn in `draw` for `duration`, instead of only this frame.
+    ///
+    /// The retained gizmos expire on the [`Time<Virtual>`] clock, so they don't expire while it's
+    /// paused. At most [`GizmoConfig::max_retained`] draws are retained for each
+    /// [`GizmoConfigGroup`]: past it, the oldest ones are dropped.
+    ///
+    /// The gizmos are drawn with the configuration of their group at the time they are rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::{GREEN, RED};
+    /// # use std::time::Duration;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.retain(Duration::from_secs(5), |gizmos| {
+    ///         gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., RED);
+    ///         gizmos.line(Vec3::ZERO, Vec3::Y, GREEN);
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn retain(&mut self, duration: Duration, draw: impl FnOnce(&mut Self)) {
+        if !self.enabled {
+            return;
+        }
+        let list_positions = self.buffer.list_positions.len();
+        let list_colors = self.buffer.list_colors.len();
+        let strip_positions = self.buffer.strip_positions.len();
+        let strip_colors = self.buffer.strip_colors.len();
+        let triangle_positions = self.buffer.triangle_positions.len();
+        let triangle_colors = self.buffer.triangle_colors.len();
+        let meshes = self.buffer.meshes.len();
+        #[cfg(feature = "bevy_text")]
+        let texts = self.buffer.texts.len();
+
+        draw(self);
+
+        // Move everything drawn by `draw` out of this frame's gizmos
+        let storage = GizmoStorage {
+            list_positions: self.buffer.list_positions.split_off(list_positions),
+            list_colors: self.buffer.list_colors.split_off(list_colors),
+            strip_positions: self.buffer.strip_positions.split_off(strip_positions),
+            strip_colors: self.buffer.strip_colors.split_off(strip_colors),
+            triangle_positions: self.buffer.triangle_positions.split_off(triangle_positions),
+            triangle_colors: self.buffer.triangle_colors.split_off(triangle_colors),
+            meshes: self.buffer.meshes.split_off(meshes),
+            #[cfg(feature = "bevy_text")]
+            texts: self.buffer.texts.split_off(texts),
+            marker: PhantomData,
+        };
+        self.buffer.retained.push((duration, storage));
+    }
+
+    /// Stop drawing the gizmos of this [`GizmoConfigGroup`] retained with [`Gizmos::retain`],
+    /// including the ones retained earlier by this system.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.clear_retained();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn clear_retained(&mut self) {
+        self.buffer.clear_retained = true;
+        self.buffer.retained.clear();
+    }
+
+    #[inline]
+    pub(crate) fn add_triangles(
+        &mut self,
+        positions: impl IntoIterator<Item = Vec3>,
+        color: impl Into<Color>,
+    ) {
+        self.buffer.triangle_positions.extend(positions);
+        let color = LinearRgba::from(color.into());
+        let count = self.buffer.triangle_positions.len() - self.buffer.triangle_colors.len();
+        self.buffer
+            .triangle_colors
+            .extend(iter::repeat(color).take(count));
+    }
+
+    #[inline]
+    pub(crate) fn add_mesh(&mut self, mesh: GizmoMesh) {
+        self.buffer.meshes.push(mesh);
+    }
+
+    #[cfg(feature = "bevy_text")]
+    #[inline]
+    pub(crate) fn add_text(&mut self, text: GizmoText) {
+        self.buffer.texts.push(text);
+    }
+
     #[inline]
     fn extend_list_positions(&mut self, positions: impl IntoIterator<Item = Vec3>) {
         self.buffer.list_positions.extend(positions);
@@ -37,10 +37,14 @@ pub mod arrows;
 pub mod circles;
 pub mod config;
 pub mod cross;
+pub mod filled;
 pub mod gizmos;
 pub mod grid;
 pub mod primitives;
+pub mod retained;
 pub mod rounded_box;
+#[cfg(feature = "bevy_text")]
+pub mod text;
 
 #[cfg(feature = "bevy_pbr")]
 pub mod light;
@@ -49,6 +53,14 @@ pub mod light;
 mod pipeline_2d;
 #[cfg(feature = "bevy_pbr")]
 mod pipeline_3d;
+#[cfg(feature = "bevy_sprite")]
+mod pipeline_filled_2d;
+#[cfg(feature = "bevy_pbr")]
+mod pipeline_filled_3d;
+#[cfg(feature = "bevy_text")]
+mod pipeline_text_2d;
+#[cfg(all(feature = "bevy_text", feature = "bevy_pbr"))]
+mod pipeline_text_3d;
 
 /// The `bevy_gizmos` prelude.
 pub mod prelude {
@@ -57,10 +69,11 @@ pub mod prelude {
         aabb::{AabbGizmoConfigGroup, ShowAabbGizmo},
         config::{
             DefaultGizmoConfigGroup, GizmoConfig, GizmoConfigGroup, GizmoConfigStore,
-            GizmoLineJoint, GizmoLineStyle,
+            GizmoLineJoint, GizmoLineStyle, GizmoTextScale,
         },
         gizmos::Gizmos,
         primitives::{dim2::GizmoPrimitive2d, dim3::GizmoPrimitive3d},
+        retained::RetainedGizmos,
         AppGizmoBuilder,
     };
 
@@ -72,6 +85,8 @@ use aabb::AabbGizmoPlugin;
 use bevy_app::{App, FixedFirst, FixedLast, Last, Plugin, RunFixedMainLoop};
 use bevy_asset::{load_internal_asset, Asset, AssetApp, Assets, Handle};
 use bevy_color::LinearRgba;
+#[cfg(feature = "bevy_text")]
+use bevy_ecs::schedule::common_conditions::resource_exists;
 use bevy_ecs::{
     component::Component,
     query::ROQueryItem,
@@ -95,6 +110,8 @@ use bevy_render::{
     renderer::RenderDevice,
     Extract, ExtractSchedule, Render, RenderApp, RenderSet,
 };
+#[cfg(feature = "bevy_text")]
+use bevy_text::TextPipeline;
 use bevy_time::Fixed;
 use bevy_utils::TypeIdMap;
 use bytemuck::cast_slice;
@@ -105,6 +122,7 @@ use config::{
 use gizmos::{GizmoStorage, Swap};
 #[cfg(feature = "bevy_pbr")]
 use light::LightGizmoPlugin;
+use retained::RetainedGizmos;
 use std::{any::TypeId, mem};
 
 const LINE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(7414812689238026784);
@@ -139,11 +157,15 @@ impl Plugin for GizmoPlugin {
             .init_resource::<LineGizmoHandles>()
             // We insert the Resource GizmoConfigStore into the world implicitly here if it does not exist.
             .init_gizmo_group::<DefaultGizmoConfigGroup>()
-            .add_plugins(AabbGizmoPlugin);
+            .add_plugins(AabbGizmoPlugin)
+            .add_plugins(filled::FilledGizmoPlugin);
 
         #[cfg(feature = "bevy_pbr")]
         app.add_plugins(LightGizmoPlugin);
 
+        #[cfg(feature = "bevy_text")]
+        app.add_plugins(text::TextGizmoPlugin);
+
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
@@ -223,9 +245,31 @@ impl AppGizmoBuilder for App {
         handles.list.insert(TypeId::of::<Config>(), None);
         handles.strip.insert(TypeId::of::<Config>(), None);
 
+        self.world_mut()
+            .get_resource_or_insert_with::<filled::FilledGizmoHandles>(Default::default)
+            .filled
+            .insert(TypeId::of::<Config>(), None);
+
+        #[cfg(feature = "bevy_text")]
+        {
+            self.world_mut()
+                .get_resource_or_insert_with::<text::TextGizmoHandles>(Default::default)
+                .texts
+                .insert(TypeId::of::<Config>(), None);
+
+            // Text is only drawn when the `TextPlugin` is added
+            self.add_systems(
+                Last,
+                text::update_text_gizmos::<Config>
+                    .in_set(UpdateGizmoMeshes)
+                    .run_if(resource_exists::<TextPipeline>),
+            );
+        }
+
         self.init_resource::<GizmoStorage<Config, ()>>()
             .init_resource::<GizmoStorage<Config, Fixed>>()
             .init_resource::<GizmoStorage<Config, Swap<Fixed>>>()
+            .init_resource::<RetainedGizmos<Config>>()
             .add_systems(
                 RunFixedMainLoop,
                 start_gizmo_context::<Config, Fixed>.before(bevy_time::run_fixed_main_schedule),
@@ -240,7 +284,9 @@ impl AppGizmoBuilder for App {
                 Last,
                 (
                     propagate_gizmos::<Config, Fixed>.before(UpdateGizmoMeshes),
+                    retained::draw_retained_gizmos::<Config>.before(UpdateGizmoMeshes),
                     update_gizmo_meshes::<Config>.in_set(UpdateGizmoMeshes),
+                    filled::update_filled_gizmos::<Config>.in_set(UpdateGizmoMeshes),
                 ),
             );
 
@@ -7,7 +7,7 @@ use crate::{
 };
 use bevy_app::{App, Plugin};
 use bevy_asset::Handle;
-use bevy_core_pipeline::core_2d::Transparent2d;
+use bevy_core_pipeline::core_2d::{Sort2dKey, Transparent2d};
 
 use bevy_ecs::{
     prelude::Entity,
@@ -15,7 +15,6 @@ use bevy_ecs::{
     system::{Query, Res, ResMut, Resource},
     world::{FromWorld, World},
 };
-use bevy_math::FloatOrd;
 use bevy_render::{
     render_asset::{prepare_assets, RenderAssets},
     render_phase::{
@@ -273,7 +272,7 @@ fn queue_line_gizmos_2d(
 
         let render_layers = render_layers.unwrap_or_default();
         for (entity, handle, config) in &line_gizmos {
-            if !config.render_layers.intersects(render_layers) {
+            if !config.is_visible_in(view_entity, render_layers) {
                 continue;
             }
 
@@ -295,7 +294,7 @@ fn queue_line_gizmos_2d(
                 entity,
                 draw_function,
                 pipeline,
-                sort_key: FloatOrd(f32::INFINITY),
+                sort_key: Sort2dKey::MAX,
                 batch_range: 0..1,
                 extra_index: PhaseItemExtraIndex::NONE,
             });
@@ -330,7 +329,7 @@ fn queue_line_joint_gizmos_2d(
 
         let render_layers = render_layers.unwrap_or_default();
         for (entity, handle, config) in &line_gizmos {
-            if !config.render_layers.intersects(render_layers) {
+            if !config.is_visible_in(view_entity, render_layers) {
                 continue;
             }
 
@@ -354,7 +353,7 @@ fn queue_line_joint_gizmos_2d(
                 entity,
                 draw_function,
                 pipeline,
-                sort_key: FloatOrd(f32::INFINITY),
+                sort_key: Sort2dKey::MAX,
                 batch_range: 0..1,
                 extra_index: PhaseItemExtraIndex::NONE,
             });
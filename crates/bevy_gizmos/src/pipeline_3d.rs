@@ -331,7 +331,7 @@ fn queue_line_gizmos_3d(
         }
 
         for (entity, handle, config) in &line_gizmos {
-            if !config.render_layers.intersects(render_layers) {
+            if !config.is_visible_in(view_entity, render_layers) {
                 continue;
             }
 
@@ -422,7 +422,7 @@ fn queue_line_joint_gizmos_3d(
         }
 
         for (entity, handle, config) in &line_gizmos {
-            if !config.render_layers.intersects(render_layers) {
+            if !config.is_visible_in(view_entity, render_layers) {
                 continue;
             }
 
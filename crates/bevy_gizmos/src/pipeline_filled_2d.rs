@@ -0,0 +1,180 @@
+use crate::{
+    config::GizmoMeshConfig,
+    filled::{
+        filled_gizmo_vertex_buffer_layout, DrawFilledGizmo, FilledGizmo, GpuFilledGizmo,
+        FILLED_SHADER_HANDLE,
+    },
+    GizmoRenderSystem, LineGizmoUniformBindgroupLayout, SetLineGizmoBindGroup,
+};
+use bevy_app::{App, Plugin};
+use bevy_asset::Handle;
+use bevy_core_pipeline::core_2d::{Sort2dKey, Transparent2d};
+
+use bevy_ecs::{
+    prelude::Entity,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut, Resource},
+    world::{FromWorld, World},
+};
+use bevy_render::{
+    render_asset::{prepare_assets, RenderAssets},
+    render_phase::{
+        AddRenderCommand, DrawFunctions, PhaseItemExtraIndex, SetItemPipeline,
+        ViewSortedRenderPhases,
+    },
+    render_resource::*,
+    texture::BevyDefault,
+    view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+    Render, RenderApp,
+};
+use bevy_sprite::{Mesh2dPipeline, Mesh2dPipelineKey, SetMesh2dViewBindGroup};
+
+pub struct FilledGizmo2dPlugin;
+
+impl Plugin for FilledGizmo2dPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .add_render_command::<Transparent2d, DrawFilledGizmo2d>()
+            .init_resource::<SpecializedRenderPipelines<FilledGizmoPipeline>>()
+            .add_systems(
+                Render,
+                queue_filled_gizmos_2d
+                    .in_set(GizmoRenderSystem::QueueLineGizmos2d)
+                    .after(prepare_assets::<GpuFilledGizmo>),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<FilledGizmoPipeline>();
+    }
+}
+
+#[derive(Clone, Resource)]
+struct FilledGizmoPipeline {
+    mesh_pipeline: Mesh2dPipeline,
+    uniform_layout: BindGroupLayout,
+}
+
+impl FromWorld for FilledGizmoPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        FilledGizmoPipeline {
+            mesh_pipeline: render_world.resource::<Mesh2dPipeline>().clone(),
+            uniform_layout: render_world
+                .resource::<LineGizmoUniformBindgroupLayout>()
+                .layout
+                .clone(),
+        }
+    }
+}
+
+impl SpecializedRenderPipeline for FilledGizmoPipeline {
+    type Key = Mesh2dPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let format = if key.contains(Mesh2dPipelineKey::HDR) {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        let shader_defs = vec![
+            #[cfg(feature = "webgl")]
+            "SIXTEEN_BYTE_ALIGNMENT".into(),
+        ];
+
+        let layout = vec![
+            self.mesh_pipeline.view_layout.clone(),
+            self.uniform_layout.clone(),
+        ];
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: FILLED_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: shader_defs.clone(),
+                buffers: vec![filled_gizmo_vertex_buffer_layout()],
+            },
+            fragment: Some(FragmentState {
+                shader: FILLED_SHADER_HANDLE,
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout,
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState {
+                count: key.msaa_samples(),
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("FilledGizmo Pipeline 2D".into()),
+            push_constant_ranges: vec![],
+        }
+    }
+}
+
+type DrawFilledGizmo2d = (
+    SetItemPipeline,
+    SetMesh2dViewBindGroup<0>,
+    SetLineGizmoBindGroup<1>,
+    DrawFilledGizmo,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn queue_filled_gizmos_2d(
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    pipeline: Res<FilledGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<FilledGizmoPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    filled_gizmos: Query<(Entity, &Handle<FilledGizmo>, &GizmoMeshConfig)>,
+    filled_gizmo_assets: Res<RenderAssets<GpuFilledGizmo>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
+    mut views: Query<(Entity, &ExtractedView, Option<&RenderLayers>)>,
+) {
+    let draw_function = draw_functions.read().get_id::<DrawFilledGizmo2d>().unwrap();
+
+    for (view_entity, view, render_layers) in &mut views {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+
+        let mesh_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
+            | Mesh2dPipelineKey::from_hdr(view.hdr);
+
+        let render_layers = render_layers.unwrap_or_default();
+        for (entity, handle, config) in &filled_gizmos {
+            if !config.is_visible_in(view_entity, render_layers) {
+                continue;
+            }
+
+            if filled_gizmo_assets.get(handle).is_none() {
+                continue;
+            }
+
+            let pipeline = pipelines.specialize(&pipeline_cache, &pipeline, mesh_key);
+
+            transparent_phase.add(Transparent2d {
+                entity,
+                draw_function,
+                pipeline,
+                sort_key: Sort2dKey::MAX,
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::NONE,
+            });
+        }
+    }
+}
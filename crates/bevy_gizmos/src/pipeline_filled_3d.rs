@@ -0,0 +1,235 @@
+use crate::{
+    config::GizmoMeshConfig,
+    filled::{
+        filled_gizmo_vertex_buffer_layout, DrawFilledGizmo, FilledGizmo, GpuFilledGizmo,
+        FILLED_SHADER_HANDLE,
+    },
+    GizmoRenderSystem, LineGizmoUniformBindgroupLayout, SetLineGizmoBindGroup,
+};
+use bevy_app::{App, Plugin};
+use bevy_asset::Handle;
+use bevy_core_pipeline::{
+    core_3d::{Transparent3d, CORE_3D_DEPTH_FORMAT},
+    prepass::{DeferredPrepass, DepthPrepass, MotionVectorPrepass, NormalPrepass},
+};
+
+use bevy_ecs::{
+    prelude::Entity,
+    query::Has,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut, Resource},
+    world::{FromWorld, World},
+};
+use bevy_pbr::{MeshPipeline, MeshPipelineKey, SetMeshViewBindGroup};
+use bevy_render::{
+    render_asset::{prepare_assets, RenderAssets},
+    render_phase::{
+        AddRenderCommand, DrawFunctions, PhaseItemExtraIndex, SetItemPipeline,
+        ViewSortedRenderPhases,
+    },
+    render_resource::*,
+    texture::BevyDefault,
+    view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+    Render, RenderApp,
+};
+
+pub struct FilledGizmo3dPlugin;
+impl Plugin for FilledGizmo3dPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .add_render_command::<Transparent3d, DrawFilledGizmo3d>()
+            .init_resource::<SpecializedRenderPipelines<FilledGizmoPipeline>>()
+            .add_systems(
+                Render,
+                queue_filled_gizmos_3d
+                    .in_set(GizmoRenderSystem::QueueLineGizmos3d)
+                    .after(prepare_assets::<GpuFilledGizmo>),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<FilledGizmoPipeline>();
+    }
+}
+
+#[derive(Clone, Resource)]
+struct FilledGizmoPipeline {
+    mesh_pipeline: MeshPipeline,
+    uniform_layout: BindGroupLayout,
+}
+
+impl FromWorld for FilledGizmoPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        FilledGizmoPipeline {
+            mesh_pipeline: render_world.resource::<MeshPipeline>().clone(),
+            uniform_layout: render_world
+                .resource::<LineGizmoUniformBindgroupLayout>()
+                .layout
+                .clone(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct FilledGizmoPipelineKey {
+    view_key: MeshPipelineKey,
+}
+
+impl SpecializedRenderPipeline for FilledGizmoPipeline {
+    type Key = FilledGizmoPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let shader_defs = vec![
+            #[cfg(feature = "webgl")]
+            "SIXTEEN_BYTE_ALIGNMENT".into(),
+        ];
+
+        let format = if key.view_key.contains(MeshPipelineKey::HDR) {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        let view_layout = self
+            .mesh_pipeline
+            .get_view_layout(key.view_key.into())
+            .clone();
+
+        let layout = vec![view_layout, self.uniform_layout.clone()];
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: FILLED_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: shader_defs.clone(),
+                buffers: vec![filled_gizmo_vertex_buffer_layout()],
+            },
+            fragment: Some(FragmentState {
+                shader: FILLED_SHADER_HANDLE,
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout,
+            primitive: PrimitiveState::default(),
+            depth_stencil: Some(DepthStencilState {
+                format: CORE_3D_DEPTH_FORMAT,
+                // Translucent triangles would hide what is drawn behind them. Like the lines,
+                // they're offset with the depth bias before the depth test
+                depth_write_enabled: false,
+                depth_compare: CompareFunction::Greater,
+                stencil: StencilState::default(),
+                bias: DepthBiasState::default(),
+            }),
+            multisample: MultisampleState {
+                count: key.view_key.msaa_samples(),
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("FilledGizmo Pipeline".into()),
+            push_constant_ranges: vec![],
+        }
+    }
+}
+
+type DrawFilledGizmo3d = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetLineGizmoBindGroup<1>,
+    DrawFilledGizmo,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn queue_filled_gizmos_3d(
+    draw_functions: Res<DrawFunctions<Transparent3d>>,
+    pipeline: Res<FilledGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<FilledGizmoPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    filled_gizmos: Query<(Entity, &Handle<FilledGizmo>, &GizmoMeshConfig)>,
+    filled_gizmo_assets: Res<RenderAssets<GpuFilledGizmo>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent3d>>,
+    mut views: Query<(
+        Entity,
+        &ExtractedView,
+        Option<&RenderLayers>,
+        (
+            Has<NormalPrepass>,
+            Has<DepthPrepass>,
+            Has<MotionVectorPrepass>,
+            Has<DeferredPrepass>,
+        ),
+    )>,
+) {
+    let draw_function = draw_functions.read().get_id::<DrawFilledGizmo3d>().unwrap();
+
+    for (
+        view_entity,
+        view,
+        render_layers,
+        (normal_prepass, depth_prepass, motion_vector_prepass, deferred_prepass),
+    ) in &mut views
+    {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+
+        let render_layers = render_layers.unwrap_or_default();
+
+        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
+
+        if normal_prepass {
+            view_key |= MeshPipelineKey::NORMAL_PREPASS;
+        }
+
+        if depth_prepass {
+            view_key |= MeshPipelineKey::DEPTH_PREPASS;
+        }
+
+        if motion_vector_prepass {
+            view_key |= MeshPipelineKey::MOTION_VECTOR_PREPASS;
+        }
+
+        if deferred_prepass {
+            view_key |= MeshPipelineKey::DEFERRED_PREPASS;
+        }
+
+        for (entity, handle, config) in &filled_gizmos {
+            if !config.is_visible_in(view_entity, render_layers) {
+                continue;
+            }
+
+            if filled_gizmo_assets.get(handle).is_none() {
+                continue;
+            }
+
+            let pipeline = pipelines.specialize(
+                &pipeline_cache,
+                &pipeline,
+                FilledGizmoPipelineKey { view_key },
+            );
+
+            transparent_phase.add(Transparent3d {
+                entity,
+                draw_function,
+                pipeline,
+                distance: 0.,
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::NONE,
+            });
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    config::GizmoMeshConfig,
+    text::{
+        text_gizmo_vertex_buffer_layout, DrawTextGizmo, GpuTextGizmo, SetTextGizmoBindGroup,
+        TextGizmo, TextGizmoBindgroupLayouts, TEXT_SHADER_HANDLE,
+    },
+    GizmoRenderSystem,
+};
+use bevy_app::{App, Plugin};
+use bevy_asset::Handle;
+use bevy_core_pipeline::core_2d::{Sort2dKey, Transparent2d};
+
+use bevy_ecs::{
+    prelude::Entity,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut, Resource},
+    world::{FromWorld, World},
+};
+use bevy_render::{
+    render_asset::{prepare_assets, RenderAssets},
+    render_phase::{
+        AddRenderCommand, DrawFunctions, PhaseItemExtraIndex, SetItemPipeline,
+        ViewSortedRenderPhases,
+    },
+    render_resource::*,
+    texture::BevyDefault,
+    view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+    Render, RenderApp,
+};
+use bevy_sprite::{Mesh2dPipeline, Mesh2dPipelineKey, SetMesh2dViewBindGroup};
+
+pub struct TextGizmo2dPlugin;
+
+impl Plugin for TextGizmo2dPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .add_render_command::<Transparent2d, DrawTextGizmo2d>()
+            .init_resource::<SpecializedRenderPipelines<TextGizmoPipeline>>()
+            .add_systems(
+                Render,
+                queue_text_gizmos_2d
+                    .in_set(GizmoRenderSystem::QueueLineGizmos2d)
+                    .after(prepare_assets::<GpuTextGizmo>),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<TextGizmoPipeline>();
+    }
+}
+
+#[derive(Clone, Resource)]
+struct TextGizmoPipeline {
+    mesh_pipeline: Mesh2dPipeline,
+    uniform_layout: BindGroupLayout,
+    texture_layout: BindGroupLayout,
+}
+
+impl FromWorld for TextGizmoPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let layouts = render_world.resource::<TextGizmoBindgroupLayouts>();
+        TextGizmoPipeline {
+            mesh_pipeline: render_world.resource::<Mesh2dPipeline>().clone(),
+            uniform_layout: layouts.uniform.clone(),
+            texture_layout: layouts.texture.clone(),
+        }
+    }
+}
+
+impl SpecializedRenderPipeline for TextGizmoPipeline {
+    type Key = Mesh2dPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let format = if key.contains(Mesh2dPipelineKey::HDR) {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        let shader_defs = vec![
+            #[cfg(feature = "webgl")]
+            "SIXTEEN_BYTE_ALIGNMENT".into(),
+        ];
+
+        let layout = vec![
+            self.mesh_pipeline.view_layout.clone(),
+            self.uniform_layout.clone(),
+            self.texture_layout.clone(),
+        ];
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: TEXT_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: shader_defs.clone(),
+                buffers: vec![text_gizmo_vertex_buffer_layout()],
+            },
+            fragment: Some(FragmentState {
+                shader: TEXT_SHADER_HANDLE,
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout,
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState {
+                count: key.msaa_samples(),
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("TextGizmo Pipeline 2D".into()),
+            push_constant_ranges: vec![],
+        }
+    }
+}
+
+type DrawTextGizmo2d = (
+    SetItemPipeline,
+    SetMesh2dViewBindGroup<0>,
+    SetTextGizmoBindGroup<1>,
+    DrawTextGizmo<2>,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn queue_text_gizmos_2d(
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    pipeline: Res<TextGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<TextGizmoPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    text_gizmos: Query<(Entity, &Handle<TextGizmo>, &GizmoMeshConfig)>,
+    text_gizmo_assets: Res<RenderAssets<GpuTextGizmo>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
+    mut views: Query<(Entity, &ExtractedView, Option<&RenderLayers>)>,
+) {
+    let draw_function = draw_functions.read().get_id::<DrawTextGizmo2d>().unwrap();
+
+    for (view_entity, view, render_layers) in &mut views {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+
+        let mesh_key = Mesh2dPipelineKey::from_msaa_samples(msaa.samples())
+            | Mesh2dPipelineKey::from_hdr(view.hdr);
+
+        let render_layers = render_layers.unwrap_or_default();
+        for (entity, handle, config) in &text_gizmos {
+            if !config.is_visible_in(view_entity, render_layers) {
+                continue;
+            }
+
+            if text_gizmo_assets.get(handle).is_none() {
+                continue;
+            }
+
+            let pipeline = pipelines.specialize(&pipeline_cache, &pipeline, mesh_key);
+
+            transparent_phase.add(Transparent2d {
+                entity,
+                draw_function,
+                pipeline,
+                sort_key: Sort2dKey::MAX,
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::NONE,
+            });
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    config::GizmoMeshConfig,
+    text::{
+        text_gizmo_vertex_buffer_layout, DrawTextGizmo, GpuTextGizmo, SetTextGizmoBindGroup,
+        TextGizmo, TextGizmoBindgroupLayouts, TEXT_SHADER_HANDLE,
+    },
+    GizmoRenderSystem,
+};
+use bevy_app::{App, Plugin};
+use bevy_asset::Handle;
+use bevy_core_pipeline::{
+    core_3d::{Transparent3d, CORE_3D_DEPTH_FORMAT},
+    prepass::{DeferredPrepass, DepthPrepass, MotionVectorPrepass, NormalPrepass},
+};
+
+use bevy_ecs::{
+    prelude::Entity,
+    query::Has,
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut, Resource},
+    world::{FromWorld, World},
+};
+use bevy_pbr::{MeshPipeline, MeshPipelineKey, SetMeshViewBindGroup};
+use bevy_render::{
+    render_asset::{prepare_assets, RenderAssets},
+    render_phase::{
+        AddRenderCommand, DrawFunctions, PhaseItemExtraIndex, SetItemPipeline,
+        ViewSortedRenderPhases,
+    },
+    render_resource::*,
+    texture::BevyDefault,
+    view::{ExtractedView, Msaa, RenderLayers, ViewTarget},
+    Render, RenderApp,
+};
+
+pub struct TextGizmo3dPlugin;
+impl Plugin for TextGizmo3dPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .add_render_command::<Transparent3d, DrawTextGizmo3d>()
+            .init_resource::<SpecializedRenderPipelines<TextGizmoPipeline>>()
+            .add_systems(
+                Render,
+                queue_text_gizmos_3d
+                    .in_set(GizmoRenderSystem::QueueLineGizmos3d)
+                    .after(prepare_assets::<GpuTextGizmo>),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<TextGizmoPipeline>();
+    }
+}
+
+#[derive(Clone, Resource)]
+struct TextGizmoPipeline {
+    mesh_pipeline: MeshPipeline,
+    uniform_layout: BindGroupLayout,
+    texture_layout: BindGroupLayout,
+}
+
+impl FromWorld for TextGizmoPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let layouts = render_world.resource::<TextGizmoBindgroupLayouts>();
+        TextGizmoPipeline {
+            mesh_pipeline: render_world.resource::<MeshPipeline>().clone(),
+            uniform_layout: layouts.uniform.clone(),
+            texture_layout: layouts.texture.clone(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct TextGizmoPipelineKey {
+    view_key: MeshPipelineKey,
+    occluded: bool,
+}
+
+impl SpecializedRenderPipeline for TextGizmoPipeline {
+    type Key = TextGizmoPipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let shader_defs = vec![
+            #[cfg(feature = "webgl")]
+            "SIXTEEN_BYTE_ALIGNMENT".into(),
+        ];
+
+        let format = if key.view_key.contains(MeshPipelineKey::HDR) {
+            ViewTarget::TEXTURE_FORMAT_HDR
+        } else {
+            TextureFormat::bevy_default()
+        };
+
+        let view_layout = self
+            .mesh_pipeline
+            .get_view_layout(key.view_key.into())
+            .clone();
+
+        let layout = vec![
+            view_layout,
+            self.uniform_layout.clone(),
+            self.texture_layout.clone(),
+        ];
+
+        // Text that isn't occluded is drawn on top of everything
+        let depth_compare = if key.occluded {
+            CompareFunction::Greater
+        } else {
+            CompareFunction::Always
+        };
+
+        RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: TEXT_SHADER_HANDLE,
+                entry_point: "vertex".into(),
+                shader_defs: shader_defs.clone(),
+                buffers: vec![text_gizmo_vertex_buffer_layout()],
+            },
+            fragment: Some(FragmentState {
+                shader: TEXT_SHADER_HANDLE,
+                shader_defs,
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout,
+            primitive: PrimitiveState::default(),
+            depth_stencil: Some(DepthStencilState {
+                format: CORE_3D_DEPTH_FORMAT,
+                // The transparent edges of the glyphs would hide what is drawn behind them
+                depth_write_enabled: false,
+                depth_compare,
+                stencil: StencilState::default(),
+                bias: DepthBiasState::default(),
+            }),
+            multisample: MultisampleState {
+                count: key.view_key.msaa_samples(),
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: Some("TextGizmo Pipeline".into()),
+            push_constant_ranges: vec![],
+        }
+    }
+}
+
+type DrawTextGizmo3d = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetTextGizmoBindGroup<1>,
+    DrawTextGizmo<2>,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn queue_text_gizmos_3d(
+    draw_functions: Res<DrawFunctions<Transparent3d>>,
+    pipeline: Res<TextGizmoPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<TextGizmoPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    msaa: Res<Msaa>,
+    text_gizmos: Query<(Entity, &Handle<TextGizmo>, &GizmoMeshConfig)>,
+    text_gizmo_assets: Res<RenderAssets<GpuTextGizmo>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent3d>>,
+    mut views: Query<(
+        Entity,
+        &ExtractedView,
+        Option<&RenderLayers>,
+        (
+            Has<NormalPrepass>,
+            Has<DepthPrepass>,
+            Has<MotionVectorPrepass>,
+            Has<DeferredPrepass>,
+        ),
+    )>,
+) {
+    let draw_function = draw_functions.read().get_id::<DrawTextGizmo3d>().unwrap();
+
+    for (
+        view_entity,
+        view,
+        render_layers,
+        (normal_prepass, depth_prepass, motion_vector_prepass, deferred_prepass),
+    ) in &mut views
+    {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+
+        let render_layers = render_layers.unwrap_or_default();
+
+        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
+
+        if normal_prepass {
+            view_key |= MeshPipelineKey::NORMAL_PREPASS;
+        }
+
+        if depth_prepass {
+            view_key |= MeshPipelineKey::DEPTH_PREPASS;
+        }
+
+        if motion_vector_prepass {
+            view_key |= MeshPipelineKey::MOTION_VECTOR_PREPASS;
+        }
+
+        if deferred_prepass {
+            view_key |= MeshPipelineKey::DEFERRED_PREPASS;
+        }
+
+        for (entity, handle, config) in &text_gizmos {
+            if !config.is_visible_in(view_entity, render_layers) {
+                continue;
+            }
+
+            if text_gizmo_assets.get(handle).is_none() {
+                continue;
+            }
+
+            let pipeline = pipelines.specialize(
+                &pipeline_cache,
+                &pipeline,
+                TextGizmoPipelineKey {
+                    view_key,
+                    occluded: config.text_occluded,
+                },
+            );
+
+            transparent_phase.add(Transparent3d {
+                entity,
+                draw_function,
+                pipeline,
+                distance: 0.,
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::NONE,
+            });
+        }
+    }
+}
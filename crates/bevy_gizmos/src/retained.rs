@@ -0,0 +1,291 @@
+//! Additional [`Gizmos`] Functions -- Retained
+//!
+//! Includes the implementation of [`Gizmos::line_for`], [`Gizmos::ray_for`] and their 2D
+//! variants, and the [`RetainedGizmos`] storage of the gizmos drawn with [`Gizmos::retain`].
+
+use std::{collections::VecDeque, marker::PhantomData, time::Duration};
+
+use bevy_color::Color;
+use bevy_ecs::system::{Res, ResMut, Resource};
+use bevy_math::{Vec2, Vec3};
+use bevy_time::{Time, Virtual};
+
+use crate::{
+    config::GizmoConfigGroup,
+    gizmos::{GizmoStorage, Gizmos},
+};
+
+impl<'w, 's, Config, Clear> Gizmos<'w, 's, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw a line in 3D from `start` to `end`, which keeps being drawn for `duration`.
+    ///
+    /// See [`Gizmos::retain`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// # use std::time::Duration;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.line_for(Vec3::ZERO, Vec3::X, GREEN, Duration::from_secs(2));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn line_for(
+        &mut self,
+        start: Vec3,
+        end: Vec3,
+        color: impl Into<Color>,
+        duration: Duration,
+    ) {
+        self.retain(duration, |gizmos| gizmos.line(start, end, color));
+    }
+
+    /// Draw a line in 3D from `start` to `start + vector`, which keeps being drawn for `duration`.
+    ///
+    /// See [`Gizmos::retain`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// # use std::time::Duration;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.ray_for(Vec3::Y, Vec3::X, GREEN, Duration::from_secs(2));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn ray_for(
+        &mut self,
+        start: Vec3,
+        vector: Vec3,
+        color: impl Into<Color>,
+        duration: Duration,
+    ) {
+        self.line_for(start, start + vector, color, duration);
+    }
+
+    /// Draw a line in 2D from `start` to `end`, which keeps being drawn for `duration`.
+    ///
+    /// See [`Gizmos::retain`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// # use std::time::Duration;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.line_2d_for(Vec2::ZERO, Vec2::X, GREEN, Duration::from_secs(2));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn line_2d_for(
+        &mut self,
+        start: Vec2,
+        end: Vec2,
+        color: impl Into<Color>,
+        duration: Duration,
+    ) {
+        self.line_for(start.extend(0.), end.extend(0.), color, duration);
+    }
+
+    /// Draw a line in 2D from `start` to `start + vector`, which keeps being drawn for `duration`.
+    ///
+    /// See [`Gizmos::retain`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// # use std::time::Duration;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.ray_2d_for(Vec2::Y, Vec2::X, GREEN, Duration::from_secs(2));
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn ray_2d_for(
+        &mut self,
+        start: Vec2,
+        vector: Vec2,
+        color: impl Into<Color>,
+        duration: Duration,
+    ) {
+        self.line_2d_for(start, start + vector, color, duration);
+    }
+}
+
+/// The gizmos of a [`GizmoConfigGroup`] drawn with [`Gizmos::retain`], which are drawn every frame
+/// until they expire.
+///
+/// At most [`GizmoConfig::max_retained`](crate::config::GizmoConfig::max_retained) retained draws
+/// are kept: the oldest ones are dropped past it.
+#[derive(Resource)]
+pub struct RetainedGizmos<Config> {
+    entries: VecDeque<RetainedGizmo<Config>>,
+    marker: PhantomData<Config>,
+}
+
+/// The gizmos drawn by a call to [`Gizmos::retain`].
+struct RetainedGizmo<Config> {
+    /// The elapsed [`Time<Virtual>`] past which the gizmos aren't drawn anymore.
+    expires_at: Duration,
+    storage: GizmoStorage<Config, ()>,
+}
+
+impl<Config> Default for RetainedGizmos<Config> {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Config: GizmoConfigGroup> RetainedGizmos<Config> {
+    /// The number of retained draws that haven't expired yet.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no retained gizmos.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Stops drawing all the retained gizmos.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Retains the gizmos of `storage` until the elapsed virtual time reaches `expires_at`, and
+    /// drops the oldest retained draws past `max_retained`.
+    pub(crate) fn push(
+        &mut self,
+        expires_at: Duration,
+        storage: GizmoStorage<Config, ()>,
+        max_retained: usize,
+    ) {
+        self.entries.push_back(RetainedGizmo {
+            expires_at,
+            storage,
+        });
+        while self.entries.len() > max_retained {
+            self.entries.pop_front();
+        }
+    }
+}
+
+/// Drops the expired retained gizmos, and adds the others to the gizmos drawn this frame.
+///
+/// The gizmos expire on the [`Time<Virtual>`] clock, so they keep being drawn while it's paused.
+pub(crate) fn draw_retained_gizmos<Config: GizmoConfigGroup>(
+    time: Option<Res<Time<Virtual>>>,
+    mut retained: ResMut<RetainedGizmos<Config>>,
+    mut storage: ResMut<GizmoStorage<Config, ()>>,
+) {
+    if retained.is_empty() {
+        return;
+    }
+    let elapsed = time.map_or(Duration::ZERO, |time| time.elapsed());
+    retained.entries.retain(|entry| entry.expires_at >= elapsed);
+    for entry in &retained.entries {
+        storage.append_storage(&entry.storage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::palettes::basic::RED;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+
+    use super::*;
+    use crate::config::{DefaultGizmoConfigGroup, GizmoConfigStore};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let mut config_store = GizmoConfigStore::default();
+        config_store.register::<DefaultGizmoConfigGroup>();
+        world.insert_resource(config_store);
+        world.init_resource::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+        world.init_resource::<RetainedGizmos<DefaultGizmoConfigGroup>>();
+        world.init_resource::<Time<Virtual>>();
+        world
+    }
+
+    /// Draws the retained gizmos like a frame would, and returns the number of lines drawn.
+    fn draw_frame(world: &mut World) -> usize {
+        world.run_system_once(draw_retained_gizmos::<DefaultGizmoConfigGroup>);
+        let mut storage = world.resource_mut::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+        let lines = storage.list_positions.len() / 2;
+        storage.clear();
+        lines
+    }
+
+    #[test]
+    fn retained_gizmos_expire() {
+        let mut world = setup();
+        world.run_system_once(|mut gizmos: Gizmos| {
+            gizmos.line_for(Vec3::ZERO, Vec3::X, RED, Duration::from_secs(2));
+            gizmos.ray_for(Vec3::ZERO, Vec3::Y, RED, Duration::from_secs(1));
+            gizmos.line(Vec3::ZERO, Vec3::Z, RED);
+        });
+        // The immediate line is only drawn this frame
+        assert_eq!(draw_frame(&mut world), 3);
+        assert_eq!(draw_frame(&mut world), 2);
+
+        let advance = |world: &mut World, millis| {
+            let mut time = world.resource_mut::<Time<Virtual>>();
+            time.advance_by(Duration::from_millis(millis));
+        };
+        advance(&mut world, 1500);
+        assert_eq!(draw_frame(&mut world), 1);
+        advance(&mut world, 1000);
+        assert_eq!(draw_frame(&mut world), 0);
+        assert!(world
+            .resource::<RetainedGizmos<DefaultGizmoConfigGroup>>()
+            .is_empty());
+    }
+
+    #[test]
+    fn oldest_retained_gizmos_are_dropped_past_the_cap() {
+        let mut world = setup();
+        world
+            .resource_mut::<GizmoConfigStore>()
+            .config_mut::<DefaultGizmoConfigGroup>()
+            .0
+            .max_retained = 2;
+        world.run_system_once(|mut gizmos: Gizmos| {
+            for x in 0..3 {
+                let start = Vec3::new(x as f32, 0., 0.);
+                gizmos.line_for(start, start + Vec3::Y, RED, Duration::from_secs(1));
+            }
+        });
+        assert_eq!(
+            world
+                .resource::<RetainedGizmos<DefaultGizmoConfigGroup>>()
+                .len(),
+            2
+        );
+        // The first line was dropped
+        world.run_system_once(draw_retained_gizmos::<DefaultGizmoConfigGroup>);
+        let storage = world.resource::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+        assert_eq!(storage.list_positions[0], Vec3::new(1., 0., 0.));
+        assert_eq!(storage.list_positions[2], Vec3::new(2., 0., 0.));
+
+        world.run_system_once(|mut gizmos: Gizmos| gizmos.clear_retained());
+        assert!(world
+            .resource::<RetainedGizmos<DefaultGizmoConfigGroup>>()
+            .is_empty());
+    }
+}
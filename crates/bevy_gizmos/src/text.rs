@@ -0,0 +1,659 @@
+//! Additional [`Gizmos`] Functions -- Text
+//!
+//! Includes the implementation of [`Gizmos::text`] and [`Gizmos::text_2d`],
+//! and assorted support items.
+
+use std::{any::TypeId, mem, ops::Range};
+
+use bevy_app::{App, Plugin};
+use bevy_asset::{load_internal_asset, Asset, AssetApp, AssetEvent, AssetId, Assets, Handle};
+use bevy_color::{ColorToComponents, LinearRgba};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    event::EventReader,
+    query::{ROQueryItem, With},
+    schedule::IntoSystemConfigs,
+    system::{
+        lifetimeless::{Read, SRes},
+        Commands, Local, Query, Res, ResMut, Resource, SystemParamItem,
+    },
+};
+use bevy_math::{Vec2, Vec3};
+use bevy_reflect::TypePath;
+use bevy_render::{
+    extract_component::{ComponentUniforms, DynamicUniformIndex, UniformComponentPlugin},
+    render_asset::{PrepareAssetError, RenderAsset, RenderAssetPlugin, RenderAssets},
+    render_phase::{PhaseItem, RenderCommand, RenderCommandResult, TrackedRenderPass},
+    render_resource::{
+        binding_types::{sampler, texture_2d, uniform_buffer},
+        BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, Buffer,
+        BufferInitDescriptor, BufferUsages, SamplerBindingType, Shader, ShaderStages, ShaderType,
+        TextureSampleType, VertexBufferLayout, VertexFormat, VertexStepMode,
+    },
+    renderer::RenderDevice,
+    texture::{GpuImage, Image},
+    Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+};
+use bevy_sprite::TextureAtlasLayout;
+use bevy_text::{
+    DefaultFont, Font, FontAtlasSets, GlyphAntialiasing, JustifyText, Text, TextAtlasSettings,
+    TextError, TextLayoutInfo, TextLayoutOptions, TextPipeline, TextSettings, TextStyle,
+    WhiteSpace, YAxisOrientation,
+};
+use bevy_utils::{hashbrown::hash_map::Entry, warn_once, HashMap, TypeIdMap};
+use bevy_window::{PrimaryWindow, Window};
+use bytemuck::{cast_slice, Pod, Zeroable};
+
+use crate::{
+    config::{GizmoConfigGroup, GizmoConfigStore, GizmoMeshConfig, GizmoTextScale},
+    gizmos::{GizmoStorage, Gizmos},
+};
+
+pub(crate) const TEXT_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(2862430318537916047);
+
+/// The maximum number of characters of the text drawn with [`Gizmos::text`] and
+/// [`Gizmos::text_2d`].
+///
+/// Longer text is truncated, so that drawing an unbounded string every frame can't fill the glyph
+/// atlases.
+pub const MAX_GIZMO_TEXT_LENGTH: usize = 256;
+
+impl<'w, 's, Config, Clear> Gizmos<'w, 's, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw text in 3D, centered on `position` and facing the camera.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// The font size of the `style` is in pixels. How the text is scaled with its distance to the
+    /// camera, faded out, and hidden behind geometry is set by the [`GizmoConfig`](crate::config::GizmoConfig)
+    /// of the gizmo group.
+    ///
+    /// Text longer than [`MAX_GIZMO_TEXT_LENGTH`] characters is truncated.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_text::TextStyle;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text(Vec3::Y, "waypoint 3", TextStyle::default());
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn text(&mut self, position: Vec3, text: impl Into<String>, style: TextStyle) {
+        if !self.enabled {
+            return;
+        }
+        let mut value = text.into();
+        if let Some((end, _)) = value.char_indices().nth(MAX_GIZMO_TEXT_LENGTH) {
+            warn_once!(
+                "Gizmo text is truncated to {MAX_GIZMO_TEXT_LENGTH} characters: {:?}.",
+                &value[..end]
+            );
+            value.truncate(end);
+        }
+        self.add_text(GizmoText {
+            position,
+            value,
+            style,
+        });
+    }
+
+    /// Draw text in 2D, centered on `position`.
+    ///
+    /// This should be called for each frame the text needs to be rendered.
+    ///
+    /// The font size of the `style` is in pixels, so the text keeps its size when the camera
+    /// zooms.
+    ///
+    /// Text longer than [`MAX_GIZMO_TEXT_LENGTH`] characters is truncated.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_text::TextStyle;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_2d(Vec2::new(100., 50.), "velocity: 3.2", TextStyle::default());
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn text_2d(&mut self, position: Vec2, text: impl Into<String>, style: TextStyle) {
+        self.text(position.extend(0.), text, style);
+    }
+}
+
+/// Text requested with [`Gizmos::text`].
+#[derive(Clone, Debug)]
+pub(crate) struct GizmoText {
+    pub(crate) position: Vec3,
+    pub(crate) value: String,
+    pub(crate) style: TextStyle,
+}
+
+/// A [`Plugin`] that renders the text drawn with [`Gizmos::text`].
+pub(crate) struct TextGizmoPlugin;
+
+impl Plugin for TextGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(app, TEXT_SHADER_HANDLE, "text.wgsl", Shader::from_wgsl);
+
+        app.add_plugins(UniformComponentPlugin::<TextGizmoUniform>::default())
+            .init_asset::<TextGizmo>()
+            .add_plugins(RenderAssetPlugin::<GpuTextGizmo>::default())
+            .init_resource::<TextGizmoHandles>();
+
+        #[cfg(feature = "bevy_pbr")]
+        if app.is_plugin_added::<bevy_pbr::PbrPlugin>() {
+            app.add_plugins(crate::pipeline_text_3d::TextGizmo3dPlugin);
+        }
+        if app.is_plugin_added::<bevy_sprite::SpritePlugin>() {
+            app.add_plugins(crate::pipeline_text_2d::TextGizmo2dPlugin);
+        }
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .init_resource::<TextGizmoImageBindGroups>()
+            .add_systems(ExtractSchedule, extract_text_gizmo_data)
+            .add_systems(
+                Render,
+                prepare_text_gizmo_bind_groups.in_set(RenderSet::PrepareBindGroups),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        let render_device = render_app.world().resource::<RenderDevice>();
+        let uniform = render_device.create_bind_group_layout(
+            "TextGizmoUniform layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::VERTEX,
+                uniform_buffer::<TextGizmoUniform>(true),
+            ),
+        );
+        let texture = render_device.create_bind_group_layout(
+            "TextGizmo texture layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                ),
+            ),
+        );
+
+        render_app.insert_resource(TextGizmoBindgroupLayouts { uniform, texture });
+    }
+}
+
+/// Holds handles to the text gizmos for each gizmo configuration group
+// See `LineGizmoHandles` for why the handles are optional.
+#[derive(Resource, Default)]
+pub(crate) struct TextGizmoHandles {
+    pub(crate) texts: TypeIdMap<Option<Handle<TextGizmo>>>,
+}
+
+/// The glyphs of the text laid out in the previous frame, so that text drawn every frame isn't laid
+/// out again.
+#[derive(Default)]
+pub(crate) struct TextGizmoCache {
+    entries: HashMap<TextGizmoCacheKey, TextGizmoCacheEntry>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct TextGizmoCacheKey {
+    value: String,
+    /// The font the text is drawn with, resolved from the [`DefaultFont`] for the default handle.
+    font: AssetId<Font>,
+    // The bits of the `f32`s, which aren't `Hash`
+    font_size: u32,
+    font_size_clamp: Option<(u32, u32)>,
+    scale_factor: u32,
+}
+
+struct TextGizmoCacheEntry {
+    glyphs: Vec<TextGizmoGlyph>,
+    /// Whether the text was drawn this frame. The entries that weren't are evicted.
+    used: bool,
+}
+
+/// A glyph quad, relative to the position of the text.
+struct TextGizmoGlyph {
+    image: AssetId<Image>,
+    /// The corners of the quad, in physical pixels.
+    min: Vec2,
+    max: Vec2,
+    uv_min: Vec2,
+    uv_max: Vec2,
+}
+
+impl TextGizmoGlyph {
+    /// Collects the glyph quads of the text laid out in `layout`, centered on the position of the
+    /// text.
+    fn from_layout(
+        layout: &TextLayoutInfo,
+        texture_atlases: &Assets<TextureAtlasLayout>,
+    ) -> Vec<Self> {
+        // The glyphs are positioned in physical pixels from the bottom left corner of the text
+        let center = layout.logical_size / 2.0;
+        layout
+            .glyphs
+            .iter()
+            .filter_map(|glyph| {
+                let atlas = texture_atlases.get(&glyph.atlas_info.texture_atlas)?;
+                let rect = atlas.textures[glyph.atlas_info.glyph_index].as_rect();
+                let min = glyph.position - rect.size() / 2.0 - center;
+                Some(TextGizmoGlyph {
+                    image: glyph.atlas_info.texture.id(),
+                    min,
+                    max: min + rect.size(),
+                    uv_min: rect.min / atlas.size.as_vec2(),
+                    uv_max: rect.max / atlas.size.as_vec2(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Lays out the text of the gizmos and prepares it for rendering.
+///
+/// Text that was already drawn with the same font, font size and scale factor in the previous
+/// frame reuses its layout, until the font is modified or the [`DefaultFont`] changes.
+///
+/// This also clears the text of the default `GizmoStorage`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_text_gizmos<Config: GizmoConfigGroup>(
+    mut cache: Local<TextGizmoCache>,
+    mut text_gizmos: ResMut<Assets<TextGizmo>>,
+    mut handles: ResMut<TextGizmoHandles>,
+    mut storage: ResMut<GizmoStorage<Config, ()>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    fonts: Res<Assets<Font>>,
+    mut font_events: EventReader<AssetEvent<Font>>,
+    default_font: Res<DefaultFont>,
+    text_settings: Res<TextSettings>,
+    text_atlas_settings: Res<TextAtlasSettings>,
+    mut text_pipeline: ResMut<TextPipeline>,
+    mut font_atlas_sets: ResMut<FontAtlasSets>,
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    mut textures: ResMut<Assets<Image>>,
+) {
+    // The font atlases may have been repacked, leaving the cached glyphs pointing to dropped atlases,
+    // and the glyphs of the previous default font may be evicted from its atlases
+    if text_atlas_settings.is_changed() || default_font.is_changed() {
+        cache.entries.clear();
+    }
+    for event in font_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            cache.entries.retain(|key, _| key.font != *id);
+        }
+    }
+
+    // The glyphs are rasterized at the scale factor of the window, so that they stay crisp
+    let scale_factor = windows
+        .get_single()
+        .map_or(1.0, |window| window.scale_factor());
+
+    // The vertices of the glyphs, batched by glyph atlas
+    let mut batches: Vec<(AssetId<Image>, Vec<TextGizmoVertex>)> = Vec::new();
+    for GizmoText {
+        position,
+        value,
+        style,
+    } in mem::take(&mut storage.texts)
+    {
+        let color = LinearRgba::from(style.color).to_f32_array();
+        let key = TextGizmoCacheKey {
+            value,
+            font: default_font.resolve(&style.font).id(),
+            font_size: style.font_size.to_bits(),
+            font_size_clamp: style
+                .font_size_clamp
+                .map(|(min, max)| (min.to_bits(), max.to_bits())),
+            scale_factor: scale_factor.to_bits(),
+        };
+        let entry = match cache.entries.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let text = Text::from_section(entry.key().value.clone(), style)
+                    .with_justify(JustifyText::Center)
+                    .with_no_wrap()
+                    .with_white_space(WhiteSpace::Preserve { tab_width: None });
+                let layout = match text_pipeline.queue_text(
+                    &fonts,
+                    &default_font,
+                    &text,
+                    TextLayoutOptions {
+                        scale_factor,
+                        window_scale_factor: scale_factor,
+                        bounds: Vec2::INFINITY,
+                        max_line_width: f32::INFINITY,
+                        // Gizmo text keeps grayscale antialiasing, whatever the `TextRenderSettings`
+                        antialiasing: GlyphAntialiasing::Grayscale,
+                        y_axis_orientation: YAxisOrientation::BottomToTop,
+                    },
+                    &mut font_atlas_sets,
+                    &mut texture_atlases,
+                    &mut textures,
+                    &text_settings,
+                ) {
+                    Ok(layout) => layout,
+                    // Gizmos are drawn every frame, so the text is drawn once its font is loaded
+                    Err(TextError::NoSuchFont) => continue,
+                    // Gizmo text has no inline images
+                    Err(e @ (TextError::FailedToAddGlyph(_) | TextError::NoSuchImage)) => {
+                        // Gizmos are drawn every frame, so this is only logged once
+                        warn_once!(
+                            "Failed to lay out gizmo text {:?}: {e}.",
+                            text.sections[0].value
+                        );
+                        continue;
+                    }
+                };
+                entry.insert(TextGizmoCacheEntry {
+                    glyphs: TextGizmoGlyph::from_layout(&layout, &texture_atlases),
+                    used: false,
+                })
+            }
+        };
+        entry.used = true;
+
+        for glyph in &entry.glyphs {
+            // The top of the glyph is at the top of its rect in the atlas
+            let vertex = |offset: Vec2, uv: Vec2| TextGizmoVertex {
+                position: position.to_array(),
+                offset: offset.to_array(),
+                uv: uv.to_array(),
+                color,
+            };
+            let bottom_left = vertex(glyph.min, Vec2::new(glyph.uv_min.x, glyph.uv_max.y));
+            let bottom_right = vertex(Vec2::new(glyph.max.x, glyph.min.y), glyph.uv_max);
+            let top_right = vertex(glyph.max, Vec2::new(glyph.uv_max.x, glyph.uv_min.y));
+            let top_left = vertex(Vec2::new(glyph.min.x, glyph.max.y), glyph.uv_min);
+
+            let index = match batches.iter().position(|(id, _)| *id == glyph.image) {
+                Some(index) => index,
+                None => {
+                    batches.push((glyph.image, Vec::new()));
+                    batches.len() - 1
+                }
+            };
+            batches[index].1.extend([
+                bottom_left,
+                bottom_right,
+                top_right,
+                bottom_left,
+                top_right,
+                top_left,
+            ]);
+        }
+    }
+
+    // Only keep the layout of the text drawn this frame, so the cache doesn't grow unbounded
+    cache
+        .entries
+        .retain(|_, entry| mem::replace(&mut entry.used, false));
+
+    if batches.is_empty() {
+        handles.texts.insert(TypeId::of::<Config>(), None);
+        return;
+    }
+
+    let mut text_gizmo = TextGizmo::default();
+    for (image, vertices) in batches {
+        let start = text_gizmo.vertices.len() as u32;
+        text_gizmo.vertices.extend(vertices);
+        let end = text_gizmo.vertices.len() as u32;
+        text_gizmo.batches.push((image, start..end));
+    }
+
+    if let Some(handle) = handles.texts.get_mut(&TypeId::of::<Config>()) {
+        if let Some(handle) = handle {
+            *text_gizmos.get_mut(handle.id()).unwrap() = text_gizmo;
+        } else {
+            *handle = Some(text_gizmos.add(text_gizmo));
+        }
+    }
+}
+
+fn extract_text_gizmo_data(
+    mut commands: Commands,
+    handles: Extract<Res<TextGizmoHandles>>,
+    config: Extract<Res<GizmoConfigStore>>,
+) {
+    for (group_type_id, handle) in handles.texts.iter() {
+        let Some((config, _)) = config.get_config_dyn(group_type_id) else {
+            continue;
+        };
+
+        if !config.enabled {
+            continue;
+        }
+
+        let Some(handle) = handle else {
+            continue;
+        };
+
+        let reference_distance = match config.text_scale {
+            GizmoTextScale::Fixed => 0.0,
+            GizmoTextScale::Perspective(distance) => distance,
+        };
+
+        commands.spawn((
+            TextGizmoUniform {
+                depth_bias: config.depth_bias,
+                max_distance: config.text_max_distance.unwrap_or(f32::MAX),
+                reference_distance,
+                #[cfg(feature = "webgl")]
+                _padding: Default::default(),
+            },
+            (*handle).clone_weak(),
+            GizmoMeshConfig::from(config),
+        ));
+    }
+}
+
+#[derive(Component, ShaderType, Clone, Copy)]
+pub(crate) struct TextGizmoUniform {
+    depth_bias: f32,
+    max_distance: f32,
+    // Zero when the text keeps a fixed size
+    reference_distance: f32,
+    /// WebGL2 structs must be 16 byte aligned.
+    #[cfg(feature = "webgl")]
+    _padding: f32,
+}
+
+/// A corner of a glyph quad.
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub(crate) struct TextGizmoVertex {
+    /// The position of the text, in world space.
+    position: [f32; 3],
+    /// The offset of the corner from the position of the text, in physical pixels.
+    offset: [f32; 2],
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+#[derive(Asset, Debug, Default, Clone, TypePath)]
+pub(crate) struct TextGizmo {
+    vertices: Vec<TextGizmoVertex>,
+    /// The glyph atlas and the range of vertices of each batch, drawn in a single draw call.
+    batches: Vec<(AssetId<Image>, Range<u32>)>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GpuTextGizmo {
+    vertex_buffer: Buffer,
+    batches: Vec<(AssetId<Image>, Range<u32>)>,
+}
+
+impl RenderAsset for GpuTextGizmo {
+    type SourceAsset = TextGizmo;
+    type Param = SRes<RenderDevice>;
+
+    fn prepare_asset(
+        gizmo: Self::SourceAsset,
+        render_device: &mut SystemParamItem<Self::Param>,
+    ) -> Result<Self, PrepareAssetError<Self::SourceAsset>> {
+        let vertex_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            usage: BufferUsages::VERTEX,
+            label: Some("TextGizmo Vertex Buffer"),
+            contents: cast_slice(&gizmo.vertices),
+        });
+
+        Ok(GpuTextGizmo {
+            vertex_buffer,
+            batches: gizmo.batches,
+        })
+    }
+}
+
+#[derive(Resource)]
+pub(crate) struct TextGizmoBindgroupLayouts {
+    pub(crate) uniform: BindGroupLayout,
+    pub(crate) texture: BindGroupLayout,
+}
+
+#[derive(Resource)]
+pub(crate) struct TextGizmoUniformBindgroup {
+    bindgroup: BindGroup,
+}
+
+/// The bind groups of the glyph atlases used by the text gizmos.
+#[derive(Resource, Default)]
+pub(crate) struct TextGizmoImageBindGroups {
+    values: HashMap<AssetId<Image>, BindGroup>,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prepare_text_gizmo_bind_groups(
+    mut commands: Commands,
+    layouts: Res<TextGizmoBindgroupLayouts>,
+    render_device: Res<RenderDevice>,
+    text_gizmo_uniforms: Res<ComponentUniforms<TextGizmoUniform>>,
+    text_gizmos: Query<&Handle<TextGizmo>>,
+    text_gizmo_assets: Res<RenderAssets<GpuTextGizmo>>,
+    gpu_images: Res<RenderAssets<GpuImage>>,
+    mut image_bind_groups: ResMut<TextGizmoImageBindGroups>,
+) {
+    if let Some(binding) = text_gizmo_uniforms.uniforms().binding() {
+        commands.insert_resource(TextGizmoUniformBindgroup {
+            bindgroup: render_device.create_bind_group(
+                "TextGizmoUniform bindgroup",
+                &layouts.uniform,
+                &BindGroupEntries::single(binding),
+            ),
+        });
+    }
+
+    // Adding glyphs to an atlas modifies its image, so the bind groups are created every frame
+    image_bind_groups.values.clear();
+    for handle in &text_gizmos {
+        let Some(text_gizmo) = text_gizmo_assets.get(handle) else {
+            continue;
+        };
+        for (image, _) in &text_gizmo.batches {
+            if image_bind_groups.values.contains_key(image) {
+                continue;
+            }
+            let Some(gpu_image) = gpu_images.get(*image) else {
+                continue;
+            };
+            let bind_group = render_device.create_bind_group(
+                "TextGizmo texture bindgroup",
+                &layouts.texture,
+                &BindGroupEntries::sequential((&gpu_image.texture_view, &gpu_image.sampler)),
+            );
+            image_bind_groups.values.insert(*image, bind_group);
+        }
+    }
+}
+
+pub(crate) struct SetTextGizmoBindGroup<const I: usize>;
+impl<const I: usize, P: PhaseItem> RenderCommand<P> for SetTextGizmoBindGroup<I> {
+    type Param = SRes<TextGizmoUniformBindgroup>;
+    type ViewQuery = ();
+    type ItemQuery = Read<DynamicUniformIndex<TextGizmoUniform>>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        _view: ROQueryItem<'w, Self::ViewQuery>,
+        uniform_index: Option<ROQueryItem<'w, Self::ItemQuery>>,
+        bind_group: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(uniform_index) = uniform_index else {
+            return RenderCommandResult::Failure;
+        };
+        pass.set_bind_group(
+            I,
+            &bind_group.into_inner().bindgroup,
+            &[uniform_index.index()],
+        );
+        RenderCommandResult::Success
+    }
+}
+
+/// Draws the glyphs of a text gizmo, with a draw call per glyph atlas whose texture is bound at
+/// the bind group `I`.
+pub(crate) struct DrawTextGizmo<const I: usize>;
+impl<const I: usize, P: PhaseItem> RenderCommand<P> for DrawTextGizmo<I> {
+    type Param = (
+        SRes<RenderAssets<GpuTextGizmo>>,
+        SRes<TextGizmoImageBindGroups>,
+    );
+    type ViewQuery = ();
+    type ItemQuery = Read<Handle<TextGizmo>>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        _view: ROQueryItem<'w, Self::ViewQuery>,
+        handle: Option<ROQueryItem<'w, Self::ItemQuery>>,
+        (text_gizmos, image_bind_groups): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(handle) = handle else {
+            return RenderCommandResult::Failure;
+        };
+        let Some(text_gizmo) = text_gizmos.into_inner().get(handle) else {
+            return RenderCommandResult::Failure;
+        };
+        let image_bind_groups = image_bind_groups.into_inner();
+
+        pass.set_vertex_buffer(0, text_gizmo.vertex_buffer.slice(..));
+        for (image, vertices) in &text_gizmo.batches {
+            // The glyph atlas isn't on the GPU yet
+            let Some(bind_group) = image_bind_groups.values.get(image) else {
+                continue;
+            };
+            pass.set_bind_group(I, bind_group, &[]);
+            pass.draw(vertices.clone(), 0..1);
+        }
+
+        RenderCommandResult::Success
+    }
+}
+
+pub(crate) fn text_gizmo_vertex_buffer_layout() -> VertexBufferLayout {
+    use VertexFormat::*;
+    VertexBufferLayout::from_vertex_formats(
+        VertexStepMode::Vertex,
+        [Float32x3, Float32x2, Float32x2, Float32x4],
+    )
+}
@@ -0,0 +1,77 @@
+// TODO use common view binding
+#import bevy_render::view::View
+
+@group(0) @binding(0) var<uniform> view: View;
+
+
+struct TextGizmoUniform {
+    depth_bias: f32,
+    max_distance: f32,
+    // Zero when the text keeps a fixed size
+    reference_distance: f32,
+#ifdef SIXTEEN_BYTE_ALIGNMENT
+    // WebGL2 structs must be 16 byte aligned.
+    _padding: f32,
+#endif
+}
+
+@group(1) @binding(0) var<uniform> text_gizmo: TextGizmoUniform;
+
+@group(2) @binding(0) var glyph_texture: texture_2d<f32>;
+@group(2) @binding(1) var glyph_sampler: sampler;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    // The offset of the corner of the glyph from the position, in physical pixels
+    @location(1) offset: vec2<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+const EPSILON: f32 = 4.88e-04;
+
+// The text fades out over this fraction of its maximum distance
+const FADE_FRACTION: f32 = 0.25;
+
+@vertex
+fn vertex(vertex: VertexInput) -> VertexOutput {
+    let clip = view.clip_from_world * vec4(vertex.position, 1.);
+
+    // This differentiates between orthographic and perspective cameras.
+    // With orthographic cameras, the text keeps a fixed size.
+    var scale = 1.;
+    if text_gizmo.reference_distance > 0. && clip.w != 1. {
+        scale = text_gizmo.reference_distance / clip.w;
+    }
+
+    // All the corners share the depth of the position, so the text faces the camera
+    let offset = 2. * vertex.offset * scale / view.viewport.zw;
+
+    var depth: f32;
+    if text_gizmo.depth_bias >= 0. {
+        depth = clip.z * (1. - text_gizmo.depth_bias);
+    } else {
+        // See `lines.wgsl`
+        depth = clip.z * exp2(-text_gizmo.depth_bias * log2(clip.w / clip.z - EPSILON));
+    }
+
+    var color = vertex.color;
+    let distance = length(vertex.position - view.world_position);
+    let fade_distance = text_gizmo.max_distance * FADE_FRACTION;
+    color.a *= saturate((text_gizmo.max_distance - distance) / fade_distance);
+
+    let clip_position = vec4(clip.xy + offset * clip.w, depth, clip.w);
+
+    return VertexOutput(clip_position, vertex.uv, color);
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color * textureSample(glyph_texture, glyph_sampler, in.uv);
+}
@@ -96,7 +96,9 @@
 //! You can use [`GltfAssetLabel`] to ensure you are using the correct label.
 
 #[cfg(feature = "bevy_animation")]
-use bevy_animation::AnimationClip;
+use bevy_animation::{AnimationClip, AnimationTargetId};
+#[cfg(feature = "bevy_animation")]
+use bevy_core::Name;
 use bevy_utils::HashMap;
 
 mod loader;
@@ -195,6 +197,13 @@ pub struct Gltf {
     /// Named animations loaded from the glTF file.
     #[cfg(feature = "bevy_animation")]
     pub named_animations: HashMap<Box<str>, Handle<AnimationClip>>,
+    /// The names of the animatable nodes and of their ancestors, from the root
+    /// of their scene, by the [`AnimationTargetId`] generated from them.
+    ///
+    /// Unnamed nodes are named `GltfNode{index}`. This is used to author
+    /// [`AnimationMask`](bevy_animation::prelude::AnimationMask)s by bone name.
+    #[cfg(feature = "bevy_animation")]
+    pub animation_target_paths: HashMap<AnimationTargetId, Vec<Name>>,
     /// The gltf root of the gltf asset, see <https://docs.rs/gltf/latest/gltf/struct.Gltf.html>. Only has a value when `GltfLoaderSettings::include_source` is true.
     pub source: Option<gltf::Gltf>,
 }
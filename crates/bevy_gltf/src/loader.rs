@@ -325,6 +325,17 @@ async fn load_gltf<'a, 'b, 'c>(
                     );
                 }
             }
+            let extras = animation.extras().as_ref();
+            if let Option::<AnimationMarkers>::Some(markers) =
+                extras.and_then(|extras| serde_json::from_str(extras.get()).ok())
+            {
+                for marker in markers.markers {
+                    animation_clip.add_event(
+                        marker.time,
+                        bevy_animation::AnimationMarker::new(marker.name),
+                    );
+                }
+            }
             let handle = load_context.add_labeled_asset(
                 GltfAssetLabel::Animation(animation.index()).to_string(),
                 animation_clip,
@@ -741,6 +752,11 @@ async fn load_gltf<'a, 'b, 'c>(
         animations,
         #[cfg(feature = "bevy_animation")]
         named_animations,
+        #[cfg(feature = "bevy_animation")]
+        animation_target_paths: paths
+            .into_values()
+            .map(|(_, path)| (AnimationTargetId::from_names(path.iter()), path))
+            .collect(),
         source: if settings.include_source {
             Some(gltf)
         } else {
@@ -1787,6 +1803,21 @@ struct MorphTargetNames {
     pub target_names: Vec<String>,
 }
 
+/// The markers of an animation in its extras, as `{ "markers": [{ "time": 0.5, "name": "footstep" }] }`,
+/// each triggering an [`AnimationMarker`](bevy_animation::AnimationMarker) event.
+#[cfg(feature = "bevy_animation")]
+#[derive(Deserialize)]
+struct AnimationMarkers {
+    pub markers: Vec<AnimationMarkerExtras>,
+}
+
+#[cfg(feature = "bevy_animation")]
+#[derive(Deserialize)]
+struct AnimationMarkerExtras {
+    pub time: f32,
+    pub name: String,
+}
+
 // A helper structure for `load_node` that contains information about the
 // nearest ancestor animation root.
 #[cfg(feature = "bevy_animation")]
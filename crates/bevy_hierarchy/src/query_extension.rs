@@ -55,6 +55,131 @@ pub trait HierarchyQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
     fn iter_ancestors(&'w self, entity: Entity) -> AncestorIter<'w, 's, D, F>
     where
         D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>;
+
+    /// Returns the closest ancestor of `entity` matched by `query`, such as the first ancestor
+    /// with a component `T` using a `Query<(), With<T>>`.
+    ///
+    /// Can only be called on a [`Query`] of [`Parent`] (i.e. `Query<&Parent>`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_hierarchy::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Window;
+    /// # #[derive(Component)]
+    /// # struct Button;
+    /// fn system(
+    ///     buttons: Query<Entity, With<Button>>,
+    ///     parent_query: Query<&Parent>,
+    ///     windows: Query<(), With<Window>>,
+    /// ) {
+    ///     for button in &buttons {
+    ///         if let Some(window) = parent_query.ancestor_with(button, &windows) {
+    ///             // Do something!
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions, panics if the ancestors of `entity` form a cycle, which means that
+    /// the hierarchy has been improperly maintained. Without them, `None` is returned.
+    fn ancestor_with<D2: QueryData, F2: QueryFilter>(
+        &'w self,
+        entity: Entity,
+        query: &Query<D2, F2>,
+    ) -> Option<Entity>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>;
+
+    /// Returns an [`Iterator`] of [`Entity`]s over all of `entity`s descendants, traversing the
+    /// hierarchy depth-first.
+    ///
+    /// Can only be called on a [`Query`] of [`Children`] (i.e. `Query<&Children>`).
+    ///
+    /// The traversal reuses the allocation of `stack`, which can be kept in a
+    /// [`Local`](bevy_ecs::system::Local). Use [`DepthFirstDescendantIter::with_max_depth`] to
+    /// only iterate over the descendants up to a depth.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_hierarchy::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Marker;
+    /// fn system(
+    ///     query: Query<Entity, With<Marker>>,
+    ///     children_query: Query<&Children>,
+    ///     mut stack: Local<DescendantStack>,
+    /// ) {
+    ///     let entity = query.single();
+    ///     // The children and grandchildren of the entity
+    ///     for descendant in children_query
+    ///         .iter_descendants_depth_first(entity, &mut stack)
+    ///         .with_max_depth(2)
+    ///     {
+    ///         // Do something!
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions, the iterator panics if the descendants of `entity` form a cycle,
+    /// which means that the hierarchy has been improperly maintained. Without them, the iteration
+    /// stops.
+    fn iter_descendants_depth_first<'a>(
+        &'w self,
+        entity: Entity,
+        stack: &'a mut DescendantStack,
+    ) -> DepthFirstDescendantIter<'w, 's, 'a, D, F>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Children>;
+
+    /// Returns an [`Iterator`] of [`Entity`]s over the descendants of `entity`, traversing the
+    /// hierarchy depth-first and skipping the descendants for which `prune` returns `true`,
+    /// along with their own descendants.
+    ///
+    /// Can only be called on a [`Query`] of [`Children`] (i.e. `Query<&Children>`).
+    ///
+    /// See [`iter_descendants_depth_first`](Self::iter_descendants_depth_first) for the
+    /// usage of `stack`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_hierarchy::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Marker;
+    /// # #[derive(Component)]
+    /// # struct Hidden;
+    /// fn system(
+    ///     query: Query<Entity, With<Marker>>,
+    ///     children_query: Query<&Children>,
+    ///     hidden: Query<(), With<Hidden>>,
+    ///     mut stack: Local<DescendantStack>,
+    /// ) {
+    ///     let entity = query.single();
+    ///     for descendant in
+    ///         children_query.iter_descendants_pruned(entity, &mut stack, |e| hidden.contains(e))
+    ///     {
+    ///         // Do something!
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn iter_descendants_pruned<'a, P: FnMut(Entity) -> bool>(
+        &'w self,
+        entity: Entity,
+        stack: &'a mut DescendantStack,
+        prune: P,
+    ) -> DepthFirstDescendantIter<'w, 's, 'a, D, F, P>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Children>;
 }
 
 impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Query<'w, 's, D, F> {
@@ -71,6 +196,50 @@ impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Q
     {
         AncestorIter::new(self, entity)
     }
+
+    fn ancestor_with<D2: QueryData, F2: QueryFilter>(
+        &'w self,
+        entity: Entity,
+        query: &Query<D2, F2>,
+    ) -> Option<Entity>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
+    {
+        let mut guard = CycleGuard::default();
+        for ancestor in self.iter_ancestors(entity) {
+            if query.contains(ancestor) {
+                return Some(ancestor);
+            }
+            // An entity has at most as many ancestors as there are entities with a parent
+            if guard.exceeded(|| self.iter().count()) {
+                return None;
+            }
+        }
+        None
+    }
+
+    fn iter_descendants_depth_first<'a>(
+        &'w self,
+        entity: Entity,
+        stack: &'a mut DescendantStack,
+    ) -> DepthFirstDescendantIter<'w, 's, 'a, D, F>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+    {
+        DepthFirstDescendantIter::new(self, entity, stack, |_| false)
+    }
+
+    fn iter_descendants_pruned<'a, P: FnMut(Entity) -> bool>(
+        &'w self,
+        entity: Entity,
+        stack: &'a mut DescendantStack,
+        prune: P,
+    ) -> DepthFirstDescendantIter<'w, 's, 'a, D, F, P>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+    {
+        DepthFirstDescendantIter::new(self, entity, stack, prune)
+    }
 }
 
 /// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
@@ -119,6 +288,132 @@ where
     }
 }
 
+/// The stack of entities left to visit by a [`DepthFirstDescendantIter`], with their depth.
+///
+/// Keep it in a [`Local`](bevy_ecs::system::Local) to reuse its allocation across iterations.
+#[derive(Debug, Default)]
+pub struct DescendantStack(Vec<(Entity, usize)>);
+
+/// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`], skipping the subtrees
+/// of the descendants for which `P` returns `true`.
+///
+/// Traverses the hierarchy depth-first.
+pub struct DepthFirstDescendantIter<
+    'w,
+    's,
+    'a,
+    D: QueryData,
+    F: QueryFilter,
+    P = fn(Entity) -> bool,
+> where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+{
+    children_query: &'w Query<'w, 's, D, F>,
+    stack: &'a mut DescendantStack,
+    prune: P,
+    max_depth: usize,
+    guard: CycleGuard,
+}
+
+impl<'w, 's, 'a, D: QueryData, F: QueryFilter, P: FnMut(Entity) -> bool>
+    DepthFirstDescendantIter<'w, 's, 'a, D, F, P>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+{
+    /// Returns a new [`DepthFirstDescendantIter`], clearing `stack`.
+    pub fn new(
+        children_query: &'w Query<'w, 's, D, F>,
+        entity: Entity,
+        stack: &'a mut DescendantStack,
+        prune: P,
+    ) -> Self {
+        stack.0.clear();
+        if let Ok(children) = children_query.get(entity) {
+            stack
+                .0
+                .extend(children.iter().rev().map(|&child| (child, 1)));
+        }
+        DepthFirstDescendantIter {
+            children_query,
+            stack,
+            prune,
+            max_depth: usize::MAX,
+            guard: CycleGuard::default(),
+        }
+    }
+
+    /// Only iterates over the descendants up to `max_depth`, where the children of the entity
+    /// are at a depth of 1, its grandchildren at a depth of 2, and so on.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self.stack.0.retain(|&(_, depth)| depth <= max_depth);
+        self
+    }
+}
+
+impl<'w, 's, 'a, D: QueryData, F: QueryFilter, P: FnMut(Entity) -> bool> Iterator
+    for DepthFirstDescendantIter<'w, 's, 'a, D, F, P>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (entity, depth) = self.stack.0.pop()?;
+            if (self.prune)(entity) {
+                continue;
+            }
+            // An entity has at most as many descendants as there are children in the hierarchy
+            let children_query = self.children_query;
+            if self
+                .guard
+                .exceeded(|| children_query.iter().map(|children| children.len()).sum())
+            {
+                self.stack.0.clear();
+                return None;
+            }
+
+            if depth < self.max_depth {
+                if let Ok(children) = self.children_query.get(entity) {
+                    self.stack
+                        .0
+                        .extend(children.iter().rev().map(|&child| (child, depth + 1)));
+                }
+            }
+            return Some(entity);
+        }
+    }
+}
+
+/// Detects cycles in malformed hierarchies, by counting the visited entities.
+#[derive(Default)]
+struct CycleGuard {
+    visited: usize,
+    max_visited: Option<usize>,
+}
+
+impl CycleGuard {
+    /// Visiting more entities than this doesn't count them, since counting can be expensive.
+    const UNCHECKED_VISITS: usize = 1024;
+
+    /// Counts a visited entity, returning `true` if more entities were visited than the
+    /// `max_visited` entities that can be visited without a cycle.
+    fn exceeded(&mut self, max_visited: impl FnOnce() -> usize) -> bool {
+        self.visited += 1;
+        if self.visited <= Self::UNCHECKED_VISITS {
+            return false;
+        }
+        let max_visited = *self.max_visited.get_or_insert_with(max_visited);
+        let exceeded = self.visited > max_visited;
+        debug_assert!(
+            !exceeded,
+            "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
+        );
+        exceeded
+    }
+}
+
 /// An [`Iterator`] of [`Entity`]s over the ancestors of an [`Entity`].
 pub struct AncestorIter<'w, 's, D: QueryData, F: QueryFilter>
 where
@@ -156,12 +451,15 @@ where
 #[cfg(test)]
 mod tests {
     use bevy_ecs::{
-        prelude::Component,
+        prelude::{Component, With},
         system::{Query, SystemState},
         world::World,
     };
 
-    use crate::{query_extension::HierarchyQueryExt, BuildWorldChildren, Children, Parent};
+    use crate::{
+        query_extension::{DescendantStack, HierarchyQueryExt},
+        BuildWorldChildren, Children, Parent,
+    };
 
     #[derive(Component, PartialEq, Debug)]
     struct A(usize);
@@ -201,4 +499,72 @@ mod tests {
 
         assert_eq!([&A(1), &A(0)], result.as_slice());
     }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn depth_first_descendant_iter() {
+        let world = &mut World::new();
+
+        let [a, b, c, d, e, f] = std::array::from_fn(|i| world.spawn(A(i)).id());
+
+        world.entity_mut(a).push_children(&[b, c, f]);
+        world.entity_mut(b).push_children(&[d]);
+        world.entity_mut(d).push_children(&[e]);
+        world.entity_mut(c).insert(Marker);
+
+        let mut system_state =
+            SystemState::<(Query<&Children>, Query<(), With<Marker>>, Query<&A>)>::new(world);
+        let (children_query, marker_query, a_query) = system_state.get(world);
+        let mut stack = DescendantStack::default();
+
+        let result: Vec<_> = a_query
+            .iter_many(children_query.iter_descendants_depth_first(a, &mut stack))
+            .collect();
+        assert_eq!([&A(1), &A(3), &A(4), &A(2), &A(5)], result.as_slice());
+
+        let result: Vec<_> = a_query
+            .iter_many(
+                children_query
+                    .iter_descendants_pruned(a, &mut stack, |e| marker_query.contains(e))
+                    .with_max_depth(2),
+            )
+            .collect();
+        assert_eq!([&A(1), &A(3), &A(5)], result.as_slice());
+    }
+
+    #[test]
+    fn ancestor_with() {
+        let world = &mut World::new();
+
+        let [a, b, c] = std::array::from_fn(|i| world.spawn(A(i)).id());
+
+        world.entity_mut(a).push_children(&[b]).insert(Marker);
+        world.entity_mut(b).push_children(&[c]);
+
+        let mut system_state = SystemState::<(Query<&Parent>, Query<(), With<Marker>>)>::new(world);
+        let (parent_query, marker_query) = system_state.get(world);
+
+        assert_eq!(parent_query.ancestor_with(c, &marker_query), Some(a));
+        assert_eq!(parent_query.ancestor_with(a, &marker_query), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed hierarchy")]
+    fn depth_first_descendant_iter_cycle() {
+        let world = &mut World::new();
+
+        let [a, b] = std::array::from_fn(|i| world.spawn(A(i)).id());
+
+        world.entity_mut(a).push_children(&[b]);
+        // Break the hierarchy's invariants
+        world.entity_mut(b).insert(Children::from_entities(&[a]));
+
+        let mut system_state = SystemState::<Query<&Children>>::new(world);
+        let children_query = system_state.get(world);
+        let mut stack = DescendantStack::default();
+
+        for _ in children_query.iter_descendants_depth_first(a, &mut stack) {}
+    }
 }
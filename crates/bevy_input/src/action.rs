@@ -0,0 +1,197 @@
+//! Actions bound to buttons of the keyboard, the mouse or gamepads.
+
+use bevy_ecs::system::{Res, SystemParam};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use std::hash::Hash;
+
+use crate::{
+    gamepad::{GamepadButton, GamepadButtonType, Gamepads},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+    ButtonInput,
+};
+
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A button of the keyboard, the mouse or any connected gamepad.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputButton {
+    /// A key of the keyboard.
+    Keyboard(KeyCode),
+    /// A button of the mouse.
+    Mouse(MouseButton),
+    /// A button of any connected gamepad.
+    Gamepad(GamepadButtonType),
+}
+
+impl From<KeyCode> for InputButton {
+    fn from(key: KeyCode) -> Self {
+        Self::Keyboard(key)
+    }
+}
+
+impl From<MouseButton> for InputButton {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl From<GamepadButtonType> for InputButton {
+    fn from(button: GamepadButtonType) -> Self {
+        Self::Gamepad(button)
+    }
+}
+
+/// An action triggered by any of its [`InputButton`]s, read with [`ActionInput`].
+///
+/// ```
+/// # use bevy_input::{action::InputAction, gamepad::GamepadButtonType, keyboard::KeyCode};
+/// let jump = InputAction::new(KeyCode::Space).or(GamepadButtonType::South);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputAction {
+    /// The buttons triggering the action.
+    pub buttons: Vec<InputButton>,
+}
+
+impl InputAction {
+    /// Creates an action triggered by `button`.
+    pub fn new(button: impl Into<InputButton>) -> Self {
+        Self {
+            buttons: vec![button.into()],
+        }
+    }
+
+    /// Returns this action, also triggered by `button`.
+    pub fn or(mut self, button: impl Into<InputButton>) -> Self {
+        self.buttons.push(button.into());
+        self
+    }
+}
+
+impl From<KeyCode> for InputAction {
+    fn from(key: KeyCode) -> Self {
+        Self::new(key)
+    }
+}
+
+impl From<MouseButton> for InputAction {
+    fn from(button: MouseButton) -> Self {
+        Self::new(button)
+    }
+}
+
+impl From<GamepadButtonType> for InputAction {
+    fn from(button: GamepadButtonType) -> Self {
+        Self::new(button)
+    }
+}
+
+/// Reads the state of [`InputAction`]s from the [`ButtonInput`] of the keyboard, the mouse and gamepads.
+///
+/// Devices whose [`ButtonInput`] resource doesn't exist are ignored.
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<'w, ButtonInput<MouseButton>>>,
+    gamepad_buttons: Option<Res<'w, ButtonInput<GamepadButton>>>,
+    gamepads: Option<Res<'w, Gamepads>>,
+}
+
+impl<'w> ActionInput<'w> {
+    /// Returns `true` if any button of `action` is pressed.
+    pub fn pressed(&self, action: &InputAction) -> bool {
+        self.any(action, ButtonCheck::Pressed)
+    }
+
+    /// Returns `true` if any button of `action` has just been pressed.
+    pub fn just_pressed(&self, action: &InputAction) -> bool {
+        self.any(action, ButtonCheck::JustPressed)
+    }
+
+    /// Returns `true` if any button of `action` has just been released.
+    pub fn just_released(&self, action: &InputAction) -> bool {
+        self.any(action, ButtonCheck::JustReleased)
+    }
+
+    fn any(&self, action: &InputAction, check: ButtonCheck) -> bool {
+        action.buttons.iter().any(|button| match *button {
+            InputButton::Keyboard(key) => check.check(self.keys.as_deref(), key),
+            InputButton::Mouse(button) => check.check(self.mouse_buttons.as_deref(), button),
+            InputButton::Gamepad(button_type) => {
+                self.gamepads.iter().flat_map(|g| g.iter()).any(|gamepad| {
+                    check.check(
+                        self.gamepad_buttons.as_deref(),
+                        GamepadButton::new(gamepad, button_type),
+                    )
+                })
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ButtonCheck {
+    Pressed,
+    JustPressed,
+    JustReleased,
+}
+
+impl ButtonCheck {
+    fn check<T: Copy + Eq + Hash + Send + Sync + 'static>(
+        self,
+        input: Option<&ButtonInput<T>>,
+        button: T,
+    ) -> bool {
+        input.is_some_and(|input| match self {
+            Self::Pressed => input.pressed(button),
+            Self::JustPressed => input.just_pressed(button),
+            Self::JustReleased => input.just_released(button),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::SystemState, world::World};
+
+    #[test]
+    fn action_triggered_by_any_button() {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        let action = InputAction::new(KeyCode::KeyF)
+            .or(MouseButton::Middle)
+            .or(GamepadButtonType::North);
+
+        // Gamepads are ignored, since their resources don't exist
+        let mut state = SystemState::<ActionInput>::new(&mut world);
+        assert!(!state.get(&world).just_pressed(&action));
+
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Middle);
+        let input = state.get(&world);
+        assert!(input.just_pressed(&action));
+        assert!(input.pressed(&action));
+
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        let input = state.get(&world);
+        assert!(!input.just_pressed(&action));
+        assert!(input.pressed(&action));
+    }
+}
@@ -114,6 +114,59 @@ pub struct GamepadInfo {
     ///
     /// For example on Windows the name may be "HID-compliant game controller".
     pub name: String,
+    /// The USB vendor ID of the gamepad, if available.
+    pub vendor_id: Option<u16>,
+    /// The USB product ID of the gamepad, if available.
+    pub product_id: Option<u16>,
+    /// The serial number of the gamepad, if available.
+    ///
+    /// It tells apart gamepads of the same model in their [`GamepadIdentity`].
+    pub serial: Option<String>,
+}
+
+impl GamepadInfo {
+    /// Creates a [`GamepadInfo`] with only a `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            vendor_id: None,
+            product_id: None,
+            serial: None,
+        }
+    }
+
+    /// Returns the [`GamepadIdentity`] of the physical device.
+    pub fn identity(&self) -> GamepadIdentity {
+        GamepadIdentity {
+            // The serial number is enough to tell the device apart
+            name: self.serial.is_none().then(|| self.name.clone()),
+            vendor_id: self.vendor_id,
+            product_id: self.product_id,
+            serial: self.serial.clone(),
+        }
+    }
+}
+
+/// Identifies a physical gamepad across its reconnections, unlike [`Gamepad`] which may change
+/// when it reconnects.
+///
+/// Without a serial number, gamepads of the same model have the same identity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadIdentity {
+    /// The name of the gamepad, when it has no serial number.
+    pub name: Option<String>,
+    /// The USB vendor ID of the gamepad, if available.
+    pub vendor_id: Option<u16>,
+    /// The USB product ID of the gamepad, if available.
+    pub product_id: Option<u16>,
+    /// The serial number of the gamepad, if available.
+    pub serial: Option<String>,
 }
 
 /// A collection of connected [`Gamepad`]s.
@@ -148,6 +201,11 @@ impl Gamepads {
         self.gamepads.get(&gamepad).map(|g| g.name.as_str())
     }
 
+    /// The [`GamepadInfo`] of the gamepad if this one is connected.
+    pub fn info(&self, gamepad: Gamepad) -> Option<&GamepadInfo> {
+        self.gamepads.get(&gamepad)
+    }
+
     /// Registers the `gamepad`, marking it as connected.
     fn register(&mut self, gamepad: Gamepad, info: GamepadInfo) {
         self.gamepads.insert(gamepad, info);
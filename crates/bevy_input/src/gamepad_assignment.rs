@@ -0,0 +1,412 @@
+//! The assignment of gamepads to player slots, kept when they reconnect.
+
+use bevy_ecs::{
+    event::{Event, EventReader, EventWriter},
+    system::{Res, ResMut, Resource, SystemParam},
+};
+use bevy_reflect::Reflect;
+use bevy_utils::HashMap;
+
+use crate::{
+    gamepad::{
+        Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, GamepadConnection,
+        GamepadConnectionEvent, GamepadIdentity,
+    },
+    Axis, ButtonInput,
+};
+
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// The gamepads assigned to the player slots, from `0` for the first player.
+///
+/// A slot remembers the [`GamepadIdentity`] of its gamepad while it is disconnected, so that the
+/// gamepad reclaims it when it reconnects. With [`auto_assign`](Self::auto_assign), the other
+/// gamepads connecting fill the lowest empty slot.
+///
+/// Changes of the assignments are sent as [`GamepadAssignmentEvent`]s. Read the inputs of a
+/// player with [`PlayerGamepads`].
+#[derive(Resource, Debug)]
+pub struct GamepadAssignments {
+    /// Assigns the gamepads connecting to the lowest empty slot, unless their identity
+    /// reclaims another slot.
+    pub auto_assign: bool,
+    /// The number of player slots.
+    pub max_players: usize,
+    slots: Vec<Option<SlotAssignment>>,
+    /// The identities of the connected gamepads, to assign them manually.
+    connected: HashMap<Gamepad, GamepadIdentity>,
+    /// The events of the manual assignments, sent by [`gamepad_assignment_system`].
+    pending_events: Vec<GamepadAssignmentEvent>,
+}
+
+#[derive(Debug, Clone)]
+struct SlotAssignment {
+    identity: GamepadIdentity,
+    /// The gamepad, or `None` while it is disconnected.
+    gamepad: Option<Gamepad>,
+}
+
+impl Default for GamepadAssignments {
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl GamepadAssignments {
+    /// Creates assignments for `max_players` player slots, assigning the gamepads automatically.
+    pub fn new(max_players: usize) -> Self {
+        Self {
+            auto_assign: true,
+            max_players,
+            slots: Vec::new(),
+            connected: HashMap::default(),
+            pending_events: Vec::new(),
+        }
+    }
+
+    /// Returns the connected gamepad assigned to `slot`.
+    pub fn gamepad(&self, slot: usize) -> Option<Gamepad> {
+        self.slots.get(slot)?.as_ref()?.gamepad
+    }
+
+    /// Returns the slot `gamepad` is assigned to.
+    pub fn slot(&self, gamepad: Gamepad) -> Option<usize> {
+        self.slots.iter().position(|assignment| {
+            assignment
+                .as_ref()
+                .is_some_and(|assignment| assignment.gamepad == Some(gamepad))
+        })
+    }
+
+    /// Returns `true` if a gamepad is assigned to `slot`, even if it is disconnected.
+    pub fn is_assigned(&self, slot: usize) -> bool {
+        self.slots.get(slot).is_some_and(Option::is_some)
+    }
+
+    /// Iterates over the slots and their connected gamepad, in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, Gamepad)> + '_ {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, assignment)| Some((slot, assignment.as_ref()?.gamepad?)))
+    }
+
+    /// Assigns the connected `gamepad` to `slot`, removing it from its previous slot and the
+    /// gamepad previously in `slot`.
+    ///
+    /// Returns `false` if `gamepad` isn't connected or `slot` isn't below
+    /// [`max_players`](Self::max_players).
+    pub fn assign(&mut self, slot: usize, gamepad: Gamepad) -> bool {
+        let Some(identity) = self.connected.get(&gamepad).cloned() else {
+            return false;
+        };
+        if slot >= self.max_players {
+            return false;
+        }
+        if let Some(previous_slot) = self.slot(gamepad) {
+            if previous_slot == slot {
+                return true;
+            }
+            self.unassign(previous_slot);
+        }
+        self.unassign(slot);
+        self.set(
+            slot,
+            SlotAssignment {
+                identity,
+                gamepad: Some(gamepad),
+            },
+        );
+        self.pending_events
+            .push(GamepadAssignmentEvent::Assigned { slot, gamepad });
+        true
+    }
+
+    /// Empties `slot`, forgetting its gamepad even if it reconnects.
+    pub fn unassign(&mut self, slot: usize) {
+        if let Some(assignment) = self.slots.get_mut(slot).and_then(Option::take) {
+            self.pending_events
+                .push(GamepadAssignmentEvent::Unassigned {
+                    slot,
+                    gamepad: assignment.gamepad,
+                });
+        }
+    }
+
+    fn set(&mut self, slot: usize, assignment: SlotAssignment) {
+        if self.slots.len() <= slot {
+            self.slots.resize(slot + 1, None);
+        }
+        self.slots[slot] = Some(assignment);
+    }
+
+    /// Assigns a connecting gamepad, returning the event of its assignment if it got a slot.
+    fn connect(
+        &mut self,
+        gamepad: Gamepad,
+        identity: GamepadIdentity,
+    ) -> Option<GamepadAssignmentEvent> {
+        self.connected.insert(gamepad, identity.clone());
+
+        // A gamepad reconnecting reclaims its slot
+        let reclaimed = self
+            .slots
+            .iter_mut()
+            .enumerate()
+            .find_map(|(slot, assignment)| {
+                let assignment = assignment.as_mut()?;
+                (assignment.gamepad.is_none() && assignment.identity == identity)
+                    .then_some((slot, assignment))
+            });
+        if let Some((slot, assignment)) = reclaimed {
+            assignment.gamepad = Some(gamepad);
+            return Some(GamepadAssignmentEvent::Reconnected { slot, gamepad });
+        }
+
+        if !self.auto_assign || self.slot(gamepad).is_some() {
+            return None;
+        }
+        let slot = (0..self.max_players).find(|&slot| !self.is_assigned(slot))?;
+        self.set(
+            slot,
+            SlotAssignment {
+                identity,
+                gamepad: Some(gamepad),
+            },
+        );
+        Some(GamepadAssignmentEvent::Assigned { slot, gamepad })
+    }
+
+    /// Keeps the slot of a disconnecting gamepad, returning the event if it had one.
+    fn disconnect(&mut self, gamepad: Gamepad) -> Option<GamepadAssignmentEvent> {
+        self.connected.remove(&gamepad);
+        let slot = self.slot(gamepad)?;
+        if let Some(assignment) = &mut self.slots[slot] {
+            assignment.gamepad = None;
+        }
+        Some(GamepadAssignmentEvent::Disconnected { slot, gamepad })
+    }
+}
+
+/// A change of the [`GamepadAssignments`].
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadAssignmentEvent {
+    /// A gamepad was assigned to an empty slot.
+    Assigned {
+        /// The slot of the gamepad.
+        slot: usize,
+        /// The gamepad.
+        gamepad: Gamepad,
+    },
+    /// The gamepad of a slot reconnected, as `gamepad`.
+    Reconnected {
+        /// The slot of the gamepad.
+        slot: usize,
+        /// The gamepad, which may differ from the one before it disconnected.
+        gamepad: Gamepad,
+    },
+    /// The gamepad of a slot disconnected. The slot waits for it to reconnect.
+    Disconnected {
+        /// The slot of the gamepad.
+        slot: usize,
+        /// The gamepad.
+        gamepad: Gamepad,
+    },
+    /// A slot was emptied with [`GamepadAssignments::unassign`], or to assign another gamepad.
+    Unassigned {
+        /// The emptied slot.
+        slot: usize,
+        /// The gamepad of the slot, or `None` if it was disconnected.
+        gamepad: Option<Gamepad>,
+    },
+}
+
+/// Updates the [`GamepadAssignments`] from the [`GamepadConnectionEvent`]s, and sends the
+/// [`GamepadAssignmentEvent`]s.
+pub fn gamepad_assignment_system(
+    mut assignments: ResMut<GamepadAssignments>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    mut assignment_events: EventWriter<GamepadAssignmentEvent>,
+) {
+    // The manual assignments happened before the connections of this frame
+    if !assignments.pending_events.is_empty() {
+        assignment_events.send_batch(assignments.pending_events.drain(..));
+    }
+    for connection_event in connection_events.read() {
+        let gamepad = connection_event.gamepad;
+        let event = match &connection_event.connection {
+            GamepadConnection::Connected(info) => assignments.connect(gamepad, info.identity()),
+            GamepadConnection::Disconnected => assignments.disconnect(gamepad),
+        };
+        if let Some(event) = event {
+            assignment_events.send(event);
+        }
+    }
+}
+
+/// Reads the gamepad inputs of the players, from their slot in the [`GamepadAssignments`].
+///
+/// The inputs of an empty slot, or of a slot whose gamepad is disconnected, are released.
+///
+/// ```
+/// # use bevy_input::{gamepad::GamepadButtonType, gamepad_assignment::PlayerGamepads};
+/// fn jump(players: PlayerGamepads) {
+///     if players.just_pressed(0, GamepadButtonType::South) {
+///         // The first player jumps
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct PlayerGamepads<'w> {
+    assignments: Res<'w, GamepadAssignments>,
+    buttons: Res<'w, ButtonInput<GamepadButton>>,
+    axes: Res<'w, Axis<GamepadAxis>>,
+}
+
+impl<'w> PlayerGamepads<'w> {
+    /// Returns the connected gamepad of `slot`.
+    pub fn gamepad(&self, slot: usize) -> Option<Gamepad> {
+        self.assignments.gamepad(slot)
+    }
+
+    /// Returns `true` if `button` of the gamepad of `slot` is pressed.
+    pub fn pressed(&self, slot: usize, button: GamepadButtonType) -> bool {
+        self.button(slot, button)
+            .is_some_and(|button| self.buttons.pressed(button))
+    }
+
+    /// Returns `true` if `button` of the gamepad of `slot` has just been pressed.
+    pub fn just_pressed(&self, slot: usize, button: GamepadButtonType) -> bool {
+        self.button(slot, button)
+            .is_some_and(|button| self.buttons.just_pressed(button))
+    }
+
+    /// Returns `true` if `button` of the gamepad of `slot` has just been released.
+    pub fn just_released(&self, slot: usize, button: GamepadButtonType) -> bool {
+        self.button(slot, button)
+            .is_some_and(|button| self.buttons.just_released(button))
+    }
+
+    /// Returns the value of `axis` of the gamepad of `slot`, or `0` without a gamepad.
+    pub fn axis(&self, slot: usize, axis: GamepadAxisType) -> f32 {
+        self.gamepad(slot)
+            .and_then(|gamepad| self.axes.get(GamepadAxis::new(gamepad, axis)))
+            .unwrap_or(0.0)
+    }
+
+    fn button(&self, slot: usize, button: GamepadButtonType) -> Option<GamepadButton> {
+        Some(GamepadButton::new(self.gamepad(slot)?, button))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gamepad::GamepadInfo;
+    use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+
+    fn connect(world: &mut World, id: usize, name: &str) -> Gamepad {
+        let gamepad = Gamepad::new(id);
+        let info = GamepadInfo::new(name);
+        world.send_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(info),
+        ));
+        gamepad
+    }
+
+    fn disconnect(world: &mut World, gamepad: Gamepad) {
+        world.send_event(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Disconnected,
+        ));
+    }
+
+    fn assignment_events(world: &mut World) -> Vec<GamepadAssignmentEvent> {
+        world.run_system_once(gamepad_assignment_system);
+        let events = world
+            .resource_mut::<Events<GamepadAssignmentEvent>>()
+            .drain()
+            .collect();
+        world
+            .resource_mut::<Events<GamepadConnectionEvent>>()
+            .clear();
+        events
+    }
+
+    #[test]
+    fn reconnecting_gamepad_reclaims_its_slot() {
+        let mut world = World::new();
+        world.init_resource::<GamepadAssignments>();
+        world.init_resource::<Events<GamepadConnectionEvent>>();
+        world.init_resource::<Events<GamepadAssignmentEvent>>();
+
+        let first = connect(&mut world, 0, "First pad");
+        let second = connect(&mut world, 1, "Second pad");
+        assert_eq!(
+            assignment_events(&mut world),
+            [
+                GamepadAssignmentEvent::Assigned {
+                    slot: 0,
+                    gamepad: first
+                },
+                GamepadAssignmentEvent::Assigned {
+                    slot: 1,
+                    gamepad: second
+                },
+            ]
+        );
+
+        // The slot of the first gamepad waits for it, while a new gamepad takes the next slot
+        disconnect(&mut world, first);
+        let third = connect(&mut world, 2, "Third pad");
+        let first_again = connect(&mut world, 3, "First pad");
+        assert_eq!(
+            assignment_events(&mut world),
+            [
+                GamepadAssignmentEvent::Disconnected {
+                    slot: 0,
+                    gamepad: first
+                },
+                GamepadAssignmentEvent::Assigned {
+                    slot: 2,
+                    gamepad: third
+                },
+                GamepadAssignmentEvent::Reconnected {
+                    slot: 0,
+                    gamepad: first_again
+                },
+            ]
+        );
+        let assignments = world.resource::<GamepadAssignments>();
+        assert_eq!(assignments.gamepad(0), Some(first_again));
+        assert_eq!(assignments.slot(third), Some(2));
+
+        // Manual assignments swap out the previous gamepad of the slot
+        world.resource_mut::<GamepadAssignments>().assign(0, third);
+        assert_eq!(
+            assignment_events(&mut world),
+            [
+                GamepadAssignmentEvent::Unassigned {
+                    slot: 2,
+                    gamepad: Some(third)
+                },
+                GamepadAssignmentEvent::Unassigned {
+                    slot: 0,
+                    gamepad: Some(first_again)
+                },
+                GamepadAssignmentEvent::Assigned {
+                    slot: 0,
+                    gamepad: third
+                },
+            ]
+        );
+    }
+}
@@ -11,11 +11,13 @@
 //!
 //! `bevy` currently supports keyboard, mouse, gamepad, and touch inputs.
 
+pub mod action;
 mod axis;
 mod button_input;
 /// Common run conditions
 pub mod common_conditions;
 pub mod gamepad;
+pub mod gamepad_assignment;
 pub mod gestures;
 pub mod keyboard;
 pub mod mouse;
@@ -28,9 +30,11 @@ pub use button_input::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        action::{ActionInput, InputAction},
         gamepad::{
             Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
         },
+        gamepad_assignment::{GamepadAssignments, PlayerGamepads},
         keyboard::KeyCode,
         mouse::MouseButton,
         touch::{TouchInput, Touches},
@@ -52,6 +56,7 @@ use gamepad::{
     GamepadButtonChangedEvent, GamepadButtonInput, GamepadConnectionEvent, GamepadEvent,
     GamepadRumbleRequest, GamepadSettings, Gamepads,
 };
+use gamepad_assignment::{gamepad_assignment_system, GamepadAssignmentEvent, GamepadAssignments};
 
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
@@ -89,8 +94,10 @@ impl Plugin for InputPlugin {
             .add_event::<GamepadAxisChangedEvent>()
             .add_event::<GamepadEvent>()
             .add_event::<GamepadRumbleRequest>()
+            .add_event::<GamepadAssignmentEvent>()
             .init_resource::<GamepadSettings>()
             .init_resource::<Gamepads>()
+            .init_resource::<GamepadAssignments>()
             .init_resource::<ButtonInput<GamepadButton>>()
             .init_resource::<Axis<GamepadAxis>>()
             .init_resource::<Axis<GamepadButton>>()
@@ -105,6 +112,7 @@ impl Plugin for InputPlugin {
                     gamepad_axis_event_system
                         .after(gamepad_event_system)
                         .after(gamepad_connection_system),
+                    gamepad_assignment_system.after(gamepad_connection_system),
                 )
                     .in_set(InputSystem),
             )
@@ -124,7 +132,10 @@ impl Plugin for InputPlugin {
             .register_type::<TouchInput>()
             .register_type::<GamepadEvent>()
             .register_type::<GamepadButtonInput>()
-            .register_type::<GamepadSettings>();
+            .register_type::<GamepadSettings>()
+            .register_type::<gamepad::GamepadIdentity>()
+            .register_type::<GamepadAssignmentEvent>()
+            .register_type::<action::InputAction>();
     }
 }
 
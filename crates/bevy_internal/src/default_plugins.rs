@@ -216,3 +216,94 @@ impl PluginGroup for MinimalPlugins {
         group
     }
 }
+
+/// This plugin group will add the plugins for a *headless* *Bevy* server, which updates at a
+/// fixed tick rate without a window or a renderer:
+/// * [`LogPlugin`](crate::log::LogPlugin)
+/// * [`TaskPoolPlugin`](crate::core::TaskPoolPlugin)
+/// * [`TypeRegistrationPlugin`](crate::core::TypeRegistrationPlugin)
+/// * [`FrameCountPlugin`](crate::core::FrameCountPlugin)
+/// * [`TimePlugin`](crate::time::TimePlugin)
+/// * [`ScheduleRunnerPlugin`](crate::app::ScheduleRunnerPlugin)
+/// * [`TransformPlugin`](crate::transform::TransformPlugin)
+/// * [`HierarchyPlugin`](crate::hierarchy::HierarchyPlugin)
+/// * [`DiagnosticsPlugin`](crate::diagnostic::DiagnosticsPlugin)
+/// * [`TerminalCtrlCHandlerPlugin`](crate::app::TerminalCtrlCHandlerPlugin) - when not targeting `wasm32`
+/// * [`AssetPlugin`](crate::asset::AssetPlugin) - with feature `bevy_asset`
+/// * [`StatesPlugin`](crate::state::app::StatesPlugin) - with feature `bevy_state`
+///
+/// The [`ScheduleRunnerPlugin`](crate::app::ScheduleRunnerPlugin) updates the app
+/// [`tick_rate`](ServerPlugins::tick_rate) times per second, and the timestep of
+/// [`Time<Fixed>`](crate::time::Fixed) is set to match it, so that
+/// [`FixedUpdate`](crate::app::FixedUpdate) runs once per tick.
+///
+/// Unlike [`MinimalPlugins`], the app exits gracefully when the process is interrupted or
+/// terminated, running the rest of its current update – including [`Last`](crate::app::Last) –
+/// before exiting.
+pub struct ServerPlugins {
+    /// The number of ticks per second.
+    ///
+    /// Defaults to 64, the default rate of [`Time<Fixed>`](crate::time::Fixed).
+    pub tick_rate: f64,
+}
+
+impl ServerPlugins {
+    /// Creates a [`ServerPlugins`] ticking `tick_rate` times per second.
+    pub fn with_tick_rate(tick_rate: f64) -> Self {
+        Self { tick_rate }
+    }
+}
+
+impl Default for ServerPlugins {
+    fn default() -> Self {
+        Self { tick_rate: 64.0 }
+    }
+}
+
+impl PluginGroup for ServerPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let timestep = std::time::Duration::from_secs_f64(self.tick_rate.recip());
+        let mut group = PluginGroupBuilder::start::<Self>();
+        group = group
+            .add(bevy_log::LogPlugin::default())
+            .add(bevy_core::TaskPoolPlugin::default())
+            .add(bevy_core::TypeRegistrationPlugin)
+            .add(bevy_core::FrameCountPlugin)
+            .add(bevy_time::TimePlugin)
+            .add(ServerTickRatePlugin { timestep })
+            .add(bevy_app::ScheduleRunnerPlugin::run_loop(timestep))
+            .add(bevy_transform::TransformPlugin)
+            .add(bevy_hierarchy::HierarchyPlugin)
+            .add(bevy_diagnostic::DiagnosticsPlugin);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            group = group.add(bevy_app::TerminalCtrlCHandlerPlugin);
+        }
+
+        #[cfg(feature = "bevy_asset")]
+        {
+            group = group.add(bevy_asset::AssetPlugin::default());
+        }
+
+        #[cfg(feature = "bevy_state")]
+        {
+            group = group.add(bevy_state::app::StatesPlugin);
+        }
+
+        group
+    }
+}
+
+/// Sets the timestep of [`Time<Fixed>`](bevy_time::Fixed) to the tick rate of [`ServerPlugins`].
+struct ServerTickRatePlugin {
+    timestep: std::time::Duration,
+}
+
+impl Plugin for ServerTickRatePlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.insert_resource(bevy_time::Time::<bevy_time::Fixed>::from_duration(
+            self.timestep,
+        ));
+    }
+}
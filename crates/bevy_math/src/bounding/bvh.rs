@@ -0,0 +1,432 @@
+use super::{Aabb3d, BoundingVolume, IntersectsVolume, RayCast3d};
+use crate::Vec3A;
+
+/// The maximum number of items in a leaf of a [`Bvh3d`].
+const MAX_LEAF_SIZE: usize = 4;
+
+/// A bounding volume hierarchy over items with an [`Aabb3d`] each, for broad-phase queries such as
+/// finding the items hit by a ray or overlapping a volume without testing all of them.
+///
+/// The items are usually entities, but can be anything that identifies them. The hierarchy is
+/// built once with [`Bvh3d::new`], and can be refitted with [`Bvh3d::refit`] when the items move.
+/// Refitting is much cheaper than rebuilding, but the queries get slower as the items move further
+/// from where they were when the hierarchy was built, so it should be rebuilt from time to time.
+///
+/// Queries return the items in the same order for the same inputs.
+///
+/// ```
+/// # use bevy_math::bounding::{Aabb3d, Bvh3d, RayCast3d};
+/// # use bevy_math::{Dir3, Vec3};
+/// let bvh = Bvh3d::new([
+///     ("near", Aabb3d::new(Vec3::new(0.0, 0.0, -2.0), Vec3::ONE)),
+///     ("far", Aabb3d::new(Vec3::new(0.0, 0.0, -8.0), Vec3::ONE)),
+///     ("aside", Aabb3d::new(Vec3::new(5.0, 0.0, -5.0), Vec3::ONE)),
+/// ]);
+///
+/// let hits = bvh.cast_ray(&RayCast3d::new(Vec3::ZERO, Dir3::NEG_Z, 100.0));
+/// assert_eq!(hits, vec![(&"near", 1.0), (&"far", 7.0)]);
+///
+/// let overlapping = bvh.intersecting(&Aabb3d::new(Vec3::new(3.0, 0.0, -5.0), Vec3::ONE));
+/// assert_eq!(overlapping, vec![&"aside"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bvh3d<T> {
+    /// The items with their bounding boxes, ordered so that the items of each leaf are contiguous.
+    items: Vec<(T, Aabb3d)>,
+    /// The nodes in depth-first order, so the children of a node always come after it.
+    nodes: Vec<BvhNode>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BvhNode {
+    aabb: Aabb3d,
+    kind: BvhNodeKind,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BvhNodeKind {
+    /// A node with the items in `items[start..end]`.
+    Leaf { start: u32, end: u32 },
+    /// A node whose first child is the next node, and whose second child is `nodes[second]`.
+    Branch { second: u32 },
+}
+
+impl<T> Default for Bvh3d<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            nodes: Vec::new(),
+        }
+    }
+}
+
+impl<T> Bvh3d<T> {
+    /// Builds a hierarchy over the given items and their bounding boxes.
+    ///
+    /// The items are split along the longest axis of their centers until there are only a few left
+    /// in each leaf.
+    pub fn new(items: impl IntoIterator<Item = (T, Aabb3d)>) -> Self {
+        let mut bvh = Self {
+            items: items.into_iter().collect(),
+            nodes: Vec::new(),
+        };
+        if !bvh.items.is_empty() {
+            bvh.nodes.reserve(2 * bvh.items.len() / MAX_LEAF_SIZE + 1);
+            bvh.build(0, bvh.items.len());
+        }
+        bvh
+    }
+
+    /// Builds the subtree for `items[start..end]`, returning the index of its root node.
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let index = self.nodes.len();
+        let aabb = merged_aabb(&self.items[start..end]);
+        self.nodes.push(BvhNode {
+            aabb,
+            kind: BvhNodeKind::Leaf {
+                start: start as u32,
+                end: end as u32,
+            },
+        });
+        if end - start <= MAX_LEAF_SIZE {
+            return index;
+        }
+
+        // Split at the median center along the longest axis of the centers
+        let (min, max) = self.items[start..end].iter().fold(
+            (Vec3A::INFINITY, Vec3A::NEG_INFINITY),
+            |(min, max), (_, aabb)| (min.min(aabb.center()), max.max(aabb.center())),
+        );
+        let extent = max - min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+        let middle = (start + end) / 2;
+        self.items[start..end].select_nth_unstable_by(middle - start, |(_, a), (_, b)| {
+            a.center()[axis].total_cmp(&b.center()[axis])
+        });
+
+        self.build(start, middle);
+        let second = self.build(middle, end);
+        self.nodes[index].kind = BvhNodeKind::Branch {
+            second: second as u32,
+        };
+        index
+    }
+
+    /// Returns the number of items in the hierarchy.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items in the hierarchy.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the bounding box of all the items, or `None` if there are none.
+    pub fn aabb(&self) -> Option<Aabb3d> {
+        self.nodes.first().map(|node| node.aabb)
+    }
+
+    /// Returns an iterator over the items and their bounding boxes, in the order of the leaves.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &Aabb3d)> {
+        self.items.iter().map(|(item, aabb)| (item, aabb))
+    }
+
+    /// Returns the estimated cost of the queries: the sum of the surface areas of the nodes,
+    /// relative to the surface area of the root.
+    ///
+    /// Refitting makes the nodes grow and overlap, which increases the cost. Comparing it to the
+    /// cost of the hierarchy when it was built tells when rebuilding it would pay off.
+    pub fn cost(&self) -> f32 {
+        let Some(root) = self.nodes.first() else {
+            return 0.0;
+        };
+        let area: f32 = self.nodes.iter().map(|node| node.aabb.visible_area()).sum();
+        area / root.aabb.visible_area().max(f32::EPSILON)
+    }
+
+    /// Updates the bounding boxes of the items that moved, and the nodes containing them.
+    ///
+    /// `aabb` is called for each item, and returns its new bounding box, or `None` if it didn't
+    /// change. Returns `true` if any item moved.
+    pub fn refit(&mut self, mut aabb: impl FnMut(&T) -> Option<Aabb3d>) -> bool {
+        let mut changed = false;
+        for (item, item_aabb) in &mut self.items {
+            if let Some(new_aabb) = aabb(item) {
+                *item_aabb = new_aabb;
+                changed = true;
+            }
+        }
+        if !changed {
+            return false;
+        }
+
+        // Children come after their parent, so going backwards updates them first
+        for index in (0..self.nodes.len()).rev() {
+            let aabb = match self.nodes[index].kind {
+                BvhNodeKind::Leaf { start, end } => {
+                    merged_aabb(&self.items[start as usize..end as usize])
+                }
+                BvhNodeKind::Branch { second } => self.nodes[index + 1]
+                    .aabb
+                    .merge(&self.nodes[second as usize].aabb),
+            };
+            self.nodes[index].aabb = aabb;
+        }
+        true
+    }
+
+    /// Visits the items whose bounding box passes `test`, skipping the nodes that don't pass it.
+    fn visit<'a>(
+        &'a self,
+        mut test: impl FnMut(&Aabb3d) -> bool,
+        mut visitor: impl FnMut(&'a T, &'a Aabb3d),
+    ) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !test(&node.aabb) {
+                continue;
+            }
+            match node.kind {
+                BvhNodeKind::Leaf { start, end } => {
+                    for (item, aabb) in &self.items[start as usize..end as usize] {
+                        if test(aabb) {
+                            visitor(item, aabb);
+                        }
+                    }
+                }
+                BvhNodeKind::Branch { second } => {
+                    // Push the second child first so that the first one is visited first
+                    stack.push(second as usize);
+                    stack.push(index + 1);
+                }
+            }
+        }
+    }
+
+    /// Returns the items whose bounding box intersects `volume`, such as another [`Aabb3d`], a
+    /// [`BoundingSphere`](super::BoundingSphere), or a [`RayCast3d`].
+    pub fn intersecting(&self, volume: &impl IntersectsVolume<Aabb3d>) -> Vec<&T> {
+        let mut items = Vec::new();
+        self.visit(|aabb| volume.intersects(aabb), |item, _| items.push(item));
+        items
+    }
+
+    /// Returns the items whose bounding box is hit by `ray`, with the distance along the ray to
+    /// their bounding box, sorted from the nearest to the furthest.
+    ///
+    /// Items whose bounding box contains the origin of the ray are at a distance of zero.
+    pub fn cast_ray(&self, ray: &RayCast3d) -> Vec<(&T, f32)> {
+        let mut hits = Vec::new();
+        self.visit(
+            |aabb| ray.intersects(aabb),
+            |item, aabb| {
+                if let Some(distance) = ray.aabb_intersection_at(aabb) {
+                    hits.push((item, distance));
+                }
+            },
+        );
+        // A stable sort keeps the order of the leaves for hits at the same distance
+        hits.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        hits
+    }
+
+    /// Returns the item whose bounding box is hit first by `ray`, with the distance along the ray
+    /// to its bounding box.
+    pub fn cast_ray_first(&self, ray: &RayCast3d) -> Option<(&T, f32)> {
+        let mut nearest: Option<(&T, f32)> = None;
+        if self.nodes.is_empty() {
+            return nearest;
+        }
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            // Skip the nodes that are further than the nearest hit so far
+            let Some(distance) = ray.aabb_intersection_at(&node.aabb) else {
+                continue;
+            };
+            if nearest.is_some_and(|(_, nearest)| nearest <= distance) {
+                continue;
+            }
+            match node.kind {
+                BvhNodeKind::Leaf { start, end } => {
+                    for (item, aabb) in &self.items[start as usize..end as usize] {
+                        let Some(distance) = ray.aabb_intersection_at(aabb) else {
+                            continue;
+                        };
+                        if nearest.map_or(true, |(_, nearest)| distance < nearest) {
+                            nearest = Some((item, distance));
+                        }
+                    }
+                }
+                BvhNodeKind::Branch { second } => {
+                    stack.push(second as usize);
+                    stack.push(index + 1);
+                }
+            }
+        }
+        nearest
+    }
+}
+
+impl<T> FromIterator<(T, Aabb3d)> for Bvh3d<T> {
+    fn from_iter<I: IntoIterator<Item = (T, Aabb3d)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// The bounding box of all the given bounding boxes.
+fn merged_aabb<T>(items: &[(T, Aabb3d)]) -> Aabb3d {
+    items
+        .iter()
+        .map(|(_, aabb)| *aabb)
+        .reduce(|a, b| a.merge(&b))
+        .unwrap_or(Aabb3d {
+            min: Vec3A::ZERO,
+            max: Vec3A::ZERO,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bounding::BoundingSphere, Dir3, Vec3};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    fn random_aabbs(count: usize, seed: u64) -> Vec<(usize, Aabb3d)> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (0..count)
+            .map(|index| {
+                let center = Vec3::new(
+                    rng.gen_range(-50.0..50.0),
+                    rng.gen_range(-50.0..50.0),
+                    rng.gen_range(-50.0..50.0),
+                );
+                let half_size = Vec3::new(
+                    rng.gen_range(0.1..2.0),
+                    rng.gen_range(0.1..2.0),
+                    rng.gen_range(0.1..2.0),
+                );
+                (index, Aabb3d::new(center, half_size))
+            })
+            .collect()
+    }
+
+    fn sorted(mut items: Vec<&usize>) -> Vec<usize> {
+        items.sort();
+        items.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn queries_match_naive_scan() {
+        let items = random_aabbs(1000, 0);
+        let bvh = Bvh3d::new(items.clone());
+        assert_eq!(bvh.len(), 1000);
+
+        let query = Aabb3d::new(Vec3::new(5.0, -3.0, 10.0), Vec3::splat(12.0));
+        let expected: Vec<_> = items
+            .iter()
+            .filter(|(_, aabb)| query.intersects(aabb))
+            .map(|(index, _)| *index)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(sorted(bvh.intersecting(&query)), expected);
+
+        let sphere = BoundingSphere::new(Vec3::new(-20.0, 10.0, 0.0), 15.0);
+        let expected: Vec<_> = items
+            .iter()
+            .filter(|(_, aabb)| sphere.intersects(aabb))
+            .map(|(index, _)| *index)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(sorted(bvh.intersecting(&sphere)), expected);
+
+        let ray = RayCast3d::new(Vec3::new(-60.0, 0.0, 0.0), Dir3::X, 200.0);
+        let mut expected: Vec<_> = items
+            .iter()
+            .filter_map(|(index, aabb)| Some((*index, ray.aabb_intersection_at(aabb)?)))
+            .collect();
+        expected.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        let hits: Vec<_> = bvh
+            .cast_ray(&ray)
+            .into_iter()
+            .map(|(index, distance)| (*index, distance))
+            .collect();
+        assert!(!hits.is_empty());
+        assert_eq!(hits, expected);
+        assert_eq!(
+            bvh.cast_ray_first(&ray),
+            hits.first()
+                .map(|(index, distance)| (&items[*index].0, *distance))
+        );
+    }
+
+    #[test]
+    fn queries_are_deterministic() {
+        let items = random_aabbs(500, 1);
+        let query = Aabb3d::new(Vec3::ZERO, Vec3::splat(30.0));
+        let first = Bvh3d::new(items.clone());
+        let second = Bvh3d::new(items);
+        assert_eq!(first.intersecting(&query), second.intersecting(&query));
+    }
+
+    #[test]
+    fn refit_follows_moved_items() {
+        let items = random_aabbs(200, 2);
+        let mut bvh = Bvh3d::new(items.clone());
+        let built_cost = bvh.cost();
+        assert!(!bvh.refit(|_| None));
+
+        // Move every other item far away
+        let offset = Vec3A::new(1000.0, 0.0, 0.0);
+        assert!(bvh.refit(|index| {
+            (index % 2 == 0).then(|| Aabb3d {
+                min: items[*index].1.min + offset,
+                max: items[*index].1.max + offset,
+            })
+        }));
+        // The nodes now span both groups, which rebuilding fixes
+        assert!(bvh.cost() > 1.5 * built_cost);
+        let rebuilt = Bvh3d::new(bvh.iter().map(|(index, aabb)| (*index, *aabb)));
+        assert!(rebuilt.cost() < bvh.cost() / 2.0);
+
+        let near = Aabb3d::new(Vec3::ZERO, Vec3::splat(100.0));
+        assert!(bvh.intersecting(&near).iter().all(|index| *index % 2 == 1));
+        let far = Aabb3d::new(Vec3::X * 1000.0, Vec3::splat(100.0));
+        assert_eq!(
+            sorted(bvh.intersecting(&far)),
+            (0..200).step_by(2).collect::<Vec<_>>()
+        );
+        assert!(bvh.aabb().unwrap().max.x > 1000.0);
+    }
+
+    #[test]
+    fn empty_and_overlapping_items() {
+        let empty = Bvh3d::<usize>::new([]);
+        assert!(empty.is_empty());
+        assert!(empty.aabb().is_none());
+        let everything = Aabb3d::new(Vec3::ZERO, Vec3::splat(f32::MAX));
+        assert!(empty.intersecting(&everything).is_empty());
+
+        // Items at the same place still end up in small leaves
+        let stacked = Bvh3d::new((0..100).map(|index| (index, Aabb3d::new(Vec3::ZERO, Vec3::ONE))));
+        assert_eq!(stacked.intersecting(&everything).len(), 100);
+        let ray = RayCast3d::new(Vec3::Z * 5.0, Dir3::NEG_Z, 10.0);
+        assert_eq!(stacked.cast_ray(&ray).len(), 100);
+        assert_eq!(
+            stacked.cast_ray_first(&ray).map(|(_, distance)| distance),
+            Some(4.0)
+        );
+    }
+}
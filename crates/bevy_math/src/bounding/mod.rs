@@ -4,6 +4,8 @@
 //! - [`BoundingVolume`] is a generic abstraction for any bounding volume
 //! - [`IntersectsVolume`] abstracts intersection tests against a [`BoundingVolume`]
 //! - [`Bounded2d`]/[`Bounded3d`] are abstractions for shapes to generate [`BoundingVolume`]s
+//!
+//! [`Bvh3d`] speeds up queries against many [`Aabb3d`]s, such as ray casts and overlap tests.
 
 /// A trait that generalizes different bounding volumes.
 /// Bounding volumes are simplified shapes that are used to get simpler ways to check for
@@ -110,6 +112,8 @@ mod bounded2d;
 pub use bounded2d::*;
 mod bounded3d;
 pub use bounded3d::*;
+mod bvh;
+pub use bvh::*;
 
 mod raycast2d;
 pub use raycast2d::*;
@@ -0,0 +1,351 @@
+//! Easing functions, which shape the progress of an interpolation over time, and the
+//! [`EasingCurve`]s interpolating between two values with them.
+//!
+//! These are the standard easings of [Robert Penner], along with cubic Bézier easings as in CSS,
+//! and steps. They're meant to be shared by animations, UI transitions and tweens, rather than
+//! reimplemented by each of them.
+//!
+//! ```
+//! # use bevy_math::{easing::{ease, EaseFunction}, Vec2};
+//! let position = ease(&Vec2::ZERO, &Vec2::new(10.0, 0.0), 0.5, EaseFunction::CubicInOut);
+//! assert_eq!(position, Vec2::new(5.0, 0.0));
+//! ```
+//!
+//! [Robert Penner]: http://robertpenner.com/easing/
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::{cubic_splines::CubicSegment, StableInterpolate, Vec2};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+/// A function shaping the progress of an interpolation: it maps the time `t`, in `0..=1`, to how
+/// far along the interpolation is.
+///
+/// All the functions start at 0 and end at 1, but the [`Back`](Self::BackIn) and
+/// [`Elastic`](Self::ElasticIn) functions overshoot this range in between.
+///
+/// The `In` functions accelerate from the start, the `Out` functions decelerate to the end, and the
+/// `InOut` functions do both.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+pub enum EaseFunction {
+    /// Constant speed.
+    Linear,
+    /// `t²`
+    QuadraticIn,
+    /// The [`QuadraticIn`](Self::QuadraticIn) function, reversed.
+    QuadraticOut,
+    /// The [`QuadraticIn`](Self::QuadraticIn) function, then the reversed one.
+    QuadraticInOut,
+    /// `t³`
+    CubicIn,
+    /// The [`CubicIn`](Self::CubicIn) function, reversed.
+    CubicOut,
+    /// The [`CubicIn`](Self::CubicIn) function, then the reversed one.
+    CubicInOut,
+    /// `t⁴`
+    QuarticIn,
+    /// The [`QuarticIn`](Self::QuarticIn) function, reversed.
+    QuarticOut,
+    /// The [`QuarticIn`](Self::QuarticIn) function, then the reversed one.
+    QuarticInOut,
+    /// `t⁵`
+    QuinticIn,
+    /// The [`QuinticIn`](Self::QuinticIn) function, reversed.
+    QuinticOut,
+    /// The [`QuinticIn`](Self::QuinticIn) function, then the reversed one.
+    QuinticInOut,
+    /// A quarter of a period of a sine wave.
+    SineIn,
+    /// The [`SineIn`](Self::SineIn) function, reversed.
+    SineOut,
+    /// The [`SineIn`](Self::SineIn) function, then the reversed one.
+    SineInOut,
+    /// A quarter of a circle.
+    CircularIn,
+    /// The [`CircularIn`](Self::CircularIn) function, reversed.
+    CircularOut,
+    /// The [`CircularIn`](Self::CircularIn) function, then the reversed one.
+    CircularInOut,
+    /// `2^(10t - 10)`, which doubles every tenth of the interpolation.
+    ExponentialIn,
+    /// The [`ExponentialIn`](Self::ExponentialIn) function, reversed.
+    ExponentialOut,
+    /// The [`ExponentialIn`](Self::ExponentialIn) function, then the reversed one.
+    ExponentialInOut,
+    /// A spring oscillating with a growing amplitude before being released.
+    ElasticIn,
+    /// The [`ElasticIn`](Self::ElasticIn) function, reversed.
+    ElasticOut,
+    /// The [`ElasticIn`](Self::ElasticIn) function, then the reversed one.
+    ElasticInOut,
+    /// Backing up below 0 before accelerating, like winding up.
+    BackIn,
+    /// The [`BackIn`](Self::BackIn) function, reversed.
+    BackOut,
+    /// The [`BackIn`](Self::BackIn) function, then the reversed one.
+    BackInOut,
+    /// Bouncing with a growing height, like a ball bouncing in reverse.
+    BounceIn,
+    /// The [`BounceIn`](Self::BounceIn) function, reversed.
+    BounceOut,
+    /// The [`BounceIn`](Self::BounceIn) function, then the reversed one.
+    BounceInOut,
+    /// Jumping in `n` equal steps, at the end of each step like the CSS `steps(n)`. The last step
+    /// is only reached at `t = 1`.
+    Steps(usize),
+    /// A cubic Bézier easing, such as those of CSS. See [`CubicSegment::new_bezier`].
+    CubicBezier(CubicSegment<Vec2>),
+}
+
+impl EaseFunction {
+    /// A cubic Bézier easing with the control points `p1` and `p2`, such as `(0.42, 0.0)` and
+    /// `(0.58, 1.0)` for the CSS `ease-in-out`.
+    pub fn cubic_bezier(p1: impl Into<Vec2>, p2: impl Into<Vec2>) -> Self {
+        EaseFunction::CubicBezier(CubicSegment::new_bezier(p1, p2))
+    }
+
+    /// The progress of the interpolation at the time `t`, which is clamped to `0..=1`.
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            EaseFunction::Linear => t,
+            EaseFunction::QuadraticIn => t * t,
+            EaseFunction::QuadraticOut => out(t, |t| t * t),
+            EaseFunction::QuadraticInOut => in_out(t, |t| t * t),
+            EaseFunction::CubicIn => t.powi(3),
+            EaseFunction::CubicOut => out(t, |t| t.powi(3)),
+            EaseFunction::CubicInOut => in_out(t, |t| t.powi(3)),
+            EaseFunction::QuarticIn => t.powi(4),
+            EaseFunction::QuarticOut => out(t, |t| t.powi(4)),
+            EaseFunction::QuarticInOut => in_out(t, |t| t.powi(4)),
+            EaseFunction::QuinticIn => t.powi(5),
+            EaseFunction::QuinticOut => out(t, |t| t.powi(5)),
+            EaseFunction::QuinticInOut => in_out(t, |t| t.powi(5)),
+            EaseFunction::SineIn => sine_in(t),
+            EaseFunction::SineOut => out(t, sine_in),
+            EaseFunction::SineInOut => in_out(t, sine_in),
+            EaseFunction::CircularIn => circular_in(t),
+            EaseFunction::CircularOut => out(t, circular_in),
+            EaseFunction::CircularInOut => in_out(t, circular_in),
+            EaseFunction::ExponentialIn => exponential_in(t),
+            EaseFunction::ExponentialOut => out(t, exponential_in),
+            EaseFunction::ExponentialInOut => in_out(t, exponential_in),
+            EaseFunction::ElasticIn => elastic_in(t),
+            EaseFunction::ElasticOut => out(t, elastic_in),
+            EaseFunction::ElasticInOut => in_out(t, elastic_in),
+            EaseFunction::BackIn => back_in(t),
+            EaseFunction::BackOut => out(t, back_in),
+            EaseFunction::BackInOut => in_out(t, back_in),
+            EaseFunction::BounceIn => out(t, bounce_out),
+            EaseFunction::BounceOut => bounce_out(t),
+            EaseFunction::BounceInOut => in_out(t, |t| out(t, bounce_out)),
+            EaseFunction::Steps(0) => t,
+            EaseFunction::Steps(steps) => (t * steps as f32).floor() / steps as f32,
+            EaseFunction::CubicBezier(ref segment) => segment.ease(t),
+        }
+    }
+}
+
+/// The `In` function `f`, reversed.
+#[inline]
+fn out(t: f32, f: impl Fn(f32) -> f32) -> f32 {
+    1.0 - f(1.0 - t)
+}
+
+/// The `In` function `f` over the first half, then reversed over the second half.
+#[inline]
+fn in_out(t: f32, f: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        f(2.0 * t) / 2.0
+    } else {
+        1.0 - f(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+fn sine_in(t: f32) -> f32 {
+    1.0 - (t * FRAC_PI_2).cos()
+}
+
+fn circular_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).max(0.0).sqrt()
+}
+
+fn exponential_in(t: f32) -> f32 {
+    // Exactly 0 at the start, rather than 2^-10
+    if t <= 0.0 {
+        0.0
+    } else {
+        2f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    -(2f32.powf(10.0 * t - 10.0)) * ((10.0 * t - 10.75) * TAU / 3.0).sin()
+}
+
+fn back_in(t: f32) -> f32 {
+    // The overshoot of 10% of Penner's easings
+    const C1: f32 = 1.70158;
+    (C1 + 1.0) * t.powi(3) - C1 * t * t
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// Interpolates from `start` to `end` at the time `t`, in `0..=1`, following the easing
+/// `function`.
+///
+/// The values are interpolated with [`StableInterpolate`]: vectors are interpolated linearly, and
+/// rotations such as [`Quat`](crate::Quat) are slerped.
+pub fn ease<T: StableInterpolate>(start: &T, end: &T, t: f32, function: EaseFunction) -> T {
+    start.interpolate_stable(end, function.sample(t))
+}
+
+/// A curve interpolating from a start value to an end value over `0..=1`, following an
+/// [`EaseFunction`].
+///
+/// ```
+/// # use bevy_math::{easing::{EaseFunction, EasingCurve}, Vec3};
+/// let curve = EasingCurve::new(Vec3::ZERO, Vec3::ONE, EaseFunction::QuadraticIn);
+/// assert_eq!(curve.sample(0.5), Vec3::splat(0.25));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct EasingCurve<T> {
+    /// The value at the start of the curve.
+    pub start: T,
+    /// The value at the end of the curve.
+    pub end: T,
+    /// The easing from the start to the end.
+    pub function: EaseFunction,
+}
+
+impl<T: StableInterpolate> EasingCurve<T> {
+    /// Creates a curve easing from `start` to `end` with `function`.
+    pub fn new(start: T, end: T, function: EaseFunction) -> Self {
+        Self {
+            start,
+            end,
+            function,
+        }
+    }
+
+    /// The value of the curve at the time `t`, which is clamped to `0..=1`.
+    pub fn sample(&self, t: f32) -> T {
+        ease(&self.start, &self.end, t, self.function)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Quat;
+    use approx::assert_relative_eq;
+    use std::f32::consts::PI;
+
+    /// The functions, with their value at `t = 0.5`, and whether they're monotonic.
+    fn functions() -> Vec<(EaseFunction, f32, bool)> {
+        use EaseFunction::*;
+        vec![
+            (Linear, 0.5, true),
+            (QuadraticIn, 0.25, true),
+            (QuadraticOut, 0.75, true),
+            (QuadraticInOut, 0.5, true),
+            (CubicIn, 0.125, true),
+            (CubicOut, 0.875, true),
+            (CubicInOut, 0.5, true),
+            (QuarticIn, 0.0625, true),
+            (QuarticOut, 0.9375, true),
+            (QuarticInOut, 0.5, true),
+            (QuinticIn, 0.03125, true),
+            (QuinticOut, 0.96875, true),
+            (QuinticInOut, 0.5, true),
+            (SineIn, 1.0 - (PI / 4.0).cos(), true),
+            (SineOut, (PI / 4.0).sin(), true),
+            (SineInOut, 0.5, true),
+            (CircularIn, 1.0 - 0.75f32.sqrt(), true),
+            (CircularOut, 0.75f32.sqrt(), true),
+            (CircularInOut, 0.5, true),
+            (ExponentialIn, 0.03125, true),
+            (ExponentialOut, 0.96875, true),
+            (ExponentialInOut, 0.5, true),
+            (ElasticIn, -0.015625, false),
+            (ElasticOut, 1.015625, false),
+            (ElasticInOut, 0.5, false),
+            (BackIn, -0.0876975, false),
+            (BackOut, 1.0876975, false),
+            (BackInOut, 0.5, false),
+            (BounceIn, 0.234375, false),
+            (BounceOut, 0.765625, false),
+            (BounceInOut, 0.5, false),
+            (Steps(4), 0.5, true),
+            (Steps(3), 1.0 / 3.0, true),
+            (
+                EaseFunction::cubic_bezier((0.42, 0.0), (0.58, 1.0)),
+                0.5,
+                true,
+            ),
+        ]
+    }
+
+    #[test]
+    fn golden_values() {
+        for (function, halfway, _) in functions() {
+            assert_relative_eq!(function.sample(0.0), 0.0, epsilon = 1e-5);
+            assert_relative_eq!(function.sample(0.5), halfway, epsilon = 1e-5);
+            assert_relative_eq!(function.sample(1.0), 1.0, epsilon = 1e-5);
+            // Clamped outside of the curve
+            assert_eq!(function.sample(-1.0), function.sample(0.0), "{function:?}");
+            assert_eq!(function.sample(2.0), function.sample(1.0), "{function:?}");
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        for (function, _, monotonic) in functions() {
+            if !monotonic {
+                continue;
+            }
+            let mut last = function.sample(0.0);
+            for i in 1..=1000 {
+                let value = function.sample(i as f32 / 1000.0);
+                assert!(value >= last - 1e-6, "{function:?} decreases at {i}");
+                last = value;
+            }
+        }
+    }
+
+    #[test]
+    fn ease_values() {
+        let rotation = ease(
+            &Quat::IDENTITY,
+            &Quat::from_rotation_z(PI),
+            0.5,
+            EaseFunction::SineInOut,
+        );
+        assert!(rotation.abs_diff_eq(Quat::from_rotation_z(FRAC_PI_2), 1e-5));
+
+        let curve = EasingCurve::new(Vec2::ZERO, Vec2::new(4.0, 8.0), EaseFunction::Steps(4));
+        assert_eq!(curve.sample(0.3), Vec2::new(1.0, 2.0));
+    }
+}
@@ -18,6 +18,7 @@ mod common_traits;
 mod compass;
 pub mod cubic_splines;
 mod direction;
+pub mod easing;
 mod float_ord;
 pub mod primitives;
 mod ray;
@@ -51,10 +52,11 @@ pub mod prelude {
             RationalGenerator, RationalSegment,
         },
         direction::{Dir2, Dir3, Dir3A},
+        easing::{EaseFunction, EasingCurve},
         primitives::*,
         BVec2, BVec3, BVec4, EulerRot, FloatExt, IRect, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4,
-        Quat, Ray2d, Ray3d, Rect, Rot2, StableInterpolate, URect, UVec2, UVec3, UVec4, Vec2,
-        Vec2Swizzles, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles,
+        Quat, Ray2d, Ray3d, Rect, RectRounding, Rot2, StableInterpolate, URect, UVec2, UVec3,
+        UVec4, Vec2, Vec2Swizzles, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles,
     };
 }
 
@@ -0,0 +1,1120 @@
+//! Closest points and signed distances of the primitive shapes, for proximity queries without a
+//! physics engine.
+
+use super::*;
+use crate::{NormedVectorSpace, Quat, Vec2, Vec3, Vec3Swizzles};
+use std::f32::consts::PI;
+
+/// A trait for finding the closest point of a 2D shape to a point, and the signed distance of the
+/// point to the shape, centered on the origin like the shape.
+///
+/// ```
+/// # use bevy_math::{primitives::{Circle, SignedDistance2d}, Vec2};
+/// let circle = Circle::new(1.0);
+/// assert_eq!(circle.signed_distance(Vec2::new(3.0, 0.0)), 2.0);
+/// assert_eq!(circle.signed_distance(Vec2::new(0.0, 0.25)), -0.75);
+/// assert_eq!(circle.closest_point(Vec2::new(3.0, 0.0)), Vec2::X);
+/// ```
+pub trait SignedDistance2d {
+    /// Finds the point of the shape that is closest to `point`.
+    ///
+    /// If `point` is inside the shape, it is returned as is. Otherwise, the returned point is on
+    /// the boundary of the shape.
+    fn closest_point(&self, point: Vec2) -> Vec2;
+
+    /// Returns the distance from `point` to the boundary of the shape: positive outside the shape,
+    /// and negative inside it.
+    ///
+    /// Shapes without an interior, such as segments, are never negative.
+    fn signed_distance(&self, point: Vec2) -> f32;
+
+    /// Returns the distance from `point` to the shape, which is zero inside it.
+    fn distance(&self, point: Vec2) -> f32 {
+        self.signed_distance(point).max(0.0)
+    }
+}
+
+/// A trait for finding the closest point of a 3D shape to a point, and the signed distance of the
+/// point to the shape, centered on the origin like the shape.
+///
+/// Shapes like [`Cylinder`], [`Capsule3d`], [`Cone`] and [`ConicalFrustum`] are oriented along
+/// the y-axis, and [`Torus`] lies in the xz-plane.
+pub trait SignedDistance3d {
+    /// Finds the point of the shape that is closest to `point`.
+    ///
+    /// If `point` is inside the shape, it is returned as is. Otherwise, the returned point is on
+    /// the boundary of the shape.
+    fn closest_point(&self, point: Vec3) -> Vec3;
+
+    /// Returns the distance from `point` to the boundary of the shape: positive outside the shape,
+    /// and negative inside it.
+    ///
+    /// Shapes without an interior, such as segments and triangles, are never negative.
+    fn signed_distance(&self, point: Vec3) -> f32;
+
+    /// Returns the distance from `point` to the shape, which is zero inside it.
+    fn distance(&self, point: Vec3) -> f32 {
+        self.signed_distance(point).max(0.0)
+    }
+}
+
+/// The point of the segment from `a` to `b` that is closest to `point`.
+fn closest_point_on_segment_2d(a: Vec2, b: Vec2, point: Vec2) -> Vec2 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+    a + ab * ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0)
+}
+
+/// The point of the segment from `a` to `b` that is closest to `point`.
+fn closest_point_on_segment_3d(a: Vec3, b: Vec3, point: Vec3) -> Vec3 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+    a + ab * ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0)
+}
+
+/// The point of the polyline through `vertices` that is closest to `point`, given the function
+/// finding the closest point of a segment. A `closed` polyline goes back to its first vertex.
+fn closest_point_on_polyline<P: NormedVectorSpace>(
+    vertices: &[P],
+    closed: bool,
+    point: P,
+    closest_point_on_segment: fn(P, P, P) -> P,
+) -> P {
+    let edge_count = if closed {
+        vertices.len()
+    } else {
+        vertices.len().saturating_sub(1)
+    };
+    let mut closest = vertices.first().copied().unwrap_or(P::ZERO);
+    let mut distance_squared = (closest - point).norm_squared();
+    for index in 0..edge_count {
+        let a = vertices[index];
+        let b = vertices[(index + 1) % vertices.len()];
+        let candidate = closest_point_on_segment(a, b, point);
+        let candidate_distance_squared = (candidate - point).norm_squared();
+        if candidate_distance_squared < distance_squared {
+            closest = candidate;
+            distance_squared = candidate_distance_squared;
+        }
+    }
+    closest
+}
+
+/// Returns `true` if `point` is inside the polygon with `vertices`, which may be concave or
+/// self-intersecting, following the even-odd rule.
+pub(crate) fn polygon_contains(vertices: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    for (index, &a) in vertices.iter().enumerate() {
+        let b = vertices[(index + 1) % vertices.len()];
+        // Count the edges crossed by the ray from the point towards +x
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// The closest point and the signed distance of `point` to the polygon with `vertices`, which
+/// may be concave.
+fn polygon_distance(vertices: &[Vec2], point: Vec2) -> (Vec2, f32) {
+    let boundary = closest_point_on_polyline(vertices, true, point, closest_point_on_segment_2d);
+    if polygon_contains(vertices, point) {
+        (point, -boundary.distance(point))
+    } else {
+        (boundary, boundary.distance(point))
+    }
+}
+
+/// The closest point and the signed distance of `point` to the convex polygon with `vertices`,
+/// in either winding order.
+///
+/// A polygon without area has no interior, so its distance is never negative.
+fn convex_polygon_distance(vertices: &[Vec2], point: Vec2) -> (Vec2, f32) {
+    let mut closest = vertices[0];
+    let mut distance_squared = f32::INFINITY;
+    let mut double_area = 0.0;
+    let (mut left_of_edge, mut right_of_edge) = (false, false);
+    for (index, &a) in vertices.iter().enumerate() {
+        let b = vertices[(index + 1) % vertices.len()];
+        let candidate = closest_point_on_segment_2d(a, b, point);
+        let candidate_distance_squared = candidate.distance_squared(point);
+        if candidate_distance_squared < distance_squared {
+            closest = candidate;
+            distance_squared = candidate_distance_squared;
+        }
+        let side = (b - a).perp_dot(point - a);
+        left_of_edge |= side > 0.0;
+        right_of_edge |= side < 0.0;
+        double_area += a.perp_dot(b);
+    }
+
+    // Inside the polygon, the point is on the same side of all the edges
+    if double_area != 0.0 && !(left_of_edge && right_of_edge) {
+        (point, -distance_squared.sqrt())
+    } else {
+        (closest, distance_squared.sqrt())
+    }
+}
+
+/// The closest point and the signed distance of `point` to the solid of revolution around the
+/// y-axis of the convex polygon `cross_section`, symmetric around the y-axis.
+fn revolved_polygon_distance(cross_section: &[Vec2], point: Vec3) -> (Vec3, f32) {
+    let radial = point.xz();
+    let radial_length = radial.length();
+    let (closest, signed_distance) =
+        convex_polygon_distance(cross_section, Vec2::new(radial_length, point.y));
+    // Points on the axis can be moved in any direction away from it
+    let direction = if radial_length > 0.0 {
+        radial / radial_length
+    } else {
+        Vec2::X
+    };
+    let closest = if signed_distance <= 0.0 {
+        point
+    } else {
+        let radial = direction * closest.x;
+        Vec3::new(radial.x, closest.y, radial.y)
+    };
+    (closest, signed_distance)
+}
+
+/// The point of the triangle `[a, b, c]` that is closest to `point`.
+///
+/// See Real-Time Collision Detection by Christer Ericson, 5.1.5.
+fn closest_point_on_triangle([a, b, c]: [Vec3; 3], point: Vec3) -> Vec3 {
+    let ab = b - a;
+    let ac = c - a;
+    if ab.cross(ac).length_squared() == 0.0 {
+        // A degenerate triangle is a segment or a point
+        return [(a, b), (b, c), (c, a)]
+            .into_iter()
+            .map(|(start, end)| closest_point_on_segment_3d(start, end, point))
+            .min_by(|x, y| {
+                x.distance_squared(point)
+                    .total_cmp(&y.distance_squared(point))
+            })
+            .unwrap();
+    }
+
+    // Closest to a vertex, an edge, or the face, from the barycentric coordinates
+    let ap = point - a;
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = point - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = point - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denominator = (va + vb + vc).recip();
+    a + ab * (vb * denominator) + ac * (vc * denominator)
+}
+
+impl SignedDistance2d for Circle {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        Circle::closest_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        point.length() - self.radius
+    }
+}
+
+impl Ellipse {
+    /// Finds the point on the boundary of the ellipse that is closest to `point`, inside or
+    /// outside the ellipse.
+    fn closest_boundary_point(&self, point: Vec2) -> Vec2 {
+        let half_size = self.half_size.abs();
+        let Vec2 { x: a, y: b } = half_size;
+        let p = point.abs();
+
+        // A flat ellipse is a segment
+        if a == 0.0 || b == 0.0 {
+            return point.clamp(-half_size, half_size);
+        }
+
+        // Iterates on the parameter of the closest point, moving it along the evolute of the
+        // ellipse. See https://blog.chatfield.io/simple-method-for-distance-to-ellipse/
+        let mut t = Vec2::splat(std::f32::consts::FRAC_1_SQRT_2);
+        for _ in 0..4 {
+            let on_ellipse = half_size * t;
+            let evolute = Vec2::new((a * a - b * b) / a, (b * b - a * a) / b) * t * t * t;
+            let r = (on_ellipse - evolute).length();
+            let q = p - evolute;
+            let q_length = q.length();
+            let target = if q_length == 0.0 {
+                on_ellipse
+            } else {
+                q * (r / q_length) + evolute
+            };
+            t = (target / half_size)
+                .clamp(Vec2::ZERO, Vec2::ONE)
+                .normalize_or_zero();
+        }
+        half_size * t * Vec2::select(point.cmplt(Vec2::ZERO), -Vec2::ONE, Vec2::ONE)
+    }
+}
+
+impl SignedDistance2d for Ellipse {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let half_size = self.half_size.abs();
+        if half_size.min_element() > 0.0 && (point / half_size).length_squared() <= 1.0 {
+            point
+        } else {
+            self.closest_boundary_point(point)
+        }
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let distance = self.closest_boundary_point(point).distance(point);
+        let half_size = self.half_size.abs();
+        if half_size.min_element() > 0.0 && (point / half_size).length_squared() < 1.0 {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+impl SignedDistance2d for Annulus {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        Annulus::closest_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let (inner, outer) = (self.inner_circle.radius, self.outer_circle.radius);
+        (point.length() - (inner + outer) / 2.0).abs() - (outer - inner) / 2.0
+    }
+}
+
+impl SignedDistance2d for Rhombus {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        Rhombus::closest_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let closest = Rhombus::closest_point(self, point);
+        if closest != point {
+            return closest.distance(point);
+        }
+        // Inside, the closest point is on the side of the quadrant of the point
+        let half_diagonals = self.half_diagonals.abs();
+        let normal = Vec2::new(half_diagonals.y, half_diagonals.x);
+        let normal_length = normal.length();
+        if normal_length == 0.0 {
+            return point.length();
+        }
+        (normal.dot(point.abs()) - half_diagonals.x * half_diagonals.y) / normal_length
+    }
+}
+
+impl SignedDistance2d for Plane2d {
+    /// Finds the point of the line of the plane that is closest to `point`.
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        point - *self.normal * self.signed_distance(point)
+    }
+
+    /// Returns the distance from `point` to the line of the plane: positive on the side of its
+    /// normal, and negative on the other side.
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        self.normal.dot(point)
+    }
+}
+
+impl SignedDistance2d for Segment2d {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_segment_2d(self.point1(), self.point2(), point)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        SignedDistance2d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance2d for Triangle2d {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        convex_polygon_distance(&self.vertices, point).0
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        convex_polygon_distance(&self.vertices, point).1
+    }
+}
+
+impl SignedDistance2d for Rectangle {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        Rectangle::closest_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let q = point.abs() - self.half_size.abs();
+        q.max(Vec2::ZERO).length() + q.max_element().min(0.0)
+    }
+}
+
+impl SignedDistance2d for RegularPolygon {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let vertices: Vec<_> = self.vertices(0.0).into_iter().collect();
+        convex_polygon_distance(&vertices, point).0
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let vertices: Vec<_> = self.vertices(0.0).into_iter().collect();
+        convex_polygon_distance(&vertices, point).1
+    }
+}
+
+impl<const N: usize> SignedDistance2d for Polygon<N> {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        polygon_distance(&self.vertices, point).0
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        polygon_distance(&self.vertices, point).1
+    }
+}
+
+impl SignedDistance2d for BoxedPolygon {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        polygon_distance(&self.vertices, point).0
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        polygon_distance(&self.vertices, point).1
+    }
+}
+
+impl<const N: usize> SignedDistance2d for Polyline2d<N> {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_polyline(&self.vertices, false, point, closest_point_on_segment_2d)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        SignedDistance2d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance2d for BoxedPolyline2d {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_polyline(&self.vertices, false, point, closest_point_on_segment_2d)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        SignedDistance2d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance2d for Capsule2d {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let center = Vec2::new(0.0, point.y.clamp(-self.half_length, self.half_length));
+        let offset = point - center;
+        if offset.length() <= self.radius {
+            point
+        } else {
+            center + offset.normalize_or_zero() * self.radius
+        }
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let center = Vec2::new(0.0, point.y.clamp(-self.half_length, self.half_length));
+        point.distance(center) - self.radius
+    }
+}
+
+impl Arc2d {
+    /// Whether the direction of `point` from the center is within the angle of the arc.
+    fn spans(&self, point: Vec2) -> bool {
+        point.x.abs().atan2(point.y) <= self.half_angle.abs()
+    }
+
+    /// Finds the point of the arc that is closest to `point`.
+    fn closest_arc_point(&self, point: Vec2) -> Vec2 {
+        if self.spans(point) {
+            // The center is equally close to all the points of the arc
+            let direction = point.try_normalize().unwrap_or(Vec2::Y);
+            return direction * self.radius;
+        }
+        let [left, right] = self.endpoints();
+        if point.distance_squared(left) < point.distance_squared(right) {
+            left
+        } else {
+            right
+        }
+    }
+}
+
+impl SignedDistance2d for Arc2d {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.closest_arc_point(point)
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        self.closest_arc_point(point).distance(point)
+    }
+}
+
+impl CircularSector {
+    /// Finds the point on the boundary of the sector that is closest to `point`, inside or
+    /// outside the sector.
+    fn closest_boundary_point(&self, point: Vec2) -> Vec2 {
+        let on_arc = self.arc.closest_arc_point(point);
+        // The radii of a full circle meet, and aren't part of its boundary
+        if self.arc.half_angle.abs() >= PI {
+            return on_arc;
+        }
+        let [left, right] = self.arc.endpoints();
+        [
+            on_arc,
+            closest_point_on_segment_2d(Vec2::ZERO, left, point),
+            closest_point_on_segment_2d(Vec2::ZERO, right, point),
+        ]
+        .into_iter()
+        .min_by(|a, b| {
+            a.distance_squared(point)
+                .total_cmp(&b.distance_squared(point))
+        })
+        .unwrap()
+    }
+
+    /// The sector is the part of its circle within the angle of the arc.
+    fn contains(&self, point: Vec2) -> bool {
+        point.length() <= self.arc.radius && self.arc.spans(point)
+    }
+}
+
+impl SignedDistance2d for CircularSector {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains(point) {
+            point
+        } else {
+            self.closest_boundary_point(point)
+        }
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let distance = self.closest_boundary_point(point).distance(point);
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+impl CircularSegment {
+    /// Finds the point on the boundary of the segment that is closest to `point`, inside or
+    /// outside the segment.
+    fn closest_boundary_point(&self, point: Vec2) -> Vec2 {
+        let on_arc = self.arc.closest_arc_point(point);
+        let [left, right] = self.arc.endpoints();
+        let on_chord = closest_point_on_segment_2d(left, right, point);
+        if on_arc.distance_squared(point) <= on_chord.distance_squared(point) {
+            on_arc
+        } else {
+            on_chord
+        }
+    }
+
+    /// The segment is the part of its circle on the side of the chord where the arc is.
+    fn contains(&self, point: Vec2) -> bool {
+        point.length() <= self.arc.radius && point.y >= self.arc.chord_midpoint().y
+    }
+}
+
+impl SignedDistance2d for CircularSegment {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains(point) {
+            point
+        } else {
+            self.closest_boundary_point(point)
+        }
+    }
+
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let distance = self.closest_boundary_point(point).distance(point);
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+impl SignedDistance3d for Sphere {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        Sphere::closest_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        point.length() - self.radius
+    }
+}
+
+impl SignedDistance3d for InfinitePlane3d {
+    /// Finds the point of the plane that is closest to `point`.
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        point - *self.normal * self.signed_distance(point)
+    }
+
+    /// Returns the distance from `point` to the plane: positive on the side of its normal, and
+    /// negative on the other side.
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point)
+    }
+}
+
+impl SignedDistance3d for Plane3d {
+    /// Finds the point of the rectangle of the plane that is closest to `point`.
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        // Like its mesh, the plane is the rectangle of its half size in the xz-plane, rotated
+        // from the y-axis to its normal
+        let rotation = Quat::from_rotation_arc(Vec3::Y, *self.normal);
+        let half_size = self.half_size.abs();
+        let closest = (rotation.inverse() * point)
+            .xz()
+            .clamp(-half_size, half_size);
+        rotation * Vec3::new(closest.x, 0.0, closest.y)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        SignedDistance3d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance3d for Segment3d {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        closest_point_on_segment_3d(self.point1(), self.point2(), point)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        SignedDistance3d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance3d for Cuboid {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        Cuboid::closest_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        let q = point.abs() - self.half_size.abs();
+        q.max(Vec3::ZERO).length() + q.max_element().min(0.0)
+    }
+}
+
+impl SignedDistance3d for Cylinder {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        // The cylinder is a disk extruded along the y-axis, so both are clamped independently
+        let radial = point.xz().clamp_length_max(self.radius);
+        let y = point.y.clamp(-self.half_height, self.half_height);
+        Vec3::new(radial.x, y, radial.y)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        let q = Vec2::new(point.xz().length(), point.y.abs())
+            - Vec2::new(self.radius, self.half_height);
+        q.max(Vec2::ZERO).length() + q.max_element().min(0.0)
+    }
+}
+
+impl SignedDistance3d for Capsule3d {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        let center = Vec3::new(0.0, point.y.clamp(-self.half_length, self.half_length), 0.0);
+        let offset = point - center;
+        if offset.length() <= self.radius {
+            point
+        } else {
+            center + offset.normalize_or_zero() * self.radius
+        }
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        let center = Vec3::new(0.0, point.y.clamp(-self.half_length, self.half_length), 0.0);
+        point.distance(center) - self.radius
+    }
+}
+
+impl Cone {
+    /// The cross-section of the cone through its axis.
+    fn cross_section(&self) -> [Vec2; 3] {
+        let half_height = self.height / 2.0;
+        [
+            Vec2::new(-self.radius, -half_height),
+            Vec2::new(self.radius, -half_height),
+            Vec2::new(0.0, half_height),
+        ]
+    }
+}
+
+impl SignedDistance3d for Cone {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        revolved_polygon_distance(&self.cross_section(), point).0
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        revolved_polygon_distance(&self.cross_section(), point).1
+    }
+}
+
+impl ConicalFrustum {
+    /// The cross-section of the frustum through its axis.
+    fn cross_section(&self) -> [Vec2; 4] {
+        let half_height = self.height / 2.0;
+        [
+            Vec2::new(-self.radius_bottom, -half_height),
+            Vec2::new(self.radius_bottom, -half_height),
+            Vec2::new(self.radius_top, half_height),
+            Vec2::new(-self.radius_top, half_height),
+        ]
+    }
+}
+
+impl SignedDistance3d for ConicalFrustum {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        revolved_polygon_distance(&self.cross_section(), point).0
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        revolved_polygon_distance(&self.cross_section(), point).1
+    }
+}
+
+impl SignedDistance3d for Torus {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        // The closest point is on the circle of the tube nearest to the point
+        let radial = point.xz().try_normalize().unwrap_or(Vec2::X) * self.major_radius;
+        let tube_center = Vec3::new(radial.x, 0.0, radial.y);
+        let offset = point - tube_center;
+        if offset.length() <= self.minor_radius {
+            point
+        } else {
+            tube_center + offset.normalize_or_zero() * self.minor_radius
+        }
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        Vec2::new(point.xz().length() - self.major_radius, point.y).length() - self.minor_radius
+    }
+}
+
+impl<const N: usize> SignedDistance3d for Polyline3d<N> {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        closest_point_on_polyline(&self.vertices, false, point, closest_point_on_segment_3d)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        SignedDistance3d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance3d for BoxedPolyline3d {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        closest_point_on_polyline(&self.vertices, false, point, closest_point_on_segment_3d)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        SignedDistance3d::closest_point(self, point).distance(point)
+    }
+}
+
+impl SignedDistance3d for Triangle3d {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        closest_point_on_triangle(self.vertices, point)
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        closest_point_on_triangle(self.vertices, point).distance(point)
+    }
+}
+
+impl SignedDistance3d for Tetrahedron {
+    fn closest_point(&self, point: Vec3) -> Vec3 {
+        if self.signed_distance(point) <= 0.0 {
+            return point;
+        }
+        self.faces()
+            .into_iter()
+            .map(|face| closest_point_on_triangle(face.vertices, point))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+            .unwrap()
+    }
+
+    fn signed_distance(&self, point: Vec3) -> f32 {
+        let centroid = self.centroid();
+        let mut inside = self.signed_volume() != 0.0;
+        let mut distance = f32::INFINITY;
+        for face in self.faces() {
+            let [a, b, c] = face.vertices;
+            let normal = (b - a).cross(c - a);
+            // Inside, the point is on the same side of each face as the centroid
+            inside &= normal.dot(point - a) * normal.dot(centroid - a) >= 0.0;
+            distance =
+                distance.min(closest_point_on_triangle(face.vertices, point).distance(point));
+        }
+        if inside {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dir2, Dir3};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rectangle_signed_distance() {
+        let rectangle = Rectangle::new(2.0, 4.0);
+        assert_eq!(rectangle.signed_distance(Vec2::new(4.0, 0.0)), 3.0);
+        assert_eq!(rectangle.signed_distance(Vec2::new(4.0, 6.0)), 5.0);
+        assert_eq!(rectangle.signed_distance(Vec2::new(0.5, 0.0)), -0.5);
+        assert_eq!(rectangle.distance(Vec2::new(0.5, 0.0)), 0.0);
+
+        // A zero-size rectangle is a point
+        let point = Rectangle::new(0.0, 0.0);
+        assert_eq!(point.signed_distance(Vec2::new(3.0, 4.0)), 5.0);
+        assert_eq!(
+            SignedDistance2d::closest_point(&point, Vec2::new(3.0, 4.0)),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn circle_and_annulus_signed_distance() {
+        let circle = Circle::new(0.0);
+        assert_eq!(circle.signed_distance(Vec2::new(0.0, 2.0)), 2.0);
+        assert_eq!(
+            SignedDistance2d::closest_point(&circle, Vec2::new(0.0, 2.0)),
+            Vec2::ZERO
+        );
+
+        let annulus = Annulus::new(1.0, 2.0);
+        assert_eq!(annulus.signed_distance(Vec2::ZERO), 1.0);
+        assert_eq!(annulus.signed_distance(Vec2::new(1.25, 0.0)), -0.25);
+        assert_eq!(annulus.signed_distance(Vec2::new(0.0, -3.0)), 1.0);
+    }
+
+    #[test]
+    fn rhombus_signed_distance() {
+        let rhombus = Rhombus::new(2.0, 2.0);
+        assert_relative_eq!(
+            rhombus.signed_distance(Vec2::ZERO),
+            -std::f32::consts::FRAC_1_SQRT_2
+        );
+        assert_relative_eq!(rhombus.signed_distance(Vec2::new(2.0, 0.0)), 1.0);
+        assert_relative_eq!(
+            rhombus.signed_distance(Vec2::new(1.0, 1.0)),
+            std::f32::consts::FRAC_1_SQRT_2
+        );
+    }
+
+    #[test]
+    fn ellipse_signed_distance() {
+        // A circular ellipse matches the circle
+        let ellipse = Ellipse::new(1.0, 1.0);
+        let circle = Circle::new(1.0);
+        for point in [
+            Vec2::new(3.0, 1.0),
+            Vec2::new(-0.2, 0.5),
+            Vec2::new(0.0, -2.0),
+        ] {
+            assert_relative_eq!(
+                ellipse.signed_distance(point),
+                circle.signed_distance(point),
+                epsilon = 1e-3
+            );
+        }
+
+        let ellipse = Ellipse::new(2.0, 1.0);
+        assert_relative_eq!(
+            ellipse.signed_distance(Vec2::new(4.0, 0.0)),
+            2.0,
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            ellipse.signed_distance(Vec2::new(0.0, 3.0)),
+            2.0,
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(ellipse.signed_distance(Vec2::ZERO), -1.0, epsilon = 1e-3);
+        let closest = SignedDistance2d::closest_point(&ellipse, Vec2::new(3.0, 3.0));
+        assert_relative_eq!((closest / ellipse.half_size).length(), 1.0, epsilon = 1e-3);
+
+        // A flat ellipse is a segment
+        let flat = Ellipse::new(2.0, 0.0);
+        assert_eq!(flat.signed_distance(Vec2::new(1.0, 1.0)), 1.0);
+    }
+
+    #[test]
+    fn polygon_signed_distance() {
+        // Both winding orders
+        for triangle in [
+            Triangle2d::new(Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0)),
+            Triangle2d::new(Vec2::ZERO, Vec2::new(0.0, 4.0), Vec2::new(4.0, 0.0)),
+        ] {
+            assert_eq!(triangle.signed_distance(Vec2::new(1.0, 1.0)), -1.0);
+            assert_eq!(triangle.signed_distance(Vec2::new(-3.0, -4.0)), 5.0);
+            assert_eq!(
+                SignedDistance2d::closest_point(&triangle, Vec2::new(2.0, -1.0)),
+                Vec2::new(2.0, 0.0)
+            );
+        }
+
+        // A degenerate triangle has no interior
+        let segment = Triangle2d::new(Vec2::ZERO, Vec2::X, Vec2::X * 2.0);
+        assert_eq!(segment.signed_distance(Vec2::new(1.0, 0.0)), 0.0);
+        assert_eq!(segment.signed_distance(Vec2::new(1.0, -1.0)), 1.0);
+
+        let hexagon = RegularPolygon::new(1.0, 6);
+        assert_relative_eq!(
+            hexagon.signed_distance(Vec2::ZERO),
+            -(3.0f32.sqrt() / 2.0),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            hexagon.signed_distance(Vec2::new(0.0, 3.0)),
+            2.0,
+            epsilon = 1e-6
+        );
+
+        // A concave L shape, whose notch is outside
+        let l_shape = BoxedPolygon::new([
+            Vec2::ZERO,
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 4.0),
+            Vec2::new(0.0, 4.0),
+        ]);
+        assert_eq!(l_shape.signed_distance(Vec2::new(0.5, 3.0)), -0.5);
+        assert_eq!(l_shape.signed_distance(Vec2::new(3.0, 2.5)), 1.5);
+        assert_eq!(
+            SignedDistance2d::closest_point(&l_shape, Vec2::new(3.0, 2.5)),
+            Vec2::new(3.0, 1.0)
+        );
+        let polygon = Polygon::<6>::new(l_shape.vertices.iter().copied());
+        assert_eq!(polygon.signed_distance(Vec2::new(2.0, 2.0)), 1.0);
+    }
+
+    #[test]
+    fn polyline_signed_distance() {
+        // Polylines aren't closed, and have no interior
+        let polyline = Polyline2d::<3>::new([Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::new(2.0, 2.0)]);
+        assert_eq!(polyline.signed_distance(Vec2::new(1.0, 1.0)), 1.0);
+        assert_eq!(
+            polyline.signed_distance(Vec2::new(0.0, 3.0)),
+            5.0_f32.sqrt()
+        );
+        assert_eq!(
+            SignedDistance2d::closest_point(&polyline, Vec2::new(3.0, 1.0)),
+            Vec2::new(2.0, 1.0)
+        );
+        assert_eq!(
+            BoxedPolyline2d::new(Vec::<Vec2>::new()).signed_distance(Vec2::new(3.0, 4.0)),
+            5.0
+        );
+
+        let polyline = BoxedPolyline3d::new([Vec3::ZERO, Vec3::X, Vec3::new(1.0, 0.0, 1.0)]);
+        assert_eq!(polyline.signed_distance(Vec3::new(0.5, 2.0, 0.0)), 2.0);
+        assert_eq!(
+            SignedDistance3d::closest_point(&polyline, Vec3::new(2.0, 0.0, 0.5)),
+            Vec3::new(1.0, 0.0, 0.5)
+        );
+        let polyline = Polyline3d::<1>::new([Vec3::X]);
+        assert_eq!(polyline.signed_distance(Vec3::new(1.0, 3.0, 0.0)), 3.0);
+    }
+
+    #[test]
+    fn capsule_and_segment_signed_distance() {
+        let capsule = Capsule2d::new(1.0, 2.0);
+        assert_eq!(capsule.signed_distance(Vec2::new(3.0, 0.5)), 2.0);
+        assert_eq!(capsule.signed_distance(Vec2::new(0.0, 4.0)), 2.0);
+        assert_eq!(capsule.signed_distance(Vec2::new(0.5, 0.0)), -0.5);
+
+        let capsule = Capsule3d::new(1.0, 2.0);
+        assert_eq!(capsule.signed_distance(Vec3::new(0.0, 0.5, -3.0)), 2.0);
+        assert_eq!(
+            SignedDistance3d::closest_point(&capsule, Vec3::new(0.0, 4.0, 0.0)),
+            Vec3::new(0.0, 2.0, 0.0)
+        );
+
+        let segment = Segment2d::new(Dir2::X, 2.0);
+        assert_eq!(segment.signed_distance(Vec2::new(0.0, 1.0)), 1.0);
+        assert_eq!(segment.signed_distance(Vec2::new(0.5, 0.0)), 0.0);
+        let segment = Segment3d::new(Dir3::Z, 0.0);
+        assert_eq!(segment.signed_distance(Vec3::new(0.0, 3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn arc_sector_and_segment_signed_distance() {
+        use std::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4, PI};
+
+        // The upper half of a circle
+        let arc = Arc2d::new(2.0, FRAC_PI_2);
+        assert_eq!(arc.signed_distance(Vec2::new(0.0, 4.0)), 2.0);
+        assert_eq!(arc.signed_distance(Vec2::new(0.0, 0.5)), 1.5);
+        assert_eq!(
+            SignedDistance2d::closest_point(&arc, Vec2::new(0.0, 0.5)),
+            Vec2::new(0.0, 2.0)
+        );
+        let closest = SignedDistance2d::closest_point(&arc, Vec2::new(3.0, -1.0));
+        assert!(closest.abs_diff_eq(Vec2::new(2.0, 0.0), 1e-6));
+
+        let sector = CircularSector::new(2.0, FRAC_PI_4);
+        assert_relative_eq!(
+            sector.signed_distance(Vec2::new(0.0, 1.0)),
+            -FRAC_1_SQRT_2,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(sector.signed_distance(Vec2::new(0.0, 3.0)), 1.0);
+        assert_relative_eq!(sector.signed_distance(Vec2::new(0.0, -1.0)), 1.0);
+        assert_eq!(
+            SignedDistance2d::closest_point(&sector, Vec2::new(0.0, -1.0)),
+            Vec2::ZERO
+        );
+        // A full circle
+        let sector = CircularSector::new(2.0, PI);
+        assert_relative_eq!(sector.signed_distance(Vec2::new(0.0, -1.0)), -1.0);
+
+        let segment = CircularSegment::new(2.0, FRAC_PI_2);
+        assert_relative_eq!(
+            segment.signed_distance(Vec2::new(0.0, 0.5)),
+            -0.5,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(segment.signed_distance(Vec2::new(0.0, 3.0)), 1.0);
+        assert_relative_eq!(
+            segment.signed_distance(Vec2::new(0.0, -1.0)),
+            1.0,
+            epsilon = 1e-6
+        );
+        let closest = SignedDistance2d::closest_point(&segment, Vec2::new(0.0, -1.0));
+        assert!(closest.abs_diff_eq(Vec2::ZERO, 1e-6));
+        // A major segment contains the center of its circle
+        let segment = CircularSegment::new(1.0, 3.0 * FRAC_PI_4);
+        assert_relative_eq!(
+            segment.signed_distance(Vec2::ZERO),
+            -FRAC_1_SQRT_2,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn solids_of_revolution_signed_distance() {
+        let cylinder = Cylinder::new(1.0, 2.0);
+        assert_eq!(cylinder.signed_distance(Vec3::new(3.0, 0.0, 0.0)), 2.0);
+        assert_eq!(cylinder.signed_distance(Vec3::new(0.0, 0.0, 0.25)), -0.75);
+        assert_eq!(
+            SignedDistance3d::closest_point(&cylinder, Vec3::new(0.0, 5.0, 3.0)),
+            Vec3::new(0.0, 1.0, 1.0)
+        );
+
+        let cone = Cone {
+            radius: 1.0,
+            height: 2.0,
+        };
+        assert_relative_eq!(cone.signed_distance(Vec3::new(0.0, -3.0, 0.0)), 2.0);
+        assert_relative_eq!(cone.signed_distance(Vec3::new(0.0, 2.0, 0.0)), 1.0);
+        assert_relative_eq!(cone.signed_distance(Vec3::new(0.0, -0.75, 0.0)), -0.25);
+        assert!(cone.signed_distance(Vec3::new(0.0, 0.0, 0.6)) > 0.0);
+        let closest = SignedDistance3d::closest_point(&cone, Vec3::new(0.0, -2.0, 0.5));
+        assert!(closest.abs_diff_eq(Vec3::new(0.0, -1.0, 0.5), 1e-6));
+
+        let frustum = ConicalFrustum {
+            radius_top: 1.0,
+            radius_bottom: 1.0,
+            height: 2.0,
+        };
+        for point in [Vec3::new(3.0, 0.5, 1.0), Vec3::new(0.2, -0.1, 0.3)] {
+            assert_relative_eq!(
+                frustum.signed_distance(point),
+                cylinder.signed_distance(point),
+                epsilon = 1e-6
+            );
+        }
+
+        let torus = Torus::new(1.0, 3.0);
+        assert_eq!(torus.signed_distance(Vec3::ZERO), 1.0);
+        assert_eq!(torus.signed_distance(Vec3::new(0.0, 0.0, 2.5)), -0.5);
+        assert_eq!(
+            SignedDistance3d::closest_point(&torus, Vec3::new(0.0, 3.0, 2.0)),
+            Vec3::new(0.0, 1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn triangle_and_tetrahedron_signed_distance() {
+        let triangle = Triangle3d::new(Vec3::ZERO, Vec3::X * 2.0, Vec3::Z * 2.0);
+        assert_eq!(triangle.signed_distance(Vec3::new(0.5, 1.0, 0.5)), 1.0);
+        assert_eq!(
+            SignedDistance3d::closest_point(&triangle, Vec3::new(-1.0, 0.0, -1.0)),
+            Vec3::ZERO
+        );
+        let degenerate = Triangle3d::new(Vec3::ZERO, Vec3::X, Vec3::X * 2.0);
+        assert_eq!(degenerate.signed_distance(Vec3::new(3.0, 0.0, 0.0)), 1.0);
+
+        let tetrahedron = Tetrahedron::new(Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z);
+        assert_relative_eq!(tetrahedron.signed_distance(Vec3::splat(0.1)), -0.1);
+        assert_eq!(tetrahedron.signed_distance(Vec3::new(-2.0, 0.0, 0.0)), 2.0);
+        assert_eq!(
+            SignedDistance3d::closest_point(&tetrahedron, Vec3::splat(0.1)),
+            Vec3::splat(0.1)
+        );
+    }
+
+    #[test]
+    fn plane_signed_distance() {
+        let plane = InfinitePlane3d::new(Vec3::Y);
+        assert_eq!(plane.signed_distance(Vec3::new(1.0, -2.0, 3.0)), -2.0);
+        assert_eq!(
+            SignedDistance3d::closest_point(&plane, Vec3::new(1.0, -2.0, 3.0)),
+            Vec3::new(1.0, 0.0, 3.0)
+        );
+        let plane = Plane2d::new(Vec2::X);
+        assert_eq!(plane.signed_distance(Vec2::new(2.0, 1.0)), 2.0);
+
+        // A finite plane is a rectangle without interior
+        let plane = Plane3d::new(Vec3::Y, Vec2::new(1.0, 2.0));
+        assert_eq!(plane.signed_distance(Vec3::new(0.5, -2.0, 1.0)), 2.0);
+        assert_eq!(
+            SignedDistance3d::closest_point(&plane, Vec3::new(3.0, 1.0, -5.0)),
+            Vec3::new(1.0, 0.0, -2.0)
+        );
+        let plane = Plane3d::new(Vec3::X, Vec2::new(1.0, 1.0));
+        assert_relative_eq!(plane.signed_distance(Vec3::new(3.0, 0.0, 0.0)), 3.0);
+        assert_relative_eq!(plane.signed_distance(Vec3::new(0.0, 3.0, 0.0)), 2.0);
+    }
+}
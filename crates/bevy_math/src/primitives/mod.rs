@@ -6,6 +6,8 @@ mod dim2;
 pub use dim2::*;
 mod dim3;
 pub use dim3::*;
+mod distance;
+pub use distance::*;
 #[cfg(feature = "serialize")]
 mod serde;
 
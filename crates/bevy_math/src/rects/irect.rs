@@ -344,6 +344,61 @@ impl IRect {
         r
     }
 
+    /// Rectangle area (width * height).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::IRect;
+    /// let r = IRect::new(0, 0, 5, 2); // w=5 h=2
+    /// assert_eq!(r.area(), 10);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> i32 {
+        self.width() * self.height()
+    }
+
+    /// The area of the intersection of this rectangle and another one.
+    ///
+    /// This is zero when the rectangles don't overlap, or only touch along an edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::IRect;
+    /// let r1 = IRect::new(0, 0, 5, 1); // w=5 h=1
+    /// let r2 = IRect::new(1, -1, 3, 3); // w=2 h=4
+    /// assert_eq!(r1.intersection_area(r2), 2);
+    /// ```
+    #[inline]
+    pub fn intersection_area(&self, other: Self) -> i32 {
+        self.intersect(other).area()
+    }
+
+    /// Create the smallest rectangle containing this one whose corners are multiples of `grid`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the components of `grid` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{IRect, IVec2};
+    /// let r = IRect::new(-3, 1, 5, 7); // w=8 h=6
+    /// let r2 = r.expand_to_multiple(IVec2::new(4, 2));
+    /// assert_eq!(r2.min, IVec2::new(-4, 0));
+    /// assert_eq!(r2.max, IVec2::new(8, 8));
+    /// ```
+    #[inline]
+    pub fn expand_to_multiple(&self, grid: IVec2) -> Self {
+        assert!(grid.cmpgt(IVec2::ZERO).all(), "IRect grid must be positive");
+        Self {
+            min: self.min.div_euclid(grid) * grid,
+            max: (self.max + grid - IVec2::ONE).div_euclid(grid) * grid,
+        }
+    }
+
     /// Returns self as [`Rect`] (f32)
     #[inline]
     pub fn as_rect(&self) -> Rect {
@@ -478,4 +533,41 @@ mod tests {
         assert_eq!(r2.min, IVec2::new(-4, -4));
         assert_eq!(r2.max, IVec2::new(4, 4));
     }
+
+    #[test]
+    fn rect_area() {
+        let r = IRect::new(2, -1, -2, 1); // w=4 h=2
+        assert_eq!(r.area(), 8);
+        assert_eq!(IRect::default().area(), 0);
+
+        // overlapping
+        assert_eq!(r.intersection_area(IRect::new(1, 0, 3, 3)), 1);
+        // touching
+        assert_eq!(r.intersection_area(IRect::new(2, -1, 3, 1)), 0);
+        // disjoint
+        assert_eq!(r.intersection_area(IRect::new(5, 5, 6, 6)), 0);
+    }
+
+    #[test]
+    fn rect_expand_to_multiple() {
+        let r = IRect::new(-3, 1, 5, 7);
+        let r2 = r.expand_to_multiple(IVec2::new(4, 2));
+        assert_eq!(r2, IRect::new(-4, 0, 8, 8));
+
+        // Already aligned, including negative corners
+        assert_eq!(r2.expand_to_multiple(IVec2::new(4, 2)), r2);
+
+        // Empty
+        let r = IRect::from_corners(IVec2::splat(-3), IVec2::splat(-3));
+        assert_eq!(
+            r.expand_to_multiple(IVec2::splat(2)),
+            IRect::new(-4, -4, -2, -2)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn rect_expand_to_multiple_zero_grid() {
+        IRect::new(0, 0, 1, 1).expand_to_multiple(IVec2::new(1, 0));
+    }
 }
@@ -1,7 +1,9 @@
 mod irect;
 mod rect;
+mod rounding;
 mod urect;
 
 pub use irect::IRect;
 pub use rect::Rect;
+pub use rounding::RectRounding;
 pub use urect::URect;
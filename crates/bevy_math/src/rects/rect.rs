@@ -1,4 +1,4 @@
-use crate::{IRect, URect, Vec2};
+use crate::{Affine2, IRect, RectRounding, URect, Vec2};
 
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
@@ -356,6 +356,225 @@ impl Rect {
         }
     }
 
+    /// Rectangle area (width * height).
+    ///
+    /// Empty rectangles, including [`Rect::EMPTY`], have an area of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::Rect;
+    /// let r = Rect::new(0., 0., 5., 2.); // w=5 h=2
+    /// assert!((r.area() - 10.).abs() <= 1e-5);
+    /// assert_eq!(Rect::EMPTY.area(), 0.);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> f32 {
+        if self.is_empty() {
+            0.
+        } else {
+            self.width() * self.height()
+        }
+    }
+
+    /// The area of the intersection of this rectangle and another one.
+    ///
+    /// This is zero when the rectangles don't overlap, or only touch along an edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::Rect;
+    /// let r1 = Rect::new(0., 0., 5., 1.); // w=5 h=1
+    /// let r2 = Rect::new(1., -1., 3., 3.); // w=2 h=4
+    /// assert!((r1.intersection_area(r2) - 2.).abs() <= 1e-5);
+    /// ```
+    #[inline]
+    pub fn intersection_area(&self, other: Self) -> f32 {
+        self.intersect(other).area()
+    }
+
+    /// Split the rectangle along the X axis into a left and a right rectangle.
+    ///
+    /// `fraction` is the part of the width given to the left rectangle, and is clamped to
+    /// `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{Rect, Vec2};
+    /// let r = Rect::new(0., 0., 4., 2.); // w=4 h=2
+    /// let (left, right) = r.split_horizontal(0.25);
+    /// assert!(left.max.abs_diff_eq(Vec2::new(1., 2.), 1e-5));
+    /// assert!(right.min.abs_diff_eq(Vec2::new(1., 0.), 1e-5));
+    /// ```
+    #[inline]
+    pub fn split_horizontal(&self, fraction: f32) -> (Self, Self) {
+        let x = self.min.x + self.width() * fraction.clamp(0., 1.);
+        (
+            Self {
+                min: self.min,
+                max: Vec2::new(x, self.max.y),
+            },
+            Self {
+                min: Vec2::new(x, self.min.y),
+                max: self.max,
+            },
+        )
+    }
+
+    /// Split the rectangle along the Y axis into a bottom and a top rectangle.
+    ///
+    /// `fraction` is the part of the height given to the bottom rectangle, and is clamped to
+    /// `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{Rect, Vec2};
+    /// let r = Rect::new(0., 0., 4., 2.); // w=4 h=2
+    /// let (bottom, top) = r.split_vertical(0.5);
+    /// assert!(bottom.max.abs_diff_eq(Vec2::new(4., 1.), 1e-5));
+    /// assert!(top.min.abs_diff_eq(Vec2::new(0., 1.), 1e-5));
+    /// ```
+    #[inline]
+    pub fn split_vertical(&self, fraction: f32) -> (Self, Self) {
+        let y = self.min.y + self.height() * fraction.clamp(0., 1.);
+        (
+            Self {
+                min: self.min,
+                max: Vec2::new(self.max.x, y),
+            },
+            Self {
+                min: Vec2::new(self.min.x, y),
+                max: self.max,
+            },
+        )
+    }
+
+    /// Create the smallest rectangle containing this one whose corners are multiples of `grid`.
+    ///
+    /// The components of `grid` must be positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{Rect, Vec2};
+    /// let r = Rect::new(-3., 1., 5., 7.); // w=8 h=6
+    /// let r2 = r.expand_to_multiple(Vec2::new(4., 2.));
+    /// assert!(r2.min.abs_diff_eq(Vec2::new(-4., 0.), 1e-5));
+    /// assert!(r2.max.abs_diff_eq(Vec2::new(8., 8.), 1e-5));
+    /// ```
+    #[inline]
+    pub fn expand_to_multiple(&self, grid: Vec2) -> Self {
+        if self.is_inverted() {
+            return *self;
+        }
+        Self {
+            min: (self.min / grid).floor() * grid,
+            max: (self.max / grid).ceil() * grid,
+        }
+    }
+
+    /// The bounding rectangle of this rectangle transformed by `transform`.
+    ///
+    /// When the transform rotates or skews the rectangle, the result is larger than the
+    /// transformed rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{Affine2, Rect, Vec2};
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// let r = Rect::new(0., 0., 4., 2.); // w=4 h=2
+    /// let transform = Affine2::from_angle_translation(FRAC_PI_2, Vec2::new(10., 0.));
+    /// let r2 = r.transform_by(&transform); // w=2 h=4
+    /// assert!(r2.min.abs_diff_eq(Vec2::new(8., 0.), 1e-5));
+    /// assert!(r2.max.abs_diff_eq(Vec2::new(10., 4.), 1e-5));
+    /// ```
+    #[inline]
+    pub fn transform_by(&self, transform: &Affine2) -> Self {
+        // `Rect::EMPTY` would turn into NaNs
+        if self.is_inverted() {
+            return Self::EMPTY;
+        }
+        let half_size = self.half_size();
+        let half_size = transform.matrix2.x_axis.abs() * half_size.x
+            + transform.matrix2.y_axis.abs() * half_size.y;
+        Self::from_center_half_size(transform.transform_point2(self.center()), half_size)
+    }
+
+    /// Snaps the corners of this rectangle to whole numbers with the given policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{Rect, RectRounding, Vec2};
+    /// let r = Rect::new(0.2, 0.5, 3.7, 2.5);
+    /// let r2 = r.round(RectRounding::Outward);
+    /// assert!(r2.min.abs_diff_eq(Vec2::new(0., 0.), 1e-5));
+    /// assert!(r2.max.abs_diff_eq(Vec2::new(4., 3.), 1e-5));
+    /// ```
+    #[inline]
+    pub fn round(&self, rounding: RectRounding) -> Self {
+        if self.is_inverted() {
+            return *self;
+        }
+        let (min, max) = rounding.round_corners(self.min, self.max);
+        Self { min, max }
+    }
+
+    /// Returns self as [`IRect`] (i32), with the corners snapped to whole numbers with the
+    /// given policy.
+    ///
+    /// Unlike [`Rect::as_irect`], which truncates the corners towards zero, this produces
+    /// consistent results for rectangles on both sides of the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{IVec2, Rect, RectRounding};
+    /// let r = Rect::new(-0.5, -1.2, 2.5, 1.7);
+    /// let r2 = r.to_irect(RectRounding::Round);
+    /// assert_eq!(r2.min, IVec2::new(0, -1));
+    /// assert_eq!(r2.max, IVec2::new(3, 2));
+    /// ```
+    #[inline]
+    pub fn to_irect(&self, rounding: RectRounding) -> IRect {
+        if self.is_inverted() {
+            return IRect::default();
+        }
+        self.round(rounding).as_irect()
+    }
+
+    /// Returns self as [`URect`] (u32), with the corners snapped to whole numbers with the
+    /// given policy.
+    ///
+    /// Negative coordinates are clamped to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{Rect, RectRounding, UVec2};
+    /// let r = Rect::new(-0.5, 1.2, 2.5, 3.7);
+    /// let r2 = r.to_urect(RectRounding::Inward);
+    /// assert_eq!(r2.min, UVec2::new(0, 2));
+    /// assert_eq!(r2.max, UVec2::new(2, 3));
+    /// ```
+    #[inline]
+    pub fn to_urect(&self, rounding: RectRounding) -> URect {
+        if self.is_inverted() {
+            return URect::default();
+        }
+        self.round(rounding).as_urect()
+    }
+
+    /// Whether the minimum corner is greater than the maximum one, like for [`Rect::EMPTY`].
+    #[inline]
+    fn is_inverted(&self) -> bool {
+        self.min.cmpgt(self.max).any()
+    }
+
     /// Returns self as [`IRect`] (i32)
     #[inline]
     pub fn as_irect(&self) -> IRect {
@@ -490,4 +709,126 @@ mod tests {
         assert!(r2.min.abs_diff_eq(Vec2::new(-0.8, -0.8), 1e-5));
         assert!(r2.max.abs_diff_eq(Vec2::new(0.8, 0.8), 1e-5));
     }
+
+    #[test]
+    fn rect_area() {
+        let r = Rect::new(2., -1., -2., 1.); // w=4 h=2
+        assert!((r.area() - 8.).abs() <= 1e-5);
+        assert_eq!(Rect::default().area(), 0.);
+        assert_eq!(Rect::EMPTY.area(), 0.);
+
+        // overlapping
+        let r2 = Rect::new(1., 0., 3., 3.);
+        assert!((r.intersection_area(r2) - 1.).abs() <= 1e-5);
+        // touching
+        let r2 = Rect::new(2., -1., 3., 1.);
+        assert_eq!(r.intersection_area(r2), 0.);
+        // disjoint
+        let r2 = Rect::new(5., 5., 6., 6.);
+        assert_eq!(r.intersection_area(r2), 0.);
+        // empty
+        assert_eq!(r.intersection_area(Rect::EMPTY), 0.);
+    }
+
+    #[test]
+    fn rect_split() {
+        let r = Rect::new(0., 0., 4., 2.);
+
+        let (left, right) = r.split_horizontal(0.25);
+        assert_eq!(left, Rect::new(0., 0., 1., 2.));
+        assert_eq!(right, Rect::new(1., 0., 4., 2.));
+        assert!((left.area() + right.area() - r.area()).abs() <= 1e-5);
+
+        let (bottom, top) = r.split_vertical(0.5);
+        assert_eq!(bottom, Rect::new(0., 0., 4., 1.));
+        assert_eq!(top, Rect::new(0., 1., 4., 2.));
+
+        // The fraction is clamped
+        let (left, right) = r.split_horizontal(-1.);
+        assert!(left.is_empty());
+        assert_eq!(right, r);
+        let (bottom, top) = r.split_vertical(2.);
+        assert_eq!(bottom, r);
+        assert!(top.is_empty());
+    }
+
+    #[test]
+    fn rect_expand_to_multiple() {
+        let r = Rect::new(-3., 1., 5., 7.);
+        let r2 = r.expand_to_multiple(Vec2::new(4., 2.));
+        assert_eq!(r2, Rect::new(-4., 0., 8., 8.));
+
+        // Already aligned
+        assert_eq!(r2.expand_to_multiple(Vec2::new(4., 2.)), r2);
+
+        // Empty
+        let r = Rect::from_corners(Vec2::splat(1.5), Vec2::splat(1.5));
+        assert_eq!(r.expand_to_multiple(Vec2::ONE), Rect::new(1., 1., 2., 2.));
+        assert_eq!(Rect::EMPTY.expand_to_multiple(Vec2::ONE), Rect::EMPTY);
+    }
+
+    #[test]
+    fn rect_transform_by() {
+        let r = Rect::new(0., 0., 4., 2.);
+
+        let translated = r.transform_by(&Affine2::from_translation(Vec2::new(1., -1.)));
+        assert!(translated.min.abs_diff_eq(Vec2::new(1., -1.), 1e-5));
+        assert!(translated.max.abs_diff_eq(Vec2::new(5., 1.), 1e-5));
+
+        // Negative scales flip the rect, which stays normalized
+        let flipped = r.transform_by(&Affine2::from_scale(Vec2::new(-1., 2.)));
+        assert!(flipped.min.abs_diff_eq(Vec2::new(-4., 0.), 1e-5));
+        assert!(flipped.max.abs_diff_eq(Vec2::new(0., 4.), 1e-5));
+
+        // The bounding box of a rect rotated by 45 degrees
+        let square = Rect::from_center_size(Vec2::ZERO, Vec2::splat(2.));
+        let rotated = square.transform_by(&Affine2::from_angle(std::f32::consts::FRAC_PI_4));
+        let half_diagonal = std::f32::consts::SQRT_2;
+        assert!(rotated.min.abs_diff_eq(Vec2::splat(-half_diagonal), 1e-5));
+        assert!(rotated.max.abs_diff_eq(Vec2::splat(half_diagonal), 1e-5));
+
+        assert_eq!(Rect::EMPTY.transform_by(&Affine2::IDENTITY), Rect::EMPTY);
+    }
+
+    #[test]
+    fn rect_rounding() {
+        let r = Rect::new(-1.5, -0.2, 2.5, 1.7);
+
+        let expected = [
+            (RectRounding::Floor, IRect::new(-2, -1, 2, 1)),
+            (RectRounding::Ceil, IRect::new(-1, 0, 3, 2)),
+            // Ties are rounded up on both sides of the origin
+            (RectRounding::Round, IRect::new(-1, 0, 3, 2)),
+            (RectRounding::Outward, IRect::new(-2, -1, 3, 2)),
+            (RectRounding::Inward, IRect::new(-1, 0, 2, 1)),
+        ];
+        for (rounding, irect) in expected {
+            assert_eq!(r.to_irect(rounding), irect, "{rounding:?}");
+            assert_eq!(r.round(rounding), irect.as_rect(), "{rounding:?}");
+        }
+
+        // Rounding ties up keeps the size of a rect spanning the origin
+        let r = Rect::new(-50.5, 0., 49.5, 1.);
+        assert_eq!(r.to_irect(RectRounding::Round).width(), 100);
+
+        // Corners given in any order are normalized first
+        let r = Rect::new(2.5, 1.7, -1.5, -0.2);
+        assert_eq!(r.to_irect(RectRounding::Outward), IRect::new(-2, -1, 3, 2));
+
+        // Rects smaller than a pixel collapse when rounded inward
+        let r = Rect::new(0.2, 0.2, 0.8, 0.8);
+        let rounded = r.round(RectRounding::Inward);
+        assert!(rounded.is_empty());
+        assert!(rounded.min.cmple(rounded.max).all());
+        assert_eq!(r.to_irect(RectRounding::Outward), IRect::new(0, 0, 1, 1));
+
+        // Negative coordinates are clamped for unsigned rects
+        let r = Rect::new(-3.2, -1., 4.6, 2.);
+        assert_eq!(r.to_urect(RectRounding::Outward), URect::new(0, 0, 5, 2));
+
+        // Empty rects stay empty
+        assert_eq!(Rect::EMPTY.round(RectRounding::Outward), Rect::EMPTY);
+        assert!(Rect::EMPTY.to_irect(RectRounding::Outward).is_empty());
+        assert!(Rect::EMPTY.to_urect(RectRounding::Outward).is_empty());
+    }
 }
@@ -0,0 +1,75 @@
+use crate::Vec2;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// How the corners of a [`Rect`](crate::Rect) are snapped to whole numbers, for example when
+/// converting a rectangle in logical pixels to physical pixels.
+///
+/// Using the same policy everywhere a rectangle is snapped keeps the results consistent,
+/// instead of the edges differing by a pixel depending on the call site.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Default)
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum RectRounding {
+    /// Both corners are rounded towards negative infinity.
+    ///
+    /// The rectangle keeps its size when it is already a whole number.
+    Floor,
+    /// Both corners are rounded towards positive infinity.
+    ///
+    /// The rectangle keeps its size when it is already a whole number.
+    Ceil,
+    /// Both corners are rounded to the nearest whole number, with ties rounded towards
+    /// positive infinity.
+    ///
+    /// Rounding ties up means that a rectangle keeps its size when moved by a whole number,
+    /// even when it spans from negative to positive coordinates.
+    #[default]
+    Round,
+    /// The minimum corner is rounded down and the maximum corner up, so the rectangle covers
+    /// every partially covered pixel.
+    Outward,
+    /// The minimum corner is rounded up and the maximum corner down, so the rectangle only
+    /// covers fully covered pixels.
+    ///
+    /// Rectangles smaller than a pixel collapse to an empty rectangle.
+    Inward,
+}
+
+impl RectRounding {
+    /// Snaps the `min` and `max` corners of a rectangle to whole numbers with this policy.
+    ///
+    /// The returned minimum corner is never greater than the maximum corner.
+    #[inline]
+    pub(crate) fn round_corners(self, min: Vec2, max: Vec2) -> (Vec2, Vec2) {
+        let (min, max) = match self {
+            RectRounding::Floor => (min.floor(), max.floor()),
+            RectRounding::Ceil => (min.ceil(), max.ceil()),
+            RectRounding::Round => (round_ties_up(min), round_ties_up(max)),
+            RectRounding::Outward => (min.floor(), max.ceil()),
+            RectRounding::Inward => (min.ceil(), max.floor()),
+        };
+        // Collapse min over max to enforce invariants and ensure e.g. width() or
+        // height() never return a negative value.
+        (min.min(max), max)
+    }
+}
+
+/// Rounds to the nearest whole number, with ties rounded towards positive infinity.
+#[inline]
+fn round_ties_up(value: Vec2) -> Vec2 {
+    // `round` rounds ties away from zero, which is only wrong for negative ties
+    let is_tie = (value - value.floor()).cmpeq(Vec2::splat(0.5));
+    Vec2::select(is_tie, value.ceil(), value.round())
+}
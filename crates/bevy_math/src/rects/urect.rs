@@ -347,6 +347,61 @@ impl URect {
         r
     }
 
+    /// Rectangle area (width * height).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::URect;
+    /// let r = URect::new(0, 0, 5, 2); // w=5 h=2
+    /// assert_eq!(r.area(), 10);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> u32 {
+        self.width() * self.height()
+    }
+
+    /// The area of the intersection of this rectangle and another one.
+    ///
+    /// This is zero when the rectangles don't overlap, or only touch along an edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::URect;
+    /// let r1 = URect::new(0, 1, 5, 2); // w=5 h=1
+    /// let r2 = URect::new(1, 0, 3, 4); // w=2 h=4
+    /// assert_eq!(r1.intersection_area(r2), 2);
+    /// ```
+    #[inline]
+    pub fn intersection_area(&self, other: Self) -> u32 {
+        self.intersect(other).area()
+    }
+
+    /// Create the smallest rectangle containing this one whose corners are multiples of `grid`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the components of `grid` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_math::{URect, UVec2};
+    /// let r = URect::new(3, 1, 5, 7); // w=2 h=6
+    /// let r2 = r.expand_to_multiple(UVec2::new(4, 2));
+    /// assert_eq!(r2.min, UVec2::new(0, 0));
+    /// assert_eq!(r2.max, UVec2::new(8, 8));
+    /// ```
+    #[inline]
+    pub fn expand_to_multiple(&self, grid: UVec2) -> Self {
+        assert!(grid.cmpgt(UVec2::ZERO).all(), "URect grid must be positive");
+        Self {
+            min: self.min / grid * grid,
+            max: ((self.max + grid - UVec2::ONE) / grid) * grid,
+        }
+    }
+
     /// Returns self as [`Rect`] (f32)
     #[inline]
     pub fn as_rect(&self) -> Rect {
@@ -481,4 +536,35 @@ mod tests {
         assert_eq!(r2.min, UVec2::new(1, 1));
         assert_eq!(r2.max, UVec2::new(11, 11));
     }
+
+    #[test]
+    fn rect_area() {
+        let r = URect::new(4, 3, 0, 1); // w=4 h=2
+        assert_eq!(r.area(), 8);
+        assert_eq!(URect::default().area(), 0);
+
+        // overlapping
+        assert_eq!(r.intersection_area(URect::new(3, 2, 5, 5)), 1);
+        // touching
+        assert_eq!(r.intersection_area(URect::new(4, 1, 5, 3)), 0);
+        // disjoint
+        assert_eq!(r.intersection_area(URect::new(7, 7, 8, 8)), 0);
+    }
+
+    #[test]
+    fn rect_expand_to_multiple() {
+        let r = URect::new(3, 1, 5, 7);
+        let r2 = r.expand_to_multiple(UVec2::new(4, 2));
+        assert_eq!(r2, URect::new(0, 0, 8, 8));
+
+        // Already aligned
+        assert_eq!(r2.expand_to_multiple(UVec2::new(4, 2)), r2);
+
+        // Empty
+        let r = URect::from_corners(UVec2::splat(3), UVec2::splat(3));
+        assert_eq!(
+            r.expand_to_multiple(UVec2::splat(2)),
+            URect::new(2, 2, 4, 4)
+        );
+    }
 }
@@ -38,9 +38,9 @@
 //!
 //! In any case, the [`Rng`] used as the source of randomness must be provided explicitly.
 
-use std::f32::consts::{PI, TAU};
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
-use crate::{primitives::*, NormedVectorSpace, Vec2, Vec3};
+use crate::{primitives::*, NormedVectorSpace, Quat, Vec2, Vec3};
 use rand::{
     distributions::{Distribution, WeightedIndex},
     Rng,
@@ -199,7 +199,8 @@ impl ShapeSample for Annulus {
         let outer_radius = self.outer_circle.radius;
 
         // Like random sampling for a circle, radius is weighted by the square.
-        let r_squared = rng.gen_range((inner_radius * inner_radius)..(outer_radius * outer_radius));
+        let r_squared =
+            rng.gen_range((inner_radius * inner_radius)..=(outer_radius * outer_radius));
         let r = r_squared.sqrt();
         let theta = rng.gen_range(0.0..TAU);
 
@@ -208,6 +209,9 @@ impl ShapeSample for Annulus {
 
     fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Output {
         let total_perimeter = self.inner_circle.perimeter() + self.outer_circle.perimeter();
+        if total_perimeter <= 0.0 {
+            return Vec2::ZERO;
+        }
         let inner_prob = (self.inner_circle.perimeter() / total_perimeter) as f64;
 
         // Sample from boundary circles, choosing which one by weighting by perimeter:
@@ -245,6 +249,311 @@ impl ShapeSample for Rectangle {
     }
 }
 
+impl ShapeSample for Ellipse {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // Stretching the unit disk scales all areas equally, so the density stays uniform
+        Circle::new(1.0).sample_interior(rng) * self.half_size
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let Vec2 { x: a, y: b } = self.half_size.abs();
+        let max_speed = a.max(b);
+        if max_speed <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        // Stretching the circle doesn't scale its length equally, so the angles are weighted by
+        // the speed of the point along the ellipse
+        loop {
+            let theta = rng.gen_range(0.0..TAU);
+            let (sin, cos) = theta.sin_cos();
+            let speed = Vec2::new(a * sin, b * cos).length();
+            if rng.gen_range(0.0..=max_speed) <= speed {
+                return Vec2::new(a * cos, b * sin);
+            }
+        }
+    }
+}
+
+impl ShapeSample for Arc2d {
+    type Output = Vec2;
+
+    /// Uniformly sample a point along the arc.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let half_angle = self.half_angle.clamp(0.0, PI);
+        let theta = FRAC_PI_2 + rng.gen_range(-half_angle..=half_angle);
+        self.radius * Vec2::from_angle(theta)
+    }
+
+    /// Sample one of the endpoints of the arc.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        if rng.gen() {
+            self.left_endpoint()
+        } else {
+            self.right_endpoint()
+        }
+    }
+}
+
+impl ShapeSample for CircularSector {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // Like random sampling for a circle, radius is weighted by the square.
+        let half_angle = self.arc.half_angle.clamp(0.0, PI);
+        let theta = FRAC_PI_2 + rng.gen_range(-half_angle..=half_angle);
+        let r = self.arc.radius * rng.gen_range(0.0_f32..=1.0).sqrt();
+        r * Vec2::from_angle(theta)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // The boundary is the arc and the two radii to its endpoints
+        let arc_length = self.arc.length().abs();
+        let radii_length = 2.0 * self.arc.radius.abs();
+        if arc_length + radii_length <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        if rng.gen_bool((arc_length / (arc_length + radii_length)) as f64) {
+            self.arc.sample_interior(rng)
+        } else {
+            let endpoint = self.arc.sample_boundary(rng);
+            endpoint * rng.gen_range(0.0_f32..=1.0)
+        }
+    }
+}
+
+impl ShapeSample for CircularSegment {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let radius = self.arc.radius.abs();
+        let bottom = self.apothem();
+        // A major segment is as wide as its circle
+        let half_width = if bottom >= 0.0 {
+            self.arc.half_chord_length().abs()
+        } else {
+            radius
+        };
+        if radius - bottom <= 0.0 || half_width <= 0.0 {
+            return self.chord_midpoint();
+        }
+
+        // Rejection sampling from the bounding box of the segment, which the segment fills for at
+        // least two thirds
+        loop {
+            let point = Vec2::new(
+                rng.gen_range(-half_width..=half_width),
+                rng.gen_range(bottom..=radius),
+            );
+            if point.length_squared() <= radius * radius {
+                return point;
+            }
+        }
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // The boundary is the arc and its chord
+        let arc_length = self.arc.length().abs();
+        let chord_length = self.arc.chord_length().abs();
+        if arc_length + chord_length <= 0.0 {
+            return self.chord_midpoint();
+        }
+
+        if rng.gen_bool((arc_length / (arc_length + chord_length)) as f64) {
+            self.arc.sample_interior(rng)
+        } else {
+            let [left, right] = self.arc.endpoints();
+            left.lerp(right, rng.gen_range(0.0..=1.0))
+        }
+    }
+}
+
+impl ShapeSample for Rhombus {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // The rhombus is a square rotated by 45 degrees and stretched along the axes
+        let s = rng.gen_range(-0.5..=0.5);
+        let t = rng.gen_range(-0.5..=0.5);
+        self.half_diagonals * Vec2::new(s + t, s - t)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // All four sides have the same length, so pick one of them
+        let t = rng.gen_range(0.0..=1.0);
+        let side =
+            Vec2::new(self.half_diagonals.x, 0.0).lerp(Vec2::new(0.0, self.half_diagonals.y), t);
+        let x_sign = if rng.gen() { -1.0 } else { 1.0 };
+        let y_sign = if rng.gen() { -1.0 } else { 1.0 };
+        side * Vec2::new(x_sign, y_sign)
+    }
+}
+
+impl ShapeSample for RegularPolygon {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // The polygon is made of equal triangles between the center and each side
+        let [a, b] = self.random_side(rng);
+        sample_triangle_interior([Vec2::ZERO, a, b], rng)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let [a, b] = self.random_side(rng);
+        a.lerp(b, rng.gen_range(0.0..=1.0))
+    }
+}
+
+impl RegularPolygon {
+    /// The endpoints of a side of the polygon chosen uniformly at random, with the vertices
+    /// placed like [`RegularPolygon::vertices`] with no rotation.
+    fn random_side<R: Rng + ?Sized>(&self, rng: &mut R) -> [Vec2; 2] {
+        let step = TAU / self.sides.max(1) as f32;
+        let side = rng.gen_range(0..self.sides.max(1)) as f32;
+        let radius = self.circumcircle.radius;
+        [
+            radius * Vec2::from_angle(FRAC_PI_2 + side * step),
+            radius * Vec2::from_angle(FRAC_PI_2 + (side + 1.0) * step),
+        ]
+    }
+}
+
+impl ShapeSample for Segment2d {
+    type Output = Vec2;
+
+    /// Uniformly sample a point along the segment.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.point1().lerp(self.point2(), rng.gen_range(0.0..=1.0))
+    }
+
+    /// Sample one of the endpoints of the segment.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        if rng.gen() {
+            self.point1()
+        } else {
+            self.point2()
+        }
+    }
+}
+
+/// Samples a point along the polyline through `vertices`, weighted by the length of its
+/// segments. A `closed` polyline goes back to its first vertex.
+fn sample_polyline<P: NormedVectorSpace, R: Rng + ?Sized>(
+    vertices: &[P],
+    closed: bool,
+    rng: &mut R,
+) -> P {
+    let edge_count = if closed {
+        vertices.len()
+    } else {
+        vertices.len().saturating_sub(1)
+    };
+    let edge = |index: usize| (vertices[index], vertices[(index + 1) % vertices.len()]);
+    if let Ok(dist) = WeightedIndex::new((0..edge_count).map(|index| {
+        let (a, b) = edge(index);
+        (b - a).norm()
+    })) {
+        let (a, b) = edge(dist.sample(rng));
+        a.lerp(b, rng.gen_range(0.0..=1.0))
+    } else {
+        // Without length, the polyline is a single point
+        vertices.first().copied().unwrap_or(P::ZERO)
+    }
+}
+
+/// Samples one of the endpoints of the polyline through `vertices`.
+fn sample_polyline_endpoint<P: NormedVectorSpace, R: Rng + ?Sized>(
+    vertices: &[P],
+    rng: &mut R,
+) -> P {
+    let endpoint = if rng.gen() {
+        vertices.first()
+    } else {
+        vertices.last()
+    };
+    endpoint.copied().unwrap_or(P::ZERO)
+}
+
+/// Interior sampling for polygons, which may be concave, by rejecting the points of their
+/// bounding rectangle that are outside of them.
+fn sample_polygon_interior<R: Rng + ?Sized>(vertices: &[Vec2], rng: &mut R) -> Vec2 {
+    // A polygon whose vertices are all on a line has no interior
+    let Some(&first) = vertices.first() else {
+        return Vec2::ZERO;
+    };
+    if vertices
+        .windows(2)
+        .all(|edge| (edge[0] - first).perp_dot(edge[1] - first) == 0.0)
+    {
+        return sample_polyline(vertices, true, rng);
+    }
+
+    let (min, max) = vertices.iter().fold((first, first), |(min, max), &vertex| {
+        (min.min(vertex), max.max(vertex))
+    });
+    loop {
+        let point = Vec2::new(rng.gen_range(min.x..=max.x), rng.gen_range(min.y..=max.y));
+        if polygon_contains(vertices, point) {
+            return point;
+        }
+    }
+}
+
+impl<const N: usize> ShapeSample for Polygon<N> {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polygon_interior(&self.vertices, rng)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polyline(&self.vertices, true, rng)
+    }
+}
+
+impl ShapeSample for BoxedPolygon {
+    type Output = Vec2;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polygon_interior(&self.vertices, rng)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polyline(&self.vertices, true, rng)
+    }
+}
+
+impl<const N: usize> ShapeSample for Polyline2d<N> {
+    type Output = Vec2;
+
+    /// Uniformly sample a point along the polyline.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polyline(&self.vertices, false, rng)
+    }
+
+    /// Sample one of the endpoints of the polyline.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polyline_endpoint(&self.vertices, rng)
+    }
+}
+
+impl ShapeSample for BoxedPolyline2d {
+    type Output = Vec2;
+
+    /// Uniformly sample a point along the polyline.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polyline(&self.vertices, false, rng)
+    }
+
+    /// Sample one of the endpoints of the polyline.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        sample_polyline_endpoint(&self.vertices, rng)
+    }
+}
+
 impl ShapeSample for Cuboid {
     type Output = Vec3;
 
@@ -447,7 +756,7 @@ impl ShapeSample for Capsule2d {
         if capsule_area > 0.0 {
             // Check if the random point should be inside the rectangle
             if rng.gen_bool((rectangle_area / capsule_area) as f64) {
-                let rectangle = Rectangle::new(self.radius, self.half_length * 2.0);
+                let rectangle = Rectangle::new(self.radius * 2.0, self.half_length * 2.0);
                 rectangle.sample_interior(rng)
             } else {
                 let circle = Circle::new(self.radius);
@@ -542,12 +851,210 @@ impl ShapeSample for Capsule3d {
     }
 }
 
+impl ShapeSample for Segment3d {
+    type Output = Vec3;
+
+    /// Uniformly sample a point along the segment.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        self.point1().lerp(self.point2(), rng.gen_range(0.0..=1.0))
+    }
+
+    /// Sample one of the endpoints of the segment.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        if rng.gen() {
+            self.point1()
+        } else {
+            self.point2()
+        }
+    }
+}
+
+impl<const N: usize> ShapeSample for Polyline3d<N> {
+    type Output = Vec3;
+
+    /// Uniformly sample a point along the polyline.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        sample_polyline(&self.vertices, false, rng)
+    }
+
+    /// Sample one of the endpoints of the polyline.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        sample_polyline_endpoint(&self.vertices, rng)
+    }
+}
+
+impl ShapeSample for BoxedPolyline3d {
+    type Output = Vec3;
+
+    /// Uniformly sample a point along the polyline.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        sample_polyline(&self.vertices, false, rng)
+    }
+
+    /// Sample one of the endpoints of the polyline.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        sample_polyline_endpoint(&self.vertices, rng)
+    }
+}
+
+impl ShapeSample for Plane3d {
+    type Output = Vec3;
+
+    /// Uniformly sample a point of the rectangle of the plane.
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let point = Rectangle::from_size(self.half_size.abs() * 2.0).sample_interior(rng);
+        Quat::from_rotation_arc(Vec3::Y, *self.normal) * Vec3::new(point.x, 0.0, point.y)
+    }
+
+    /// Uniformly sample a point of the edges of the rectangle of the plane.
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let point = Rectangle::from_size(self.half_size.abs() * 2.0).sample_boundary(rng);
+        Quat::from_rotation_arc(Vec3::Y, *self.normal) * Vec3::new(point.x, 0.0, point.y)
+    }
+}
+
+impl ShapeSample for Cone {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // The area of the cross-section grows with the square of the distance from the tip, so
+        // the distance is weighted by the cube.
+        let s = rng.gen_range(0.0_f32..=1.0).cbrt();
+        let Vec2 { x, y: z } = Circle::new(self.radius * s).sample_interior(rng);
+        Vec3::new(x, self.height * (0.5 - s), z)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let base_area = PI * self.radius * self.radius;
+        let lateral_area = PI * self.radius * Vec2::new(self.radius, self.height).length();
+        if base_area + lateral_area <= 0.0 {
+            return Vec3::new(0.0, self.height / 2.0, 0.0);
+        }
+
+        if rng.gen_bool((base_area / (base_area + lateral_area)) as f64) {
+            let Vec2 { x, y: z } = self.base().sample_interior(rng);
+            Vec3::new(x, -self.height / 2.0, z)
+        } else {
+            // The circumference grows linearly with the distance from the tip, so the distance is
+            // weighted by the square.
+            let s = rng.gen_range(0.0_f32..=1.0).sqrt();
+            let Vec2 { x, y: z } = Circle::new(self.radius * s).sample_boundary(rng);
+            Vec3::new(x, self.height * (0.5 - s), z)
+        }
+    }
+}
+
+impl ConicalFrustum {
+    /// Sample a height of the frustum, with a density proportional to `radius.powi(power)` at
+    /// that height, returning the height and the radius there.
+    fn sample_height<R: Rng + ?Sized>(&self, power: i32, rng: &mut R) -> (f32, f32) {
+        let half_height = self.height / 2.0;
+        let max_radius = self.radius_top.max(self.radius_bottom);
+        loop {
+            let y = rng.gen_range(-half_height..=half_height);
+            let t = if self.height > 0.0 {
+                y / self.height + 0.5
+            } else {
+                0.5
+            };
+            let radius = self.radius_bottom + (self.radius_top - self.radius_bottom) * t;
+            if max_radius <= 0.0
+                || rng.gen_range(0.0_f32..=1.0) <= (radius / max_radius).powi(power)
+            {
+                return (y, radius);
+            }
+        }
+    }
+}
+
+impl ShapeSample for ConicalFrustum {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // The cross-section at each height is a disk whose area grows with the square of its radius
+        let (y, radius) = self.sample_height(2, rng);
+        let Vec2 { x, y: z } = Circle::new(radius).sample_interior(rng);
+        Vec3::new(x, y, z)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let slant_height = Vec2::new(self.radius_top - self.radius_bottom, self.height).length();
+        let Ok(dist) = WeightedIndex::new([
+            PI * self.radius_top * self.radius_top,
+            PI * self.radius_bottom * self.radius_bottom,
+            PI * (self.radius_top + self.radius_bottom) * slant_height,
+        ]) else {
+            return Vec3::ZERO;
+        };
+
+        let half_height = self.height / 2.0;
+        match dist.sample(rng) {
+            0 => {
+                let Vec2 { x, y: z } = Circle::new(self.radius_top).sample_interior(rng);
+                Vec3::new(x, half_height, z)
+            }
+            1 => {
+                let Vec2 { x, y: z } = Circle::new(self.radius_bottom).sample_interior(rng);
+                Vec3::new(x, -half_height, z)
+            }
+            2 => {
+                // The circumference at each height grows linearly with its radius
+                let (y, radius) = self.sample_height(1, rng);
+                let Vec2 { x, y: z } = Circle::new(radius).sample_boundary(rng);
+                Vec3::new(x, y, z)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl ShapeSample for Torus {
+    type Output = Vec3;
+
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let max_radius = self.outer_radius().abs();
+        if max_radius <= 0.0 {
+            return Vec3::ZERO;
+        }
+
+        // Revolving a cross-section of the tube around the y-axis sweeps a volume proportional to
+        // its distance from the axis, so points of the cross-section are weighted by it.
+        let tube = Circle::new(self.minor_radius);
+        loop {
+            let point = tube.sample_interior(rng);
+            let radial = self.major_radius + point.x;
+            if rng.gen_range(0.0..=max_radius) <= radial.abs() {
+                let theta = rng.gen_range(0.0..TAU);
+                return Vec3::new(radial * theta.cos(), point.y, radial * theta.sin());
+            }
+        }
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let max_radius = self.outer_radius().abs();
+        if max_radius <= 0.0 {
+            return Vec3::ZERO;
+        }
+
+        // Like the interior, points of the tube's circle are weighted by their distance to the axis
+        let tube = Circle::new(self.minor_radius);
+        loop {
+            let point = tube.sample_boundary(rng);
+            let radial = self.major_radius + point.x;
+            if rng.gen_range(0.0..=max_radius) <= radial.abs() {
+                let theta = rng.gen_range(0.0..TAU);
+                return Vec3::new(radial * theta.cos(), point.y, radial * theta.sin());
+            }
+        }
+    }
+}
+
 impl<P: Primitive2d + Measured2d + ShapeSample<Output = Vec2>> ShapeSample for Extrusion<P> {
     type Output = Vec3;
 
     fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::Output {
         let base_point = self.base_shape.sample_interior(rng);
-        let depth = rng.gen_range(-self.half_depth..self.half_depth);
+        let depth = rng.gen_range(-self.half_depth..=self.half_depth);
         base_point.extend(depth)
     }
 
@@ -555,7 +1062,7 @@ impl<P: Primitive2d + Measured2d + ShapeSample<Output = Vec2>> ShapeSample for E
         let base_area = self.base_shape.area();
         let total_area = self.area();
 
-        let random = rng.gen_range(0.0..total_area);
+        let random = rng.gen_range(0.0..=total_area);
         match random {
             x if x < base_area => self.base_shape.sample_interior(rng).extend(self.half_depth),
             x if x < 2. * base_area => self
@@ -565,7 +1072,7 @@ impl<P: Primitive2d + Measured2d + ShapeSample<Output = Vec2>> ShapeSample for E
             _ => self
                 .base_shape
                 .sample_boundary(rng)
-                .extend(rng.gen_range(-self.half_depth..self.half_depth)),
+                .extend(rng.gen_range(-self.half_depth..=self.half_depth)),
         }
     }
 }
@@ -573,6 +1080,7 @@ impl<P: Primitive2d + Measured2d + ShapeSample<Output = Vec2>> ShapeSample for E
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Dir2, Vec3Swizzles};
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
@@ -635,4 +1143,395 @@ mod tests {
             "samples will occur across all array items at statistically equal chance"
         );
     }
+
+    /// The fraction of interior samples of `shape` inside a copy of it scaled by `scale` around
+    /// `center`, which should be `scale.powi(2)` for a uniform density.
+    fn scaled_fraction_2d(
+        shape: impl ShapeSample<Output = Vec2> + SignedDistance2d,
+        center: Vec2,
+        scale: f32,
+    ) -> f32 {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let hits = (0..10000)
+            .map(|_| shape.sample_interior(&mut rng))
+            .filter(|&point| shape.signed_distance(center + (point - center) / scale) <= 0.0)
+            .count();
+        hits as f32 / 10000.0
+    }
+
+    /// The fraction of interior samples of `shape` inside a copy of it scaled by `scale` around
+    /// `center`, which should be `scale.powi(3)` for a uniform density.
+    fn scaled_fraction_3d(
+        shape: impl ShapeSample<Output = Vec3> + SignedDistance3d,
+        center: Vec3,
+        scale: f32,
+    ) -> f32 {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let hits = (0..10000)
+            .map(|_| shape.sample_interior(&mut rng))
+            .filter(|&point| shape.signed_distance(center + (point - center) / scale) <= 0.0)
+            .count();
+        hits as f32 / 10000.0
+    }
+
+    #[test]
+    fn interior_sampling_is_uniform_2d() {
+        let segment = CircularSegment::new(2.0, 1.0);
+        let l_shape = BoxedPolygon::new([
+            Vec2::ZERO,
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 4.0),
+            Vec2::new(0.0, 4.0),
+        ]);
+        let cases: [(&str, f32); 9] = [
+            (
+                "ellipse",
+                scaled_fraction_2d(Ellipse::new(3.0, 1.0), Vec2::ZERO, 0.5),
+            ),
+            (
+                "rectangle",
+                scaled_fraction_2d(Rectangle::new(3.0, 1.0), Vec2::ZERO, 0.5),
+            ),
+            (
+                "rhombus",
+                scaled_fraction_2d(Rhombus::new(3.0, 1.0), Vec2::ZERO, 0.5),
+            ),
+            (
+                "polygon",
+                scaled_fraction_2d(RegularPolygon::new(2.0, 5), Vec2::ZERO, 0.5),
+            ),
+            (
+                "capsule",
+                scaled_fraction_2d(Capsule2d::new(1.0, 3.0), Vec2::ZERO, 0.5),
+            ),
+            (
+                "annulus",
+                scaled_fraction_2d(Annulus::new(0.0, 2.0), Vec2::ZERO, 0.5),
+            ),
+            (
+                "triangle",
+                scaled_fraction_2d(
+                    Triangle2d::new(Vec2::ZERO, Vec2::new(3.0, 0.0), Vec2::new(0.0, 1.0)),
+                    Vec2::new(0.5, 0.2),
+                    0.5,
+                ),
+            ),
+            (
+                "circular segment",
+                scaled_fraction_2d(
+                    segment,
+                    (segment.chord_midpoint() + Vec2::Y * 2.0) / 2.0,
+                    0.5,
+                ),
+            ),
+            (
+                "concave polygon",
+                scaled_fraction_2d(l_shape, Vec2::ZERO, 0.5),
+            ),
+        ];
+        for (name, fraction) in cases {
+            assert!(
+                (fraction - 0.25).abs() < 0.02,
+                "{name}: {fraction} of the samples are in a quarter of the area"
+            );
+        }
+    }
+
+    #[test]
+    fn circular_sector_interior_sampling() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let sector = CircularSector::new(2.0, 1.0);
+        let mut inner = 0;
+        for _ in 0..10000 {
+            let point = sector.sample_interior(&mut rng);
+            assert!(point.length() <= 2.0 + 1e-5);
+            assert!(Vec2::Y.angle_between(point).abs() <= 1.0 + 1e-5);
+            if point.length() <= 1.0 {
+                inner += 1;
+            }
+        }
+        let fraction = inner as f32 / 10000.0;
+        assert!((fraction - 0.25).abs() < 0.02, "{fraction}");
+    }
+
+    #[test]
+    fn interior_sampling_is_uniform_3d() {
+        let cases: [(&str, f32); 5] = [
+            (
+                "cone",
+                scaled_fraction_3d(
+                    Cone {
+                        radius: 1.0,
+                        height: 2.0,
+                    },
+                    Vec3::new(0.0, -0.5, 0.0),
+                    0.5,
+                ),
+            ),
+            (
+                "frustum",
+                scaled_fraction_3d(
+                    ConicalFrustum {
+                        radius_top: 0.5,
+                        radius_bottom: 2.0,
+                        height: 1.0,
+                    },
+                    Vec3::ZERO,
+                    0.5,
+                ),
+            ),
+            (
+                "cylinder",
+                scaled_fraction_3d(Cylinder::new(1.0, 3.0), Vec3::ZERO, 0.5),
+            ),
+            (
+                "capsule",
+                scaled_fraction_3d(Capsule3d::new(1.0, 3.0), Vec3::ZERO, 0.5),
+            ),
+            (
+                "cuboid",
+                scaled_fraction_3d(Cuboid::new(1.0, 2.0, 3.0), Vec3::ZERO, 0.5),
+            ),
+        ];
+        for (name, fraction) in cases {
+            assert!(
+                (fraction - 0.125).abs() < 0.02,
+                "{name}: {fraction} of the samples are in an eighth of the volume"
+            );
+        }
+    }
+
+    #[test]
+    fn torus_sampling_is_weighted_by_radius() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let torus = Torus {
+            minor_radius: 1.0,
+            major_radius: 2.0,
+        };
+
+        // By Pappus's theorem, the inner half of the tube has a smaller share of the volume
+        let expected = (2.0 - 4.0 / (3.0 * PI)) / 4.0;
+        let mut inner = 0;
+        for _ in 0..10000 {
+            let point = torus.sample_interior(&mut rng);
+            assert!(torus.signed_distance(point) <= 1e-5);
+            if point.xz().length() < 2.0 {
+                inner += 1;
+            }
+        }
+        let fraction = inner as f32 / 10000.0;
+        assert!(
+            (fraction - expected).abs() < 0.02,
+            "{fraction} != {expected}"
+        );
+
+        // And the inner half of the surface is smaller too
+        let expected = (2.0 - 2.0 / PI) / 4.0;
+        let mut inner = 0;
+        for _ in 0..10000 {
+            let point = torus.sample_boundary(&mut rng);
+            assert!(torus.signed_distance(point).abs() <= 1e-5);
+            if point.xz().length() < 2.0 {
+                inner += 1;
+            }
+        }
+        let fraction = inner as f32 / 10000.0;
+        assert!(
+            (fraction - expected).abs() < 0.02,
+            "{fraction} != {expected}"
+        );
+    }
+
+    #[test]
+    fn boundary_samples_are_on_the_boundary() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let shapes_2d: [&dyn Fn(&mut ChaCha8Rng) -> f32; 8] = [
+            &|rng| {
+                let ellipse = Ellipse::new(3.0, 1.0);
+                ellipse.signed_distance(ellipse.sample_boundary(rng))
+            },
+            &|rng| {
+                let rhombus = Rhombus::new(3.0, 1.0);
+                rhombus.signed_distance(rhombus.sample_boundary(rng))
+            },
+            &|rng| {
+                let polygon = RegularPolygon::new(2.0, 7);
+                polygon.signed_distance(polygon.sample_boundary(rng))
+            },
+            &|rng| {
+                let capsule = Capsule2d::new(1.0, 3.0);
+                capsule.signed_distance(capsule.sample_boundary(rng))
+            },
+            &|rng| {
+                let annulus = Annulus::new(1.0, 2.0);
+                annulus.signed_distance(annulus.sample_boundary(rng))
+            },
+            &|rng| {
+                let sector = CircularSector::new(2.0, 1.0);
+                let point = sector.sample_boundary(rng);
+                // On the arc or on the line of one of the radii
+                let [left, right] = sector.arc.endpoints();
+                (point.length() - 2.0)
+                    .abs()
+                    .min(point.perp_dot(left).abs() / 2.0)
+                    .min(point.perp_dot(right).abs() / 2.0)
+            },
+            &|rng| {
+                let segment = CircularSegment::new(2.0, 1.0);
+                let point = segment.sample_boundary(rng);
+                // On the arc or the chord
+                (point.length() - 2.0)
+                    .abs()
+                    .min((point.y - segment.apothem()).abs())
+            },
+            &|rng| {
+                let polygon = Polygon::<5>::new([
+                    Vec2::ZERO,
+                    Vec2::new(2.0, 0.0),
+                    Vec2::new(1.0, 0.5),
+                    Vec2::new(2.0, 2.0),
+                    Vec2::new(0.0, 2.0),
+                ]);
+                polygon.signed_distance(polygon.sample_boundary(rng))
+            },
+        ];
+        for (index, signed_distance) in shapes_2d.iter().enumerate() {
+            for _ in 0..1000 {
+                let distance = signed_distance(&mut rng);
+                assert!(distance.abs() < 1e-4, "2D shape {index}: {distance}");
+            }
+        }
+
+        let cone = Cone {
+            radius: 1.0,
+            height: 2.0,
+        };
+        let frustum = ConicalFrustum {
+            radius_top: 0.5,
+            radius_bottom: 2.0,
+            height: 1.0,
+        };
+        for _ in 0..1000 {
+            let distance = cone.signed_distance(cone.sample_boundary(&mut rng));
+            assert!(distance.abs() < 1e-4, "cone: {distance}");
+            let distance = frustum.signed_distance(frustum.sample_boundary(&mut rng));
+            assert!(distance.abs() < 1e-4, "frustum: {distance}");
+        }
+
+        // The boundary of a plane is the edges of its rectangle
+        let plane = Plane3d::new(Vec3::new(1.0, 1.0, 0.0), Vec2::new(1.0, 2.0));
+        let rectangle = Rectangle::new(2.0, 4.0);
+        let rotation = Quat::from_rotation_arc(Vec3::Y, *plane.normal);
+        for _ in 0..1000 {
+            let point = rotation.inverse() * plane.sample_boundary(&mut rng);
+            assert!(point.y.abs() < 1e-4, "plane: {point}");
+            let distance = rectangle.signed_distance(point.xz());
+            assert!(distance.abs() < 1e-4, "plane: {distance}");
+        }
+    }
+
+    #[test]
+    fn boundary_sampling_is_weighted_by_length_and_area() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        // The length along the ellipse isn't proportional to the angle: the part of the boundary
+        // with |x| < 1.5 is about 0.4514 of the perimeter
+        let ellipse = Ellipse::new(3.0, 1.0);
+        let fraction = (0..10000)
+            .filter(|_| ellipse.sample_boundary(&mut rng).x.abs() < 1.5)
+            .count() as f32
+            / 10000.0;
+        assert!((fraction - 0.4514).abs() < 0.02, "ellipse: {fraction}");
+
+        // The base of the cone has an area of pi, and its lateral surface of pi * sqrt(2)
+        let cone = Cone {
+            radius: 1.0,
+            height: 1.0,
+        };
+        let fraction = (0..10000)
+            .filter(|_| cone.sample_boundary(&mut rng).y == -0.5)
+            .count() as f32
+            / 10000.0;
+        let expected = 1.0 / (1.0 + 2.0_f32.sqrt());
+        assert!((fraction - expected).abs() < 0.02, "cone: {fraction}");
+
+        // The sides of the capsule are 4 long, and its ends 2 * pi
+        let capsule = Capsule2d::new(1.0, 2.0);
+        let fraction = (0..10000)
+            .filter(|_| capsule.sample_boundary(&mut rng).y.abs() < 1.0)
+            .count() as f32
+            / 10000.0;
+        let expected = 4.0 / (4.0 + TAU);
+        assert!((fraction - expected).abs() < 0.02, "capsule: {fraction}");
+    }
+
+    #[test]
+    fn degenerate_shapes_sample_finite_points() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        for _ in 0..100 {
+            let points_2d = [
+                Circle::new(0.0).sample_interior(&mut rng),
+                Circle::new(0.0).sample_boundary(&mut rng),
+                Rectangle::new(0.0, 0.0).sample_interior(&mut rng),
+                Rectangle::new(0.0, 0.0).sample_boundary(&mut rng),
+                Rectangle::new(2.0, 0.0).sample_boundary(&mut rng),
+                Annulus::new(1.0, 1.0).sample_interior(&mut rng),
+                Annulus::new(0.0, 0.0).sample_boundary(&mut rng),
+                Ellipse::new(0.0, 0.0).sample_boundary(&mut rng),
+                Ellipse::new(2.0, 0.0).sample_boundary(&mut rng),
+                Capsule2d::new(0.0, 0.0).sample_interior(&mut rng),
+                Capsule2d::new(0.0, 0.0).sample_boundary(&mut rng),
+                Rhombus::new(0.0, 0.0).sample_interior(&mut rng),
+                RegularPolygon::new(0.0, 3).sample_interior(&mut rng),
+                CircularSector::new(0.0, 1.0).sample_boundary(&mut rng),
+                CircularSector::new(1.0, 0.0).sample_interior(&mut rng),
+                CircularSegment::new(1.0, 0.0).sample_interior(&mut rng),
+                CircularSegment::new(0.0, 1.0).sample_boundary(&mut rng),
+                Segment2d::new(Dir2::X, 0.0).sample_interior(&mut rng),
+                BoxedPolygon::new([Vec2::ZERO, Vec2::X, Vec2::X * 2.0]).sample_interior(&mut rng),
+                BoxedPolygon::new(Vec::<Vec2>::new()).sample_boundary(&mut rng),
+                BoxedPolyline2d::new(Vec::<Vec2>::new()).sample_interior(&mut rng),
+                Polyline2d::<2>::new([Vec2::X, Vec2::X]).sample_interior(&mut rng),
+            ];
+            for point in points_2d {
+                assert!(point.is_finite(), "{point}");
+            }
+
+            let points_3d = [
+                Sphere::new(0.0).sample_boundary(&mut rng),
+                Cuboid::new(0.0, 0.0, 0.0).sample_boundary(&mut rng),
+                Cylinder::new(0.0, 0.0).sample_boundary(&mut rng),
+                Capsule3d::new(0.0, 0.0).sample_boundary(&mut rng),
+                Cone {
+                    radius: 0.0,
+                    height: 1.0,
+                }
+                .sample_boundary(&mut rng),
+                ConicalFrustum {
+                    radius_top: 0.0,
+                    radius_bottom: 0.0,
+                    height: 0.0,
+                }
+                .sample_interior(&mut rng),
+                ConicalFrustum {
+                    radius_top: 0.0,
+                    radius_bottom: 0.0,
+                    height: 0.0,
+                }
+                .sample_boundary(&mut rng),
+                Torus::new(0.0, 0.0).sample_interior(&mut rng),
+                Torus::new(0.0, 0.0).sample_boundary(&mut rng),
+                Extrusion::new(Rectangle::new(0.0, 0.0), 0.0).sample_interior(&mut rng),
+                Extrusion::new(Rectangle::new(0.0, 0.0), 0.0).sample_boundary(&mut rng),
+                Plane3d::new(Vec3::Y, Vec2::ZERO).sample_interior(&mut rng),
+                Plane3d::new(Vec3::Y, Vec2::ZERO).sample_boundary(&mut rng),
+                BoxedPolyline3d::new(Vec::<Vec3>::new()).sample_boundary(&mut rng),
+            ];
+            for point in points_3d {
+                assert!(point.is_finite(), "{point}");
+            }
+        }
+    }
 }
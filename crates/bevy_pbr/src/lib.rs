@@ -69,6 +69,7 @@ pub mod prelude {
         light::{light_consts, AmbientLight, DirectionalLight, PointLight, SpotLight},
         light_probe::{
             environment_map::{EnvironmentMapLight, ReflectionProbeBundle},
+            environment_map_processor::EquirectEnvironmentMap,
             LightProbe,
         },
         material::{Material, MaterialPlugin},
@@ -0,0 +1,807 @@
+//! Converting equirectangular images into environment maps at runtime.
+//!
+//! An [`EnvironmentMapLight`] needs a pair of prefiltered cubemaps (see
+//! [`crate::environment_map`]), but the surroundings of a scene are often
+//! available as a single equirectangular HDR image instead. An
+//! [`EquirectEnvironmentMap`] converts such an image into the cubemaps of an
+//! [`EnvironmentMapLight`], which is inserted on its entity once the image has
+//! loaded and been converted:
+//!
+//! ```no_run
+//! # use bevy_asset::AssetServer;
+//! # use bevy_core_pipeline::core_3d::Camera3dBundle;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_pbr::environment_map::EnvironmentMapLight;
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.spawn((
+//!         Camera3dBundle::default(),
+//!         EnvironmentMapLight::from_equirect(asset_server.load("sky.hdr"), 1000.0),
+//!     ));
+//! }
+//! ```
+//!
+//! The specular cubemap is prefiltered for the GGX distribution at each of its
+//! mip levels, and the diffuse cubemap holds the Lambertian irradiance,
+//! computed from the spherical harmonics of the image.
+//!
+//! The conversion runs on the CPU, in the [`AsyncComputeTaskPool`], so it
+//! works on every platform, including WebGL2, which has no compute shaders.
+//! Without threads, such as on the web, it runs in the frame where the image
+//! has loaded, which can cause a hitch for large images; prefiltered cubemaps
+//! avoid it.
+//!
+//! The texels of float images are linear, as in HDR and EXR files, while 8-bit
+//! images are decoded from sRGB if their format is sRGB. Images that aren't
+//! kept in the main world, or with other formats, such as compressed ones,
+//! can't be converted, and are reported with an
+//! [`EnvironmentMapProcessorError`].
+//!
+//! The converted cubemaps are cached in the [`EnvironmentMapProcessor`],
+//! keyed by the source image, so that the entities sharing an image share its
+//! cubemaps. They are converted again when the image is modified, and dropped
+//! once the image is unused.
+//!
+//! [`AsyncComputeTaskPool`]: bevy_tasks::AsyncComputeTaskPool
+
+use std::f32::consts::{PI, TAU};
+
+use bevy_asset::{AssetEvent, AssetId, AssetServer, Assets, Handle, LoadState};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EventReader,
+    reflect::ReflectComponent,
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_math::{Vec2, Vec3};
+use bevy_reflect::Reflect;
+use bevy_render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{
+        Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension,
+    },
+    texture::{Image, ImageSampler},
+};
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+use bevy_tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+use bevy_utils::{tracing::error, HashMap, HashSet};
+use thiserror::Error;
+
+use crate::environment_map::EnvironmentMapLight;
+
+/// Converts an equirectangular image into the [`EnvironmentMapLight`] of its
+/// entity.
+///
+/// The [`EnvironmentMapLight`] is inserted once the image has loaded and been
+/// converted, replacing the previous one, if any, which can serve as a
+/// placeholder until then.
+///
+/// See [`crate::environment_map_processor`] for detailed information.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct EquirectEnvironmentMap {
+    /// The equirectangular image, whose center faces -Z and whose top faces +Y.
+    pub image: Handle<Image>,
+
+    /// The intensity of the [`EnvironmentMapLight`].
+    ///
+    /// See [`EnvironmentMapLight::intensity`].
+    pub intensity: f32,
+}
+
+impl EnvironmentMapLight {
+    /// Returns an [`EquirectEnvironmentMap`], which inserts the
+    /// [`EnvironmentMapLight`] converted from the equirectangular `image` on
+    /// its entity once it is ready.
+    pub fn from_equirect(image: Handle<Image>, intensity: f32) -> EquirectEnvironmentMap {
+        EquirectEnvironmentMap { image, intensity }
+    }
+}
+
+/// An error converting an equirectangular image into an environment map.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EnvironmentMapProcessorError {
+    /// The data of the image isn't kept in the main world.
+    #[error("the image has no data in the main world, as its `RenderAssetUsages` don't include `MAIN_WORLD`")]
+    NoData,
+    /// The image isn't a single 2D image.
+    #[error("the image isn't a single 2D image")]
+    NotEquirect,
+    /// The format of the image can't be converted.
+    #[error("the image format {0:?} can't be converted, only `Rgba32Float`, `Rgba16Float`, `Rgb9e5Ufloat`, `Rgba8Unorm` and `Rgba8UnormSrgb` can; prefilter the environment map ahead of time instead")]
+    UnsupportedFormat(TextureFormat),
+}
+
+/// The settings of the conversion of equirectangular images into environment
+/// maps.
+#[derive(Clone, Copy, Debug)]
+pub struct EnvironmentMapProcessorSettings {
+    /// The size of the faces of the specular cubemap, rounded up to a power of
+    /// two. Its mip levels go down to a single texel.
+    pub specular_size: u32,
+
+    /// The size of the faces of the diffuse cubemap.
+    pub diffuse_size: u32,
+
+    /// The number of samples of the image averaged for each texel of the
+    /// prefiltered specular mip levels.
+    pub sample_count: u32,
+}
+
+impl Default for EnvironmentMapProcessorSettings {
+    fn default() -> Self {
+        Self {
+            specular_size: 256,
+            diffuse_size: 32,
+            sample_count: 64,
+        }
+    }
+}
+
+/// Converts the images of [`EquirectEnvironmentMap`]s into environment maps,
+/// caching the cubemaps of each image.
+///
+/// See [`crate::environment_map_processor`] for detailed information.
+#[derive(Resource, Default)]
+pub struct EnvironmentMapProcessor {
+    /// The settings of the conversions.
+    ///
+    /// Changing them doesn't convert the images already converted again.
+    pub settings: EnvironmentMapProcessorSettings,
+    cache: HashMap<AssetId<Image>, CachedEnvironmentMap>,
+}
+
+impl EnvironmentMapProcessor {
+    /// Returns `true` if the equirectangular `image` has been converted into
+    /// an environment map.
+    pub fn is_ready(&self, image: impl Into<AssetId<Image>>) -> bool {
+        self.cache
+            .get(&image.into())
+            .is_some_and(|cached| matches!(cached.state, ConversionState::Ready))
+    }
+}
+
+/// The cubemaps converted from an equirectangular image.
+struct CachedEnvironmentMap {
+    diffuse_map: Handle<Image>,
+    specular_map: Handle<Image>,
+    state: ConversionState,
+}
+
+type ConversionResult = Result<ConvertedEnvironmentMap, EnvironmentMapProcessorError>;
+
+enum ConversionState {
+    /// Waiting for the image to load.
+    Waiting,
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    Converting(Task<ConversionResult>),
+    Ready,
+    Failed,
+}
+
+impl CachedEnvironmentMap {
+    /// Starts converting `image` in the background, returning `None`.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    fn start(
+        &mut self,
+        image: Image,
+        settings: EnvironmentMapProcessorSettings,
+    ) -> Option<ConversionResult> {
+        self.state = ConversionState::Converting(
+            AsyncComputeTaskPool::get().spawn(async move { convert_equirect(&image, settings) }),
+        );
+        None
+    }
+
+    /// Converts `image` right away, as there are no threads to convert it in
+    /// the background.
+    #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
+    fn start(
+        &mut self,
+        image: Image,
+        settings: EnvironmentMapProcessorSettings,
+    ) -> Option<ConversionResult> {
+        Some(convert_equirect(&image, settings))
+    }
+
+    fn finish(&mut self, id: AssetId<Image>, result: ConversionResult, images: &mut Assets<Image>) {
+        match result {
+            Ok(converted) => {
+                images.insert(self.diffuse_map.id(), converted.diffuse_map);
+                images.insert(self.specular_map.id(), converted.specular_map);
+                self.state = ConversionState::Ready;
+            }
+            Err(err) => {
+                error!("Failed to convert the equirectangular image {id:?} into an environment map: {err}");
+                self.state = ConversionState::Failed;
+            }
+        }
+    }
+}
+
+/// Converts the images of the [`EquirectEnvironmentMap`]s, and inserts the
+/// [`EnvironmentMapLight`]s of their entities once they are converted.
+///
+/// This runs after the loaded assets are added, so that the images loaded
+/// this frame are converted before the images only kept in the render world
+/// are moved there.
+pub fn process_equirect_environment_maps(
+    mut commands: Commands,
+    mut processor: ResMut<EnvironmentMapProcessor>,
+    mut images: ResMut<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    mut image_events: EventReader<AssetEvent<Image>>,
+    environment_maps: Query<(
+        Entity,
+        &EquirectEnvironmentMap,
+        Option<&EnvironmentMapLight>,
+    )>,
+) {
+    let processor = &mut *processor;
+    // The images added or modified since the last run, which have data unless
+    // they were moved to the render world
+    let mut added = HashSet::new();
+    for event in image_events.read() {
+        match *event {
+            AssetEvent::Added { id } => {
+                added.insert(id);
+            }
+            AssetEvent::Modified { id } => {
+                added.insert(id);
+                // Dropping the task of an ongoing conversion cancels it
+                if let Some(cached) = processor.cache.get_mut(&id) {
+                    cached.state = ConversionState::Waiting;
+                }
+            }
+            AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+                processor.cache.remove(&id);
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    for (&id, cached) in &mut processor.cache {
+        let ConversionState::Converting(task) = &mut cached.state else {
+            continue;
+        };
+        if !task.is_finished() {
+            continue;
+        }
+        if let Some(result) = block_on(poll_once(task)) {
+            cached.finish(id, result, &mut images);
+        }
+    }
+
+    for (entity, environment_map, environment_map_light) in &environment_maps {
+        let id = environment_map.image.id();
+        let cached = processor
+            .cache
+            .entry(id)
+            .or_insert_with(|| CachedEnvironmentMap {
+                diffuse_map: images.reserve_handle(),
+                specular_map: images.reserve_handle(),
+                state: ConversionState::Waiting,
+            });
+        if matches!(cached.state, ConversionState::Waiting) {
+            if let Some(image) = images.get(id).cloned() {
+                if let Some(result) = cached.start(image, processor.settings) {
+                    cached.finish(id, result, &mut images);
+                }
+            } else if added.contains(&id) || asset_server.load_state(id) == LoadState::Loaded {
+                // The image was loaded, but only kept in the render world
+                cached.finish(id, Err(EnvironmentMapProcessorError::NoData), &mut images);
+            }
+        }
+
+        if !matches!(cached.state, ConversionState::Ready) {
+            continue;
+        }
+        let up_to_date = environment_map_light.is_some_and(|light| {
+            light.diffuse_map == cached.diffuse_map
+                && light.specular_map == cached.specular_map
+                && light.intensity == environment_map.intensity
+        });
+        if !up_to_date {
+            commands.entity(entity).insert(EnvironmentMapLight {
+                diffuse_map: cached.diffuse_map.clone(),
+                specular_map: cached.specular_map.clone(),
+                intensity: environment_map.intensity,
+            });
+        }
+    }
+}
+
+/// The diffuse and specular cubemaps converted from an equirectangular image.
+pub struct ConvertedEnvironmentMap {
+    /// The cubemap of the Lambertian irradiance.
+    pub diffuse_map: Image,
+    /// The mipmapped cubemap of the radiance prefiltered for the GGX
+    /// distribution, with a perceptual roughness going from 0 at its first
+    /// mip level to 1 at its last one.
+    pub specular_map: Image,
+}
+
+/// Converts an equirectangular `image` into the cubemaps of an
+/// [`EnvironmentMapLight`].
+///
+/// This is what an [`EquirectEnvironmentMap`] runs in the background, which
+/// can be used directly to convert images ahead of time, such as in an asset
+/// processor.
+pub fn convert_equirect(
+    image: &Image,
+    settings: EnvironmentMapProcessorSettings,
+) -> Result<ConvertedEnvironmentMap, EnvironmentMapProcessorError> {
+    let equirect = Equirect::new(decode_equirect(image)?);
+
+    let specular_size = settings.specular_size.max(1).next_power_of_two();
+    let mip_level_count = specular_size.ilog2() + 1;
+    let mut specular_data = vec![];
+    for face in 0..6 {
+        for mip_level in 0..mip_level_count {
+            let size = specular_size >> mip_level;
+            if mip_level == 0 {
+                render_face(face, size, &mut specular_data, |direction, solid_angle| {
+                    equirect.sample(direction, equirect.lod(solid_angle))
+                });
+                continue;
+            }
+            let roughness = mip_level as f32 / (mip_level_count - 1) as f32;
+            let samples = equirect.ggx_samples(roughness, settings.sample_count.max(1));
+            render_face(face, size, &mut specular_data, |direction, _| {
+                equirect.prefilter(direction, &samples)
+            });
+        }
+    }
+
+    let irradiance = equirect.irradiance();
+    let diffuse_size = settings.diffuse_size.max(1);
+    let mut diffuse_data = vec![];
+    for face in 0..6 {
+        render_face(face, diffuse_size, &mut diffuse_data, |direction, _| {
+            irradiance.evaluate(direction)
+        });
+    }
+
+    Ok(ConvertedEnvironmentMap {
+        diffuse_map: cubemap(diffuse_size, 1, diffuse_data),
+        specular_map: cubemap(specular_size, mip_level_count, specular_data),
+    })
+}
+
+/// Creates a cubemap from its texels, one face after the other, with all the
+/// mip levels of a face one after the other.
+fn cubemap(size: u32, mip_level_count: u32, data: Vec<u8>) -> Image {
+    let mut image = Image {
+        data,
+        sampler: ImageSampler::linear(),
+        texture_view_descriptor: Some(TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::Cube),
+            ..Default::default()
+        }),
+        asset_usage: RenderAssetUsages::RENDER_WORLD,
+        ..Default::default()
+    };
+    image.texture_descriptor.size = Extent3d {
+        width: size,
+        height: size,
+        depth_or_array_layers: 6,
+    };
+    image.texture_descriptor.dimension = TextureDimension::D2;
+    image.texture_descriptor.format = TextureFormat::Rgb9e5Ufloat;
+    image.texture_descriptor.mip_level_count = mip_level_count;
+    image
+}
+
+/// Appends the texels of a `face` of a cubemap of `size` to `data`, calling
+/// `color` with the direction of each texel and the solid angle it covers.
+fn render_face(face: u32, size: u32, data: &mut Vec<u8>, color: impl Fn(Vec3, f32) -> Vec3) {
+    let texel_size = 2.0 / size as f32;
+    for y in 0..size {
+        for x in 0..size {
+            let s = (x as f32 + 0.5) * texel_size - 1.0;
+            let t = (y as f32 + 0.5) * texel_size - 1.0;
+            let solid_angle = texel_size * texel_size / (1.0 + s * s + t * t).powf(1.5);
+            let texel = encode_rgb9e5(color(cube_direction(face, s, t), solid_angle));
+            data.extend_from_slice(&texel.to_le_bytes());
+        }
+    }
+}
+
+/// Returns the direction of the point at `s`, `t`, both between -1 and 1, on
+/// the `face` of a cubemap.
+fn cube_direction(face: u32, s: f32, t: f32) -> Vec3 {
+    let direction = match face {
+        0 => Vec3::new(1.0, -t, -s),
+        1 => Vec3::new(-1.0, -t, s),
+        2 => Vec3::new(s, 1.0, t),
+        3 => Vec3::new(s, -1.0, -t),
+        4 => Vec3::new(s, -t, 1.0),
+        _ => Vec3::new(-s, -t, -1.0),
+    };
+    // The PBR shader samples cubemaps with the Z axis flipped
+    Vec3::new(direction.x, direction.y, -direction.z).normalize()
+}
+
+/// Returns the coordinates of `direction` in an equirectangular image.
+fn equirect_uv(direction: Vec3) -> Vec2 {
+    Vec2::new(
+        0.5 + direction.x.atan2(-direction.z) / TAU,
+        direction.y.clamp(-1.0, 1.0).acos() / PI,
+    )
+}
+
+/// Decodes the linear colors of the texels of an equirectangular image.
+fn decode_equirect(image: &Image) -> Result<EquirectLevel, EnvironmentMapProcessorError> {
+    let descriptor = &image.texture_descriptor;
+    if descriptor.dimension != TextureDimension::D2 || descriptor.size.depth_or_array_layers != 1 {
+        return Err(EnvironmentMapProcessorError::NotEquirect);
+    }
+    let format = descriptor.format;
+    let texel_size = match format {
+        TextureFormat::Rgba32Float => 16,
+        TextureFormat::Rgba16Float => 8,
+        TextureFormat::Rgb9e5Ufloat | TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {
+            4
+        }
+        _ => return Err(EnvironmentMapProcessorError::UnsupportedFormat(format)),
+    };
+    let width = descriptor.size.width as usize;
+    let height = descriptor.size.height as usize;
+    // Only the first mip level is read
+    if width == 0 || height == 0 || image.data.len() < width * height * texel_size {
+        return Err(EnvironmentMapProcessorError::NoData);
+    }
+    let texels = image
+        .data
+        .chunks_exact(texel_size)
+        .take(width * height)
+        .map(|texel| decode_texel(format, texel))
+        .collect();
+    Ok(EquirectLevel {
+        width,
+        height,
+        texels,
+    })
+}
+
+fn decode_texel(format: TextureFormat, texel: &[u8]) -> Vec3 {
+    let channel_f32 =
+        |i: usize| f32::from_le_bytes([texel[i], texel[i + 1], texel[i + 2], texel[i + 3]]);
+    let channel_f16 = |i: usize| f16_to_f32(u16::from_le_bytes([texel[i], texel[i + 1]]));
+    match format {
+        TextureFormat::Rgba32Float => Vec3::new(channel_f32(0), channel_f32(4), channel_f32(8)),
+        TextureFormat::Rgba16Float => Vec3::new(channel_f16(0), channel_f16(2), channel_f16(4)),
+        TextureFormat::Rgb9e5Ufloat => {
+            decode_rgb9e5(u32::from_le_bytes([texel[0], texel[1], texel[2], texel[3]]))
+        }
+        TextureFormat::Rgba8UnormSrgb => Vec3::new(
+            bevy_color::Srgba::gamma_function(texel[0] as f32 / 255.0),
+            bevy_color::Srgba::gamma_function(texel[1] as f32 / 255.0),
+            bevy_color::Srgba::gamma_function(texel[2] as f32 / 255.0),
+        ),
+        _ => Vec3::new(texel[0] as f32, texel[1] as f32, texel[2] as f32) / 255.0,
+    }
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((half >> 10) & 0x1f);
+    let mantissa = half & 0x3ff;
+    match exponent {
+        0 => sign * f32::from(mantissa) * 2f32.powi(-24),
+        0x1f if mantissa == 0 => sign * f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => sign * (1.0 + f32::from(mantissa) / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+fn decode_rgb9e5(texel: u32) -> Vec3 {
+    let scale = 2f32.powi((texel >> 27) as i32 - 24);
+    Vec3::new(
+        (texel & 0x1ff) as f32,
+        ((texel >> 9) & 0x1ff) as f32,
+        ((texel >> 18) & 0x1ff) as f32,
+    ) * scale
+}
+
+/// Encodes `color` in the `Rgb9e5Ufloat` format, clamping it to the range of
+/// the format.
+fn encode_rgb9e5(color: Vec3) -> u32 {
+    // The largest value of the format, 511 / 512 * 2^16
+    const MAX: f32 = 65408.0;
+    let color = color.max(Vec3::ZERO).min(Vec3::splat(MAX));
+    let max = color.max_element();
+    let mut exponent = (max.log2().floor() as i32).max(-16) + 16;
+    if (max / 2f32.powi(exponent - 24) + 0.5).floor() >= 512.0 {
+        exponent += 1;
+    }
+    let [r, g, b] = (color / 2f32.powi(exponent - 24) + 0.5)
+        .floor()
+        .to_array()
+        .map(|channel| channel as u32);
+    r | (g << 9) | (b << 18) | ((exponent as u32) << 27)
+}
+
+/// A mip level of an equirectangular image, with linear colors.
+struct EquirectLevel {
+    width: usize,
+    height: usize,
+    texels: Vec<Vec3>,
+}
+
+impl EquirectLevel {
+    fn texel(&self, x: usize, y: usize) -> Vec3 {
+        self.texels[y * self.width + x]
+    }
+
+    /// Returns the next mip level, half the size of this one.
+    fn downsample(&self) -> Self {
+        let width = (self.width / 2).max(1);
+        let height = (self.height / 2).max(1);
+        let mut texels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let (y0, y1) = (
+                (2 * y).min(self.height - 1),
+                (2 * y + 1).min(self.height - 1),
+            );
+            for x in 0..width {
+                let (x0, x1) = ((2 * x).min(self.width - 1), (2 * x + 1).min(self.width - 1));
+                texels.push(
+                    (self.texel(x0, y0)
+                        + self.texel(x1, y0)
+                        + self.texel(x0, y1)
+                        + self.texel(x1, y1))
+                        * 0.25,
+                );
+            }
+        }
+        Self {
+            width,
+            height,
+            texels,
+        }
+    }
+
+    /// Samples the level in `direction` with bilinear filtering, wrapping
+    /// around horizontally.
+    fn sample(&self, direction: Vec3) -> Vec3 {
+        let uv = equirect_uv(direction);
+        let x = uv.x * self.width as f32 - 0.5;
+        let y = (uv.y * self.height as f32 - 0.5).clamp(0.0, (self.height - 1) as f32);
+        let (fx, fy) = (x - x.floor(), y - y.floor());
+        let x0 = (x.floor() as isize).rem_euclid(self.width as isize) as usize;
+        let x1 = (x0 + 1) % self.width;
+        let y0 = y.floor() as usize;
+        let y1 = (y0 + 1).min(self.height - 1);
+        let top = self.texel(x0, y0).lerp(self.texel(x1, y0), fx);
+        let bottom = self.texel(x0, y1).lerp(self.texel(x1, y1), fx);
+        top.lerp(bottom, fy)
+    }
+}
+
+/// An equirectangular image with its mip levels.
+struct Equirect {
+    levels: Vec<EquirectLevel>,
+    /// The average solid angle covered by a texel of the first level.
+    texel_solid_angle: f32,
+}
+
+impl Equirect {
+    fn new(image: EquirectLevel) -> Self {
+        let texel_solid_angle = 4.0 * PI / (image.width * image.height) as f32;
+        let mut levels = vec![image];
+        while let Some(level) = levels
+            .last()
+            .filter(|level| level.width > 1 || level.height > 1)
+        {
+            let next = level.downsample();
+            levels.push(next);
+        }
+        Self {
+            levels,
+            texel_solid_angle,
+        }
+    }
+
+    /// Returns the mip level whose texels cover `solid_angle`.
+    fn lod(&self, solid_angle: f32) -> f32 {
+        0.5 * (solid_angle / self.texel_solid_angle).log2()
+    }
+
+    /// Samples the image in `direction`, at the mip level closest to `lod`.
+    fn sample(&self, direction: Vec3, lod: f32) -> Vec3 {
+        let level = (lod.round().max(0.0) as usize).min(self.levels.len() - 1);
+        self.levels[level].sample(direction)
+    }
+
+    /// Returns the importance samples of the GGX distribution for a
+    /// perceptual `roughness`: the half vectors in tangent space, with the
+    /// mip level to sample for each, according to the probability of the
+    /// sample.
+    fn ggx_samples(&self, roughness: f32, sample_count: u32) -> Vec<(Vec3, f32)> {
+        let alpha = roughness * roughness;
+        let alpha_squared = alpha * alpha;
+        (0..sample_count)
+            .map(|i| {
+                // A Hammersley point set
+                let phi = TAU * i as f32 / sample_count as f32;
+                let xi = i.reverse_bits() as f32 / (u32::MAX as f32 + 1.0);
+                let cos_theta = ((1.0 - xi) / (1.0 + (alpha_squared - 1.0) * xi)).sqrt();
+                let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+                let half = Vec3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta);
+
+                // With the normal, view and reflection directions all equal, the
+                // probability of a sample is a quarter of the distribution
+                let distribution = alpha_squared
+                    / (PI * ((alpha_squared - 1.0) * cos_theta * cos_theta + 1.0).powi(2));
+                let sample_solid_angle = 4.0 / (sample_count as f32 * distribution);
+                (half, self.lod(sample_solid_angle) + 1.0)
+            })
+            .collect()
+    }
+
+    /// Returns the radiance reflected towards `normal` by a surface facing it,
+    /// averaging the `samples` of [`Equirect::ggx_samples`].
+    fn prefilter(&self, normal: Vec3, samples: &[(Vec3, f32)]) -> Vec3 {
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        let mut radiance = Vec3::ZERO;
+        let mut total_weight = 0.0;
+        for &(half, lod) in samples {
+            let half = tangent * half.x + bitangent * half.y + normal * half.z;
+            let light = (2.0 * normal.dot(half) * half - normal).normalize();
+            let weight = normal.dot(light);
+            if weight > 0.0 {
+                radiance += self.sample(light, lod) * weight;
+                total_weight += weight;
+            }
+        }
+        if total_weight > 0.0 {
+            radiance / total_weight
+        } else {
+            self.sample(normal, 0.0)
+        }
+    }
+
+    /// Projects the image onto spherical harmonics, returning the irradiance
+    /// they represent.
+    fn irradiance(&self) -> Irradiance {
+        // A level of at most 128 texels in width is precise enough for the
+        // irradiance, which has no high frequencies
+        let level = self
+            .levels
+            .iter()
+            .find(|level| level.width <= 128)
+            .unwrap_or(&self.levels[self.levels.len() - 1]);
+        let mut coefficients = [Vec3::ZERO; 9];
+        for y in 0..level.height {
+            let theta = (y as f32 + 0.5) / level.height as f32 * PI;
+            let solid_angle = TAU / level.width as f32 * PI / level.height as f32 * theta.sin();
+            for x in 0..level.width {
+                let phi = ((x as f32 + 0.5) / level.width as f32 - 0.5) * TAU;
+                let direction = Vec3::new(
+                    theta.sin() * phi.sin(),
+                    theta.cos(),
+                    -theta.sin() * phi.cos(),
+                );
+                let radiance = level.texel(x, y) * solid_angle;
+                for (coefficient, basis) in coefficients.iter_mut().zip(sh_basis(direction)) {
+                    *coefficient += radiance * basis;
+                }
+            }
+        }
+        // Convolve with the clamped cosine, and divide by π to get the average
+        // radiance reaching a Lambertian surface
+        for (i, coefficient) in coefficients.iter_mut().enumerate() {
+            *coefficient *= match i {
+                0 => 1.0,
+                1..=3 => 2.0 / 3.0,
+                _ => 0.25,
+            };
+        }
+        Irradiance(coefficients)
+    }
+}
+
+/// The irradiance of an environment, as the coefficients of the first three
+/// bands of spherical harmonics.
+struct Irradiance([Vec3; 9]);
+
+impl Irradiance {
+    fn evaluate(&self, direction: Vec3) -> Vec3 {
+        self.0
+            .iter()
+            .zip(sh_basis(direction))
+            .map(|(coefficient, basis)| *coefficient * basis)
+            .sum::<Vec3>()
+            .max(Vec3::ZERO)
+    }
+}
+
+/// Returns the real spherical harmonics of the first three bands in
+/// `direction`.
+fn sh_basis(direction: Vec3) -> [f32; 9] {
+    let Vec3 { x, y, z } = direction;
+    [
+        0.282_095,
+        0.488_603 * y,
+        0.488_603 * z,
+        0.488_603 * x,
+        1.092_548 * x * y,
+        1.092_548 * y * z,
+        0.315_392 * (3.0 * z * z - 1.0),
+        1.092_548 * x * z,
+        0.546_274 * (x * x - y * y),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uniform_equirect(color: [f32; 4]) -> Image {
+        let texel: Vec<u8> = color.iter().flat_map(|c| c.to_le_bytes()).collect();
+        Image::new_fill(
+            Extent3d {
+                width: 64,
+                height: 32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &texel,
+            TextureFormat::Rgba32Float,
+            RenderAssetUsages::MAIN_WORLD,
+        )
+    }
+
+    #[test]
+    fn uniform_environment_converts_to_uniform_cubemaps() {
+        let settings = EnvironmentMapProcessorSettings {
+            specular_size: 16,
+            diffuse_size: 4,
+            sample_count: 16,
+        };
+        let converted =
+            convert_equirect(&uniform_equirect([0.5, 2.0, 100.0, 1.0]), settings).unwrap();
+
+        let specular = &converted.specular_map;
+        assert_eq!(specular.texture_descriptor.mip_level_count, 5);
+        // 6 faces of 16², 8², 4², 2² and 1² texels
+        assert_eq!(specular.data.len(), 6 * 341 * 4);
+        assert_eq!(converted.diffuse_map.data.len(), 6 * 16 * 4);
+
+        for image in [&converted.specular_map, &converted.diffuse_map] {
+            for texel in image.data.chunks_exact(4) {
+                let color = decode_rgb9e5(u32::from_le_bytes(texel.try_into().unwrap()));
+                let error = (color - Vec3::new(0.5, 2.0, 100.0)).abs() / Vec3::new(0.5, 2.0, 100.0);
+                assert!(error.max_element() < 0.02, "{color}");
+            }
+        }
+    }
+
+    #[test]
+    fn rgb9e5_round_trip() {
+        for color in [
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.5, 0.25),
+            Vec3::new(60000.0, 3.0, 0.0),
+        ] {
+            let decoded = decode_rgb9e5(encode_rgb9e5(color));
+            assert!((decoded - color).abs().max_element() <= color.max_element() / 256.0);
+        }
+        assert_eq!(f16_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_to_f32(0xc000), -2.0);
+    }
+
+    #[test]
+    fn unsupported_formats_are_reported() {
+        let mut image = uniform_equirect([1.0; 4]);
+        image.texture_descriptor.format = TextureFormat::Bc6hRgbUfloat;
+        assert_eq!(
+            convert_equirect(&image, EnvironmentMapProcessorSettings::default()).err(),
+            Some(EnvironmentMapProcessorError::UnsupportedFormat(
+                TextureFormat::Bc6hRgbUfloat
+            ))
+        );
+    }
+}
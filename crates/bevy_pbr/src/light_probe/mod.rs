@@ -1,7 +1,7 @@
 //! Light probes for baked global illumination.
 
-use bevy_app::{App, Plugin};
-use bevy_asset::{load_internal_asset, AssetId, Handle};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_asset::{load_internal_asset, AssetId, Handle, TrackAssets};
 use bevy_core_pipeline::core_3d::Camera3d;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
@@ -32,6 +32,9 @@ use std::hash::Hash;
 use std::ops::Deref;
 
 use crate::{
+    environment_map_processor::{
+        process_equirect_environment_maps, EnvironmentMapProcessor, EquirectEnvironmentMap,
+    },
     irradiance_volume::IRRADIANCE_VOLUME_SHADER_HANDLE,
     light_probe::environment_map::{
         EnvironmentMapIds, EnvironmentMapLight, ENVIRONMENT_MAP_SHADER_HANDLE,
@@ -43,6 +46,7 @@ use self::irradiance_volume::IrradianceVolume;
 pub const LIGHT_PROBE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(8954249792581071582);
 
 pub mod environment_map;
+pub mod environment_map_processor;
 pub mod irradiance_volume;
 
 /// The maximum number of each type of light probe that each view will consider.
@@ -319,7 +323,13 @@ impl Plugin for LightProbePlugin {
 
         app.register_type::<LightProbe>()
             .register_type::<EnvironmentMapLight>()
-            .register_type::<IrradianceVolume>();
+            .register_type::<EquirectEnvironmentMap>()
+            .register_type::<IrradianceVolume>()
+            .init_resource::<EnvironmentMapProcessor>()
+            .add_systems(
+                PreUpdate,
+                process_equirect_environment_maps.after(TrackAssets),
+            );
     }
 
     fn finish(&self, app: &mut App) {
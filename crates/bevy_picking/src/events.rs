@@ -0,0 +1,302 @@
+//! The [`Pointer`] events sent to the entities hovered by the pointers.
+
+use std::fmt::Debug;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::{HashMap, HashSet};
+
+use crate::{
+    backend::HitData,
+    focus::{HoverMap, PreviousHoverMap},
+    pointer::{InputPress, Location, PointerButton, PointerId, PointerLocation, PressDirection},
+};
+
+/// An event `E` of a pointer on an entity, sent as an event and as an observer trigger targeting
+/// the entity.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_picking::events::{Click, Pointer};
+/// fn spawn_button(mut commands: Commands) {
+///     commands
+///         .spawn_empty()
+///         .observe(|trigger: Trigger<Pointer<Click>>| {
+///             println!("{:?} clicked", trigger.entity());
+///         });
+/// }
+/// ```
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct Pointer<E: Debug + Clone + Send + Sync + 'static> {
+    /// The pointer that sent the event.
+    pub pointer_id: PointerId,
+    /// The location of the pointer when the event was sent.
+    pub pointer_location: Location,
+    /// The entity the event is sent to.
+    pub target: Entity,
+    /// The event.
+    pub event: E,
+}
+
+/// The pointer started hovering the entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Over {
+    /// The hit of the pointer on the entity.
+    pub hit: HitData,
+}
+
+/// The pointer stopped hovering the entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Out {
+    /// The last hit of the pointer on the entity.
+    pub hit: HitData,
+}
+
+/// A button of the pointer was pressed over the entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Down {
+    /// The button that was pressed.
+    pub button: PointerButton,
+    /// The hit of the pointer on the entity.
+    pub hit: HitData,
+}
+
+/// A button of the pointer was released over the entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Up {
+    /// The button that was released.
+    pub button: PointerButton,
+    /// The hit of the pointer on the entity.
+    pub hit: HitData,
+}
+
+/// A button of the pointer was pressed then released over the entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Click {
+    /// The button that was clicked.
+    pub button: PointerButton,
+    /// The hit of the pointer on the entity, when the button was released.
+    pub hit: HitData,
+}
+
+/// The entities each button of each pointer was pressed over, to send [`Click`]s when they are
+/// released.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct DownMap(pub HashMap<(PointerId, PointerButton), HashSet<Entity>>);
+
+/// Sends the [`Pointer`] events from the changes of the [`HoverMap`] and the [`InputPress`]es.
+///
+/// The [`Out`] events are sent before the [`Over`] events, and the [`Up`] event of an entity
+/// before its [`Click`] event. The events of a pointer that left its target, and has no
+/// [`Location`] anymore, are sent with its last known location.
+#[allow(clippy::too_many_arguments)]
+pub fn pointer_events(
+    mut commands: Commands,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    mut last_locations: Local<HashMap<PointerId, Location>>,
+    hover_map: Res<HoverMap>,
+    previous_hover_map: Res<PreviousHoverMap>,
+    mut input_presses: EventReader<InputPress>,
+    mut down_map: ResMut<DownMap>,
+    mut over_events: EventWriter<Pointer<Over>>,
+    mut out_events: EventWriter<Pointer<Out>>,
+    mut down_events: EventWriter<Pointer<Down>>,
+    mut up_events: EventWriter<Pointer<Up>>,
+    mut click_events: EventWriter<Pointer<Click>>,
+) {
+    fn send<E: Debug + Clone + Send + Sync + 'static>(
+        commands: &mut Commands,
+        events: &mut EventWriter<Pointer<E>>,
+        pointer_id: PointerId,
+        pointer_location: &Location,
+        target: Entity,
+        event: E,
+    ) {
+        let event = Pointer {
+            pointer_id,
+            pointer_location: pointer_location.clone(),
+            target,
+            event,
+        };
+        commands.trigger_targets(event.clone(), target);
+        events.send(event);
+    }
+
+    for (&pointer_id, pointer_location) in &pointers {
+        if let Some(location) = pointer_location.location() {
+            last_locations.insert(pointer_id, location.clone());
+        }
+    }
+    let location = |pointer_id: &PointerId| last_locations.get(pointer_id).cloned();
+
+    for (&pointer_id, previous) in &previous_hover_map.0 {
+        let Some(pointer_location) = location(&pointer_id) else {
+            continue;
+        };
+        let hovered = hover_map.0.get(&pointer_id);
+        for (&entity, hit) in previous {
+            if !hovered.is_some_and(|hovered| hovered.contains_key(&entity)) {
+                let event = Out { hit: hit.clone() };
+                let events = &mut out_events;
+                send(
+                    &mut commands,
+                    events,
+                    pointer_id,
+                    &pointer_location,
+                    entity,
+                    event,
+                );
+            }
+        }
+    }
+
+    for (&pointer_id, hovered) in &hover_map.0 {
+        let Some(pointer_location) = location(&pointer_id) else {
+            continue;
+        };
+        let previous = previous_hover_map.0.get(&pointer_id);
+        for (&entity, hit) in hovered {
+            if !previous.is_some_and(|previous| previous.contains_key(&entity)) {
+                let event = Over { hit: hit.clone() };
+                let events = &mut over_events;
+                send(
+                    &mut commands,
+                    events,
+                    pointer_id,
+                    &pointer_location,
+                    entity,
+                    event,
+                );
+            }
+        }
+    }
+
+    for press in input_presses.read() {
+        let pointer_id = press.pointer_id;
+        let button = press.button;
+        let (Some(pointer_location), Some(hovered)) =
+            (location(&pointer_id), hover_map.0.get(&pointer_id))
+        else {
+            continue;
+        };
+        match press.direction {
+            PressDirection::Down => {
+                let pressed = down_map.0.entry((pointer_id, button)).or_default();
+                pressed.clear();
+                for (&entity, hit) in hovered {
+                    pressed.insert(entity);
+                    let event = Down {
+                        button,
+                        hit: hit.clone(),
+                    };
+                    let events = &mut down_events;
+                    send(
+                        &mut commands,
+                        events,
+                        pointer_id,
+                        &pointer_location,
+                        entity,
+                        event,
+                    );
+                }
+            }
+            PressDirection::Up => {
+                let pressed = down_map.0.remove(&(pointer_id, button)).unwrap_or_default();
+                for (&entity, hit) in hovered {
+                    let event = Up {
+                        button,
+                        hit: hit.clone(),
+                    };
+                    let events = &mut up_events;
+                    send(
+                        &mut commands,
+                        events,
+                        pointer_id,
+                        &pointer_location,
+                        entity,
+                        event,
+                    );
+                    if pressed.contains(&entity) {
+                        let event = Click {
+                            button,
+                            hit: hit.clone(),
+                        };
+                        let events = &mut click_events;
+                        send(
+                            &mut commands,
+                            events,
+                            pointer_id,
+                            &pointer_location,
+                            entity,
+                            event,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec2;
+    use bevy_render::camera::{ManualTextureViewHandle, NormalizedRenderTarget};
+
+    #[test]
+    fn out_is_sent_when_the_pointer_leaves_its_target() {
+        let mut world = World::new();
+        world.init_resource::<HoverMap>();
+        world.init_resource::<PreviousHoverMap>();
+        world.init_resource::<DownMap>();
+        world.init_resource::<Events<InputPress>>();
+        world.init_resource::<Events<Pointer<Over>>>();
+        world.init_resource::<Events<Pointer<Out>>>();
+        world.init_resource::<Events<Pointer<Down>>>();
+        world.init_resource::<Events<Pointer<Up>>>();
+        world.init_resource::<Events<Pointer<Click>>>();
+        let system = world.register_system(pointer_events);
+
+        let location = Location {
+            target: NormalizedRenderTarget::TextureView(ManualTextureViewHandle(0)),
+            position: Vec2::new(10.0, 20.0),
+        };
+        let pointer = world
+            .spawn((
+                PointerId::Mouse,
+                PointerLocation {
+                    location: Some(location.clone()),
+                },
+            ))
+            .id();
+        let camera = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+        let hit = HitData::new(camera, 0.0, None, None);
+
+        // The pointer starts hovering the target
+        world
+            .resource_mut::<HoverMap>()
+            .0
+            .insert(PointerId::Mouse, HashMap::from([(target, hit.clone())]));
+        world.run_system(system).unwrap();
+        assert_eq!(world.resource::<Events<Pointer<Over>>>().len(), 1);
+
+        // Then leaves the window, so it has no location and hovers nothing
+        world.get_mut::<PointerLocation>(pointer).unwrap().location = None;
+        let hovered = std::mem::take(&mut world.resource_mut::<HoverMap>().0);
+        world.resource_mut::<PreviousHoverMap>().0 = hovered;
+        world.run_system(system).unwrap();
+        let outs: Vec<_> = world
+            .resource_mut::<Events<Pointer<Out>>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            outs,
+            [Pointer {
+                pointer_id: PointerId::Mouse,
+                pointer_location: location,
+                target,
+                event: Out { hit },
+            }]
+        );
+    }
+}
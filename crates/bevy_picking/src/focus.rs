@@ -0,0 +1,114 @@
+//! Determines the entities hovered by each pointer from the [`PointerHits`] of the backends.
+
+use std::cmp::Ordering;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::HashMap;
+
+use crate::{
+    backend::{HitData, PointerHits},
+    pointer::{PointerId, PointerInteraction},
+    Pickable,
+};
+
+/// The entities hovered by each pointer this frame, with the hit that hovered them.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct HoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
+
+/// The [`HoverMap`] of the previous frame, to find the entities that started or stopped being
+/// hovered.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct PreviousHoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
+
+/// Updates the [`HoverMap`] and the [`PointerInteraction`] of each pointer from the
+/// [`PointerHits`] sent this frame.
+///
+/// The hits of each pointer are gone through from the highest [`PointerHits::order`] and the
+/// lowest [`HitData::depth`], hovering the entities until one whose [`Pickable`] blocks the lower
+/// ones.
+pub fn update_focus(
+    pickables: Query<&Pickable>,
+    mut pointers: Query<(&PointerId, &mut PointerInteraction)>,
+    mut pointer_hits: EventReader<PointerHits>,
+    mut hover_map: ResMut<HoverMap>,
+    mut previous_hover_map: ResMut<PreviousHoverMap>,
+) {
+    previous_hover_map.0 = std::mem::take(&mut hover_map.0);
+
+    let mut hits_of_pointers: HashMap<PointerId, Vec<(f32, Entity, &HitData)>> = HashMap::new();
+    for hits in pointer_hits.read() {
+        hits_of_pointers.entry(hits.pointer).or_default().extend(
+            hits.picks
+                .iter()
+                .map(|(entity, hit)| (hits.order, *entity, hit)),
+        );
+    }
+
+    for (pointer_id, mut interaction) in &mut pointers {
+        interaction.sorted_entities.clear();
+        let hovered = hover_map.0.entry(*pointer_id).or_default();
+        let Some(hits) = hits_of_pointers.get_mut(pointer_id) else {
+            continue;
+        };
+        hits.sort_by(|(order_a, _, hit_a), (order_b, _, hit_b)| {
+            order_b
+                .partial_cmp(order_a)
+                .unwrap_or(Ordering::Equal)
+                .then(hit_a.depth.total_cmp(&hit_b.depth))
+        });
+        for &(_, entity, hit) in hits.iter() {
+            let pickable = pickables.get(entity).cloned().unwrap_or_default();
+            if pickable.is_hoverable {
+                hovered.insert(entity, hit.clone());
+                interaction.sorted_entities.push((entity, hit.clone()));
+            }
+            if pickable.should_block_lower {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[test]
+    fn hits_hover_until_blocked() {
+        let mut world = World::new();
+        world.init_resource::<HoverMap>();
+        world.init_resource::<PreviousHoverMap>();
+        world.init_resource::<Events<PointerHits>>();
+        world.spawn((PointerId::Mouse, PointerInteraction::default()));
+
+        let camera = world.spawn_empty().id();
+        let passthrough = world
+            .spawn(Pickable {
+                should_block_lower: false,
+                is_hoverable: true,
+            })
+            .id();
+        let ignored = world.spawn(Pickable::IGNORE).id();
+        let blocking = world.spawn_empty().id();
+        let hidden = world.spawn_empty().id();
+        let hit = |depth| HitData::new(camera, depth, None, None);
+        world.send_event(PointerHits::new(
+            PointerId::Mouse,
+            vec![
+                (hidden, hit(3.0)),
+                (blocking, hit(2.0)),
+                (ignored, hit(1.0)),
+                (passthrough, hit(0.0)),
+            ],
+            0.0,
+        ));
+
+        world.run_system_once(update_focus);
+        let hovered = &world.resource::<HoverMap>().0[&PointerId::Mouse];
+        assert!(hovered.contains_key(&passthrough));
+        assert!(!hovered.contains_key(&ignored));
+        assert!(hovered.contains_key(&blocking));
+        assert!(!hovered.contains_key(&hidden));
+    }
+}
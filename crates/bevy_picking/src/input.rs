@@ -0,0 +1,149 @@
+//! Sends the [`InputMove`] and [`InputPress`] events of the mouse pointer from the mouse inputs.
+//!
+//! Touch pointers aren't spawned yet: spawn a [`PointerBundle`](crate::PointerBundle) with a
+//! [`PointerId::Touch`] and send its events to pick with touches.
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    mouse::{MouseButton, MouseButtonInput},
+    ButtonState,
+};
+use bevy_render::camera::NormalizedRenderTarget;
+use bevy_window::{CursorLeft, CursorMoved, WindowRef};
+
+use crate::{
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerId, PointerLocation},
+    PickSet, PointerBundle,
+};
+
+/// Spawns the mouse pointer, and sends its events from the mouse inputs in [`PickSet::Input`].
+pub struct PointerInputPlugin;
+
+impl Plugin for PointerInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_mouse_pointer)
+            .add_systems(First, mouse_pick_events.in_set(PickSet::Input));
+    }
+}
+
+/// Spawns the pointer of the mouse.
+pub fn spawn_mouse_pointer(mut commands: Commands) {
+    commands.spawn(PointerBundle::new(PointerId::Mouse));
+}
+
+/// Sends the [`InputMove`] and [`InputPress`] events of the mouse pointer.
+///
+/// When the cursor leaves the window the mouse pointer is in, the [`PointerLocation`] of the
+/// pointer is cleared, so that it stops hovering the entities of the window. The moves in that
+/// window during the same frame are ignored, as they came before the cursor left.
+pub fn mouse_pick_events(
+    mut cursor_moves: EventReader<CursorMoved>,
+    mut cursor_lefts: EventReader<CursorLeft>,
+    mut mouse_inputs: EventReader<MouseButtonInput>,
+    mut pointers: Query<(&PointerId, &mut PointerLocation)>,
+    mut pointer_moves: EventWriter<InputMove>,
+    mut pointer_presses: EventWriter<InputPress>,
+) {
+    let left_windows: Vec<Entity> = cursor_lefts.read().map(|event| event.window).collect();
+    if !left_windows.is_empty() {
+        for (pointer_id, mut pointer_location) in &mut pointers {
+            let in_left_window = pointer_location.location().is_some_and(|location| {
+                matches!(
+                    &location.target,
+                    NormalizedRenderTarget::Window(window) if left_windows.contains(&window.entity())
+                )
+            });
+            if pointer_id.is_mouse() && in_left_window {
+                pointer_location.location = None;
+            }
+        }
+    }
+
+    for event in cursor_moves.read() {
+        if left_windows.contains(&event.window) {
+            continue;
+        }
+        let Some(window) = WindowRef::Entity(event.window).normalize(None) else {
+            continue;
+        };
+        pointer_moves.send(InputMove::new(
+            PointerId::Mouse,
+            Location {
+                target: NormalizedRenderTarget::Window(window),
+                position: event.position,
+            },
+            event.delta.unwrap_or_default(),
+        ));
+    }
+
+    for event in mouse_inputs.read() {
+        let button = match event.button {
+            MouseButton::Left => PointerButton::Primary,
+            MouseButton::Right => PointerButton::Secondary,
+            MouseButton::Middle => PointerButton::Middle,
+            _ => continue,
+        };
+        pointer_presses.send(match event.state {
+            ButtonState::Pressed => InputPress::new_down(PointerId::Mouse, button),
+            ButtonState::Released => InputPress::new_up(PointerId::Mouse, button),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec2;
+
+    #[test]
+    fn the_mouse_pointer_leaves_with_the_cursor() {
+        let mut world = World::new();
+        world.init_resource::<Events<CursorMoved>>();
+        world.init_resource::<Events<CursorLeft>>();
+        world.init_resource::<Events<MouseButtonInput>>();
+        world.init_resource::<Events<InputMove>>();
+        world.init_resource::<Events<InputPress>>();
+        let system = world.register_system(mouse_pick_events);
+
+        let window = world.spawn_empty().id();
+        let other_window = world.spawn_empty().id();
+        let location = |window| {
+            Some(Location {
+                target: NormalizedRenderTarget::Window(
+                    WindowRef::Entity(window).normalize(None).unwrap(),
+                ),
+                position: Vec2::new(10.0, 20.0),
+            })
+        };
+        let mouse = world
+            .spawn((
+                PointerId::Mouse,
+                PointerLocation {
+                    location: location(window),
+                },
+            ))
+            .id();
+
+        // Leaving another window keeps the location
+        world.send_event(CursorLeft {
+            window: other_window,
+        });
+        world.run_system(system).unwrap();
+        assert_eq!(
+            world.get::<PointerLocation>(mouse).unwrap().location,
+            location(window)
+        );
+
+        // The moves before leaving the window aren't sent
+        world.send_event(CursorMoved {
+            window,
+            position: Vec2::ZERO,
+            delta: None,
+        });
+        world.send_event(CursorLeft { window });
+        world.run_system(system).unwrap();
+        assert_eq!(world.get::<PointerLocation>(mouse).unwrap().location, None);
+        assert!(world.resource::<Events<InputMove>>().is_empty());
+    }
+}
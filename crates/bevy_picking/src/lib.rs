@@ -3,9 +3,12 @@
 #![deny(missing_docs)]
 
 pub mod backend;
+pub mod events;
+pub mod focus;
+pub mod input;
 pub mod pointer;
 
-use bevy_app::prelude::*;
+use bevy_app::{prelude::*, PluginGroupBuilder};
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
 
@@ -166,6 +169,24 @@ pub enum PickSet {
     Last,
 }
 
+/// The plugins picking entities with the mouse, and sending them [`Pointer`](events::Pointer)
+/// events:
+/// * [`PickingPlugin`]
+/// * [`PointerInputPlugin`](input::PointerInputPlugin)
+/// * [`InteractionPlugin`]
+///
+/// Entities are only picked by the backends added next to these plugins.
+pub struct DefaultPickingPlugins;
+
+impl PluginGroup for DefaultPickingPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(PickingPlugin)
+            .add(input::PointerInputPlugin)
+            .add(InteractionPlugin)
+    }
+}
+
 /// This plugin sets up the core picking infrastructure. It receives input events, and provides the shared
 /// types used by other picking plugins.
 pub struct PickingPlugin;
@@ -210,3 +231,28 @@ impl Plugin for PickingPlugin {
             .register_type::<backend::ray::RayId>();
     }
 }
+
+/// Updates the [`HoverMap`](focus::HoverMap) from the [`PointerHits`](backend::PointerHits) of
+/// the backends in [`PickSet::Focus`], and sends the [`Pointer`](events::Pointer) events.
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        use events::*;
+
+        app.init_resource::<focus::HoverMap>()
+            .init_resource::<focus::PreviousHoverMap>()
+            .init_resource::<DownMap>()
+            .add_event::<Pointer<Over>>()
+            .add_event::<Pointer<Out>>()
+            .add_event::<Pointer<Down>>()
+            .add_event::<Pointer<Up>>()
+            .add_event::<Pointer<Click>>()
+            .add_systems(
+                PreUpdate,
+                (focus::update_focus, pointer_events)
+                    .chain()
+                    .in_set(PickSet::Focus),
+            );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::serde::SerializationData;
+use crate::serde::{ReflectSerializeAs, SerializationData};
 use crate::{
     ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
     DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
@@ -522,6 +522,25 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     {
         let type_path = self.registration.type_info().type_path();
 
+        // Types serialized as a proxy are deserialized as the proxy, then converted back
+        if let Some(serialize_as) = self.registration.data::<ReflectSerializeAs>() {
+            let proxy_registration = self
+                .registry
+                .get(serialize_as.proxy_type_id())
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "the serialization proxy of `{type_path}` is not registered in the type registry"
+                    ))
+                })?;
+            let proxy = TypedReflectDeserializer::new(proxy_registration, self.registry)
+                .deserialize(deserializer)?;
+            return serialize_as.from_proxy(&*proxy).ok_or_else(|| {
+                Error::custom(format_args!(
+                    "cannot convert the serialization proxy of `{type_path}` back into it"
+                ))
+            });
+        }
+
         // Handle both Value case and types that have a custom `ReflectDeserialize`
         if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
             let value = deserialize_reflect.deserialize(deserializer)?;
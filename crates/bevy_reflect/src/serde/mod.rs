@@ -1,9 +1,11 @@
 mod de;
 mod ser;
+mod serialize_as;
 mod type_data;
 
 pub use de::*;
 pub use ser::*;
+pub use serialize_as::*;
 pub use type_data::*;
 
 #[cfg(test)]
@@ -181,4 +183,72 @@ mod tests {
 
         assert!(expected.reflect_partial_eq(&result).unwrap());
     }
+
+    #[test]
+    fn should_roundtrip_serialize_as_proxy() {
+        use crate::serde::{ReflectSerializeAs, SerializeAs};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(SerializeAs)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Reflect)]
+        struct PointProxy((i32, i32));
+
+        impl SerializeAs for Point {
+            type Proxy = PointProxy;
+
+            fn to_proxy(&self) -> PointProxy {
+                PointProxy((self.x, self.y))
+            }
+
+            fn from_proxy(proxy: PointProxy) -> Self {
+                Point {
+                    x: proxy.0 .0,
+                    y: proxy.0 .1,
+                }
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Shape {
+            points: Vec<Point>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Shape>();
+        registry.register::<PointProxy>();
+
+        let shape = Shape {
+            points: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+        };
+        // Dynamic values are converted into their proxy too
+        let serializer = ReflectSerializer::new(&shape, &registry);
+        let result = ron::ser::to_string(&serializer).unwrap();
+        let dynamic = shape.clone_value();
+        let serializer = ReflectSerializer::new(&*dynamic, &registry);
+        assert_eq!(ron::ser::to_string(&serializer).unwrap(), result);
+        assert_eq!(
+            result,
+            r#"{"bevy_reflect::serde::tests::Shape":(points:[((1,2)),((3,4))])}"#
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&result).unwrap();
+        let value = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Shape::from_reflect(&*value).unwrap(), shape);
+
+        // The proxy has to be registered
+        let mut registry = TypeRegistry::default();
+        registry.register::<Shape>();
+        let mut deserializer = ron::de::Deserializer::from_str(&result).unwrap();
+        let error = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(error.to_string().contains("serialization proxy"));
+    }
 }
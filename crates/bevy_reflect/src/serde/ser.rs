@@ -1,3 +1,4 @@
+use crate::serde::ReflectSerializeAs;
 use crate::{
     Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
     TypeInfo, TypeRegistry, VariantInfo, VariantType,
@@ -186,6 +187,21 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        // Types serialized as a proxy are serialized as the proxy instead
+        let serialize_as = self.value.get_represented_type_info().and_then(|info| {
+            self.registry
+                .get_type_data::<ReflectSerializeAs>(info.type_id())
+        });
+        if let Some(serialize_as) = serialize_as {
+            let proxy = serialize_as.to_proxy(self.value).ok_or_else(|| {
+                Error::custom(format_args!(
+                    "cannot convert `{}` into its serialization proxy",
+                    self.value.reflect_type_path()
+                ))
+            })?;
+            return TypedReflectSerializer::new(&*proxy, self.registry).serialize(serializer);
+        }
+
         // Handle both Value case and types that have a custom `Serialize`
         let serializable = get_serializable::<S::Error>(self.value, self.registry);
         if let Ok(serializable) = serializable {
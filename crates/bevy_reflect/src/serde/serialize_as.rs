@@ -0,0 +1,118 @@
+use crate::{FromReflect, FromType, GetTypeRegistration, Reflect, TypePath};
+use std::any::TypeId;
+
+/// A type which is (de)serialized by reflection as another type, its [proxy](SerializeAs::Proxy).
+///
+/// This lets a type choose a more compact serialized form than the one of its fields, such as
+/// joining many short strings into one, while its proxy is still serialized by reflection, so the
+/// types within it don't need to implement [`Serialize`](::serde::Serialize).
+///
+/// The conversion is registered with `#[reflect(SerializeAs)]`, as [`ReflectSerializeAs`] type
+/// data, and is used by the [`TypedReflectSerializer`] and the [`TypedReflectDeserializer`].
+/// The proxy has to be registered too.
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{ReflectDeserializer, ReflectSerializer, ReflectSerializeAs, SerializeAs}};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(SerializeAs)]
+/// struct Tags {
+///     tags: Vec<String>,
+/// }
+///
+/// // The tags are serialized as a single string
+/// #[derive(Reflect)]
+/// struct TagsProxy(String);
+///
+/// impl SerializeAs for Tags {
+///     type Proxy = TagsProxy;
+///
+///     fn to_proxy(&self) -> TagsProxy {
+///         TagsProxy(self.tags.join(","))
+///     }
+///
+///     fn from_proxy(proxy: TagsProxy) -> Self {
+///         let tags = proxy.0.split(',').filter(|tag| !tag.is_empty()).map(String::from);
+///         Tags { tags: tags.collect() }
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Tags>();
+/// registry.register::<TagsProxy>();
+///
+/// let tags = Tags { tags: vec!["red".into(), "round".into()] };
+/// let serialized = ron::to_string(&ReflectSerializer::new(&tags, &registry)).unwrap();
+/// assert!(serialized.contains(r#"("red,round")"#));
+///
+/// let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+/// let deserialized = ReflectDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(deserialized.take::<Tags>().unwrap(), tags);
+/// ```
+///
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+pub trait SerializeAs: FromReflect + TypePath {
+    /// The type this type is serialized as.
+    type Proxy: FromReflect + TypePath + GetTypeRegistration;
+
+    /// Converts this value into the proxy it is serialized as.
+    fn to_proxy(&self) -> Self::Proxy;
+
+    /// Converts a deserialized proxy back into a value of this type.
+    fn from_proxy(proxy: Self::Proxy) -> Self;
+}
+
+/// Type data for types (de)serialized as another type, see [`SerializeAs`].
+///
+/// A `ReflectSerializeAs` for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectSerializeAs {
+    proxy_type_id: TypeId,
+    to_proxy: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+    from_proxy: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectSerializeAs {
+    /// The [`TypeId`] of the proxy the type is serialized as.
+    pub fn proxy_type_id(&self) -> TypeId {
+        self.proxy_type_id
+    }
+
+    /// Converts `value` into the proxy it is serialized as.
+    ///
+    /// Returns `None` if `value` can't be converted into the type of this type data.
+    pub fn to_proxy(&self, value: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.to_proxy)(value)
+    }
+
+    /// Converts a deserialized `proxy`, which may be a dynamic value, back into the type of this
+    /// type data.
+    ///
+    /// Returns `None` if `proxy` can't be converted into the type of the proxy.
+    pub fn from_proxy(&self, proxy: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.from_proxy)(proxy)
+    }
+}
+
+impl<T: SerializeAs> FromType<T> for ReflectSerializeAs {
+    fn from_type() -> Self {
+        ReflectSerializeAs {
+            proxy_type_id: TypeId::of::<T::Proxy>(),
+            to_proxy: |value| {
+                let proxy = match value.downcast_ref::<T>() {
+                    Some(value) => value.to_proxy(),
+                    None => T::from_reflect(value)?.to_proxy(),
+                };
+                Some(Box::new(proxy))
+            },
+            from_proxy: |proxy| {
+                let proxy = T::Proxy::from_reflect(proxy)?;
+                Some(Box::new(T::from_proxy(proxy)))
+            },
+        }
+    }
+}
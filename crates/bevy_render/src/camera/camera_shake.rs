@@ -0,0 +1,313 @@
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::prelude::*;
+use bevy_math::{BVec3, EulerRot, Quat, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::{Time, Virtual};
+use bevy_transform::{components::Transform, TransformSystem};
+
+/// Adds [`CameraShake`] support.
+///
+/// This is added by the [`CameraPlugin`](crate::camera::CameraPlugin).
+#[derive(Default)]
+pub struct CameraShakePlugin;
+
+impl Plugin for CameraShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CameraShake>().add_systems(
+            PostUpdate,
+            (
+                shake_cameras
+                    .in_set(CameraShakeSystem::Shake)
+                    .before(TransformSystem::TransformPropagate),
+                restore_shaken_cameras
+                    .in_set(CameraShakeSystem::Restore)
+                    .after(TransformSystem::TransformPropagate),
+            ),
+        );
+    }
+}
+
+/// The systems of [`CameraShake`], running in [`PostUpdate`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CameraShakeSystem {
+    /// Adds the shake offset to the [`Transform`] of the cameras, before the transforms are
+    /// propagated. Camera logic running in [`PostUpdate`] should run before this set.
+    Shake,
+    /// Restores the unshaken [`Transform`] of the cameras, once the transforms are propagated.
+    Restore,
+}
+
+/// Shakes the entity it is on, usually a camera, by an amount driven by its trauma.
+///
+/// Trauma is added with [`add_trauma`](Self::add_trauma), such as when the player is hit or
+/// something explodes nearby, and decays over time. The shake grows with the square of the
+/// trauma, and follows smooth noise on each of the enabled axes.
+///
+/// The shake is only added to the [`GlobalTransform`] of the entity: its [`Transform`] is shaken
+/// right before the transforms are propagated, and restored right after. Gameplay and camera
+/// logic, such as a follow camera, always see the unshaken [`Transform`].
+///
+/// The shake advances with [`Time<Virtual>`], so it stops, keeping its current offset, while the
+/// game is paused.
+///
+/// [`GlobalTransform`]: bevy_transform::components::GlobalTransform
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct CameraShake {
+    /// The trauma lost per second.
+    pub decay: f32,
+    /// The largest translation of the shake along each local axis, at full trauma.
+    pub max_translation: Vec3,
+    /// The largest rotation of the shake around each local axis at full trauma, in radians.
+    pub max_rotation: Vec3,
+    /// The local axes the shake translates along.
+    pub translation_axes: BVec3,
+    /// The local axes the shake rotates around.
+    pub rotation_axes: BVec3,
+    /// How fast the shake changes, in noise periods per second.
+    pub frequency: f32,
+    /// The seed of the noise, so that cameras shaking together don't shake alike.
+    pub seed: u32,
+    trauma: f32,
+    /// The time the noise is sampled at.
+    time: f32,
+    #[reflect(ignore)]
+    unshaken: Option<Transform>,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self::new_3d(0.2, 0.1)
+    }
+}
+
+impl CameraShake {
+    /// Creates a shake for a 2D camera, translating along the x and y axes by up to
+    /// `max_translation`, and rotating around the z axis by up to `max_rotation` radians.
+    pub fn new_2d(max_translation: f32, max_rotation: f32) -> Self {
+        Self {
+            decay: 0.8,
+            max_translation: Vec3::new(max_translation, max_translation, 0.0),
+            max_rotation: Vec3::new(0.0, 0.0, max_rotation),
+            translation_axes: BVec3::new(true, true, false),
+            rotation_axes: BVec3::new(false, false, true),
+            frequency: 15.0,
+            seed: 0,
+            trauma: 0.0,
+            time: 0.0,
+            unshaken: None,
+        }
+    }
+
+    /// Creates a shake for a 3D camera, translating along every axis by up to `max_translation`,
+    /// and rotating around every axis by up to `max_rotation` radians.
+    pub fn new_3d(max_translation: f32, max_rotation: f32) -> Self {
+        Self {
+            max_translation: Vec3::splat(max_translation),
+            max_rotation: Vec3::splat(max_rotation),
+            translation_axes: BVec3::TRUE,
+            rotation_axes: BVec3::TRUE,
+            ..Self::new_2d(0.0, 0.0)
+        }
+    }
+
+    /// Returns this shake with a new [`decay`](Self::decay).
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Returns this shake with a new [`frequency`](Self::frequency).
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Returns this shake with a new [`seed`](Self::seed).
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Adds `amount` of trauma, keeping the trauma between `0.0` and `1.0`.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// Returns the current trauma, between `0.0` and `1.0`.
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// Returns the translation and rotation of the shake in the local space of the entity.
+    fn offset(&self) -> (Vec3, Quat) {
+        let shake = self.trauma * self.trauma;
+        let time = self.time * self.frequency;
+        let noise = |channel: u32| perlin_noise(self.seed.wrapping_add(channel), time);
+        let axis = |enabled: bool, channel: u32| if enabled { noise(channel) } else { 0.0 };
+
+        let translation = Vec3::new(
+            axis(self.translation_axes.x, 0),
+            axis(self.translation_axes.y, 1),
+            axis(self.translation_axes.z, 2),
+        ) * self.max_translation
+            * shake;
+        let rotation = Vec3::new(
+            axis(self.rotation_axes.x, 3),
+            axis(self.rotation_axes.y, 4),
+            axis(self.rotation_axes.z, 5),
+        ) * self.max_rotation
+            * shake;
+        (
+            translation,
+            Quat::from_euler(EulerRot::XYZ, rotation.x, rotation.y, rotation.z),
+        )
+    }
+}
+
+/// Advances the [`CameraShake`]s, and adds their offset to the [`Transform`] of their entities.
+pub fn shake_cameras(
+    time: Res<Time<Virtual>>,
+    mut cameras: Query<(&mut CameraShake, &mut Transform)>,
+) {
+    let delta = time.delta_seconds();
+    for (mut shake, mut transform) in &mut cameras {
+        if shake.trauma <= 0.0 {
+            continue;
+        }
+        let shake = shake.as_mut();
+        shake.time += delta;
+        let (translation, rotation) = shake.offset();
+        shake.trauma = (shake.trauma - shake.decay * delta).max(0.0);
+
+        shake.unshaken = Some(*transform);
+        transform.translation += transform.rotation * translation;
+        transform.rotation *= rotation;
+    }
+}
+
+/// Restores the unshaken [`Transform`] of the entities shaken by [`shake_cameras`].
+pub fn restore_shaken_cameras(mut cameras: Query<(&mut CameraShake, &mut Transform)>) {
+    for (mut shake, mut transform) in &mut cameras {
+        if let Some(unshaken) = shake.bypass_change_detection().unshaken.take() {
+            *transform = unshaken;
+        }
+    }
+}
+
+/// One dimensional Perlin noise of `x`, between `-1.0` and `1.0`.
+fn perlin_noise(seed: u32, x: f32) -> f32 {
+    let cell = x.floor();
+    let t = x - cell;
+    let cell = cell as i32 as u32;
+    // The gradients at both ends of the cell, between -1 and 1
+    let gradient = |cell: u32| {
+        let mut hash = cell.wrapping_mul(0x9E37_79B9) ^ seed.wrapping_mul(0x85EB_CA6B);
+        hash ^= hash >> 16;
+        hash = hash.wrapping_mul(0x7FEB_352D);
+        hash ^= hash >> 15;
+        hash = hash.wrapping_mul(0x846C_A68B);
+        hash ^= hash >> 16;
+        hash as f32 / u32::MAX as f32 * 2.0 - 1.0
+    };
+    let start = gradient(cell) * t;
+    let end = gradient(cell.wrapping_add(1)) * (t - 1.0);
+    let fade = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+    // The noise is at most half the largest gradient
+    2.0 * (start + (end - start) * fade)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_app::App;
+    use bevy_transform::{components::GlobalTransform, TransformPlugin};
+
+    use super::*;
+
+    fn setup(shake: CameraShake) -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time<Virtual>>()
+            .add_plugins((TransformPlugin, CameraShakePlugin));
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        let entity = app
+            .world_mut()
+            .spawn((shake, transform, GlobalTransform::from(transform)))
+            .id();
+        (app, entity)
+    }
+
+    fn step(app: &mut App, seconds: f32) {
+        let mut time = app.world_mut().resource_mut::<Time<Virtual>>();
+        // Like the `TimePlugin`, a paused clock doesn't advance
+        let seconds = if time.is_paused() { 0.0 } else { seconds };
+        time.advance_by(Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    #[test]
+    fn trauma_is_clamped() {
+        let mut shake = CameraShake::default();
+        shake.add_trauma(0.7);
+        shake.add_trauma(0.7);
+        assert_eq!(shake.trauma(), 1.0);
+        shake.add_trauma(-3.0);
+        assert_eq!(shake.trauma(), 0.0);
+    }
+
+    #[test]
+    fn noise_is_smooth_and_bounded() {
+        let mut previous = perlin_noise(7, 0.0);
+        for i in 1..1000 {
+            let noise = perlin_noise(7, i as f32 * 0.01);
+            assert!((-1.0..=1.0).contains(&noise));
+            assert!((noise - previous).abs() < 0.1);
+            previous = noise;
+        }
+        // The noise is zero at integers, so it is sampled between them
+        assert_ne!(perlin_noise(7, 0.5), perlin_noise(8, 0.5));
+    }
+
+    #[test]
+    fn shake_only_reaches_the_global_transform() {
+        let mut shake = CameraShake::new_2d(10.0, 0.2).with_decay(0.5);
+        shake.add_trauma(1.0);
+        let (mut app, entity) = setup(shake);
+        step(&mut app, 0.23);
+
+        let world = app.world();
+        let transform = *world.get::<Transform>(entity).unwrap();
+        let global = world
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .compute_transform();
+        assert_eq!(transform, Transform::from_xyz(1.0, 2.0, 3.0));
+        assert_ne!(global.translation, transform.translation);
+        // A 2D shake keeps the camera at its depth, and only rolls it
+        assert_eq!(global.translation.z, 3.0);
+        let (x, y, _) = global.rotation.to_euler(EulerRot::XYZ);
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+
+        let trauma = world.get::<CameraShake>(entity).unwrap().trauma();
+        assert!((trauma - (1.0 - 0.5 * 0.23)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn shake_stops_while_paused() {
+        let mut shake = CameraShake::default();
+        shake.add_trauma(0.5);
+        let (mut app, entity) = setup(shake);
+        step(&mut app, 0.1);
+        let trauma = app.world().get::<CameraShake>(entity).unwrap().trauma();
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        step(&mut app, 0.1);
+        let world = app.world();
+        assert_eq!(world.get::<CameraShake>(entity).unwrap().trauma(), trauma);
+        assert_eq!(
+            *world.get::<Transform>(entity).unwrap(),
+            Transform::from_xyz(1.0, 2.0, 3.0)
+        );
+    }
+}
@@ -1,12 +1,14 @@
 #[allow(clippy::module_inception)]
 mod camera;
 mod camera_driver_node;
+mod camera_shake;
 mod clear_color;
 mod manual_texture_view;
 mod projection;
 
 pub use camera::*;
 pub use camera_driver_node::*;
+pub use camera_shake::*;
 pub use clear_color::*;
 pub use manual_texture_view::*;
 pub use projection::*;
@@ -33,6 +35,7 @@ impl Plugin for CameraPlugin {
             .init_resource::<ManualTextureViews>()
             .init_resource::<ClearColor>()
             .add_plugins((
+                CameraShakePlugin,
                 CameraProjectionPlugin::<Projection>::default(),
                 CameraProjectionPlugin::<OrthographicProjection>::default(),
                 CameraProjectionPlugin::<PerspectiveProjection>::default(),
@@ -40,8 +40,8 @@ pub mod prelude {
     pub use crate::{
         alpha::AlphaMode,
         camera::{
-            Camera, ClearColor, ClearColorConfig, OrthographicProjection, PerspectiveProjection,
-            Projection,
+            Camera, CameraShake, ClearColor, ClearColorConfig, OrthographicProjection,
+            PerspectiveProjection, Projection,
         },
         mesh::{morph::MorphWeights, primitives::MeshBuilder, primitives::Meshable, Mesh},
         render_resource::Shader,
@@ -77,6 +77,7 @@ use crate::{
 };
 use bevy_app::{App, AppLabel, Plugin, SubApp};
 use bevy_asset::{load_internal_asset, AssetApp, AssetServer, Handle};
+use bevy_diagnostic::DiagnosticsRecorder;
 use bevy_ecs::{prelude::*, schedule::ScheduleLabel, system::SystemState};
 use bevy_utils::tracing::debug;
 use std::{
@@ -384,7 +385,12 @@ impl Plugin for RenderPlugin {
             app.insert_resource(device.clone())
                 .insert_resource(queue.clone())
                 .insert_resource(adapter_info.clone())
-                .insert_resource(render_adapter.clone());
+                .insert_resource(render_adapter.clone())
+                .add_systems(
+                    Update,
+                    add_adapter_to_diagnostics_recorder
+                        .run_if(resource_added::<DiagnosticsRecorder>),
+                );
 
             let render_app = app.sub_app_mut(RenderApp);
 
@@ -410,6 +416,14 @@ impl Plugin for RenderPlugin {
     }
 }
 
+/// Adds the name of the graphics adapter to the metadata of the [`DiagnosticsRecorder`].
+fn add_adapter_to_diagnostics_recorder(
+    adapter_info: Res<RenderAdapterInfo>,
+    mut recorder: ResMut<DiagnosticsRecorder>,
+) {
+    recorder.set_metadata("adapter", adapter_info.name.clone());
+}
+
 /// A "scratch" world used to avoid allocating new worlds every frame when
 /// swapping out the [`MainWorld`] for [`ExtractSchedule`].
 #[derive(Resource, Default)]
@@ -1,7 +1,9 @@
 mod conversions;
+mod simplify;
 pub mod skinning;
 use bevy_transform::components::Transform;
 use bitflags::bitflags;
+pub use simplify::*;
 pub use wgpu::PrimitiveTopology;
 
 use crate::{
@@ -0,0 +1,854 @@
+use std::{
+    future::Future,
+    ops::{Add, AddAssign},
+};
+
+use bevy_math::{DVec3, Vec3};
+use bevy_utils::HashMap;
+use thiserror::Error;
+use wgpu::{PrimitiveTopology, VertexFormat};
+
+use super::{Indices, Mesh, VertexAttributeValues, VertexFormatSize};
+
+/// The weight of the planes keeping the borders and seams of a mesh in place, relative to the
+/// planes of its triangles.
+const BORDER_WEIGHT: f64 = 10.0;
+
+/// An error simplifying a [`Mesh`] with [`Mesh::simplified`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MeshSimplificationError {
+    #[error("only triangle lists can be simplified, not {0:?}")]
+    UnsupportedTopology(PrimitiveTopology),
+    #[error("meshes with morph targets can't be simplified")]
+    MorphTargets,
+    #[error("skinned meshes, with joint weights, can't be simplified")]
+    JointWeights,
+    #[error("the mesh has no Float32x3 positions")]
+    MissingPositions,
+}
+
+impl Mesh {
+    /// Returns a copy of this mesh simplified to about `target_ratio` of its triangles, such as to
+    /// generate the levels of detail of a mesh.
+    ///
+    /// The edges of the mesh are collapsed one after the other, starting with the edges changing
+    /// the shape of the mesh the least, as measured by quadric error metrics. Collapsing an edge
+    /// moves one of its vertices onto the other, so the remaining vertices keep their attributes,
+    /// and:
+    /// - the seams of the mesh, where its vertices are split, such as UV seams and hard edges with
+    ///     split normals, only collapse along themselves, and their corners are kept;
+    /// - the open borders of the mesh only collapse along themselves;
+    /// - no collapse folds a triangle over, or makes a watertight mesh non-manifold.
+    ///
+    /// So the simplified mesh may keep more triangles than asked for, such as a flat-shaded mesh,
+    /// whose vertices are all split.
+    ///
+    /// Vertices closer than a millionth of the size of the mesh are welded. The simplified mesh is
+    /// indexed, even if this mesh isn't, and only depends on this mesh and `target_ratio`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh isn't a [`PrimitiveTopology::TriangleList`], if it has morph
+    /// targets or joint weights, which aren't supported yet, or if it has no positions.
+    pub fn simplified(&self, target_ratio: f32) -> Result<Mesh, MeshSimplificationError> {
+        if self.primitive_topology != PrimitiveTopology::TriangleList {
+            return Err(MeshSimplificationError::UnsupportedTopology(
+                self.primitive_topology,
+            ));
+        }
+        if self.has_morph_targets() {
+            return Err(MeshSimplificationError::MorphTargets);
+        }
+        if self.contains_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT)
+            || self.contains_attribute(Mesh::ATTRIBUTE_JOINT_INDEX)
+        {
+            return Err(MeshSimplificationError::JointWeights);
+        }
+        let Some(positions) = self
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(VertexAttributeValues::as_float3)
+        else {
+            return Err(MeshSimplificationError::MissingPositions);
+        };
+
+        let mut simplifier = Simplifier::new(self, positions);
+        let target = (simplifier.alive as f32 * target_ratio.clamp(0.0, 1.0)).round() as usize;
+        simplifier.simplify(target);
+        Ok(simplifier.into_mesh(self))
+    }
+
+    /// Simplifies this mesh like [`Mesh::simplified`], in the
+    /// [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool).
+    ///
+    /// The single-threaded task pools, such as on the web, don't run tasks in the background: the
+    /// mesh is simplified when the returned future is first polled instead.
+    pub fn simplified_async(
+        self,
+        target_ratio: f32,
+    ) -> impl Future<Output = Result<Mesh, MeshSimplificationError>> + Send + 'static {
+        let simplify = async move { self.simplified(target_ratio) };
+        #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+        let simplify = bevy_tasks::AsyncComputeTaskPool::get().spawn(simplify);
+        simplify
+    }
+}
+
+/// The sum of the squared distances to a set of planes, as a symmetric 4x4 matrix.
+#[derive(Clone, Copy, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    /// Returns the quadric of the plane through `point` with `normal`, scaled by `weight`.
+    fn from_plane(normal: DVec3, point: DVec3, weight: f64) -> Self {
+        let DVec3 { x: a, y: b, z: c } = normal;
+        let d = -normal.dot(point);
+        Self([
+            a * a,
+            a * b,
+            a * c,
+            a * d,
+            b * b,
+            b * c,
+            b * d,
+            c * c,
+            c * d,
+            d * d,
+        ])
+        .scaled(weight)
+    }
+
+    fn scaled(self, weight: f64) -> Self {
+        Self(self.0.map(|coefficient| coefficient * weight))
+    }
+
+    fn evaluate(&self, point: DVec3) -> f64 {
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, dd] = self.0;
+        let DVec3 { x, y, z } = point;
+        aa * x * x
+            + 2.0 * ab * x * y
+            + 2.0 * ac * x * z
+            + 2.0 * ad * x
+            + bb * y * y
+            + 2.0 * bc * y * z
+            + 2.0 * bd * y
+            + cc * z * z
+            + 2.0 * cd * z
+            + dd
+    }
+}
+
+impl Add for Quadric {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl AddAssign for Quadric {
+    fn add_assign(&mut self, rhs: Self) {
+        for (coefficient, rhs) in self.0.iter_mut().zip(rhs.0) {
+            *coefficient += rhs;
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EdgeKind {
+    /// An edge of a single triangle, on an open border of the mesh.
+    Border,
+    /// An edge between two triangles sharing its vertices.
+    Interior,
+    /// An edge between two triangles with different vertices at one of its ends, such as on a UV
+    /// seam.
+    Seam,
+    /// An edge of more than two triangles.
+    NonManifold,
+}
+
+struct Edge {
+    positions: [u32; 2],
+    kind: EdgeKind,
+    /// One of the triangles of the edge.
+    triangle: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VertexKind {
+    /// A position with a single vertex, inside the mesh, which can collapse along any edge.
+    Manifold,
+    /// A position with a single vertex, on an open border, which only collapses along it.
+    Border,
+    /// A position with two vertices, on a seam, which only collapses along it.
+    Seam,
+    /// A position which doesn't move, such as the corner of a seam.
+    Locked,
+}
+
+/// A collapse of the position `from` onto the position `to`.
+struct Collapse {
+    cost: f64,
+    from: u32,
+    to: u32,
+}
+
+/// Simplifies a mesh, whose vertices with distinct attributes, the wedges, are grouped by their
+/// welded positions.
+struct Simplifier {
+    positions: Vec<Vec3>,
+    quadrics: Vec<Quadric>,
+    /// The position of each wedge.
+    wedge_positions: Vec<u32>,
+    /// The vertex of the mesh each wedge was created from.
+    wedge_vertices: Vec<u32>,
+    /// The wedges of each triangle, or `None` once the triangle has collapsed.
+    triangles: Vec<Option<[u32; 3]>>,
+    /// The number of triangles which haven't collapsed.
+    alive: usize,
+}
+
+impl Simplifier {
+    fn new(mesh: &Mesh, vertex_positions: &[[f32; 3]]) -> Self {
+        // Weld the positions closer than a millionth of the size of the mesh, such as the
+        // vertices on either side of a seam
+        let (min, max) = vertex_positions
+            .iter()
+            .fold((Vec3::MAX, Vec3::MIN), |(min, max), &position| {
+                (min.min(position.into()), max.max(position.into()))
+            });
+        let cell_size = ((max - min).max_element() * 1e-6).max(f32::MIN_POSITIVE);
+        let mut welded = HashMap::new();
+        let mut positions = vec![];
+        let vertex_position_ids: Vec<u32> = vertex_positions
+            .iter()
+            .map(|&position| {
+                let position = Vec3::from(position);
+                *welded
+                    .entry((position / cell_size).round().as_ivec3())
+                    .or_insert_with(|| {
+                        positions.push(position);
+                        positions.len() as u32 - 1
+                    })
+            })
+            .collect();
+
+        // The vertices at a position with the same attributes are the same wedge
+        let attributes: Vec<(&[u8], usize)> = mesh
+            .attributes()
+            .filter(|(id, _)| *id != Mesh::ATTRIBUTE_POSITION.id)
+            .map(|(_, values)| {
+                (
+                    values.get_bytes(),
+                    VertexFormat::from(values).get_size() as usize,
+                )
+            })
+            .collect();
+        let mut wedges = HashMap::new();
+        let mut wedge_positions = vec![];
+        let mut wedge_vertices = vec![];
+        let mut key = vec![];
+        let vertex_wedges: Vec<u32> = (0..vertex_positions.len())
+            .map(|vertex| {
+                key.clear();
+                key.extend_from_slice(&vertex_position_ids[vertex].to_le_bytes());
+                for &(bytes, size) in &attributes {
+                    key.extend_from_slice(
+                        bytes
+                            .get(vertex * size..(vertex + 1) * size)
+                            .unwrap_or_default(),
+                    );
+                }
+                *wedges.entry(key.clone()).or_insert_with(|| {
+                    wedge_positions.push(vertex_position_ids[vertex]);
+                    wedge_vertices.push(vertex as u32);
+                    wedge_vertices.len() as u32 - 1
+                })
+            })
+            .collect();
+
+        let corners: Vec<u32> = match mesh.indices() {
+            Some(indices) => indices.iter().map(|index| vertex_wedges[index]).collect(),
+            None => vertex_wedges,
+        };
+        // Degenerate triangles are dropped, as they have no shape to keep
+        let triangles: Vec<_> = corners
+            .chunks_exact(3)
+            .map(|corners| [corners[0], corners[1], corners[2]])
+            .filter(|wedges| {
+                let [a, b, c] = wedges.map(|wedge| wedge_positions[wedge as usize]);
+                a != b && b != c && c != a
+            })
+            .map(Some)
+            .collect();
+
+        let mut simplifier = Self {
+            quadrics: vec![Quadric::default(); positions.len()],
+            positions,
+            wedge_positions,
+            wedge_vertices,
+            alive: triangles.len(),
+            triangles,
+        };
+        simplifier.init_quadrics();
+        simplifier
+    }
+
+    fn position(&self, wedge: u32) -> u32 {
+        self.wedge_positions[wedge as usize]
+    }
+
+    fn triangle_positions(&self, wedges: [u32; 3]) -> [u32; 3] {
+        wedges.map(|wedge| self.position(wedge))
+    }
+
+    fn triangle_normal(&self, positions: [u32; 3]) -> DVec3 {
+        let [a, b, c] = positions.map(|position| self.positions[position as usize].as_dvec3());
+        (b - a).cross(c - a)
+    }
+
+    /// Sums the planes of the triangles around each position, weighted by their area, and the
+    /// planes perpendicular to the triangles along the borders and seams, keeping them in place.
+    fn init_quadrics(&mut self) {
+        for wedges in self.triangles.iter().flatten() {
+            let positions = self.triangle_positions(*wedges);
+            let normal = self.triangle_normal(positions);
+            let double_area = normal.length();
+            if double_area <= 0.0 {
+                continue;
+            }
+            let point = self.positions[positions[0] as usize].as_dvec3();
+            let quadric = Quadric::from_plane(normal / double_area, point, double_area * 0.5);
+            for position in positions {
+                self.quadrics[position as usize] += quadric;
+            }
+        }
+
+        for edge in self.edges() {
+            if !matches!(edge.kind, EdgeKind::Border | EdgeKind::Seam) {
+                continue;
+            }
+            let Some(wedges) = self.triangles[edge.triangle as usize] else {
+                continue;
+            };
+            let normal = self
+                .triangle_normal(self.triangle_positions(wedges))
+                .normalize_or_zero();
+            let [a, b] = edge
+                .positions
+                .map(|position| self.positions[position as usize].as_dvec3());
+            let plane_normal = (b - a).cross(normal).normalize_or_zero();
+            let quadric =
+                Quadric::from_plane(plane_normal, a, BORDER_WEIGHT * (b - a).length_squared());
+            for position in edge.positions {
+                self.quadrics[position as usize] += quadric;
+            }
+        }
+    }
+
+    /// Returns the edges between the positions of the triangles, sorted by their positions.
+    fn edges(&self) -> Vec<Edge> {
+        // The sides of the triangles, with their positions and wedges sorted by position
+        let mut sides = vec![];
+        for (triangle, wedges) in self.triangles.iter().enumerate() {
+            let Some(wedges) = wedges else {
+                continue;
+            };
+            for corner in 0..3 {
+                let (a, b) = (wedges[corner], wedges[(corner + 1) % 3]);
+                let (a, b) = if self.position(a) < self.position(b) {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                sides.push((
+                    [self.position(a), self.position(b)],
+                    [a, b],
+                    triangle as u32,
+                ));
+            }
+        }
+        sides.sort_unstable();
+        sides
+            .chunk_by(|a, b| a.0 == b.0)
+            .map(|sides| {
+                let kind = match sides {
+                    [_] => EdgeKind::Border,
+                    [(_, a, _), (_, b, _)] if a == b => EdgeKind::Interior,
+                    [_, _] => EdgeKind::Seam,
+                    _ => EdgeKind::NonManifold,
+                };
+                Edge {
+                    positions: sides[0].0,
+                    kind,
+                    triangle: sides[0].2,
+                }
+            })
+            .collect()
+    }
+
+    fn vertex_kinds(&self, edges: &[Edge]) -> Vec<VertexKind> {
+        let mut border_edges = vec![0; self.positions.len()];
+        let mut seam_edges = vec![0; self.positions.len()];
+        let mut non_manifold = vec![false; self.positions.len()];
+        for edge in edges {
+            for position in edge.positions {
+                let position = position as usize;
+                match edge.kind {
+                    EdgeKind::Border => border_edges[position] += 1,
+                    EdgeKind::Seam => seam_edges[position] += 1,
+                    EdgeKind::NonManifold => non_manifold[position] = true,
+                    EdgeKind::Interior => {}
+                }
+            }
+        }
+
+        let mut position_wedges: Vec<(u32, u32)> = self
+            .triangles
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|&wedge| (self.position(wedge), wedge))
+            .collect();
+        position_wedges.sort_unstable();
+        position_wedges.dedup();
+        let mut wedge_counts = vec![0; self.positions.len()];
+        for (position, _) in position_wedges {
+            wedge_counts[position as usize] += 1;
+        }
+
+        (0..self.positions.len())
+            .map(|position| {
+                match (
+                    non_manifold[position],
+                    border_edges[position],
+                    seam_edges[position],
+                    wedge_counts[position],
+                ) {
+                    (false, 0, _, 1) => VertexKind::Manifold,
+                    (false, 2, _, 1) => VertexKind::Border,
+                    (false, 0, 2, 2) => VertexKind::Seam,
+                    _ => VertexKind::Locked,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the triangles around each position.
+    fn adjacency(&self) -> Vec<Vec<u32>> {
+        let mut adjacency = vec![vec![]; self.positions.len()];
+        for (triangle, wedges) in self.triangles.iter().enumerate() {
+            for wedge in wedges.iter().flatten() {
+                adjacency[self.position(*wedge) as usize].push(triangle as u32);
+            }
+        }
+        adjacency
+    }
+
+    /// Returns the cheapest of the collapses of `edge` which keep the borders and seams.
+    fn best_collapse(&self, edge: &Edge, kinds: &[VertexKind]) -> Option<Collapse> {
+        let [a, b] = edge.positions;
+        [(a, b), (b, a)]
+            .into_iter()
+            .filter(|&(from, to)| {
+                let to_kind = kinds[to as usize];
+                match kinds[from as usize] {
+                    VertexKind::Manifold => true,
+                    VertexKind::Border => {
+                        edge.kind == EdgeKind::Border
+                            && matches!(to_kind, VertexKind::Border | VertexKind::Locked)
+                    }
+                    VertexKind::Seam => {
+                        edge.kind == EdgeKind::Seam
+                            && matches!(to_kind, VertexKind::Seam | VertexKind::Locked)
+                    }
+                    VertexKind::Locked => false,
+                }
+            })
+            .map(|(from, to)| Collapse {
+                cost: (self.quadrics[from as usize] + self.quadrics[to as usize])
+                    .evaluate(self.positions[to as usize].as_dvec3()),
+                from,
+                to,
+            })
+            .min_by(|a, b| a.cost.total_cmp(&b.cost))
+    }
+
+    /// Collapses edges until `target` triangles are left, or no edge can be collapsed anymore.
+    fn simplify(&mut self, target: usize) {
+        while self.alive > target {
+            let edges = self.edges();
+            let kinds = self.vertex_kinds(&edges);
+            let adjacency = self.adjacency();
+            let mut collapses: Vec<_> = edges
+                .iter()
+                .filter_map(|edge| self.best_collapse(edge, &kinds))
+                .collect();
+            collapses.sort_by(|a, b| {
+                a.cost
+                    .total_cmp(&b.cost)
+                    .then(a.from.cmp(&b.from))
+                    .then(a.to.cmp(&b.to))
+            });
+
+            // Each collapse removes about two triangles. The positions around a collapse are
+            // touched, and only collapse again in the next pass, once the adjacency is updated.
+            let goal = ((self.alive - target) / 2).max(1);
+            let mut touched = vec![false; self.positions.len()];
+            let mut collapsed = 0;
+            for collapse in collapses {
+                if collapsed >= goal || self.alive <= target {
+                    break;
+                }
+                if touched[collapse.from as usize] || touched[collapse.to as usize] {
+                    continue;
+                }
+                if self.try_collapse(&collapse, &adjacency, &mut touched) {
+                    collapsed += 1;
+                }
+            }
+            if collapsed == 0 {
+                break;
+            }
+        }
+    }
+
+    /// Collapses `collapse.from` onto `collapse.to`, unless it would change the topology of the
+    /// mesh, fold a triangle over, or break a seam, returning whether it was collapsed.
+    fn try_collapse(
+        &mut self,
+        collapse: &Collapse,
+        adjacency: &[Vec<u32>],
+        touched: &mut [bool],
+    ) -> bool {
+        let Collapse { from, to, .. } = *collapse;
+        let triangles = &adjacency[from as usize];
+
+        // Each wedge at `from` is replaced by the wedge at `to` in the triangles collapsing
+        // with the edge, which must be the same in all of them
+        let mut wedge_map: Vec<(u32, u32)> = vec![];
+        let mut opposite = vec![];
+        for &triangle in triangles {
+            let Some(wedges) = self.triangles[triangle as usize] else {
+                continue;
+            };
+            let positions = self.triangle_positions(wedges);
+            let (Some(from_corner), Some(to_corner)) = (
+                positions.iter().position(|&p| p == from),
+                positions.iter().position(|&p| p == to),
+            ) else {
+                continue;
+            };
+            opposite.push(positions[3 - from_corner - to_corner]);
+            let (from_wedge, to_wedge) = (wedges[from_corner], wedges[to_corner]);
+            match wedge_map.iter().find(|(wedge, _)| *wedge == from_wedge) {
+                Some(&(_, mapped)) if mapped != to_wedge => return false,
+                Some(_) => {}
+                None => wedge_map.push((from_wedge, to_wedge)),
+            }
+        }
+        let mapped = |wedge: u32| {
+            wedge_map
+                .iter()
+                .find(|(from_wedge, _)| *from_wedge == wedge)
+                .map(|(_, to_wedge)| *to_wedge)
+        };
+        let all_mapped = triangles.iter().all(|&triangle| {
+            self.triangles[triangle as usize].map_or(true, |wedges| {
+                wedges
+                    .iter()
+                    .filter(|&&wedge| self.position(wedge) == from)
+                    .all(|&wedge| mapped(wedge).is_some())
+            })
+        });
+        if !all_mapped {
+            return false;
+        }
+
+        // The positions adjacent to both ends of the edge must be the opposite corners of its
+        // triangles, or the collapse would make the mesh non-manifold
+        let neighbors = |position: u32| {
+            let mut neighbors: Vec<u32> = adjacency[position as usize]
+                .iter()
+                .filter_map(|&triangle| self.triangles[triangle as usize])
+                .flat_map(|wedges| self.triangle_positions(wedges))
+                .filter(|&neighbor| neighbor != position)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        };
+        let to_neighbors = neighbors(to);
+        let common_neighbors = neighbors(from)
+            .iter()
+            .filter(|neighbor| to_neighbors.binary_search(neighbor).is_ok())
+            .count();
+        let triangle_count = opposite.len();
+        opposite.sort_unstable();
+        opposite.dedup();
+        if common_neighbors != opposite.len() || opposite.len() != triangle_count {
+            return false;
+        }
+
+        // The remaining triangles must not fold over
+        for &triangle in triangles {
+            let Some(wedges) = self.triangles[triangle as usize] else {
+                continue;
+            };
+            let positions = self.triangle_positions(wedges);
+            if positions.contains(&to) {
+                continue;
+            }
+            let before = self.triangle_normal(positions).normalize_or_zero();
+            let after = self
+                .triangle_normal(positions.map(|p| if p == from { to } else { p }))
+                .normalize_or_zero();
+            if before.dot(after) <= 1e-3 {
+                return false;
+            }
+        }
+
+        touched[from as usize] = true;
+        touched[to as usize] = true;
+        for &triangle in triangles {
+            let Some(wedges) = &mut self.triangles[triangle as usize] else {
+                continue;
+            };
+            let wedge_positions = &self.wedge_positions;
+            for &wedge in wedges.iter() {
+                touched[wedge_positions[wedge as usize] as usize] = true;
+            }
+            if wedges
+                .iter()
+                .any(|&wedge| wedge_positions[wedge as usize] == to)
+            {
+                self.triangles[triangle as usize] = None;
+                self.alive -= 1;
+                continue;
+            }
+            for wedge in wedges.iter_mut() {
+                if let Some(to_wedge) = mapped(*wedge) {
+                    *wedge = to_wedge;
+                }
+            }
+        }
+        let from_quadric = self.quadrics[from as usize];
+        self.quadrics[to as usize] += from_quadric;
+        true
+    }
+
+    /// Returns a copy of `mesh` with the remaining triangles, and the wedges they use.
+    fn into_mesh(self, mesh: &Mesh) -> Mesh {
+        let mut new_wedges = vec![u32::MAX; self.wedge_vertices.len()];
+        let mut kept_wedges = vec![];
+        let indices: Vec<u32> = self
+            .triangles
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|&wedge| {
+                if new_wedges[wedge as usize] == u32::MAX {
+                    new_wedges[wedge as usize] = kept_wedges.len() as u32;
+                    kept_wedges.push(wedge);
+                }
+                new_wedges[wedge as usize]
+            })
+            .collect();
+
+        let mut simplified = mesh.clone();
+        // Duplicating the vertices of the kept wedges keeps their attributes, in order
+        simplified.indices = Some(Indices::U32(
+            kept_wedges
+                .iter()
+                .map(|&wedge| self.wedge_vertices[wedge as usize])
+                .collect(),
+        ));
+        simplified.duplicate_vertices();
+        simplified.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            kept_wedges
+                .iter()
+                .map(|&wedge| self.positions[self.position(wedge) as usize].to_array())
+                .collect::<Vec<_>>(),
+        );
+        let indices = match mesh.indices() {
+            Some(Indices::U16(_)) if kept_wedges.len() <= usize::from(u16::MAX) + 1 => {
+                Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+            }
+            _ => Indices::U32(indices),
+        };
+        simplified.insert_indices(indices);
+        simplified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::primitives::Sphere;
+
+    use super::*;
+    use crate::{mesh::Meshable, render_asset::RenderAssetUsages};
+
+    fn triangle_count(mesh: &Mesh) -> usize {
+        mesh.indices().unwrap().len() / 3
+    }
+
+    fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+            .unwrap()
+            .as_float3()
+            .unwrap()
+    }
+
+    /// Returns `true` if every edge of the mesh is shared by two triangles, in opposite
+    /// directions.
+    fn is_watertight(mesh: &Mesh) -> bool {
+        let positions = positions(mesh);
+        let key = |index: usize| positions[index].map(f32::to_bits);
+        let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+        let mut edges = HashMap::new();
+        for triangle in indices.chunks_exact(3) {
+            for corner in 0..3 {
+                let edge = (key(triangle[corner]), key(triangle[(corner + 1) % 3]));
+                *edges.entry(edge).or_insert(0) += 1;
+            }
+        }
+        edges
+            .iter()
+            .all(|(&(a, b), &count)| count == 1 && edges.get(&(b, a)) == Some(&1))
+    }
+
+    /// The tangent axes of a face of the cube with `normal`, along which its UVs increase.
+    fn face_axes(normal: Vec3) -> (Vec3, Vec3) {
+        let u = normal.any_orthonormal_vector();
+        (u, normal.cross(u))
+    }
+
+    /// A cube whose faces are grids of `subdivisions` squares, with their own vertices, so
+    /// that its edges are UV seams and hard edges.
+    fn seamed_cube(subdivisions: u32) -> Mesh {
+        let mut positions = vec![];
+        let mut normals = vec![];
+        let mut uvs = vec![];
+        let mut indices = vec![];
+        for normal in [
+            Vec3::X,
+            Vec3::NEG_X,
+            Vec3::Y,
+            Vec3::NEG_Y,
+            Vec3::Z,
+            Vec3::NEG_Z,
+        ] {
+            let (u, v) = face_axes(normal);
+            let first = positions.len() as u32;
+            for y in 0..=subdivisions {
+                for x in 0..=subdivisions {
+                    let s = x as f32 / subdivisions as f32;
+                    let t = y as f32 / subdivisions as f32;
+                    positions.push((normal * 0.5 + u * (s - 0.5) + v * (t - 0.5)).to_array());
+                    normals.push(normal.to_array());
+                    uvs.push([s, t]);
+                }
+            }
+            let row = subdivisions + 1;
+            for y in 0..subdivisions {
+                for x in 0..subdivisions {
+                    let i = first + y * row + x;
+                    indices.extend([i, i + 1, i + row + 1, i, i + row + 1, i + row]);
+                }
+            }
+        }
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices))
+    }
+
+    #[test]
+    fn simplify_sphere() {
+        let sphere = Sphere::new(1.0).mesh().uv(32, 18);
+        assert_eq!(triangle_count(&sphere), 1088);
+
+        let simplified = sphere.simplified(0.5).unwrap();
+        assert!(triangle_count(&simplified) <= 544);
+        assert!(triangle_count(&simplified) > 100);
+        assert!(is_watertight(&simplified));
+        for position in positions(&simplified) {
+            assert!((Vec3::from(*position).length() - 1.0).abs() < 1e-5);
+        }
+
+        // The output only depends on the input
+        let again = sphere.simplified(0.5).unwrap();
+        assert_eq!(
+            simplified.get_vertex_buffer_data(),
+            again.get_vertex_buffer_data()
+        );
+        assert_eq!(
+            simplified.get_index_buffer_bytes(),
+            again.get_index_buffer_bytes()
+        );
+    }
+
+    #[test]
+    fn simplify_seamed_cube() {
+        let cube = seamed_cube(4);
+        assert_eq!(triangle_count(&cube), 192);
+
+        for mesh in [cube.clone(), cube.with_duplicated_vertices()] {
+            let simplified = mesh.simplified(0.25).unwrap();
+            let count = triangle_count(&simplified);
+            // The corners of the cube are kept, so each face has at least two triangles
+            assert!((12..=48).contains(&count), "{count} triangles");
+            assert!(is_watertight(&simplified));
+
+            // The triangles stay on the faces of the cube, with the normals and UVs of their face
+            let positions = positions(&simplified);
+            let Some(VertexAttributeValues::Float32x3(normals)) =
+                simplified.attribute(Mesh::ATTRIBUTE_NORMAL)
+            else {
+                panic!("the normals are missing");
+            };
+            let Some(VertexAttributeValues::Float32x2(uvs)) =
+                simplified.attribute(Mesh::ATTRIBUTE_UV_0)
+            else {
+                panic!("the UVs are missing");
+            };
+            let indices: Vec<usize> = simplified.indices().unwrap().iter().collect();
+            for triangle in indices.chunks_exact(3) {
+                let normal = Vec3::from(normals[triangle[0]]);
+                let [a, b, c] = [0, 1, 2].map(|corner| Vec3::from(positions[triangle[corner]]));
+                assert!((b - a).cross(c - a).normalize().dot(normal) > 0.999);
+                let (u, v) = face_axes(normal);
+                for &index in triangle {
+                    assert_eq!(Vec3::from(normals[index]), normal);
+                    let position = Vec3::from(positions[index]);
+                    assert!((position.dot(normal) - 0.5).abs() < 1e-5);
+                    assert!((position.dot(u) + 0.5 - uvs[index][0]).abs() < 1e-5);
+                    assert!((position.dot(v) + 0.5 - uvs[index][1]).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unsupported_meshes_are_reported() {
+        let mut mesh = seamed_cube(1);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT, vec![[1.0, 0.0, 0.0, 0.0]; 24]);
+        assert_eq!(
+            mesh.simplified(0.5).err(),
+            Some(MeshSimplificationError::JointWeights)
+        );
+
+        let lines = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default());
+        assert_eq!(
+            lines.simplified(0.5).err(),
+            Some(MeshSimplificationError::UnsupportedTopology(
+                PrimitiveTopology::LineList
+            ))
+        );
+    }
+}
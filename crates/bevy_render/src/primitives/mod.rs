@@ -1,7 +1,10 @@
 use std::borrow::Borrow;
 
 use bevy_ecs::{component::Component, entity::EntityHashMap, reflect::ReflectComponent};
-use bevy_math::{Affine3A, Mat3A, Mat4, Vec3, Vec3A, Vec4, Vec4Swizzles};
+use bevy_math::{
+    bounding::{Aabb3d, BoundingVolume, IntersectsVolume},
+    Affine3A, Mat3A, Mat4, Vec3, Vec3A, Vec4, Vec4Swizzles,
+};
 use bevy_reflect::prelude::*;
 
 /// An axis-aligned bounding box, defined by:
@@ -302,6 +305,23 @@ impl Frustum {
     }
 }
 
+impl IntersectsVolume<Aabb3d> for Frustum {
+    /// Checks if an axis-aligned box in world space intersects the frustum, including its near and
+    /// far planes.
+    ///
+    /// Like the other tests of the frustum, this may report boxes near its corners as intersecting
+    /// even if they are just outside of it.
+    #[inline]
+    fn intersects(&self, aabb: &Aabb3d) -> bool {
+        let center = aabb.center().extend(1.0);
+        let half_size = aabb.half_size();
+        self.half_spaces.iter().all(|half_space| {
+            let relative_radius = half_space.normal().abs().dot(half_size);
+            half_space.normal_d().dot(center) + relative_radius > 0.0
+        })
+    }
+}
+
 #[derive(Component, Clone, Debug, Default, Reflect)]
 #[reflect(Component, Default)]
 pub struct CubemapFrusta {
@@ -504,4 +524,14 @@ mod tests {
             Aabb::from_min_max(Vec3::new(-1.0, -5.0, 0.0), Vec3::new(2.0, 0.0, 1.0))
         );
     }
+
+    #[test]
+    fn intersects_aabb_3d_frustum() {
+        let frustum = frustum();
+        // Inside, crossing the boundary, and outside the frustum
+        assert!(frustum.intersects(&Aabb3d::new(Vec3::ZERO, Vec3::splat(0.1))));
+        assert!(frustum.intersects(&Aabb3d::new(Vec3::new(1.5, 0.0, 0.0), Vec3::splat(1.0))));
+        assert!(!frustum.intersects(&Aabb3d::new(Vec3::new(10.0, 0.0, 0.0), Vec3::splat(0.5))));
+        assert!(!frustum.intersects(&Aabb3d::new(Vec3::new(0.0, -3.0, 0.0), Vec3::splat(0.5))));
+    }
 }
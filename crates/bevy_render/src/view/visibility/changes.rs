@@ -0,0 +1,304 @@
+use bevy_ecs::{
+    entity::{EntityHashMap, EntityHashSet},
+    prelude::*,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+use crate::camera::Camera;
+
+use super::{ViewVisibility, VisibleEntities};
+
+/// Sent when the visibility of an entity with [`TrackVisibility`] changes, as an event and as an
+/// observer trigger targeting the entity.
+///
+/// An entity starting to be tracked, such as when it is spawned, changes from an unknown
+/// visibility to its first computed one.
+///
+/// This is sent in [`PostUpdate`](bevy_app::PostUpdate), in
+/// [`VisibilitySystems::SendVisibilityChanges`](super::VisibilitySystems::SendVisibilityChanges).
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisibilityChanged {
+    /// The entity whose visibility changed.
+    pub entity: Entity,
+    /// Whether the entity is now visible.
+    pub visible: bool,
+    /// The view the entity became visible or hidden in, with
+    /// [`VisibilityTrackingMode::PerView`], or `None` with [`VisibilityTrackingMode::AnyView`].
+    pub view: Option<Entity>,
+}
+
+/// How the visibility of an entity with [`TrackVisibility`] is tracked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, PartialEq, Hash)]
+pub enum VisibilityTrackingMode {
+    /// Tracks whether the entity is visible in any view, as given by its [`ViewVisibility`].
+    #[default]
+    AnyView,
+    /// Tracks whether the entity is visible in each active view, as given by the
+    /// [`VisibleEntities`] of the view.
+    ///
+    /// This has to go through the visible entities of every view, so it is more expensive than
+    /// [`AnyView`](Self::AnyView).
+    PerView,
+}
+
+/// Sends [`VisibilityChanged`] when the computed visibility of this entity changes.
+///
+/// Entities without this component are not tracked, and the tracking system doesn't run at all
+/// while no entity has it.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct TrackVisibility {
+    /// Whether the visibility is tracked in any view or in each view.
+    pub mode: VisibilityTrackingMode,
+    /// The visibility in any view last frame, if tracked then.
+    #[reflect(ignore)]
+    visible: Option<bool>,
+    /// The views the entity was visible in last frame, if tracked per view then.
+    #[reflect(ignore)]
+    visible_views: Option<EntityHashSet>,
+}
+
+impl TrackVisibility {
+    /// Tracks whether the entity is visible in any view.
+    pub fn any_view() -> Self {
+        Self::default()
+    }
+
+    /// Tracks whether the entity is visible in each active view.
+    pub fn per_view() -> Self {
+        Self {
+            mode: VisibilityTrackingMode::PerView,
+            ..Self::default()
+        }
+    }
+}
+
+/// Sends a [`VisibilityChanged`] for each change of the visibility of the entities with
+/// [`TrackVisibility`].
+///
+/// This system is part of the [`VisibilitySystems::SendVisibilityChanges`](super::VisibilitySystems::SendVisibilityChanges)
+/// set, and only runs while some entity has [`TrackVisibility`].
+pub fn send_visibility_changes(
+    mut commands: Commands,
+    mut events: EventWriter<VisibilityChanged>,
+    mut tracked: Query<(Entity, &mut TrackVisibility, &ViewVisibility)>,
+    views: Query<(Entity, &Camera, &VisibleEntities)>,
+    mut active_views: Local<Vec<Entity>>,
+    mut views_of_entities: Local<EntityHashMap<Vec<Entity>>>,
+    mut visible_views: Local<EntityHashSet>,
+) {
+    let mut send = |event: VisibilityChanged| {
+        events.send(event);
+        commands.trigger_targets(event, event.entity);
+    };
+
+    // Only go through the visible entities of the views if they are needed
+    active_views.clear();
+    views_of_entities.clear();
+    if tracked
+        .iter()
+        .any(|(_, track, _)| track.mode == VisibilityTrackingMode::PerView)
+    {
+        for (view, camera, visible_entities) in &views {
+            if !camera.is_active {
+                continue;
+            }
+            active_views.push(view);
+            for &entity in visible_entities.entities.values().flatten() {
+                if tracked
+                    .get(entity)
+                    .is_ok_and(|(_, track, _)| track.mode == VisibilityTrackingMode::PerView)
+                {
+                    views_of_entities.entry(entity).or_default().push(view);
+                }
+            }
+        }
+    }
+
+    for (entity, mut track, view_visibility) in &mut tracked {
+        match track.mode {
+            VisibilityTrackingMode::AnyView => {
+                // Only write to the component when something changes, to keep its change ticks
+                if track.visible_views.is_some() {
+                    track.visible_views = None;
+                }
+                let visible = view_visibility.get();
+                if track.visible != Some(visible) {
+                    track.visible = Some(visible);
+                    send(VisibilityChanged {
+                        entity,
+                        visible,
+                        view: None,
+                    });
+                }
+            }
+            VisibilityTrackingMode::PerView => {
+                if track.visible.is_some() {
+                    track.visible = None;
+                }
+                visible_views.clear();
+                visible_views.extend(views_of_entities.get(&entity).into_iter().flatten());
+                match &track.visible_views {
+                    None => {
+                        for &view in active_views.iter() {
+                            send(VisibilityChanged {
+                                entity,
+                                visible: visible_views.contains(&view),
+                                view: Some(view),
+                            });
+                        }
+                    }
+                    Some(previous) if previous != &*visible_views => {
+                        for &view in visible_views.difference(previous) {
+                            send(VisibilityChanged {
+                                entity,
+                                visible: true,
+                                view: Some(view),
+                            });
+                        }
+                        // This includes the views that were deactivated or despawned
+                        for &view in previous.difference(&*visible_views) {
+                            send(VisibilityChanged {
+                                entity,
+                                visible: false,
+                                view: Some(view),
+                            });
+                        }
+                    }
+                    Some(_) => continue,
+                }
+                // The set of last frame is filled again for the next entity
+                let previous = track
+                    .visible_views
+                    .replace(std::mem::take(&mut *visible_views));
+                *visible_views = previous.unwrap_or_default();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::{component::Tick, event::Events};
+
+    use super::*;
+
+    fn drain_changes(app: &mut App) -> Vec<VisibilityChanged> {
+        let mut events = app.world_mut().resource_mut::<Events<VisibilityChanged>>();
+        events.drain().collect()
+    }
+
+    fn track_last_changed(app: &App, entity: Entity) -> Tick {
+        app.world()
+            .entity(entity)
+            .get_ref::<TrackVisibility>()
+            .unwrap()
+            .last_changed()
+    }
+
+    #[test]
+    fn any_view_changes() {
+        let mut app = App::new();
+        app.add_event::<VisibilityChanged>()
+            .add_systems(PostUpdate, send_visibility_changes);
+
+        let entity = app
+            .world_mut()
+            .spawn((TrackVisibility::any_view(), ViewVisibility::HIDDEN))
+            .id();
+        // Untracked entities don't send anything
+        app.world_mut().spawn(ViewVisibility::HIDDEN);
+
+        // The initial visibility counts as a change
+        app.update();
+        let hidden = VisibilityChanged {
+            entity,
+            visible: false,
+            view: None,
+        };
+        assert_eq!(drain_changes(&mut app), [hidden]);
+
+        // Nothing is written to the tracked entity while its visibility doesn't change
+        let last_changed = track_last_changed(&app, entity);
+        app.update();
+        assert_eq!(drain_changes(&mut app), []);
+        assert_eq!(track_last_changed(&app, entity), last_changed);
+
+        app.world_mut()
+            .get_mut::<ViewVisibility>(entity)
+            .unwrap()
+            .set();
+        app.update();
+        assert_eq!(
+            drain_changes(&mut app),
+            [VisibilityChanged {
+                visible: true,
+                ..hidden
+            }]
+        );
+    }
+
+    #[test]
+    fn per_view_changes() {
+        let mut app = App::new();
+        app.add_event::<VisibilityChanged>()
+            .add_systems(PostUpdate, send_visibility_changes);
+
+        let entity = app
+            .world_mut()
+            .spawn((TrackVisibility::per_view(), ViewVisibility::HIDDEN))
+            .id();
+        let mut visible_entities = VisibleEntities::default();
+        visible_entities.push::<()>(entity);
+        let view = app
+            .world_mut()
+            .spawn((Camera::default(), visible_entities))
+            .id();
+        let other_view = app
+            .world_mut()
+            .spawn((Camera::default(), VisibleEntities::default()))
+            .id();
+
+        app.update();
+        let mut changes = drain_changes(&mut app);
+        changes.sort_by_key(|change| change.view);
+        let mut expected = [
+            VisibilityChanged {
+                entity,
+                visible: true,
+                view: Some(view),
+            },
+            VisibilityChanged {
+                entity,
+                visible: false,
+                view: Some(other_view),
+            },
+        ];
+        expected.sort_by_key(|change| change.view);
+        assert_eq!(changes, expected);
+
+        // Moving the entity to the other view is a change in both
+        app.world_mut()
+            .get_mut::<VisibleEntities>(view)
+            .unwrap()
+            .clear::<()>();
+        app.world_mut()
+            .get_mut::<VisibleEntities>(other_view)
+            .unwrap()
+            .push::<()>(entity);
+        app.update();
+        let mut changes = drain_changes(&mut app);
+        changes.sort_by_key(|change| change.view);
+        for change in &mut expected {
+            change.visible = !change.visible;
+        }
+        assert_eq!(changes, expected);
+
+        let last_changed = track_last_changed(&app, entity);
+        app.update();
+        assert_eq!(drain_changes(&mut app), []);
+        assert_eq!(track_last_changed(&app, entity), last_changed);
+    }
+}
@@ -1,10 +1,14 @@
+mod changes;
 mod range;
 mod render_layers;
+mod spatial_index;
 
 use std::any::TypeId;
 
+pub use changes::*;
 pub use range::*;
 pub use render_layers::*;
+pub use spatial_index::*;
 
 use bevy_app::{Plugin, PostUpdate};
 use bevy_asset::{Assets, Handle};
@@ -247,6 +251,9 @@ pub enum VisibilitySystems {
     /// Label for the [`check_visibility`] system updating [`ViewVisibility`]
     /// of each entity and the [`VisibleEntities`] of each view.
     CheckVisibility,
+    /// Label for the [`send_visibility_changes`] system sending [`VisibilityChanged`] events,
+    /// after [`CheckVisibility`](Self::CheckVisibility).
+    SendVisibilityChanges,
 }
 
 pub struct VisibilityPlugin;
@@ -261,12 +268,17 @@ impl Plugin for VisibilityPlugin {
                 .before(CheckVisibility)
                 .after(TransformSystem::TransformPropagate),
         )
+        .configure_sets(PostUpdate, SendVisibilityChanges.after(CheckVisibility))
+        .add_event::<VisibilityChanged>()
         .add_systems(
             PostUpdate,
             (
                 calculate_bounds.in_set(CalculateBounds),
                 (visibility_propagate_system, reset_view_visibility).in_set(VisibilityPropagate),
                 check_visibility::<WithMesh>.in_set(CheckVisibility),
+                send_visibility_changes
+                    .run_if(any_with_component::<TrackVisibility>)
+                    .in_set(SendVisibilityChanges),
             ),
         );
     }
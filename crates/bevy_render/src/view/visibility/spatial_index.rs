@@ -0,0 +1,270 @@
+//! A bounding volume hierarchy over the bounding boxes of entities, for game logic queries such as
+//! selection boxes or lines of sight.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
+use bevy_math::bounding::{Aabb3d, Bvh3d, IntersectsVolume, RayCast3d};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+
+use crate::primitives::Aabb;
+
+use super::VisibilitySystems;
+
+/// Adds the [`SpatialIndex`] resource, which tracks the bounding boxes of the entities with a
+/// [`SpatialIndexed`] component.
+pub struct SpatialIndexPlugin;
+
+impl Plugin for SpatialIndexPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SpatialIndexed>()
+            .init_resource::<SpatialIndex>()
+            .add_systems(
+                PostUpdate,
+                update_spatial_index
+                    .after(TransformSystem::TransformPropagate)
+                    .after(VisibilitySystems::CalculateBounds),
+            );
+    }
+}
+
+/// Opts an entity into the [`SpatialIndex`].
+///
+/// The entity also needs a [`GlobalTransform`] and an [`Aabb`], which is added automatically to
+/// meshes and sprites.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct SpatialIndexed;
+
+/// The world space bounding boxes of the entities with a [`SpatialIndexed`] component, in a
+/// [`Bvh3d`] for fast queries.
+///
+/// It is maintained by [`update_spatial_index`] in [`PostUpdate`] when the [`SpatialIndexPlugin`]
+/// is added: the hierarchy is rebuilt when entities are added or removed, and refitted when they
+/// move. Since refitting slows the queries down as the entities move away from where they were,
+/// the hierarchy is also rebuilt after [`SpatialIndex::MAX_REFITS`] refits, or when its
+/// [cost](Bvh3d::cost) grows by a factor of [`SpatialIndex::MAX_COST_GROWTH`]. Systems querying
+/// it after [`update_spatial_index`] see the bounding boxes of this frame, and the ones running
+/// before see those of the previous frame.
+///
+/// Queries return the same entities in the same order for the same bounding boxes.
+#[derive(Resource, Default, Debug)]
+pub struct SpatialIndex {
+    bvh: Bvh3d<Entity>,
+    /// The entities in the hierarchy, to tell whether removed components affect it.
+    entities: EntityHashSet,
+    /// The number of refits since the hierarchy was built.
+    refits: u32,
+    /// The cost of the hierarchy when it was built.
+    built_cost: f32,
+}
+
+impl SpatialIndex {
+    /// The number of refits after which the hierarchy is rebuilt.
+    pub const MAX_REFITS: u32 = 300;
+
+    /// How many times the cost of the hierarchy can grow from refits before it's rebuilt.
+    pub const MAX_COST_GROWTH: f32 = 1.3;
+
+    /// Builds the hierarchy over `items`.
+    fn rebuild(&mut self, mut items: Vec<(Entity, Aabb3d)>) {
+        // Sort by entity so that the hierarchy doesn't depend on the order of the query
+        items.sort_by_key(|(entity, _)| *entity);
+        self.entities.clear();
+        self.entities
+            .extend(items.iter().map(|(entity, _)| *entity));
+        self.bvh = Bvh3d::new(items);
+        self.refits = 0;
+        self.built_cost = self.bvh.cost();
+    }
+
+    /// Returns the hierarchy of the bounding boxes of the indexed entities.
+    pub fn bvh(&self) -> &Bvh3d<Entity> {
+        &self.bvh
+    }
+
+    /// Returns the entities whose bounding box intersects `volume`, such as an [`Aabb3d`], a
+    /// [`BoundingSphere`](bevy_math::bounding::BoundingSphere), or the
+    /// [`Frustum`](crate::primitives::Frustum) of a camera.
+    pub fn intersecting(&self, volume: &impl IntersectsVolume<Aabb3d>) -> Vec<Entity> {
+        self.bvh.intersecting(volume).into_iter().copied().collect()
+    }
+
+    /// Returns the entities whose bounding box is hit by `ray`, with the distance along the ray to
+    /// their bounding box, sorted from the nearest to the furthest.
+    pub fn cast_ray(&self, ray: &RayCast3d) -> Vec<(Entity, f32)> {
+        self.bvh
+            .cast_ray(ray)
+            .into_iter()
+            .map(|(entity, distance)| (*entity, distance))
+            .collect()
+    }
+
+    /// Returns the entity whose bounding box is hit first by `ray`, with the distance along the ray
+    /// to its bounding box.
+    pub fn cast_ray_first(&self, ray: &RayCast3d) -> Option<(Entity, f32)> {
+        self.bvh
+            .cast_ray_first(ray)
+            .map(|(entity, distance)| (*entity, distance))
+    }
+}
+
+/// The bounding box in world space of the local `aabb` transformed by `transform`.
+fn world_aabb(transform: &GlobalTransform, aabb: &Aabb) -> Aabb3d {
+    let affine = transform.affine();
+    let half_size = affine.matrix3.x_axis.abs() * aabb.half_extents.x
+        + affine.matrix3.y_axis.abs() * aabb.half_extents.y
+        + affine.matrix3.z_axis.abs() * aabb.half_extents.z;
+    Aabb3d::new(affine.transform_point3a(aabb.center), half_size)
+}
+
+/// Rebuilds the [`SpatialIndex`] when indexed entities are added or removed, and refits it to the
+/// entities that moved otherwise, rebuilding it when refits made it too slow to query.
+pub fn update_spatial_index(
+    mut index: ResMut<SpatialIndex>,
+    indexed: Query<(Entity, Ref<GlobalTransform>, Ref<Aabb>), With<SpatialIndexed>>,
+    added: Query<
+        (),
+        (
+            With<SpatialIndexed>,
+            With<GlobalTransform>,
+            With<Aabb>,
+            Or<(Added<SpatialIndexed>, Added<GlobalTransform>, Added<Aabb>)>,
+        ),
+    >,
+    mut removed_indexed: RemovedComponents<SpatialIndexed>,
+    mut removed_transforms: RemovedComponents<GlobalTransform>,
+    mut removed_aabbs: RemovedComponents<Aabb>,
+) {
+    // Despawned entities are reported as removed too, and only indexed ones matter
+    let mut removed = false;
+    for entity in removed_indexed
+        .read()
+        .chain(removed_transforms.read())
+        .chain(removed_aabbs.read())
+    {
+        removed |= index.entities.contains(&entity);
+    }
+
+    if removed || !added.is_empty() || index.entities.len() != indexed.iter().len() {
+        let items = indexed
+            .iter()
+            .map(|(entity, transform, aabb)| (entity, world_aabb(&transform, &aabb)))
+            .collect();
+        index.rebuild(items);
+        return;
+    }
+
+    let refitted = index.bvh.refit(|entity| {
+        let (_, transform, aabb) = indexed.get(*entity).ok()?;
+        (transform.is_changed() || aabb.is_changed()).then(|| world_aabb(&transform, &aabb))
+    });
+    if !refitted {
+        return;
+    }
+    index.refits += 1;
+    if index.refits >= SpatialIndex::MAX_REFITS
+        || index.bvh.cost() > index.built_cost * SpatialIndex::MAX_COST_GROWTH
+    {
+        let items = index
+            .bvh
+            .iter()
+            .map(|(entity, aabb)| (*entity, *aabb))
+            .collect();
+        index.rebuild(items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::{Dir3, Vec3, Vec3A};
+
+    fn spawn_box(app: &mut App, translation: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                SpatialIndexed,
+                GlobalTransform::from_translation(translation),
+                Aabb {
+                    center: Vec3A::ZERO,
+                    half_extents: Vec3A::splat(0.5),
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn spatial_index_follows_entities() {
+        let mut app = App::new();
+        app.add_plugins(SpatialIndexPlugin);
+
+        let near = spawn_box(&mut app, Vec3::new(0.0, 0.0, -2.0));
+        let far = spawn_box(&mut app, Vec3::new(0.0, 0.0, -6.0));
+        // Not opted in
+        let unindexed = app
+            .world_mut()
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -4.0)),
+                Aabb::default(),
+            ))
+            .id();
+        app.update();
+
+        let ray = RayCast3d::new(Vec3::ZERO, Dir3::NEG_Z, 100.0);
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.cast_ray(&ray), vec![(near, 1.5), (far, 5.5)]);
+        assert_eq!(index.cast_ray_first(&ray), Some((near, 1.5)));
+
+        // Moving an entity refits the index
+        *app.world_mut().get_mut::<GlobalTransform>(near).unwrap() =
+            GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -10.0));
+        app.update();
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.refits, 1);
+        assert_eq!(index.cast_ray_first(&ray), Some((far, 5.5)));
+        let around_near = Aabb3d::new(Vec3::new(0.0, 0.0, -10.0), Vec3::ONE);
+        assert_eq!(index.intersecting(&around_near), vec![near]);
+
+        // Despawning an entity that isn't indexed doesn't rebuild it
+        app.world_mut().despawn(unindexed);
+        app.update();
+        assert_eq!(app.world().resource::<SpatialIndex>().refits, 1);
+
+        // Despawning an indexed entity rebuilds it
+        app.world_mut().despawn(far);
+        app.update();
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.refits, 0);
+        assert_eq!(index.cast_ray(&ray), vec![(near, 9.5)]);
+    }
+
+    #[test]
+    fn spatial_index_is_rebuilt_when_refits_slow_it_down() {
+        let mut app = App::new();
+        app.add_plugins(SpatialIndexPlugin);
+        let boxes: Vec<_> = (0..8)
+            .map(|x| spawn_box(&mut app, Vec3::X * 10.0 * x as f32))
+            .collect();
+        app.update();
+
+        // Swapping the boxes at both ends makes both halves of the hierarchy span almost
+        // everything
+        let move_to = |app: &mut App, entity, x: f32| {
+            *app.world_mut().get_mut::<GlobalTransform>(entity).unwrap() =
+                GlobalTransform::from_translation(Vec3::X * x);
+        };
+        move_to(&mut app, boxes[0], 70.0);
+        move_to(&mut app, boxes[7], 0.0);
+        app.update();
+        let index = app.world().resource::<SpatialIndex>();
+        assert_eq!(index.refits, 0);
+        assert!(index.bvh.cost() <= index.built_cost);
+
+        // And the index is rebuilt from time to time anyway
+        for frame in 1..=SpatialIndex::MAX_REFITS {
+            move_to(&mut app, boxes[3], frame as f32 * 0.01);
+            app.update();
+        }
+        assert_eq!(app.world().resource::<SpatialIndex>().refits, 0);
+    }
+}
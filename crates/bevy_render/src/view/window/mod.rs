@@ -6,7 +6,8 @@ use crate::{
     texture::TextureFormatPixelInfo,
     Extract, ExtractSchedule, Render, RenderApp, RenderSet, WgpuWrapper,
 };
-use bevy_app::{App, Plugin};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_asset::{io::ScaleVariants, AssetServer};
 use bevy_ecs::{entity::EntityHashMap, prelude::*};
 #[cfg(target_os = "linux")]
 use bevy_utils::warn_once;
@@ -34,9 +35,25 @@ use super::Msaa;
 
 pub struct WindowRenderPlugin;
 
+/// Selects the asset variants matching the scale factor of the primary window.
+///
+/// See [`ScaleVariants`].
+fn update_asset_scale_factor(
+    windows: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    variants: Res<ScaleVariants>,
+    asset_server: Res<AssetServer>,
+) {
+    if let Ok(window) = windows.get_single() {
+        variants.set_scale_factor(window.scale_factor(), &asset_server);
+    }
+}
+
 impl Plugin for WindowRenderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ScreenshotPlugin);
+        app.add_plugins(ScreenshotPlugin).add_systems(
+            PreUpdate,
+            update_asset_scale_factor.run_if(resource_exists::<ScaleVariants>),
+        );
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
@@ -11,7 +11,7 @@ use bevy_ecs::{
 use bevy_render::prelude::{InheritedVisibility, ViewVisibility, Visibility};
 use bevy_transform::components::{GlobalTransform, Transform};
 
-use crate::{DynamicScene, InstanceId, Scene, SceneSpawner};
+use crate::{DynamicScene, InstanceId, Scene, SceneSpawnMode, SceneSpawner};
 
 /// [`InstanceId`] of a spawned scene. It can be used with the [`SceneSpawner`] to
 /// interact with the spawned scene.
@@ -46,6 +46,9 @@ pub struct SceneBundle {
 ///
 /// The dynamic scene from `scene` will be spawn as a child of the entity with this component.
 /// Once it's spawned, the entity will have a [`SceneInstance`] component.
+///
+/// A large scene can be spawned over several frames by inserting a [`SceneSpawnMode`] on the
+/// entity.
 #[derive(Default, Bundle, Clone)]
 pub struct DynamicSceneBundle {
     /// Handle to the scene to spawn.
@@ -74,7 +77,12 @@ pub fn scene_spawner(
         (Changed<Handle<Scene>>, Without<Handle<DynamicScene>>),
     >,
     mut dynamic_scene_to_spawn: Query<
-        (Entity, &Handle<DynamicScene>, Option<&mut SceneInstance>),
+        (
+            Entity,
+            &Handle<DynamicScene>,
+            Option<&SceneSpawnMode>,
+            Option<&mut SceneInstance>,
+        ),
         (Changed<Handle<DynamicScene>>, Without<Handle<Scene>>),
     >,
     mut scene_spawner: ResMut<SceneSpawner>,
@@ -88,8 +96,12 @@ pub fn scene_spawner(
             commands.entity(entity).insert(SceneInstance(new_instance));
         }
     }
-    for (entity, dynamic_scene, instance) in &mut dynamic_scene_to_spawn {
-        let new_instance = scene_spawner.spawn_dynamic_as_child(dynamic_scene.clone(), entity);
+    for (entity, dynamic_scene, mode, instance) in &mut dynamic_scene_to_spawn {
+        let new_instance = scene_spawner.spawn_dynamic_as_child_with_mode(
+            dynamic_scene.clone(),
+            entity,
+            mode.copied().unwrap_or_default(),
+        );
         if let Some(mut old_instance) = instance {
             scene_spawner.despawn_instance(**old_instance);
             *old_instance = SceneInstance(new_instance);
@@ -5,8 +5,10 @@ use bevy_ecs::{
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
     world::World,
 };
+use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::{Reflect, TypePath, TypeRegistry};
-use bevy_utils::TypeIdMap;
+use bevy_utils::{tracing::warn, HashSet, TypeIdMap};
+use std::{any::TypeId, ops::Range};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
@@ -58,6 +60,69 @@ impl DynamicScene {
             .build()
     }
 
+    /// Create a new dynamic scene from `root` and its descendants in `world`, such as a prefab
+    /// that can be instantiated multiple times.
+    ///
+    /// The [`Parent`] of `root` isn't captured, so that it is instantiated as a root entity.
+    /// Components that aren't registered with [`ReflectComponent`] can't be captured:
+    /// they are dropped with a warning.
+    ///
+    /// Writing the scene to a world with an empty entity map, or spawning it with the
+    /// [`SceneSpawner`](crate::SceneSpawner), instantiates new entities, and remaps the references
+    /// to the entities of the subtree to them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `world` doesn't have an [`AppTypeRegistry`] resource.
+    pub fn from_subtree(world: &World, root: Entity) -> Self {
+        let mut entities = Vec::new();
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            entities.push(entity);
+            if let Some(children) = world.get::<Children>(entity) {
+                stack.extend(children.iter().rev());
+            }
+        }
+
+        {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            let mut dropped = HashSet::new();
+            for &entity in &entities {
+                let Some(entity) = world.get_entity(entity) else {
+                    continue;
+                };
+                for component_id in entity.archetype().components() {
+                    let Some(info) = world.components().get_info(component_id) else {
+                        continue;
+                    };
+                    let reflected = info.type_id().is_some_and(|type_id| {
+                        type_registry
+                            .get_type_data::<ReflectComponent>(type_id)
+                            .is_some()
+                    });
+                    if !reflected && dropped.insert(component_id) {
+                        warn!(
+                            "Component {} isn't registered with `ReflectComponent`, so it is dropped from the subtree of {root:?}",
+                            info.name()
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut scene = DynamicSceneBuilder::from_world(world)
+            .extract_entities(entities.into_iter())
+            .build();
+        if let Some(root) = scene.entities.iter_mut().find(|e| e.entity == root) {
+            root.components.retain(|component| {
+                component
+                    .get_represented_type_info()
+                    .map_or(true, |info| info.type_id() != TypeId::of::<Parent>())
+            });
+        }
+        scene
+    }
+
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
@@ -70,14 +135,31 @@ impl DynamicScene {
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+        self.write_entities(world, entity_map, 0..self.entities.len(), &type_registry)?;
+        self.write_resources(world, entity_map, &type_registry)
+    }
 
+    /// Writes the dynamic entities in `range` and their components to the given world, mapping
+    /// their references to the entities of the scene with `entity_map`.
+    ///
+    /// When the entities are written across several calls, such as with
+    /// [`SceneSpawnMode::Incremental`](crate::SceneSpawnMode::Incremental), `entity_map` must
+    /// already map the entities written by the later calls, for the references to them to be
+    /// mapped.
+    pub(crate) fn write_entities(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        range: Range<usize>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         // For each component types that reference other entities, we keep track
         // of which entities in the scene use that component.
         // This is so we can update the scene-internal references to references
         // of the actual entities in the world.
         let mut scene_mappings: TypeIdMap<Vec<Entity>> = Default::default();
 
-        for scene_entity in &self.entities {
+        for scene_entity in &self.entities[range] {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
             // no corresponding entry.
@@ -117,7 +199,7 @@ impl DynamicScene {
                 // If the entity already has the given component attached,
                 // just apply the (possibly) new value, otherwise add the
                 // component to the entity.
-                reflect_component.apply_or_insert(entity_mut, &**component, &type_registry);
+                reflect_component.apply_or_insert(entity_mut, &**component, type_registry);
             }
         }
 
@@ -131,8 +213,19 @@ impl DynamicScene {
             }
         }
 
-        // Insert resources after all entities have been added to the world.
-        // This ensures the entities are available for the resources to reference during mapping.
+        Ok(())
+    }
+
+    /// Writes the resources of the scene to the given world, mapping their references to the
+    /// entities of the scene with `entity_map`.
+    ///
+    /// They're written after all the entities, so that the resources can reference them.
+    pub(crate) fn write_resources(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         for resource in &self.resources {
             let type_info = resource.get_represented_type_info().ok_or_else(|| {
                 SceneSpawnError::NoRepresentedType {
@@ -152,7 +245,7 @@ impl DynamicScene {
 
             // If the world already contains an instance of the given resource
             // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, &**resource, &type_registry);
+            reflect_resource.apply_or_insert(world, &**resource, type_registry);
 
             // Map entities in the resource if it implements [`MapEntities`].
             if let Some(map_entities_reflect) = registration.data::<ReflectMapEntitiesResource>() {
@@ -205,14 +298,15 @@ where
 
 #[cfg(test)]
 mod tests {
+    use bevy_ecs::component::Component;
     use bevy_ecs::entity::{Entity, EntityHashMap, EntityMapper, MapEntities};
     use bevy_ecs::reflect::{ReflectMapEntitiesResource, ReflectResource};
     use bevy_ecs::system::Resource;
     use bevy_ecs::{reflect::AppTypeRegistry, world::Command, world::World};
-    use bevy_hierarchy::{Parent, PushChild};
+    use bevy_hierarchy::{BuildWorldChildren, Children, Parent, PushChild};
     use bevy_reflect::Reflect;
 
-    use crate::dynamic_scene_builder::DynamicSceneBuilder;
+    use crate::{dynamic_scene_builder::DynamicSceneBuilder, DynamicScene};
 
     #[derive(Resource, Reflect, Debug)]
     #[reflect(Resource, MapEntitiesResource)]
@@ -343,4 +437,43 @@ mod tests {
             "something is wrong with the this test or the code reloading scenes since the relationship between scene entities is broken"
         );
     }
+
+    #[test]
+    fn from_subtree_instantiates_copies() {
+        #[derive(Component)]
+        struct NotReflected;
+
+        let mut world = World::new();
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+        }
+        world.insert_resource(type_registry);
+
+        let outside = world.spawn_empty().id();
+        let root = world.spawn(NotReflected).id();
+        let child = world.spawn_empty().id();
+        world.entity_mut(outside).push_children(&[root]);
+        world.entity_mut(root).push_children(&[child]);
+
+        let scene = DynamicScene::from_subtree(&world, root);
+        assert_eq!(scene.entities.len(), 2);
+
+        let mut copies = Vec::new();
+        for _ in 0..3 {
+            let mut entity_map = EntityHashMap::default();
+            scene.write_to_world(&mut world, &mut entity_map).unwrap();
+            copies.push((entity_map[&root], entity_map[&child]));
+        }
+
+        for (copy_root, copy_child) in copies {
+            assert!(copy_root != root && copy_child != child);
+            assert!(world.get::<Parent>(copy_root).is_none());
+            assert!(world.get::<NotReflected>(copy_root).is_none());
+            assert_eq!(world.get::<Parent>(copy_child).unwrap().get(), copy_root);
+            assert_eq!(&**world.get::<Children>(copy_root).unwrap(), &[copy_child]);
+        }
+    }
 }
@@ -14,6 +14,7 @@
 mod bundle;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod placeholder;
 mod scene;
 mod scene_filter;
 mod scene_loader;
@@ -29,6 +30,7 @@ use bevy_ecs::schedule::IntoSystemConfigs;
 pub use bundle::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use placeholder::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
@@ -38,7 +40,8 @@ pub use scene_spawner::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, Scene, SceneBundle, SceneFilter,
+        DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, Scene, SceneBundle,
+        SceneCommandsExt, SceneFilter, SceneInstanceMember, ScenePlaceholder, SceneSpawnMode,
         SceneSpawner,
     };
 }
@@ -57,8 +60,19 @@ impl Plugin for ScenePlugin {
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
             .add_event::<SceneInstanceReady>()
+            .add_event::<SceneSpawnProgress>()
             .init_resource::<SceneSpawner>()
-            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+            .register_type::<ScenePlaceholder>()
+            .add_systems(
+                SpawnScene,
+                (
+                    resolve_scene_placeholders,
+                    scene_spawner,
+                    scene_spawner_system,
+                    apply_scene_placeholder_overrides,
+                )
+                    .chain(),
+            );
 
         // Register component hooks for DynamicScene
         app.world_mut()
@@ -0,0 +1,314 @@
+use bevy_asset::{AssetEvent, AssetId, AssetPath, AssetServer, Handle};
+use bevy_core::Name;
+use bevy_ecs::{
+    change_detection::{DetectChanges, Ref},
+    entity::Entity,
+    event::EventReader,
+    prelude::Component,
+    query::Changed,
+    reflect::ReflectComponent,
+    system::{Commands, EntityCommands, Query, Res},
+};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::Transform;
+use bevy_utils::{tracing::error, HashSet};
+
+use crate::{DynamicScene, SceneInstance, SceneInstanceReady, SceneSpawner};
+
+/// A reference to another [`DynamicScene`], to be instantiated as a child of this entity.
+///
+/// This allows scenes to be composed of other scenes, prefab-style, instead of being flattened:
+/// a level scene can place several instances of an enemy scene, each with its own [`Transform`].
+/// As a regular reflected component, it can be stored in a [`DynamicScene`] and serialized.
+///
+/// Placeholders are resolved by [`resolve_scene_placeholders`], which loads the referenced scene
+/// and spawns it as a child of the placeholder entity. This happens recursively for placeholders
+/// inside the referenced scene, and references that would create a cycle are ignored with an error.
+///
+/// The [`overrides`](Self::overrides) are applied to the root entities of the spawned instance,
+/// and keep winning over the values of the referenced scene when it is hot-reloaded.
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct ScenePlaceholder {
+    /// The path of the referenced scene, relative to the assets root.
+    pub scene: AssetPath<'static>,
+    /// Component values applied on top of the root entities of the referenced scene.
+    pub overrides: SceneOverrides,
+}
+
+/// Component overrides of a [`ScenePlaceholder`].
+#[derive(Reflect, Clone, Debug, Default)]
+#[reflect(Default)]
+pub struct SceneOverrides {
+    /// Replaces the [`Transform`] of the root entities, if set.
+    pub transform: Option<Transform>,
+    /// Replaces the [`Name`] of the root entities, if set.
+    pub name: Option<Name>,
+}
+
+impl SceneOverrides {
+    /// Inserts the overridden components into `entity`.
+    pub fn apply(&self, entity: &mut EntityCommands) {
+        if let Some(transform) = self.transform {
+            entity.insert(transform);
+        }
+        if let Some(name) = &self.name {
+            entity.insert(name.clone());
+        }
+    }
+}
+
+/// System that starts spawning the scenes referenced by new or changed [`ScenePlaceholder`]s,
+/// by inserting a [`Handle<DynamicScene>`] on the placeholder entity.
+///
+/// A placeholder is ignored if one of its ancestors was spawned from the same scene,
+/// as spawning it would recurse infinitely.
+pub fn resolve_scene_placeholders(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    placeholders: Query<
+        (Entity, &ScenePlaceholder, Option<&Handle<DynamicScene>>),
+        Changed<ScenePlaceholder>,
+    >,
+    parents: Query<&Parent>,
+    scene_handles: Query<&Handle<DynamicScene>>,
+) {
+    for (entity, placeholder, current) in &placeholders {
+        let handle: Handle<DynamicScene> = asset_server.load(placeholder.scene.clone());
+        if current.map(Handle::id) == Some(handle.id()) {
+            continue;
+        }
+
+        let is_cycle = parents.iter_ancestors(entity).any(|ancestor| {
+            scene_handles
+                .get(ancestor)
+                .is_ok_and(|ancestor_handle| ancestor_handle.id() == handle.id())
+        });
+        if is_cycle {
+            error!(
+                "Scene placeholder {entity:?} references `{}`, which is already one of its ancestors. \
+                The placeholder will not be spawned.",
+                placeholder.scene
+            );
+            continue;
+        }
+
+        commands.entity(entity).insert(handle);
+    }
+}
+
+/// System that applies the [`SceneOverrides`] of each [`ScenePlaceholder`] to the root entities
+/// of its instance, whenever the instance is spawned, its scene is modified or the placeholder changes.
+pub fn apply_scene_placeholder_overrides(
+    mut commands: Commands,
+    mut ready_events: EventReader<SceneInstanceReady>,
+    mut scene_events: EventReader<AssetEvent<DynamicScene>>,
+    scene_spawner: Res<SceneSpawner>,
+    placeholders: Query<(
+        Entity,
+        Ref<ScenePlaceholder>,
+        &Handle<DynamicScene>,
+        &SceneInstance,
+    )>,
+    parents: Query<&Parent>,
+) {
+    let ready: HashSet<Entity> = ready_events.read().map(|event| event.parent).collect();
+    let modified: HashSet<AssetId<DynamicScene>> = scene_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, placeholder, handle, instance) in &placeholders {
+        if !(placeholder.is_changed() || ready.contains(&entity) || modified.contains(&handle.id()))
+        {
+            continue;
+        }
+
+        for root in scene_spawner
+            .iter_instance_entities(**instance)
+            .filter(|root| {
+                parents
+                    .get(*root)
+                    .is_ok_and(|parent| parent.get() == entity)
+            })
+        {
+            placeholder.overrides.apply(&mut commands.entity(root));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use std::{any::TypeId, path::Path};
+
+    use bevy_app::App;
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin, Assets, Handle,
+    };
+    use bevy_core::{Name, TaskPoolPlugin};
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::{HierarchyPlugin, Parent};
+    use bevy_transform::components::Transform;
+    use bevy_utils::default;
+
+    use crate::{DynamicScene, ScenePlaceholder, ScenePlugin};
+
+    use super::SceneOverrides;
+
+    fn setup(dir: &Dir) -> App {
+        let mut app = App::new();
+        let reader_dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || {
+                Box::new(MemoryAssetReader {
+                    root: reader_dir.clone(),
+                })
+            }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            HierarchyPlugin,
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Transform>()
+        .register_type::<Name>();
+        app
+    }
+
+    fn insert_scene(app: &App, dir: &Dir, path: &str, spawn: impl FnOnce(&mut World)) {
+        let registry = app.world().resource::<AppTypeRegistry>().clone();
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        spawn(&mut world);
+        let scene = DynamicScene::from_world(&world)
+            .serialize(&registry.read())
+            .unwrap();
+        dir.insert_asset_text(Path::new(path), &scene);
+    }
+
+    fn update_until(app: &mut App, mut predicate: impl FnMut(&mut World) -> bool) {
+        for _ in 0..1000 {
+            app.update();
+            if predicate(app.world_mut()) {
+                return;
+            }
+        }
+        panic!("Ran out of loops waiting for the scene to spawn");
+    }
+
+    #[test]
+    fn overrides_survive_hot_reload() {
+        let dir = Dir::default();
+        let mut app = setup(&dir);
+        insert_scene(&app, &dir, "inner.scn.ron", |world| {
+            world.spawn((Transform::from_xyz(1.0, 0.0, 0.0), Name::new("inner")));
+        });
+
+        let placeholder = app
+            .world_mut()
+            .spawn(ScenePlaceholder {
+                scene: "inner.scn.ron".into(),
+                overrides: SceneOverrides {
+                    transform: Some(Transform::from_xyz(5.0, 0.0, 0.0)),
+                    name: None,
+                },
+            })
+            .id();
+
+        let mut root = None;
+        update_until(&mut app, |world| {
+            root = world
+                .query::<(Entity, &Parent, &Name)>()
+                .iter(world)
+                .find(|(_, parent, _)| parent.get() == placeholder)
+                .map(|(entity, ..)| entity);
+            root.is_some()
+        });
+        let root = root.unwrap();
+        assert_eq!(
+            app.world().get::<Transform>(root),
+            Some(&Transform::from_xyz(5.0, 0.0, 0.0))
+        );
+        assert_eq!(app.world().get::<Name>(root).unwrap().as_str(), "inner");
+
+        // Modify the inner scene, as a hot reload would.
+        let handle = app
+            .world()
+            .get::<Handle<DynamicScene>>(placeholder)
+            .unwrap()
+            .clone();
+        {
+            let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+            let scene = scenes.get_mut(&handle).unwrap();
+            let name = scene.entities[0]
+                .components
+                .iter_mut()
+                .find(|component| {
+                    component
+                        .get_represented_type_info()
+                        .map(|info| info.type_id())
+                        == Some(TypeId::of::<Name>())
+                })
+                .unwrap();
+            name.apply(&Name::new("reloaded"));
+        }
+        // Asset events are sent at the end of the frame, and handled during the next one.
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<Name>(root).unwrap().as_str(), "reloaded");
+        assert_eq!(
+            app.world().get::<Transform>(root),
+            Some(&Transform::from_xyz(5.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn cyclic_placeholders_are_not_spawned() {
+        let dir = Dir::default();
+        let mut app = setup(&dir);
+        insert_scene(&app, &dir, "cycle.scn.ron", |world| {
+            world.spawn(ScenePlaceholder {
+                scene: "cycle.scn.ron".into(),
+                ..default()
+            });
+        });
+
+        app.world_mut().spawn(ScenePlaceholder {
+            scene: "cycle.scn.ron".into(),
+            ..default()
+        });
+
+        update_until(&mut app, |world| {
+            world.query::<&ScenePlaceholder>().iter(world).len() == 2
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world_mut()
+                .query::<&ScenePlaceholder>()
+                .iter(app.world())
+                .len(),
+            2
+        );
+        assert_eq!(
+            app.world_mut()
+                .query_filtered::<(), (With<ScenePlaceholder>, With<Handle<DynamicScene>>)>()
+                .iter(app.world())
+                .len(),
+            1
+        );
+    }
+}
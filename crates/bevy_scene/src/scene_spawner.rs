@@ -2,10 +2,11 @@ use crate::{DynamicScene, Scene};
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
+    component::Component,
     entity::Entity,
     event::{Event, Events, ManualEventReader},
     reflect::AppTypeRegistry,
-    system::Resource,
+    system::{Commands, Resource},
     world::{Command, Mut, World},
 };
 use bevy_hierarchy::{BuildWorldChildren, DespawnRecursiveExt, Parent, PushChild};
@@ -22,6 +23,110 @@ pub struct SceneInstanceReady {
     pub parent: Entity,
 }
 
+/// Sent by the [`scene_spawner_system`] after each frame of the spawn of a dynamic scene with
+/// [`SceneSpawnMode::Incremental`], such as to show a progress bar.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Event)]
+pub struct SceneSpawnProgress {
+    /// The instance being spawned.
+    pub instance_id: InstanceId,
+    /// The number of entities of the scene spawned so far.
+    pub spawned: usize,
+    /// The number of entities of the scene.
+    pub total: usize,
+}
+
+impl SceneSpawnProgress {
+    /// Returns the fraction of the entities of the scene spawned so far, from 0 to 1.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.
+        } else {
+            self.spawned as f32 / self.total as f32
+        }
+    }
+
+    /// Returns `true` once all the entities of the scene are spawned.
+    pub fn is_done(&self) -> bool {
+        self.spawned >= self.total
+    }
+}
+
+/// How the [`SceneSpawner`] spawns a dynamic scene.
+///
+/// Inserted on an entity with a [`DynamicSceneBundle`](crate::DynamicSceneBundle), it sets how
+/// the scene of the bundle is spawned.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SceneSpawnMode {
+    /// Spawns all the entities of the scene in the same frame.
+    #[default]
+    AllAtOnce,
+    /// Spawns at most `entities_per_frame` entities of the scene per frame, so that a large scene
+    /// doesn't stall a frame, sending a [`SceneSpawnProgress`] event after each frame.
+    ///
+    /// All the entities of the scene are reserved in the first frame, so that the references
+    /// between them, such as their hierarchy, are mapped whichever frame they're spawned in. The
+    /// resources of the scene are written in the last frame, and the instance is only
+    /// [ready](SceneSpawner::instance_is_ready), and added to its parent, once all its entities
+    /// are spawned.
+    Incremental {
+        /// The number of entities spawned per frame, at least 1.
+        entities_per_frame: usize,
+    },
+}
+
+/// A dynamic scene instance spawned over several frames, see [`SceneSpawnMode::Incremental`].
+struct IncrementalSpawn {
+    handle: Handle<DynamicScene>,
+    instance_id: InstanceId,
+    /// Maps all the entities of the scene, reserved when the spawn starts.
+    entity_map: EntityHashMap<Entity>,
+    /// The number of entities of the scene spawned so far.
+    spawned: usize,
+    entities_per_frame: usize,
+}
+
+impl IncrementalSpawn {
+    /// Spawns the next entities of the scene, returning `true` once all of them are spawned.
+    fn spawn_next(
+        &mut self,
+        world: &mut World,
+        scenes: &Assets<DynamicScene>,
+    ) -> Result<bool, SceneSpawnError> {
+        let id = self.handle.id();
+        let scene = scenes
+            .get(id)
+            .ok_or(SceneSpawnError::NonExistentScene { id })?;
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+
+        // The scene may have been reloaded with fewer entities since the last frame
+        let total = scene.entities.len();
+        let start = self.spawned.min(total);
+        let end = (start + self.entities_per_frame).min(total);
+        scene.write_entities(world, &mut self.entity_map, start..end, &type_registry)?;
+        for scene_entity in &scene.entities[start..end] {
+            let Some(&entity) = self.entity_map.get(&scene_entity.entity) else {
+                continue;
+            };
+            if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+                entity_mut.insert(SceneInstanceMember(self.instance_id));
+            }
+        }
+        self.spawned = end;
+
+        let done = end == total;
+        if done {
+            scene.write_resources(world, &mut self.entity_map, &type_registry)?;
+        }
+        world.send_event(SceneSpawnProgress {
+            instance_id: self.instance_id,
+            spawned: end,
+            total,
+        });
+        Ok(done)
+    }
+}
+
 /// Information about a scene instance.
 #[derive(Debug)]
 pub struct InstanceInfo {
@@ -39,6 +144,17 @@ impl InstanceId {
     }
 }
 
+/// Marks an entity as having been spawned by the scene instance with the given [`InstanceId`].
+///
+/// This is inserted by the [`SceneSpawner`] on every entity it writes when spawning an instance,
+/// including entities added to the instance when its scene is hot-reloaded.
+///
+/// Entities spawned later by the game, even as children of instance entities, are *not* members
+/// of the instance and won't receive this component. However, since instances are despawned
+/// recursively, such children are still despawned along with their instance parent.
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SceneInstanceMember(pub InstanceId);
+
 /// Handles spawning and despawning scenes in the world, either synchronously or batched through the [`scene_spawner_system`].
 ///
 /// Synchronous methods: (Scene operations will take effect immediately)
@@ -48,12 +164,15 @@ impl InstanceId {
 /// - [`despawn_instance_sync`](Self::despawn_instance_sync)
 /// - [`update_spawned_scenes`](Self::update_spawned_scenes)
 /// - [`spawn_queued_scenes`](Self::spawn_queued_scenes)
+/// - [`spawn_incremental_scenes`](Self::spawn_incremental_scenes)
 /// - [`despawn_queued_scenes`](Self::despawn_queued_scenes)
 /// - [`despawn_queued_instances`](Self::despawn_queued_instances)
 ///
 /// Deferred methods: (Scene operations will be processed when the [`scene_spawner_system`] is run)
 /// - [`spawn_dynamic`](Self::spawn_dynamic)
 /// - [`spawn_dynamic_as_child`](Self::spawn_dynamic_as_child)
+/// - [`spawn_dynamic_with_mode`](Self::spawn_dynamic_with_mode)
+/// - [`spawn_dynamic_as_child_with_mode`](Self::spawn_dynamic_as_child_with_mode)
 /// - [`spawn`](Self::spawn)
 /// - [`spawn_as_child`](Self::spawn_as_child)
 /// - [`despawn`](Self::despawn)
@@ -68,6 +187,8 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    spawn_modes: HashMap<InstanceId, SceneSpawnMode>,
+    incremental_spawns: Vec<IncrementalSpawn>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -144,6 +265,34 @@ impl SceneSpawner {
         instance_id
     }
 
+    /// Schedule the spawn of a new instance of the provided dynamic scene, spawned with `mode`.
+    pub fn spawn_dynamic_with_mode(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        mode: SceneSpawnMode,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic(id);
+        if mode != SceneSpawnMode::AllAtOnce {
+            self.spawn_modes.insert(instance_id, mode);
+        }
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `parent`,
+    /// spawned with `mode`.
+    pub fn spawn_dynamic_as_child_with_mode(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        parent: Entity,
+        mode: SceneSpawnMode,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic_as_child(id, parent);
+        if mode != SceneSpawnMode::AllAtOnce {
+            self.spawn_modes.insert(instance_id, mode);
+        }
+        instance_id
+    }
+
     /// Schedule the spawn of a new instance of the provided scene.
     pub fn spawn(&mut self, id: impl Into<Handle<Scene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -169,32 +318,70 @@ impl SceneSpawner {
         self.instances_to_despawn.push(instance_id);
     }
 
-    /// Immediately despawns all instances of a dynamic scene.
+    /// Immediately despawns all instances of a dynamic scene, including the instances still
+    /// being spawned.
     pub fn despawn_sync(
         &mut self,
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
         }
+        let spawning: Vec<_> = self
+            .incremental_spawns
+            .iter()
+            .filter(|spawn| spawn.handle.id() == id)
+            .map(|spawn| spawn.instance_id)
+            .collect();
+        for instance_id in spawning {
+            self.despawn_instance_sync(world, &instance_id);
+        }
         Ok(())
     }
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
+    ///
+    /// An instance still being spawned, see [`SceneSpawnMode::Incremental`], stops being spawned.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for &entity in instance.entity_map.values() {
-                if let Some(mut entity_mut) = world.get_entity_mut(entity) {
-                    entity_mut.remove_parent();
-                    entity_mut.despawn_recursive();
-                };
-            }
+            despawn_entities(world, &instance.entity_map);
+        } else if let Some(index) = self
+            .incremental_spawns
+            .iter()
+            .position(|spawn| spawn.instance_id == *instance_id)
+        {
+            let spawn = self.incremental_spawns.remove(index);
+            despawn_entities(world, &spawn.entity_map);
         }
     }
 
+    /// Immediately despawns a scene instance along with all descendants of its entities,
+    /// and forgets about it entirely.
+    ///
+    /// Unlike [`despawn_instance_sync`](Self::despawn_instance_sync), this also cancels the
+    /// instance if it is still queued for spawning, and removes it from the instances tracked
+    /// for its dynamic scene.
+    ///
+    /// Descendants include entities the game spawned as children of instance entities, even
+    /// though they aren't [members](SceneInstanceMember) of the instance.
+    pub fn despawn_instance_recursive(&mut self, world: &mut World, instance_id: InstanceId) {
+        self.dynamic_scenes_to_spawn
+            .retain(|(_, instance)| *instance != instance_id);
+        self.scenes_to_spawn
+            .retain(|(_, instance)| *instance != instance_id);
+        self.scenes_with_parent
+            .retain(|(instance, _)| *instance != instance_id);
+        self.spawn_modes.remove(&instance_id);
+        for instances in self.spawned_dynamic_scenes.values_mut() {
+            instances.remove(&instance_id);
+        }
+        self.despawn_instance_sync(world, &instance_id);
+    }
+
     /// Immediately spawns a new instance of the provided dynamic scene.
     pub fn spawn_dynamic_sync(
         &mut self,
@@ -205,6 +392,7 @@ impl SceneSpawner {
         let id = id.into();
         Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
+        insert_instance_members(world, instance_id, &entity_map);
         self.spawned_instances
             .insert(instance_id, InstanceInfo { entity_map });
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
@@ -247,6 +435,7 @@ impl SceneSpawner {
 
             let instance_info =
                 scene.write_to_world_with(world, &world.resource::<AppTypeRegistry>().clone())?;
+            insert_instance_members(world, instance_id, &instance_info.entity_map);
 
             self.spawned_instances.insert(instance_id, instance_info);
             Ok(instance_id)
@@ -266,6 +455,7 @@ impl SceneSpawner {
                 for instance_id in spawned_instances {
                     if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
                         Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
+                        insert_instance_members(world, *instance_id, &instance_info.entity_map);
                     }
                 }
             }
@@ -297,10 +487,46 @@ impl SceneSpawner {
         let scenes_to_spawn = std::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (handle, instance_id) in scenes_to_spawn {
+            if let Some(&SceneSpawnMode::Incremental { entities_per_frame }) =
+                self.spawn_modes.get(&instance_id)
+            {
+                // Reserve the entities of the whole scene, so that the references between them
+                // are mapped whichever frame they're spawned in
+                let Some(scene_entities) = world
+                    .resource::<Assets<DynamicScene>>()
+                    .get(&handle)
+                    .map(|scene| {
+                        scene
+                            .entities
+                            .iter()
+                            .map(|entity| entity.entity)
+                            .collect::<Vec<_>>()
+                    })
+                else {
+                    self.dynamic_scenes_to_spawn.push((handle, instance_id));
+                    continue;
+                };
+                let entity_map = scene_entities
+                    .into_iter()
+                    .map(|scene_entity| (scene_entity, world.spawn_empty().id()))
+                    .collect();
+                self.spawn_modes.remove(&instance_id);
+                self.incremental_spawns.push(IncrementalSpawn {
+                    handle,
+                    instance_id,
+                    entity_map,
+                    spawned: 0,
+                    entities_per_frame: entities_per_frame.max(1),
+                });
+                continue;
+            }
+
             let mut entity_map = EntityHashMap::default();
 
             match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
                 Ok(_) => {
+                    self.spawn_modes.remove(&instance_id);
+                    insert_instance_members(world, instance_id, &entity_map);
                     self.spawned_instances
                         .insert(instance_id, InstanceInfo { entity_map });
                     let spawned = self
@@ -331,6 +557,41 @@ impl SceneSpawner {
         Ok(())
     }
 
+    /// Immediately spawns the next entities of the dynamic scenes spawned over several frames,
+    /// see [`SceneSpawnMode::Incremental`].
+    pub fn spawn_incremental_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        let mut index = 0;
+        while index < self.incremental_spawns.len() {
+            let spawn = &mut self.incremental_spawns[index];
+            let result = world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                spawn.spawn_next(world, &scenes)
+            });
+            match result {
+                Ok(false) => index += 1,
+                Ok(true) => {
+                    let spawn = self.incremental_spawns.remove(index);
+                    self.spawned_instances.insert(
+                        spawn.instance_id,
+                        InstanceInfo {
+                            entity_map: spawn.entity_map,
+                        },
+                    );
+                    self.spawned_dynamic_scenes
+                        .entry(spawn.handle.id())
+                        .or_default()
+                        .insert(spawn.instance_id);
+                }
+                Err(SceneSpawnError::NonExistentScene { id }) => {
+                    let spawn = self.incremental_spawns.remove(index);
+                    error!("The dynamic scene {id:?} was removed while being spawned, so its instance is despawned");
+                    despawn_entities(world, &spawn.entity_map);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
         let scenes_with_parent = std::mem::take(&mut self.scenes_with_parent);
 
@@ -385,6 +646,61 @@ impl SceneSpawner {
     }
 }
 
+fn despawn_entities(world: &mut World, entity_map: &EntityHashMap<Entity>) {
+    for &entity in entity_map.values() {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.remove_parent();
+            entity_mut.despawn_recursive();
+        };
+    }
+}
+
+fn insert_instance_members(
+    world: &mut World,
+    instance_id: InstanceId,
+    entity_map: &EntityHashMap<Entity>,
+) {
+    for &entity in entity_map.values() {
+        if let Some(mut entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.insert(SceneInstanceMember(instance_id));
+        }
+    }
+}
+
+/// Despawns a scene instance and all descendants of its entities, see
+/// [`SceneSpawner::despawn_instance_recursive`].
+#[derive(Debug)]
+pub struct DespawnSceneInstance {
+    /// The instance to despawn.
+    pub instance_id: InstanceId,
+}
+
+impl Command for DespawnSceneInstance {
+    fn apply(self, world: &mut World) {
+        if !world.contains_resource::<SceneSpawner>() {
+            return;
+        }
+        world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+            scene_spawner.despawn_instance_recursive(world, self.instance_id);
+        });
+    }
+}
+
+/// Scene-related extension methods for [`Commands`].
+pub trait SceneCommandsExt {
+    /// Despawns the scene instance with the given id, along with all descendants of its entities.
+    ///
+    /// The id of the instance an entity belongs to can be read from its [`SceneInstanceMember`].
+    /// See [`SceneSpawner::despawn_instance_recursive`] for details.
+    fn despawn_scene_instance(&mut self, instance_id: InstanceId);
+}
+
+impl SceneCommandsExt for Commands<'_, '_> {
+    fn despawn_scene_instance(&mut self, instance_id: InstanceId) {
+        self.add(DespawnSceneInstance { instance_id });
+    }
+}
+
 /// System that handles scheduled scene instance spawning and despawning through a [`SceneSpawner`].
 pub fn scene_spawner_system(world: &mut World) {
     world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
@@ -407,6 +723,10 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .scenes_to_spawn
             .retain(|(_, instance)| !dead_instances.contains(instance));
+        for instance in &dead_instances {
+            scene_spawner.spawn_modes.remove(instance);
+            scene_spawner.despawn_instance_sync(world, instance);
+        }
 
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
@@ -428,6 +748,9 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .spawn_queued_scenes(world)
             .unwrap_or_else(|err| panic!("{}", err));
+        scene_spawner
+            .spawn_incremental_scenes(world)
+            .unwrap_or_else(|err| panic!("{}", err));
         scene_spawner
             .update_spawned_scenes(world, &updated_spawned_scenes)
             .unwrap();
@@ -444,6 +767,7 @@ mod tests {
     use bevy_ecs::query::With;
     use bevy_ecs::system::{Commands, Res, ResMut, RunSystemOnce};
     use bevy_ecs::{component::Component, system::Query};
+    use bevy_hierarchy::Children;
     use bevy_reflect::Reflect;
 
     use crate::{DynamicSceneBuilder, ScenePlugin};
@@ -501,6 +825,176 @@ mod tests {
         assert_eq!(old_a, new_a);
     }
 
+    #[test]
+    fn instance_members() {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<A>();
+        world.insert_resource(atr);
+        world.insert_resource(Assets::<DynamicScene>::default());
+
+        let first = world.spawn(A(1)).id();
+        let second = world.spawn(A(2)).id();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(first)
+            .build();
+        let scene_id = world.resource_mut::<Assets<DynamicScene>>().add(scene);
+
+        let mut scene_spawner = SceneSpawner::default();
+        let instance_id = scene_spawner
+            .spawn_dynamic_sync(&mut world, &scene_id)
+            .unwrap();
+
+        let check_members = |world: &mut World, scene_spawner: &SceneSpawner, count: usize| {
+            let entities = scene_spawner
+                .iter_instance_entities(instance_id)
+                .collect::<Vec<_>>();
+            assert_eq!(entities.len(), count);
+            for entity in entities {
+                assert_eq!(
+                    world.get::<SceneInstanceMember>(entity),
+                    Some(&SceneInstanceMember(instance_id))
+                );
+            }
+            assert_eq!(
+                world.query::<&SceneInstanceMember>().iter(world).len(),
+                count
+            );
+        };
+        check_members(&mut world, &scene_spawner, 1);
+
+        // Hot-reload the scene with an additional entity.
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities([first, second].into_iter())
+            .build();
+        world
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&scene_id, scene);
+        scene_spawner
+            .update_spawned_scenes(&mut world, &[scene_id.id()])
+            .unwrap();
+        check_members(&mut world, &scene_spawner, 2);
+    }
+
+    #[test]
+    fn despawn_scene_instance() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+        app.register_type::<A>();
+
+        app.world_mut().spawn(A(1));
+        let scene = {
+            let world = app.world();
+            world
+                .resource::<AssetServer>()
+                .add(DynamicScene::from_world(world))
+        };
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(scene);
+        app.update();
+
+        let member = app
+            .world()
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .next()
+            .unwrap();
+
+        // Children spawned by the game aren't members of the instance...
+        let mut child = None;
+        app.world_mut().entity_mut(member).with_children(|parent| {
+            child = Some(parent.spawn_empty().id());
+        });
+        let child = child.unwrap();
+        assert!(app.world().get::<SceneInstanceMember>(child).is_none());
+
+        // ...but they are despawned along with it.
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                commands.despawn_scene_instance(instance_id);
+            });
+
+        assert!(app.world().get_entity(member).is_none());
+        assert!(app.world().get_entity(child).is_none());
+        assert_eq!(app.world_mut().query::<&A>().iter(app.world()).len(), 1);
+        assert!(!app
+            .world()
+            .resource::<SceneSpawner>()
+            .instance_is_ready(instance_id));
+    }
+
+    #[test]
+    fn incremental_spawn() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin));
+        app.register_type::<A>()
+            .register_type::<Parent>()
+            .register_type::<Children>();
+
+        let root = app
+            .world_mut()
+            .spawn(A(0))
+            .with_children(|parent| {
+                for i in 1..5 {
+                    parent.spawn(A(i));
+                }
+            })
+            .id();
+        let scene = DynamicScene::from_subtree(app.world(), root);
+        let scene = app.world().resource::<AssetServer>().add(scene);
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic_with_mode(
+                scene,
+                SceneSpawnMode::Incremental {
+                    entities_per_frame: 2,
+                },
+            );
+
+        let mut reader = ManualEventReader::<SceneSpawnProgress>::default();
+        let mut progress = Vec::new();
+        let mut ready = Vec::new();
+        for _ in 0..3 {
+            app.update();
+            let events = app.world().resource::<Events<SceneSpawnProgress>>();
+            progress.extend(reader.read(events).map(|event| event.spawned));
+            ready.push(
+                app.world()
+                    .resource::<SceneSpawner>()
+                    .instance_is_ready(instance_id),
+            );
+        }
+        assert_eq!(progress, [2, 4, 5]);
+        assert_eq!(ready, [false, false, true]);
+
+        // The hierarchy spawned across frames is mapped to the entities of the instance
+        let world = app.world();
+        let entities: Vec<_> = world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .collect();
+        assert_eq!(entities.len(), 5);
+        let new_root = entities
+            .iter()
+            .copied()
+            .find(|&entity| world.get::<Parent>(entity).is_none())
+            .unwrap();
+        assert_ne!(new_root, root);
+        let children = world.get::<Children>(new_root).unwrap();
+        assert_eq!(children.len(), 4);
+        for &child in children {
+            assert!(entities.contains(&child));
+            assert_eq!(world.get::<Parent>(child).unwrap().get(), new_root);
+            assert_eq!(
+                world.get::<SceneInstanceMember>(child),
+                Some(&SceneInstanceMember(instance_id))
+            );
+        }
+    }
+
     #[derive(Component, Reflect, Default)]
     #[reflect(Component)]
     struct ComponentA;
@@ -0,0 +1,94 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::system::{Res, Resource};
+use bevy_render::RenderApp;
+
+/// Adds the "sprite/draw_calls" diagnostic to an App, measuring the number of sprite batches
+/// drawn each frame.
+///
+/// Each batch is drawn with a single draw call, and consecutive sprites share a batch as long as
+/// they are drawn from the same texture.
+///
+/// Also adds the "sprite/instances" diagnostic, measuring the number of sprite instances drawn
+/// each frame. Together, they show how many instances a batch draws on average, for example with
+/// the glyphs of `Text2d` drawn as [`ExtractedSpriteQuads`](crate::ExtractedSpriteQuads).
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](bevy_diagnostic::LogDiagnosticsPlugin) to output diagnostics to the
+/// console.
+#[derive(Default)]
+pub struct SpriteDiagnosticsPlugin;
+
+impl Plugin for SpriteDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let draw_calls = SpriteDrawCalls::default();
+        let instances = SpriteInstances::default();
+        app.register_diagnostic(Diagnostic::new(Self::DRAW_CALLS))
+            .register_diagnostic(Diagnostic::new(Self::INSTANCES))
+            .insert_resource(draw_calls.clone())
+            .insert_resource(instances.clone())
+            .add_systems(Update, Self::diagnostic_system);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .insert_resource(draw_calls)
+                .insert_resource(instances);
+        }
+    }
+}
+
+impl SpriteDiagnosticsPlugin {
+    pub const DRAW_CALLS: DiagnosticPath = DiagnosticPath::const_new("sprite/draw_calls");
+    pub const INSTANCES: DiagnosticPath = DiagnosticPath::const_new("sprite/instances");
+
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        draw_calls: Res<SpriteDrawCalls>,
+        instances: Res<SpriteInstances>,
+    ) {
+        diagnostics.add_measurement(&Self::DRAW_CALLS, || draw_calls.get() as f64);
+        diagnostics.add_measurement(&Self::INSTANCES, || instances.get() as f64);
+    }
+}
+
+/// The number of sprite batches prepared for the last rendered frame, shared between the main
+/// world and the render world.
+///
+/// Only present when the [`SpriteDiagnosticsPlugin`] is added.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SpriteDrawCalls(Arc<AtomicUsize>);
+
+impl SpriteDrawCalls {
+    /// The number of sprite draw calls of the last rendered frame.
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set(&self, draw_calls: usize) {
+        self.0.store(draw_calls, Ordering::Relaxed);
+    }
+}
+
+/// The number of sprite instances prepared for the last rendered frame, shared between the main
+/// world and the render world.
+///
+/// Only present when the [`SpriteDiagnosticsPlugin`] is added.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SpriteInstances(Arc<AtomicUsize>);
+
+impl SpriteInstances {
+    /// The number of sprite instances of the last rendered frame.
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set(&self, instances: usize) {
+        self.0.store(instances, Ordering::Relaxed);
+    }
+}
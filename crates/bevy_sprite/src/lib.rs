@@ -9,12 +9,18 @@
 
 //! Provides 2D sprite rendering functionality.
 mod bundle;
+mod diagnostic;
 mod dynamic_texture_atlas_builder;
 mod mesh2d;
+#[cfg(feature = "bevy_picking")]
+mod picking_backend;
 mod render;
+mod sorting_layer;
 mod sprite;
 mod texture_atlas;
+mod texture_atlas_animation;
 mod texture_atlas_builder;
+mod texture_atlas_loader;
 mod texture_slice;
 
 pub mod prelude {
@@ -25,8 +31,10 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         bundle::SpriteBundle,
+        sorting_layer::{LayerOrder, SortingLayer, SortingLayers},
         sprite::{ImageScaleMode, Sprite},
         texture_atlas::{TextureAtlas, TextureAtlasLayout},
+        texture_atlas_animation::{AnimationFrameReached, AtlasLoopMode, TextureAtlasAnimation},
         texture_slice::{BorderRect, SliceScaleMode, TextureSlice, TextureSlicer},
         ColorMaterial, ColorMesh2dBundle, TextureAtlasBuilder,
     };
@@ -34,12 +42,18 @@ pub mod prelude {
 
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 pub use bundle::*;
+pub use diagnostic::*;
 pub use dynamic_texture_atlas_builder::*;
 pub use mesh2d::*;
+#[cfg(feature = "bevy_picking")]
+pub use picking_backend::*;
 pub use render::*;
+pub use sorting_layer::*;
 pub use sprite::*;
 pub use texture_atlas::*;
+pub use texture_atlas_animation::*;
 pub use texture_atlas_builder::*;
+pub use texture_atlas_loader::*;
 pub use texture_slice::*;
 
 use bevy_app::prelude::*;
@@ -56,6 +70,7 @@ use bevy_render::{
     view::{check_visibility, NoFrustumCulling, VisibilitySystems},
     ExtractSchedule, Render, RenderApp, RenderSet,
 };
+use bevy_transform::TransformSystem;
 
 /// Adds support for 2D sprite rendering.
 #[derive(Default)]
@@ -70,6 +85,8 @@ pub const SPRITE_VIEW_BINDINGS_SHADER_HANDLE: Handle<Shader> =
 pub enum SpriteSystem {
     ExtractSprites,
     ComputeSlices,
+    /// Advances the [`TextureAtlasAnimation`]s.
+    AnimateTextureAtlases,
 }
 
 /// A component that marks entities that aren't themselves sprites but become
@@ -109,17 +126,31 @@ impl Plugin for SpritePlugin {
             .register_type::<TextureSlicer>()
             .register_type::<Anchor>()
             .register_type::<TextureAtlas>()
+            .register_type::<TextureAtlasFrame>()
+            .register_type::<TextureAtlasAnimation>()
+            .register_type::<AtlasLoopMode>()
             .register_type::<Mesh2dHandle>()
             .register_type::<SpriteSource>()
+            .register_type::<SortingLayers>()
+            .register_type::<SortingLayer>()
+            .register_type::<ComputedSortingLayer>()
+            .init_resource::<SortingLayers>()
+            .init_asset_loader::<TexturePackerAtlasLoader>()
+            .add_event::<AnimationFrameReached>()
             .add_plugins((
                 Mesh2dRenderPlugin,
                 ColorMaterialPlugin,
                 ExtractComponentPlugin::<SpriteSource>::default(),
+                ExtractComponentPlugin::<ComputedSortingLayer>::default(),
             ))
             .add_systems(
                 PostUpdate,
                 (
+                    animate_texture_atlases
+                        .in_set(SpriteSystem::AnimateTextureAtlases)
+                        .before(VisibilitySystems::CalculateBounds),
                     calculate_bounds_2d.in_set(VisibilitySystems::CalculateBounds),
+                    compute_sorting_layers.after(TransformSystem::TransformPropagate),
                     (
                         compute_slices_on_asset_event,
                         compute_slices_on_sprite_change,
@@ -196,6 +227,28 @@ pub fn calculate_bounds_2d(
         }
     }
     for (entity, sprite, texture_handle, atlas) in &sprites_to_recalculate_aabb {
+        // A trimmed atlas texture only covers part of its untrimmed frame
+        if let Some((rect, frame)) = atlas
+            .filter(|_| sprite.rect.is_none())
+            .and_then(|atlas| Some((atlas.texture_rect(&atlases)?, atlas.frame(&atlases)?)))
+        {
+            let texture_size = rect.size().as_vec2();
+            let anchor = frame.texture_anchor(
+                sprite.anchor.as_vec(),
+                texture_size,
+                sprite.flip_x,
+                sprite.flip_y,
+            );
+            let size = sprite
+                .custom_size
+                .map_or(texture_size, |size| frame.texture_size(size, texture_size));
+            let aabb = Aabb {
+                center: (-anchor * size).extend(0.0).into(),
+                half_extents: (0.5 * size).extend(0.0).into(),
+            };
+            commands.entity(entity).try_insert(aabb);
+            continue;
+        }
         if let Some(size) = sprite
             .custom_size
             .or_else(|| sprite.rect.map(|rect| rect.size()))
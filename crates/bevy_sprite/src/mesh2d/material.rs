@@ -10,7 +10,6 @@ use bevy_ecs::{
     prelude::*,
     system::{lifetimeless::SRes, SystemParamItem},
 };
-use bevy_math::FloatOrd;
 use bevy_render::{
     mesh::{GpuMesh, MeshVertexBufferLayoutRef},
     render_asset::{
@@ -36,8 +35,8 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 
 use crate::{
-    DrawMesh2d, Mesh2dHandle, Mesh2dPipeline, Mesh2dPipelineKey, RenderMesh2dInstances,
-    SetMesh2dBindGroup, SetMesh2dViewBindGroup, WithMesh2d,
+    ComputedSortingLayer, DrawMesh2d, Mesh2dHandle, Mesh2dPipeline, Mesh2dPipelineKey,
+    RenderMesh2dInstances, SetMesh2dBindGroup, SetMesh2dViewBindGroup, WithMesh2d,
 };
 
 /// Materials are used alongside [`Material2dPlugin`] and [`MaterialMesh2dBundle`]
@@ -374,6 +373,7 @@ pub fn queue_material2d_meshes<M: Material2d>(
     render_materials: Res<RenderAssets<PreparedMaterial2d<M>>>,
     mut render_mesh_instances: ResMut<RenderMesh2dInstances>,
     render_material_instances: Res<RenderMaterial2dInstances<M>>,
+    sorting_layers: Query<&ComputedSortingLayer>,
     mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
     mut views: Query<(
         Entity,
@@ -452,8 +452,12 @@ pub fn queue_material2d_meshes<M: Material2d>(
                 // NOTE: Back-to-front ordering for transparent with ascending sort means far should have the
                 // lowest sort key and getting closer should increase. As we have
                 // -z in front of the camera, the largest distance is -far with values increasing toward the
-                // camera. As such we can just use mesh_z as the distance
-                sort_key: FloatOrd(mesh_z + material_2d.depth_bias),
+                // camera. As such we can just use mesh_z as the distance, within the sorting layer
+                sort_key: sorting_layers
+                    .get(*visible_entity)
+                    .copied()
+                    .unwrap_or_default()
+                    .sort_key(mesh_z + material_2d.depth_bias, *visible_entity),
                 // Batching is done in batch_and_prepare_render_phase
                 batch_range: 0..1,
                 extra_index: PhaseItemExtraIndex::NONE,
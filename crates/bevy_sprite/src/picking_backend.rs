@@ -0,0 +1,409 @@
+use std::cmp::Reverse;
+
+use bevy_app::prelude::*;
+use bevy_asset::{Assets, Handle};
+use bevy_core_pipeline::core_2d::Camera2d;
+use bevy_ecs::prelude::*;
+use bevy_math::{Rect, Vec2};
+use bevy_picking::backend::prelude::*;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    camera::Camera,
+    primitives::Aabb,
+    render_resource::TextureFormat,
+    texture::Image,
+    view::{ViewVisibility, VisibleEntities},
+};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::PrimaryWindow;
+
+use crate::{
+    render::sprite_texture_layout, ComputedSortingLayer, ImageScaleMode, Sprite, SpriteSource,
+    TextureAtlas, TextureAtlasLayout, WithSprite,
+};
+
+/// How the pointers hit sprites with the [`SpritePickingPlugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum SpritePickingMode {
+    /// Sprites are hit anywhere in their rect.
+    BoundingBox,
+    /// Sprites are only hit where the alpha of their image is above the threshold, from `0` to
+    /// `1`.
+    ///
+    /// Sprites are hit anywhere in their rect if their image isn't in the main world, uses a
+    /// texture format without an 8 bits alpha channel, or is sliced by an [`ImageScaleMode`].
+    AlphaThreshold(f32),
+}
+
+impl Default for SpritePickingMode {
+    fn default() -> Self {
+        Self::AlphaThreshold(0.1)
+    }
+}
+
+/// The settings of the [`SpritePickingPlugin`].
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct SpritePickingSettings {
+    /// How the pointers hit sprites.
+    pub mode: SpritePickingMode,
+}
+
+/// A picking backend for [`Sprite`]s and the other entities drawn as sprites, such as `Text2d`,
+/// seen by [`Camera2d`]s.
+///
+/// Sprites are hit following the [`SpritePickingSettings`], and other entities anywhere in their
+/// [`Aabb`], which is computed from the layout of `Text2d`. Entities with a
+/// [`NoFrustumCulling`](bevy_render::view::NoFrustumCulling) component have no [`Aabb`], so they
+/// can't be hit.
+///
+/// The hits of a camera are ordered like the sprites are drawn, by [`SortingLayer`](crate::SortingLayer)
+/// then `Z` translation, the last drawn sprite being the closest. Add a [`Pickable`] to a sprite
+/// to let the pointer hit the sprites under it, or to ignore it.
+///
+/// Needs the `bevy_picking::DefaultPickingPlugins` to send the pointer events.
+pub struct SpritePickingPlugin;
+
+impl Plugin for SpritePickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpritePickingSettings>()
+            .register_type::<SpritePickingSettings>()
+            .register_type::<SpritePickingMode>()
+            .add_systems(PreUpdate, sprite_picking.in_set(PickSet::Backend));
+    }
+}
+
+/// Sends the [`PointerHits`] of the sprites under the pointers, for each [`Camera2d`].
+#[allow(clippy::too_many_arguments)]
+pub fn sprite_picking(
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform, &VisibleEntities), With<Camera2d>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    settings: Res<SpritePickingSettings>,
+    images: Res<Assets<Image>>,
+    texture_atlases: Res<Assets<TextureAtlasLayout>>,
+    sprites: Query<(
+        &GlobalTransform,
+        &ViewVisibility,
+        Option<&Sprite>,
+        Option<&Handle<Image>>,
+        Option<&TextureAtlas>,
+        Option<&ImageScaleMode>,
+        Option<&Aabb>,
+        Option<&ComputedSortingLayer>,
+        Has<SpriteSource>,
+    )>,
+    mut output: EventWriter<PointerHits>,
+) {
+    let mut hits = Vec::new();
+    for (pointer_id, pointer_location) in &pointers {
+        let Some(location) = pointer_location.location() else {
+            continue;
+        };
+        for (camera_entity, camera, camera_transform, visible_entities) in &cameras {
+            if !camera.is_active || !location.is_in_viewport(camera, &primary_window) {
+                continue;
+            }
+            let mut viewport_position = location.position;
+            if let Some(viewport) = &camera.viewport {
+                let Some(viewport_min) = camera.to_logical(viewport.physical_position) else {
+                    continue;
+                };
+                viewport_position -= viewport_min;
+            }
+            let Some(cursor) = camera.viewport_to_world_2d(camera_transform, viewport_position)
+            else {
+                continue;
+            };
+
+            hits.clear();
+            for &entity in visible_entities.iter::<WithSprite>() {
+                let Ok((
+                    transform,
+                    view_visibility,
+                    sprite,
+                    image_handle,
+                    atlas,
+                    scale_mode,
+                    aabb,
+                    sorting_layer,
+                    is_source,
+                )) = sprites.get(entity)
+                else {
+                    continue;
+                };
+                if !view_visibility.get() {
+                    continue;
+                }
+
+                // The cursor in the plane of the sprite, relative to its transform
+                let world_cursor = cursor.extend(transform.translation().z);
+                let local_cursor = transform
+                    .affine()
+                    .inverse()
+                    .transform_point3(world_cursor)
+                    .truncate();
+
+                let is_hit = match (sprite, image_handle) {
+                    (Some(sprite), Some(image_handle)) if !is_source => {
+                        let image = images.get(image_handle);
+                        let (rect, anchor, custom_size) =
+                            sprite_texture_layout(sprite, atlas, &texture_atlases);
+                        let Some(size) = custom_size
+                            .or(rect.map(|rect| rect.size()))
+                            .or(image.map(|image| image.size().as_vec2()))
+                        else {
+                            continue;
+                        };
+                        // The position of the cursor in the sprite, from `0` to `1` with y up
+                        let uv = local_cursor / size + 0.5 + anchor;
+                        if !(0.0..=1.0).contains(&uv.x) || !(0.0..=1.0).contains(&uv.y) {
+                            continue;
+                        }
+                        match (settings.mode, image) {
+                            (SpritePickingMode::AlphaThreshold(threshold), Some(image))
+                                if scale_mode.is_none() =>
+                            {
+                                let rect = rect.unwrap_or(Rect {
+                                    min: Vec2::ZERO,
+                                    max: image.size().as_vec2(),
+                                });
+                                let uv = Vec2::new(
+                                    if sprite.flip_x { 1.0 - uv.x } else { uv.x },
+                                    if sprite.flip_y { uv.y } else { 1.0 - uv.y },
+                                );
+                                alpha_at(image, rect.min + uv * rect.size())
+                                    .map_or(true, |alpha| alpha > threshold)
+                            }
+                            _ => true,
+                        }
+                    }
+                    _ => aabb.is_some_and(|aabb| {
+                        let offset = local_cursor - aabb.center.truncate();
+                        offset.abs().cmple(aabb.half_extents.truncate()).all()
+                    }),
+                };
+                if is_hit {
+                    let z = transform.translation().z;
+                    let sort_key = sorting_layer
+                        .copied()
+                        .unwrap_or_default()
+                        .sort_key(z, entity);
+                    hits.push((sort_key, entity, world_cursor));
+                }
+            }
+
+            // The last drawn sprite is the closest one
+            hits.sort_unstable_by_key(|&(sort_key, ..)| Reverse(sort_key));
+            let picks = hits
+                .iter()
+                .enumerate()
+                .map(|(depth, &(_, entity, position))| {
+                    let hit = HitData::new(camera_entity, depth as f32, Some(position), None);
+                    (entity, hit)
+                })
+                .collect();
+            output.send(PointerHits::new(*pointer_id, picks, camera.order as f32));
+        }
+    }
+}
+
+/// Returns the alpha of `image` at `position`, in pixels, or `None` if its format isn't supported.
+fn alpha_at(image: &Image, position: Vec2) -> Option<f32> {
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8Unorm
+        | TextureFormat::Rgba8UnormSrgb
+        | TextureFormat::Bgra8Unorm
+        | TextureFormat::Bgra8UnormSrgb => {}
+        _ => return None,
+    }
+    let size = image.size();
+    let x = (position.x as u32).min(size.x.checked_sub(1)?);
+    let y = (position.y as u32).min(size.y.checked_sub(1)?);
+    let index = (y * size.x + x) as usize * 4 + 3;
+    image.data.get(index).map(|&alpha| alpha as f32 / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Anchor, TextureAtlasFrame};
+    use bevy_asset::AssetEvent;
+    use bevy_core_pipeline::core_2d::Camera2dBundle;
+    use bevy_math::{Quat, URect, UVec2};
+    use bevy_picking::pointer::Location;
+    use bevy_render::camera::{ManualTextureViews, NormalizedRenderTarget, OrthographicProjection};
+    use bevy_render::render_asset::RenderAssetUsages;
+    use bevy_render::render_resource::{Extent3d, TextureDimension};
+    use bevy_transform::components::Transform;
+    use bevy_utils::default;
+    use bevy_window::{
+        Window, WindowCreated, WindowRef, WindowResized, WindowResolution, WindowScaleFactorChanged,
+    };
+
+    #[test]
+    fn sprites_under_the_pointer_are_hit() {
+        let mut world = World::new();
+        world.init_resource::<SpritePickingSettings>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<Assets<TextureAtlasLayout>>();
+        world.init_resource::<Events<PointerHits>>();
+        // Required for the camera system
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<ManualTextureViews>();
+
+        let window = world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(800.0, 600.0),
+                    ..default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        world.spawn(Camera2dBundle::default());
+        let pointer = world
+            .spawn((PointerId::Mouse, PointerLocation::default()))
+            .id();
+
+        let mut spawn_sprite = |sprite: Sprite, transform: Transform| {
+            let mut view_visibility = ViewVisibility::HIDDEN;
+            view_visibility.set();
+            world
+                .spawn((
+                    sprite,
+                    Handle::<Image>::default(),
+                    GlobalTransform::from(transform),
+                    view_visibility,
+                ))
+                .id()
+        };
+        let anchored = spawn_sprite(
+            Sprite {
+                custom_size: Some(Vec2::new(100.0, 50.0)),
+                anchor: Anchor::BottomLeft,
+                ..default()
+            },
+            Transform::default(),
+        );
+        let rotated = spawn_sprite(
+            Sprite {
+                custom_size: Some(Vec2::new(100.0, 20.0)),
+                ..default()
+            },
+            Transform::from_xyz(-300.0, 0.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        );
+        let trimmed = spawn_sprite(Sprite::default(), Transform::from_xyz(300.0, 0.0, 0.0));
+        let front = spawn_sprite(
+            Sprite {
+                custom_size: Some(Vec2::splat(50.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 200.0, 10.0),
+        );
+        let back = spawn_sprite(
+            Sprite {
+                custom_size: Some(Vec2::splat(50.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 200.0, 5.0),
+        );
+        let layered = spawn_sprite(
+            Sprite {
+                custom_size: Some(Vec2::splat(50.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 200.0, 1.0),
+        );
+        world.entity_mut(layered).insert(ComputedSortingLayer {
+            layer: 1,
+            order: 0.0,
+        });
+
+        // A 16x16 texture trimmed out of the top right quarter of a 32x32 frame
+        let mut layout = TextureAtlasLayout::new_empty(UVec2::splat(64));
+        let index = layout.add_texture(URect::new(0, 0, 16, 16));
+        layout.set_frame(
+            index,
+            TextureAtlasFrame {
+                source_size: Vec2::splat(32.0),
+                offset: Vec2::splat(8.0),
+            },
+        );
+        let layout = world
+            .resource_mut::<Assets<TextureAtlasLayout>>()
+            .add(layout);
+        world
+            .entity_mut(trimmed)
+            .insert(TextureAtlas { layout, index });
+
+        let sprites = [anchored, rotated, trimmed, front, back, layered];
+        let mut visible_entities = world.query::<&mut VisibleEntities>().single_mut(&mut world);
+        for sprite in sprites {
+            visible_entities.push::<WithSprite>(sprite);
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                bevy_render::camera::camera_system::<OrthographicProjection>,
+                sprite_picking,
+            )
+                .chain(),
+        );
+        // The entities hit at `position` in the world, from the closest one
+        let mut hits_at = |position: Vec2| {
+            world.get_mut::<PointerLocation>(pointer).unwrap().location = Some(Location {
+                target: NormalizedRenderTarget::Window(
+                    WindowRef::Primary.normalize(Some(window)).unwrap(),
+                ),
+                position: Vec2::new(400.0 + position.x, 300.0 - position.y),
+            });
+            schedule.run(&mut world);
+            let hits: Vec<_> = world
+                .resource_mut::<Events<PointerHits>>()
+                .drain()
+                .collect();
+            assert_eq!(hits.len(), 1);
+            let picks = hits[0].picks.iter().map(|&(entity, _)| entity);
+            picks.collect::<Vec<_>>()
+        };
+
+        // The sprite spans its custom size from its anchor
+        assert_eq!(hits_at(Vec2::new(90.0, 40.0)), [anchored]);
+        assert!(hits_at(Vec2::new(-10.0, 10.0)).is_empty());
+        // The rotated sprite is hit along its rotated size
+        assert_eq!(hits_at(Vec2::new(-300.0, 40.0)), [rotated]);
+        assert!(hits_at(Vec2::new(-260.0, 0.0)).is_empty());
+        // The trimmed texture is only hit where it's drawn in its frame
+        assert_eq!(hits_at(Vec2::new(308.0, 8.0)), [trimmed]);
+        assert!(hits_at(Vec2::new(292.0, -8.0)).is_empty());
+        // The hits are ordered by sorting layer, then translation Z
+        assert_eq!(hits_at(Vec2::new(0.0, 200.0)), [layered, front, back]);
+    }
+
+    #[test]
+    fn alpha_of_pixels() {
+        // A 2x1 image, transparent on the left and opaque on the right
+        let image = Image::new(
+            Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![255, 255, 255, 0, 255, 255, 255, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        assert_eq!(alpha_at(&image, Vec2::new(0.5, 0.5)), Some(0.0));
+        assert_eq!(alpha_at(&image, Vec2::new(1.5, 0.5)), Some(1.0));
+        // The right and bottom edges are in the image
+        assert_eq!(alpha_at(&image, Vec2::new(2.0, 1.0)), Some(1.0));
+    }
+}
@@ -2,7 +2,8 @@ use std::ops::Range;
 
 use crate::{
     texture_atlas::{TextureAtlas, TextureAtlasLayout},
-    ComputedTextureSlices, Sprite, WithSprite, SPRITE_SHADER_HANDLE,
+    ComputedSortingLayer, ComputedTextureSlices, Sprite, SpriteDrawCalls, SpriteInstances,
+    WithSprite, SPRITE_SHADER_HANDLE,
 };
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_color::{ColorToComponents, LinearRgba};
@@ -18,7 +19,7 @@ use bevy_ecs::{
     prelude::*,
     system::{lifetimeless::*, SystemParamItem, SystemState},
 };
-use bevy_math::{Affine3A, FloatOrd, Quat, Rect, Vec2, Vec4};
+use bevy_math::{Affine3A, Quat, Rect, Vec2, Vec4};
 use bevy_render::{
     render_asset::RenderAssets,
     render_phase::{
@@ -324,9 +325,37 @@ pub struct ExtractedSprite {
     pub original_entity: Option<Entity>,
 }
 
+/// A textured quad of an [`ExtractedSpriteQuads`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpriteQuad {
+    /// The center of the quad, relative to the transform of the [`ExtractedSpriteQuads`].
+    pub position: Vec2,
+    /// The size of the quad.
+    pub size: Vec2,
+    /// The area of the texture drawn on the quad, in pixels.
+    pub rect: Rect,
+    pub color: LinearRgba,
+}
+
+/// Quads sharing a transform and a texture, drawn as instances of a single phase item.
+///
+/// This is cheaper to extract, sort and prepare than an [`ExtractedSprite`] for each quad, for
+/// example for the glyphs of a text. The quads are drawn in order, and consecutive items sharing
+/// a texture are still batched into a single draw call.
+pub struct ExtractedSpriteQuads {
+    pub transform: GlobalTransform,
+    /// Asset ID of the [`Image`] of the quads
+    pub image_handle_id: AssetId<Image>,
+    pub quads: Vec<SpriteQuad>,
+    /// The entity that caused the creation of these quads during extraction, used in determining
+    /// visibility.
+    pub original_entity: Option<Entity>,
+}
+
 #[derive(Resource, Default)]
 pub struct ExtractedSprites {
     pub sprites: EntityHashMap<ExtractedSprite>,
+    pub quads: EntityHashMap<ExtractedSpriteQuads>,
 }
 
 #[derive(Resource, Default)]
@@ -363,6 +392,7 @@ pub fn extract_sprites(
     >,
 ) {
     extracted_sprites.sprites.clear();
+    extracted_sprites.quads.clear();
     for (entity, view_visibility, sprite, transform, handle, sheet, slices) in sprite_query.iter() {
         if !view_visibility.get() {
             continue;
@@ -375,18 +405,8 @@ pub fn extract_sprites(
                     .map(|e| (commands.spawn_empty().id(), e)),
             );
         } else {
-            let atlas_rect = sheet.and_then(|s| s.texture_rect(&texture_atlases));
-            let rect = match (atlas_rect, sprite.rect) {
-                (None, None) => None,
-                (None, Some(sprite_rect)) => Some(sprite_rect),
-                (Some(atlas_rect), None) => Some(atlas_rect.as_rect()),
-                (Some(atlas_rect), Some(mut sprite_rect)) => {
-                    sprite_rect.min += atlas_rect.min.as_vec2();
-                    sprite_rect.max += atlas_rect.min.as_vec2();
-
-                    Some(sprite_rect)
-                }
-            };
+            let (rect, anchor, custom_size) =
+                sprite_texture_layout(sprite, sheet, &texture_atlases);
 
             // PERF: we don't check in this function that the `Image` asset is ready, since it should be in most cases and hashing the handle is expensive
             extracted_sprites.sprites.insert(
@@ -396,11 +416,11 @@ pub fn extract_sprites(
                     transform: *transform,
                     rect,
                     // Pass the custom size
-                    custom_size: sprite.custom_size,
+                    custom_size,
                     flip_x: sprite.flip_x,
                     flip_y: sprite.flip_y,
                     image_handle_id: handle.id(),
-                    anchor: sprite.anchor.as_vec(),
+                    anchor,
                     original_entity: None,
                 },
             );
@@ -408,6 +428,39 @@ pub fn extract_sprites(
     }
 }
 
+/// Returns the rect of the image drawn by `sprite`, with its anchor and custom size, placing a
+/// trimmed atlas texture where it was in its untrimmed frame.
+pub(crate) fn sprite_texture_layout(
+    sprite: &Sprite,
+    sheet: Option<&TextureAtlas>,
+    texture_atlases: &Assets<TextureAtlasLayout>,
+) -> (Option<Rect>, Vec2, Option<Vec2>) {
+    let atlas_rect = sheet.and_then(|s| s.texture_rect(texture_atlases));
+    let rect = match (atlas_rect, sprite.rect) {
+        (None, None) => None,
+        (None, Some(sprite_rect)) => Some(sprite_rect),
+        (Some(atlas_rect), None) => Some(atlas_rect.as_rect()),
+        (Some(atlas_rect), Some(mut sprite_rect)) => {
+            sprite_rect.min += atlas_rect.min.as_vec2();
+            sprite_rect.max += atlas_rect.min.as_vec2();
+
+            Some(sprite_rect)
+        }
+    };
+
+    // A trimmed atlas texture is drawn where it was in its untrimmed frame
+    let mut anchor = sprite.anchor.as_vec();
+    let mut custom_size = sprite.custom_size;
+    if let (Some(atlas_rect), None) = (atlas_rect, sprite.rect) {
+        if let Some(frame) = sheet.and_then(|s| s.frame(texture_atlases)) {
+            let texture_size = atlas_rect.size().as_vec2();
+            anchor = frame.texture_anchor(anchor, texture_size, sprite.flip_x, sprite.flip_y);
+            custom_size = custom_size.map(|size| frame.texture_size(size, texture_size));
+        }
+    }
+    (rect, anchor, custom_size)
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Pod, Zeroable)]
 struct SpriteInstance {
@@ -473,6 +526,7 @@ pub fn queue_sprites(
     pipeline_cache: Res<PipelineCache>,
     msaa: Res<Msaa>,
     extracted_sprites: Res<ExtractedSprites>,
+    sorting_layers: Query<&ComputedSortingLayer>,
     mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
     mut views: Query<(
         Entity,
@@ -527,17 +581,36 @@ pub fn queue_sprites(
 
         transparent_phase
             .items
-            .reserve(extracted_sprites.sprites.len());
+            .reserve(extracted_sprites.sprites.len() + extracted_sprites.quads.len());
 
-        for (entity, extracted_sprite) in extracted_sprites.sprites.iter() {
-            let index = extracted_sprite.original_entity.unwrap_or(*entity).index();
+        let sprites = extracted_sprites.sprites.iter().map(|(entity, sprite)| {
+            (
+                entity,
+                sprite.original_entity,
+                sprite.transform.translation().z,
+            )
+        });
+        let quads = extracted_sprites.quads.iter().map(|(entity, quads)| {
+            (
+                entity,
+                quads.original_entity,
+                quads.transform.translation().z,
+            )
+        });
+        for (entity, original_entity, depth) in sprites.chain(quads) {
+            let original_entity = original_entity.unwrap_or(*entity);
 
-            if !view_entities.contains(index as usize) {
+            if !view_entities.contains(original_entity.index() as usize) {
                 continue;
             }
 
-            // These items will be sorted by depth with other phase items
-            let sort_key = FloatOrd(extracted_sprite.transform.translation().z);
+            // These items will be sorted by sorting layer then depth with other phase items,
+            // and by entity when they are equal, to keep a stable order across frames
+            let sort_key = sorting_layers
+                .get(original_entity)
+                .copied()
+                .unwrap_or_default()
+                .sort_key(depth, *entity);
 
             // Add the item to the render phase
             transparent_phase.add(Transparent2d {
@@ -600,6 +673,8 @@ pub fn prepare_sprite_image_bind_groups(
     extracted_sprites: Res<ExtractedSprites>,
     mut phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
     events: Res<SpriteAssetEvents>,
+    draw_calls: Option<Res<SpriteDrawCalls>>,
+    instances: Option<Res<SpriteInstances>>,
 ) {
     // If an image has changed, the GpuImage has (probably) changed
     for event in &events.images {
@@ -633,7 +708,12 @@ pub fn prepare_sprite_image_bind_groups(
         // Compatible items share the same entity.
         for item_index in 0..transparent_phase.items.len() {
             let item = &transparent_phase.items[item_index];
-            let Some(extracted_sprite) = extracted_sprites.sprites.get(&item.entity) else {
+            let extracted_sprite = extracted_sprites.sprites.get(&item.entity);
+            let extracted_quads = extracted_sprites.quads.get(&item.entity);
+            let Some(image_handle_id) = extracted_sprite
+                .map(|sprite| sprite.image_handle_id)
+                .or(extracted_quads.map(|quads| quads.image_handle_id))
+            else {
                 // If there is a phase item that is not a sprite, then we must start a new
                 // batch to draw the other phase item(s) and to respect draw order. This can be
                 // done by invalidating the batch_image_handle
@@ -641,14 +721,14 @@ pub fn prepare_sprite_image_bind_groups(
                 continue;
             };
 
-            let batch_image_changed = batch_image_handle != extracted_sprite.image_handle_id;
+            let batch_image_changed = batch_image_handle != image_handle_id;
             if batch_image_changed {
-                let Some(gpu_image) = gpu_images.get(extracted_sprite.image_handle_id) else {
+                let Some(gpu_image) = gpu_images.get(image_handle_id) else {
                     continue;
                 };
 
                 batch_image_size = gpu_image.size.as_vec2();
-                batch_image_handle = extracted_sprite.image_handle_id;
+                batch_image_handle = image_handle_id;
                 image_bind_groups
                     .values
                     .entry(batch_image_handle)
@@ -662,60 +742,8 @@ pub fn prepare_sprite_image_bind_groups(
                             )),
                         )
                     });
-            }
-
-            // By default, the size of the quad is the size of the texture
-            let mut quad_size = batch_image_size;
-
-            // Calculate vertex data for this item
-            let mut uv_offset_scale: Vec4;
-
-            // If a rect is specified, adjust UVs and the size of the quad
-            if let Some(rect) = extracted_sprite.rect {
-                let rect_size = rect.size();
-                uv_offset_scale = Vec4::new(
-                    rect.min.x / batch_image_size.x,
-                    rect.max.y / batch_image_size.y,
-                    rect_size.x / batch_image_size.x,
-                    -rect_size.y / batch_image_size.y,
-                );
-                quad_size = rect_size;
-            } else {
-                uv_offset_scale = Vec4::new(0.0, 1.0, 1.0, -1.0);
-            }
-
-            if extracted_sprite.flip_x {
-                uv_offset_scale.x += uv_offset_scale.z;
-                uv_offset_scale.z *= -1.0;
-            }
-            if extracted_sprite.flip_y {
-                uv_offset_scale.y += uv_offset_scale.w;
-                uv_offset_scale.w *= -1.0;
-            }
-
-            // Override the size if a custom one is specified
-            if let Some(custom_size) = extracted_sprite.custom_size {
-                quad_size = custom_size;
-            }
-            let transform = extracted_sprite.transform.affine()
-                * Affine3A::from_scale_rotation_translation(
-                    quad_size.extend(1.0),
-                    Quat::IDENTITY,
-                    (quad_size * (-extracted_sprite.anchor - Vec2::splat(0.5))).extend(0.0),
-                );
-
-            // Store the vertex data and add the item to the render phase
-            sprite_meta
-                .sprite_instance_buffer
-                .push(SpriteInstance::from(
-                    &transform,
-                    &extracted_sprite.color,
-                    &uv_offset_scale,
-                ));
 
-            if batch_image_changed {
                 batch_item_index = item_index;
-
                 batches.push((
                     item.entity,
                     SpriteBatch {
@@ -725,11 +753,31 @@ pub fn prepare_sprite_image_bind_groups(
                 ));
             }
 
+            // Store the vertex data of the instances of this item
+            let mut instance_count = 0;
+            if let Some(extracted_sprite) = extracted_sprite {
+                sprite_meta
+                    .sprite_instance_buffer
+                    .push(sprite_instance(extracted_sprite, batch_image_size));
+                instance_count = 1;
+            } else if let Some(extracted_quads) = extracted_quads {
+                let transform = extracted_quads.transform.affine();
+                for quad in &extracted_quads.quads {
+                    sprite_meta.sprite_instance_buffer.push(quad_instance(
+                        &transform,
+                        quad,
+                        batch_image_size,
+                    ));
+                }
+                instance_count = extracted_quads.quads.len() as u32;
+            }
+
+            // The item is drawn by the batch as a whole, along with all of its instances
             transparent_phase.items[batch_item_index]
                 .batch_range_mut()
                 .end += 1;
-            batches.last_mut().unwrap().1.range.end += 1;
-            index += 1;
+            batches.last_mut().unwrap().1.range.end += instance_count;
+            index += instance_count;
         }
     }
     sprite_meta
@@ -761,9 +809,80 @@ pub fn prepare_sprite_image_bind_groups(
     }
 
     *previous_len = batches.len();
+    if let Some(draw_calls) = draw_calls {
+        draw_calls.set(batches.len());
+    }
+    if let Some(instances) = instances {
+        instances.set(index as usize);
+    }
     commands.insert_or_spawn_batch(batches);
 }
 
+/// Computes the instance drawing an [`ExtractedSprite`] from a texture of the given size.
+fn sprite_instance(extracted_sprite: &ExtractedSprite, image_size: Vec2) -> SpriteInstance {
+    // By default, the size of the quad is the size of the texture
+    let mut quad_size = image_size;
+
+    // Calculate vertex data for this item
+    let mut uv_offset_scale: Vec4;
+
+    // If a rect is specified, adjust UVs and the size of the quad
+    if let Some(rect) = extracted_sprite.rect {
+        let rect_size = rect.size();
+        uv_offset_scale = Vec4::new(
+            rect.min.x / image_size.x,
+            rect.max.y / image_size.y,
+            rect_size.x / image_size.x,
+            -rect_size.y / image_size.y,
+        );
+        quad_size = rect_size;
+    } else {
+        uv_offset_scale = Vec4::new(0.0, 1.0, 1.0, -1.0);
+    }
+
+    if extracted_sprite.flip_x {
+        uv_offset_scale.x += uv_offset_scale.z;
+        uv_offset_scale.z *= -1.0;
+    }
+    if extracted_sprite.flip_y {
+        uv_offset_scale.y += uv_offset_scale.w;
+        uv_offset_scale.w *= -1.0;
+    }
+
+    // Override the size if a custom one is specified
+    if let Some(custom_size) = extracted_sprite.custom_size {
+        quad_size = custom_size;
+    }
+    let transform = extracted_sprite.transform.affine()
+        * Affine3A::from_scale_rotation_translation(
+            quad_size.extend(1.0),
+            Quat::IDENTITY,
+            (quad_size * (-extracted_sprite.anchor - Vec2::splat(0.5))).extend(0.0),
+        );
+
+    SpriteInstance::from(&transform, &extracted_sprite.color, &uv_offset_scale)
+}
+
+/// Computes the instance drawing a [`SpriteQuad`] of [`ExtractedSpriteQuads`] with the given
+/// transform, from a texture of the given size.
+fn quad_instance(transform: &Affine3A, quad: &SpriteQuad, image_size: Vec2) -> SpriteInstance {
+    let rect_size = quad.rect.size();
+    let uv_offset_scale = Vec4::new(
+        quad.rect.min.x / image_size.x,
+        quad.rect.max.y / image_size.y,
+        rect_size.x / image_size.x,
+        -rect_size.y / image_size.y,
+    );
+    let transform = *transform
+        * Affine3A::from_scale_rotation_translation(
+            quad.size.extend(1.0),
+            Quat::IDENTITY,
+            (quad.position - quad.size * 0.5).extend(0.0),
+        );
+
+    SpriteInstance::from(&transform, &quad.color, &uv_offset_scale)
+}
+
 /// [`RenderCommand`] for sprite rendering.
 pub type DrawSprite = (
     SetItemPipeline,
@@ -854,3 +973,40 @@ impl<P: PhaseItem> RenderCommand<P> for DrawSpriteBatch {
         RenderCommandResult::Success
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec3;
+
+    use super::*;
+
+    #[test]
+    fn quad_instance_matches_sprite_instance() {
+        let transform = GlobalTransform::from_translation(Vec3::new(10.0, -4.0, 2.0));
+        let quad = SpriteQuad {
+            position: Vec2::new(3.0, 5.0),
+            size: Vec2::new(8.0, 12.0),
+            rect: Rect::new(16.0, 0.0, 24.0, 12.0),
+            color: LinearRgba::RED,
+        };
+        let sprite = ExtractedSprite {
+            transform: transform * GlobalTransform::from_translation(quad.position.extend(0.0)),
+            color: quad.color,
+            rect: Some(quad.rect),
+            custom_size: Some(quad.size),
+            image_handle_id: AssetId::default(),
+            flip_x: false,
+            flip_y: false,
+            anchor: Vec2::ZERO,
+            original_entity: None,
+        };
+
+        let image_size = Vec2::new(64.0, 32.0);
+        let quad_instance = quad_instance(&transform.affine(), &quad, image_size);
+        let sprite_instance = sprite_instance(&sprite, image_size);
+        assert_eq!(
+            bytemuck::bytes_of(&quad_instance),
+            bytemuck::bytes_of(&sprite_instance)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+
+use bevy_core_pipeline::core_2d::Sort2dKey;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::{Entity, EntityHashSet},
+    prelude::{Component, With},
+    query::Without,
+    reflect::{ReflectComponent, ReflectResource},
+    system::{Commands, Local, Query, Res, Resource},
+};
+use bevy_hierarchy::{Children, DescendantStack, HierarchyQueryExt};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::extract_component::ExtractComponent;
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::warn_once;
+
+/// The sorting layers of 2D entities, from the first drawn to the last drawn.
+///
+/// Entities without a [`SortingLayer`] are in the [`SortingLayers::DEFAULT`] layer.
+#[derive(Resource, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct SortingLayers {
+    /// The names of the layers, in drawing order. Should contain [`SortingLayers::DEFAULT`].
+    pub layers: Vec<Cow<'static, str>>,
+}
+
+impl SortingLayers {
+    /// The name of the layer of entities without a [`SortingLayer`].
+    pub const DEFAULT: &'static str = "Default";
+
+    /// Creates sorting layers with the names `layers`, in drawing order.
+    pub fn new(layers: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> Self {
+        Self {
+            layers: layers.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the index of the layer `name` relative to the default layer,
+    /// or `None` if there is no such layer.
+    ///
+    /// Layers drawn before the default layer have negative indices.
+    pub fn index(&self, name: &str) -> Option<i16> {
+        let position = |name: &str| self.layers.iter().position(|layer| layer == name);
+        let default = position(Self::DEFAULT).unwrap_or(0);
+        position(name).map(|index| (index as isize - default as isize) as i16)
+    }
+}
+
+impl Default for SortingLayers {
+    fn default() -> Self {
+        Self::new([Self::DEFAULT])
+    }
+}
+
+/// How the entities of a [`SortingLayer`] are ordered in it.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum LayerOrder {
+    /// Entities with a lower order are drawn first.
+    Fixed(i32),
+    /// Entities are ordered by their global `Y` translation, so that entities lower on the screen
+    /// are drawn over the ones above them, as in top-down games.
+    YSort,
+}
+
+impl Default for LayerOrder {
+    fn default() -> Self {
+        Self::Fixed(0)
+    }
+}
+
+/// Sorts a 2D entity in a layer of the [`SortingLayers`], instead of by its `Z` translation.
+///
+/// The descendants of the entity without a [`SortingLayer`] inherit its layer and order,
+/// and are sorted among themselves by their `Z` translation. This keeps the parts of an entity,
+/// such as the sprites of a character, together in its layer.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sprite::{LayerOrder, SortingLayer, SortingLayers, SpriteBundle};
+/// fn setup(mut commands: Commands) {
+///     commands.insert_resource(SortingLayers::new(["Background", "Default", "Foreground"]));
+///     commands.spawn((
+///         SpriteBundle::default(),
+///         SortingLayer::new("Foreground").with_order(LayerOrder::YSort),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct SortingLayer {
+    /// The name of the layer, in the [`SortingLayers`].
+    pub layer: Cow<'static, str>,
+    /// The order of the entity in its layer.
+    pub order: LayerOrder,
+}
+
+impl SortingLayer {
+    /// Creates a [`SortingLayer`] in the layer `layer`, with an order of `0`.
+    pub fn new(layer: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            layer: layer.into(),
+            order: LayerOrder::default(),
+        }
+    }
+
+    /// Returns this [`SortingLayer`] with the order `order`.
+    pub fn with_order(mut self, order: LayerOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+impl Default for SortingLayer {
+    fn default() -> Self {
+        Self::new(SortingLayers::DEFAULT)
+    }
+}
+
+/// The sorting layer and order of an entity, computed from its [`SortingLayer`] or the one of
+/// its closest ancestor by [`compute_sorting_layers`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, ExtractComponent, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ComputedSortingLayer {
+    /// The index of the layer, relative to the default layer.
+    pub layer: i16,
+    /// The order in the layer.
+    pub order: f32,
+}
+
+impl ComputedSortingLayer {
+    /// Returns the key sorting an entity of this layer with a translation `Z` of `depth`.
+    pub fn sort_key(&self, depth: f32, entity: Entity) -> Sort2dKey {
+        Sort2dKey::new(self.layer, self.order, depth, entity.index())
+    }
+}
+
+/// Computes the [`ComputedSortingLayer`] of the entities with a [`SortingLayer`] and of their
+/// descendants, removing it from the entities which are no longer in a layer.
+pub fn compute_sorting_layers(
+    mut commands: Commands,
+    sorting_layers: Res<SortingLayers>,
+    layer_query: Query<(Entity, &SortingLayer, &GlobalTransform)>,
+    children_query: Query<&Children>,
+    mut computed_query: Query<&mut ComputedSortingLayer>,
+    orphan_query: Query<Entity, (With<ComputedSortingLayer>, Without<SortingLayer>)>,
+    mut stack: Local<DescendantStack>,
+    mut in_layer: Local<EntityHashSet>,
+) {
+    in_layer.clear();
+    for (entity, sorting_layer, transform) in &layer_query {
+        let layer = sorting_layers
+            .index(&sorting_layer.layer)
+            .unwrap_or_else(|| {
+                warn_once!(
+                    "Sorting layer {:?} isn't in the `SortingLayers`, using the default layer instead",
+                    sorting_layer.layer
+                );
+                0
+            });
+        let order = match sorting_layer.order {
+            LayerOrder::Fixed(order) => order as f32,
+            LayerOrder::YSort => -transform.translation().y,
+        };
+        let computed = ComputedSortingLayer { layer, order };
+
+        let descendants =
+            children_query.iter_descendants_pruned(entity, &mut stack, |e| layer_query.contains(e));
+        for entity in std::iter::once(entity).chain(descendants) {
+            match computed_query.get_mut(entity) {
+                Ok(mut current) => {
+                    current.set_if_neq(computed);
+                }
+                Err(_) => {
+                    commands.entity(entity).insert(computed);
+                }
+            }
+            in_layer.insert(entity);
+        }
+    }
+
+    // Entities whose layered ancestor was removed, or lost its layer
+    for entity in &orphan_query {
+        if !in_layer.contains(&entity) {
+            commands.entity(entity).remove::<ComputedSortingLayer>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_transform::components::Transform;
+
+    #[test]
+    fn children_inherit_sorting_layer() {
+        let mut world = World::new();
+        world.insert_resource(SortingLayers::new(["Background", "Default", "Foreground"]));
+
+        let child = world.spawn(GlobalTransform::default()).id();
+        let overridden = world
+            .spawn((GlobalTransform::default(), SortingLayer::new("Background")))
+            .id();
+        let parent = world
+            .spawn((
+                GlobalTransform::from(Transform::from_xyz(0.0, 25.0, 0.0)),
+                SortingLayer::new("Foreground").with_order(LayerOrder::YSort),
+            ))
+            .push_children(&[child, overridden])
+            .id();
+
+        world.run_system_once(compute_sorting_layers);
+        let expected = ComputedSortingLayer {
+            layer: 1,
+            order: -25.0,
+        };
+        assert_eq!(world.get(parent), Some(&expected));
+        assert_eq!(world.get(child), Some(&expected));
+        assert_eq!(
+            world.get(overridden),
+            Some(&ComputedSortingLayer {
+                layer: -1,
+                order: 0.0,
+            })
+        );
+
+        world.entity_mut(parent).remove::<SortingLayer>();
+        world.run_system_once(compute_sorting_layers);
+        assert_eq!(world.get::<ComputedSortingLayer>(parent), None);
+        assert_eq!(world.get::<ComputedSortingLayer>(child), None);
+        assert!(world.get::<ComputedSortingLayer>(overridden).is_some());
+    }
+}
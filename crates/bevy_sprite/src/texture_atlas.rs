@@ -1,6 +1,6 @@
 use bevy_asset::{Asset, AssetId, Assets, Handle};
 use bevy_ecs::component::Component;
-use bevy_math::{URect, UVec2};
+use bevy_math::{URect, UVec2, Vec2};
 use bevy_reflect::Reflect;
 use bevy_render::texture::Image;
 use bevy_utils::HashMap;
@@ -29,6 +29,54 @@ pub struct TextureAtlasLayout {
     ///
     /// [`TextureAtlasBuilder`]: crate::TextureAtlasBuilder
     pub(crate) texture_handles: Option<HashMap<AssetId<Image>, usize>>,
+    /// The untrimmed frames of the textures that were trimmed or have a pivot, by their index in
+    /// `textures`.
+    ///
+    /// Sprites drawing one of these textures are placed as if the whole untrimmed frame was
+    /// drawn, so that animations don't jitter when their frames are trimmed differently.
+    pub frames: HashMap<usize, TextureAtlasFrame>,
+}
+
+/// The untrimmed frame of a texture in a [`TextureAtlasLayout`], such as a frame of an animation
+/// whose transparent borders were trimmed when the atlas was packed.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, PartialEq)]
+pub struct TextureAtlasFrame {
+    /// The size of the untrimmed frame, in pixels.
+    pub source_size: Vec2,
+    /// The center of the trimmed texture, relative to the center of the untrimmed frame, in
+    /// pixels with y up.
+    pub offset: Vec2,
+}
+
+impl TextureAtlasFrame {
+    /// Returns the anchor of a `texture_size` texture drawn in this frame, such that the point at
+    /// `anchor` in the untrimmed frame stays at the origin of the sprite.
+    ///
+    /// The position of the texture in the frame is mirrored with the sprite by `flip_x` and
+    /// `flip_y`.
+    pub fn texture_anchor(
+        &self,
+        anchor: Vec2,
+        texture_size: Vec2,
+        flip_x: bool,
+        flip_y: bool,
+    ) -> Vec2 {
+        let mut offset = self.offset;
+        if flip_x {
+            offset.x = -offset.x;
+        }
+        if flip_y {
+            offset.y = -offset.y;
+        }
+        (anchor * self.source_size - offset) / texture_size
+    }
+
+    /// Returns the size of a `texture_size` texture drawn in this frame when the whole frame is
+    /// drawn with a size of `custom_size`.
+    pub fn texture_size(&self, custom_size: Vec2, texture_size: Vec2) -> Vec2 {
+        texture_size * custom_size / self.source_size
+    }
 }
 
 /// Component used to draw a specific section of a texture.
@@ -58,6 +106,7 @@ impl TextureAtlasLayout {
             size: dimensions,
             texture_handles: None,
             textures: Vec::new(),
+            frames: HashMap::default(),
         }
     }
 
@@ -111,6 +160,7 @@ impl TextureAtlasLayout {
             size: ((tile_size + current_padding) * grid_size) - current_padding,
             textures: sprites,
             texture_handles: None,
+            frames: HashMap::default(),
         }
     }
 
@@ -127,6 +177,16 @@ impl TextureAtlasLayout {
         self.textures.len() - 1
     }
 
+    /// Returns the untrimmed frame of the texture at `index`, if it was trimmed or has a pivot.
+    pub fn frame(&self, index: usize) -> Option<TextureAtlasFrame> {
+        self.frames.get(&index).copied()
+    }
+
+    /// Sets the untrimmed frame of the texture at `index`.
+    pub fn set_frame(&mut self, index: usize, frame: TextureAtlasFrame) {
+        self.frames.insert(index, frame);
+    }
+
     /// The number of textures in the [`TextureAtlasLayout`]
     pub fn len(&self) -> usize {
         self.textures.len()
@@ -155,6 +215,11 @@ impl TextureAtlas {
         let atlas = texture_atlases.get(&self.layout)?;
         atlas.textures.get(self.index).copied()
     }
+
+    /// Retrieves the untrimmed [`TextureAtlasFrame`] of the current texture, if it has one
+    pub fn frame(&self, texture_atlases: &Assets<TextureAtlasLayout>) -> Option<TextureAtlasFrame> {
+        texture_atlases.get(&self.layout)?.frame(self.index)
+    }
 }
 
 impl From<Handle<TextureAtlasLayout>> for TextureAtlas {
@@ -0,0 +1,343 @@
+use std::ops::Range;
+
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventWriter},
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::{Real, Time, Virtual};
+use bevy_utils::warn_once;
+
+use crate::{TextureAtlas, TextureAtlasLayout};
+
+/// Plays the frames of a [`TextureAtlasLayout`] as a flipbook, advancing the
+/// [`TextureAtlas::index`] of its entity, such as the atlas of a sprite or of a UI image.
+///
+/// The frames advance with [`Time<Virtual>`], so pausing the game pauses the animation, unless
+/// [`real_time`](Self::real_time) is set. An [`AnimationFrameReached`] event is sent whenever a
+/// frame is shown.
+///
+/// If the layout has fewer frames than the range, the range is clamped to the frames of the
+/// layout, with a warning.
+///
+/// ```
+/// # use bevy_asset::Handle;
+/// # use bevy_sprite::{AtlasLoopMode, TextureAtlas, TextureAtlasAnimation};
+/// # use bevy_ecs::prelude::*;
+/// fn spawn_spinner(mut commands: Commands) {
+///     let layout = Handle::default();
+///     commands.spawn((
+///         TextureAtlas::from(layout.clone()),
+///         TextureAtlasAnimation::new(layout, 0..8, 12.0).with_loop_mode(AtlasLoopMode::PingPong),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct TextureAtlasAnimation {
+    /// The layout holding the frames, set on the [`TextureAtlas`] of the entity.
+    pub layout: Handle<TextureAtlasLayout>,
+    /// The indices of the frames in the layout, played in order.
+    pub frames: Range<usize>,
+    /// The number of frames shown per second.
+    pub fps: f32,
+    /// What happens once the last frame has been shown.
+    pub loop_mode: AtlasLoopMode,
+    /// Whether the animation is paused, keeping its current frame.
+    pub paused: bool,
+    /// Whether the animation advances with [`Time<Real>`], and keeps playing while the virtual
+    /// clock is paused, such as for a loading spinner.
+    pub real_time: bool,
+    /// The offset of the current frame in [`frames`](Self::frames).
+    frame: usize,
+    /// Whether a [`AtlasLoopMode::PingPong`] animation is playing backwards.
+    reversed: bool,
+    /// The time since the current frame was shown, in seconds.
+    elapsed: f32,
+    finished: bool,
+}
+
+impl Default for TextureAtlasAnimation {
+    fn default() -> Self {
+        Self::new(Handle::default(), 0..1, 10.0)
+    }
+}
+
+impl TextureAtlasAnimation {
+    /// Creates a looping animation of the `frames` of `layout`, shown at `fps` frames per second.
+    pub fn new(layout: Handle<TextureAtlasLayout>, frames: Range<usize>, fps: f32) -> Self {
+        Self {
+            layout,
+            frames,
+            fps,
+            loop_mode: AtlasLoopMode::Loop,
+            paused: false,
+            real_time: false,
+            frame: 0,
+            reversed: false,
+            elapsed: 0.0,
+            finished: false,
+        }
+    }
+
+    /// Returns this animation with a new [`AtlasLoopMode`].
+    pub fn with_loop_mode(mut self, loop_mode: AtlasLoopMode) -> Self {
+        self.loop_mode = loop_mode;
+        self
+    }
+
+    /// Returns this animation advancing with [`Time<Real>`] rather than [`Time<Virtual>`].
+    pub fn with_real_time(mut self) -> Self {
+        self.real_time = true;
+        self
+    }
+
+    /// Resumes the animation, restarting it if it was finished.
+    pub fn play(&mut self) {
+        if self.finished {
+            self.restart();
+        }
+        self.paused = false;
+    }
+
+    /// Pauses the animation, keeping its current frame.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Shows the first frame of the animation again.
+    pub fn restart(&mut self) {
+        self.frame = 0;
+        self.reversed = false;
+        self.elapsed = 0.0;
+        self.finished = false;
+    }
+
+    /// Returns `true` if an [`AtlasLoopMode::Once`] animation has shown its last frame.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the index of the current frame in the layout.
+    pub fn current_index(&self) -> usize {
+        self.frames.start + self.frame
+    }
+
+    /// Moves to the next frame in `frame_count` frames, returning `false` if the animation is
+    /// finished.
+    fn advance(&mut self, frame_count: usize) -> bool {
+        let last = frame_count - 1;
+        match self.loop_mode {
+            AtlasLoopMode::Once if self.frame >= last => {
+                self.finished = true;
+                return false;
+            }
+            AtlasLoopMode::Once => self.frame += 1,
+            AtlasLoopMode::Loop => self.frame = (self.frame + 1) % frame_count,
+            AtlasLoopMode::PingPong if last == 0 => {}
+            AtlasLoopMode::PingPong => {
+                if self.frame == last {
+                    self.reversed = true;
+                } else if self.frame == 0 {
+                    self.reversed = false;
+                }
+                if self.reversed {
+                    self.frame -= 1;
+                } else {
+                    self.frame += 1;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// What a [`TextureAtlasAnimation`] does once it has shown its last frame.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum AtlasLoopMode {
+    /// Stops on the last frame.
+    Once,
+    /// Starts again from the first frame.
+    #[default]
+    Loop,
+    /// Plays the frames backwards to the first frame, then forwards again.
+    PingPong,
+}
+
+/// Sent when a [`TextureAtlasAnimation`] shows a frame, such as to play a footstep sound on the
+/// frame where a foot touches the ground.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimationFrameReached {
+    /// The entity of the animation.
+    pub entity: Entity,
+    /// The index of the frame in the layout of the animation.
+    pub index: usize,
+}
+
+/// Advances the [`TextureAtlasAnimation`]s, updating the [`TextureAtlas`] of their entities.
+pub fn animate_texture_atlases(
+    virtual_time: Res<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut animations: Query<(Entity, &mut TextureAtlasAnimation, &mut TextureAtlas)>,
+    mut frame_events: EventWriter<AnimationFrameReached>,
+) {
+    for (entity, mut animation, mut atlas) in &mut animations {
+        if atlas.layout != animation.layout {
+            atlas.layout = animation.layout.clone();
+        }
+        let Some(layout) = layouts.get(&animation.layout) else {
+            continue;
+        };
+        let end = animation.frames.end.min(layout.len());
+        if end < animation.frames.end {
+            warn_once!(
+                "The frames {:?} of a TextureAtlasAnimation are out of its layout of {} frames, and are clamped to it.",
+                animation.frames,
+                layout.len()
+            );
+        }
+        let frame_count = end.saturating_sub(animation.frames.start);
+        if frame_count == 0 {
+            continue;
+        }
+        if animation.frame >= frame_count {
+            animation.frame = frame_count - 1;
+        }
+
+        if !animation.paused && !animation.finished && animation.fps > 0.0 {
+            let time = if animation.real_time {
+                real_time.delta_seconds()
+            } else {
+                virtual_time.delta_seconds()
+            };
+            let frame_time = animation.fps.recip();
+            animation.elapsed += time;
+            // A long frame skips frames, but only sends the events of the frames of one loop
+            let mut advanced = 0;
+            while animation.elapsed >= frame_time {
+                animation.elapsed -= frame_time;
+                if !animation.advance(frame_count) {
+                    animation.elapsed = 0.0;
+                    break;
+                }
+                advanced += 1;
+                if advanced <= frame_count {
+                    frame_events.send(AnimationFrameReached {
+                        entity,
+                        index: animation.current_index(),
+                    });
+                }
+            }
+        }
+
+        let index = animation.current_index();
+        if atlas.index != index {
+            atlas.index = index;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_app::{App, Update};
+    use bevy_ecs::event::{Events, ManualEventReader};
+    use bevy_math::UVec2;
+
+    use super::*;
+
+    fn setup(animation: TextureAtlasAnimation) -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<Assets<TextureAtlasLayout>>()
+            .add_event::<AnimationFrameReached>()
+            .add_systems(Update, animate_texture_atlases);
+        let layout = TextureAtlasLayout::from_grid(UVec2::splat(8), 4, 1, None, None);
+        let layout = app
+            .world_mut()
+            .resource_mut::<Assets<TextureAtlasLayout>>()
+            .add(layout);
+        let entity = app
+            .world_mut()
+            .spawn((
+                TextureAtlas::from(layout.clone()),
+                TextureAtlasAnimation {
+                    layout,
+                    ..animation
+                },
+            ))
+            .id();
+        (app, entity)
+    }
+
+    // Updates `app` after advancing the virtual clock by `seconds`, returning the atlas index
+    fn step(app: &mut App, entity: Entity, seconds: f32) -> usize {
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .advance_by(Duration::from_secs_f32(seconds));
+        app.update();
+        app.world().get::<TextureAtlas>(entity).unwrap().index
+    }
+
+    #[test]
+    fn frames_are_clamped_to_the_layout() {
+        let (mut app, entity) = setup(TextureAtlasAnimation::new(Handle::default(), 1..6, 10.0));
+        let mut reader = ManualEventReader::<AnimationFrameReached>::default();
+        let mut indices = Vec::new();
+        let mut reached = Vec::new();
+        for _ in 0..4 {
+            indices.push(step(&mut app, entity, 0.11));
+            let events = app.world().resource::<Events<AnimationFrameReached>>();
+            reached.extend(reader.read(events).map(|event| event.index));
+        }
+        assert_eq!(indices, [2, 3, 1, 2]);
+        assert_eq!(reached, [2, 3, 1, 2]);
+    }
+
+    #[test]
+    fn loop_modes() {
+        let ping_pong = TextureAtlasAnimation::new(Handle::default(), 0..3, 10.0)
+            .with_loop_mode(AtlasLoopMode::PingPong);
+        let (mut app, entity) = setup(ping_pong);
+        let indices: Vec<_> = (0..5).map(|_| step(&mut app, entity, 0.11)).collect();
+        assert_eq!(indices, [1, 2, 1, 0, 1]);
+
+        let once = TextureAtlasAnimation::new(Handle::default(), 0..3, 10.0)
+            .with_loop_mode(AtlasLoopMode::Once);
+        let (mut app, entity) = setup(once);
+        assert_eq!(step(&mut app, entity, 0.25), 2);
+        assert_eq!(step(&mut app, entity, 0.1), 2);
+        assert!(app
+            .world()
+            .get::<TextureAtlasAnimation>(entity)
+            .unwrap()
+            .is_finished());
+    }
+
+    #[test]
+    fn paused_animations_keep_their_frame() {
+        let (mut app, entity) = setup(TextureAtlasAnimation::new(Handle::default(), 0..4, 10.0));
+        app.world_mut()
+            .get_mut::<TextureAtlasAnimation>(entity)
+            .unwrap()
+            .pause();
+        assert_eq!(step(&mut app, entity, 0.3), 0);
+
+        // Only the virtual clock advances, so animations on the real clock don't
+        let mut animation = app
+            .world_mut()
+            .get_mut::<TextureAtlasAnimation>(entity)
+            .unwrap();
+        animation.play();
+        animation.real_time = true;
+        assert_eq!(step(&mut app, entity, 0.3), 0);
+    }
+}
@@ -294,6 +294,7 @@ impl<'a> TextureAtlasBuilder<'a> {
                 size: atlas_texture.size(),
                 textures: texture_rects,
                 texture_handles: Some(texture_ids),
+                frames: HashMap::default(),
             },
             atlas_texture,
         ))
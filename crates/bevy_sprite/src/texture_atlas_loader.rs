@@ -0,0 +1,299 @@
+use std::fmt;
+
+use bevy_asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+use bevy_math::{URect, UVec2, Vec2};
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use thiserror::Error;
+
+use crate::{TextureAtlasFrame, TextureAtlasLayout};
+
+/// Loads a [`TextureAtlasLayout`] from the JSON data of a [TexturePacker](https://www.codeandweb.com/texturepacker)
+/// sprite sheet (`.atlas.json`), in either its hash or array format.
+///
+/// The textures of the layout are indexed in the order of the frames of the file. The trimming
+/// and pivot of each frame are kept in the [`frames`](TextureAtlasLayout::frames) of the layout,
+/// so that sprites are placed as if the untrimmed frame was drawn.
+///
+/// The image of the sheet is not loaded, and is loaded on its own.
+#[derive(Default)]
+pub struct TexturePackerAtlasLoader;
+
+/// Possible errors that can be produced by [`TexturePackerAtlasLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum TexturePackerAtlasLoaderError {
+    /// An [IO](std::io) Error
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The file isn't valid TexturePacker JSON data
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// A frame was rotated when the sheet was packed, which isn't supported
+    #[error("frame {0} of the sprite sheet is rotated, which isn't supported")]
+    RotatedFrame(usize),
+}
+
+impl AssetLoader for TexturePackerAtlasLoader {
+    type Asset = TextureAtlasLayout;
+    type Settings = ();
+    type Error = TexturePackerAtlasLoaderError;
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<TextureAtlasLayout, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        TextureAtlasLayout::from_texture_packer_json(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.json"]
+    }
+}
+
+impl TextureAtlasLayout {
+    /// Creates a [`TextureAtlasLayout`] from the JSON data of a TexturePacker sprite sheet.
+    ///
+    /// See [`TexturePackerAtlasLoader`] for how the frames of the sheet are read.
+    pub fn from_texture_packer_json(bytes: &[u8]) -> Result<Self, TexturePackerAtlasLoaderError> {
+        let sheet: TexturePackerSheet = serde_json::from_slice(bytes)?;
+        let frames = match sheet.frames {
+            TexturePackerFrames::Hash(FramesInOrder(frames)) => frames,
+            TexturePackerFrames::Array(frames) => frames,
+        };
+
+        let mut layout = TextureAtlasLayout::new_empty(sheet.meta.size.into());
+        for (index, frame) in frames.into_iter().enumerate() {
+            if frame.rotated {
+                return Err(TexturePackerAtlasLoaderError::RotatedFrame(index));
+            }
+            let min = UVec2::new(frame.frame.x, frame.frame.y);
+            let size = UVec2::new(frame.frame.w, frame.frame.h);
+            layout.add_texture(URect::from_corners(min, min + size));
+
+            let size = size.as_vec2();
+            let source_size = frame
+                .source_size
+                .map_or(size, |size| UVec2::from(size).as_vec2());
+            let source_min = frame
+                .sprite_source_size
+                .map_or(Vec2::ZERO, |rect| UVec2::new(rect.x, rect.y).as_vec2());
+            // The center of the texture in the frame, relative to the center of the frame with y
+            // up, moved so that the pivot is at the center of the frame
+            let center = source_min + 0.5 * size - 0.5 * source_size;
+            let pivot = frame.pivot.map_or(Vec2::ZERO, |pivot| {
+                (Vec2::new(pivot.x, pivot.y) - 0.5) * source_size
+            });
+            let offset = (center - pivot) * Vec2::new(1.0, -1.0);
+            if offset != Vec2::ZERO || source_size != size {
+                layout.set_frame(
+                    index,
+                    TextureAtlasFrame {
+                        source_size,
+                        offset,
+                    },
+                );
+            }
+        }
+        Ok(layout)
+    }
+}
+
+#[derive(Deserialize)]
+struct TexturePackerSheet {
+    frames: TexturePackerFrames,
+    meta: TexturePackerMeta,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TexturePackerFrames {
+    Hash(FramesInOrder),
+    Array(Vec<TexturePackerFrame>),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TexturePackerFrame {
+    frame: TexturePackerRect,
+    #[serde(default)]
+    rotated: bool,
+    sprite_source_size: Option<TexturePackerRect>,
+    source_size: Option<TexturePackerSize>,
+    pivot: Option<TexturePackerPivot>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+struct TexturePackerRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+struct TexturePackerSize {
+    w: u32,
+    h: u32,
+}
+
+impl From<TexturePackerSize> for UVec2 {
+    fn from(size: TexturePackerSize) -> Self {
+        UVec2::new(size.w, size.h)
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+struct TexturePackerPivot {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Deserialize)]
+struct TexturePackerMeta {
+    size: TexturePackerSize,
+}
+
+/// The frames of a sheet in the hash format, in the order of the file rather than of their names.
+struct FramesInOrder(Vec<TexturePackerFrame>);
+
+impl<'de> Deserialize<'de> for FramesInOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FramesVisitor;
+
+        impl<'de> Visitor<'de> for FramesVisitor {
+            type Value = FramesInOrder;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of frames by name")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some((_, frame)) = map.next_entry::<String, TexturePackerFrame>()? {
+                    frames.push(frame);
+                }
+                Ok(FramesInOrder(frames))
+            }
+        }
+
+        deserializer.deserialize_map(FramesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = r#"{
+        "frames": {
+            "run_2.png": {
+                "frame": {"x": 34, "y": 0, "w": 20, "h": 28},
+                "rotated": false,
+                "trimmed": true,
+                "spriteSourceSize": {"x": 8, "y": 2, "w": 20, "h": 28},
+                "sourceSize": {"w": 32, "h": 32},
+                "pivot": {"x": 0.5, "y": 0.5}
+            },
+            "run_1.png": {
+                "frame": {"x": 0, "y": 0, "w": 32, "h": 32},
+                "rotated": false,
+                "trimmed": false,
+                "spriteSourceSize": {"x": 0, "y": 0, "w": 32, "h": 32},
+                "sourceSize": {"w": 32, "h": 32},
+                "pivot": {"x": 0.5, "y": 1.0}
+            },
+            "run_3.png": {
+                "frame": {"x": 56, "y": 0, "w": 32, "h": 32},
+                "rotated": false,
+                "trimmed": false,
+                "spriteSourceSize": {"x": 0, "y": 0, "w": 32, "h": 32},
+                "sourceSize": {"w": 32, "h": 32},
+                "pivot": {"x": 0.5, "y": 0.5}
+            }
+        },
+        "meta": {
+            "image": "run.png",
+            "format": "RGBA8888",
+            "size": {"w": 128, "h": 32},
+            "scale": "1"
+        }
+    }"#;
+
+    #[test]
+    fn texture_packer_frames() {
+        let layout = TextureAtlasLayout::from_texture_packer_json(SHEET.as_bytes()).unwrap();
+        assert_eq!(layout.size, UVec2::new(128, 32));
+        // The frames are in the order of the file, not of their names
+        assert_eq!(
+            layout.textures,
+            [
+                URect::new(34, 0, 54, 28),
+                URect::new(0, 0, 32, 32),
+                URect::new(56, 0, 88, 32),
+            ]
+        );
+
+        // Trimmed 8 pixels from the left, 4 from the right and 2 from the top and the bottom
+        assert_eq!(
+            layout.frame(0),
+            Some(TextureAtlasFrame {
+                source_size: Vec2::splat(32.0),
+                offset: Vec2::new(2.0, 0.0),
+            })
+        );
+        // Untrimmed, with its pivot at the middle of its bottom edge
+        assert_eq!(
+            layout.frame(1),
+            Some(TextureAtlasFrame {
+                source_size: Vec2::splat(32.0),
+                offset: Vec2::new(0.0, 16.0),
+            })
+        );
+        assert_eq!(layout.frame(2), None);
+
+        // A sprite anchored at the bottom left of the untrimmed frame is anchored 18 pixels left
+        // of the center of the texture, and mirrored when flipped
+        let frame = layout.frame(0).unwrap();
+        let texture_size = Vec2::new(20.0, 28.0);
+        assert_eq!(
+            frame.texture_anchor(Vec2::splat(-0.5), texture_size, false, false),
+            Vec2::new(-18.0 / 20.0, -16.0 / 28.0)
+        );
+        assert_eq!(
+            frame.texture_anchor(Vec2::splat(-0.5), texture_size, true, false),
+            Vec2::new(-14.0 / 20.0, -16.0 / 28.0)
+        );
+    }
+
+    #[test]
+    fn texture_packer_array_and_rotated_frames() {
+        let array = r#"{
+            "frames": [
+                {"filename": "a", "frame": {"x": 0, "y": 0, "w": 8, "h": 8}},
+                {"filename": "b", "frame": {"x": 8, "y": 0, "w": 8, "h": 4}}
+            ],
+            "meta": {"size": {"w": 16, "h": 8}}
+        }"#;
+        let layout = TextureAtlasLayout::from_texture_packer_json(array.as_bytes()).unwrap();
+        assert_eq!(
+            layout.textures,
+            [URect::new(0, 0, 8, 8), URect::new(8, 0, 16, 4)]
+        );
+        assert!(layout.frames.is_empty());
+
+        let rotated = array.replace(
+            r#""filename": "b","#,
+            r#""filename": "b", "rotated": true,"#,
+        );
+        assert!(matches!(
+            TextureAtlasLayout::from_texture_packer_json(rotated.as_bytes()),
+            Err(TexturePackerAtlasLoaderError::RotatedFrame(1))
+        ));
+    }
+}
@@ -7,8 +7,8 @@ use bevy_ecs::{
 use bevy_utils::{tracing::warn, warn_once};
 
 use crate::state::{
-    setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
-    StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
+    setup_state_transitions_in_world, ComputedStates, ExitSchedules, FreelyMutableState, NextState,
+    State, StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
 };
 use crate::state_scoped::clear_state_scoped_entities;
 
@@ -55,6 +55,11 @@ pub trait AppExtStates {
 
     /// Enable state-scoped entity clearing for state `S`.
     ///
+    /// The entities are despawned after the [`OnExit`](crate::state::OnExit) schedule of the
+    /// exited state runs, so its systems can still query them, and before the
+    /// [`OnTransition`](crate::state::OnTransition) and [`OnEnter`](crate::state::OnEnter)
+    /// schedules run.
+    ///
     /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
 }
@@ -176,9 +181,12 @@ impl AppExtStates for SubApp {
         }
         // We work with [`StateTransition`] in set [`StateTransitionSteps::ExitSchedules`] as opposed to [`OnExit`],
         // because [`OnExit`] only runs for one specific variant of the state.
+        // Running after [`ExitSchedules`] lets the [`OnExit`] systems still query the entities.
         self.add_systems(
             StateTransition,
-            clear_state_scoped_entities::<S>.in_set(StateTransitionSteps::ExitSchedules),
+            clear_state_scoped_entities::<S>
+                .in_set(StateTransitionSteps::ExitSchedules)
+                .after(ExitSchedules::<S>::default()),
         )
     }
 }
@@ -226,10 +234,15 @@ mod tests {
     use crate::{
         self as bevy_state,
         app::StatesPlugin,
-        state::{State, StateTransition, StateTransitionEvent},
+        state::{ComputedStates, NextState, OnExit, State, StateTransition, StateTransitionEvent},
+        state_scoped::StateScoped,
     };
     use bevy_app::App;
-    use bevy_ecs::event::Events;
+    use bevy_ecs::{
+        event::Events,
+        query::With,
+        system::{Query, ResMut, Resource},
+    };
     use bevy_state_macros::States;
 
     use super::AppExtStates;
@@ -281,4 +294,105 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[cfg(feature = "bevy_hierarchy")]
+    #[test]
+    fn state_scoped_entities_are_despawned_recursively() {
+        use bevy_hierarchy::BuildWorldChildren;
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>();
+        app.enable_state_scoped_entities::<TestState>();
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+
+        let scoped = world.spawn(StateScoped(TestState::A)).id();
+        let child = world.spawn_empty().set_parent(scoped).id();
+        let other = world.spawn(StateScoped(TestState::B)).id();
+
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+
+        assert!(world.get_entity(scoped).is_none());
+        assert!(world.get_entity(child).is_none());
+        assert!(world.get_entity(other).is_some());
+    }
+
+    #[derive(States, Default, PartialEq, Eq, Hash, Debug, Clone)]
+    enum MenuState {
+        #[default]
+        Closed,
+        Open,
+    }
+
+    #[derive(PartialEq, Eq, Hash, Debug, Clone)]
+    struct InGameMenu;
+
+    impl ComputedStates for InGameMenu {
+        type SourceStates = (TestState, MenuState);
+
+        fn compute(sources: (TestState, MenuState)) -> Option<Self> {
+            match sources {
+                (TestState::B, MenuState::Open) => Some(InGameMenu),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct ScopedOnExit(usize);
+
+    #[test]
+    fn state_scoped_entities_are_despawned_after_on_exit() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>();
+        app.init_state::<MenuState>();
+        app.add_computed_state::<InGameMenu>();
+        app.enable_state_scoped_entities::<InGameMenu>();
+        app.init_resource::<ScopedOnExit>();
+        app.add_systems(
+            OnExit(InGameMenu),
+            |query: Query<(), With<StateScoped<InGameMenu>>>,
+             mut scoped_on_exit: ResMut<ScopedOnExit>| {
+                scoped_on_exit.0 = query.iter().count();
+            },
+        );
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        // Only read the transitions from now on, not the ones of the initial states
+        let mut reader = world
+            .resource::<Events<StateTransitionEvent<InGameMenu>>>()
+            .get_reader_current();
+
+        // Both sources change in the same frame, which computes a single transition
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world
+            .resource_mut::<NextState<MenuState>>()
+            .set(MenuState::Open);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<InGameMenu>>().0, InGameMenu);
+        let events = world.resource::<Events<StateTransitionEvent<InGameMenu>>>();
+        let transitions: Vec<_> = reader.read(events).collect();
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].exited, None);
+        assert_eq!(transitions[0].entered, Some(InGameMenu));
+
+        let scoped = world.spawn(StateScoped(InGameMenu)).id();
+
+        world
+            .resource_mut::<NextState<MenuState>>()
+            .set(MenuState::Closed);
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<InGameMenu>>());
+        assert_eq!(world.resource::<ScopedOnExit>().0, 1);
+        assert!(world.get_entity(scoped).is_none());
+    }
 }
@@ -17,6 +17,9 @@ use crate::state::{StateTransitionEvent, States};
 ///
 /// If `bevy_hierarchy` feature is enabled, which it is by default, the despawn will be recursive.
 ///
+/// The entities are despawned right after the [`OnExit`](crate::state::OnExit) schedule of the
+/// exited state runs, so its systems can still query them.
+///
 /// ```
 /// use bevy_state::prelude::*;
 /// use bevy_ecs::prelude::*;
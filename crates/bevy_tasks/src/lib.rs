@@ -11,6 +11,12 @@ pub use slice::{ParallelSlice, ParallelSliceMut};
 mod task;
 pub use task::Task;
 
+mod task_priority;
+pub use task_priority::TaskPriority;
+
+mod task_pool_stats;
+pub use task_pool_stats::TaskPoolStats;
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
 mod task_pool;
 #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 use std::{cell::RefCell, future::Future, marker::PhantomData, mem, rc::Rc};
 
+use crate::{TaskPoolStats, TaskPriority};
+
 thread_local! {
     static LOCAL_EXECUTOR: async_executor::LocalExecutor<'static> = const { async_executor::LocalExecutor::new() };
 }
@@ -44,6 +46,11 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    pub fn background_threads(self, _background_threads: usize) -> Self {
+        self
+    }
+
     /// Creates a new [`TaskPool`]
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal()
@@ -53,7 +60,9 @@ impl TaskPoolBuilder {
 /// A thread pool for executing tasks. Tasks are futures that are being automatically driven by
 /// the pool on threads owned by the pool. In this case - main thread only.
 #[derive(Debug, Default, Clone)]
-pub struct TaskPool {}
+pub struct TaskPool {
+    stats: Arc<TaskPoolStats>,
+}
 
 impl TaskPool {
     /// Just create a new `ThreadExecutor` for wasm
@@ -68,7 +77,7 @@ impl TaskPool {
 
     #[allow(unused_variables)]
     fn new_internal() -> Self {
-        Self {}
+        Self::default()
     }
 
     /// Return the number of threads owned by the task pool
@@ -76,6 +85,18 @@ impl TaskPool {
         1
     }
 
+    /// Return the number of threads running only the background tasks, always 0 on the single
+    /// threaded task pool
+    pub fn background_thread_num(&self) -> usize {
+        0
+    }
+
+    /// Returns the statistics of the tasks spawned with `priority`, which are never recorded on
+    /// the single threaded task pool
+    pub fn stats(&self, _priority: TaskPriority) -> &TaskPoolStats {
+        &self.stats
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -171,6 +192,19 @@ impl TaskPool {
         FakeTask
     }
 
+    /// Spawns a static future onto the thread pool. This is exactly the same as
+    /// [`TaskPool::spawn`], as the single threaded task pool has no background threads.
+    pub fn spawn_with_priority<T>(
+        &self,
+        _priority: TaskPriority,
+        future: impl Future<Output = T> + 'static,
+    ) -> FakeTask
+    where
+        T: 'static,
+    {
+        self.spawn(future)
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskPool::spawn`].
     pub fn spawn_local<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask
     where
@@ -3,8 +3,9 @@ use std::{
     marker::PhantomData,
     mem,
     panic::AssertUnwindSafe,
-    sync::Arc,
+    sync::{atomic::Ordering, Arc},
     thread::{self, JoinHandle},
+    time::Instant,
 };
 
 use async_executor::FallibleTask;
@@ -14,7 +15,7 @@ use futures_lite::FutureExt;
 use crate::{
     block_on,
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
-    Task,
+    Task, TaskPoolStats, TaskPriority,
 };
 
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
@@ -39,6 +40,9 @@ pub struct TaskPoolBuilder {
     /// Allows customizing the name of the threads - helpful for debugging. If set, threads will
     /// be named `<thread_name> (<thread_index>)`, i.e. `"MyThreadPool (2)"`.
     thread_name: Option<String>,
+    /// The number of threads, out of `num_threads`, running only the [`TaskPriority::Background`]
+    /// tasks.
+    background_threads: usize,
 
     on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
@@ -70,6 +74,17 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Reserves `background_threads` of the threads of the pool to the tasks spawned with
+    /// [`TaskPriority::Background`], which don't run on the other threads. Defaults to 0, running
+    /// the background tasks like the normal ones.
+    ///
+    /// At least one thread is always left to the normal tasks. Background threads are named
+    /// `<thread_name> Background (<thread_index>)`.
+    pub fn background_threads(mut self, background_threads: usize) -> Self {
+        self.background_threads = background_threads;
+        self
+    }
+
     /// Sets a callback that is invoked once for every created thread as it starts.
     ///
     /// This is called on the thread itself and has access to all thread-local storage.
@@ -108,6 +123,11 @@ impl TaskPoolBuilder {
 pub struct TaskPool {
     /// The executor for the pool.
     executor: Arc<async_executor::Executor<'static>>,
+    /// The executor for the [`TaskPriority::Background`] tasks, if the pool has background threads.
+    background_executor: Option<Arc<async_executor::Executor<'static>>>,
+    background_thread_num: usize,
+    normal_stats: Arc<TaskPoolStats>,
+    background_stats: Arc<TaskPoolStats>,
 
     // The inner state of the pool.
     threads: Vec<JoinHandle<()>>,
@@ -139,16 +159,26 @@ impl TaskPool {
             .num_threads
             .unwrap_or_else(crate::available_parallelism);
 
-        let threads = (0..num_threads)
-            .map(|i| {
-                let ex = Arc::clone(&executor);
+        let background_thread_num = builder
+            .background_threads
+            .min(num_threads.saturating_sub(1));
+        let background_executor =
+            (background_thread_num > 0).then(|| Arc::new(async_executor::Executor::new()));
+
+        let pool_name = builder.thread_name.as_deref().unwrap_or("TaskPool");
+        let normal_threads = (0..num_threads - background_thread_num)
+            .map(|i| (&executor, format!("{pool_name} ({i})")));
+        let background_threads = background_executor.iter().flat_map(|background_executor| {
+            (0..background_thread_num)
+                .map(move |i| (background_executor, format!("{pool_name} Background ({i})")))
+        });
+
+        let threads = normal_threads
+            .chain(background_threads)
+            .map(|(executor, thread_name)| {
+                let ex = Arc::clone(executor);
                 let shutdown_rx = shutdown_rx.clone();
 
-                let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
-                    format!("{thread_name} ({i})")
-                } else {
-                    format!("TaskPool ({i})")
-                };
                 let mut thread_builder = thread::Builder::new().name(thread_name);
 
                 if let Some(stack_size) = builder.stack_size {
@@ -189,16 +219,39 @@ impl TaskPool {
 
         Self {
             executor,
+            background_executor,
+            background_thread_num,
+            normal_stats: Arc::default(),
+            background_stats: Arc::default(),
             threads,
             shutdown_tx,
         }
     }
 
-    /// Return the number of threads owned by the task pool
+    /// Return the number of threads owned by the task pool, including its background threads
     pub fn thread_num(&self) -> usize {
         self.threads.len()
     }
 
+    /// Return the number of threads running only the [`TaskPriority::Background`] tasks
+    pub fn background_thread_num(&self) -> usize {
+        self.background_thread_num
+    }
+
+    /// Returns the statistics of the tasks spawned with `priority`.
+    ///
+    /// The statistics must be enabled with [`TaskPoolStats::enable`] to be recorded.
+    pub fn stats(&self, priority: TaskPriority) -> &TaskPoolStats {
+        self.stats_arc(priority)
+    }
+
+    fn stats_arc(&self, priority: TaskPriority) -> &Arc<TaskPoolStats> {
+        match priority {
+            TaskPriority::Normal => &self.normal_stats,
+            TaskPriority::Background => &self.background_stats,
+        }
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -533,7 +586,49 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        self.spawn_with_priority(TaskPriority::Normal, future)
+    }
+
+    /// Spawns a static future onto the thread pool, like [`TaskPool::spawn`], on the lane of
+    /// `priority`.
+    ///
+    /// [`TaskPriority::Background`] tasks only run on the background threads of the pool, set
+    /// with [`TaskPoolBuilder::background_threads`], so that long-running work leaves the other
+    /// threads to the normal tasks. They run like normal tasks if the pool has no background
+    /// threads.
+    ///
+    /// ```
+    /// use bevy_tasks::{TaskPoolBuilder, TaskPriority};
+    ///
+    /// let pool = TaskPoolBuilder::new()
+    ///     .num_threads(4)
+    ///     .background_threads(1)
+    ///     .build();
+    /// let task = pool.spawn_with_priority(TaskPriority::Background, async {
+    ///     // Expensive, non-urgent computation
+    ///     1 + 1
+    /// });
+    /// assert_eq!(bevy_tasks::block_on(task), 2);
+    /// ```
+    pub fn spawn_with_priority<T>(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let executor = match (priority, &self.background_executor) {
+            (TaskPriority::Background, Some(background_executor)) => background_executor,
+            _ => &self.executor,
+        };
+        let queued = QueuedTask::new(self.stats_arc(priority));
+        Task::new(executor.spawn(async move {
+            if let Some(queued) = queued {
+                queued.start();
+            }
+            future.await
+        }))
     }
 
     /// Spawns a static future on the thread-local async executor for the
@@ -593,6 +688,39 @@ impl Drop for TaskPool {
     }
 }
 
+/// Counts a task as queued in its [`TaskPoolStats`] until it is first polled, or dropped.
+struct QueuedTask {
+    stats: Arc<TaskPoolStats>,
+    spawned_at: Instant,
+}
+
+impl QueuedTask {
+    fn new(stats: &Arc<TaskPoolStats>) -> Option<Self> {
+        if !stats.is_enabled() {
+            return None;
+        }
+        stats.queued.fetch_add(1, Ordering::Relaxed);
+        Some(Self {
+            stats: Arc::clone(stats),
+            spawned_at: Instant::now(),
+        })
+    }
+
+    fn start(self) {
+        let latency = self.spawned_at.elapsed().as_nanos() as u64;
+        self.stats.started.fetch_add(1, Ordering::Relaxed);
+        self.stats
+            .latency_nanos
+            .fetch_add(latency, Ordering::Relaxed);
+    }
+}
+
+impl Drop for QueuedTask {
+    fn drop(&mut self) {
+        self.stats.queued.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 /// A [`TaskPool`] scope for running one or more non-`'static` futures.
 ///
 /// For more information, see [`TaskPool::scope`].
@@ -922,6 +1050,66 @@ mod tests {
         assert_eq!(count.load(Ordering::Acquire), 200);
     }
 
+    fn spawned_thread_name(pool: &TaskPool, priority: TaskPriority) -> String {
+        block_on(pool.spawn_with_priority(priority, async {
+            thread::current().name().unwrap().to_string()
+        }))
+    }
+
+    #[test]
+    fn background_tasks_run_on_background_threads() {
+        let pool = TaskPoolBuilder::new()
+            .num_threads(3)
+            .background_threads(1)
+            .thread_name("Pool".to_string())
+            .build();
+        assert_eq!(pool.thread_num(), 3);
+        assert_eq!(pool.background_thread_num(), 1);
+        for _ in 0..10 {
+            assert_eq!(
+                spawned_thread_name(&pool, TaskPriority::Background),
+                "Pool Background (0)"
+            );
+            assert!(!spawned_thread_name(&pool, TaskPriority::Normal).contains("Background"));
+        }
+    }
+
+    #[test]
+    fn background_tasks_fall_back_to_normal_threads() {
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .background_threads(1)
+            .thread_name("Pool".to_string())
+            .build();
+        assert_eq!(pool.background_thread_num(), 0);
+        assert_eq!(
+            spawned_thread_name(&pool, TaskPriority::Background),
+            "Pool (0)"
+        );
+    }
+
+    #[test]
+    fn stats_count_queued_tasks() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let stats = pool.stats(TaskPriority::Normal);
+        stats.enable();
+
+        let (tx, rx) = std::sync::mpsc::channel::<()>();
+        let blocker = pool.spawn(async move { rx.recv().unwrap() });
+        // The single thread of the pool is blocked, so this task stays queued
+        let queued = pool.spawn(async {});
+        while stats.queued() != 1 {
+            thread::yield_now();
+        }
+
+        tx.send(()).unwrap();
+        block_on(blocker);
+        block_on(queued);
+        assert_eq!(stats.queued(), 0);
+        assert!(stats.take_mean_latency().is_some());
+        assert_eq!(stats.take_mean_latency(), None);
+    }
+
     // This test will often freeze on other executors.
     #[test]
     fn test_nested_scopes() {
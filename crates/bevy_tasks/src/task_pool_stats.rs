@@ -0,0 +1,43 @@
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Statistics of the tasks spawned on one [`TaskPriority`](crate::TaskPriority) lane of a
+/// [`TaskPool`](crate::TaskPool), returned by [`TaskPool::stats`](crate::TaskPool::stats).
+///
+/// Nothing is recorded until [`TaskPoolStats::enable`] is called, so that spawning tasks costs
+/// nothing more when the statistics aren't used. The single threaded task pool, used on wasm,
+/// never records any statistics.
+#[derive(Debug, Default)]
+pub struct TaskPoolStats {
+    pub(crate) enabled: AtomicBool,
+    pub(crate) queued: AtomicUsize,
+    pub(crate) started: AtomicU64,
+    pub(crate) latency_nanos: AtomicU64,
+}
+
+impl TaskPoolStats {
+    /// Starts recording the statistics of the tasks spawned from now on.
+    pub fn enable(&self) {
+        self.enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the statistics are being recorded.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of tasks waiting to be polled for the first time.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    /// Returns the mean time between the spawn of a task and its first poll, over the tasks
+    /// started since the last call, or `None` if no task started since then.
+    pub fn take_mean_latency(&self) -> Option<Duration> {
+        let started = self.started.swap(0, Ordering::Relaxed);
+        let latency_nanos = self.latency_nanos.swap(0, Ordering::Relaxed);
+        (started > 0).then(|| Duration::from_nanos(latency_nanos / started))
+    }
+}
@@ -0,0 +1,15 @@
+/// The lane a task is spawned on with
+/// [`TaskPool::spawn_with_priority`](crate::TaskPool::spawn_with_priority).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TaskPriority {
+    /// Runs on every thread of the pool, like the tasks spawned with
+    /// [`TaskPool::spawn`](crate::TaskPool::spawn).
+    #[default]
+    Normal,
+    /// Runs only on the background threads of the pool, set with
+    /// [`TaskPoolBuilder::background_threads`](crate::TaskPoolBuilder::background_threads), so
+    /// that long-running tasks don't compete with the normal ones for every core.
+    ///
+    /// Runs like a [`TaskPriority::Normal`] task if the pool has no background threads.
+    Background,
+}
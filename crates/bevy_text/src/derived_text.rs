@@ -0,0 +1,176 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    prelude::Component,
+    query::With,
+    system::{Local, QueryState, Resource},
+    world::{Ref, World},
+};
+
+use crate::{DetectTextChanges, Text};
+
+/// Derives the value of sections of a [`Text`] from the [`World`], such as a resource, without
+/// writing a system to update it.
+///
+/// The value is derived by [`update_derived_texts`] when the component is added, and then when
+/// one of the resources declared with [`DerivedText::reads`] changes. A [`DerivedText`] that
+/// declares no resource is only derived once.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_text::{DerivedText, Text};
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// fn spawn_score(mut commands: Commands) {
+///     commands.spawn((
+///         Text::from_section("", Default::default()),
+///         DerivedText::new(|world| format!("Score: {}", world.resource::<Score>().0))
+///             .reads::<Score>(),
+///     ));
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_score);
+/// ```
+#[derive(Component)]
+pub struct DerivedText {
+    derive: Box<dyn Fn(&World) -> String + Send + Sync>,
+    dependencies: Vec<fn(&World) -> bool>,
+    sections: Vec<usize>,
+}
+
+impl DerivedText {
+    /// Creates a [`DerivedText`] setting the first section of the [`Text`] to the value returned
+    /// by `derive`.
+    pub fn new(derive: impl Fn(&World) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            derive: Box::new(derive),
+            dependencies: Vec::new(),
+            sections: vec![0],
+        }
+    }
+
+    /// Derives the text again when the resource `R` changes.
+    ///
+    /// This should be called for every resource read by the closure, since it isn't run
+    /// otherwise.
+    pub fn reads<R: Resource>(mut self) -> Self {
+        self.dependencies.push(World::is_resource_changed::<R>);
+        self
+    }
+
+    /// Sets the derived value to the sections at these indices, rather than the first one.
+    ///
+    /// Indices past the sections of the [`Text`] are ignored.
+    pub fn in_sections(mut self, sections: impl IntoIterator<Item = usize>) -> Self {
+        self.sections = sections.into_iter().collect();
+        self
+    }
+}
+
+/// Derives the value of the [`Text`] of each [`DerivedText`] that was added, or which reads a
+/// resource that changed since this system last ran.
+///
+/// The [`Text`] is only marked as changed when its value does change.
+pub fn update_derived_texts(
+    world: &mut World,
+    mut query: Local<QueryState<(Entity, Ref<DerivedText>), With<Text>>>,
+    mut derived: Local<Vec<(Entity, String)>>,
+) {
+    // As an exclusive system, the resources are checked for changes since this system last ran
+    for (entity, derived_text) in query.iter(world) {
+        let dependency_changed = derived_text
+            .dependencies
+            .iter()
+            .any(|is_changed| is_changed(world));
+        if derived_text.is_added() || dependency_changed {
+            derived.push((entity, (derived_text.derive)(world)));
+        }
+    }
+
+    for (entity, value) in derived.drain(..) {
+        let Some(sections) = world
+            .get::<DerivedText>(entity)
+            .map(|derived_text| derived_text.sections.clone())
+        else {
+            continue;
+        };
+        let Some(mut text) = world.get_mut::<Text>(entity) else {
+            continue;
+        };
+        for index in sections {
+            if index < text.sections.len() {
+                text.set_section_if_changed(index, &value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{change_detection::DetectChangesMut, schedule::Schedule};
+
+    use super::*;
+    use crate::{TextSection, TextStyle};
+
+    #[derive(Resource)]
+    struct Counter(u32);
+
+    #[derive(Resource)]
+    struct Unrelated;
+
+    #[test]
+    fn derived_text_follows_its_dependencies() {
+        let mut world = World::new();
+        world.insert_resource(Counter(1));
+        world.insert_resource(Unrelated);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_derived_texts);
+
+        let entity = world
+            .spawn((
+                Text::from_sections([
+                    TextSection::new("count: ", TextStyle::default()),
+                    TextSection::default(),
+                    TextSection::default(),
+                ]),
+                DerivedText::new(|world| world.resource::<Counter>().0.to_string())
+                    .reads::<Counter>()
+                    .in_sections([1, 2, 7]),
+            ))
+            .id();
+        let sections = |world: &World| -> Vec<String> {
+            world.get::<Text>(entity).unwrap().sections[1..]
+                .iter()
+                .map(|section| section.value.clone())
+                .collect()
+        };
+
+        // Derived when added
+        schedule.run(&mut world);
+        assert_eq!(sections(&world), ["1", "1"]);
+        assert_eq!(
+            world.get::<Text>(entity).unwrap().sections[0].value,
+            "count: "
+        );
+
+        // Not derived again when an unrelated resource changes
+        world.resource_mut::<Counter>().bypass_change_detection().0 = 2;
+        world.resource_mut::<Unrelated>().set_changed();
+        world.clear_trackers();
+        schedule.run(&mut world);
+        assert_eq!(sections(&world), ["1", "1"]);
+
+        // Derived again when a dependency changes
+        world.resource_mut::<Counter>().0 = 3;
+        world.clear_trackers();
+        schedule.run(&mut world);
+        assert_eq!(sections(&world), ["3", "3"]);
+
+        // The text isn't marked as changed when the derived value is the same
+        world.resource_mut::<Counter>().set_changed();
+        world.clear_trackers();
+        schedule.run(&mut world);
+        let text = world.entity(entity).get_ref::<Text>().unwrap();
+        assert!(!text.is_changed());
+    }
+}
@@ -1,10 +1,160 @@
 use ab_glyph::GlyphId;
+use bevy_asset::Assets;
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Component, With},
+    reflect::ReflectComponent,
+    system::Commands,
+};
+use bevy_reflect::Reflect;
 use thiserror::Error;
 
+use crate::{DefaultFont, Font, Text, TextLayoutInfo, TextSection};
+
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum TextError {
     #[error("font not found")]
     NoSuchFont,
     #[error("failed to add glyph to newly-created atlas {0:?}")]
     FailedToAddGlyph(GlyphId),
+    #[error("inline image not found")]
+    NoSuchImage,
+}
+
+/// Describes why a [`Text`] entity didn't render, or only partially rendered.
+///
+/// Inserted by the text layout systems, and removed once the text is laid out again without error,
+/// such as when its font finishes loading. Find the failed text entities with [`FailedText`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub enum TextRenderError {
+    /// The font of a section isn't loaded yet, or failed to load.
+    ///
+    /// The text is laid out again once the font is loaded.
+    FontNotLoaded,
+    /// The [`InlineImage`](crate::InlineImage) of a section isn't loaded yet, or failed to load.
+    ///
+    /// The text is laid out again once the image is loaded.
+    ImageNotLoaded,
+    /// The font of a section has no glyph for this visible character,
+    /// which is displayed with the fallback glyph of the font instead.
+    GlyphMissing(char),
+    /// A glyph didn't fit in a newly-created font atlas, so the text wasn't laid out.
+    AtlasFull,
+}
+
+impl TextRenderError {
+    /// Returns the error of the layout of `sections`, or `None` if it succeeded.
+    pub fn from_layout(
+        result: &Result<TextLayoutInfo, TextError>,
+        fonts: &Assets<Font>,
+        default_font: &DefaultFont,
+        sections: &[TextSection],
+    ) -> Option<Self> {
+        match result {
+            Err(TextError::NoSuchFont) => Some(Self::FontNotLoaded),
+            Err(TextError::FailedToAddGlyph(_)) => Some(Self::AtlasFull),
+            Err(TextError::NoSuchImage) => Some(Self::ImageNotLoaded),
+            // The placeholder of an inline image isn't displayed, so its glyph may be missing
+            Ok(_) => sections
+                .iter()
+                .filter(|section| section.image.is_none())
+                .find_map(|section| {
+                    let font = fonts.get(default_font.resolve(&section.style.font))?;
+                    section
+                        .displayed_value()
+                        .chars()
+                        .filter(|c| !c.is_whitespace() && !c.is_control())
+                        .find(|&c| ab_glyph::Font::glyph_id(&font.font, c).0 == 0)
+                        .map(Self::GlyphMissing)
+                }),
+        }
+    }
+
+    /// Inserts `error` on `entity` if it differs from its `current` error,
+    /// or removes the current error if `error` is `None`.
+    pub fn update(
+        commands: &mut Commands,
+        entity: Entity,
+        current: Option<&Self>,
+        error: Option<Self>,
+    ) {
+        match error {
+            Some(error) if current != Some(&error) => {
+                commands.entity(entity).insert(error);
+            }
+            None if current.is_some() => {
+                commands.entity(entity).remove::<Self>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A [`QueryFilter`](bevy_ecs::query::QueryFilter) matching the [`Text`] entities that failed to render,
+/// with a [`TextRenderError`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_text::{FailedText, TextRenderError};
+/// fn report_failed_text(query: Query<(Entity, &TextRenderError), FailedText>) {
+///     for (entity, error) in &query {
+///         println!("{entity:?} failed to render: {error:?}");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(report_failed_text);
+/// ```
+pub type FailedText = (With<Text>, With<TextRenderError>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InlineImage, TextStyle};
+    use bevy_asset::{AssetId, Handle};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn render_error_from_layout() {
+        let mut fonts = Assets::<Font>::default();
+        fonts.insert(
+            AssetId::default(),
+            Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap(),
+        );
+        let default_font = DefaultFont::default();
+        let ok = Ok(TextLayoutInfo::default());
+
+        let sections = [TextSection::new("Hello, world!\n", TextStyle::default())];
+        assert_eq!(
+            TextRenderError::from_layout(&ok, &fonts, &default_font, &sections),
+            None
+        );
+
+        // The subset of FiraMono has no CJK glyphs
+        let sections = [
+            TextSection::new("Hello", TextStyle::default()),
+            TextSection::new(" 世界", TextStyle::default()),
+        ];
+        assert_eq!(
+            TextRenderError::from_layout(&ok, &fonts, &default_font, &sections),
+            Some(TextRenderError::GlyphMissing('世'))
+        );
+
+        // The placeholder of an inline image has no glyph, but isn't displayed
+        let image = InlineImage::new(Handle::default(), Vec2::splat(24.0));
+        let image_sections = [TextSection::inline_image(image, TextStyle::default())];
+        assert_eq!(
+            TextRenderError::from_layout(&ok, &fonts, &default_font, &image_sections),
+            None
+        );
+
+        assert_eq!(
+            TextRenderError::from_layout(
+                &Err(TextError::NoSuchFont),
+                &fonts,
+                &default_font,
+                &sections
+            ),
+            Some(TextRenderError::FontNotLoaded)
+        );
+    }
 }
@@ -1,22 +1,244 @@
-use ab_glyph::{FontArc, FontVec, InvalidFont, OutlinedGlyph};
-use bevy_asset::Asset;
-use bevy_reflect::TypePath;
+use ab_glyph::{Font as _, FontArc, FontVec, InvalidFont, OutlinedGlyph, ScaleFont as _};
+use bevy_asset::{Asset, AssetId, Handle};
+use bevy_color::{ColorToPacked, LinearRgba, Srgba};
+use bevy_ecs::{reflect::ReflectResource, system::Resource};
+use bevy_math::Rect;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath};
 use bevy_render::{
     render_asset::RenderAssetUsages,
     render_resource::{Extent3d, TextureDimension, TextureFormat},
     texture::Image,
 };
 
+/// A font asset, loaded from a TrueType or OpenType file.
+///
+/// Create a [`Font`] with [`Font::try_from_bytes`], which reads the metrics of the font from its
+/// tables, or with [`Font::from_font_arc`] for an already parsed [`FontArc`]: the font can't be
+/// created with a struct literal.
 #[derive(Asset, TypePath, Debug, Clone)]
 pub struct Font {
     pub font: FontArc,
+    tables: FontTables,
+}
+
+/// The metrics of a [`Font`] which aren't exposed by [`ab_glyph`], read from its tables once
+/// when it is loaded. In font units.
+#[derive(Debug, Clone, Copy, Default)]
+struct FontTables {
+    cap_height: Option<f32>,
+    x_height: Option<f32>,
+    underline: Option<(f32, f32)>,
+}
+
+impl FontTables {
+    fn parse(font_data: &[u8]) -> Self {
+        let Ok(face) = ttf_parser::Face::parse(font_data, 0) else {
+            return Self::default();
+        };
+        Self {
+            cap_height: face.capital_height().map(f32::from),
+            x_height: face.x_height().map(f32::from),
+            underline: face
+                .underline_metrics()
+                .map(|line| (f32::from(line.position), f32::from(line.thickness))),
+        }
+    }
+}
+
+/// The metrics of a [`Font`] at a font size, returned by [`Font::metrics`].
+///
+/// The values are in pixels, scaled the same way as when laying out text with this font size.
+/// Vertical values are relative to the baseline, positive upwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    /// The height of the font above the baseline.
+    pub ascent: f32,
+    /// The depth of the font below the baseline, which is usually negative.
+    pub descent: f32,
+    /// The extra gap between the descent of a line and the ascent of the next one.
+    pub line_gap: f32,
+    /// The height of flat capital letters, such as `H`, if the font specifies it.
+    pub cap_height: Option<f32>,
+    /// The height of flat lowercase letters, such as `x`, if the font specifies it.
+    pub x_height: Option<f32>,
+    /// The position of the top of the underline, which is usually negative, if the font
+    /// specifies it.
+    pub underline_position: Option<f32>,
+    /// The thickness of the underline, if the font specifies it.
+    pub underline_thickness: Option<f32>,
+}
+
+impl FontMetrics {
+    /// The distance between the baselines of two consecutive lines.
+    pub fn line_height(&self) -> f32 {
+        self.ascent - self.descent + self.line_gap
+    }
+}
+
+/// The metrics of a glyph of a [`Font`] at a font size, returned by [`Font::glyph_metrics`].
+///
+/// The values are in pixels, scaled the same way as when laying out text with this font size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphMetrics {
+    /// The horizontal distance from the origin of the glyph to the origin of the next one,
+    /// before kerning.
+    pub advance: f32,
+    /// The horizontal distance from the origin of the glyph to the left of its outline.
+    pub left_side_bearing: f32,
+    /// The bounds of the outline of the glyph, relative to its origin on the baseline, with
+    /// `y` pointing up.
+    ///
+    /// `None` for glyphs without an outline, such as a space.
+    pub bounds: Option<Rect>,
 }
 
 impl Font {
     pub fn try_from_bytes(font_data: Vec<u8>) -> Result<Self, InvalidFont> {
+        let tables = FontTables::parse(&font_data);
         let font = FontVec::try_from_vec(font_data)?;
         let font = FontArc::new(font);
-        Ok(Font { font })
+        Ok(Font { font, tables })
+    }
+
+    /// Creates a [`Font`] from an already parsed [`FontArc`].
+    ///
+    /// The data of the font isn't available from a [`FontArc`], so the metrics read from its
+    /// tables, such as [`FontMetrics::cap_height`], are `None`. Prefer [`Font::try_from_bytes`]
+    /// when the data of the font is available.
+    pub fn from_font_arc(font: FontArc) -> Self {
+        Font {
+            font,
+            tables: FontTables::default(),
+        }
+    }
+
+    /// Returns the metrics of this font at `font_size`.
+    pub fn metrics(&self, font_size: f32) -> FontMetrics {
+        let scaled = self.font.as_scaled(font_size);
+        let scale = scaled.v_scale_factor();
+        FontMetrics {
+            ascent: scaled.ascent(),
+            descent: scaled.descent(),
+            line_gap: scaled.line_gap(),
+            cap_height: self.tables.cap_height.map(|height| height * scale),
+            x_height: self.tables.x_height.map(|height| height * scale),
+            underline_position: self.tables.underline.map(|(position, _)| position * scale),
+            underline_thickness: self
+                .tables
+                .underline
+                .map(|(_, thickness)| thickness * scale),
+        }
+    }
+
+    /// Returns the metrics of the glyph of `character` at `font_size`, or `None` if this font
+    /// has no glyph for it.
+    pub fn glyph_metrics(&self, character: char, font_size: f32) -> Option<GlyphMetrics> {
+        let glyph_id = self.font.glyph_id(character);
+        // The glyph 0 is the one displayed for missing characters
+        if glyph_id.0 == 0 {
+            return None;
+        }
+        let scaled = self.font.as_scaled(font_size);
+        let (h_scale, v_scale) = (scaled.h_scale_factor(), scaled.v_scale_factor());
+        Some(GlyphMetrics {
+            advance: scaled.h_advance(glyph_id),
+            left_side_bearing: scaled.h_side_bearing(glyph_id),
+            bounds: self.font.outline(glyph_id).map(|outline| {
+                Rect::new(
+                    outline.bounds.min.x * h_scale,
+                    outline.bounds.min.y * v_scale,
+                    outline.bounds.max.x * h_scale,
+                    outline.bounds.max.y * v_scale,
+                )
+            }),
+        })
+    }
+
+    /// Rasterizes `outlined_glyph`, an outline of a glyph of this font, with `antialiasing`.
+    ///
+    /// Like the one of [`Font::get_outlined_glyph_texture`], the returned texture has a pixel
+    /// wide transparent border along its edges.
+    pub fn rasterize_glyph(
+        &self,
+        outlined_glyph: OutlinedGlyph,
+        antialiasing: GlyphAntialiasing,
+    ) -> Image {
+        match antialiasing {
+            GlyphAntialiasing::None => {
+                let mut texture = Self::get_outlined_glyph_texture(outlined_glyph);
+                for pixel in texture.data.chunks_exact_mut(4) {
+                    pixel[3] = if pixel[3] >= 128 { 255 } else { 0 };
+                }
+                texture
+            }
+            GlyphAntialiasing::Grayscale => Self::get_outlined_glyph_texture(outlined_glyph),
+            GlyphAntialiasing::SubpixelRgb => self.get_subpixel_glyph_texture(&outlined_glyph),
+        }
+    }
+
+    /// Rasterizes the coverage of the red, green and blue subpixels of each pixel by the glyph
+    /// into the color channels of the texture, and the coverage of the most covered one into its
+    /// alpha.
+    fn get_subpixel_glyph_texture(&self, outlined_glyph: &OutlinedGlyph) -> Image {
+        // Filters the coverage of each subpixel with its neighbors, which reduces the color
+        // fringes at the edges of the glyph
+        const FILTER: [f32; 5] = [1. / 9., 2. / 9., 3. / 9., 2. / 9., 1. / 9.];
+
+        let bounds = outlined_glyph.px_bounds();
+        let width = bounds.width() as usize + 2;
+        let height = bounds.height() as usize + 2;
+        let subpixel_width = 3 * width;
+        let mut coverage = vec![0.0; subpixel_width * height];
+
+        // Outline the glyph again, three times wider, so that each pixel spans three subpixels
+        let mut glyph = outlined_glyph.glyph().clone();
+        glyph.scale.x *= 3.;
+        glyph.position.x *= 3.;
+        if let Some(subpixel_glyph) = self.font.outline_glyph(glyph) {
+            let subpixel_bounds = subpixel_glyph.px_bounds();
+            // Where the subpixel outline starts in the texture, past its transparent border
+            let x_offset = (subpixel_bounds.min.x - 3. * (bounds.min.x - 1.)) as usize;
+            let y_offset = (subpixel_bounds.min.y - (bounds.min.y - 1.)) as usize;
+            subpixel_glyph.draw(|x, y, v| {
+                let (x, y) = (x as usize + x_offset, y as usize + y_offset);
+                if x < subpixel_width && y < height {
+                    coverage[y * subpixel_width + x] = v;
+                }
+            });
+        }
+
+        let mut data = Vec::with_capacity(width * height * 4);
+        for row in coverage.chunks_exact(subpixel_width) {
+            let filtered = |subpixel: usize| {
+                FILTER
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, weight)| {
+                        let neighbor = (subpixel + i).checked_sub(2)?;
+                        row.get(neighbor).map(|v| v * weight)
+                    })
+                    .sum::<f32>()
+            };
+            for x in 0..width {
+                let [r, g, b] = [filtered(3 * x), filtered(3 * x + 1), filtered(3 * x + 2)];
+                // The coverage is linear, so it's encoded to be read back unchanged from the
+                // sRGB texture
+                let color = LinearRgba::new(r, g, b, r.max(g).max(b));
+                data.extend_from_slice(&Srgba::from(color).to_u8_array());
+            }
+        }
+
+        Image::new(
+            Extent3d {
+                width: width as u32,
+                height: height as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::MAIN_WORLD,
+        )
     }
 
     pub fn get_outlined_glyph_texture(outlined_glyph: OutlinedGlyph) -> Image {
@@ -51,3 +273,121 @@ impl Font {
         )
     }
 }
+
+/// How the glyphs of text are antialiased when they're rasterized, set in the
+/// [`TextRenderSettings`](crate::TextRenderSettings).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum GlyphAntialiasing {
+    /// Each pixel is either inside or outside the glyph, such as for pixel art fonts.
+    None,
+    /// The coverage of each pixel by the glyph is drawn as the alpha of the text color.
+    #[default]
+    Grayscale,
+    /// The coverage of the red, green and blue subpixels of each pixel is drawn separately,
+    /// which makes small text sharper on horizontal RGB LCD displays.
+    ///
+    /// Each color channel is blended separately, which needs the color of the background behind
+    /// the text: only UI text with an opaque `BackgroundColor` of its own is drawn this way.
+    /// Other text, such as `Text2d`, falls back to [`GlyphAntialiasing::Grayscale`].
+    SubpixelRgb,
+}
+
+impl GlyphAntialiasing {
+    /// Returns [`GlyphAntialiasing::Grayscale`] instead of [`GlyphAntialiasing::SubpixelRgb`], for
+    /// text drawn over an unknown or non-opaque background.
+    pub fn without_subpixel(self) -> Self {
+        match self {
+            Self::SubpixelRgb => Self::Grayscale,
+            antialiasing => antialiasing,
+        }
+    }
+}
+
+/// The font used by the [`TextSection`](crate::TextSection)s whose [`TextStyle::font`](crate::TextStyle::font)
+/// is the default handle.
+///
+/// By default, this is the default handle itself, which is the `FiraMono-subset.ttf` font compiled
+/// into the library when the `default_font` feature is enabled. Text using the default font is laid
+/// out again when this resource changes.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_asset::AssetServer;
+/// # use bevy_text::DefaultFont;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.insert_resource(DefaultFont(asset_server.load("fonts/FiraSans-Bold.ttf")));
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct DefaultFont(pub Handle<Font>);
+
+impl DefaultFont {
+    /// Returns the handle of the font used for `font`: this default font if `font` is the default
+    /// handle, or `font` itself otherwise.
+    pub fn resolve<'a>(&'a self, font: &'a Handle<Font>) -> &'a Handle<Font> {
+        if font.id() == AssetId::default() {
+            &self.0
+        } else {
+            font
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn font_metrics_come_from_the_font_tables() {
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        // The layout scales the ascent minus the descent of the font to the font size
+        let metrics = font.metrics(20.);
+        assert!((metrics.ascent - metrics.descent - 20.).abs() < 1e-4);
+        let scale = 20. / ab_glyph::Font::height_unscaled(&font.font);
+        assert_eq!(metrics.cap_height, Some(690. * scale));
+        assert_eq!(metrics.x_height, Some(528. * scale));
+        assert_eq!(metrics.underline_position, Some(-75. * scale));
+        assert_eq!(metrics.underline_thickness, Some(50. * scale));
+
+        let x = font.glyph_metrics('x', 20.).unwrap();
+        assert!(x.advance > 0.);
+        let bounds = x.bounds.unwrap();
+        assert_eq!(bounds.min.y, 0.);
+        assert!((bounds.max.y - metrics.x_height.unwrap()).abs() < 0.5);
+
+        assert_eq!(font.glyph_metrics(' ', 20.).unwrap().bounds, None);
+        assert_eq!(font.glyph_metrics('🦀', 20.), None);
+
+        // Without the data of the font, only the metrics of `ab_glyph` are known
+        let from_arc = Font::from_font_arc(font.font.clone());
+        let arc_metrics = from_arc.metrics(20.);
+        assert_eq!(arc_metrics.ascent, metrics.ascent);
+        assert_eq!(arc_metrics.cap_height, None);
+    }
+
+    #[test]
+    fn rasterize_glyph_antialiasing() {
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        let glyph = font.font.glyph_id('x').with_scale(20.);
+        let outline = || font.font.outline_glyph(glyph.clone()).unwrap();
+
+        // Without antialiasing, each pixel is either inside or outside the glyph
+        let aliased = font.rasterize_glyph(outline(), GlyphAntialiasing::None);
+        assert!(aliased
+            .data
+            .chunks_exact(4)
+            .all(|pixel| pixel[3] == 0 || pixel[3] == 255));
+
+        // Subpixel coverage has the size of the grayscale one, and differs between the channels
+        // at the edges of the glyph
+        let grayscale = font.rasterize_glyph(outline(), GlyphAntialiasing::Grayscale);
+        let subpixel = font.rasterize_glyph(outline(), GlyphAntialiasing::SubpixelRgb);
+        assert_eq!(subpixel.size(), grayscale.size());
+        assert!(subpixel
+            .data
+            .chunks_exact(4)
+            .any(|pixel| pixel[0] != pixel[2]));
+    }
+}
@@ -1,14 +1,16 @@
 use ab_glyph::{GlyphId, Point};
-use bevy_asset::{Assets, Handle};
+use bevy_asset::{AssetId, Assets, Handle};
 use bevy_math::UVec2;
 use bevy_render::{
     render_asset::RenderAssetUsages,
     render_resource::{Extent3d, TextureDimension, TextureFormat},
-    texture::Image,
+    texture::{Image, TextureFormatPixelInfo},
 };
 use bevy_sprite::{DynamicTextureAtlasBuilder, TextureAtlasLayout};
 use bevy_utils::HashMap;
 
+use crate::GlyphAntialiasing;
+
 #[cfg(feature = "subpixel_glyph_atlas")]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct SubpixelOffset {
@@ -47,20 +49,30 @@ pub struct PlacedGlyph {
     pub glyph_id: GlyphId,
     /// The sub-pixel offset of the placed glyph.
     pub subpixel_offset: SubpixelOffset,
+    /// How the glyph is antialiased, which is rasterized separately for each antialiasing.
+    pub antialiasing: GlyphAntialiasing,
 }
 
 pub struct FontAtlas {
     pub dynamic_texture_atlas_builder: DynamicTextureAtlasBuilder,
     pub glyph_to_atlas_index: HashMap<PlacedGlyph, usize>,
+    /// The images added with [`FontAtlas::add_image`], such as icons drawn next to the text.
+    pub image_to_atlas_index: HashMap<AssetId<Image>, usize>,
     pub texture_atlas: Handle<TextureAtlasLayout>,
     pub texture: Handle<Image>,
 }
 
 impl FontAtlas {
+    /// Creates an empty [`FontAtlas`] of `size`, keeping `glyph_padding` transparent pixels
+    /// around each of its glyphs and images.
+    ///
+    /// The glyphs and images added to the atlas already have a pixel wide transparent border, so
+    /// the gap left between them is `2 * (glyph_padding - 1)` pixels wide.
     pub fn new(
         textures: &mut Assets<Image>,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
         size: UVec2,
+        glyph_padding: u16,
     ) -> FontAtlas {
         let texture = textures.add(Image::new_fill(
             Extent3d {
@@ -78,7 +90,11 @@ impl FontAtlas {
         Self {
             texture_atlas: texture_atlases.add(texture_atlas),
             glyph_to_atlas_index: HashMap::default(),
-            dynamic_texture_atlas_builder: DynamicTextureAtlasBuilder::new(size, 0),
+            image_to_atlas_index: HashMap::default(),
+            dynamic_texture_atlas_builder: DynamicTextureAtlasBuilder::new(
+                size,
+                2 * (u32::from(glyph_padding.max(1)) - 1),
+            ),
             texture,
         }
     }
@@ -126,4 +142,76 @@ impl FontAtlas {
             false
         }
     }
+
+    pub fn get_image_index(&self, image: AssetId<Image>) -> Option<usize> {
+        self.image_to_atlas_index.get(&image).copied()
+    }
+
+    /// Add an image, such as an icon, to the atlas, so that it can be drawn in the same batch as
+    /// the glyphs.
+    ///
+    /// Like the glyphs, the image is surrounded by a pixel wide transparent border in the atlas,
+    /// which isn't part of its rect in the atlas layout.
+    ///
+    /// # Returns
+    ///
+    /// Returns the index of the image in the atlas layout, or `None` if it isn't loaded, if its
+    /// data isn't kept in the main world, if its format differs from the one of the atlas, or if
+    /// it doesn't fit in the atlas.
+    pub fn add_image(
+        &mut self,
+        textures: &mut Assets<Image>,
+        atlas_layouts: &mut Assets<TextureAtlasLayout>,
+        image: AssetId<Image>,
+    ) -> Option<usize> {
+        if let Some(index) = self.get_image_index(image) {
+            return Some(index);
+        }
+        let padded_image = {
+            let atlas_texture = textures.get(&self.texture)?;
+            let image = textures.get(image)?;
+            if image.data.is_empty()
+                || image.texture_descriptor.format != atlas_texture.texture_descriptor.format
+            {
+                return None;
+            }
+            with_transparent_border(image)
+        };
+        let atlas_layout = atlas_layouts.get_mut(&self.texture_atlas)?;
+        let atlas_texture = textures.get_mut(&self.texture)?;
+        let index = self.dynamic_texture_atlas_builder.add_texture(
+            atlas_layout,
+            &padded_image,
+            atlas_texture,
+        )?;
+        let rect = &mut atlas_layout.textures[index];
+        rect.min += UVec2::ONE;
+        rect.max -= UVec2::ONE;
+        self.image_to_atlas_index.insert(image, index);
+        Some(index)
+    }
+}
+
+/// Returns a copy of `image` with a pixel wide transparent border along its edges, so that
+/// sampling its edges in an atlas doesn't bleed into its neighbors.
+fn with_transparent_border(image: &Image) -> Image {
+    let pixel_size = image.texture_descriptor.format.pixel_size();
+    let row_size = image.width() as usize * pixel_size;
+    let padded_row_size = row_size + 2 * pixel_size;
+    let mut data = vec![0; padded_row_size * (image.height() as usize + 2)];
+    for (y, row) in image.data.chunks_exact(row_size).enumerate() {
+        let start = (y + 1) * padded_row_size + pixel_size;
+        data[start..start + row_size].copy_from_slice(row);
+    }
+    Image::new(
+        Extent3d {
+            width: image.width() + 2,
+            height: image.height() + 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        image.texture_descriptor.format,
+        RenderAssetUsages::MAIN_WORLD,
+    )
 }
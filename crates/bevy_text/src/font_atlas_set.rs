@@ -1,20 +1,31 @@
-use crate::{error::TextError, Font, FontAtlas, PlacedGlyph};
+use crate::{error::TextError, Font, FontAtlas, GlyphAntialiasing, PlacedGlyph, TextAtlasSettings};
 use ab_glyph::{GlyphId, OutlinedGlyph, Point};
 use bevy_asset::{AssetEvent, AssetId};
 use bevy_asset::{Assets, Handle};
 use bevy_ecs::prelude::*;
 use bevy_math::{FloatOrd, UVec2};
 use bevy_reflect::Reflect;
-use bevy_render::texture::Image;
+use bevy_render::{render_resource::TextureFormat, texture::Image};
 use bevy_sprite::TextureAtlasLayout;
 use bevy_utils::HashMap;
 
 type FontSizeKey = FloatOrd;
 
-#[derive(Default, Resource)]
+#[derive(Resource)]
 pub struct FontAtlasSets {
     // PERF: in theory this could be optimized with Assets storage ... consider making some fast "simple" AssetMap
     pub(crate) sets: HashMap<AssetId<Font>, FontAtlasSet>,
+    /// The [`TextAtlasSettings::glyph_padding`] the atlases are packed with.
+    pub(crate) glyph_padding: u16,
+}
+
+impl Default for FontAtlasSets {
+    fn default() -> Self {
+        Self {
+            sets: HashMap::default(),
+            glyph_padding: 1,
+        }
+    }
 }
 
 impl FontAtlasSets {
@@ -22,6 +33,55 @@ impl FontAtlasSets {
         let id: AssetId<Font> = id.into();
         self.sets.get(&id)
     }
+
+    /// The [`TextAtlasSettings::glyph_padding`] the atlases are packed with.
+    pub fn glyph_padding(&self) -> u16 {
+        self.glyph_padding
+    }
+
+    /// Add `image` to the font atlas whose texture is `atlas_texture`, so that it can be drawn in
+    /// the same batch as the glyphs of that atlas.
+    ///
+    /// Unlike the ones of [`FontAtlasSet::get_glyph_atlas_info`], the handles of the returned
+    /// [`GlyphAtlasInfo`] are strong, so that the atlas outlives its font if the image is still
+    /// drawn from it.
+    ///
+    /// See [`FontAtlas::add_image`].
+    pub fn add_image_to_atlas(
+        &mut self,
+        atlas_texture: AssetId<Image>,
+        image: AssetId<Image>,
+        texture_atlases: &mut Assets<TextureAtlasLayout>,
+        textures: &mut Assets<Image>,
+    ) -> Option<GlyphAtlasInfo> {
+        let atlas = self
+            .sets
+            .values_mut()
+            .flat_map(|set| set.font_atlases.values_mut().flatten())
+            .find(|atlas| atlas.texture.id() == atlas_texture)?;
+        let glyph_index = atlas.add_image(textures, texture_atlases, image)?;
+        Some(GlyphAtlasInfo {
+            texture_atlas: atlas.texture_atlas.clone(),
+            texture: atlas.texture.clone(),
+            glyph_index,
+        })
+    }
+}
+
+/// Clears the font atlases when the [`TextAtlasSettings::glyph_padding`] changes, so that their
+/// glyphs are packed again with the new padding as text is laid out again.
+pub fn update_glyph_padding(
+    text_atlas_settings: Res<TextAtlasSettings>,
+    mut font_atlas_sets: ResMut<FontAtlasSets>,
+) {
+    if !text_atlas_settings.is_changed() {
+        return;
+    }
+    let glyph_padding = text_atlas_settings.glyph_padding.max(1);
+    if font_atlas_sets.glyph_padding != glyph_padding {
+        font_atlas_sets.sets.clear();
+        font_atlas_sets.glyph_padding = glyph_padding;
+    }
 }
 
 pub fn remove_dropped_font_atlas_sets(
@@ -38,35 +98,61 @@ pub fn remove_dropped_font_atlas_sets(
 
 pub struct FontAtlasSet {
     font_atlases: HashMap<FontSizeKey, Vec<FontAtlas>>,
+    /// The number of transparent pixels kept around each glyph, see
+    /// [`TextAtlasSettings::glyph_padding`].
+    glyph_padding: u16,
 }
 
+/// The location of a glyph in a [`FontAtlas`].
 #[derive(Debug, Clone, Reflect)]
 pub struct GlyphAtlasInfo {
+    /// The layout of the atlas, holding the rect of the glyph.
     pub texture_atlas: Handle<TextureAtlasLayout>,
+    /// The texture of the atlas.
     pub texture: Handle<Image>,
+    /// The index of the rect of the glyph in the [`TextureAtlasLayout::textures`].
     pub glyph_index: usize,
 }
 
 impl Default for FontAtlasSet {
     fn default() -> Self {
+        FontAtlasSet::new(1)
+    }
+}
+
+impl FontAtlasSet {
+    /// Creates a [`FontAtlasSet`] keeping `glyph_padding` transparent pixels around each glyph in
+    /// its atlases, at least 1.
+    pub fn new(glyph_padding: u16) -> Self {
         FontAtlasSet {
             font_atlases: HashMap::with_capacity_and_hasher(1, Default::default()),
+            glyph_padding: glyph_padding.max(1),
         }
     }
-}
 
-impl FontAtlasSet {
+    /// Returns the number of transparent pixels kept around each glyph in the atlases of this set.
+    pub fn glyph_padding(&self) -> u16 {
+        self.glyph_padding
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&FontSizeKey, &Vec<FontAtlas>)> {
         self.font_atlases.iter()
     }
 
-    pub fn has_glyph(&self, glyph_id: GlyphId, glyph_position: Point, font_size: f32) -> bool {
+    pub fn has_glyph(
+        &self,
+        glyph_id: GlyphId,
+        glyph_position: Point,
+        font_size: f32,
+        antialiasing: GlyphAntialiasing,
+    ) -> bool {
         self.font_atlases
             .get(&FloatOrd(font_size))
             .map_or(false, |font_atlas| {
                 let placed_glyph = PlacedGlyph {
                     glyph_id,
                     subpixel_offset: glyph_position.into(),
+                    antialiasing,
                 };
                 font_atlas
                     .iter()
@@ -74,24 +160,37 @@ impl FontAtlasSet {
             })
     }
 
+    /// Rasterizes `outlined_glyph`, an outline of a glyph of `font`, with `antialiasing`, and adds
+    /// it to the atlases of its font size.
     pub fn add_glyph_to_atlas(
         &mut self,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
         textures: &mut Assets<Image>,
+        font: &Font,
         outlined_glyph: OutlinedGlyph,
+        antialiasing: GlyphAntialiasing,
     ) -> Result<GlyphAtlasInfo, TextError> {
         let glyph = outlined_glyph.glyph();
         let placed_glyph = PlacedGlyph {
             glyph_id: glyph.id,
             subpixel_offset: glyph.position.into(),
+            antialiasing,
         };
         let font_size = glyph.scale.y;
+        let glyph_padding = self.glyph_padding;
         let font_atlases = self
             .font_atlases
             .entry(FloatOrd(font_size))
-            .or_insert_with(|| vec![FontAtlas::new(textures, texture_atlases, UVec2::splat(512))]);
+            .or_insert_with(|| {
+                vec![FontAtlas::new(
+                    textures,
+                    texture_atlases,
+                    UVec2::splat(512),
+                    glyph_padding,
+                )]
+            });
 
-        let glyph_texture = Font::get_outlined_glyph_texture(outlined_glyph);
+        let glyph_texture = font.rasterize_glyph(outlined_glyph, antialiasing);
         let add_char_to_font_atlas = |atlas: &mut FontAtlas| -> bool {
             atlas.add_glyph(textures, texture_atlases, &placed_glyph, &glyph_texture)
         };
@@ -108,6 +207,7 @@ impl FontAtlasSet {
                 textures,
                 texture_atlases,
                 UVec2::splat(containing),
+                glyph_padding,
             ));
             if !font_atlases.last_mut().unwrap().add_glyph(
                 textures,
@@ -122,6 +222,65 @@ impl FontAtlasSet {
         Ok(self.get_glyph_atlas_info(font_size, &placed_glyph).unwrap())
     }
 
+    /// Add `image` to the font atlases of `font_size`, so that it can be laid out with the glyphs
+    /// of that size, like an [`InlineImage`](crate::InlineImage).
+    ///
+    /// A new atlas is created if the image doesn't fit in the existing ones. Returns `None` if the
+    /// image isn't loaded, if its data isn't kept in the main world, or if its format isn't the
+    /// one of the atlases.
+    pub fn add_inline_image(
+        &mut self,
+        texture_atlases: &mut Assets<TextureAtlasLayout>,
+        textures: &mut Assets<Image>,
+        font_size: f32,
+        image: AssetId<Image>,
+    ) -> Option<GlyphAtlasInfo> {
+        let image_size = {
+            let image = textures.get(image)?;
+            if image.data.is_empty()
+                || image.texture_descriptor.format != TextureFormat::Rgba8UnormSrgb
+            {
+                return None;
+            }
+            image.size()
+        };
+        let glyph_padding = self.glyph_padding;
+        let font_atlases = self.font_atlases.entry(FloatOrd(font_size)).or_default();
+        let added = font_atlases
+            .iter_mut()
+            .enumerate()
+            .find_map(|(atlas_index, atlas)| {
+                let glyph_index = atlas.add_image(textures, texture_atlases, image)?;
+                Some((atlas_index, glyph_index))
+            });
+        let (atlas_index, glyph_index) = match added {
+            Some(added) => added,
+            None => {
+                // The image is padded with a pixel wide border on each side
+                let image_max_size = image_size.max_element() + 2;
+                let containing = (1u32 << (32 - image_max_size.leading_zeros())).max(512);
+                font_atlases.push(FontAtlas::new(
+                    textures,
+                    texture_atlases,
+                    UVec2::splat(containing),
+                    glyph_padding,
+                ));
+                let glyph_index =
+                    font_atlases
+                        .last_mut()
+                        .unwrap()
+                        .add_image(textures, texture_atlases, image)?;
+                (font_atlases.len() - 1, glyph_index)
+            }
+        };
+        let atlas = &font_atlases[atlas_index];
+        Some(GlyphAtlasInfo {
+            texture_atlas: atlas.texture_atlas.clone_weak(),
+            texture: atlas.texture.clone_weak(),
+            glyph_index,
+        })
+    }
+
     pub fn get_glyph_atlas_info(
         &mut self,
         font_size: f32,
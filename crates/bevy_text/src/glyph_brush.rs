@@ -1,4 +1,6 @@
-use ab_glyph::{Font as _, FontArc, Glyph, PxScaleFont, ScaleFont as _};
+use std::borrow::Cow;
+
+use ab_glyph::{point, Font as _, FontArc, Glyph, PxScaleFont, ScaleFont as _};
 use bevy_asset::{AssetId, Assets};
 use bevy_math::{Rect, Vec2};
 use bevy_reflect::Reflect;
@@ -9,10 +11,13 @@ use glyph_brush_layout::{
     BuiltInLineBreaker, FontId, GlyphPositioner, Layout, SectionGeometry, SectionGlyph,
     SectionText, ToSectionText,
 };
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo, Level};
+use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
 
 use crate::{
-    error::TextError, BreakLineOn, Font, FontAtlasSet, FontAtlasSets, GlyphAtlasInfo, JustifyText,
-    PlacedGlyph, TextSettings, YAxisOrientation,
+    error::TextError, BreakLineOn, Font, FontAtlasSet, FontAtlasSets, GlyphAntialiasing,
+    GlyphAtlasInfo, JustifyText, LineMetrics, PlacedGlyph, SectionBackground, TextDirection,
+    TextOverflow, TextSettings, YAxisOrientation,
 };
 
 pub struct GlyphBrush {
@@ -53,18 +58,29 @@ impl GlyphBrush {
         Ok(section_glyphs)
     }
 
+    /// Rasterizes the `glyphs` laid out from `sections` into the font atlases, and positions them.
+    ///
+    /// The glyphs of each section are moved up by its baseline shift in `baseline_shifts`, in
+    /// physical pixels, such as for a [`TextVariant::Superscript`](crate::TextVariant::Superscript).
+    ///
+    /// The glyph ranges of `lines`, indexing `glyphs`, are mapped to the returned glyphs, which
+    /// skip the glyphs without an outline such as spaces.
     #[allow(clippy::too_many_arguments)]
     pub fn process_glyphs(
         &self,
         glyphs: Vec<SectionGlyph>,
         sections: &[SectionText],
+        section_images: &[Option<SectionImage>],
+        baseline_shifts: &[f32],
         font_atlas_sets: &mut FontAtlasSets,
         fonts: &Assets<Font>,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
         textures: &mut Assets<Image>,
         text_settings: &TextSettings,
+        antialiasing: GlyphAntialiasing,
         y_axis_orientation: YAxisOrientation,
         h_anchor: f32,
+        lines: &mut [LineMetrics],
     ) -> Result<Vec<PositionedGlyph>, TextError> {
         if glyphs.is_empty() {
             return Ok(Vec::new());
@@ -80,7 +96,7 @@ impl GlyphBrush {
                     asset_id,
                     font,
                     font_size,
-                    ab_glyph::Font::as_scaled(&font.font, font_size),
+                    ab_glyph::Font::as_scaled(&font.font, section.scale),
                 ))
             })
             .collect::<Result<Vec<_>, _>>()?;
@@ -88,31 +104,85 @@ impl GlyphBrush {
         let text_bounds = compute_text_bounds(&glyphs, |index| sections_data[index].3);
 
         let mut positioned_glyphs = Vec::new();
+        // The index of the positioned glyph of each glyph, or of the next one if it's skipped
+        let mut glyph_indices = Vec::with_capacity(glyphs.len() + 1);
         for sg in glyphs {
+            glyph_indices.push(positioned_glyphs.len());
             let SectionGlyph {
                 section_index: _,
                 byte_index,
                 mut glyph,
                 font_id: _,
             } = sg;
+            if let Some(image) = section_images[sg.section_index] {
+                let glyph_padding = font_atlas_sets.glyph_padding;
+                let font_atlas_set = font_atlas_sets
+                    .sets
+                    .entry(*sections_data[sg.section_index].0)
+                    .or_insert_with(|| FontAtlasSet::new(glyph_padding));
+                let Some(atlas_info) = font_atlas_set.add_inline_image(
+                    texture_atlases,
+                    textures,
+                    image.font_size,
+                    image.image,
+                ) else {
+                    if !textures.contains(image.image) {
+                        return Err(TextError::NoSuchImage);
+                    }
+                    warn_once!(
+                        "An inline image couldn't be added to a font atlas, its data has to be kept in the main world and its format has to be Rgba8UnormSrgb."
+                    );
+                    continue;
+                };
+
+                let x = glyph.position.x + image.size.x / 2.0 + h_anchor;
+                let bottom = glyph.position.y - image.bottom;
+                let y = match y_axis_orientation {
+                    YAxisOrientation::BottomToTop => {
+                        text_bounds.max.y - bottom + image.size.y / 2.0
+                    }
+                    YAxisOrientation::TopToBottom => {
+                        bottom - image.size.y / 2.0 - text_bounds.min.y
+                    }
+                };
+
+                positioned_glyphs.push(PositionedGlyph {
+                    position: Vec2::new(x, y),
+                    size: image.size,
+                    atlas_info,
+                    section_index: sg.section_index,
+                    byte_index,
+                });
+                continue;
+            }
+            // Shifted after the layout, so that the line keeps the baseline of its other sections
+            glyph.position.y -= baseline_shifts[sg.section_index];
             let placed_glyph = PlacedGlyph {
                 glyph_id: glyph.id,
                 subpixel_offset: glyph.position.into(),
+                antialiasing,
             };
             let adjust = GlyphPlacementAdjuster::new(&mut glyph);
             let section_data = sections_data[sg.section_index];
             if let Some(outlined_glyph) = section_data.1.font.outline_glyph(glyph) {
                 let bounds = outlined_glyph.px_bounds();
+                let glyph_padding = font_atlas_sets.glyph_padding;
                 let font_atlas_set = font_atlas_sets
                     .sets
                     .entry(*section_data.0)
-                    .or_insert_with(FontAtlasSet::default);
+                    .or_insert_with(|| FontAtlasSet::new(glyph_padding));
 
                 let atlas_info = font_atlas_set
                     .get_glyph_atlas_info(section_data.2, &placed_glyph)
                     .map(Ok)
                     .unwrap_or_else(|| {
-                        font_atlas_set.add_glyph_to_atlas(texture_atlases, textures, outlined_glyph)
+                        font_atlas_set.add_glyph_to_atlas(
+                            texture_atlases,
+                            textures,
+                            section_data.1,
+                            outlined_glyph,
+                            antialiasing,
+                        )
                     })?;
 
                 if !text_settings.allow_dynamic_font_size
@@ -151,9 +221,183 @@ impl GlyphBrush {
                 });
             }
         }
+        glyph_indices.push(positioned_glyphs.len());
+        for line in lines {
+            line.glyphs = glyph_indices[line.glyphs.start]..glyph_indices[line.glyphs.end];
+        }
         Ok(positioned_glyphs)
     }
 
+    /// Truncates the lines of `glyphs`, laid out from `sections`, that are wider than `width`
+    /// following `overflow`, replacing the graphemes that don't fit with `ellipsis`.
+    ///
+    /// The truncated lines are aligned again following `text_alignment`. The ellipsis is laid out
+    /// with the font of the section of the first truncated grapheme, or of a grapheme next to it if
+    /// that section is an inline image.
+    #[allow(clippy::too_many_arguments)]
+    pub fn truncate_lines(
+        &self,
+        glyphs: &mut Vec<SectionGlyph>,
+        sections: &[SectionText],
+        section_images: &[Option<SectionImage>],
+        width: f32,
+        overflow: TextOverflow,
+        ellipsis: &str,
+        text_alignment: JustifyText,
+    ) {
+        if overflow == TextOverflow::Clip || !width.is_finite() {
+            return;
+        }
+        let scaled_font = |index: usize| {
+            let section = &sections[index];
+            self.fonts[section.font_id.0].as_scaled(section.scale)
+        };
+        let advance = |sg: &SectionGlyph| scaled_font(sg.section_index).h_advance(sg.glyph.id);
+        let character = |sg: &SectionGlyph| {
+            sections[sg.section_index].text[sg.byte_index..]
+                .chars()
+                .next()
+        };
+        // Lays out the ellipsis from `x` on `baseline` with the font of `section_index`
+        let layout_ellipsis = |section_index: usize, x: f32, baseline: f32| {
+            let font = scaled_font(section_index);
+            let mut x = x;
+            let mut previous = None;
+            let glyphs: Vec<Glyph> = ellipsis
+                .chars()
+                .map(|c| {
+                    let id = font.glyph_id(c);
+                    if let Some(previous) = previous {
+                        x += font.kern(previous, id);
+                    }
+                    previous = Some(id);
+                    let glyph = id.with_scale_and_position(font.scale, point(x, baseline));
+                    x += font.h_advance(id);
+                    glyph
+                })
+                .collect();
+            (glyphs, x)
+        };
+
+        let mut truncated = Vec::with_capacity(glyphs.len());
+        // The glyphs of a line are consecutive, in logical order, and share their baseline
+        for line in glyphs.chunk_by(|a, b| a.glyph.position.y == b.glyph.position.y) {
+            let left = line[0].glyph.position.x;
+            // Trailing whitespace is allowed out of the bounds by the line breaker
+            let right = line
+                .iter()
+                .rev()
+                .find(|sg| !character(sg).is_some_and(char::is_whitespace))
+                .map_or(left, |sg| sg.glyph.position.x + advance(sg));
+            if right - left <= width {
+                truncated.extend_from_slice(line);
+                continue;
+            }
+
+            // The ranges of the glyphs of each grapheme
+            let mut clusters: Vec<std::ops::Range<usize>> = Vec::new();
+            for (index, sg) in line.iter().enumerate() {
+                let text = sections[sg.section_index].text;
+                let is_boundary = GraphemeCursor::new(sg.byte_index, text.len(), true)
+                    .is_boundary(text, 0)
+                    .unwrap_or(true);
+                match clusters.last_mut() {
+                    Some(cluster)
+                        if !is_boundary
+                            && line[cluster.start].section_index == sg.section_index =>
+                    {
+                        cluster.end = index + 1;
+                    }
+                    _ => clusters.push(index..index + 1),
+                }
+            }
+            let count = clusters.len();
+            let start = |cluster: usize| {
+                clusters
+                    .get(cluster)
+                    .map_or(right, |cluster| line[cluster.start].glyph.position.x)
+            };
+            // The section of the ellipsis replacing the graphemes from `head` to `tail`
+            let ellipsis_section = |head: usize, tail: usize| {
+                [Some(head), head.checked_sub(1), Some(tail)]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|cluster| clusters.get(cluster))
+                    .map(|cluster| line[cluster.start].section_index)
+                    .find(|&section_index| section_images[section_index].is_none())
+            };
+            let ellipsis_width = |head: usize, tail: usize| {
+                ellipsis_section(head, tail).map_or(0.0, |section_index| {
+                    layout_ellipsis(section_index, 0.0, 0.0).1
+                })
+            };
+            let overflows = |head: usize, tail: usize| {
+                start(head) - left + ellipsis_width(head, tail) + right - start(tail) > width
+            };
+
+            // The graphemes from `head` to `tail` are truncated
+            let (mut head, mut tail) = match overflow {
+                TextOverflow::EllipsisMiddle => (count / 2, count / 2),
+                _ => (count, count),
+            };
+            while (head > 0 || tail < count) && overflows(head, tail) {
+                let head_width = start(head) - left;
+                let tail_width = right - start(tail);
+                if head > 0 && (overflow == TextOverflow::Ellipsis || head_width >= tail_width) {
+                    head -= 1;
+                } else if tail < count {
+                    tail += 1;
+                } else {
+                    head -= 1;
+                }
+            }
+
+            let baseline = line[0].glyph.position.y;
+            let head_end = clusters
+                .get(head)
+                .map_or(line.len(), |cluster| cluster.start);
+            let mut new_line = line[..head_end].to_vec();
+            let mut x = start(head);
+            if let Some(section_index) = ellipsis_section(head, tail) {
+                // The ellipsis glyphs map back to the first truncated grapheme
+                let byte_index = clusters
+                    .get(head)
+                    .map_or(line[line.len() - 1].byte_index, |cluster| {
+                        line[cluster.start].byte_index
+                    });
+                let (ellipsis_glyphs, end) = layout_ellipsis(section_index, x, baseline);
+                new_line.extend(ellipsis_glyphs.into_iter().map(|glyph| SectionGlyph {
+                    section_index,
+                    byte_index,
+                    glyph,
+                    font_id: sections[section_index].font_id,
+                }));
+                x = end;
+            }
+            if let Some(cluster) = clusters.get(tail) {
+                let offset = x - start(tail);
+                new_line.extend(line[cluster.start..].iter().map(|sg| {
+                    let mut sg = sg.clone();
+                    sg.glyph.position.x += offset;
+                    sg
+                }));
+                x = right + offset;
+            }
+
+            // Keep the line aligned like it was laid out
+            let shift = match text_alignment {
+                JustifyText::Left => 0.0,
+                JustifyText::Center => (right - x) / 2.0,
+                JustifyText::Right => right - x,
+            };
+            for sg in &mut new_line {
+                sg.glyph.position.x += shift;
+            }
+            truncated.extend(new_line);
+        }
+        *glyphs = truncated;
+    }
+
     pub fn add_font(&mut self, asset_id: AssetId<Font>, font: FontArc) -> FontId {
         self.fonts.push(font);
         self.asset_ids.push(asset_id);
@@ -163,15 +407,52 @@ impl GlyphBrush {
     }
 }
 
+/// An [`InlineImage`](crate::InlineImage) laid out in place of the glyph of its section.
+#[derive(Debug, Clone, Copy)]
+pub struct SectionImage {
+    pub image: AssetId<Image>,
+    /// The size of the image, in physical pixels.
+    pub size: Vec2,
+    /// The height of the bottom of the image above the baseline, in physical pixels.
+    pub bottom: f32,
+    /// The font size of the section, in physical pixels, whose font atlases the image is added to.
+    pub font_size: f32,
+}
+
+/// A glyph of a laid out text, listed in [`TextLayoutInfo::glyphs`](crate::TextLayoutInfo::glyphs).
+///
+/// Its texture is a rect of a font atlas, which can be used to draw custom effects with the
+/// glyphs, such as particles or outlines.
 #[derive(Debug, Clone, Reflect)]
 pub struct PositionedGlyph {
+    /// The center of the glyph, in physical pixels, from the top left corner of the text for UI
+    /// text, or from its bottom left corner for [`Text2dBundle`](crate::Text2dBundle).
     pub position: Vec2,
+    /// The size of the glyph in its atlas, or the size an inline image is displayed at.
     pub size: Vec2,
+    /// Where the texture of the glyph is stored.
     pub atlas_info: GlyphAtlasInfo,
+    /// The index of the [`TextSection`](crate::TextSection) the glyph belongs to.
     pub section_index: usize,
+    /// The index of the first byte of the glyph in the [displayed value](crate::Text::displayed_values)
+    /// of its section.
     pub byte_index: usize,
 }
 
+impl PositionedGlyph {
+    /// Returns the character of this glyph, given the [displayed values](crate::Text::displayed_values)
+    /// of its text.
+    ///
+    /// Returns `None` if the glyph doesn't belong to the text.
+    pub fn character(&self, displayed_values: &[Cow<str>]) -> Option<char> {
+        displayed_values
+            .get(self.section_index)?
+            .get(self.byte_index..)?
+            .chars()
+            .next()
+    }
+}
+
 #[cfg(feature = "subpixel_glyph_atlas")]
 struct GlyphPlacementAdjuster;
 
@@ -235,3 +516,329 @@ where
 
     text_bounds
 }
+
+/// Returns the ascent and the descent of the tallest and deepest fonts of `line`.
+fn line_ascent_descent<T>(
+    line: &[SectionGlyph],
+    get_scaled_font: &impl Fn(usize) -> PxScaleFont<T>,
+) -> (f32, f32)
+where
+    T: ab_glyph::Font,
+{
+    line.iter().fold((0f32, 0f32), |(ascent, descent), sg| {
+        let scaled_font = get_scaled_font(sg.section_index);
+        (
+            ascent.max(scaled_font.ascent()),
+            descent.min(scaled_font.descent()),
+        )
+    })
+}
+
+/// Computes the [`LineMetrics`] of the lines of `section_glyphs`, laid out from `sections`, in the
+/// coordinates of the [`PositionedGlyph`]s laid out in `text_bounds`.
+///
+/// The glyph ranges of the lines index `section_glyphs`. The lines left empty by leading,
+/// consecutive or trailing line breaks have no glyphs and no width: they are placed below the
+/// previous line like the layout would, with the metrics of the font of their line break.
+pub(crate) fn compute_line_metrics<T>(
+    section_glyphs: &[SectionGlyph],
+    sections: &[SectionText],
+    get_scaled_font: impl Fn(usize) -> PxScaleFont<T>,
+    text_bounds: Rect,
+    y_axis_orientation: &YAxisOrientation,
+    h_anchor: f32,
+) -> Vec<LineMetrics>
+where
+    T: ab_glyph::Font,
+{
+    /// A line in the coordinates of the layout, with y pointing down.
+    struct LayoutLine {
+        baseline: f32,
+        ascent: f32,
+        /// Negative below the baseline.
+        descent: f32,
+        line_gap: f32,
+        left: f32,
+        right: f32,
+        glyphs: std::ops::Range<usize>,
+    }
+
+    fn empty_line<T: ab_glyph::Font>(
+        previous: Option<&LayoutLine>,
+        scaled_font: &PxScaleFont<T>,
+        glyph_index: usize,
+    ) -> LayoutLine {
+        // The layout moves down by the descent and the line gap of the previous line, then by
+        // the ascent of the next one
+        let top = previous.map_or(0., |previous| {
+            previous.baseline - previous.descent + previous.line_gap
+        });
+        LayoutLine {
+            baseline: top + scaled_font.ascent(),
+            ascent: scaled_font.ascent(),
+            descent: scaled_font.descent(),
+            line_gap: scaled_font.line_gap(),
+            left: 0.,
+            right: 0.,
+            glyphs: glyph_index..glyph_index,
+        }
+    }
+
+    // The line breaks of the text, as their offset in the concatenated sections and their section
+    let mut offsets = Vec::with_capacity(sections.len());
+    let mut line_breaks = Vec::new();
+    let mut length = 0;
+    for (section_index, section) in sections.iter().enumerate() {
+        offsets.push(length);
+        line_breaks.extend(
+            section
+                .text
+                .match_indices('\n')
+                .map(|(index, _)| (length + index, section_index)),
+        );
+        length += section.text.len();
+    }
+    let offset = |sg: &SectionGlyph| offsets[sg.section_index] + sg.byte_index;
+    let line_breaks_between = |start: usize, end: usize| {
+        let first = line_breaks.partition_point(|&(offset, _)| offset < start);
+        let last = line_breaks.partition_point(|&(offset, _)| offset < end);
+        &line_breaks[first..last.max(first)]
+    };
+
+    let mut lines: Vec<LayoutLine> = Vec::new();
+    let mut text_offset = 0;
+    let mut glyph_index = 0;
+    // The glyphs of a line are consecutive, and share their baseline
+    for line in section_glyphs.chunk_by(|a, b| a.glyph.position.y == b.glyph.position.y) {
+        // The glyphs of a line may have been reordered for bidirectional text
+        let (first, last) = line
+            .iter()
+            .map(offset)
+            .fold((usize::MAX, 0), |(first, last), o| {
+                (first.min(o), last.max(o))
+            });
+        // A line break ends the previous line, and each following one leaves an empty line
+        let skipped = usize::from(!lines.is_empty());
+        for &(_, section_index) in line_breaks_between(text_offset, first).iter().skip(skipped) {
+            let empty = empty_line(lines.last(), &get_scaled_font(section_index), glyph_index);
+            lines.push(empty);
+        }
+
+        let (ascent, descent) = line_ascent_descent(line, &get_scaled_font);
+        let line_gap = line.iter().fold(0f32, |line_gap, sg| {
+            line_gap.max(get_scaled_font(sg.section_index).line_gap())
+        });
+        let (left, right) = line.iter().fold((f32::MAX, f32::MIN), |(left, right), sg| {
+            let x = sg.glyph.position.x;
+            let advance = get_scaled_font(sg.section_index).h_advance(sg.glyph.id);
+            (left.min(x), right.max(x + advance))
+        });
+        lines.push(LayoutLine {
+            baseline: line[0].glyph.position.y,
+            ascent,
+            descent,
+            line_gap,
+            left,
+            right,
+            glyphs: glyph_index..glyph_index + line.len(),
+        });
+        glyph_index += line.len();
+        text_offset = last + 1;
+    }
+    // Each line break after the last glyph leaves an empty line, down to the trailing one
+    for &(_, section_index) in line_breaks_between(text_offset, length) {
+        let empty = empty_line(lines.last(), &get_scaled_font(section_index), glyph_index);
+        lines.push(empty);
+    }
+
+    lines
+        .into_iter()
+        .map(|line| {
+            let (top, bottom) = (line.baseline - line.ascent, line.baseline - line.descent);
+            let (baseline, min_y, max_y) = match y_axis_orientation {
+                YAxisOrientation::BottomToTop => (
+                    text_bounds.max.y - line.baseline,
+                    text_bounds.max.y - bottom,
+                    text_bounds.max.y - top,
+                ),
+                YAxisOrientation::TopToBottom => (
+                    line.baseline - text_bounds.min.y,
+                    top - text_bounds.min.y,
+                    bottom - text_bounds.min.y,
+                ),
+            };
+            LineMetrics {
+                baseline,
+                ascent: line.ascent,
+                descent: -line.descent,
+                rect: Rect::new(line.left + h_anchor, min_y, line.right + h_anchor, max_y),
+                glyphs: line.glyphs,
+            }
+        })
+        .collect()
+}
+
+/// Computes the [`SectionBackground`]s of the sections for which `highlighted` returns `true`, in
+/// the coordinates of the [`PositionedGlyph`]s laid out in `text_bounds`.
+pub(crate) fn compute_section_backgrounds<T>(
+    section_glyphs: &[SectionGlyph],
+    get_scaled_font: impl Fn(usize) -> PxScaleFont<T>,
+    highlighted: impl Fn(usize) -> bool,
+    text_bounds: Rect,
+    y_axis_orientation: &YAxisOrientation,
+    h_anchor: f32,
+) -> Vec<SectionBackground>
+where
+    T: ab_glyph::Font,
+{
+    let mut backgrounds: Vec<SectionBackground> = Vec::new();
+    // The glyphs of a line are consecutive, and share their baseline
+    for line in section_glyphs.chunk_by(|a, b| a.glyph.position.y == b.glyph.position.y) {
+        let baseline = line[0].glyph.position.y;
+        let (ascent, descent) = line_ascent_descent(line, &get_scaled_font);
+        let (top, bottom) = (baseline - ascent, baseline - descent);
+        let (min_y, max_y) = match y_axis_orientation {
+            YAxisOrientation::BottomToTop => (text_bounds.max.y - bottom, text_bounds.max.y - top),
+            YAxisOrientation::TopToBottom => (top - text_bounds.min.y, bottom - text_bounds.min.y),
+        };
+
+        // The glyphs of a line are in logical order, but right-to-left text moved them to their
+        // visual order, so the highlighted runs are found from left to right
+        let mut visual_order: Vec<&SectionGlyph> = line.iter().collect();
+        visual_order.sort_by(|a, b| a.glyph.position.x.total_cmp(&b.glyph.position.x));
+
+        let mut previous_section = None;
+        for (index, sg) in visual_order.iter().enumerate() {
+            let section_index = sg.section_index;
+            if !highlighted(section_index) {
+                previous_section = None;
+                continue;
+            }
+            let start = sg.glyph.position.x;
+            // Up to the next glyph rather than to the end of the advance, so that kerning leaves
+            // no gap between adjacent highlights
+            let end = match visual_order.get(index + 1) {
+                Some(next) => next.glyph.position.x,
+                None => start + get_scaled_font(section_index).h_advance(sg.glyph.id),
+            };
+            if previous_section == Some(section_index) {
+                if let Some(background) = backgrounds.last_mut() {
+                    background.rect.min.x = background.rect.min.x.min(start + h_anchor);
+                    background.rect.max.x = background.rect.max.x.max(end + h_anchor);
+                }
+            } else {
+                backgrounds.push(SectionBackground {
+                    section_index,
+                    rect: Rect::new(start + h_anchor, min_y, end + h_anchor, max_y),
+                });
+            }
+            previous_section = Some(section_index);
+        }
+    }
+    backgrounds
+}
+
+/// Moves the glyphs of each line of `section_glyphs`, laid out left to right from `sections`,
+/// to their visual order, following the Unicode Bidirectional Algorithm with the base
+/// `direction`.
+///
+/// The glyphs of a line keep the space taken by the line, so the size of the text and the
+/// alignment of its lines are unchanged. The glyphs of a grapheme, such as a letter and its
+/// combining marks, are moved together.
+pub(crate) fn reorder_bidi_lines<T>(
+    section_glyphs: &mut [SectionGlyph],
+    sections: &[SectionText],
+    get_scaled_font: impl Fn(usize) -> PxScaleFont<T>,
+    direction: TextDirection,
+) where
+    T: ab_glyph::Font,
+{
+    let default_level = match direction {
+        TextDirection::Auto => None,
+        TextDirection::LeftToRight => Some(Level::ltr()),
+        TextDirection::RightToLeft => Some(Level::rtl()),
+    };
+    let mut offsets = Vec::with_capacity(sections.len());
+    let mut text = String::new();
+    for section in sections {
+        offsets.push(text.len());
+        text.push_str(section.text);
+    }
+    // Without right-to-left characters, the glyphs are already in their visual order
+    let is_rtl = |c| {
+        matches!(
+            bidi_class(c),
+            BidiClass::R | BidiClass::AL | BidiClass::RLE | BidiClass::RLO | BidiClass::RLI
+        )
+    };
+    if default_level != Some(Level::rtl()) && !text.chars().any(is_rtl) {
+        return;
+    }
+
+    let bidi_info = BidiInfo::new(&text, default_level);
+    let offset = |sg: &SectionGlyph| offsets[sg.section_index] + sg.byte_index;
+    // The glyphs of a line are consecutive, in logical order, and share their baseline
+    for line in section_glyphs.chunk_by_mut(|a, b| a.glyph.position.y == b.glyph.position.y) {
+        let start = offset(&line[0]);
+        let Some(paragraph) = bidi_info
+            .paragraphs
+            .iter()
+            .find(|paragraph| paragraph.range.contains(&start))
+        else {
+            continue;
+        };
+        let last = offset(&line[line.len() - 1]);
+        let end = text[last..]
+            .chars()
+            .next()
+            .map_or(last, |c| last + c.len_utf8())
+            .min(paragraph.range.end);
+        let (levels, runs) = bidi_info.visual_runs(paragraph, start..end);
+        if runs.len() == 1 && levels[runs[0].start].is_ltr() {
+            continue;
+        }
+
+        // The ranges of the glyphs of each grapheme, in logical order
+        let boundaries: Vec<usize> = text[start..end]
+            .grapheme_indices(true)
+            .map(|(index, _)| start + index)
+            .collect();
+        let mut clusters: Vec<std::ops::Range<usize>> = Vec::new();
+        for (index, sg) in line.iter().enumerate() {
+            match clusters.last_mut() {
+                Some(cluster) if boundaries.binary_search(&offset(sg)).is_err() => {
+                    cluster.end = index + 1;
+                }
+                _ => clusters.push(index..index + 1),
+            }
+        }
+
+        let mut positions: Vec<f32> = line.iter().map(|sg| sg.glyph.position.x).collect();
+        let mut x = positions[0];
+        let mut place = |cluster: &std::ops::Range<usize>| {
+            let left = line[cluster.start].glyph.position.x;
+            for index in cluster.clone() {
+                positions[index] = x + line[index].glyph.position.x - left;
+            }
+            let last = &line[cluster.end - 1];
+            x += last.glyph.position.x
+                + get_scaled_font(last.section_index).h_advance(last.glyph.id)
+                - left;
+        };
+        for run in runs {
+            let first =
+                clusters.partition_point(|cluster| offset(&line[cluster.start]) < run.start);
+            let count =
+                clusters[first..].partition_point(|cluster| offset(&line[cluster.start]) < run.end);
+            let run_clusters = clusters[first..first + count].iter();
+            if levels[run.start].is_rtl() {
+                run_clusters.rev().for_each(&mut place);
+            } else {
+                run_clusters.for_each(&mut place);
+            }
+        }
+        for (sg, x) in line.iter_mut().zip(positions) {
+            sg.glyph.position.x = x;
+        }
+    }
+}
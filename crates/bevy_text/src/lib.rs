@@ -7,29 +7,43 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
+mod derived_text;
 mod error;
 mod font;
 mod font_atlas;
 mod font_atlas_set;
 mod font_loader;
 mod glyph_brush;
+mod log_text;
 mod pipeline;
 mod text;
 mod text2d;
+mod text_scale;
+mod theme;
 
+pub use derived_text::*;
 pub use error::*;
 pub use font::*;
 pub use font_atlas::*;
 pub use font_atlas_set::*;
 pub use font_loader::*;
 pub use glyph_brush::*;
+pub use log_text::*;
 pub use pipeline::*;
 pub use text::*;
 pub use text2d::*;
+pub use text_scale::*;
+pub use theme::*;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Font, JustifyText, Text, Text2dBundle, TextError, TextSection, TextStyle};
+    pub use crate::{
+        ColorRole, DefaultFont, DerivedText, DetectTextChanges, FailedText, Font,
+        GlyphAntialiasing, JustifyText, LogText, SectionTruncation, Text, Text2dBundle,
+        TextAtlasSettings, TextDirection, TextError, TextGlyphColor, TextOverflow, TextRenderError,
+        TextRenderSettings, TextScale, TextScaleOverride, TextSection, TextStyle, TextVariant,
+        Theme, ThemedColor, WhiteSpace,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -37,6 +51,7 @@ use bevy_asset::AssetApp;
 #[cfg(feature = "default_font")]
 use bevy_asset::{load_internal_binary_asset, Handle};
 use bevy_ecs::prelude::*;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
     camera::CameraUpdateSystem, view::VisibilitySystems, ExtractSchedule, RenderApp,
 };
@@ -70,6 +85,38 @@ impl Default for TextSettings {
     }
 }
 
+/// Settings of how text is rasterized and drawn.
+///
+/// Text is laid out again when these settings change, rasterizing its glyphs anew.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct TextRenderSettings {
+    /// How the glyphs are antialiased.
+    pub antialiasing: GlyphAntialiasing,
+}
+
+/// Settings of how glyphs are packed into the font atlases.
+///
+/// The font atlases are cleared when these settings change, so that their glyphs are rasterized
+/// and packed again, and text is laid out again.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct TextAtlasSettings {
+    /// The number of transparent pixels kept around each glyph in the font atlases, so that
+    /// sampling a glyph with filtering, or from a smaller mip level, doesn't bleed its neighbors
+    /// into it.
+    ///
+    /// Glyphs are always rasterized with a pixel wide transparent border, so the minimum, and
+    /// default, padding is 1: smaller values are treated as 1.
+    pub glyph_padding: u16,
+}
+
+impl Default for TextAtlasSettings {
+    fn default() -> Self {
+        Self { glyph_padding: 1 }
+    }
+}
+
 /// Text is rendered for two different view projections, a [`Text2dBundle`] is rendered with a
 /// `BottomToTop` y axis, while UI is rendered with a `TopToBottom` y axis. This matters for text because
 /// the glyph positioning is different in either layout.
@@ -86,10 +133,28 @@ impl Plugin for TextPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<Font>()
             .register_type::<Text>()
+            .register_type::<SerializedText>()
             .register_type::<Text2dBounds>()
+            .register_type::<DefaultFont>()
+            .register_type::<TextRenderError>()
+            .register_type::<LogText>()
+            .register_type::<Theme>()
+            .register_type::<ThemedColor>()
+            .register_type::<TextRenderSettings>()
+            .register_type::<TextAtlasSettings>()
+            .register_type::<TextVariant>()
+            .register_type::<TextDirection>()
+            .register_type::<TextOverflow>()
+            .register_type::<TextScale>()
+            .register_type::<TextScaleOverride>()
             .init_asset_loader::<FontLoader>()
             .init_resource::<TextSettings>()
+            .init_resource::<TextRenderSettings>()
+            .init_resource::<TextAtlasSettings>()
+            .init_resource::<TextScale>()
+            .init_resource::<DefaultFont>()
             .init_resource::<FontAtlasSets>()
+            .init_resource::<Theme>()
             .insert_resource(TextPipeline::default())
             .add_systems(
                 PostUpdate,
@@ -99,12 +164,17 @@ impl Plugin for TextPlugin {
                         .after(update_text2d_layout),
                     update_text2d_layout
                         .after(font_atlas_set::remove_dropped_font_atlas_sets)
+                        .after(update_glyph_padding)
                         // Potential conflict: `Assets<Image>`
                         // In practice, they run independently since `bevy_render::camera_update_system`
                         // will only ever observe its own render target, and `update_text2d_layout`
                         // will never modify a pre-existing `Image` asset.
                         .ambiguous_with(CameraUpdateSystem),
                     remove_dropped_font_atlas_sets,
+                    update_glyph_padding,
+                    update_themed_text_colors.before(update_text2d_layout),
+                    update_log_texts.before(update_text2d_layout),
+                    update_derived_texts.before(update_text2d_layout),
                 ),
             );
 
@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    prelude::{Component, DetectChanges},
+    reflect::ReflectComponent,
+    system::Query,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+use crate::{Text, TextSection, TextStyle};
+
+/// Turns a [`Text`] into a log, such as an in-game console, with one section per line.
+///
+/// Lines pushed with [`LogText::push_line`] are appended at the bottom of the text by
+/// [`update_log_texts`], and the oldest lines are dropped past [`max_lines`](Self::max_lines),
+/// so the memory used by the text stays bounded however many lines are pushed.
+///
+/// Appending lines doesn't only lay out the new lines at the bottom: the whole [`Text`] is laid
+/// out again, which takes time proportional to its number of lines. To bound that time, the
+/// pending lines are appended at most once per frame, however many are pushed, and the number of
+/// lines is capped at [`LogText::MAX_LINES`].
+///
+/// To keep the newest line visible, place the text at the bottom of a node that clips its overflow.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct LogText {
+    /// The maximum number of lines kept in the [`Text`].
+    ///
+    /// A value above [`LogText::MAX_LINES`] is silently clamped to it: such a log keeps
+    /// [`LogText::MAX_LINES`] lines.
+    pub max_lines: usize,
+    /// The style of the new lines.
+    pub style: TextStyle,
+    #[reflect(ignore)]
+    pending: VecDeque<String>,
+}
+
+impl Default for LogText {
+    fn default() -> Self {
+        Self::new(100, TextStyle::default())
+    }
+}
+
+impl LogText {
+    /// The maximum number of lines of any [`LogText`], which bounds the time taken to lay it out.
+    pub const MAX_LINES: usize = 1000;
+
+    /// Creates a [`LogText`] keeping at most `max_lines` lines, written with `style`.
+    ///
+    /// `max_lines` is clamped to [`LogText::MAX_LINES`] when the lines are appended.
+    pub fn new(max_lines: usize, style: TextStyle) -> Self {
+        Self {
+            max_lines,
+            style,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Queues a line to be appended at the bottom of the [`Text`].
+    ///
+    /// Pending lines past [`max_lines`](Self::max_lines) are dropped right away, since they
+    /// would be dropped from the text anyway.
+    pub fn push_line(&mut self, line: &str) {
+        let max_lines = self.line_cap();
+        if max_lines == 0 {
+            return;
+        }
+        let mut pending = if self.pending.len() >= max_lines {
+            // Reuse the allocation of the oldest pending line
+            self.pending.pop_front().unwrap()
+        } else {
+            String::new()
+        };
+        pending.clear();
+        pending.push_str(line);
+        self.pending.push_back(pending);
+    }
+
+    /// Appends the pending lines to `text`, dropping its oldest lines past [`max_lines`](Self::max_lines).
+    ///
+    /// Returns `true` if `text` was changed.
+    pub fn apply(&mut self, text: &mut Text) -> bool {
+        let max_lines = self.line_cap();
+        if self.pending.is_empty() && text.sections.len() <= max_lines {
+            return false;
+        }
+        while self.pending.len() > max_lines {
+            self.pending.pop_front();
+        }
+
+        // Recycle the sections of the dropped lines for the new ones, rather than allocating new ones
+        let dropped = (text.sections.len() + self.pending.len()).saturating_sub(max_lines);
+        text.sections.rotate_left(dropped);
+        let kept = text.sections.len() - dropped;
+        text.sections.truncate(kept + self.pending.len());
+        if let Some(first) = text.sections.first_mut() {
+            if first.value.starts_with('\n') {
+                first.value.remove(0);
+            }
+        }
+
+        for (index, line) in (kept..).zip(self.pending.drain(..)) {
+            if index == text.sections.len() {
+                text.sections
+                    .push(TextSection::from_style(self.style.clone()));
+            }
+            let section = &mut text.sections[index];
+            section.style = self.style.clone();
+            section.value.clear();
+            // Lines are separated by a line break at the start of every line but the first one
+            if index > 0 {
+                section.value.push('\n');
+            }
+            section.value.push_str(&line);
+        }
+        true
+    }
+
+    /// The number of lines kept, [`max_lines`](Self::max_lines) capped at [`LogText::MAX_LINES`].
+    fn line_cap(&self) -> usize {
+        self.max_lines.min(Self::MAX_LINES)
+    }
+}
+
+/// Appends the pending lines of each [`LogText`] to its [`Text`].
+///
+/// Only the [`Text`] of logs with new lines is marked as changed.
+pub fn update_log_texts(mut query: Query<(&mut LogText, &mut Text)>) {
+    for (mut log, mut text) in &mut query {
+        if !log.is_changed() {
+            continue;
+        }
+        if log
+            .bypass_change_detection()
+            .apply(text.bypass_change_detection())
+        {
+            text.set_changed();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_lines_are_dropped() {
+        let mut log = LogText::new(3, TextStyle::default());
+        let mut text = Text::default();
+
+        for i in 0..10_000 {
+            log.push_line(&format!("line {i}"));
+            if i % 7 == 0 {
+                log.apply(&mut text);
+            }
+        }
+        assert!(log.pending.len() <= 3);
+        log.apply(&mut text);
+
+        let lines: Vec<_> = text
+            .sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect();
+        assert_eq!(lines, ["line 9997", "\nline 9998", "\nline 9999"]);
+        assert!(!log.apply(&mut text));
+
+        log.max_lines = 1;
+        assert!(log.apply(&mut text));
+        assert_eq!(text.sections.len(), 1);
+        assert_eq!(text.sections[0].value, "line 9999");
+    }
+
+    #[test]
+    fn lines_are_capped() {
+        let mut log = LogText::new(usize::MAX, TextStyle::default());
+        let mut text = Text::default();
+        for i in 0..LogText::MAX_LINES + 10 {
+            log.push_line(&format!("line {i}"));
+        }
+        log.apply(&mut text);
+        assert_eq!(text.sections.len(), LogText::MAX_LINES);
+        assert_eq!(text.sections[0].value, "line 10");
+    }
+}
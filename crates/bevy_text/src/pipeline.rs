@@ -1,19 +1,39 @@
 use crate::{
-    compute_text_bounds, error::TextError, glyph_brush::GlyphBrush, scale_value, BreakLineOn, Font,
-    FontAtlasSets, JustifyText, PositionedGlyph, Text, TextSection, TextSettings, YAxisOrientation,
+    compute_line_metrics, compute_section_backgrounds, compute_text_bounds, error::TextError,
+    glyph_brush::GlyphBrush, reorder_bidi_lines, DefaultFont, Font, FontAtlasSets,
+    GlyphAntialiasing, InlineImageAlign, JustifyText, PositionedGlyph, SectionImage, Text,
+    TextOverflow, TextSection, TextSettings, TextVariant, YAxisOrientation,
 };
-use ab_glyph::PxScale;
+use ab_glyph::{Font as _, PxScale};
 use bevy_asset::{AssetId, Assets, Handle};
 use bevy_ecs::component::Component;
 use bevy_ecs::prelude::ReflectComponent;
 use bevy_ecs::system::Resource;
-use bevy_math::Vec2;
+use bevy_math::{Rect, Vec2};
 use bevy_reflect::prelude::ReflectDefault;
 use bevy_reflect::Reflect;
 use bevy_render::texture::Image;
 use bevy_sprite::TextureAtlasLayout;
 use bevy_utils::HashMap;
 use glyph_brush_layout::{FontId, GlyphPositioner, SectionGeometry, SectionText, ToSectionText};
+use std::{borrow::Cow, ops::Range};
+
+/// How [`TextPipeline::queue_text`] lays out a [`Text`], besides the settings of the text itself.
+pub struct TextLayoutOptions {
+    /// The product of every factor scaling the font sizes, see [`TextStyle::scaled_font_size`].
+    ///
+    /// [`TextStyle::scaled_font_size`]: crate::TextStyle::scaled_font_size
+    pub scale_factor: f32,
+    /// The scale factor converting logical pixels to physical pixels.
+    pub window_scale_factor: f32,
+    /// The size of the area the text is laid out in, in physical pixels, soft wrapping at its
+    /// width. Either dimension can be infinite.
+    pub bounds: Vec2,
+    /// The width in physical pixels past which the lines are truncated by the [`TextOverflow`].
+    pub max_line_width: f32,
+    pub antialiasing: GlyphAntialiasing,
+    pub y_axis_orientation: YAxisOrientation,
+}
 
 #[derive(Default, Resource)]
 pub struct TextPipeline {
@@ -27,8 +47,81 @@ pub struct TextPipeline {
 #[derive(Component, Clone, Default, Debug, Reflect)]
 #[reflect(Component, Default)]
 pub struct TextLayoutInfo {
+    /// The glyphs of the text, in layout order, also iterated with [`TextLayoutInfo::iter_glyphs`].
     pub glyphs: Vec<PositionedGlyph>,
     pub logical_size: Vec2,
+    /// How the glyphs were antialiased when they were rasterized.
+    pub antialiasing: GlyphAntialiasing,
+    /// The highlighted areas of the sections with a
+    /// [`TextStyle::background_color`](crate::TextStyle::background_color), drawn behind the
+    /// glyphs.
+    pub backgrounds: Vec<SectionBackground>,
+    /// The boxes of the lines of the text, from the first to the last, including the empty lines
+    /// left by line breaks such as a trailing `\n`.
+    pub lines: Vec<LineMetrics>,
+}
+
+/// The highlighted area of a section on one line, in the coordinates of the
+/// [`PositionedGlyph`]s.
+///
+/// It spans the advances of the glyphs of the section on that line, and the ascent and descent
+/// of the fonts of the line.
+#[derive(Clone, Debug, Reflect)]
+pub struct SectionBackground {
+    pub section_index: usize,
+    pub rect: Rect,
+}
+
+/// The box of a line of text, in the coordinates of the [`PositionedGlyph`]s.
+///
+/// It describes the layout of the line, without the [`TextSection::offset`]s its glyphs are drawn
+/// with.
+#[derive(Clone, Debug, Reflect)]
+pub struct LineMetrics {
+    /// The height of the baseline of the line.
+    pub baseline: f32,
+    /// The height of the tallest font of the line above its baseline.
+    pub ascent: f32,
+    /// The depth of the deepest font of the line below its baseline, as a positive distance.
+    pub descent: f32,
+    /// The area of the line, spanning the advances of its glyphs once justified, and its ascent
+    /// and descent.
+    ///
+    /// An empty line has no width, and is placed at the anchor of its [`JustifyText`].
+    pub rect: Rect,
+    /// The range of the glyphs of the line in [`TextLayoutInfo::glyphs`], empty for an empty line.
+    ///
+    /// Their [`PositionedGlyph::section_index`] gives the sections on the line.
+    pub glyphs: Range<usize>,
+}
+
+impl TextLayoutInfo {
+    /// Iterates over the [`PositionedGlyph`]s of the text, in layout order.
+    ///
+    /// Their [`PositionedGlyph::byte_index`] maps them back to the characters of the text:
+    ///
+    /// ```
+    /// # use bevy_text::{Text, TextLayoutInfo};
+    /// fn print_glyphs(text: &Text, layout: &TextLayoutInfo) {
+    ///     let displayed_values = text.displayed_values();
+    ///     for glyph in layout.iter_glyphs() {
+    ///         let character = glyph.character(&displayed_values);
+    ///         println!("{character:?} at {}", glyph.position);
+    ///     }
+    /// }
+    /// ```
+    pub fn iter_glyphs(&self) -> std::slice::Iter<'_, PositionedGlyph> {
+        self.glyphs.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TextLayoutInfo {
+    type Item = &'a PositionedGlyph;
+    type IntoIter = std::slice::Iter<'a, PositionedGlyph>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_glyphs()
+    }
 }
 
 impl TextPipeline {
@@ -40,52 +133,113 @@ impl TextPipeline {
             .or_insert_with(|| brush.add_font(handle.id(), font.font.clone()))
     }
 
+    /// Lays out `text` with its justification, line breaks, white space, direction and overflow,
+    /// and adds its glyphs to the font atlases.
     #[allow(clippy::too_many_arguments)]
     pub fn queue_text(
         &mut self,
         fonts: &Assets<Font>,
-        sections: &[TextSection],
-        scale_factor: f32,
-        text_alignment: JustifyText,
-        linebreak_behavior: BreakLineOn,
-        bounds: Vec2,
+        default_font: &DefaultFont,
+        text: &Text,
+        options: TextLayoutOptions,
         font_atlas_sets: &mut FontAtlasSets,
         texture_atlases: &mut Assets<TextureAtlasLayout>,
         textures: &mut Assets<Image>,
         text_settings: &TextSettings,
-        y_axis_orientation: YAxisOrientation,
     ) -> Result<TextLayoutInfo, TextError> {
-        let mut scaled_fonts = Vec::with_capacity(sections.len());
-        let sections = sections
+        let TextLayoutOptions {
+            scale_factor,
+            window_scale_factor,
+            bounds,
+            max_line_width,
+            antialiasing,
+            y_axis_orientation,
+        } = options;
+        let sections: &[TextSection] = &text.sections;
+        let values = text
+            .white_space
+            .apply(sections.iter().map(TextSection::displayed_value));
+        // The glyph brush lays out runs rather than sections, see `section_runs`
+        let runs = section_runs(sections, &values);
+        let highlighted: Vec<bool> = runs
+            .iter()
+            .map(|run| sections[run.section_index].style.background_color.is_some())
+            .collect();
+        let mut scaled_fonts = Vec::with_capacity(runs.len());
+        let mut section_images = Vec::with_capacity(runs.len());
+        let mut baseline_shifts = Vec::with_capacity(runs.len());
+        let section_texts = runs
             .iter()
-            .map(|section| {
-                let font = fonts
-                    .get(&section.style.font)
-                    .ok_or(TextError::NoSuchFont)?;
-                let font_id = self.get_or_insert_font_id(&section.style.font, font);
-                let font_size = scale_value(section.style.font_size, scale_factor);
+            .map(|run| {
+                let section = &sections[run.section_index];
+                let handle = default_font.resolve(&section.style.font);
+                let font = fonts.get(handle).ok_or(TextError::NoSuchFont)?;
+                let font_id = self.get_or_insert_font_id(handle, font);
+                let scale = run.scale(section_scale(
+                    section,
+                    font,
+                    scale_factor,
+                    window_scale_factor,
+                ));
+                let font_size = section
+                    .style
+                    .scaled_font_size(scale_factor, window_scale_factor);
 
-                scaled_fonts.push(ab_glyph::Font::as_scaled(&font.font, font_size));
+                scaled_fonts.push(ab_glyph::Font::as_scaled(&font.font, scale));
+                baseline_shifts.push(run.baseline_shift * font_size);
+                section_images.push(section.image.as_ref().map(|image| {
+                    let size = image.size * scale_factor;
+                    SectionImage {
+                        image: image.image.id(),
+                        size,
+                        bottom: inline_image_bottom(image.align, &font.font, font_size, size.y),
+                        font_size,
+                    }
+                }));
 
                 let section = SectionText {
                     font_id,
-                    scale: PxScale::from(font_size),
-                    text: &section.value,
+                    scale,
+                    text: &run.text,
                 };
 
                 Ok(section)
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let section_glyphs =
-            self.brush
-                .compute_glyphs(&sections, bounds, text_alignment, linebreak_behavior)?;
+        let mut section_glyphs = self.brush.compute_glyphs(
+            &section_texts,
+            bounds,
+            text.justify,
+            text.linebreak_behavior,
+        )?;
 
         if section_glyphs.is_empty() {
-            return Ok(TextLayoutInfo::default());
+            return Ok(TextLayoutInfo {
+                antialiasing,
+                ..Default::default()
+            });
         }
 
-        let size = compute_text_bounds(&section_glyphs, |index| scaled_fonts[index]).size();
+        self.brush.truncate_lines(
+            &mut section_glyphs,
+            &section_texts,
+            &section_images,
+            max_line_width,
+            text.overflow,
+            &text.ellipsis,
+            text.justify,
+        );
+
+        reorder_bidi_lines(
+            &mut section_glyphs,
+            &section_texts,
+            |index| scaled_fonts[index],
+            text.direction,
+        );
+
+        let text_bounds = compute_text_bounds(&section_glyphs, |index| scaled_fonts[index]);
+        let size = text_bounds.size();
 
         let h_limit = if bounds.x.is_finite() {
             bounds.x
@@ -93,36 +247,185 @@ impl TextPipeline {
             size.x
         };
 
-        let h_anchor = match text_alignment {
+        let h_anchor = match text.justify {
             JustifyText::Left => 0.0,
             JustifyText::Center => h_limit * 0.5,
             JustifyText::Right => h_limit * 1.0,
         }
         .floor();
 
-        let glyphs = self.brush.process_glyphs(
+        let mut backgrounds = if highlighted.contains(&true) {
+            compute_section_backgrounds(
+                &section_glyphs,
+                |index| scaled_fonts[index],
+                |index| highlighted[index],
+                text_bounds,
+                &y_axis_orientation,
+                h_anchor,
+            )
+        } else {
+            Vec::new()
+        };
+        for background in &mut backgrounds {
+            background.section_index = runs[background.section_index].section_index;
+        }
+
+        let mut lines = compute_line_metrics(
+            &section_glyphs,
+            &section_texts,
+            |index| scaled_fonts[index],
+            text_bounds,
+            &y_axis_orientation,
+            h_anchor,
+        );
+
+        let mut glyphs = self.brush.process_glyphs(
             section_glyphs,
-            &sections,
+            &section_texts,
+            &section_images,
+            &baseline_shifts,
             font_atlas_sets,
             fonts,
             texture_atlases,
             textures,
             text_settings,
+            antialiasing,
             y_axis_orientation,
             h_anchor,
+            &mut lines,
         )?;
+        for glyph in &mut glyphs {
+            let run = &runs[glyph.section_index];
+            glyph.section_index = run.section_index;
+            glyph.byte_index += run.byte_offset;
+        }
 
         Ok(TextLayoutInfo {
             glyphs,
             logical_size: size,
+            antialiasing,
+            backgrounds,
+            lines,
         })
     }
 }
 
+/// A run of the displayed value of a section, laid out with the same scale.
+///
+/// A section is split into several runs by [`TextVariant::SmallCaps`], for its lowercase letters
+/// to be laid out as smaller capitals.
+struct SectionRun<'a> {
+    section_index: usize,
+    /// The offset of the run in the displayed value of its section, in bytes.
+    byte_offset: usize,
+    text: Cow<'a, str>,
+    /// The scale of the glyphs of the run, relative to the scale of its section.
+    relative_scale: f32,
+    /// The height the baseline of the run is raised by, relative to the font size of its section.
+    baseline_shift: f32,
+}
+
+impl SectionRun<'_> {
+    fn scale(&self, section_scale: PxScale) -> PxScale {
+        PxScale {
+            x: section_scale.x * self.relative_scale,
+            y: section_scale.y * self.relative_scale,
+        }
+    }
+}
+
+/// Splits the `values` displayed by `sections` into the runs of text laid out with the same
+/// scale, following the [`TextVariant`] of each section.
+///
+/// The placeholder glyph of an [`InlineImage`](crate::InlineImage) is never scaled.
+fn section_runs<'a>(sections: &[TextSection], values: &'a [Cow<str>]) -> Vec<SectionRun<'a>> {
+    let mut runs = Vec::with_capacity(sections.len());
+    for (section_index, (section, value)) in sections.iter().zip(values).enumerate() {
+        let variant = if section.image.is_some() {
+            TextVariant::Normal
+        } else {
+            section.style.variant
+        };
+        runs.extend(
+            variant
+                .runs(value)
+                .into_iter()
+                .map(|(byte_offset, text, relative_scale)| SectionRun {
+                    section_index,
+                    byte_offset,
+                    text,
+                    relative_scale,
+                    baseline_shift: variant.baseline_shift(),
+                }),
+        );
+    }
+    runs
+}
+
+/// Returns the scale of the glyphs of `section`, in physical pixels.
+///
+/// See [`TextStyle::scaled_font_size`](crate::TextStyle::scaled_font_size) for the scale factors.
+///
+/// The placeholder glyph of an [`InlineImage`](crate::InlineImage) is stretched to the size of the
+/// image: as wide as the image, with an ascent and a descent fitting it, so that the layout makes
+/// room for it.
+fn section_scale(
+    section: &TextSection,
+    font: &Font,
+    scale_factor: f32,
+    window_scale_factor: f32,
+) -> PxScale {
+    let font_size = section
+        .style
+        .scaled_font_size(scale_factor, window_scale_factor);
+    let Some(image) = &section.image else {
+        return PxScale::from(font_size);
+    };
+    let font = &font.font;
+    let size = image.size * scale_factor;
+    let bottom = inline_image_bottom(image.align, font, font_size, size.y);
+    let height = font.height_unscaled();
+    let ascent = font.ascent_unscaled();
+    let descent = font.descent_unscaled();
+    let advance = font.h_advance_unscaled(font.glyph_id('\u{FFFC}'));
+    let above = (bottom + size.y).max(0.) * height / ascent;
+    let below = if descent < 0. {
+        -bottom * height / -descent
+    } else {
+        0.
+    };
+    PxScale {
+        x: if advance > 0. {
+            size.x * height / advance
+        } else {
+            font_size
+        },
+        y: above.max(below).max(f32::EPSILON),
+    }
+}
+
+/// Returns the height of the bottom of an inline image above the baseline, in the unit of
+/// `font_size` and `image_height`.
+fn inline_image_bottom(
+    align: InlineImageAlign,
+    font: &impl ab_glyph::Font,
+    font_size: f32,
+    image_height: f32,
+) -> f32 {
+    match align {
+        InlineImageAlign::Baseline => 0.,
+        InlineImageAlign::Center => {
+            let center = (font.ascent_unscaled() + font.descent_unscaled()) / 2. * font_size
+                / font.height_unscaled();
+            center - image_height / 2.
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TextMeasureSection {
     pub text: Box<str>,
-    pub scale: f32,
+    pub scale: PxScale,
     pub font_id: FontId,
 }
 
@@ -132,7 +435,11 @@ pub struct TextMeasureInfo {
     pub sections: Box<[TextMeasureSection]>,
     pub justification: JustifyText,
     pub linebreak_behavior: glyph_brush_layout::BuiltInLineBreaker,
+    /// The min-content size of the text, wrapped at every opportunity: its width is the width
+    /// of its widest unbreakable word.
     pub min: Vec2,
+    /// The max-content size of the text, without any soft wrapping: its width is the width
+    /// of its widest line.
     pub max: Vec2,
 }
 
@@ -140,19 +447,29 @@ impl TextMeasureInfo {
     pub fn from_text(
         text: &Text,
         fonts: &Assets<Font>,
+        default_font: &DefaultFont,
         scale_factor: f32,
+        window_scale_factor: f32,
     ) -> Result<TextMeasureInfo, TextError> {
         let sections = &text.sections;
-        let mut auto_fonts = Vec::with_capacity(sections.len());
-        let mut out_sections = Vec::with_capacity(sections.len());
-        for (i, section) in sections.iter().enumerate() {
-            match fonts.get(&section.style.font) {
+        let values = text.displayed_values();
+        let runs = section_runs(sections, &values);
+        let mut auto_fonts = Vec::with_capacity(runs.len());
+        let mut out_sections = Vec::with_capacity(runs.len());
+        for (i, run) in runs.iter().enumerate() {
+            let section = &sections[run.section_index];
+            match fonts.get(default_font.resolve(&section.style.font)) {
                 Some(font) => {
                     auto_fonts.push(font.font.clone());
                     out_sections.push(TextMeasureSection {
                         font_id: FontId(i),
-                        scale: scale_value(section.style.font_size, scale_factor),
-                        text: section.value.clone().into_boxed_str(),
+                        scale: run.scale(section_scale(
+                            section,
+                            font,
+                            scale_factor,
+                            window_scale_factor,
+                        )),
+                        text: run.text.as_ref().into(),
                     });
                 }
                 None => return Err(TextError::NoSuchFont),
@@ -201,8 +518,7 @@ impl TextMeasureInfo {
 
         compute_text_bounds(&section_glyphs, |index| {
             let font = &self.fonts[index];
-            let font_size = self.sections[index].scale;
-            ab_glyph::Font::into_scaled(font, font_size)
+            ab_glyph::Font::into_scaled(font, self.sections[index].scale)
         })
         .size()
     }
@@ -212,8 +528,240 @@ impl ToSectionText for TextMeasureSection {
     fn to_section_text(&self) -> SectionText<'_> {
         SectionText {
             text: &self.text,
-            scale: PxScale::from(self.scale),
+            scale: self.scale,
             font_id: self.font_id,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BreakLineOn, InlineImage, TextDirection, TextStyle};
+
+    #[test]
+    fn measure_min_and_max_content() {
+        let mut fonts = Assets::<Font>::default();
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        fonts.insert(AssetId::default(), font);
+
+        let measure = |value: &str| {
+            let text = Text::from_section(value, TextStyle::default());
+            TextMeasureInfo::from_text(&text, &fonts, &DefaultFont::default(), 1.0, 1.0).unwrap()
+        };
+
+        // The font is monospaced, so "hello bevy" is about twice as wide as "hello"
+        let info = measure("hello bevy");
+        assert!((info.max.x / info.min.x - 2.0).abs() < 0.1);
+        assert!((info.min.y / info.max.y - 2.0).abs() < 0.1);
+
+        // Hard line breaks apply to both
+        let info = measure("hello\nbevy!");
+        assert!((info.max.x - info.min.x).abs() < f32::EPSILON);
+        assert!((info.max.y - info.min.y).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn measure_inline_image() {
+        let mut fonts = Assets::<Font>::default();
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        fonts.insert(AssetId::default(), font);
+
+        let measure = |sections: Vec<TextSection>| {
+            let text = Text::from_sections(sections);
+            TextMeasureInfo::from_text(&text, &fonts, &DefaultFont::default(), 1.0, 1.0).unwrap()
+        };
+
+        let style = TextStyle::default();
+        let plain = measure(vec![TextSection::new("hello", style.clone())]);
+        let image = InlineImage::new(Handle::default(), Vec2::new(100.0, 60.0));
+        let info = measure(vec![
+            TextSection::new("hello", style.clone()),
+            TextSection::inline_image(image, style),
+        ]);
+
+        // The image advances the text by its width, and makes the line tall enough to fit it
+        assert!((info.max.x - plain.max.x - 100.0).abs() < 1.0);
+        assert!(info.max.y > 60.0);
+    }
+
+    #[test]
+    fn measure_text_variants() {
+        let mut fonts = Assets::<Font>::default();
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        fonts.insert(AssetId::default(), font);
+
+        let measure = |variant| {
+            let text = Text::from_sections([
+                TextSection::new("10", TextStyle::default()),
+                TextSection::new(
+                    "ab",
+                    TextStyle {
+                        variant,
+                        ..Default::default()
+                    },
+                ),
+            ]);
+            TextMeasureInfo::from_text(&text, &fonts, &DefaultFont::default(), 1.0, 1.0).unwrap()
+        };
+
+        let normal = measure(TextVariant::Normal);
+        let superscript = measure(TextVariant::superscript());
+        let small_caps = measure(TextVariant::small_caps());
+        // The scaled glyphs are narrower, and don't make the line taller
+        assert!(superscript.max.x < normal.max.x);
+        assert!(small_caps.max.x < normal.max.x);
+        assert!(superscript.max.x < small_caps.max.x);
+        assert!((superscript.max.y - normal.max.y).abs() < f32::EPSILON);
+        assert!((small_caps.max.y - normal.max.y).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn bidi_keeps_embedded_numbers_left_to_right() {
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        let mut brush = GlyphBrush::default();
+        let font_id = brush.add_font(AssetId::default(), font.font.clone());
+        let scale = PxScale::from(20.0);
+        let scaled_font = ab_glyph::Font::as_scaled(&font.font, scale);
+
+        let visual_order = |value: &str, direction| {
+            let sections = [SectionText {
+                font_id,
+                scale,
+                text: value,
+            }];
+            let mut glyphs = brush
+                .compute_glyphs(
+                    &sections,
+                    Vec2::INFINITY,
+                    JustifyText::Left,
+                    BreakLineOn::NoWrap,
+                )
+                .unwrap();
+            reorder_bidi_lines(&mut glyphs, &sections, |_| scaled_font, direction);
+            glyphs.sort_by(|a, b| a.glyph.position.x.total_cmp(&b.glyph.position.x));
+            glyphs
+                .iter()
+                .filter_map(|sg| value[sg.byte_index..].chars().next())
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+        };
+
+        // The Arabic words are reversed, and read from right to left, but not the number
+        assert_eq!(
+            visual_order("السعر 123 ريال", TextDirection::Auto),
+            "لاير123رعسلا"
+        );
+        // Left-to-right text is left as it is, unless its paragraph is right-to-left
+        assert_eq!(visual_order("abc 123!", TextDirection::Auto), "abc123!");
+        assert_eq!(
+            visual_order("abc 123!", TextDirection::RightToLeft),
+            "!abc123"
+        );
+    }
+
+    #[test]
+    fn backgrounds_of_right_to_left_sections() {
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        let mut brush = GlyphBrush::default();
+        let font_id = brush.add_font(AssetId::default(), font.font.clone());
+        let scale = PxScale::from(20.0);
+        let scaled_font = ab_glyph::Font::as_scaled(&font.font, scale);
+
+        let sections = ["مرحبا", "بك"].map(|text| SectionText {
+            font_id,
+            scale,
+            text,
+        });
+        let mut glyphs = brush
+            .compute_glyphs(
+                &sections,
+                Vec2::INFINITY,
+                JustifyText::Left,
+                BreakLineOn::NoWrap,
+            )
+            .unwrap();
+        reorder_bidi_lines(&mut glyphs, &sections, |_| scaled_font, TextDirection::Auto);
+        let text_bounds = compute_text_bounds(&glyphs, |_| scaled_font);
+        let backgrounds = compute_section_backgrounds(
+            &glyphs,
+            |_| scaled_font,
+            |index| index == 0,
+            text_bounds,
+            &YAxisOrientation::TopToBottom,
+            0.0,
+        );
+
+        // The first section is read first, so it's displayed on the right of the second one
+        assert_eq!(backgrounds.len(), 1);
+        let rect = backgrounds[0].rect;
+        let extent = |section_index| {
+            glyphs
+                .iter()
+                .filter(|sg| sg.section_index == section_index)
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), sg| {
+                    let x = sg.glyph.position.x;
+                    let advance = ab_glyph::ScaleFont::h_advance(&scaled_font, sg.glyph.id);
+                    (min.min(x), max.max(x + advance))
+                })
+        };
+        let (first_min, first_max) = extent(0);
+        let (_, second_max) = extent(1);
+        assert!((rect.min.x - first_min).abs() < 1e-3, "{rect:?}");
+        assert!((rect.max.x - first_max).abs() < 1e-3, "{rect:?}");
+        assert!(second_max <= rect.min.x + 1e-3, "{rect:?}");
+    }
+
+    #[test]
+    fn truncate_overflowing_lines() {
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        let mut brush = GlyphBrush::default();
+        let font_id = brush.add_font(AssetId::default(), font.font.clone());
+        let scale = PxScale::from(20.0);
+        let scaled_font = ab_glyph::Font::as_scaled(&font.font, scale);
+        let dot = ab_glyph::Font::glyph_id(&font.font, '.');
+        let advance = ab_glyph::ScaleFont::h_advance(&scaled_font, dot);
+
+        let truncate = |value: &str, overflow| {
+            let sections = [SectionText {
+                font_id,
+                scale,
+                text: value,
+            }];
+            let mut glyphs = brush
+                .compute_glyphs(
+                    &sections,
+                    Vec2::INFINITY,
+                    JustifyText::Left,
+                    BreakLineOn::NoWrap,
+                )
+                .unwrap();
+            // The font is monospaced, so 6 characters fit
+            brush.truncate_lines(
+                &mut glyphs,
+                &sections,
+                &[None],
+                6.5 * advance,
+                overflow,
+                "...",
+                JustifyText::Left,
+            );
+            glyphs
+                .iter()
+                .map(|sg| match sg.glyph.id {
+                    id if id == dot => '.',
+                    _ => value[sg.byte_index..].chars().next().unwrap(),
+                })
+                .collect::<String>()
+        };
+
+        assert_eq!(truncate("abcdefghij", TextOverflow::Clip), "abcdefghij");
+        assert_eq!(truncate("abcdef", TextOverflow::Ellipsis), "abcdef");
+        assert_eq!(truncate("abcdefghij", TextOverflow::Ellipsis), "abc...");
+        // Both ends of the text are kept
+        assert_eq!(
+            truncate("abcdefghij", TextOverflow::EllipsisMiddle),
+            "a...ij"
+        );
+    }
+}
@@ -1,14 +1,22 @@
-use bevy_asset::Handle;
-use bevy_color::Color;
-use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_asset::{AssetId, Handle};
+use bevy_color::{Color, Mix};
+use bevy_ecs::{change_detection::DetectChangesMut, prelude::Component, reflect::ReflectComponent};
+use bevy_math::Vec2;
 use bevy_reflect::prelude::*;
+use bevy_reflect::serde::{ReflectSerializeAs, SerializeAs};
+use bevy_render::texture::Image;
 use bevy_utils::default;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::Font;
+use crate::{Font, PositionedGlyph};
 
-#[derive(Component, Debug, Clone, Default, Reflect)]
-#[reflect(Component, Default)]
+/// Text made of [`TextSection`]s, laid out and displayed in a UI node or as a `Text2dBundle`.
+///
+/// In scenes, a [`Text`] is serialized as a compact [`SerializedText`].
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, SerializeAs)]
 pub struct Text {
     pub sections: Vec<TextSection>,
     /// The text's internal alignment.
@@ -16,6 +24,40 @@ pub struct Text {
     pub justify: JustifyText,
     /// How the text should linebreak when running out of the bounds determined by `max_size`
     pub linebreak_behavior: BreakLineOn,
+    /// How the whitespace of the sections is displayed.
+    pub white_space: WhiteSpace,
+    /// The width in logical pixels at which the text soft wraps, instead of the width of its
+    /// bounds.
+    ///
+    /// A UI node of this text without a set width hugs the wrapped text, which is aligned within
+    /// the node as if it had been wrapped at its width. When `None`, the text wraps at the width
+    /// of the node, or of the [`Text2dBounds`](crate::Text2dBounds) of a `Text2dBundle`.
+    ///
+    /// This has no effect with [`BreakLineOn::NoWrap`].
+    pub wrap_width: Option<f32>,
+    /// The base direction of the paragraphs of the text, which orders its left-to-right and
+    /// right-to-left runs on each line.
+    pub direction: TextDirection,
+    /// How the lines wider than the text are displayed.
+    pub overflow: TextOverflow,
+    /// The text replacing the truncated graphemes of a line with a [`TextOverflow::Ellipsis`] or
+    /// [`TextOverflow::EllipsisMiddle`] overflow, `"…"` by default.
+    pub ellipsis: Cow<'static, str>,
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            justify: JustifyText::default(),
+            linebreak_behavior: BreakLineOn::default(),
+            white_space: WhiteSpace::default(),
+            wrap_width: None,
+            direction: TextDirection::default(),
+            overflow: TextOverflow::default(),
+            ellipsis: Cow::Borrowed("…"),
+        }
+    }
 }
 
 impl Text {
@@ -36,6 +78,7 @@ impl Text {
     ///         font: font_handle.clone(),
     ///         font_size: 60.0,
     ///         color: Color::WHITE,
+    ///         ..Default::default()
     ///     },
     /// );
     ///
@@ -45,6 +88,7 @@ impl Text {
     ///         font: font_handle,
     ///         font_size: 60.0,
     ///         color: Color::WHITE,
+    ///         ..Default::default()
     ///     },
     /// ) // You can still add text justifaction.
     /// .with_justify(JustifyText::Center);
@@ -73,6 +117,7 @@ impl Text {
     ///             font: font_handle.clone(),
     ///             font_size: 60.0,
     ///             color: BLUE.into(),
+    ///             ..Default::default()
     ///         },
     ///     ),
     ///     TextSection::new(
@@ -81,6 +126,7 @@ impl Text {
     ///             font: font_handle,
     ///             font_size: 60.0,
     ///             color: RED.into(),
+    ///             ..Default::default()
     ///         },
     ///     ),
     /// ]);
@@ -104,12 +150,315 @@ impl Text {
         self.linebreak_behavior = BreakLineOn::NoWrap;
         self
     }
+
+    /// Returns this [`Text`] soft wrapping at `wrap_width` logical pixels, whatever the width of
+    /// its bounds.
+    pub const fn with_wrap_width(mut self, wrap_width: f32) -> Self {
+        self.wrap_width = Some(wrap_width);
+        self
+    }
+
+    /// Returns this [`Text`] with a new [`WhiteSpace`].
+    pub const fn with_white_space(mut self, white_space: WhiteSpace) -> Self {
+        self.white_space = white_space;
+        self
+    }
+
+    /// Returns this [`Text`] with a new [`TextDirection`].
+    pub const fn with_direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns this [`Text`] with a new [`TextOverflow`].
+    pub const fn with_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns this [`Text`] with a new [`ellipsis`](Self::ellipsis).
+    pub fn with_ellipsis(mut self, ellipsis: impl Into<Cow<'static, str>>) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Returns `true` if any section of this [`Text`] uses the [`DefaultFont`](crate::DefaultFont),
+    /// by leaving its [`TextStyle::font`] to the default handle.
+    pub fn uses_default_font(&self) -> bool {
+        self.sections
+            .iter()
+            .any(|section| section.style.font.id() == AssetId::default())
+    }
+
+    /// Returns the values of the sections as they are laid out, after their [`SectionTruncation`]
+    /// and the [`WhiteSpace`] of this [`Text`].
+    ///
+    /// The [`PositionedGlyph::byte_index`] of the glyphs index into these values.
+    ///
+    /// ```
+    /// # use bevy_text::{Text, TextStyle, WhiteSpace};
+    /// let text = Text::from_section("  a    b ", TextStyle::default());
+    /// assert_eq!(text.displayed_values(), ["  a    b "]);
+    /// let text = text.with_white_space(WhiteSpace::Normal);
+    /// assert_eq!(text.displayed_values(), ["a b"]);
+    /// ```
+    pub fn displayed_values(&self) -> Vec<Cow<str>> {
+        self.white_space
+            .apply(self.sections.iter().map(TextSection::displayed_value))
+    }
+
+    /// Returns the position of each of the laid out `glyphs` of this [`Text`] within its section,
+    /// from `0.0` for the first glyph of the section to `1.0` for its last glyph, to color them
+    /// with the [`color_gradient`](TextStyle::color_gradient) of the section.
+    ///
+    /// Returns `None` if no section has a gradient.
+    pub fn glyph_gradient_positions(&self, glyphs: &[PositionedGlyph]) -> Option<Vec<f32>> {
+        if self
+            .sections
+            .iter()
+            .all(|section| section.style.color_gradient.is_none())
+        {
+            return None;
+        }
+        let mut counts = vec![0usize; self.sections.len()];
+        for glyph in glyphs {
+            if let Some(count) = counts.get_mut(glyph.section_index) {
+                *count += 1;
+            }
+        }
+        let mut indices = vec![0usize; self.sections.len()];
+        let positions = glyphs
+            .iter()
+            .map(|glyph| {
+                let Some(index) = indices.get_mut(glyph.section_index) else {
+                    return 0.0;
+                };
+                let last = counts[glyph.section_index].saturating_sub(1).max(1);
+                let position = *index as f32 / last as f32;
+                *index += 1;
+                position
+            })
+            .collect();
+        Some(positions)
+    }
+
+    /// Returns the indices of the laid out `glyphs` of this [`Text`] in the order they're drawn:
+    /// by the [`layer`](TextSection::layer) of their section, then in layout order.
+    ///
+    /// Nothing is allocated unless a section has a layer.
+    pub fn glyph_draw_order(&self, glyphs: &[PositionedGlyph]) -> impl Iterator<Item = usize> {
+        let layered = self.sections.iter().any(|section| section.layer != 0);
+        let mut order = Vec::new();
+        if layered {
+            order.extend(0..glyphs.len());
+            // The sort is stable, keeping the layout order within each layer
+            order.sort_by_key(|&index| {
+                self.sections
+                    .get(glyphs[index].section_index)
+                    .map_or(0, |section| section.layer)
+            });
+        }
+        let layout_order = if layered { 0..0 } else { 0..glyphs.len() };
+        layout_order.chain(order)
+    }
+}
+
+/// The compact form a [`Text`] is serialized as: the values of its sections are joined into one
+/// string, and each distinct style of its sections is only listed once.
+///
+/// The rarely set fields of a section are only serialized if any of them is set.
+///
+/// Fields missing from a scene take their value in [`Text::default`], so scenes saved in the
+/// former, field by field, format of [`Text`] are still loaded.
+#[derive(Debug, Clone, Reflect)]
+#[reflect(Debug, Default)]
+pub struct SerializedText {
+    /// The values of the sections, joined.
+    pub value: String,
+    /// The distinct styles of the sections.
+    pub styles: Vec<TextStyle>,
+    /// The sections, split out of [`value`](Self::value).
+    pub spans: Vec<SerializedTextSection>,
+    /// The sections of a text saved in the former format of [`Text`], which come before the
+    /// [`spans`](Self::spans). Always empty in a text serialized by this version.
+    pub sections: Vec<TextSection>,
+    pub justify: JustifyText,
+    pub linebreak_behavior: BreakLineOn,
+    pub white_space: WhiteSpace,
+    pub wrap_width: Option<f32>,
+    pub direction: TextDirection,
+    pub overflow: TextOverflow,
+    pub ellipsis: String,
+}
+
+impl Default for SerializedText {
+    fn default() -> Self {
+        Text::default().to_proxy()
+    }
+}
+
+/// A [`TextSection`] of a [`SerializedText`].
+#[derive(Debug, Clone, Default, Reflect)]
+#[reflect(Debug, Default)]
+pub struct SerializedTextSection {
+    /// The length of the value of the section in [`SerializedText::value`], in bytes.
+    pub len: usize,
+    /// The index of the style of the section in [`SerializedText::styles`].
+    pub style: usize,
+    /// The [`truncation`](TextSection::truncation), [`layer`](TextSection::layer),
+    /// [`offset`](TextSection::offset) and [`image`](TextSection::image) of the section, unless
+    /// they're all unset.
+    pub extra: Option<(Option<SectionTruncation>, i8, Vec2, Option<InlineImage>)>,
+}
+
+impl SerializeAs for Text {
+    type Proxy = SerializedText;
+
+    fn to_proxy(&self) -> SerializedText {
+        let mut value = String::new();
+        let mut styles: Vec<TextStyle> = Vec::new();
+        let spans = self
+            .sections
+            .iter()
+            .map(|section| {
+                value.push_str(&section.value);
+                let style = match styles.iter().position(|style| *style == section.style) {
+                    Some(index) => index,
+                    None => {
+                        styles.push(section.style.clone());
+                        styles.len() - 1
+                    }
+                };
+                let extra = (section.truncation.is_some()
+                    || section.layer != 0
+                    || section.offset != Vec2::ZERO
+                    || section.image.is_some())
+                .then(|| {
+                    (
+                        section.truncation.clone(),
+                        section.layer,
+                        section.offset,
+                        section.image.clone(),
+                    )
+                });
+                SerializedTextSection {
+                    len: section.value.len(),
+                    style,
+                    extra,
+                }
+            })
+            .collect();
+        SerializedText {
+            value,
+            styles,
+            spans,
+            sections: Vec::new(),
+            justify: self.justify,
+            linebreak_behavior: self.linebreak_behavior,
+            white_space: self.white_space,
+            wrap_width: self.wrap_width,
+            direction: self.direction,
+            overflow: self.overflow,
+            ellipsis: self.ellipsis.to_string(),
+        }
+    }
+
+    fn from_proxy(proxy: SerializedText) -> Self {
+        let mut start = 0;
+        let mut sections = proxy.sections;
+        sections.extend(proxy.spans.into_iter().map(|section| {
+            let end = start.saturating_add(section.len);
+            // Values out of the joined value, or not split at a character, are left empty
+            let value = proxy.value.get(start..end).unwrap_or_default();
+            start = end;
+            let style = proxy.styles.get(section.style).cloned().unwrap_or_default();
+            let (truncation, layer, offset, image) = section.extra.unwrap_or_default();
+            TextSection {
+                truncation,
+                layer,
+                offset,
+                image,
+                ..TextSection::new(value, style)
+            }
+        }));
+        Text {
+            sections,
+            justify: proxy.justify,
+            linebreak_behavior: proxy.linebreak_behavior,
+            white_space: proxy.white_space,
+            wrap_width: proxy.wrap_width,
+            direction: proxy.direction,
+            overflow: proxy.overflow,
+            ellipsis: proxy.ellipsis.into(),
+        }
+    }
+}
+
+/// Methods to update a [`Text`] behind change detection, such as a [`Mut<Text>`](bevy_ecs::change_detection::Mut),
+/// only marking it as changed when its content actually changes.
+///
+/// A changed [`Text`] is laid out again, so this avoids needless layouts when a text is updated
+/// every frame with the same value.
+pub trait DetectTextChanges {
+    /// Sets the value of the section at `index`, triggering change detection only if it differs
+    /// from the current value. Returns `true` if the value was changed.
+    ///
+    /// The allocation of the section's value is reused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no section at `index`.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_text::{DetectTextChanges, Text};
+    /// #[derive(Component)]
+    /// struct Score(u32);
+    ///
+    /// fn update_score(mut query: Query<(&mut Text, &Score)>) {
+    ///     for (mut text, score) in &mut query {
+    ///         text.set_section_if_changed(1, &score.0.to_string());
+    ///     }
+    /// }
+    /// ```
+    fn set_section_if_changed(&mut self, index: usize, value: &str) -> bool;
+}
+
+impl<T: DetectChangesMut<Inner = Text>> DetectTextChanges for T {
+    fn set_section_if_changed(&mut self, index: usize, value: &str) -> bool {
+        let section = &mut self.bypass_change_detection().sections[index];
+        if section.value == value {
+            return false;
+        }
+        section.value.clear();
+        section.value.push_str(value);
+        self.set_changed();
+        true
+    }
 }
 
 #[derive(Debug, Default, Clone, Reflect)]
+#[reflect(Default)]
 pub struct TextSection {
     pub value: String,
     pub style: TextStyle,
+    /// Limits the number of graphemes of [`value`](Self::value) that are displayed.
+    pub truncation: Option<SectionTruncation>,
+    /// The order in which the glyphs of this section are drawn, relative to the other sections of
+    /// the same [`Text`]: sections of a higher layer are drawn on top of those of a lower layer.
+    ///
+    /// Sections of the same layer are drawn in order, so the default of `0` keeps the order of the
+    /// sections. This lets overlapping sections, such as a shadow behind a number, be drawn in a
+    /// chosen order. It doesn't affect the layout of the text.
+    pub layer: i8,
+    /// Moves the glyphs of this section when they're drawn, in logical pixels, without affecting
+    /// the layout of the text. The axes are those of the layout: y points down in UI text, and up
+    /// in `Text2d`.
+    pub offset: Vec2,
+    /// Replaces the value of this section with an image, laid out like a single glyph.
+    ///
+    /// See [`InlineImage`].
+    pub image: Option<InlineImage>,
 }
 
 impl TextSection {
@@ -118,6 +467,10 @@ impl TextSection {
         Self {
             value: value.into(),
             style,
+            truncation: None,
+            layer: 0,
+            offset: Vec2::ZERO,
+            image: None,
         }
     }
 
@@ -126,10 +479,212 @@ impl TextSection {
         Self {
             value: String::new(),
             style,
+            truncation: None,
+            layer: 0,
+            offset: Vec2::ZERO,
+            image: None,
+        }
+    }
+
+    /// Returns this [`TextSection`] with a new [`SectionTruncation`].
+    pub fn with_truncation(mut self, truncation: SectionTruncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Returns this [`TextSection`] drawn in a new [`layer`](Self::layer).
+    pub const fn with_layer(mut self, layer: i8) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Returns this [`TextSection`] drawn with a new [`offset`](Self::offset).
+    pub const fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Create a [`TextSection`] displaying an [`InlineImage`] instead of a value.
+    ///
+    /// The font of the `style` sets the height of the line around the image, and its color tints
+    /// the image.
+    pub fn inline_image(image: InlineImage, style: TextStyle) -> Self {
+        Self {
+            image: Some(image),
+            ..Self::from_style(style)
+        }
+    }
+
+    /// Returns the value of this section as it is laid out, after its [`SectionTruncation`].
+    ///
+    /// The [`WhiteSpace`] of the [`Text`] then applies to it, see [`Text::displayed_values`].
+    ///
+    /// ```
+    /// # use bevy_text::{SectionTruncation, TextSection, TextStyle};
+    /// let section = TextSection::new("assets/textures/rpg/chars/mani.png", TextStyle::default())
+    ///     .with_truncation(SectionTruncation::keep_end(20, "…"));
+    /// assert_eq!(section.displayed_value(), "…/rpg/chars/mani.png");
+    /// ```
+    ///
+    /// The value of a section with an [`InlineImage`] is [`InlineImage::PLACEHOLDER`].
+    pub fn displayed_value(&self) -> Cow<str> {
+        if self.image.is_some() {
+            return Cow::Borrowed(InlineImage::PLACEHOLDER);
+        }
+        match &self.truncation {
+            Some(truncation) => truncation.apply(&self.value),
+            None => Cow::Borrowed(&self.value),
         }
     }
 }
 
+/// An image laid out in a [`Text`] as a single glyph of its [`TextSection`].
+///
+/// The image advances the following glyphs by its width, wraps with the words around it, and
+/// makes its line taller if it doesn't fit in it. It is selected and hit-tested like a glyph.
+///
+/// Like the glyphs, the image is drawn from a font atlas, so its data has to be kept in the main
+/// world and its format has to be the one of the atlases,
+/// [`TextureFormat::Rgba8UnormSrgb`](bevy_render::render_resource::TextureFormat::Rgba8UnormSrgb).
+/// The text is laid out again until the image is loaded.
+///
+/// ```
+/// # use bevy_asset::Handle;
+/// # use bevy_math::Vec2;
+/// # use bevy_text::{InlineImage, Text, TextSection, TextStyle};
+/// # let key_icon = Handle::default();
+/// let text = Text::from_sections([
+///     TextSection::new("Press ", TextStyle::default()),
+///     TextSection::inline_image(
+///         InlineImage::new(key_icon, Vec2::splat(24.)),
+///         TextStyle::default(),
+///     ),
+///     TextSection::new(" to jump", TextStyle::default()),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct InlineImage {
+    /// The image, drawn stretched to [`size`](Self::size).
+    pub image: Handle<Image>,
+    /// The size the image is displayed at, in logical pixels like the font size.
+    pub size: Vec2,
+    /// How the image is aligned vertically with the glyphs of its line.
+    pub align: InlineImageAlign,
+}
+
+impl InlineImage {
+    /// The displayed value of a [`TextSection`] with an [`InlineImage`], the object replacement
+    /// character.
+    ///
+    /// The image is laid out in place of the glyph of this character, so the font of the section
+    /// has to give it a width, as the fallback glyph of most fonts does.
+    pub const PLACEHOLDER: &'static str = "\u{FFFC}";
+
+    /// Creates an [`InlineImage`] of `size`, sitting on the baseline.
+    pub fn new(image: Handle<Image>, size: Vec2) -> Self {
+        Self {
+            image,
+            size,
+            align: InlineImageAlign::Baseline,
+        }
+    }
+
+    /// Returns this [`InlineImage`] with a new [`InlineImageAlign`].
+    pub const fn with_align(mut self, align: InlineImageAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// How an [`InlineImage`] is aligned vertically with the glyphs of its line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum InlineImageAlign {
+    /// The bottom of the image sits on the baseline, like the glyph of a capital letter.
+    #[default]
+    Baseline,
+    /// The image is centered between the ascent and the descent of the font of its section.
+    Center,
+}
+
+/// Truncates the value of a [`TextSection`] to a maximum number of graphemes, replacing the
+/// truncated graphemes with a marker.
+///
+/// The marker is displayed with the style of the section. Sections whose value isn't longer than
+/// [`max_chars`](Self::max_chars) are displayed unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct SectionTruncation {
+    /// The maximum number of graphemes displayed, including the ones of the marker.
+    pub max_chars: usize,
+    /// The text replacing the truncated graphemes, such as `"…"`.
+    pub truncate_marker: String,
+    /// Which end of the value is kept.
+    pub keep: TruncationKeep,
+}
+
+/// Which end of the value of a [`TextSection`] is kept by its [`SectionTruncation`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum TruncationKeep {
+    /// Keeps the start of the value, followed by the marker.
+    #[default]
+    Start,
+    /// Keeps the end of the value, preceded by the marker, such as the name at the end of a path.
+    End,
+}
+
+impl SectionTruncation {
+    /// Creates a [`SectionTruncation`] keeping the start of the value, followed by `truncate_marker`.
+    pub fn keep_start(max_chars: usize, truncate_marker: impl Into<String>) -> Self {
+        Self {
+            max_chars,
+            truncate_marker: truncate_marker.into(),
+            keep: TruncationKeep::Start,
+        }
+    }
+
+    /// Creates a [`SectionTruncation`] keeping the end of the value, preceded by `truncate_marker`.
+    pub fn keep_end(max_chars: usize, truncate_marker: impl Into<String>) -> Self {
+        Self {
+            max_chars,
+            truncate_marker: truncate_marker.into(),
+            keep: TruncationKeep::End,
+        }
+    }
+
+    /// Returns `value` truncated to at most [`max_chars`](Self::max_chars) graphemes.
+    ///
+    /// If the marker alone is longer than that, it is displayed in full.
+    pub fn apply<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        let count = value.graphemes(true).count();
+        if count <= self.max_chars {
+            return Cow::Borrowed(value);
+        }
+        let kept = self
+            .max_chars
+            .saturating_sub(self.truncate_marker.graphemes(true).count());
+        let marker = &self.truncate_marker;
+        Cow::Owned(match self.keep {
+            TruncationKeep::Start => {
+                let end = value
+                    .grapheme_indices(true)
+                    .nth(kept)
+                    .map_or(value.len(), |(index, _)| index);
+                format!("{}{marker}", &value[..end])
+            }
+            TruncationKeep::End => {
+                let start = value
+                    .grapheme_indices(true)
+                    .nth(count - kept)
+                    .map_or(value.len(), |(index, _)| index);
+                format!("{marker}{}", &value[start..])
+            }
+        })
+    }
+}
+
 #[cfg(feature = "default_font")]
 impl From<&str> for TextSection {
     fn from(value: &str) -> Self {
@@ -170,6 +725,30 @@ pub enum JustifyText {
     Right,
 }
 
+/// The base direction of the paragraphs of a [`Text`], as defined by the Unicode Bidirectional
+/// Algorithm ([UAX #9](https://www.unicode.org/reports/tr9/)).
+///
+/// The glyphs of each line are laid out in their visual order: right-to-left runs, such as Arabic
+/// or Hebrew words, are reversed, while the numbers and left-to-right words embedded in them keep
+/// their order. In "السعر 123 ريال", "123" is still read from left to right.
+///
+/// Only the order of the glyphs changes: the [`PositionedGlyph::byte_index`] of each glyph still
+/// refers to its character in the text, and selecting text selects a logical range of it.
+/// The glyphs aren't shaped, so the contextual forms of Arabic letters require a font mapping
+/// them to their presentation forms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum TextDirection {
+    /// The direction of each paragraph is the direction of its first strong character, such as
+    /// a Latin or Arabic letter, and left-to-right if it has none.
+    #[default]
+    Auto,
+    /// The paragraphs are left-to-right.
+    LeftToRight,
+    /// The paragraphs are right-to-left.
+    RightToLeft,
+}
+
 impl From<JustifyText> for glyph_brush_layout::HorizontalAlign {
     fn from(val: JustifyText) -> Self {
         match val {
@@ -180,9 +759,11 @@ impl From<JustifyText> for glyph_brush_layout::HorizontalAlign {
     }
 }
 
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default)]
 pub struct TextStyle {
     /// If this is not specified, then
+    /// * if the [`DefaultFont`](crate::DefaultFont) resource was set, its font is used.
     /// * if `default_font` feature is enabled (enabled by default in `bevy` crate),
     ///      `FiraMono-subset.ttf` compiled into the library is used.
     /// * otherwise no text will be rendered.
@@ -195,7 +776,32 @@ pub struct TextStyle {
     /// A new font atlas is generated for every combination of font handle and scaled font size
     /// which can have a strong performance impact.
     pub font_size: f32,
+    /// If set, the minimum and maximum font size, in logical pixels.
+    ///
+    /// The clamp applies to the font size once it is multiplied by `UiScale` and the
+    /// [`TextScale`](crate::TextScale), and before it is multiplied by the window scale factor to
+    /// rasterize the glyphs. This keeps text readable, but not oversized, whatever the scale the
+    /// player chose or the size of the window the font size was computed from. Defaults to
+    /// `None`, which doesn't clamp the font size.
+    pub font_size_clamp: Option<(f32, f32)>,
     pub color: Color,
+    /// If set, the glyphs of the section are highlighted with this color, such as for mentions in
+    /// a chat message.
+    ///
+    /// The highlight spans the advances of the glyphs, and the ascent and descent of the fonts of
+    /// each line, so that highlights on the same line have equal heights and adjacent
+    /// highlighted sections join without a gap. A section wrapped over several lines is
+    /// highlighted on each of them. See [`TextLayoutInfo::backgrounds`](crate::TextLayoutInfo::backgrounds).
+    pub background_color: Option<Color>,
+    /// How the glyphs of the section are synthesized from its font, such as superscript for
+    /// footnote markers.
+    pub variant: TextVariant,
+    /// If set, the color of the glyphs of the section goes from [`color`](Self::color) for its
+    /// first glyph to this color for its last glyph.
+    ///
+    /// A [`TextGlyphColor`] overrides the gradient. As with the color,
+    /// changing the gradient doesn't cause the text to be laid out again.
+    pub color_gradient: Option<Color>,
 }
 
 impl Default for TextStyle {
@@ -203,7 +809,309 @@ impl Default for TextStyle {
         Self {
             font: Default::default(),
             font_size: 24.0,
+            font_size_clamp: None,
             color: Color::WHITE,
+            background_color: None,
+            variant: TextVariant::Normal,
+            color_gradient: None,
+        }
+    }
+}
+
+impl TextStyle {
+    /// Returns the color of a glyph at `position` in the [`color_gradient`](Self::color_gradient)
+    /// of the section, between `0.0` and `1.0`, or the [`color`](Self::color) of the section if
+    /// it has no gradient.
+    pub fn gradient_color(&self, position: f32) -> Color {
+        match self.color_gradient {
+            Some(end) => self.color.mix(&end, position),
+            None => self.color,
+        }
+    }
+
+    /// Returns the font size the glyphs are rasterized at, in physical pixels.
+    ///
+    /// `scale_factor` is the product of every factor scaling the font size, including the
+    /// `window_scale_factor` converting logical pixels to physical pixels. The
+    /// [`font_size_clamp`](Self::font_size_clamp) applies before the window scale factor.
+    pub fn scaled_font_size(&self, scale_factor: f32, window_scale_factor: f32) -> f32 {
+        let font_size = self.font_size * scale_factor;
+        match self.font_size_clamp {
+            // Unlike `f32::clamp`, this doesn't panic if the bounds are swapped
+            Some((min, max)) => font_size
+                .max(min * window_scale_factor)
+                .min(max * window_scale_factor),
+            None => font_size,
+        }
+    }
+}
+
+/// A variant of the glyphs of a [`TextSection`], synthesized from its font rather than read from
+/// a dedicated font.
+///
+/// The scaled glyphs are laid out with their smaller size, so they don't push the lines of the
+/// text apart, and their baseline is shifted from the baseline shared by the line.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum TextVariant {
+    /// The glyphs of the font, as they are.
+    #[default]
+    Normal,
+    /// Glyphs scaled by `scale`, with their baseline raised by [`TextVariant::SUPERSCRIPT_SHIFT`].
+    Superscript {
+        /// The scale of the glyphs, relative to the font size of the section.
+        scale: f32,
+    },
+    /// Glyphs scaled by `scale`, with their baseline lowered by [`TextVariant::SUBSCRIPT_SHIFT`].
+    Subscript {
+        /// The scale of the glyphs, relative to the font size of the section.
+        scale: f32,
+    },
+    /// Lowercase letters displayed as capitals scaled by `scale`, while the other characters
+    /// are left as they are.
+    ///
+    /// The capitals are always synthesized, as the `smcp` OpenType feature isn't supported.
+    SmallCaps {
+        /// The scale of the capitals replacing the lowercase letters, relative to the font size
+        /// of the section.
+        scale: f32,
+    },
+}
+
+impl TextVariant {
+    /// The height the baseline of a [`TextVariant::Superscript`] is raised by, relative to the
+    /// font size of its section.
+    pub const SUPERSCRIPT_SHIFT: f32 = 0.35;
+
+    /// The height the baseline of a [`TextVariant::Subscript`] is lowered by, relative to the
+    /// font size of its section.
+    pub const SUBSCRIPT_SHIFT: f32 = 0.2;
+
+    /// A [`TextVariant::Superscript`] with glyphs scaled to 65% of the font size.
+    pub const fn superscript() -> Self {
+        Self::Superscript { scale: 0.65 }
+    }
+
+    /// A [`TextVariant::Subscript`] with glyphs scaled to 65% of the font size.
+    pub const fn subscript() -> Self {
+        Self::Subscript { scale: 0.65 }
+    }
+
+    /// A [`TextVariant::SmallCaps`] with capitals scaled to 75% of the font size.
+    pub const fn small_caps() -> Self {
+        Self::SmallCaps { scale: 0.75 }
+    }
+
+    /// Returns the height the baseline of the glyphs is raised by, relative to the font size of
+    /// their section. It is negative for [`TextVariant::Subscript`].
+    pub fn baseline_shift(self) -> f32 {
+        match self {
+            TextVariant::Superscript { .. } => Self::SUPERSCRIPT_SHIFT,
+            TextVariant::Subscript { .. } => -Self::SUBSCRIPT_SHIFT,
+            TextVariant::Normal | TextVariant::SmallCaps { .. } => 0.,
+        }
+    }
+
+    /// Splits `value`, the displayed value of a section with this variant, into the runs of
+    /// text laid out with the same scale, as `(byte_offset, text, scale)`.
+    ///
+    /// Only [`TextVariant::SmallCaps`] splits the value: lowercase letters are replaced by their
+    /// capital when it is a single character of the same length, so that the byte offsets of
+    /// the characters are kept.
+    pub(crate) fn runs(self, value: &str) -> Vec<(usize, Cow<str>, f32)> {
+        let small_scale = match self {
+            TextVariant::Normal => return vec![(0, Cow::Borrowed(value), 1.)],
+            TextVariant::Superscript { scale } | TextVariant::Subscript { scale } => {
+                return vec![(0, Cow::Borrowed(value), scale)];
+            }
+            TextVariant::SmallCaps { scale } => scale,
+        };
+        let capital = |c: char| {
+            let mut upper = c.to_uppercase();
+            match (upper.next(), upper.next()) {
+                (Some(upper), None) if c.is_lowercase() && upper.len_utf8() == c.len_utf8() => {
+                    Some(upper)
+                }
+                _ => None,
+            }
+        };
+
+        let mut runs = Vec::new();
+        let mut start = 0;
+        let mut small = false;
+        for (index, c) in value.char_indices() {
+            let is_small = capital(c).is_some();
+            if index > start && is_small != small {
+                runs.push((start, &value[start..index], small));
+                start = index;
+            }
+            small = is_small;
+        }
+        if start < value.len() {
+            runs.push((start, &value[start..], small));
+        }
+        runs.into_iter()
+            .map(|(start, run, small)| {
+                if small {
+                    let capitals = run.chars().filter_map(capital).collect();
+                    (start, Cow::Owned(capitals), small_scale)
+                } else {
+                    (start, Cow::Borrowed(run), 1.)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Procedurally sets the color of each glyph of a [`Text`], overriding the color and the
+/// [`color_gradient`](TextStyle::color_gradient) of its sections.
+///
+/// The function is given the index of the glyph in the laid out text, and the character it displays.
+/// As only the colors of the glyphs are affected, changing this component doesn't cause the text
+/// to be laid out again.
+///
+/// ```
+/// # use bevy_color::Color;
+/// # use bevy_text::TextGlyphColor;
+/// #
+/// // A rainbow, where the hue of each glyph depends on its index.
+/// let rainbow = TextGlyphColor::new(|index, _| {
+///     Color::hsl(index as f32 * 36.0 % 360.0, 1.0, 0.5)
+/// });
+/// ```
+#[derive(Component)]
+pub struct TextGlyphColor(pub Box<dyn Fn(usize, char) -> Color + Send + Sync>);
+
+impl TextGlyphColor {
+    /// Creates a [`TextGlyphColor`] from a function of the glyph index and character.
+    pub fn new(color: impl Fn(usize, char) -> Color + Send + Sync + 'static) -> Self {
+        Self(Box::new(color))
+    }
+
+    /// Returns the color of `glyph`, the glyph at `index` in the layout of a text whose
+    /// [displayed values](Text::displayed_values) are `displayed_values`.
+    ///
+    /// Returns `None` if the glyph doesn't belong to the text.
+    pub fn glyph_color(
+        &self,
+        displayed_values: &[Cow<str>],
+        index: usize,
+        glyph: &PositionedGlyph,
+    ) -> Option<Color> {
+        let character = glyph.character(displayed_values)?;
+        Some((self.0)(index, character))
+    }
+}
+
+impl std::fmt::Debug for TextGlyphColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TextGlyphColor").finish_non_exhaustive()
+    }
+}
+
+/// How the whitespace of the sections of a [`Text`] is displayed, like the CSS `white-space` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum WhiteSpace {
+    /// The whitespace is displayed as written: runs of spaces, and the whitespace at the start and
+    /// end of the lines, are kept, and newlines break the lines.
+    ///
+    /// This is the default, without a `tab_width`.
+    Preserve {
+        /// The number of characters between tab stops: when set, tabs are expanded with spaces up
+        /// to the next tab stop, counted from the start of the line. When `None`, tabs are laid
+        /// out as the glyph of the font, as when the whitespace wasn't configurable.
+        tab_width: Option<u8>,
+    },
+    /// Each run of whitespace, including tabs and newlines, is collapsed to a single space, and the
+    /// whitespace at the start and end of the text is trimmed. The lines are only broken by
+    /// wrapping, and the space where a line wraps isn't displayed at the start of the next line.
+    ///
+    /// Runs spanning several sections are collapsed into the section where they start.
+    /// Non-breaking spaces aren't collapsed.
+    Normal,
+}
+
+impl Default for WhiteSpace {
+    fn default() -> Self {
+        WhiteSpace::Preserve { tab_width: None }
+    }
+}
+
+impl WhiteSpace {
+    /// Returns the `values` of consecutive sections as they are displayed with this [`WhiteSpace`].
+    pub fn apply<'a>(self, values: impl IntoIterator<Item = Cow<'a, str>>) -> Vec<Cow<'a, str>> {
+        let mut values: Vec<_> = values.into_iter().collect();
+        match self {
+            WhiteSpace::Preserve { tab_width: None } => values,
+            WhiteSpace::Preserve {
+                tab_width: Some(tab_width),
+            } => {
+                if !values.iter().any(|value| value.contains('\t')) {
+                    return values;
+                }
+                let tab_width = usize::from(tab_width.max(1));
+                // Tab stops are counted from the start of the line, across sections
+                let mut column = 0;
+                for value in &mut values {
+                    if !value.contains('\t') {
+                        column = match value.rfind('\n') {
+                            Some(newline) => value[newline + 1..].chars().count(),
+                            None => column + value.chars().count(),
+                        };
+                        continue;
+                    }
+                    let mut expanded = String::with_capacity(value.len());
+                    for character in value.chars() {
+                        match character {
+                            '\t' => {
+                                let spaces = tab_width - column % tab_width;
+                                expanded.extend(std::iter::repeat(' ').take(spaces));
+                                column += spaces;
+                            }
+                            '\n' => {
+                                expanded.push(character);
+                                column = 0;
+                            }
+                            _ => {
+                                expanded.push(character);
+                                column += 1;
+                            }
+                        }
+                    }
+                    *value = Cow::Owned(expanded);
+                }
+                values
+            }
+            WhiteSpace::Normal => {
+                // Whitespace is skipped at the start of the text, and after a collapsed space
+                let mut skip_whitespace = true;
+                // The section ending with a collapsed space, trimmed if nothing follows it
+                let mut trailing_space = None;
+                let mut collapsed: Vec<String> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let mut collapsed = String::with_capacity(value.len());
+                        for character in value.chars() {
+                            if !matches!(character, ' ' | '\t' | '\n' | '\r' | '\x0C') {
+                                collapsed.push(character);
+                                skip_whitespace = false;
+                                trailing_space = None;
+                            } else if !skip_whitespace {
+                                collapsed.push(' ');
+                                skip_whitespace = true;
+                                trailing_space = Some(index);
+                            }
+                        }
+                        collapsed
+                    })
+                    .collect();
+                if let Some(index) = trailing_space {
+                    collapsed[index].pop();
+                }
+                collapsed.into_iter().map(Cow::Owned).collect()
+            }
         }
     }
 }
@@ -226,6 +1134,27 @@ pub enum BreakLineOn {
     NoWrap,
 }
 
+/// How the lines of a [`Text`] wider than the text are displayed: the lines of a
+/// [`BreakLineOn::NoWrap`] text wider than its node or bounds, or the words too long to be
+/// wrapped.
+///
+/// Only the overflowing lines are truncated, whole graphemes at a time. The
+/// [`ellipsis`](Text::ellipsis) is displayed with the style of the section of the first truncated
+/// grapheme, and its glyphs have the [`byte_index`](crate::PositionedGlyph::byte_index) of that
+/// grapheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Serialize, Deserialize)]
+pub enum TextOverflow {
+    /// The lines are displayed in full, overflowing the text, and can be clipped by their node.
+    #[default]
+    Clip,
+    /// The end of the line is replaced with the ellipsis, such as `"A very long ti…"`.
+    Ellipsis,
+    /// The middle of the line is replaced with the ellipsis, keeping both of its ends, such as
+    /// `"assets/very…/name.png"` for a path.
+    EllipsisMiddle,
+}
+
 impl From<BreakLineOn> for glyph_brush_layout::BuiltInLineBreaker {
     fn from(val: BreakLineOn) -> Self {
         match val {
@@ -238,3 +1167,326 @@ impl From<BreakLineOn> for glyph_brush_layout::BuiltInLineBreaker {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{change_detection::DetectChanges, world::World};
+
+    use super::*;
+    use crate::GlyphAtlasInfo;
+
+    #[test]
+    fn font_size_clamp_is_in_logical_pixels() {
+        let style = TextStyle {
+            font_size: 20.0,
+            font_size_clamp: Some((24.0, 96.0)),
+            ..Default::default()
+        };
+        // Raised to 24 logical pixels, then rasterized at twice that on a window scaled by 2
+        assert_eq!(style.scaled_font_size(2.0, 2.0), 48.0);
+        // Scaled by 6, such as by `TextScale`, then lowered to 96 logical pixels
+        assert_eq!(style.scaled_font_size(12.0, 2.0), 192.0);
+        // Within the clamp
+        assert_eq!(style.scaled_font_size(3.0, 1.5), 60.0);
+
+        let unclamped = TextStyle {
+            font_size_clamp: None,
+            ..style
+        };
+        assert_eq!(unclamped.scaled_font_size(12.0, 2.0), 240.0);
+    }
+
+    #[test]
+    fn equal_section_is_not_changed() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(Text::from_sections([
+                "FPS: ".into(),
+                TextSection::new("60.00", TextStyle::default()),
+            ]))
+            .id();
+        world.clear_trackers();
+
+        let mut text = world.get_mut::<Text>(entity).unwrap();
+        assert!(!text.set_section_if_changed(1, "60.00"));
+        assert!(!text.is_changed());
+
+        assert!(text.set_section_if_changed(1, "59.99"));
+        assert!(text.is_changed());
+        assert_eq!(text.sections[1].value, "59.99");
+    }
+
+    #[test]
+    fn truncation_counts_graphemes() {
+        // "é" is made of two chars here, but is a single grapheme
+        let value = "cafe\u{301} au lait";
+        let start = SectionTruncation::keep_start(6, "→");
+        assert_eq!(start.apply(value), "cafe\u{301} →");
+        let end = SectionTruncation::keep_end(6, "…");
+        assert_eq!(end.apply(value), "… lait");
+
+        // Short values and the sections without truncation are displayed unchanged
+        assert_eq!(SectionTruncation::keep_start(13, "→").apply(value), value);
+        assert!(matches!(start.apply("cafe"), Cow::Borrowed("cafe")));
+        assert_eq!(
+            TextSection::new(value, TextStyle::default()).displayed_value(),
+            value
+        );
+    }
+
+    #[test]
+    fn white_space_collapses_or_preserves() {
+        let values = |white_space: WhiteSpace, values: &[&str]| {
+            white_space
+                .apply(values.iter().map(|&value| Cow::Borrowed(value)))
+                .into_iter()
+                .map(Cow::into_owned)
+                .collect::<Vec<_>>()
+        };
+
+        let preserve = WhiteSpace::default();
+        let expand_tabs = WhiteSpace::Preserve { tab_width: Some(4) };
+
+        // The default keeps today's behavior, tabs included
+        assert_eq!(Text::default().white_space, preserve);
+        assert_eq!(values(preserve, &["a    b"]), ["a    b"]);
+        assert_eq!(values(preserve, &["a\tb"]), ["a\tb"]);
+        assert_eq!(values(WhiteSpace::Normal, &["a    b"]), ["a b"]);
+
+        // Leading and trailing whitespace, and newlines
+        let lines = [" \t a \n\n b \t"];
+        assert_eq!(values(expand_tabs, &lines), ["     a \n\n b  "]);
+        assert_eq!(values(WhiteSpace::Normal, &lines), ["a b"]);
+
+        // Tab stops and collapsed runs span the sections
+        let sections = ["ab", "\tc \n", "\td  ", "  ", " e  "];
+        assert_eq!(values(preserve, &sections), sections);
+        assert_eq!(
+            values(expand_tabs, &sections),
+            ["ab", "  c \n", "    d  ", "  ", " e  "]
+        );
+        assert_eq!(
+            values(WhiteSpace::Normal, &sections),
+            ["ab", " c ", "d ", "", "e"]
+        );
+
+        // Non-breaking spaces are kept
+        assert_eq!(values(WhiteSpace::Normal, &["a \u{a0} b"]), ["a \u{a0} b"]);
+    }
+
+    #[test]
+    fn small_caps_runs() {
+        let runs = |value| {
+            TextVariant::small_caps()
+                .runs(value)
+                .into_iter()
+                .map(|(offset, text, scale)| (offset, text.into_owned(), scale))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            runs("Hello, World"),
+            [
+                (0, "H".to_string(), 1.),
+                (1, "ELLO".to_string(), 0.75),
+                (5, ", W".to_string(), 1.),
+                (8, "ORLD".to_string(), 0.75),
+            ]
+        );
+        // Byte offsets are kept, so letters whose capital differs in length aren't replaced
+        assert_eq!(
+            runs("éß"),
+            [(0, "É".to_string(), 0.75), (2, "ß".to_string(), 1.)]
+        );
+        assert!(runs("").is_empty());
+
+        let superscript = TextVariant::superscript().runs("10");
+        assert_eq!(superscript.len(), 1);
+        assert_eq!(superscript[0].2, 0.65);
+    }
+
+    #[test]
+    fn glyphs_are_drawn_by_layer() {
+        let glyph = |section_index| PositionedGlyph {
+            position: Vec2::ZERO,
+            size: Vec2::ZERO,
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Handle::default(),
+                texture: Handle::default(),
+                glyph_index: 0,
+            },
+            section_index,
+            byte_index: 0,
+        };
+        let glyphs = [glyph(0), glyph(0), glyph(1), glyph(1), glyph(2)];
+        let mut text = Text::from_sections([
+            TextSection::new("ab", TextStyle::default()),
+            TextSection::new("cd", TextStyle::default()),
+            TextSection::new("e", TextStyle::default()),
+        ]);
+        assert!(text.glyph_draw_order(&glyphs).eq([0, 1, 2, 3, 4]));
+
+        text.sections[1].layer = -1;
+        text.sections[2].layer = -1;
+        assert!(text.glyph_draw_order(&glyphs).eq([2, 3, 4, 0, 1]));
+    }
+
+    #[test]
+    fn gradients_span_their_sections() {
+        let glyph = |section_index| PositionedGlyph {
+            position: Vec2::ZERO,
+            size: Vec2::ZERO,
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Handle::default(),
+                texture: Handle::default(),
+                glyph_index: 0,
+            },
+            section_index,
+            byte_index: 0,
+        };
+        let glyphs = [glyph(0), glyph(0), glyph(0), glyph(1), glyph(0), glyph(2)];
+        let gradient = TextStyle {
+            color: Color::BLACK,
+            color_gradient: Some(Color::WHITE),
+            ..default()
+        };
+        let mut text = Text::from_sections([
+            TextSection::new("abc", gradient.clone()),
+            TextSection::new("d", TextStyle::default()),
+            TextSection::new("e", gradient.clone()),
+        ]);
+        // The glyphs of a section wrapped around another section continue its gradient
+        assert_eq!(
+            text.glyph_gradient_positions(&glyphs).unwrap(),
+            [0.0, 1.0 / 3.0, 2.0 / 3.0, 0.0, 1.0, 0.0]
+        );
+        assert_eq!(gradient.gradient_color(0.0), Color::BLACK);
+        assert_eq!(gradient.gradient_color(1.0), Color::WHITE);
+
+        text.sections[0].style.color_gradient = None;
+        text.sections[2].style.color_gradient = None;
+        assert!(text.glyph_gradient_positions(&glyphs).is_none());
+    }
+
+    #[test]
+    fn text_scene_is_compact() {
+        use bevy_ecs::reflect::AppTypeRegistry;
+        use bevy_reflect::TypeRegistration;
+        use bevy_scene::{serde::SceneDeserializer, DynamicScene};
+        use serde::de::DeserializeSeed;
+
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Text>();
+        registry.write().register::<SerializedText>();
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+
+        let label = TextStyle {
+            font_size: 24.0,
+            ..default()
+        };
+        let value = TextStyle {
+            font_size: 24.0,
+            color: Color::srgb(1.0, 0.8, 0.0),
+            ..default()
+        };
+        let mut sections = Vec::new();
+        for (name, amount) in [("Gold", "1 200"), ("Wood", "340"), ("Stone", "87")] {
+            sections.push(TextSection::new(format!("{name}: "), label.clone()));
+            sections.push(TextSection::new(format!("{amount}\n"), value.clone()));
+        }
+        sections.push(
+            TextSection::new("…", value.clone())
+                .with_layer(1)
+                .with_offset(Vec2::new(0.0, 2.0)),
+        );
+        let text = Text::from_sections(sections).with_justify(JustifyText::Right);
+        world.spawn(text.clone());
+        let scene = DynamicScene::from_world(&world);
+
+        let compact = scene.serialize(&registry.read()).unwrap();
+        // Without its `ReflectSerializeAs`, the text is serialized field by field
+        let plain = {
+            let mut registry = registry.write();
+            registry.overwrite_registration(TypeRegistration::of::<Text>());
+            scene.serialize(&registry).unwrap()
+        };
+        assert!(
+            compact.len() * 3 < plain.len() * 2,
+            "{} bytes serialized compactly, {} otherwise",
+            compact.len(),
+            plain.len()
+        );
+
+        // The compact text is deserialized as the original text
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Text>();
+        registry.write().register::<SerializedText>();
+        let mut deserializer = ron::de::Deserializer::from_str(&compact).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        scene
+            .write_to_world_with(&mut world, &mut Default::default(), &registry)
+            .unwrap();
+        let texts: Vec<_> = world.query::<&Text>().iter(&world).collect();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(format!("{:?}", texts[0]), format!("{text:?}"));
+    }
+
+    #[test]
+    fn text_in_former_format_is_deserialized() {
+        use bevy_reflect::{serde::TypedReflectDeserializer, TypeRegistry};
+        use serde::de::DeserializeSeed;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Text>();
+        registry.register::<SerializedText>();
+        let deserialize = |ron: &str| {
+            let registration = registry.get(std::any::TypeId::of::<Text>()).unwrap();
+            let mut deserializer = ron::de::Deserializer::from_str(ron).unwrap();
+            let text = TypedReflectDeserializer::new(registration, &registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            text.take::<Text>().unwrap()
+        };
+
+        // A text saved before texts were serialized compactly
+        let text = deserialize(
+            r#"(
+                sections: [
+                    (
+                        value: "Hello",
+                        style: (
+                            font: Weak(Uuid(uuid: "97128bb1-2588-480b-bdc6-87b4adbec477")),
+                            font_size: 40.0,
+                            color: Srgba((red: 1.0, green: 0.0, blue: 0.0, alpha: 1.0)),
+                        ),
+                    ),
+                ],
+                justify: Center,
+                linebreak_behavior: NoWrap,
+            )"#,
+        );
+        let expected = Text::from_section(
+            "Hello",
+            TextStyle {
+                font_size: 40.0,
+                color: Color::srgb(1.0, 0.0, 0.0),
+                ..default()
+            },
+        )
+        .with_justify(JustifyText::Center)
+        .with_no_wrap();
+        assert_eq!(format!("{text:?}"), format!("{expected:?}"));
+
+        // Fields missing from a compact text take their default value
+        let text = deserialize(r#"(value: "Hi", styles: [()], spans: [(len: 2, style: 0)])"#);
+        let expected = Text::from_section("Hi", TextStyle::default());
+        assert_eq!(text.ellipsis, "…");
+        assert_eq!(format!("{text:?}"), format!("{expected:?}"));
+    }
+}
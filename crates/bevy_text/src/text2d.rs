@@ -1,9 +1,11 @@
 use crate::{
-    BreakLineOn, Font, FontAtlasSets, PositionedGlyph, Text, TextError, TextLayoutInfo,
-    TextPipeline, TextSettings, YAxisOrientation,
+    BreakLineOn, DefaultFont, Font, FontAtlasSets, PositionedGlyph, SectionBackground, Text,
+    TextAtlasSettings, TextError, TextGlyphColor, TextLayoutInfo, TextLayoutOptions, TextPipeline,
+    TextRenderError, TextRenderSettings, TextScale, TextScaleOverride, TextSettings,
+    YAxisOrientation,
 };
-use bevy_asset::Assets;
-use bevy_color::LinearRgba;
+use bevy_asset::{AssetId, Assets};
+use bevy_color::{Alpha, LinearRgba};
 use bevy_ecs::{
     bundle::Bundle,
     change_detection::{DetectChanges, Ref},
@@ -13,6 +15,7 @@ use bevy_ecs::{
     prelude::With,
     query::{Changed, Without},
     reflect::ReflectComponent,
+    removal_detection::RemovedComponents,
     system::{Commands, Local, Query, Res, ResMut},
 };
 use bevy_math::Vec2;
@@ -23,9 +26,12 @@ use bevy_render::{
     view::{InheritedVisibility, NoFrustumCulling, ViewVisibility, Visibility},
     Extract,
 };
-use bevy_sprite::{Anchor, ExtractedSprite, ExtractedSprites, SpriteSource, TextureAtlasLayout};
+use bevy_sprite::{
+    Anchor, ExtractedSprite, ExtractedSpriteQuads, ExtractedSprites, SpriteQuad, SpriteSource,
+    TextureAtlasLayout,
+};
 use bevy_transform::prelude::{GlobalTransform, Transform};
-use bevy_utils::HashSet;
+use bevy_utils::{tracing::error, HashSet};
 use bevy_window::{PrimaryWindow, Window, WindowScaleFactorChanged};
 
 /// The maximum width and height of text. The text will wrap according to the specified size.
@@ -93,6 +99,12 @@ pub struct Text2dBundle {
 
 /// This system extracts the sprites from the 2D text components and adds them to the
 /// "render world".
+///
+/// The glyphs of a text are extracted as a single [`ExtractedSpriteQuads`], drawn with an instance
+/// for each glyph, as long as they're all drawn from the same texture: the text has no inline
+/// image, and its fonts and sizes all fit in the same font atlas texture. Consecutive texts drawn
+/// from the same texture, such as labels sharing a font, are then drawn in a single draw call.
+/// Texts whose glyphs span several textures are extracted as a sprite for each glyph instead.
 pub fn extract_text2d_sprite(
     mut commands: Commands,
     mut extracted_sprites: ResMut<ExtractedSprites>,
@@ -106,6 +118,7 @@ pub fn extract_text2d_sprite(
             &TextLayoutInfo,
             &Anchor,
             &GlobalTransform,
+            Option<&TextGlyphColor>,
         )>,
     >,
 ) {
@@ -116,8 +129,16 @@ pub fn extract_text2d_sprite(
         .unwrap_or(1.0);
     let scaling = GlobalTransform::from_scale(Vec2::splat(scale_factor.recip()).extend(1.));
 
-    for (original_entity, view_visibility, text, text_layout_info, anchor, global_transform) in
-        text2d_query.iter()
+    let mut quads = Vec::new();
+    for (
+        original_entity,
+        view_visibility,
+        text,
+        text_layout_info,
+        anchor,
+        global_transform,
+        glyph_color,
+    ) in text2d_query.iter()
     {
         if !view_visibility.get() {
             continue;
@@ -128,30 +149,115 @@ pub fn extract_text2d_sprite(
         let transform = *global_transform
             * GlobalTransform::from_translation(alignment_translation.extend(0.))
             * scaling;
-        let mut color = LinearRgba::WHITE;
-        let mut current_section = usize::MAX;
-        for PositionedGlyph {
-            position,
-            atlas_info,
+        // Spawned before the glyphs so that they're drawn behind them
+        for SectionBackground {
             section_index,
-            ..
-        } in &text_layout_info.glyphs
+            rect,
+        } in &text_layout_info.backgrounds
         {
+            let section = &text.sections[*section_index];
+            let Some(color) = section
+                .style
+                .background_color
+                .filter(|color| !color.is_fully_transparent())
+            else {
+                continue;
+            };
+            let entity = commands.spawn_empty().id();
+            extracted_sprites.sprites.insert(
+                entity,
+                ExtractedSprite {
+                    transform: transform
+                        * GlobalTransform::from_translation(
+                            (rect.center() + section.offset * scale_factor).extend(0.),
+                        ),
+                    color: LinearRgba::from(color),
+                    rect: None,
+                    custom_size: Some(rect.size()),
+                    image_handle_id: AssetId::default(),
+                    flip_x: false,
+                    flip_y: false,
+                    anchor: Anchor::Center.as_vec(),
+                    original_entity: Some(original_entity),
+                },
+            );
+        }
+
+        // The characters of the glyphs are only needed for their colors
+        let displayed_values = glyph_color.map(|_| text.displayed_values());
+        let gradient_positions = text.glyph_gradient_positions(&text_layout_info.glyphs);
+        let mut section_color = LinearRgba::WHITE;
+        let mut section_offset = Vec2::ZERO;
+        let mut current_section = usize::MAX;
+        // Sections of a higher layer are spawned, and so drawn, after the ones of a lower layer
+        for index in text.glyph_draw_order(&text_layout_info.glyphs) {
+            let glyph = &text_layout_info.glyphs[index];
+            let PositionedGlyph {
+                position,
+                size,
+                atlas_info,
+                section_index,
+                ..
+            } = glyph;
             if *section_index != current_section {
-                color = LinearRgba::from(text.sections[*section_index].style.color);
+                section_color = LinearRgba::from(text.sections[*section_index].style.color);
+                section_offset = text.sections[*section_index].offset;
                 current_section = *section_index;
             }
+            let color = glyph_color
+                .zip(displayed_values.as_deref())
+                .and_then(|(glyph_color, values)| glyph_color.glyph_color(values, index, glyph))
+                .or_else(|| {
+                    let position = gradient_positions.as_ref()?[index];
+                    Some(text.sections[*section_index].style.gradient_color(position))
+                })
+                .map_or(section_color, LinearRgba::from);
+            // Transparent glyphs, such as hidden text kept in the layout, aren't drawn at all
+            if color.is_fully_transparent() {
+                continue;
+            }
             let atlas = texture_atlases.get(&atlas_info.texture_atlas).unwrap();
+            quads.push((
+                atlas_info.texture.id(),
+                SpriteQuad {
+                    position: *position + section_offset * scale_factor,
+                    // Inline images are displayed at their own size rather than the one of their rect
+                    size: *size,
+                    rect: atlas.textures[atlas_info.glyph_index].as_rect(),
+                    color,
+                },
+            ));
+        }
 
+        // Glyphs drawn from a single texture are extracted as one item with an instance for each
+        // of them, which is cheaper to extract, sort and prepare than a sprite for each glyph.
+        // This is the case unless the text has inline images or fonts of several atlas textures.
+        let Some(&(image_handle_id, _)) = quads.first() else {
+            continue;
+        };
+        if quads.iter().all(|(id, _)| *id == image_handle_id) {
+            extracted_sprites.quads.insert(
+                commands.spawn_empty().id(),
+                ExtractedSpriteQuads {
+                    transform,
+                    image_handle_id,
+                    quads: quads.drain(..).map(|(_, quad)| quad).collect(),
+                    original_entity: Some(original_entity),
+                },
+            );
+            continue;
+        }
+        for (image_handle_id, quad) in quads.drain(..) {
             let entity = commands.spawn_empty().id();
             extracted_sprites.sprites.insert(
                 entity,
                 ExtractedSprite {
-                    transform: transform * GlobalTransform::from_translation(position.extend(0.)),
-                    color,
-                    rect: Some(atlas.textures[atlas_info.glyph_index].as_rect()),
-                    custom_size: None,
-                    image_handle_id: atlas_info.texture.id(),
+                    transform: transform
+                        * GlobalTransform::from_translation(quad.position.extend(0.)),
+                    color: quad.color,
+                    rect: Some(quad.rect),
+                    custom_size: Some(quad.size),
+                    image_handle_id,
                     flip_x: false,
                     flip_y: false,
                     anchor: Anchor::Center.as_vec(),
@@ -164,6 +270,7 @@ pub fn extract_text2d_sprite(
 
 /// Updates the layout and size information whenever the text or style is changed.
 /// This information is computed by the [`TextPipeline`] on insertion, then stored.
+/// Text that fails to render gets a [`TextRenderError`], removed once it is laid out without error.
 ///
 /// ## World Resources
 ///
@@ -173,18 +280,36 @@ pub fn extract_text2d_sprite(
 pub fn update_text2d_layout(
     // Text items which should be reprocessed again, generally when the font hasn't loaded yet.
     mut queue: Local<HashSet<Entity>>,
+    mut commands: Commands,
     mut textures: ResMut<Assets<Image>>,
     fonts: Res<Assets<Font>>,
+    default_font: Res<DefaultFont>,
     text_settings: Res<TextSettings>,
+    text_render_settings: Res<TextRenderSettings>,
+    text_atlas_settings: Res<TextAtlasSettings>,
+    text_scale: Res<TextScale>,
+    mut last_text_scale: Local<Option<f32>>,
+    mut removed_text_scale_overrides: RemovedComponents<TextScaleOverride>,
     windows: Query<&Window, With<PrimaryWindow>>,
     mut scale_factor_changed: EventReader<WindowScaleFactorChanged>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
     mut font_atlas_sets: ResMut<FontAtlasSets>,
     mut text_pipeline: ResMut<TextPipeline>,
-    mut text_query: Query<(Entity, Ref<Text>, Ref<Text2dBounds>, &mut TextLayoutInfo)>,
+    mut text_query: Query<(
+        Entity,
+        Ref<Text>,
+        Ref<Text2dBounds>,
+        &mut TextLayoutInfo,
+        Option<&TextRenderError>,
+        Option<Ref<TextScaleOverride>>,
+    )>,
 ) {
     // We need to consume the entire iterator, hence `last`
     let factor_changed = scale_factor_changed.read().last().is_some();
+    // Compared by value, so that a slider setting the same factor every frame doesn't relayout
+    let text_scale_changed = last_text_scale.replace(text_scale.0) != Some(text_scale.0);
+    let removed_text_scale_overrides: HashSet<Entity> =
+        removed_text_scale_overrides.read().collect();
 
     // TODO: Support window-independent scaling: https://github.com/bevyengine/bevy/issues/5621
     let scale_factor = windows
@@ -194,36 +319,70 @@ pub fn update_text2d_layout(
 
     let inverse_scale_factor = scale_factor.recip();
 
-    for (entity, text, bounds, mut text_layout_info) in &mut text_query {
-        if factor_changed || text.is_changed() || bounds.is_changed() || queue.remove(&entity) {
+    // `Text2d` is drawn over the scene, whose color isn't known to blend subpixel coverage with
+    let antialiasing = text_render_settings.antialiasing.without_subpixel();
+
+    for (entity, text, bounds, mut text_layout_info, render_error, text_scale_override) in
+        &mut text_query
+    {
+        if factor_changed
+            || (text_scale_changed && text_scale_override.is_none())
+            || text_scale_override
+                .as_ref()
+                .is_some_and(DetectChanges::is_changed)
+            || removed_text_scale_overrides.contains(&entity)
+            || text.is_changed()
+            || bounds.is_changed()
+            || (default_font.is_changed() && text.uses_default_font())
+            || text_layout_info.antialiasing != antialiasing
+            // The font atlases may have been repacked
+            || text_atlas_settings.is_changed()
+            || queue.remove(&entity)
+        {
             let text_bounds = Vec2::new(
                 if text.linebreak_behavior == BreakLineOn::NoWrap {
                     f32::INFINITY
                 } else {
-                    scale_value(bounds.size.x, scale_factor)
+                    scale_value(text.wrap_width.unwrap_or(bounds.size.x), scale_factor)
                 },
                 scale_value(bounds.size.y, scale_factor),
             );
-            match text_pipeline.queue_text(
+            // The text scale only scales the font sizes, not the bounds
+            let font_scale_factor =
+                scale_factor * text_scale.resolve(text_scale_override.as_deref());
+            let result = text_pipeline.queue_text(
                 &fonts,
-                &text.sections,
-                scale_factor,
-                text.justify,
-                text.linebreak_behavior,
-                text_bounds,
+                &default_font,
+                &text,
+                TextLayoutOptions {
+                    scale_factor: font_scale_factor,
+                    window_scale_factor: scale_factor,
+                    bounds: text_bounds,
+                    // Lines overflowing the bounds are truncated even without wrapping
+                    max_line_width: scale_value(
+                        text.wrap_width.unwrap_or(bounds.size.x),
+                        scale_factor,
+                    ),
+                    antialiasing,
+                    y_axis_orientation: YAxisOrientation::BottomToTop,
+                },
                 &mut font_atlas_sets,
                 &mut texture_atlases,
                 &mut textures,
                 text_settings.as_ref(),
-                YAxisOrientation::BottomToTop,
-            ) {
-                Err(TextError::NoSuchFont) => {
+            );
+            let error =
+                TextRenderError::from_layout(&result, &fonts, &default_font, &text.sections);
+            TextRenderError::update(&mut commands, entity, render_error, error);
+            match result {
+                Err(TextError::NoSuchFont | TextError::NoSuchImage) => {
                     // There was an error processing the text layout, let's add this entity to the
                     // queue for further processing
                     queue.insert(entity);
                 }
                 Err(e @ TextError::FailedToAddGlyph(_)) => {
-                    panic!("Fatal error when processing text: {e}.");
+                    // The text is laid out again when it changes
+                    error!("Failed to lay out text {entity:?}: {e}.");
                 }
                 Ok(mut info) => {
                     info.logical_size.x = scale_value(info.logical_size.x, inverse_scale_factor);
@@ -282,6 +441,7 @@ mod tests {
     use bevy_utils::default;
 
     use super::*;
+    use crate::{update_glyph_padding, JustifyText};
 
     const FIRST_TEXT: &str = "Sample text.";
     const SECOND_TEXT: &str = "Another, longer sample text.";
@@ -292,12 +452,17 @@ mod tests {
             .init_resource::<Assets<Image>>()
             .init_resource::<Assets<TextureAtlasLayout>>()
             .init_resource::<TextSettings>()
+            .init_resource::<TextRenderSettings>()
+            .init_resource::<TextAtlasSettings>()
+            .init_resource::<TextScale>()
             .init_resource::<FontAtlasSets>()
+            .init_resource::<DefaultFont>()
             .init_resource::<Events<WindowScaleFactorChanged>>()
             .insert_resource(TextPipeline::default())
             .add_systems(
                 Update,
                 (
+                    update_glyph_padding.before(update_text2d_layout),
                     update_text2d_layout,
                     calculate_bounds_text2d.after(update_text2d_layout),
                 ),
@@ -387,4 +552,190 @@ mod tests {
         assert!(FIRST_TEXT.len() < SECOND_TEXT.len());
         assert!(first_aabb.half_extents.x < second_aabb.half_extents.x);
     }
+
+    #[test]
+    fn glyph_padding_repacks_atlases() {
+        let (mut app, entity) = setup();
+        app.update();
+        let first_texture = app.world().get::<TextLayoutInfo>(entity).unwrap().glyphs[0]
+            .atlas_info
+            .texture
+            .id();
+
+        app.insert_resource(TextAtlasSettings { glyph_padding: 3 });
+        app.update();
+
+        let glyphs = &app.world().get::<TextLayoutInfo>(entity).unwrap().glyphs;
+        assert_ne!(glyphs[0].atlas_info.texture.id(), first_texture);
+        let layout = app
+            .world()
+            .resource::<Assets<TextureAtlasLayout>>()
+            .get(&glyphs[0].atlas_info.texture_atlas)
+            .unwrap();
+        // Each rect has a pixel wide transparent border, so 3 pixels of padding around each glyph
+        // leave a gap of 4 pixels between the rects
+        for (i, a) in layout.textures.iter().enumerate() {
+            for b in &layout.textures[i + 1..] {
+                let gap_x = b
+                    .min
+                    .x
+                    .saturating_sub(a.max.x)
+                    .max(a.min.x.saturating_sub(b.max.x));
+                let gap_y = b
+                    .min
+                    .y
+                    .saturating_sub(a.max.y)
+                    .max(a.min.y.saturating_sub(b.max.y));
+                assert!(gap_x >= 4 || gap_y >= 4, "{a:?} and {b:?} are too close");
+            }
+        }
+    }
+
+    #[test]
+    fn glyph_byte_indices_map_to_text() {
+        let (mut app, entity) = setup();
+        app.update();
+
+        let text = app.world().get::<Text>(entity).unwrap();
+        let layout = app.world().get::<TextLayoutInfo>(entity).unwrap();
+        let displayed_values = text.displayed_values();
+        // Whitespace has no outline, so no glyph
+        let characters: String = layout
+            .iter_glyphs()
+            .map(|glyph| glyph.character(&displayed_values).unwrap())
+            .collect();
+        assert_eq!(characters, FIRST_TEXT.replace(' ', ""));
+    }
+
+    #[test]
+    fn text_scale_scales_font_size() {
+        let (mut app, entity) = setup();
+        app.update();
+        let size = |app: &App| {
+            app.world()
+                .get::<TextLayoutInfo>(entity)
+                .unwrap()
+                .logical_size
+        };
+        let unscaled = size(&app);
+
+        app.insert_resource(TextScale(1.5));
+        app.update();
+        let scaled = size(&app);
+        approx::assert_relative_eq!(scaled.x, unscaled.x * 1.5, max_relative = 0.1);
+        assert!(scaled.y > unscaled.y);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(TextScaleOverride(1.0));
+        app.update();
+        assert_eq!(size(&app), unscaled);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<TextScaleOverride>();
+        app.update();
+        assert_eq!(size(&app), scaled);
+    }
+
+    #[test]
+    fn font_size_clamp_limits_text_scale() {
+        let (mut app, entity) = setup();
+        app.update();
+        let size = |app: &App| {
+            app.world()
+                .get::<TextLayoutInfo>(entity)
+                .unwrap()
+                .logical_size
+        };
+        let unscaled = size(&app);
+
+        // A maximum at the font size cancels a larger text scale
+        let mut text = app.world_mut().get_mut::<Text>(entity).unwrap();
+        let style = &mut text.sections[0].style;
+        style.font_size_clamp = Some((0.0, style.font_size));
+        app.insert_resource(TextScale(1.5));
+        app.update();
+        assert_eq!(size(&app), unscaled);
+
+        // And a minimum above it enlarges the text like a text scale would
+        let mut text = app.world_mut().get_mut::<Text>(entity).unwrap();
+        let style = &mut text.sections[0].style;
+        style.font_size_clamp = Some((style.font_size * 2.0, f32::INFINITY));
+        app.update();
+        assert!(size(&app).x > unscaled.x * 1.5);
+    }
+
+    #[test]
+    fn render_error_cleared_once_font_loaded() {
+        let (mut app, entity) = setup();
+        let missing_font = Handle::weak_from_u128(0x5f3e_6a1c_92b4_4d07_8e21_c0ff_ee00_1234);
+        app.world_mut().get_mut::<Text>(entity).unwrap().sections[0]
+            .style
+            .font = missing_font.clone();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<TextRenderError>(entity),
+            Some(&TextRenderError::FontNotLoaded)
+        );
+
+        // The text is laid out again once the font is loaded
+        let font = Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap();
+        app.world_mut()
+            .resource_mut::<Assets<Font>>()
+            .insert(&missing_font, font);
+        app.update();
+        assert_eq!(app.world().get::<TextRenderError>(entity), None);
+    }
+
+    #[test]
+    fn layout_has_line_metrics() {
+        let (mut app, entity) = setup();
+        *app.world_mut().get_mut::<Text>(entity).unwrap() =
+            Text::from_section("hello\nbevy!", default());
+        app.update();
+
+        let info = app.world().get::<TextLayoutInfo>(entity).unwrap();
+        let [first, second] = &info.lines[..] else {
+            panic!("expected two lines, found {}", info.lines.len());
+        };
+        // The y axis of 2d text points up, so the first line is above the second one
+        assert!(first.baseline > second.baseline);
+        for line in [first, second] {
+            assert!(line.ascent > 0. && line.descent > 0.);
+            approx::assert_abs_diff_eq!(line.rect.min.y, line.baseline - line.descent);
+            approx::assert_abs_diff_eq!(line.rect.max.y, line.baseline + line.ascent);
+            assert!(line.rect.width() > 0.);
+        }
+        approx::assert_abs_diff_eq!(first.rect.max.y, info.logical_size.y);
+        assert_eq!((first.glyphs.clone(), second.glyphs.clone()), (0..5, 5..10));
+    }
+
+    #[test]
+    fn layout_keeps_empty_lines() {
+        let (mut app, entity) = setup();
+        *app.world_mut().get_mut::<Text>(entity).unwrap() =
+            Text::from_section("hello\n\nbevy!\n", default()).with_justify(JustifyText::Center);
+        app.update();
+
+        let info = app.world().get::<TextLayoutInfo>(entity).unwrap();
+        let [hello, empty, bevy, trailing] = &info.lines[..] else {
+            panic!("expected four lines, found {}", info.lines.len());
+        };
+        assert_eq!(hello.glyphs, 0..5);
+        assert_eq!(bevy.glyphs, 5..10);
+        for (line, previous) in [(empty, hello), (bevy, empty), (trailing, bevy)] {
+            assert!(line.baseline < previous.baseline);
+        }
+        // The empty lines have no glyphs and no width, at the center of the text
+        assert_eq!(
+            (empty.glyphs.clone(), trailing.glyphs.clone()),
+            (5..5, 10..10)
+        );
+        for line in [empty, trailing] {
+            assert_eq!(line.rect.width(), 0.);
+            approx::assert_abs_diff_eq!(line.rect.min.x, hello.rect.center().x, epsilon = 1.);
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use bevy_ecs::{component::Component, reflect::ReflectComponent, system::Resource};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Scales the font size of all text, such as for a "text size" accessibility setting.
+///
+/// The font size of each section, and the size of its inline images, is multiplied by this
+/// factor when the text is laid out, on top of the scale factor of the window and, for UI text,
+/// of `UiScale`. Text is only laid out again when the factor changes.
+///
+/// Entities with a [`TextScaleOverride`] are scaled by their own factor instead.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct TextScale(pub f32);
+
+impl Default for TextScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl TextScale {
+    /// Returns the factor scaling the text of an entity with `text_scale_override`.
+    pub fn resolve(&self, text_scale_override: Option<&TextScaleOverride>) -> f32 {
+        text_scale_override.map_or(self.0, |text_scale_override| text_scale_override.0)
+    }
+}
+
+/// Scales the font size of the text of its entity by its factor rather than by [`TextScale`],
+/// such as to keep the labels of a fixed-size HUD at their size.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct TextScaleOverride(pub f32);
+
+impl Default for TextScaleOverride {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
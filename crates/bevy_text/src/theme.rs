@@ -0,0 +1,222 @@
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    prelude::{Component, DetectChanges},
+    reflect::{ReflectComponent, ReflectResource},
+    system::{Local, Query, Res, Resource},
+    world::Ref,
+};
+use bevy_reflect::prelude::*;
+
+use crate::Text;
+
+/// The role a color plays in a [`Theme`].
+///
+/// Attach a [`ThemedColor`] to a text entity to have its color follow the [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum ColorRole {
+    /// The main accent color.
+    Primary,
+    /// Content drawn on top of [`ColorRole::Primary`].
+    OnPrimary,
+    /// A secondary accent color.
+    Secondary,
+    /// Content drawn on top of [`ColorRole::Secondary`].
+    OnSecondary,
+    /// The color of surfaces such as panels and cards.
+    Surface,
+    /// Content drawn on top of [`ColorRole::Surface`].
+    OnSurface,
+    /// The color used to indicate errors.
+    Error,
+    /// Content drawn on top of [`ColorRole::Error`].
+    OnError,
+}
+
+/// A central set of colors that [`ThemedColor`] text pulls from.
+///
+/// Changing this resource recolors all themed text at runtime, without requiring a relayout.
+#[derive(Resource, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct Theme {
+    /// The color of [`ColorRole::Primary`].
+    pub primary: Color,
+    /// The color of [`ColorRole::OnPrimary`].
+    pub on_primary: Color,
+    /// The color of [`ColorRole::Secondary`].
+    pub secondary: Color,
+    /// The color of [`ColorRole::OnSecondary`].
+    pub on_secondary: Color,
+    /// The color of [`ColorRole::Surface`].
+    pub surface: Color,
+    /// The color of [`ColorRole::OnSurface`].
+    pub on_surface: Color,
+    /// The color of [`ColorRole::Error`].
+    pub error: Color,
+    /// The color of [`ColorRole::OnError`].
+    pub on_error: Color,
+}
+
+impl Theme {
+    /// A theme with light content on dark surfaces.
+    pub const DARK: Self = Self {
+        primary: Color::Srgba(Srgba::rgb(0.73, 0.53, 0.99)),
+        on_primary: Color::BLACK,
+        secondary: Color::Srgba(Srgba::rgb(0.01, 0.85, 0.77)),
+        on_secondary: Color::BLACK,
+        surface: Color::Srgba(Srgba::rgb(0.07, 0.07, 0.07)),
+        on_surface: Color::WHITE,
+        error: Color::Srgba(Srgba::rgb(0.81, 0.4, 0.47)),
+        on_error: Color::BLACK,
+    };
+
+    /// A theme with dark content on light surfaces.
+    pub const LIGHT: Self = Self {
+        primary: Color::Srgba(Srgba::rgb(0.38, 0.0, 0.93)),
+        on_primary: Color::WHITE,
+        secondary: Color::Srgba(Srgba::rgb(0.01, 0.85, 0.77)),
+        on_secondary: Color::BLACK,
+        surface: Color::WHITE,
+        on_surface: Color::BLACK,
+        error: Color::Srgba(Srgba::rgb(0.69, 0.0, 0.13)),
+        on_error: Color::WHITE,
+    };
+
+    /// Returns the color assigned to `role`.
+    pub fn color(&self, role: ColorRole) -> Color {
+        match role {
+            ColorRole::Primary => self.primary,
+            ColorRole::OnPrimary => self.on_primary,
+            ColorRole::Secondary => self.secondary,
+            ColorRole::OnSecondary => self.on_secondary,
+            ColorRole::Surface => self.surface,
+            ColorRole::OnSurface => self.on_surface,
+            ColorRole::Error => self.error,
+            ColorRole::OnError => self.on_error,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DARK
+    }
+}
+
+/// Makes the color of every section of a [`Text`] follow a [`ColorRole`] of the [`Theme`].
+///
+/// The colors are written by [`update_themed_text_colors`], which overrides any
+/// [`TextStyle::color`](crate::TextStyle::color) set on the sections.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct ThemedColor(pub ColorRole);
+
+/// Writes the [`Theme`] color of each entity's [`ThemedColor`] role into the sections of its [`Text`].
+///
+/// Only entities whose role maps to a color that changed, whose [`ThemedColor`] changed,
+/// or whose [`Text`] changed are written to, and their [`Text`] is only marked as changed if one
+/// of its section colors differs from the theme color.
+///
+/// [`TextPlugin`](crate::TextPlugin) runs it in [`PostUpdate`](bevy_app::PostUpdate), before the
+/// text layout systems of both `Text2d` and UI text, so a theme change is laid out and extracted
+/// in the same frame.
+pub fn update_themed_text_colors(
+    theme: Res<Theme>,
+    mut previous_theme: Local<Option<Theme>>,
+    mut query: Query<(Ref<ThemedColor>, &mut Text)>,
+) {
+    let theme_changed = theme.is_changed();
+    for (themed_color, mut text) in &mut query {
+        let role = themed_color.0;
+        let color = theme.color(role);
+        let role_changed = theme_changed
+            && previous_theme
+                .as_ref()
+                .map_or(true, |previous| previous.color(role) != color);
+
+        if (role_changed || themed_color.is_changed() || text.is_changed())
+            && text
+                .sections
+                .iter()
+                .any(|section| section.style.color != color)
+        {
+            for section in &mut text.sections {
+                section.style.color = color;
+            }
+        }
+    }
+
+    if theme_changed {
+        *previous_theme = Some(theme.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_color::Color;
+    use bevy_ecs::prelude::*;
+
+    use super::*;
+    use crate::TextStyle;
+
+    fn color_of(app: &App, entity: Entity) -> Color {
+        app.world().get::<Text>(entity).unwrap().sections[0]
+            .style
+            .color
+    }
+
+    #[test]
+    fn only_changed_roles_are_written() {
+        let mut app = App::new();
+        app.init_resource::<Theme>()
+            .add_systems(Update, update_themed_text_colors);
+
+        let text = || Text::from_section("text", TextStyle::default());
+        let primary = app
+            .world_mut()
+            .spawn((text(), ThemedColor(ColorRole::Primary)))
+            .id();
+        let surface = app
+            .world_mut()
+            .spawn((text(), ThemedColor(ColorRole::OnSurface)))
+            .id();
+        let unthemed = app.world_mut().spawn(text()).id();
+
+        app.update();
+        assert_eq!(color_of(&app, primary), Theme::DARK.primary);
+        assert_eq!(color_of(&app, surface), Theme::DARK.on_surface);
+        assert_eq!(color_of(&app, unthemed), TextStyle::default().color);
+
+        // Overwrite the surface text color behind the system's back: as its role's color is
+        // unchanged by the new theme, it must not be written again.
+        app.world_mut()
+            .get_mut::<Text>(surface)
+            .unwrap()
+            .bypass_change_detection()
+            .sections[0]
+            .style
+            .color = Color::BLACK;
+        app.world_mut().resource_mut::<Theme>().primary = Color::WHITE;
+
+        app.update();
+        assert_eq!(color_of(&app, primary), Color::WHITE);
+        assert_eq!(color_of(&app, surface), Color::BLACK);
+
+        // Texts are only marked as changed when their colors change
+        let changed_tick = |app: &App, entity| {
+            app.world()
+                .entity(entity)
+                .get_change_ticks::<Text>()
+                .unwrap()
+                .last_changed_tick()
+        };
+        let (primary_tick, surface_tick) =
+            (changed_tick(&app, primary), changed_tick(&app, surface));
+        app.world_mut().resource_mut::<Theme>().primary = Color::BLACK;
+        app.update();
+        assert_ne!(changed_tick(&app, primary), primary_tick);
+        assert_eq!(changed_tick(&app, surface), surface_tick);
+    }
+}
@@ -0,0 +1,300 @@
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    component::Component,
+    system::{Res, ResMut, Resource},
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+use bevy_utils::{Duration, HashMap};
+
+use crate::{time::Time, virt::Virtual};
+
+/// The name of an auxiliary [`Clock`] of the [`Clocks`] resource.
+///
+/// ```
+/// # use bevy_time::ClockId;
+/// const ENEMIES: ClockId = ClockId("enemies");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct ClockId(pub &'static str);
+
+/// Makes the entity follow an auxiliary [`Clock`] instead of [`Time<Virtual>`](Virtual).
+///
+/// Systems and helpers that advance entities over time, such as animation players, consult this
+/// component to get the delta time of the entity from the [`Clocks`] resource.
+/// Entities following a clock that isn't registered follow [`Time<Virtual>`](Virtual).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct UseClock(pub ClockId);
+
+/// An auxiliary clock, advanced from [`Time<Virtual>`](Virtual) at its own relative speed.
+///
+/// Pausing a clock doesn't pause virtual time, and pausing virtual time pauses every clock.
+///
+/// A clock can drive its own fixed timestep: when [`Clock::set_fixed_timestep`] is set, the
+/// clock accumulates its delta and counts the [`fixed_steps`](Clock::fixed_steps) to run on each frame,
+/// independently of [`Time<Fixed>`](crate::Fixed).
+#[derive(Debug, Clone)]
+pub struct Clock {
+    time: Time,
+    relative_speed: f64,
+    paused: bool,
+    fixed_timestep: Option<Duration>,
+    overstep: Duration,
+    fixed_steps: u32,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self {
+            time: Time::default(),
+            relative_speed: 1.0,
+            paused: false,
+            fixed_timestep: None,
+            overstep: Duration::ZERO,
+            fixed_steps: 0,
+        }
+    }
+}
+
+impl Clock {
+    /// The time of this clock, whose delta was scaled by its relative speed.
+    #[inline]
+    pub fn time(&self) -> &Time {
+        &self.time
+    }
+
+    /// How much time this clock advanced since the last frame.
+    #[inline]
+    pub fn delta(&self) -> Duration {
+        self.time.delta()
+    }
+
+    /// How much time this clock advanced since the last frame, as [`f32`] seconds.
+    #[inline]
+    pub fn delta_seconds(&self) -> f32 {
+        self.time.delta_seconds()
+    }
+
+    /// How much time this clock advanced since its creation.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.time.elapsed()
+    }
+
+    /// Returns the speed of this clock, relative to [`Time<Virtual>`](Virtual).
+    #[inline]
+    pub fn relative_speed(&self) -> f64 {
+        self.relative_speed
+    }
+
+    /// Sets the speed of this clock, relative to [`Time<Virtual>`](Virtual).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    pub fn set_relative_speed(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.relative_speed = ratio;
+    }
+
+    /// Stops the clock, without pausing [`Time<Virtual>`](Virtual).
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the clock.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the clock is paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the fixed timestep of this clock, if any.
+    #[inline]
+    pub fn fixed_timestep(&self) -> Option<Duration> {
+        self.fixed_timestep
+    }
+
+    /// Sets the fixed timestep driven by this clock, or removes it with `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    pub fn set_fixed_timestep(&mut self, timestep: Option<Duration>) {
+        assert_ne!(
+            timestep,
+            Some(Duration::ZERO),
+            "attempted to set fixed timestep to zero"
+        );
+        self.fixed_timestep = timestep;
+        self.overstep = Duration::ZERO;
+        self.fixed_steps = 0;
+    }
+
+    /// The number of fixed timesteps this clock accumulated on the last frame.
+    ///
+    /// Always zero if the clock has no [`fixed_timestep`](Self::fixed_timestep).
+    #[inline]
+    pub fn fixed_steps(&self) -> u32 {
+        self.fixed_steps
+    }
+
+    /// The time accumulated towards the next fixed timestep, as a fraction of the timestep.
+    ///
+    /// Useful to interpolate between fixed timesteps. Zero if the clock has no fixed timestep.
+    pub fn overstep_fraction(&self) -> f32 {
+        self.fixed_timestep.map_or(0.0, |timestep| {
+            self.overstep.as_secs_f32() / timestep.as_secs_f32()
+        })
+    }
+
+    /// Advances the clock by `virtual_delta`, scaled by its relative speed.
+    pub fn advance_by(&mut self, virtual_delta: Duration) {
+        let delta = if self.paused {
+            Duration::ZERO
+        } else if self.relative_speed == 1.0 {
+            virtual_delta
+        } else {
+            virtual_delta.mul_f64(self.relative_speed)
+        };
+        self.time.advance_by(delta);
+
+        self.fixed_steps = 0;
+        if let Some(timestep) = self.fixed_timestep {
+            self.overstep += delta;
+            while self.overstep >= timestep {
+                self.overstep -= timestep;
+                self.fixed_steps += 1;
+            }
+        }
+    }
+}
+
+/// Auxiliary clocks, advanced from [`Time<Virtual>`](Virtual) by [`advance_clocks`], each with
+/// their own relative speed and pause state.
+///
+/// This allows slowing down some entities without affecting others, for instance for a
+/// "bullet time" effect slowing enemies while the player and UI run at normal speed.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{ClockId, Clocks, Timer, UseClock};
+/// const ENEMIES: ClockId = ClockId("enemies");
+///
+/// fn start_bullet_time(mut clocks: ResMut<Clocks>) {
+///     clocks.register(ENEMIES).set_relative_speed(0.2);
+/// }
+///
+/// #[derive(Component)]
+/// struct Cooldown(Timer);
+///
+/// fn tick_cooldowns(clocks: Res<Clocks>, mut query: Query<(&mut Cooldown, &UseClock)>) {
+///     for (mut cooldown, use_clock) in &mut query {
+///         if let Some(clock) = clocks.get(use_clock.0) {
+///             cooldown.0.tick_with(clock);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct Clocks {
+    clocks: HashMap<ClockId, Clock>,
+}
+
+impl Clocks {
+    /// Returns the clock named `id`, registering it if it doesn't exist yet.
+    pub fn register(&mut self, id: ClockId) -> &mut Clock {
+        self.clocks.entry(id).or_default()
+    }
+
+    /// Removes the clock named `id`, returning it if it was registered.
+    pub fn unregister(&mut self, id: ClockId) -> Option<Clock> {
+        self.clocks.remove(&id)
+    }
+
+    /// Returns the clock named `id`, if it's registered.
+    pub fn get(&self, id: ClockId) -> Option<&Clock> {
+        self.clocks.get(&id)
+    }
+
+    /// Returns the clock named `id` mutably, if it's registered.
+    pub fn get_mut(&mut self, id: ClockId) -> Option<&mut Clock> {
+        self.clocks.get_mut(&id)
+    }
+
+    /// Returns the delta time of an entity with an optional [`UseClock`],
+    /// or `virtual_delta` if it doesn't follow a registered clock.
+    pub fn delta_for(&self, use_clock: Option<&UseClock>, virtual_delta: Duration) -> Duration {
+        use_clock
+            .and_then(|use_clock| self.get(use_clock.0))
+            .map_or(virtual_delta, Clock::delta)
+    }
+
+    /// Returns an iterator over the registered clocks.
+    pub fn iter(&self) -> impl Iterator<Item = (ClockId, &Clock)> {
+        self.clocks.iter().map(|(id, clock)| (*id, clock))
+    }
+}
+
+/// Advances the [`Clocks`] by the delta of [`Time<Virtual>`](Virtual).
+pub fn advance_clocks(virtual_time: Res<Time<Virtual>>, mut clocks: ResMut<Clocks>) {
+    let delta = virtual_time.delta();
+    for clock in clocks.clocks.values_mut() {
+        clock.advance_by(delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENEMIES: ClockId = ClockId("enemies");
+
+    #[test]
+    fn clocks_scale_and_pause() {
+        let mut clocks = Clocks::default();
+        let enemies = clocks.register(ENEMIES);
+        enemies.set_relative_speed(0.25);
+        enemies.set_fixed_timestep(Some(Duration::from_millis(100)));
+
+        let mut virtual_time = Time::<Virtual>::default();
+        let mut advance = |clocks: &mut Clocks, millis| {
+            virtual_time.advance_by(Duration::from_millis(millis));
+            for clock in clocks.clocks.values_mut() {
+                clock.advance_by(virtual_time.delta());
+            }
+        };
+
+        advance(&mut clocks, 1000);
+        let enemies = clocks.get(ENEMIES).unwrap();
+        assert_eq!(enemies.delta(), Duration::from_millis(250));
+        assert_eq!(enemies.fixed_steps(), 2);
+        assert_eq!(
+            clocks.delta_for(Some(&UseClock(ENEMIES)), Duration::from_millis(1000)),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            clocks.delta_for(None, Duration::from_millis(1000)),
+            Duration::from_millis(1000)
+        );
+
+        // Pausing the clock doesn't pause virtual time
+        clocks.get_mut(ENEMIES).unwrap().pause();
+        advance(&mut clocks, 1000);
+        let enemies = clocks.get(ENEMIES).unwrap();
+        assert_eq!(enemies.delta(), Duration::ZERO);
+        assert_eq!(enemies.elapsed(), Duration::from_millis(250));
+        assert_eq!(enemies.fixed_steps(), 0);
+        assert!((enemies.overstep_fraction() - 0.5).abs() < 1e-6);
+    }
+}
@@ -1,5 +1,8 @@
 use bevy_app::FixedMain;
-use bevy_ecs::world::World;
+use bevy_ecs::{
+    event::{Event, Events},
+    world::World,
+};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use bevy_utils::Duration;
@@ -64,11 +67,35 @@ use crate::{time::Time, virt::Virtual};
 /// [`FixedUpdate`](bevy_app::FixedUpdate), even if it is still during the same
 /// frame. Any [`overstep()`](Time::overstep) present in the accumulator will be
 /// processed according to the new [`timestep()`](Time::timestep) value.
+///
+/// When a frame takes long, the schedule runs many times to catch up, which
+/// makes the next frame take even longer. To avoid this "death spiral",
+/// [`set_max_steps_per_frame()`](Time::set_max_steps_per_frame) caps the
+/// number of runs per frame. Once the cap is reached, the whole timesteps left
+/// in the [`overstep()`](Time::overstep) are discarded, and only the fraction of
+/// a timestep is kept, so interpolating with
+/// [`overstep_fraction()`](Time::overstep_fraction) stays smooth. The discarded
+/// time is reported with a [`FixedUpdateOverrun`] event, and is lost: the
+/// fixed clock falls behind the virtual clock. There is no cap by default.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub struct Fixed {
     timestep: Duration,
     overstep: Duration,
+    max_steps_per_frame: Option<u32>,
+}
+
+/// Sent when [`FixedMain`] reached the
+/// [maximum number of steps per frame](Time::set_max_steps_per_frame) of
+/// [`Time<Fixed>`](Fixed), and the remaining steps were discarded.
+///
+/// Games can use it to warn about the slowdown, or to resynchronize their state.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedUpdateOverrun {
+    /// The time discarded from the [`overstep()`](Time::overstep).
+    pub dropped: Duration,
+    /// The number of timesteps that were not run.
+    pub dropped_steps: u32,
 }
 
 impl Time<Fixed> {
@@ -205,6 +232,31 @@ impl Time<Fixed> {
         self.context().overstep.as_secs_f64() / self.context().timestep.as_secs_f64()
     }
 
+    /// Returns the maximum number of times [`FixedMain`] runs per frame, if any.
+    #[inline]
+    pub fn max_steps_per_frame(&self) -> Option<u32> {
+        self.context().max_steps_per_frame
+    }
+
+    /// Sets the maximum number of times [`FixedMain`] runs per frame, or removes
+    /// the cap with `None`.
+    ///
+    /// The whole timesteps left once the cap is reached are discarded, and
+    /// reported with a [`FixedUpdateOverrun`] event. See [`Fixed`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is zero.
+    #[inline]
+    pub fn set_max_steps_per_frame(&mut self, max_steps: Option<u32>) {
+        assert_ne!(
+            max_steps,
+            Some(0),
+            "attempted to set max fixed steps per frame to zero"
+        );
+        self.context_mut().max_steps_per_frame = max_steps;
+    }
+
     fn accumulate(&mut self, delta: Duration) {
         self.context_mut().overstep += delta;
     }
@@ -221,6 +273,22 @@ impl Time<Fixed> {
             false
         }
     }
+
+    /// Discards the whole timesteps left in the overstep, keeping the fraction of a timestep.
+    fn discard_steps(&mut self) -> Option<FixedUpdateOverrun> {
+        let timestep = self.timestep().as_nanos();
+        let overstep = self.overstep();
+        let dropped_steps = (overstep.as_nanos() / timestep) as u32;
+        if dropped_steps == 0 {
+            return None;
+        }
+        let remainder = Duration::from_nanos((overstep.as_nanos() % timestep) as u64);
+        self.context_mut().overstep = remainder;
+        Some(FixedUpdateOverrun {
+            dropped: overstep - remainder,
+            dropped_steps,
+        })
+    }
 }
 
 impl Default for Fixed {
@@ -228,24 +296,47 @@ impl Default for Fixed {
         Self {
             timestep: Time::<Fixed>::DEFAULT_TIMESTEP,
             overstep: Duration::ZERO,
+            max_steps_per_frame: None,
         }
     }
 }
 
 /// Runs [`FixedMain`] zero or more times based on delta of
-/// [`Time<Virtual>`](Virtual) and [`Time::overstep`]
+/// [`Time<Virtual>`](Virtual) and [`Time::overstep`], up to
+/// [`Time::max_steps_per_frame`] times.
+///
+/// Sends a [`FixedUpdateOverrun`] event when steps were discarded.
 pub fn run_fixed_main_schedule(world: &mut World) {
     let delta = world.resource::<Time<Virtual>>().delta();
     world.resource_mut::<Time<Fixed>>().accumulate(delta);
 
-    // Run the schedule until we run out of accumulated time
+    // Run the schedule until we run out of accumulated time, or reach the cap
+    let mut steps = 0;
     let _ = world.try_schedule_scope(FixedMain, |world, schedule| {
-        while world.resource_mut::<Time<Fixed>>().expend() {
+        loop {
+            // The cap and the timestep are read on every step, since fixed systems may change them
+            let mut fixed = world.resource_mut::<Time<Fixed>>();
+            if fixed.max_steps_per_frame().is_some_and(|max| steps >= max) || !fixed.expend() {
+                break;
+            }
+            steps += 1;
             *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
             schedule.run(world);
         }
     });
 
+    let mut fixed = world.resource_mut::<Time<Fixed>>();
+    let overrun = fixed
+        .max_steps_per_frame()
+        .is_some_and(|max| steps >= max)
+        .then(|| fixed.discard_steps())
+        .flatten();
+    if let Some(overrun) = overrun {
+        if let Some(mut events) = world.get_resource_mut::<Events<FixedUpdateOverrun>>() {
+            events.send(overrun);
+        }
+    }
+
     *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
 }
 
@@ -356,4 +447,45 @@ mod test {
         assert_eq!(time.elapsed(), Duration::from_secs(6));
         assert_eq!(time.overstep(), Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_max_steps_per_frame() {
+        use bevy_ecs::system::{ResMut, Resource};
+
+        #[derive(Resource, Default)]
+        struct Steps(u32);
+
+        let mut world = World::new();
+        let mut virtual_time = Time::<Virtual>::default();
+        virtual_time.advance_by(Duration::from_secs(1));
+        let mut fixed = Time::<Fixed>::from_duration(Duration::from_millis(300));
+        fixed.set_max_steps_per_frame(Some(2));
+        fixed.accumulate(Duration::from_millis(50));
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(virtual_time);
+        world.insert_resource(fixed);
+        world.init_resource::<Steps>();
+        world.init_resource::<Events<FixedUpdateOverrun>>();
+        let mut schedule = bevy_ecs::schedule::Schedule::new(FixedMain);
+        schedule.add_systems(|mut steps: ResMut<Steps>| steps.0 += 1);
+        world.add_schedule(schedule);
+
+        // 1.05s accumulated: 2 steps run, the 1 whole step left is dropped, and the fraction is kept
+        run_fixed_main_schedule(&mut world);
+        assert_eq!(world.resource::<Steps>().0, 2);
+        let fixed = world.resource::<Time<Fixed>>();
+        assert_eq!(fixed.elapsed(), Duration::from_millis(600));
+        assert_eq!(fixed.overstep(), Duration::from_millis(150));
+        let overruns: Vec<_> = world
+            .resource_mut::<Events<FixedUpdateOverrun>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            overruns,
+            [FixedUpdateOverrun {
+                dropped: Duration::from_millis(300),
+                dropped_steps: 1,
+            }]
+        );
+    }
 }
@@ -6,18 +6,22 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
+mod clocks;
 /// Common run conditions
 pub mod common_conditions;
 mod fixed;
 mod real;
+mod scheduled;
 mod stopwatch;
 #[allow(clippy::module_inception)]
 mod time;
 mod timer;
 mod virt;
 
+pub use clocks::*;
 pub use fixed::*;
 pub use real::*;
+pub use scheduled::*;
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
@@ -26,7 +30,7 @@ pub use virt::*;
 pub mod prelude {
     //! The Bevy Time Prelude.
     #[doc(hidden)]
-    pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
+    pub use crate::{Fixed, Real, Time, Timer, TimerAppExt, TimerMode, Virtual};
 }
 
 use bevy_app::{prelude::*, RunFixedMainLoop};
@@ -51,7 +55,9 @@ impl Plugin for TimePlugin {
             .init_resource::<Time<Real>>()
             .init_resource::<Time<Virtual>>()
             .init_resource::<Time<Fixed>>()
-            .init_resource::<TimeUpdateStrategy>();
+            .init_resource::<Clocks>()
+            .init_resource::<TimeUpdateStrategy>()
+            .add_event::<FixedUpdateOverrun>();
 
         #[cfg(feature = "bevy_reflect")]
         {
@@ -59,11 +65,15 @@ impl Plugin for TimePlugin {
                 .register_type::<Time<Real>>()
                 .register_type::<Time<Virtual>>()
                 .register_type::<Time<Fixed>>()
-                .register_type::<Timer>();
+                .register_type::<Timer>()
+                .register_type::<UseClock>();
         }
 
-        app.add_systems(First, time_system.in_set(TimeSystem))
-            .add_systems(RunFixedMainLoop, run_fixed_main_schedule);
+        app.add_systems(
+            First,
+            (time_system, advance_clocks).chain().in_set(TimeSystem),
+        )
+        .add_systems(RunFixedMainLoop, run_fixed_main_schedule);
 
         // Ensure the events are not dropped until `FixedMain` systems can observe them
         app.add_systems(FixedPostUpdate, signal_event_update_system);
@@ -89,6 +99,11 @@ pub enum TimeUpdateStrategy {
     /// Note that the `Time` resource will not be updated until [`TimeSystem`] runs.
     ManualInstant(Instant),
     /// [`Time`] will be incremented by the specified [`Duration`] each frame.
+    ///
+    /// The times don't depend on the system clock, so they're the same from one run to the next.
+    /// To replay a sequence of frame times, change this value before [`TimeSystem`] runs in each
+    /// frame. The virtual clock can also be started at a given time by advancing it with
+    /// [`Time::advance_by`] before the first frame.
     ManualDuration(Duration),
 }
 
@@ -362,4 +377,36 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn manual_duration_replays_the_same_times() {
+        let seed = Duration::from_millis(1500);
+        let frame_times = [5, 20, 8, 33, 16].map(Duration::from_millis);
+
+        let replay = || {
+            let mut app = App::new();
+            app.add_plugins(TimePlugin);
+            app.world_mut()
+                .resource_mut::<Time<Virtual>>()
+                .advance_by(seed);
+
+            frame_times
+                .iter()
+                .map(|frame_time| {
+                    app.insert_resource(TimeUpdateStrategy::ManualDuration(*frame_time));
+                    app.update();
+                    app.world().resource::<Time<Virtual>>().elapsed()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let elapsed = replay();
+        assert_eq!(elapsed, replay());
+        // The first frame only starts the real clock
+        assert_eq!(elapsed[0], seed);
+        assert_eq!(
+            *elapsed.last().unwrap(),
+            seed + frame_times[1..].iter().sum::<Duration>()
+        );
+    }
 }
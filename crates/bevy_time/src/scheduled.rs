@@ -0,0 +1,93 @@
+use bevy_app::{App, Update};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_utils::Duration;
+
+use crate::common_conditions::{on_timer, once_after_delay};
+
+/// Methods scheduling systems to run after a delay, or periodically, for [`App`].
+///
+/// The systems are added to the [`Update`] schedule, and their timers are advanced by the default
+/// [`Time`](crate::Time), which follows [`Time<Virtual>`](crate::Virtual) there: they are paused
+/// while virtual time is paused, and scaled by its relative speed.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_time::TimerAppExt;
+/// # use bevy_utils::Duration;
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .run_after(Duration::from_secs(3), hide_splash_screen)
+///         .run_every(Duration::from_secs(60), autosave)
+///         .run();
+/// }
+/// # fn hide_splash_screen() {}
+/// # fn autosave() {}
+/// ```
+pub trait TimerAppExt {
+    /// Runs `systems` once in [`Update`], after `delay` elapsed.
+    ///
+    /// See [`once_after_delay`].
+    fn run_after<M>(&mut self, delay: Duration, systems: impl IntoSystemConfigs<M>) -> &mut Self;
+
+    /// Runs `systems` in [`Update`] every time `period` elapsed.
+    ///
+    /// The systems run at most once per frame, even if the period elapsed several times during the
+    /// frame: use a [`Timer`](crate::Timer) and its [`finish_offsets`](crate::Timer::finish_offsets)
+    /// to handle every completion. See [`on_timer`].
+    fn run_every<M>(&mut self, period: Duration, systems: impl IntoSystemConfigs<M>) -> &mut Self;
+}
+
+impl TimerAppExt for App {
+    fn run_after<M>(&mut self, delay: Duration, systems: impl IntoSystemConfigs<M>) -> &mut Self {
+        self.add_systems(Update, systems.run_if(once_after_delay(delay)))
+    }
+
+    fn run_every<M>(&mut self, period: Duration, systems: impl IntoSystemConfigs<M>) -> &mut Self {
+        self.add_systems(Update, systems.run_if(on_timer(period)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Time, TimePlugin, TimeUpdateStrategy, Virtual};
+    use bevy_ecs::system::{ResMut, Resource};
+
+    #[derive(Resource, Default)]
+    struct Runs {
+        after: u32,
+        every: u32,
+    }
+
+    #[test]
+    fn scheduled_systems_follow_virtual_time() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .init_resource::<Runs>()
+            .run_after(Duration::from_millis(450), |mut runs: ResMut<Runs>| {
+                runs.after += 1;
+            })
+            .run_every(Duration::from_millis(200), |mut runs: ResMut<Runs>| {
+                runs.every += 1;
+            });
+
+        // The first update only starts the clock
+        for _ in 0..11 {
+            app.update();
+        }
+        let runs = app.world().resource::<Runs>();
+        assert_eq!((runs.after, runs.every), (1, 5));
+
+        // Nothing runs while virtual time is paused
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        for _ in 0..10 {
+            app.update();
+        }
+        let runs = app.world().resource::<Runs>();
+        assert_eq!((runs.after, runs.every), (1, 5));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Stopwatch;
+use crate::{Clock, Stopwatch};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
 use bevy_utils::Duration;
@@ -21,6 +21,8 @@ pub struct Timer {
     mode: TimerMode,
     finished: bool,
     times_finished_this_tick: u32,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    overshoot: Duration,
 }
 
 impl Timer {
@@ -221,6 +223,7 @@ impl Timer {
     pub fn tick(&mut self, delta: Duration) -> &Self {
         if self.paused() {
             self.times_finished_this_tick = 0;
+            self.overshoot = Duration::ZERO;
             if self.mode == TimerMode::Repeating {
                 self.finished = false;
             }
@@ -229,6 +232,7 @@ impl Timer {
 
         if self.mode != TimerMode::Repeating && self.finished() {
             self.times_finished_this_tick = 0;
+            self.overshoot = Duration::ZERO;
             return self;
         }
 
@@ -248,17 +252,28 @@ impl Timer {
                         .checked_rem(self.duration().as_nanos())
                         .map_or(Duration::ZERO, |x| Duration::from_nanos(x as u64)),
                 );
+                self.overshoot = self.elapsed();
             } else {
                 self.times_finished_this_tick = 1;
+                self.overshoot = self.elapsed() - self.duration();
                 self.set_elapsed(self.duration());
             }
         } else {
             self.times_finished_this_tick = 0;
+            self.overshoot = Duration::ZERO;
         }
 
         self
     }
 
+    /// Advance the timer by the delta of an auxiliary [`Clock`].
+    ///
+    /// This is a shorthand for [`tick`](Timer::tick) with [`Clock::delta`], for timers of entities
+    /// with a [`UseClock`](crate::UseClock).
+    pub fn tick_with(&mut self, clock: &Clock) -> &Self {
+        self.tick(clock.delta())
+    }
+
     /// Pauses the Timer. Disables the ticking of the timer.
     ///
     /// See also [`Stopwatch::pause`](Stopwatch::pause).
@@ -335,6 +350,7 @@ impl Timer {
         self.stopwatch.reset();
         self.finished = false;
         self.times_finished_this_tick = 0;
+        self.overshoot = Duration::ZERO;
     }
 
     /// Returns the fraction of the timer elapsed time (goes from 0.0 to 1.0).
@@ -425,6 +441,50 @@ impl Timer {
     pub fn times_finished_this_tick(&self) -> u32 {
         self.times_finished_this_tick
     }
+
+    /// Returns how far past its last completion the timer went during the last [`tick`](Timer::tick),
+    /// or zero if it didn't finish during that tick.
+    ///
+    /// This is the time elapsed between the completion and the end of the tick, which is lost
+    /// when a non repeating timer clamps its elapsed time to its duration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_time::*;
+    /// use std::time::Duration;
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
+    /// timer.tick(Duration::from_secs_f32(1.25));
+    /// assert_eq!(timer.overshoot(), Duration::from_secs_f32(0.25));
+    /// timer.tick(Duration::from_secs_f32(1.0));
+    /// assert_eq!(timer.overshoot(), Duration::ZERO);
+    /// ```
+    #[inline]
+    pub fn overshoot(&self) -> Duration {
+        self.overshoot
+    }
+
+    /// Returns an iterator over the completions of the last [`tick`](Timer::tick), from the earliest
+    /// to the latest, giving for each one how long before the end of the tick it happened.
+    ///
+    /// This allows back-dating what happens on each completion, for instance to spawn projectiles
+    /// at fixed intervals without drifting, by advancing each one by its offset.
+    /// The iterator yields [`times_finished_this_tick`](Timer::times_finished_this_tick) items,
+    /// and its last item is the [`overshoot`](Timer::overshoot).
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_time::*;
+    /// use std::time::Duration;
+    /// let mut timer = Timer::from_seconds(1.0, TimerMode::Repeating);
+    /// timer.tick(Duration::from_secs_f32(2.5));
+    /// let offsets: Vec<_> = timer.finish_offsets().collect();
+    /// assert_eq!(offsets, [Duration::from_secs_f32(1.5), Duration::from_secs_f32(0.5)]);
+    /// ```
+    pub fn finish_offsets(&self) -> impl ExactSizeIterator<Item = Duration> + '_ {
+        (0..self.times_finished_this_tick)
+            .rev()
+            .map(|completions_after| self.overshoot + self.duration * completions_after)
+    }
 }
 
 /// Specifies [`Timer`] behavior.
@@ -607,4 +667,38 @@ mod tests {
         assert!(!t.just_finished());
         assert!(!t.finished());
     }
+
+    #[test]
+    fn overshoot_and_finish_offsets() {
+        let mut t = Timer::from_seconds(2.0, TimerMode::Repeating);
+        t.tick(Duration::from_secs_f32(1.5));
+        assert_eq!(t.overshoot(), Duration::ZERO);
+        assert_eq!(t.finish_offsets().len(), 0);
+
+        // Finishes at 2s, 4s and 6s, and the tick ends at 6.5s
+        t.tick(Duration::from_secs_f32(5.0));
+        assert_eq!(t.overshoot(), Duration::from_secs_f32(0.5));
+        assert_eq!(
+            t.finish_offsets().collect::<Vec<_>>(),
+            [
+                Duration::from_secs_f32(4.5),
+                Duration::from_secs_f32(2.5),
+                Duration::from_secs_f32(0.5),
+            ]
+        );
+
+        // The overshoot of a non repeating timer isn't lost when its elapsed time is clamped
+        let mut t = Timer::from_seconds(2.0, TimerMode::Once);
+        t.tick(Duration::from_secs_f32(2.75));
+        assert_eq!(t.elapsed(), Duration::from_secs_f32(2.0));
+        assert_eq!(t.overshoot(), Duration::from_secs_f32(0.75));
+        assert_eq!(
+            t.finish_offsets().collect::<Vec<_>>(),
+            [Duration::from_secs_f32(0.75)]
+        );
+        t.tick(Duration::from_secs_f32(1.0));
+        assert_eq!(t.overshoot(), Duration::ZERO);
+        t.reset();
+        assert_eq!(t.finish_offsets().len(), 0);
+    }
 }
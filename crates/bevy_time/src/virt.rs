@@ -37,6 +37,28 @@ use crate::{real::Real, time::Time};
 /// [`set_relative_speed()`](Time::set_relative_speed) will not affect the
 /// [`delta()`](Time::delta) value for the update currently being processed.
 ///
+/// Systems simulating the game should read the generic [`Time`], so that they
+/// slow down, speed up and pause with the virtual clock. Systems that must keep
+/// running at the speed of the wall clock, such as debug overlays or menus shown
+/// while the game is paused, should read [`Time<Real>`](Real) instead.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// fn toggle_slow_motion(mut time: ResMut<Time<Virtual>>) {
+///     let speed = if time.relative_speed() < 1.0 { 1.0 } else { 0.25 };
+///     time.set_relative_speed(speed);
+/// }
+///
+/// fn toggle_pause(mut time: ResMut<Time<Virtual>>) {
+///     if time.is_paused() {
+///         time.unpause();
+///     } else {
+///         time.pause();
+///     }
+/// }
+/// ```
+///
 /// The maximum amount of delta time that can be added by a single update can be
 /// set by [`set_max_delta()`](Time::set_max_delta). This value serves a dual
 /// purpose in the virtual clock.
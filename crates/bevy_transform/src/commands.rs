@@ -3,7 +3,51 @@
 
 use crate::prelude::{GlobalTransform, Transform};
 use bevy_ecs::{prelude::Entity, system::EntityCommands, world::Command, world::World};
-use bevy_hierarchy::{PushChild, RemoveParent};
+use bevy_hierarchy::{Parent, PushChild, RemoveParent};
+
+/// Computes the [`GlobalTransform`] of `entity` from the [`Transform`]s of its ancestors.
+///
+/// The [`GlobalTransform`]s stored on the entities may be stale, if their [`Transform`]s or the
+/// hierarchy changed since the last transform propagation, for instance when reparenting an entity
+/// twice in a frame. This falls back to the stored [`GlobalTransform`] if an ancestor has no
+/// [`Transform`].
+fn compute_global_transform(world: &World, entity: Entity) -> Option<GlobalTransform> {
+    let compute = || {
+        let entity = world.get_entity(entity)?;
+        let mut global_transform = GlobalTransform::from(*entity.get::<Transform>()?);
+        let mut parent = entity.get::<Parent>().map(Parent::get);
+        while let Some(ancestor) = parent {
+            let ancestor = world.get_entity(ancestor)?;
+            global_transform = *ancestor.get::<Transform>()? * global_transform;
+            parent = ancestor.get::<Parent>().map(Parent::get);
+        }
+        Some(global_transform)
+    };
+    compute().or_else(|| world.get::<GlobalTransform>(entity).copied())
+}
+
+/// Sets the [`Transform`] of `entity` so that its [`GlobalTransform`] is `global_transform`
+/// under its current parent, if any.
+fn set_global_transform(world: &mut World, entity: Entity, global_transform: GlobalTransform) {
+    let parent = world.get::<Parent>(entity).map(Parent::get);
+    let transform = match parent {
+        Some(parent) => match compute_global_transform(world, parent) {
+            Some(parent) => global_transform.reparented_to(&parent),
+            None => return,
+        },
+        None => global_transform.compute_transform(),
+    };
+    let Some(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if let Some(mut local) = entity.get_mut::<Transform>() {
+        *local = transform;
+    }
+    // Keep the global transform up to date for the systems running before the next propagation
+    if let Some(mut global) = entity.get_mut::<GlobalTransform>() {
+        *global = global_transform;
+    }
+}
 
 /// Command similar to [`PushChild`], but updating the child transform to keep
 /// it at the same [`GlobalTransform`].
@@ -18,21 +62,16 @@ pub struct PushChildInPlace {
 }
 impl Command for PushChildInPlace {
     fn apply(self, world: &mut World) {
+        // The global transform must be computed under the previous parent
+        let child_global = compute_global_transform(world, self.child);
         let hierarchy_command = PushChild {
             child: self.child,
             parent: self.parent,
         };
         hierarchy_command.apply(world);
-        // FIXME: Replace this closure with a `try` block. See: https://github.com/rust-lang/rust/issues/31436.
-        let mut update_transform = || {
-            let parent = *world.get_entity(self.parent)?.get::<GlobalTransform>()?;
-            let child_global = *world.get_entity(self.child)?.get::<GlobalTransform>()?;
-            let mut child_entity = world.get_entity_mut(self.child)?;
-            let mut child = child_entity.get_mut::<Transform>()?;
-            *child = child_global.reparented_to(&parent);
-            Some(())
-        };
-        update_transform();
+        if let Some(child_global) = child_global {
+            set_global_transform(world, self.child, child_global);
+        }
     }
 }
 /// Command similar to [`RemoveParent`], but updating the child transform to keep
@@ -46,17 +85,13 @@ pub struct RemoveParentInPlace {
 }
 impl Command for RemoveParentInPlace {
     fn apply(self, world: &mut World) {
+        // The global transform must be computed under the previous parent
+        let child_global = compute_global_transform(world, self.child);
         let hierarchy_command = RemoveParent { child: self.child };
         hierarchy_command.apply(world);
-        // FIXME: Replace this closure with a `try` block. See: https://github.com/rust-lang/rust/issues/31436.
-        let mut update_transform = || {
-            let child_global = *world.get_entity(self.child)?.get::<GlobalTransform>()?;
-            let mut child_entity = world.get_entity_mut(self.child)?;
-            let mut child = child_entity.get_mut::<Transform>()?;
-            *child = child_global.compute_transform();
-            Some(())
-        };
-        update_transform();
+        if let Some(child_global) = child_global {
+            set_global_transform(world, self.child, child_global);
+        }
     }
 }
 /// Collection of methods similar to [`BuildChildren`](bevy_hierarchy::BuildChildren), but preserving each
@@ -65,6 +100,11 @@ pub trait BuildChildrenTransformExt {
     /// Change this entity's parent while preserving this entity's [`GlobalTransform`]
     /// by updating its [`Transform`].
     ///
+    /// The global transforms are computed from the [`Transform`]s of the entity's ancestors,
+    /// so this is correct even if they changed since the last transform propagation.
+    /// See [`GlobalTransform::reparented_to`] for how the new [`Transform`] is computed when the
+    /// new parent is scaled non-uniformly.
+    ///
     /// See [`BuildChildren::set_parent`](bevy_hierarchy::BuildChildren::set_parent) for a method that doesn't update the
     /// [`Transform`].
     ///
@@ -76,6 +116,9 @@ pub trait BuildChildrenTransformExt {
     /// Make this entity parentless while preserving this entity's [`GlobalTransform`]
     /// by updating its [`Transform`] to be equal to its current [`GlobalTransform`].
     ///
+    /// The global transform is computed from the [`Transform`]s of the entity's ancestors,
+    /// so this is correct even if they changed since the last transform propagation.
+    ///
     /// See [`BuildChildren::remove_parent`](bevy_hierarchy::BuildChildren::remove_parent) for a method that doesn't update the
     /// [`Transform`].
     ///
@@ -97,3 +140,137 @@ impl BuildChildrenTransformExt for EntityCommands<'_> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bundles::TransformBundle, TransformPlugin};
+    use bevy_app::App;
+    use bevy_ecs::system::Commands;
+    use bevy_ecs::world::CommandQueue;
+    use bevy_hierarchy::BuildWorldChildren;
+    use bevy_math::{EulerRot, Quat, Vec3};
+
+    fn spawn(app: &mut App, transform: Transform) -> Entity {
+        app.world_mut().spawn(TransformBundle::from(transform)).id()
+    }
+
+    fn apply(app: &mut App, f: impl FnOnce(&mut Commands)) {
+        let mut queue = CommandQueue::default();
+        f(&mut Commands::new(&mut queue, app.world()));
+        queue.apply(app.world_mut());
+    }
+
+    fn assert_global_eq(app: &App, entity: Entity, expected: GlobalTransform) {
+        let global = *app.world().get::<GlobalTransform>(entity).unwrap();
+        assert!(
+            global.affine().abs_diff_eq(expected.affine(), 1e-4),
+            "{global:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn reparent_in_place_with_rotated_and_scaled_parents() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let rotated = spawn(
+            &mut app,
+            Transform::from_xyz(1.0, 2.0, 3.0).with_rotation(Quat::from_euler(
+                EulerRot::XYZ,
+                0.3,
+                1.2,
+                -0.4,
+            )),
+        );
+        let scaled = spawn(
+            &mut app,
+            Transform::from_xyz(-2.0, 0.0, 1.0).with_scale(Vec3::new(2.0, 0.5, 3.0)),
+        );
+        let child = spawn(
+            &mut app,
+            Transform::from_xyz(5.0, 6.0, 7.0).with_rotation(Quat::from_rotation_y(0.8)),
+        );
+        app.update();
+        let global = *app.world().get::<GlobalTransform>(child).unwrap();
+
+        apply(&mut app, |commands| {
+            commands.entity(child).set_parent_in_place(rotated);
+        });
+        app.update();
+        assert_global_eq(&app, child, global);
+
+        // The child is rotated relative to the non-uniformly scaled parent
+        apply(&mut app, |commands| {
+            commands.entity(child).set_parent_in_place(scaled);
+        });
+        app.update();
+        let child_transform = app.world().get::<Transform>(child).unwrap();
+        assert!(child_transform.rotation.is_normalized());
+        assert!(app
+            .world()
+            .get::<GlobalTransform>(child)
+            .unwrap()
+            .translation()
+            .abs_diff_eq(global.translation(), 1e-4));
+
+        apply(&mut app, |commands| {
+            commands.entity(child).remove_parent_in_place();
+        });
+        app.update();
+        assert!(app
+            .world()
+            .get::<GlobalTransform>(child)
+            .unwrap()
+            .translation()
+            .abs_diff_eq(global.translation(), 1e-4));
+    }
+
+    #[test]
+    fn reparent_in_place_twice_before_propagation() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let a = spawn(&mut app, Transform::from_xyz(1.0, 0.0, 0.0));
+        let b = spawn(
+            &mut app,
+            Transform::from_xyz(0.0, 2.0, 0.0).with_rotation(Quat::from_rotation_z(1.0)),
+        );
+        let child = spawn(&mut app, Transform::from_xyz(0.0, 0.0, 3.0));
+        app.update();
+        let global = *app.world().get::<GlobalTransform>(child).unwrap();
+
+        // Neither the `GlobalTransform` of the child nor the one of `a` are up to date
+        // when reparenting to `b`
+        app.world_mut()
+            .get_mut::<Transform>(a)
+            .unwrap()
+            .translation
+            .x = 4.0;
+        apply(&mut app, |commands| {
+            commands
+                .entity(child)
+                .set_parent_in_place(a)
+                .set_parent_in_place(b);
+        });
+        app.update();
+        assert_global_eq(&app, child, global);
+
+        // Moving the child in the same frame as removing its parent. The child keeps the rotation
+        // of -1 radian it was given under `b`, which isn't applied anymore under `a`
+        app.world_mut().entity_mut(child).set_parent(a);
+        app.world_mut()
+            .get_mut::<Transform>(child)
+            .unwrap()
+            .translation = Vec3::new(0.0, 1.0, 0.0);
+        apply(&mut app, |commands| {
+            commands.entity(child).remove_parent_in_place();
+        });
+        app.update();
+        assert_global_eq(
+            &app,
+            child,
+            Transform::from_xyz(4.0, 1.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(-1.0))
+                .into(),
+        );
+    }
+}
@@ -3,7 +3,7 @@ use std::ops::Mul;
 use super::Transform;
 #[cfg(feature = "bevy-support")]
 use bevy_ecs::{component::Component, reflect::ReflectComponent};
-use bevy_math::{Affine3A, Dir3, Mat4, Quat, Vec3, Vec3A};
+use bevy_math::{Affine3A, Dir3, Mat3A, Mat4, Quat, Vec3, Vec3A};
 #[cfg(feature = "bevy-support")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 
@@ -145,14 +145,34 @@ impl GlobalTransform {
     /// }
     /// ```
     ///
-    /// The transform is expected to be non-degenerate and without shearing, or the output
-    /// will be invalid.
-    #[inline]
+    /// When `parent` is scaled non-uniformly and rotated relative to `self`, no [`Transform`] can
+    /// exactly reproduce `self`, since it can't represent shearing. The translation is then
+    /// preserved exactly, the rotation is the closest one to the relative orientation, and the
+    /// scale preserves the global size of each local axis.
+    ///
+    /// The transforms are expected to be non-degenerate, or the output will be invalid.
     pub fn reparented_to(&self, parent: &GlobalTransform) -> Transform {
         let relative_affine = parent.affine().inverse() * self.affine();
-        let (scale, rotation, translation) = relative_affine.to_scale_rotation_translation();
+        let mut matrix = relative_affine.matrix3;
+        // A rotation can't represent a reflection, which is moved to the scale instead
+        let flip = if matrix.determinant() < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        matrix.x_axis *= flip;
+        let rotation = closest_rotation(matrix);
+
+        let parent_matrix = parent.affine().matrix3;
+        let matrix = self.affine().matrix3;
+        let global_length = |axis: Vec3A| (parent_matrix * (rotation * axis)).length();
+        let scale = Vec3::new(
+            flip * matrix.x_axis.length() / global_length(Vec3A::X),
+            matrix.y_axis.length() / global_length(Vec3A::Y),
+            matrix.z_axis.length() / global_length(Vec3A::Z),
+        );
         Transform {
-            translation,
+            translation: relative_affine.translation.into(),
             rotation,
             scale,
         }
@@ -256,6 +276,22 @@ impl Mul<Vec3> for GlobalTransform {
     }
 }
 
+/// Returns the rotation closest to `matrix`, the orthogonal factor of its polar decomposition.
+///
+/// `matrix` is expected to have a positive determinant.
+fn closest_rotation(matrix: Mat3A) -> Quat {
+    let mut rotation = matrix;
+    for _ in 0..16 {
+        let next = (rotation + rotation.inverse().transpose()) * 0.5;
+        let converged = next.abs_diff_eq(rotation, 1e-6);
+        rotation = next;
+        if converged {
+            break;
+        }
+    }
+    Quat::from_mat3a(&rotation).normalize()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -311,4 +347,43 @@ mod test {
             t1_prime.compute_transform(),
         );
     }
+
+    #[test]
+    fn reparented_to_non_uniform_scale() {
+        let t1 = GlobalTransform::from(Transform {
+            translation: Vec3::new(3.0, -2.0, 5.0),
+            rotation: Quat::from_euler(XYZ, 0.4, 0.7, 1.2),
+            scale: Vec3::new(1.5, 1.5, 1.5),
+        });
+        let t2 = GlobalTransform::from(Transform {
+            translation: Vec3::new(1.0, 2.0, 3.0),
+            rotation: Quat::from_euler(XYZ, 1.1, 0.2, 0.5),
+            scale: Vec3::new(2.0, 0.5, 1.0),
+        });
+        let reparented = t1.reparented_to(&t2);
+        assert!(reparented.rotation.is_normalized());
+
+        // The position and the size of the local axes are preserved, even if the child is sheared
+        let t1_prime = t2 * reparented;
+        assert!(t1_prime.translation().abs_diff_eq(t1.translation(), 1e-4));
+        for axis in [Vec3A::X, Vec3A::Y, Vec3A::Z] {
+            let length = (t1.affine().matrix3 * axis).length();
+            let length_prime = (t1_prime.affine().matrix3 * axis).length();
+            assert!((length - length_prime).abs() < 1e-4);
+        }
+
+        // Reparenting again to the same parent doesn't drift
+        let twice = (t2 * reparented).reparented_to(&t2);
+        assert!(twice.translation.abs_diff_eq(reparented.translation, 1e-4));
+        assert!(twice.rotation.abs_diff_eq(reparented.rotation, 1e-4));
+        assert!(twice.scale.abs_diff_eq(reparented.scale, 1e-4));
+    }
+
+    #[test]
+    fn reparented_to_mirrored_parent() {
+        let t1 = GlobalTransform::from(Transform::from_xyz(1.0, 2.0, 3.0));
+        let t2 = GlobalTransform::from(Transform::from_scale(Vec3::new(-1.0, 1.0, 1.0)));
+        let reparented = t1.reparented_to(&t2);
+        assert!(transform_equal(t1, (t2 * reparented).compute_transform()));
+    }
 }
@@ -0,0 +1,26 @@
+#[cfg(feature = "bevy-support")]
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+#[cfg(feature = "bevy-support")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Marks an entity and all of its descendants as static scenery, whose [`Transform`](super::Transform)s
+/// don't change.
+///
+/// Transform propagation skips the whole subtree of a [`StaticTransform`] entity, unless its
+/// ancestors moved, or a [`Transform`](super::Transform) or [`Parent`](bevy_hierarchy::Parent)
+/// changed inside of the subtree since the last propagation. This saves traversing large
+/// hierarchies of scenery every frame, at the cost of looking up the ancestors of the entities
+/// whose [`Transform`](super::Transform) or [`Parent`](bevy_hierarchy::Parent) changed.
+///
+/// Static entities can still be moved, or reparented: their [`GlobalTransform`](super::GlobalTransform)s
+/// and the ones of their descendants are updated correctly, but a warning is logged the first time
+/// a static [`Transform`](super::Transform) is mutated, since entities that move shouldn't be
+/// marked as static.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy-support",
+    derive(Component, Reflect),
+    reflect(Component, Default, PartialEq)
+)]
+pub struct StaticTransform;
@@ -3,7 +3,7 @@ use bevy_ecs::schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet};
 use bevy_hierarchy::ValidParentCheckPlugin;
 
 use crate::{
-    prelude::{GlobalTransform, Transform},
+    prelude::{GlobalTransform, StaticTransform, Transform},
     systems::{propagate_transforms, sync_simple_transforms},
 };
 
@@ -27,6 +27,7 @@ impl Plugin for TransformPlugin {
 
         app.register_type::<Transform>()
             .register_type::<GlobalTransform>()
+            .register_type::<StaticTransform>()
             .add_plugins(ValidParentCheckPlugin::<GlobalTransform>::default())
             .configure_sets(
                 PostStartup,
@@ -1,12 +1,14 @@
-use crate::components::{GlobalTransform, Transform};
+use crate::components::{GlobalTransform, StaticTransform, Transform};
 use bevy_ecs::{
     change_detection::Ref,
-    prelude::{Changed, DetectChanges, Entity, Query, With, Without},
+    entity::EntityHashSet,
+    prelude::{Changed, DetectChanges, Entity, Has, Query, With, Without},
     query::{Added, Or},
     removal_detection::RemovedComponents,
     system::{Local, ParamSet},
 };
 use bevy_hierarchy::{Children, Parent};
+use bevy_utils::warn_once;
 
 /// Update [`GlobalTransform`] component of entities that aren't in the hierarchy
 ///
@@ -45,23 +47,76 @@ pub fn sync_simple_transforms(
 /// Update [`GlobalTransform`] component of entities based on entity hierarchy and
 /// [`Transform`] component.
 ///
+/// Subtrees marked with [`StaticTransform`] are skipped, unless something changed in them.
+///
 /// Third party plugins should ensure that this is used in concert with [`sync_simple_transforms`].
+#[allow(clippy::too_many_arguments)]
 pub fn propagate_transforms(
     mut root_query: Query<
-        (Entity, &Children, Ref<Transform>, &mut GlobalTransform),
+        (
+            Entity,
+            &Children,
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Has<StaticTransform>,
+        ),
         Without<Parent>,
     >,
     mut orphaned: RemovedComponents<Parent>,
-    transform_query: Query<(Ref<Transform>, &mut GlobalTransform, Option<&Children>), With<Parent>>,
+    transform_query: Query<
+        (
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Option<&Children>,
+            Has<StaticTransform>,
+        ),
+        With<Parent>,
+    >,
     parent_query: Query<(Entity, Ref<Parent>)>,
+    changed_query: Query<(Entity, Ref<Transform>), Or<(Changed<Transform>, Changed<Parent>)>>,
+    static_query: Query<(), With<StaticTransform>>,
     mut orphaned_entities: Local<Vec<Entity>>,
+    mut dirty_static_entities: Local<EntityHashSet>,
 ) {
     orphaned_entities.clear();
     orphaned_entities.extend(orphaned.read());
     orphaned_entities.sort_unstable();
+
+    // Find the static subtrees in which something changed, which must be traversed
+    dirty_static_entities.clear();
+    if !static_query.is_empty() {
+        for (entity, transform) in &changed_query {
+            let mut is_static = false;
+            let mut ancestor = Some(entity);
+            while let Some(current) = ancestor {
+                if static_query.contains(current) {
+                    is_static = true;
+                    // The static ancestors of `current` have already been marked as dirty
+                    if !dirty_static_entities.insert(current) {
+                        break;
+                    }
+                }
+                ancestor = parent_query
+                    .get(current)
+                    .ok()
+                    .map(|(_, parent)| parent.get());
+            }
+            if is_static && transform.is_changed() && !transform.is_added() {
+                warn_once!(
+                    "The `Transform` of {entity:?} changed, but it is in a subtree marked with `StaticTransform`. \
+                    Entities that move shouldn't be marked as static."
+                );
+            }
+        }
+    }
+    let dirty_static_entities = &*dirty_static_entities;
+
     root_query.par_iter_mut().for_each(
-        |(entity, children, transform, mut global_transform)| {
+        |(entity, children, transform, mut global_transform, is_static)| {
             let changed = transform.is_changed() || global_transform.is_added() || orphaned_entities.binary_search(&entity).is_ok();
+            if is_static && !changed && !dirty_static_entities.contains(&entity) {
+                return;
+            }
             if changed {
                 *global_transform = GlobalTransform::from(*transform);
             }
@@ -85,6 +140,7 @@ pub fn propagate_transforms(
                         &global_transform,
                         &transform_query,
                         &parent_query,
+                        dirty_static_entities,
                         child,
                         changed || actual_parent.is_changed(),
                     );
@@ -96,6 +152,9 @@ pub fn propagate_transforms(
 
 /// Recursively propagates the transforms for `entity` and all of its descendants.
 ///
+/// The subtree is skipped if `entity` is marked with [`StaticTransform`], didn't change, and isn't
+/// in `dirty_static_entities`.
+///
 /// # Panics
 ///
 /// If `entity`'s descendants have a malformed hierarchy, this function will panic occur before propagating
@@ -111,15 +170,21 @@ pub fn propagate_transforms(
 unsafe fn propagate_recursive(
     parent: &GlobalTransform,
     transform_query: &Query<
-        (Ref<Transform>, &mut GlobalTransform, Option<&Children>),
+        (
+            Ref<Transform>,
+            &mut GlobalTransform,
+            Option<&Children>,
+            Has<StaticTransform>,
+        ),
         With<Parent>,
     >,
     parent_query: &Query<(Entity, Ref<Parent>)>,
+    dirty_static_entities: &EntityHashSet,
     entity: Entity,
     mut changed: bool,
 ) {
     let (global_matrix, children) = {
-        let Ok((transform, mut global_transform, children)) =
+        let Ok((transform, mut global_transform, children, is_static)) =
             // SAFETY: This call cannot create aliased mutable references.
             //   - The top level iteration parallelizes on the roots of the hierarchy.
             //   - The caller ensures that each child has one and only one unique parent throughout the entire
@@ -151,6 +216,9 @@ unsafe fn propagate_recursive(
             };
 
         changed |= transform.is_changed() || global_transform.is_added();
+        if is_static && !changed && !dirty_static_entities.contains(&entity) {
+            return;
+        }
         if changed {
             *global_transform = parent.mul_transform(*transform);
         }
@@ -173,6 +241,7 @@ unsafe fn propagate_recursive(
                 &global_matrix,
                 transform_query,
                 parent_query,
+                dirty_static_entities,
                 child,
                 changed || actual_parent.is_changed(),
             );
@@ -539,4 +608,67 @@ mod test {
             *world.entity(child).get::<GlobalTransform>().unwrap()
         );
     }
+
+    #[test]
+    fn static_subtrees_propagate_only_when_changed() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::default();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((sync_simple_transforms, propagate_transforms));
+
+        let root = world
+            .spawn(TransformBundle::from(Transform::from_xyz(1.0, 0.0, 0.0)))
+            .id();
+        let scenery = world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                StaticTransform,
+            ))
+            .set_parent(root)
+            .id();
+        let child = world
+            .spawn(TransformBundle::from(Transform::from_xyz(0.0, 0.0, 1.0)))
+            .set_parent(scenery)
+            .id();
+        schedule.run(&mut world);
+        let expected = GlobalTransform::from_xyz(1.0, 1.0, 1.0);
+        assert_eq!(*world.get::<GlobalTransform>(child).unwrap(), expected);
+
+        // The static subtree isn't traversed when nothing changed in it
+        *world.get_mut::<GlobalTransform>(child).unwrap() = GlobalTransform::IDENTITY;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(child).unwrap(),
+            GlobalTransform::IDENTITY
+        );
+
+        // Changes inside of the static subtree are still propagated
+        world.get_mut::<Transform>(child).unwrap().translation.z = 2.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(child).unwrap(),
+            GlobalTransform::from_xyz(1.0, 1.0, 2.0)
+        );
+
+        // Reparenting into the static subtree dirties it
+        let other = world
+            .spawn(TransformBundle::from(Transform::from_xyz(0.0, 0.0, 3.0)))
+            .id();
+        schedule.run(&mut world);
+        world.entity_mut(child).add_child(other);
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(other).unwrap(),
+            GlobalTransform::from_xyz(1.0, 1.0, 5.0)
+        );
+
+        // Moving the ancestors of the static subtree moves it
+        world.get_mut::<Transform>(root).unwrap().translation.x = 2.0;
+        schedule.run(&mut world);
+        assert_eq!(
+            *world.get::<GlobalTransform>(other).unwrap(),
+            GlobalTransform::from_xyz(2.0, 1.0, 5.0)
+        );
+    }
 }
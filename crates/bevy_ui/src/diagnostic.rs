@@ -0,0 +1,96 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::system::{Res, Resource};
+use bevy_render::RenderApp;
+
+/// Adds the "ui/draw_calls" diagnostic to an App, measuring the number of UI batches drawn each
+/// frame.
+///
+/// Each batch is drawn with a single draw call, and consecutive UI nodes share a batch as long as
+/// they are drawn from the same texture, so this can be used to check the effect of packing UI
+/// images into the glyph atlases of the text, with
+/// [`TextAtlasIcon`](crate::widget::TextAtlasIcon).
+///
+/// Also adds the "ui/extracted_glyphs" diagnostic, measuring the number of text glyphs extracted
+/// from the main world each frame. The glyphs of a [`StaticText`](crate::widget::StaticText)
+/// aren't counted on the frames they're reused from the render world.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](bevy_diagnostic::LogDiagnosticsPlugin) to output diagnostics to the
+/// console.
+#[derive(Default)]
+pub struct UiDiagnosticsPlugin;
+
+impl Plugin for UiDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let draw_calls = UiDrawCalls::default();
+        let extracted_glyphs = UiExtractedGlyphs::default();
+        app.register_diagnostic(Diagnostic::new(Self::DRAW_CALLS))
+            .register_diagnostic(Diagnostic::new(Self::EXTRACTED_GLYPHS))
+            .insert_resource(draw_calls.clone())
+            .insert_resource(extracted_glyphs.clone())
+            .add_systems(Update, Self::diagnostic_system);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .insert_resource(draw_calls)
+                .insert_resource(extracted_glyphs);
+        }
+    }
+}
+
+impl UiDiagnosticsPlugin {
+    pub const DRAW_CALLS: DiagnosticPath = DiagnosticPath::const_new("ui/draw_calls");
+    pub const EXTRACTED_GLYPHS: DiagnosticPath = DiagnosticPath::const_new("ui/extracted_glyphs");
+
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        draw_calls: Res<UiDrawCalls>,
+        extracted_glyphs: Res<UiExtractedGlyphs>,
+    ) {
+        diagnostics.add_measurement(&Self::DRAW_CALLS, || draw_calls.get() as f64);
+        diagnostics.add_measurement(&Self::EXTRACTED_GLYPHS, || extracted_glyphs.get() as f64);
+    }
+}
+
+/// The number of UI batches prepared for the last rendered frame, shared between the main world
+/// and the render world.
+///
+/// Only present when the [`UiDiagnosticsPlugin`] is added.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct UiDrawCalls(Arc<AtomicUsize>);
+
+impl UiDrawCalls {
+    /// The number of UI draw calls of the last rendered frame.
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set(&self, draw_calls: usize) {
+        self.0.store(draw_calls, Ordering::Relaxed);
+    }
+}
+
+/// The number of text glyphs extracted from the main world for the last rendered frame, shared
+/// between the main world and the render world.
+///
+/// Only present when the [`UiDiagnosticsPlugin`] is added.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct UiExtractedGlyphs(Arc<AtomicUsize>);
+
+impl UiExtractedGlyphs {
+    /// The number of glyphs extracted for the last rendered frame.
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set(&self, glyphs: usize) {
+        self.0.store(glyphs, Ordering::Relaxed);
+    }
+}
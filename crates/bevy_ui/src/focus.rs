@@ -158,6 +158,10 @@ pub fn ui_focus_system(
     ui_scale: Res<UiScale>,
     ui_stack: Res<UiStack>,
     mut node_query: Query<NodeQuery>,
+    #[cfg(feature = "bevy_text")] render_scales: Query<
+        &crate::widget::TextRenderScale,
+        With<crate::widget::TextRenderScalePicking>,
+    >,
 ) {
     let primary_window = primary_window.iter().next();
 
@@ -243,6 +247,15 @@ pub fn ui_focus_system(
 
             let node_rect = node.node.logical_rect(node.global_transform);
 
+            // Text scaled when rendering is picked within its scaled bounds
+            #[cfg(feature = "bevy_text")]
+            let node_rect = match render_scales.get(node.entity) {
+                Ok(render_scale) => {
+                    Rect::from_center_size(node_rect.center(), node_rect.size() * render_scale.0)
+                }
+                Err(_) => node_rect,
+            };
+
             // Intersect with the calculated clip rect to find the bounds of the visible region of the node
             let visible_rect = node
                 .calculated_clip
@@ -13,6 +13,7 @@ use bevy_ecs::{
 use bevy_hierarchy::{Children, Parent};
 use bevy_math::{UVec2, Vec2};
 use bevy_render::camera::{Camera, NormalizedRenderTarget};
+use bevy_sprite::BorderRect;
 use bevy_transform::components::Transform;
 use bevy_utils::tracing::warn;
 use bevy_utils::{HashMap, HashSet};
@@ -251,6 +252,13 @@ pub fn ui_layout_system(
                 inverse_target_scale_factor * Vec2::new(layout.size.width, layout.size.height);
             let layout_location =
                 inverse_target_scale_factor * Vec2::new(layout.location.x, layout.location.y);
+            let content_inset = BorderRect {
+                left: inverse_target_scale_factor * (layout.border.left + layout.padding.left),
+                right: inverse_target_scale_factor * (layout.border.right + layout.padding.right),
+                top: inverse_target_scale_factor * (layout.border.top + layout.padding.top),
+                bottom: inverse_target_scale_factor
+                    * (layout.border.bottom + layout.padding.bottom),
+            };
 
             absolute_location += layout_location;
 
@@ -261,9 +269,13 @@ pub fn ui_layout_system(
                 round_layout_coords(layout_location) + 0.5 * (rounded_size - parent_size);
 
             // only trigger change detection when the new values are different
-            if node.calculated_size != rounded_size || node.unrounded_size != layout_size {
+            if node.calculated_size != rounded_size
+                || node.unrounded_size != layout_size
+                || node.content_inset != content_inset
+            {
                 node.calculated_size = rounded_size;
                 node.unrounded_size = layout_size;
+                node.content_inset = content_inset;
             }
             if transform.translation.truncate() != rounded_location {
                 transform.translation = rounded_location.extend(0.);
@@ -924,6 +936,40 @@ mod tests {
         assert_eq!(layout.size.height, content_size.y);
     }
 
+    #[test]
+    fn ui_node_content_inset_should_include_border_and_padding() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+
+        let ui_entity = world
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(200.),
+                    height: Val::Px(100.),
+                    border: UiRect::all(Val::Px(2.)),
+                    padding: UiRect {
+                        left: Val::Px(30.),
+                        right: Val::Px(10.),
+                        top: Val::Px(10.),
+                        bottom: Val::Px(10.),
+                    },
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+
+        ui_schedule.run(&mut world);
+
+        let node = world.get::<Node>(ui_entity).unwrap();
+        let inset = node.content_inset();
+        assert_eq!(
+            (inset.left, inset.right, inset.top, inset.bottom),
+            (32., 12., 12., 12.)
+        );
+        // The wider left padding shifts the content box to the right, within the border box
+        assert_eq!(node.content_size(), Vec2::new(156., 76.));
+    }
+
     #[test]
     fn measure_funcs_should_be_removed_on_content_size_removal() {
         let (mut world, mut ui_schedule) = setup_ui_test_world();
@@ -1081,4 +1127,61 @@ mod tests {
 
         ui_schedule.run(&mut world);
     }
+
+    #[test]
+    fn manual_input_clicks_laid_out_button() {
+        use crate::{stack::ui_stack_system, ui_focus_system, UiStack};
+        use bevy_input::{
+            keyboard::KeyboardInput,
+            mouse::{mouse_button_input_system, MouseButton, MouseButtonInput},
+            touch::Touches,
+            ButtonInput,
+        };
+        use bevy_window::{CursorMoved, ManualInput};
+
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        world.init_resource::<UiStack>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<Events<KeyboardInput>>();
+        world.init_resource::<Events<MouseButtonInput>>();
+        world.init_resource::<Events<CursorMoved>>();
+        ui_schedule.add_systems(
+            (mouse_button_input_system, ui_stack_system, ui_focus_system)
+                .chain()
+                .after(propagate_transforms),
+        );
+
+        let button = world
+            .spawn(ButtonBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(300.),
+                    top: Val::Px(10.),
+                    width: Val::Px(100.),
+                    height: Val::Px(40.),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        ui_schedule.run(&mut world);
+
+        // Clicking next to the button doesn't press it
+        world.run_system_once(|mut input: ManualInput| {
+            input.click_at(Vec2::new(250., 30.), MouseButton::Left);
+        });
+        ui_schedule.run(&mut world);
+        assert_eq!(world.get::<Interaction>(button), Some(&Interaction::None));
+
+        // Clicking within the node, as laid out, does
+        world.run_system_once(|mut input: ManualInput| {
+            input.click_at(Vec2::new(350., 30.), MouseButton::Left);
+        });
+        ui_schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Interaction>(button),
+            Some(&Interaction::Pressed)
+        );
+    }
 }
@@ -21,6 +21,7 @@ use bevy_derive::{Deref, DerefMut};
 use bevy_reflect::Reflect;
 #[cfg(feature = "bevy_text")]
 mod accessibility;
+mod diagnostic;
 mod focus;
 mod geometry;
 mod layout;
@@ -29,6 +30,7 @@ mod stack;
 mod texture_slice;
 mod ui_node;
 
+pub use diagnostic::*;
 pub use focus::*;
 pub use geometry::*;
 pub use layout::*;
@@ -43,7 +45,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         geometry::*, node_bundles::*, ui_material::*, ui_node::*, widget::Button, widget::Label,
-        Interaction, UiMaterialPlugin, UiScale,
+        widget::UiImageAnimation, Interaction, UiMaterialPlugin, UiScale,
     };
     // `bevy_sprite` re-exports for texture slicing
     #[doc(hidden)]
@@ -121,6 +123,7 @@ impl Plugin for UiPlugin {
             .register_type::<TargetCamera>()
             .register_type::<UiImage>()
             .register_type::<UiImageSize>()
+            .register_type::<UiClipShape>()
             .register_type::<UiRect>()
             .register_type::<UiScale>()
             .register_type::<BorderColor>()
@@ -129,9 +132,17 @@ impl Plugin for UiPlugin {
             .register_type::<widget::Label>()
             .register_type::<ZIndex>()
             .register_type::<Outline>()
+            .register_type::<widget::ToggleVisibility>()
+            .register_type::<widget::Slider>()
+            .register_type::<widget::SliderHandle>()
+            .add_event::<widget::SliderChanged>()
             .add_systems(
                 PreUpdate,
-                ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
+                (
+                    ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
+                    widget::toggle_visibility_system.after(InputSystem),
+                    widget::slider_system.after(UiSystem::Focus),
+                ),
             );
 
         app.add_systems(
@@ -172,6 +183,7 @@ impl Plugin for UiPlugin {
                     texture_slice::compute_slices_on_image_change,
                 )
                     .after(UiSystem::Layout),
+                widget::update_slider_handles.before(UiSystem::Layout),
             ),
         );
 
@@ -197,13 +209,43 @@ fn build_text_interop(app: &mut App) {
     use bevy_text::TextLayoutInfo;
 
     app.register_type::<TextLayoutInfo>()
-        .register_type::<TextFlags>();
+        .register_type::<TextFlags>()
+        .register_type::<widget::TextDecoration>()
+        .register_type::<widget::TextDecorations>()
+        .register_type::<widget::TextRenderScale>()
+        .register_type::<widget::TextRenderScalePicking>()
+        .register_type::<widget::StaticText>()
+        .register_type::<widget::TextSpanInteraction>()
+        .register_type::<widget::LinkStyles>()
+        .register_type::<widget::TextPosition>()
+        .register_type::<widget::SelectableText>()
+        .register_type::<widget::TextClipboard>()
+        .register_type::<widget::TextAtlasIcon>()
+        .register_type::<widget::SliderValueText>()
+        .init_resource::<widget::TextClipboard>()
+        .add_event::<widget::TextCopied>();
+
+    app.add_systems(
+        PreUpdate,
+        (
+            (
+                widget::text_span_interaction_system,
+                widget::update_link_decorations,
+            )
+                .chain(),
+            widget::text_selection_system,
+        )
+            .after(UiSystem::Focus),
+    );
 
     app.add_systems(
         PostUpdate,
         (
             widget::measure_text_system
                 .before(UiSystem::Layout)
+                .after(bevy_text::update_log_texts)
+                .after(bevy_text::update_derived_texts)
+                .after(bevy_text::update_themed_text_colors)
                 // Potential conflict: `Assets<Image>`
                 // In practice, they run independently since `bevy_render::camera_update_system`
                 // will only ever observe its own render target, and `widget::measure_text_system`
@@ -219,8 +261,15 @@ fn build_text_interop(app: &mut App) {
             widget::text_system
                 .after(UiSystem::Layout)
                 .after(bevy_text::remove_dropped_font_atlas_sets)
+                .after(bevy_text::update_glyph_padding)
+                .after(bevy_text::update_themed_text_colors)
                 // Text2d and bevy_ui text are entirely on separate entities
                 .ambiguous_with(bevy_text::update_text2d_layout),
+            widget::pack_text_atlas_icons
+                .after(widget::text_system)
+                // Both only add to the glyph atlases, so their order doesn't matter
+                .ambiguous_with(bevy_text::update_text2d_layout),
+            widget::update_slider_value_texts.before(widget::measure_text_system),
         ),
     );
 
@@ -283,6 +283,13 @@ impl TextBundle {
         self.text.linebreak_behavior = BreakLineOn::NoWrap;
         self
     }
+
+    /// Returns this [`TextBundle`] soft wrapping at `wrap_width` logical pixels, with
+    /// [`Text::wrap_width`]. Without a set width, the node hugs the wrapped text.
+    pub const fn with_wrap_width(mut self, wrap_width: f32) -> Self {
+        self.text.wrap_width = Some(wrap_width);
+        self
+    }
 }
 
 #[cfg(feature = "bevy_text")]
@@ -22,10 +22,14 @@ pub use ui_material_pipeline::*;
 use crate::graph::{NodeUi, SubGraphUi};
 use crate::{
     texture_slice::ComputedTextureSlices, BackgroundColor, BorderColor, BorderRadius,
-    CalculatedClip, ContentSize, DefaultUiCamera, Node, Outline, Style, TargetCamera, UiImage,
-    UiScale, Val,
+    CalculatedClip, ClipMask, ContentSize, DefaultUiCamera, Node, Outline, Style, TargetCamera,
+    UiDrawCalls, UiExtractedGlyphs, UiImage, UiScale, Val,
 };
 
+#[cfg(feature = "bevy_text")]
+use crate::widget::{
+    SelectableText, StaticText, TextDecoration, TextDecorations, TextPosition, TextRenderScale,
+};
 use bevy_app::prelude::*;
 use bevy_asset::{load_internal_asset, AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::entity::{EntityHashMap, EntityHashSet};
@@ -44,11 +48,14 @@ use bevy_render::{
 };
 use bevy_sprite::TextureAtlasLayout;
 #[cfg(feature = "bevy_text")]
-use bevy_text::{PositionedGlyph, Text, TextLayoutInfo};
+use bevy_text::{
+    GlyphAntialiasing, PositionedGlyph, SectionBackground, Text, TextGlyphColor, TextLayoutInfo,
+};
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashMap;
 use bytemuck::{Pod, Zeroable};
 use std::ops::Range;
+use std::sync::Arc;
 
 pub mod graph {
     use bevy_render::render_graph::{RenderLabel, RenderSubGraph};
@@ -119,6 +126,9 @@ pub fn build_ui_render(app: &mut App) {
             ),
         );
 
+    #[cfg(feature = "bevy_text")]
+    render_app.init_resource::<ExtractedStaticTexts>();
+
     // Render graph
     let ui_graph_2d = get_ui_graph(render_app);
     let ui_graph_3d = get_ui_graph(render_app);
@@ -154,8 +164,16 @@ fn get_ui_graph(render_app: &mut SubApp) -> RenderGraph {
 pub enum NodeType {
     Rect,
     Border,
+    /// A glyph rasterized with [`GlyphAntialiasing::SubpixelRgb`], whose color channels are
+    /// blended separately with the `background` behind it.
+    ///
+    /// Without an opaque `background`, the glyph falls back to grayscale antialiasing.
+    SubpixelText {
+        background: LinearRgba,
+    },
 }
 
+#[derive(Clone)]
 pub struct ExtractedUiNode {
     pub stack_index: u32,
     pub transform: Mat4,
@@ -164,6 +182,8 @@ pub struct ExtractedUiNode {
     pub image: AssetId<Image>,
     pub atlas_size: Option<Vec2>,
     pub clip: Option<Rect>,
+    /// The mask the UI node is clipped to, in addition to the clip rect.
+    pub clip_mask: Option<ClipMask>,
     pub flip_x: bool,
     pub flip_y: bool,
     // Camera to render this UI node to. By the time it is extracted,
@@ -182,6 +202,11 @@ pub struct ExtractedUiNode {
 #[derive(Resource, Default)]
 pub struct ExtractedUiNodes {
     pub uinodes: EntityHashMap<ExtractedUiNode>,
+    /// Runs of nodes queued and drawn as a single item, in order, such as the nodes of a
+    /// [`StaticText`] that are kept in the render world between frames.
+    ///
+    /// The textured nodes of a run must all share the same image and camera.
+    pub uinode_runs: EntityHashMap<Arc<[ExtractedUiNode]>>,
 }
 
 pub fn extract_uinode_background_colors(
@@ -276,6 +301,7 @@ pub fn extract_uinode_background_colors(
                     max: uinode.calculated_size,
                 },
                 clip: clip.map(|clip| clip.clip),
+                clip_mask: clip.and_then(|clip| clip.mask),
                 image: AssetId::default(),
                 atlas_size: None,
                 flip_x: false,
@@ -415,6 +441,7 @@ pub fn extract_uinode_images(
                 color: image.color.into(),
                 rect,
                 clip: clip.map(|clip| clip.clip),
+                clip_mask: clip.and_then(|clip| clip.mask),
                 image: image.texture.id(),
                 atlas_size,
                 flip_x: image.flip_x,
@@ -596,6 +623,7 @@ pub fn extract_uinode_borders(
                 image,
                 atlas_size: None,
                 clip: clip.map(|clip| clip.clip),
+                clip_mask: clip.and_then(|clip| clip.mask),
                 flip_x: false,
                 flip_y: false,
                 camera_entity,
@@ -689,6 +717,7 @@ pub fn extract_uinode_outlines(
                         image,
                         atlas_size: None,
                         clip: maybe_clip.map(|clip| clip.clip),
+                        clip_mask: maybe_clip.and_then(|clip| clip.mask),
                         flip_x: false,
                         flip_y: false,
                         camera_entity,
@@ -785,28 +814,93 @@ pub fn extract_default_ui_camera_view(
     transparent_render_phases.retain(|entity, _| live_entities.contains(entity));
 }
 
+/// The UI nodes extracted for each [`StaticText`], reused on the following frames as long as the
+/// text doesn't change.
+#[cfg(feature = "bevy_text")]
+#[derive(Resource, Default)]
+pub struct ExtractedStaticTexts {
+    texts: EntityHashMap<ExtractedStaticText>,
+}
+
+#[cfg(feature = "bevy_text")]
+struct ExtractedStaticText {
+    /// What the nodes were extracted with, besides the components checked for changes.
+    key: StaticTextKey,
+    /// The nodes of the text, split into runs drawn from the same image.
+    runs: Vec<Arc<[ExtractedUiNode]>>,
+    /// Whether the text was extracted or reused this frame, otherwise it's removed from the cache.
+    used: bool,
+}
+
+#[cfg(feature = "bevy_text")]
+#[derive(PartialEq)]
+struct StaticTextKey {
+    camera_entity: Entity,
+    scale_factor: f32,
+    // Updated without triggering change detection
+    stack_index: u32,
+    // Removing one of them doesn't trigger change detection either
+    optional_components: [bool; 6],
+}
+
+#[cfg(feature = "bevy_text")]
+fn is_changed<T: Component>(component: &Option<Ref<T>>) -> bool {
+    component.as_ref().map_or(false, DetectChanges::is_changed)
+}
+
 #[cfg(feature = "bevy_text")]
+#[allow(clippy::too_many_arguments)]
 pub fn extract_uinode_text(
     mut commands: Commands,
     mut extracted_uinodes: ResMut<ExtractedUiNodes>,
+    mut extracted_static_texts: ResMut<ExtractedStaticTexts>,
+    extracted_glyphs: Option<Res<UiExtractedGlyphs>>,
     camera_query: Extract<Query<(Entity, &Camera)>>,
     default_ui_camera: Extract<DefaultUiCamera>,
     texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
     ui_scale: Extract<Res<UiScale>>,
     uinode_query: Extract<
         Query<(
-            &Node,
-            &GlobalTransform,
+            Entity,
+            Ref<Node>,
+            Ref<GlobalTransform>,
             &ViewVisibility,
-            Option<&CalculatedClip>,
+            Option<Ref<CalculatedClip>>,
             Option<&TargetCamera>,
-            &Text,
-            &TextLayoutInfo,
+            Ref<Text>,
+            Ref<TextLayoutInfo>,
+            Option<Ref<TextDecorations>>,
+            Option<Ref<TextGlyphColor>>,
+            Option<Ref<SelectableText>>,
+            Option<Ref<TextRenderScale>>,
+            Option<Ref<BackgroundColor>>,
+            Has<StaticText>,
         )>,
     >,
 ) {
-    for (uinode, global_transform, view_visibility, clip, camera, text, text_layout_info) in
-        &uinode_query
+    let mut glyph_count = 0;
+    // The nodes of a single text, moved to the cache if it's static
+    let mut text_nodes = Vec::new();
+    for cached in extracted_static_texts.texts.values_mut() {
+        cached.used = false;
+    }
+
+    for (
+        entity,
+        uinode,
+        global_transform,
+        view_visibility,
+        clip,
+        camera,
+        text,
+        text_layout_info,
+        decorations,
+        glyph_color,
+        selectable,
+        render_scale,
+        background_color,
+        is_static,
+    ) in &uinode_query
     {
         let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
         else {
@@ -826,61 +920,324 @@ pub fn extract_uinode_text(
             * ui_scale.0;
         let inverse_scale_factor = scale_factor.recip();
 
+        let static_key = is_static.then(|| StaticTextKey {
+            camera_entity,
+            scale_factor,
+            stack_index: uinode.stack_index,
+            optional_components: [
+                clip.is_some(),
+                decorations.is_some(),
+                glyph_color.is_some(),
+                selectable.is_some(),
+                render_scale.is_some(),
+                background_color.is_some(),
+            ],
+        });
+        if let Some(key) = &static_key {
+            let changed = uinode.is_changed()
+                || global_transform.is_changed()
+                || text.is_changed()
+                || text_layout_info.is_changed()
+                || is_changed(&clip)
+                || is_changed(&decorations)
+                || is_changed(&glyph_color)
+                || is_changed(&selectable)
+                || is_changed(&render_scale)
+                || is_changed(&background_color);
+            if let Some(cached) = extracted_static_texts.texts.get_mut(&entity) {
+                if !changed && cached.key == *key {
+                    cached.used = true;
+                    extracted_uinodes.uinode_runs.extend(
+                        cached
+                            .runs
+                            .iter()
+                            .map(|run| (commands.spawn_empty().id(), run.clone())),
+                    );
+                    continue;
+                }
+            }
+        }
+        glyph_count += text_layout_info.glyphs.len();
+        let clip = clip.as_deref();
+        let decorations = decorations.as_deref();
+        let glyph_color = glyph_color.as_deref();
+        let selectable = selectable.as_deref();
+        let render_scale = render_scale.as_deref();
+        let background_color = background_color.as_deref();
+
         // Align the text to the nearest physical pixel:
-        // * Translate by minus the text node's half-size
-        //      (The transform translates to the center of the node but the text coordinates are relative to the node's top left corner)
+        // * Translate by minus the text node's half-size, plus the inset of its content box
+        //      (The transform translates to the center of the node but the text coordinates are relative to the top left corner of its content box)
         // * Multiply the logical coordinates by the scale factor to get its position in physical coordinates
         // * Round the physical position to the nearest physical pixel
         // * Multiply by the rounded physical position by the inverse scale factor to return to logical coordinates
 
-        let logical_top_left = -0.5 * uinode.size();
+        let content_inset = uinode.content_inset();
+        let logical_top_left =
+            -0.5 * uinode.size() + Vec2::new(content_inset.left, content_inset.top);
+
+        // The render scale is applied around the center of the node, scaling the positions and
+        // sizes of the glyph quads alike without laying them out again
+        let render_scale = render_scale.map_or(1., |render_scale| render_scale.0);
 
         let mut transform = global_transform.affine()
+            * bevy_math::Affine3A::from_scale(Vec3::new(render_scale, render_scale, 1.))
             * bevy_math::Affine3A::from_translation(logical_top_left.extend(0.));
 
         transform.translation *= scale_factor;
         transform.translation = transform.translation.round();
         transform.translation *= inverse_scale_factor;
 
-        let mut color = LinearRgba::WHITE;
-        let mut current_section = usize::MAX;
-        for PositionedGlyph {
-            position,
-            atlas_info,
+        // Spawned before the glyphs and the selection so that they're drawn behind them
+        for SectionBackground {
             section_index,
-            ..
-        } in &text_layout_info.glyphs
+            rect,
+        } in &text_layout_info.backgrounds
         {
+            let section = &text.sections[*section_index];
+            let Some(color) = section
+                .style
+                .background_color
+                .filter(|color| !color.is_fully_transparent())
+            else {
+                continue;
+            };
+            text_nodes.push(ExtractedUiNode {
+                stack_index: uinode.stack_index,
+                transform: transform
+                    * Mat4::from_translation(
+                        (rect.center() * inverse_scale_factor + section.offset).extend(0.),
+                    ),
+                color: LinearRgba::from(color),
+                rect: Rect {
+                    min: Vec2::ZERO,
+                    max: rect.size() * inverse_scale_factor,
+                },
+                image: AssetId::default(),
+                atlas_size: None,
+                clip: clip.map(|clip| clip.clip),
+                clip_mask: clip.and_then(|clip| clip.mask),
+                flip_x: false,
+                flip_y: false,
+                camera_entity,
+                border: [0.; 4],
+                border_radius: [0.; 4],
+                node_type: NodeType::Rect,
+            });
+        }
+
+        if let Some(selectable) = selectable {
+            // Spawned before the glyphs so that the highlight is drawn behind them
+            let selected = glyph_runs(&text_layout_info.glyphs, |glyph| {
+                selectable.is_selected(TextPosition {
+                    section: glyph.section_index,
+                    byte: glyph.byte_index,
+                })
+            });
+            for (_, bounds) in selected {
+                text_nodes.push(ExtractedUiNode {
+                    stack_index: uinode.stack_index,
+                    transform: transform
+                        * Mat4::from_translation(
+                            (bounds.center() * inverse_scale_factor).extend(0.),
+                        ),
+                    color: LinearRgba::from(selectable.highlight),
+                    rect: Rect {
+                        min: Vec2::ZERO,
+                        max: bounds.size() * inverse_scale_factor,
+                    },
+                    image: AssetId::default(),
+                    atlas_size: None,
+                    clip: clip.map(|clip| clip.clip),
+                    clip_mask: clip.and_then(|clip| clip.mask),
+                    flip_x: false,
+                    flip_y: false,
+                    camera_entity,
+                    border: [0.; 4],
+                    border_radius: [0.; 4],
+                    node_type: NodeType::Rect,
+                });
+            }
+        }
+
+        // The characters of the glyphs are only needed for their colors
+        let displayed_values = glyph_color.map(|_| text.displayed_values());
+        let gradient_positions = text.glyph_gradient_positions(&text_layout_info.glyphs);
+        let subpixel_background = (text_layout_info.antialiasing == GlyphAntialiasing::SubpixelRgb)
+            .then(|| background_color.map_or(LinearRgba::NONE, |color| color.0.into()));
+        let mut section_color = LinearRgba::WHITE;
+        let mut section_offset = Vec2::ZERO;
+        let mut current_section = usize::MAX;
+        // Sections of a higher layer are spawned, and so drawn, after the ones of a lower layer
+        for index in text.glyph_draw_order(&text_layout_info.glyphs) {
+            let glyph = &text_layout_info.glyphs[index];
+            let PositionedGlyph {
+                position,
+                size,
+                atlas_info,
+                section_index,
+                ..
+            } = glyph;
             if *section_index != current_section {
-                color = LinearRgba::from(text.sections[*section_index].style.color);
+                section_color = LinearRgba::from(text.sections[*section_index].style.color);
+                section_offset = text.sections[*section_index].offset;
                 current_section = *section_index;
             }
+            let color = glyph_color
+                .zip(displayed_values.as_deref())
+                .and_then(|(glyph_color, values)| glyph_color.glyph_color(values, index, glyph))
+                .or_else(|| {
+                    let position = gradient_positions.as_ref()?[index];
+                    Some(text.sections[*section_index].style.gradient_color(position))
+                })
+                .map_or(section_color, LinearRgba::from);
+            // Transparent glyphs, such as hidden text kept in the layout, aren't drawn at all
+            if color.is_fully_transparent() {
+                continue;
+            }
             let atlas = texture_atlases.get(&atlas_info.texture_atlas).unwrap();
 
             let mut rect = atlas.textures[atlas_info.glyph_index].as_rect();
+            // Inline images are displayed at their own size rather than the one of their atlas rect
+            let quad_scale = *size / rect.size();
+            let node_type = match subpixel_background {
+                // Inline images aren't rasterized, so their colors aren't subpixel coverage
+                Some(background) if text.sections[*section_index].image.is_none() => {
+                    NodeType::SubpixelText { background }
+                }
+                _ => NodeType::Rect,
+            };
             rect.min *= inverse_scale_factor;
             rect.max *= inverse_scale_factor;
-            extracted_uinodes.uinodes.insert(
-                commands.spawn_empty().id(),
-                ExtractedUiNode {
+            text_nodes.push(ExtractedUiNode {
+                stack_index: uinode.stack_index,
+                transform: transform
+                    * Mat4::from_translation(
+                        (*position * inverse_scale_factor + section_offset).extend(0.),
+                    )
+                    * Mat4::from_scale(quad_scale.extend(1.)),
+                color,
+                rect,
+                image: atlas_info.texture.id(),
+                atlas_size: Some(atlas.size.as_vec2() * inverse_scale_factor),
+                clip: clip.map(|clip| clip.clip),
+                clip_mask: clip.and_then(|clip| clip.mask),
+                flip_x: false,
+                flip_y: false,
+                camera_entity,
+                border: [0.; 4],
+                border_radius: [0.; 4],
+                node_type,
+            });
+        }
+
+        if let Some(decorations) = decorations {
+            let underlines = glyph_runs(&text_layout_info.glyphs, |glyph| {
+                decorations.get(glyph.section_index) == TextDecoration::Underline
+            });
+            for (section_index, underline) in underlines {
+                // Scale the thickness with the font size, staying at least one physical pixel thick
+                let thickness =
+                    (text.sections[section_index].style.font_size * scale_factor / 14.).max(1.);
+                let size = Vec2::new(underline.width(), thickness) * inverse_scale_factor;
+                let center = Vec2::new(underline.center().x, underline.max.y + 0.5 * thickness)
+                    * inverse_scale_factor;
+                text_nodes.push(ExtractedUiNode {
                     stack_index: uinode.stack_index,
-                    transform: transform
-                        * Mat4::from_translation(position.extend(0.) * inverse_scale_factor),
-                    color,
-                    rect,
-                    image: atlas_info.texture.id(),
-                    atlas_size: Some(atlas.size.as_vec2() * inverse_scale_factor),
+                    transform: transform * Mat4::from_translation(center.extend(0.)),
+                    color: LinearRgba::from(text.sections[section_index].style.color),
+                    rect: Rect {
+                        min: Vec2::ZERO,
+                        max: size,
+                    },
+                    image: AssetId::default(),
+                    atlas_size: None,
                     clip: clip.map(|clip| clip.clip),
+                    clip_mask: clip.and_then(|clip| clip.mask),
                     flip_x: false,
                     flip_y: false,
                     camera_entity,
                     border: [0.; 4],
                     border_radius: [0.; 4],
                     node_type: NodeType::Rect,
+                });
+            }
+        }
+
+        if let Some(key) = static_key {
+            let runs = image_runs(&mut text_nodes);
+            extracted_uinodes.uinode_runs.extend(
+                runs.iter()
+                    .map(|run| (commands.spawn_empty().id(), run.clone())),
+            );
+            extracted_static_texts.texts.insert(
+                entity,
+                ExtractedStaticText {
+                    key,
+                    runs,
+                    used: true,
                 },
             );
+        } else {
+            extracted_uinodes.uinodes.extend(
+                text_nodes
+                    .drain(..)
+                    .map(|node| (commands.spawn_empty().id(), node)),
+            );
+        }
+    }
+
+    // Forget the static texts that were despawned, hidden or unmarked
+    extracted_static_texts.texts.retain(|_, cached| cached.used);
+    if let Some(extracted_glyphs) = extracted_glyphs {
+        extracted_glyphs.set(glyph_count);
+    }
+}
+
+/// Drains `nodes` into runs of consecutive nodes whose textured nodes share the same image, so that
+/// each run can be drawn as a single item.
+#[cfg(feature = "bevy_text")]
+fn image_runs(nodes: &mut Vec<ExtractedUiNode>) -> Vec<Arc<[ExtractedUiNode]>> {
+    let mut runs = Vec::new();
+    let mut run_start = 0;
+    let mut run_image = AssetId::default();
+    for (index, node) in nodes.iter().enumerate() {
+        if node.image == AssetId::default() {
+            continue;
+        }
+        if run_image != AssetId::default() && node.image != run_image {
+            runs.push(Arc::from(&nodes[run_start..index]));
+            run_start = index;
         }
+        run_image = node.image;
     }
+    if run_start < nodes.len() {
+        runs.push(Arc::from(&nodes[run_start..]));
+    }
+    nodes.clear();
+    runs
+}
+
+/// Returns the bounds of the glyphs matching `filter` in each section, one per line, in physical pixels.
+#[cfg(feature = "bevy_text")]
+fn glyph_runs<'a>(
+    glyphs: &'a [PositionedGlyph],
+    filter: impl Fn(&PositionedGlyph) -> bool + 'a,
+) -> impl Iterator<Item = (usize, Rect)> + 'a {
+    let mut glyphs = glyphs.iter().filter(move |glyph| filter(glyph)).peekable();
+    std::iter::from_fn(move || {
+        let first = glyphs.next()?;
+        let mut bounds = Rect::from_center_size(first.position, first.size);
+        let mut previous_x = first.position.x;
+        // Glyphs of the same section and line are consecutive, a line break moves back to the left
+        while let Some(glyph) = glyphs.next_if(|glyph| {
+            glyph.section_index == first.section_index && glyph.position.x > previous_x
+        }) {
+            bounds = bounds.union(Rect::from_center_size(glyph.position, glyph.size));
+            previous_x = glyph.position.x;
+        }
+        Some((first.section_index, bounds))
+    })
 }
 
 #[repr(C)]
@@ -900,6 +1257,13 @@ struct UiVertex {
     pub border: [f32; 4],
     /// Size of the UI node.
     pub size: [f32; 2],
+    /// The rect of the clip mask, in logical pixels.
+    /// Ordering: min x, min y, max x, max y.
+    pub clip_mask: [f32; 4],
+    /// The radius of the corners of the clip mask, in logical pixels.
+    pub clip_mask_radius: f32,
+    /// The opaque background of a glyph with subpixel antialiasing.
+    pub background: [f32; 4],
 }
 
 #[derive(Resource)]
@@ -942,6 +1306,8 @@ pub mod shader_flags {
     /// Ordering: top left, top right, bottom right, bottom left.
     pub const CORNERS: [u32; 4] = [0, 2, 2 | 4, 4];
     pub const BORDER: u32 = 8;
+    pub const CLIP_MASK: u32 = 16;
+    pub const SUBPIXEL_TEXT: u32 = 32;
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -955,7 +1321,12 @@ pub fn queue_uinodes(
     draw_functions: Res<DrawFunctions<TransparentUi>>,
 ) {
     let draw_function = draw_functions.read().id::<DrawUi>();
-    for (entity, extracted_uinode) in extracted_uinodes.uinodes.iter() {
+    // A run is queued with its first node, the others share its camera and stack index
+    let runs = extracted_uinodes
+        .uinode_runs
+        .iter()
+        .filter_map(|(entity, run)| Some((entity, run.first()?)));
+    for (entity, extracted_uinode) in extracted_uinodes.uinodes.iter().chain(runs) {
         let Ok((view_entity, view)) = views.get_mut(extracted_uinode.camera_entity) else {
             continue;
         };
@@ -1002,6 +1373,7 @@ pub fn prepare_uinodes(
     gpu_images: Res<RenderAssets<GpuImage>>,
     mut phases: ResMut<ViewSortedRenderPhases<TransparentUi>>,
     events: Res<SpriteAssetEvents>,
+    draw_calls: Option<Res<UiDrawCalls>>,
     mut previous_len: Local<usize>,
 ) {
     // If an image has changed, the GpuImage has (probably) changed
@@ -1037,8 +1409,20 @@ pub fn prepare_uinodes(
             let mut batch_image_handle = AssetId::invalid();
 
             for item_index in 0..ui_phase.items.len() {
-                let item = &mut ui_phase.items[item_index];
-                if let Some(extracted_uinode) = extracted_uinodes.uinodes.get(&item.entity) {
+                let item_entity = ui_phase.items[item_index].entity;
+                let extracted_run =
+                    if let Some(extracted_uinode) = extracted_uinodes.uinodes.get(&item_entity) {
+                        std::slice::from_ref(extracted_uinode)
+                    } else if let Some(run) = extracted_uinodes.uinode_runs.get(&item_entity) {
+                        &run[..]
+                    } else {
+                        batch_image_handle = AssetId::invalid();
+                        continue;
+                    };
+
+                // Whether any node of the item was added to a batch
+                let mut batched = false;
+                for extracted_uinode in extracted_run {
                     let mut existing_batch = batches.last_mut();
 
                     if batch_image_handle == AssetId::invalid()
@@ -1059,7 +1443,7 @@ pub fn prepare_uinodes(
                                 camera: extracted_uinode.camera_entity,
                             };
 
-                            batches.push((item.entity, new_batch));
+                            batches.push((item_entity, new_batch));
 
                             image_bind_groups
                                 .values
@@ -1208,9 +1592,32 @@ pub fn prepare_uinodes(
                     };
 
                     let color = extracted_uinode.color.to_f32_array();
-                    if extracted_uinode.node_type == NodeType::Border {
-                        flags |= shader_flags::BORDER;
+                    let mut background = [0.; 4];
+                    match extracted_uinode.node_type {
+                        NodeType::Rect => {}
+                        NodeType::Border => flags |= shader_flags::BORDER,
+                        NodeType::SubpixelText {
+                            background: subpixel_background,
+                        } => {
+                            flags |= shader_flags::SUBPIXEL_TEXT;
+                            background = subpixel_background.to_f32_array();
+                        }
                     }
+                    let (clip_mask, clip_mask_radius) =
+                        if let Some(mask) = extracted_uinode.clip_mask {
+                            flags |= shader_flags::CLIP_MASK;
+                            (
+                                [
+                                    mask.rect.min.x,
+                                    mask.rect.min.y,
+                                    mask.rect.max.x,
+                                    mask.rect.max.y,
+                                ],
+                                mask.radius,
+                            )
+                        } else {
+                            ([0.; 4], 0.)
+                        };
 
                     for i in 0..4 {
                         ui_meta.vertices.push(UiVertex {
@@ -1221,6 +1628,9 @@ pub fn prepare_uinodes(
                             radius: extracted_uinode.border_radius,
                             border: extracted_uinode.border,
                             size: rect_size.xy().into(),
+                            clip_mask,
+                            clip_mask_radius,
+                            background,
                         });
                     }
 
@@ -1232,16 +1642,128 @@ pub fn prepare_uinodes(
                     indices_index += 4;
 
                     existing_batch.unwrap().1.range.end = vertices_index;
+                    batched = true;
+                }
+                if batched {
                     ui_phase.items[batch_item_index].batch_range_mut().end += 1;
-                } else {
-                    batch_image_handle = AssetId::invalid();
                 }
             }
         }
         ui_meta.vertices.write_buffer(&render_device, &render_queue);
         ui_meta.indices.write_buffer(&render_device, &render_queue);
         *previous_len = batches.len();
+        if let Some(draw_calls) = draw_calls {
+            draw_calls.set(batches.len());
+        }
         commands.insert_or_spawn_batch(batches);
     }
     extracted_uinodes.uinodes.clear();
+    extracted_uinodes.uinode_runs.clear();
+}
+
+#[cfg(all(test, feature = "bevy_text"))]
+mod tests {
+    use super::*;
+    use bevy_math::UVec2;
+    use bevy_render::MainWorld;
+    use bevy_text::{GlyphAtlasInfo, TextStyle};
+
+    #[test]
+    fn unchanged_static_text_is_not_extracted_again() {
+        let mut main_world = World::new();
+        main_world.init_resource::<UiScale>();
+        main_world.init_resource::<Assets<TextureAtlasLayout>>();
+        main_world.spawn(Camera::default());
+
+        let mut layout = TextureAtlasLayout::new_empty(UVec2::splat(16));
+        let glyph_index = layout.add_texture(URect::new(0, 0, 8, 8));
+        let texture_atlas = main_world
+            .resource_mut::<Assets<TextureAtlasLayout>>()
+            .add(layout);
+        let glyph = |x| PositionedGlyph {
+            position: Vec2::new(x, 4.),
+            size: Vec2::splat(8.),
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: texture_atlas.clone(),
+                texture: Handle::default(),
+                glyph_index,
+            },
+            section_index: 0,
+            byte_index: 0,
+        };
+        let mut spawn_text = |is_static| {
+            let mut view_visibility = ViewVisibility::HIDDEN;
+            view_visibility.set();
+            let mut text = main_world.spawn((
+                Node {
+                    calculated_size: Vec2::new(100., 20.),
+                    unrounded_size: Vec2::new(100., 20.),
+                    ..Default::default()
+                },
+                GlobalTransform::default(),
+                view_visibility,
+                Text::from_section("ab", TextStyle::default()),
+                TextLayoutInfo {
+                    glyphs: vec![glyph(0.), glyph(8.)],
+                    ..Default::default()
+                },
+            ));
+            if is_static {
+                text.insert(StaticText);
+            }
+            text.id()
+        };
+        let label = spawn_text(true);
+        spawn_text(false);
+
+        let mut render_world = World::new();
+        render_world.init_resource::<ExtractedUiNodes>();
+        render_world.init_resource::<ExtractedStaticTexts>();
+        let extracted_glyphs = UiExtractedGlyphs::default();
+        render_world.insert_resource(extracted_glyphs.clone());
+        let mut extract_main_world = MainWorld::default();
+        *extract_main_world = main_world;
+        render_world.insert_resource(extract_main_world);
+
+        // Extracts a frame, and returns the number of nodes, runs and glyphs extracted
+        let extract = render_world.register_system(extract_uinode_text);
+        let extract_frame = |render_world: &mut World| {
+            render_world.run_system(extract).unwrap();
+            let mut extracted_uinodes = render_world.resource_mut::<ExtractedUiNodes>();
+            let nodes = extracted_uinodes.uinodes.drain().count();
+            let runs = extracted_uinodes.uinode_runs.drain().count();
+            render_world.resource_mut::<MainWorld>().clear_trackers();
+            (nodes, runs, extracted_glyphs.get())
+        };
+
+        // The nodes of the static label are extracted as a single run
+        assert_eq!(extract_frame(&mut render_world), (2, 1, 4));
+        // And reused while it's unchanged
+        assert_eq!(extract_frame(&mut render_world), (2, 1, 2));
+        let cached_run =
+            render_world.resource::<ExtractedStaticTexts>().texts[&label].runs[0].clone();
+        assert_eq!(cached_run.len(), 2);
+        assert_eq!(extract_frame(&mut render_world), (2, 1, 2));
+        assert!(Arc::ptr_eq(
+            &cached_run,
+            &render_world.resource::<ExtractedStaticTexts>().texts[&label].runs[0]
+        ));
+
+        // Until it changes
+        render_world
+            .resource_mut::<MainWorld>()
+            .get_mut::<Text>(label)
+            .unwrap()
+            .set_changed();
+        assert_eq!(extract_frame(&mut render_world), (2, 1, 4));
+        assert_eq!(extract_frame(&mut render_world), (2, 1, 2));
+
+        // A despawned label is removed from the cache
+        render_world.resource_mut::<MainWorld>().despawn(label);
+        assert_eq!(extract_frame(&mut render_world), (2, 0, 2));
+        assert!(render_world
+            .resource::<ExtractedStaticTexts>()
+            .texts
+            .is_empty());
+    }
 }
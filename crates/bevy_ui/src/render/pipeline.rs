@@ -71,6 +71,12 @@ impl SpecializedRenderPipeline for UiPipeline {
                 VertexFormat::Float32x4,
                 // border size
                 VertexFormat::Float32x2,
+                // clip mask
+                VertexFormat::Float32x4,
+                // clip mask radius
+                VertexFormat::Float32,
+                // subpixel text background
+                VertexFormat::Float32x4,
             ],
         );
         let shader_defs = Vec::new();
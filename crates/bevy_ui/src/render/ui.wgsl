@@ -4,6 +4,8 @@ const TEXTURED = 1u;
 const RIGHT_VERTEX = 2u;
 const BOTTOM_VERTEX = 4u;
 const BORDER: u32 = 8u;
+const CLIP_MASK: u32 = 16u;
+const SUBPIXEL_TEXT: u32 = 32u;
 
 fn enabled(flags: u32, mask: u32) -> bool {
     return (flags & mask) != 0u;
@@ -22,6 +24,12 @@ struct VertexOutput {
 
     // Position relative to the center of the rectangle.
     @location(6) point: vec2<f32>,
+    // Position in the UI, in logical pixels.
+    @location(7) ui_position: vec2<f32>,
+    // x: min x, y: min y, z: max x, w: max y.
+    @location(8) @interpolate(flat) clip_mask: vec4<f32>,
+    @location(9) @interpolate(flat) clip_mask_radius: f32,
+    @location(10) @interpolate(flat) background: vec4<f32>,
     @builtin(position) position: vec4<f32>,
 };
 
@@ -38,6 +46,11 @@ fn vertex(
     // x: left, y: top, z: right, w: bottom.
     @location(5) border: vec4<f32>,
     @location(6) size: vec2<f32>,
+
+    // x: min x, y: min y, z: max x, w: max y.
+    @location(7) clip_mask: vec4<f32>,
+    @location(8) clip_mask_radius: f32,
+    @location(9) background: vec4<f32>,
 ) -> VertexOutput {
     var out: VertexOutput;
     out.uv = vertex_uv;
@@ -47,6 +60,10 @@ fn vertex(
     out.radius = radius;
     out.size = size;
     out.border = border;
+    out.ui_position = vertex_position.xy;
+    out.clip_mask = clip_mask;
+    out.clip_mask_radius = clip_mask_radius;
+    out.background = background;
     var point = 0.49999 * size;
     if (flags & RIGHT_VERTEX) == 0u {
         point.x *= -1.;
@@ -169,13 +186,45 @@ fn draw_background(in: VertexOutput, texture_color: vec4<f32>) -> vec4<f32> {
     return vec4(color.rgb, saturate(color.a * t));
 }
 
+// Draws a glyph rasterized with subpixel antialiasing, whose texture holds the coverage of the
+// red, green and blue subpixels.
+fn draw_subpixel_text(in: VertexOutput, coverage: vec4<f32>) -> vec4<f32> {
+    // Without an opaque background to blend each channel with, fall back to grayscale coverage
+    if in.background.a < 1.0 {
+        let alpha = (coverage.r + coverage.g + coverage.b) / 3.0;
+        return vec4(in.color.rgb, in.color.a * alpha);
+    }
+
+    let channel_coverage = coverage.rgb * in.color.a;
+    let alpha = max(channel_coverage.r, max(channel_coverage.g, channel_coverage.b));
+    if alpha <= 0.0 {
+        return vec4(0.0);
+    }
+    // The color which, alpha blended over the background with the coverage of the most covered
+    // subpixel, blends each channel with its own coverage.
+    let blended = mix(in.background.rgb, in.color.rgb, channel_coverage);
+    return vec4((blended - in.background.rgb * (1.0 - alpha)) / alpha, alpha);
+}
+
+// The coverage of the fragment by the clip mask of the node, antialiased at its edge.
+fn clip_mask_coverage(in: VertexOutput) -> f32 {
+    let size = in.clip_mask.zw - in.clip_mask.xy;
+    let center = 0.5 * (in.clip_mask.xy + in.clip_mask.zw);
+    let distance = sd_rounded_box(in.ui_position - center, size, vec4(in.clip_mask_radius));
+    return select(1.0, saturate(antialias(distance)), enabled(in.flags, CLIP_MASK));
+}
+
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     let texture_color = textureSample(sprite_texture, sprite_sampler, in.uv);
 
-    if enabled(in.flags, BORDER) {
-        return draw(in, texture_color);    
+    var color: vec4<f32>;
+    if enabled(in.flags, SUBPIXEL_TEXT) {
+        color = draw_subpixel_text(in, texture_color);
+    } else if enabled(in.flags, BORDER) {
+        color = draw(in, texture_color);    
     } else {
-        return draw_background(in, texture_color);
+        color = draw_background(in, texture_color);
     }
+    return vec4(color.rgb, color.a * clip_mask_coverage(in));
 }
@@ -67,6 +67,7 @@ impl ComputedTextureSlices {
                 image: image.texture.id(),
                 atlas_size,
                 clip: clip.map(|clip| clip.clip),
+                clip_mask: clip.and_then(|clip| clip.mask),
                 camera_entity,
                 border: [0.; 4],
                 border_radius: [0.; 4],
@@ -8,6 +8,7 @@ use bevy_render::{
     camera::{Camera, RenderTarget},
     texture::Image,
 };
+use bevy_sprite::BorderRect;
 use bevy_transform::prelude::GlobalTransform;
 use bevy_utils::warn_once;
 use bevy_window::{PrimaryWindow, WindowRef};
@@ -44,6 +45,10 @@ pub struct Node {
     ///
     /// Automatically calculated by [`super::layout::ui_layout_system`].
     pub(crate) unrounded_size: Vec2,
+    /// The resolved border and padding of the node, insetting its content box in logical pixels.
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub(crate) content_inset: BorderRect,
 }
 
 impl Node {
@@ -67,6 +72,24 @@ impl Node {
         self.unrounded_size
     }
 
+    /// The widths of the border and padding of each edge of the node in logical pixels, between
+    /// its edges and its content box.
+    ///
+    /// Automatically calculated by [`super::layout::ui_layout_system`].
+    pub const fn content_inset(&self) -> BorderRect {
+        self.content_inset
+    }
+
+    /// The size of the content box of the node in logical pixels before rounding, which is its
+    /// unrounded size minus its border and padding.
+    ///
+    /// The text of a text node is laid out and wrapped within its content box.
+    pub fn content_size(&self) -> Vec2 {
+        let inset = self.content_inset;
+        (self.unrounded_size - Vec2::new(inset.left + inset.right, inset.top + inset.bottom))
+            .max(Vec2::ZERO)
+    }
+
     /// Returns the size of the node in physical pixels based on the given scale factor and `UiScale`.
     #[inline]
     pub fn physical_size(&self, scale_factor: f32, ui_scale: f32) -> Vec2 {
@@ -118,6 +141,7 @@ impl Node {
         outline_width: 0.,
         outline_offset: 0.,
         unrounded_size: Vec2::ZERO,
+        content_inset: BorderRect::square(0.),
     };
 }
 
@@ -1869,11 +1893,67 @@ impl From<Handle<Image>> for UiImage {
 }
 
 /// The calculated clip of the node
-#[derive(Component, Default, Copy, Clone, Debug, Reflect)]
+#[derive(Component, Default, Copy, Clone, Debug, PartialEq, Reflect)]
 #[reflect(Component, Default)]
 pub struct CalculatedClip {
     /// The rect of the clip
     pub clip: Rect,
+    /// The mask of the clip, set by the [`UiClipShape`] of the nearest clipping ancestor
+    /// which has one.
+    pub mask: Option<ClipMask>,
+}
+
+/// The shape of the clip a node applies to its descendants when its [`Overflow`] isn't visible.
+///
+/// Descendants are antialiased against the edge of the shape, which allows for example
+/// clipping text to a circular badge.
+///
+/// Only the shape of the nearest clipping ancestor is applied, while the clip rects of all
+/// the clipping ancestors are intersected. Nodes using a [`UiMaterial`](crate::UiMaterial)
+/// are only clipped by the rect of the clip.
+#[derive(Component, Default, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub enum UiClipShape {
+    /// Clip to the rect of the node.
+    #[default]
+    Rect,
+    /// Clip to the rect of the node with its corners rounded by the radius, in logical pixels.
+    RoundedRect(f32),
+    /// Clip to the largest circle centered in the node.
+    Circle,
+}
+
+impl UiClipShape {
+    /// Returns the mask clipping to this shape in the `node_rect`, in logical pixels.
+    ///
+    /// Returns `None` for [`UiClipShape::Rect`], since the clip rect is enough.
+    pub fn mask(self, node_rect: Rect) -> Option<ClipMask> {
+        let half_size = node_rect.half_size();
+        match self {
+            UiClipShape::Rect => None,
+            UiClipShape::RoundedRect(radius) => Some(ClipMask {
+                rect: node_rect,
+                radius: radius.clamp(0., half_size.min_element()),
+            }),
+            UiClipShape::Circle => {
+                let radius = half_size.min_element();
+                Some(ClipMask {
+                    rect: Rect::from_center_half_size(node_rect.center(), Vec2::splat(radius)),
+                    radius,
+                })
+            }
+        }
+    }
+}
+
+/// A rounded rect descendants of a node are clipped to, computed from its [`UiClipShape`].
+#[derive(Default, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct ClipMask {
+    /// The rect of the mask, in logical pixels.
+    pub rect: Rect,
+    /// The radius of the corners of the mask, in logical pixels.
+    pub radius: f32,
 }
 
 /// Indicates that this [`Node`] entity's front-to-back ordering is not controlled solely
@@ -2169,7 +2249,9 @@ impl BorderRadius {
 
 #[cfg(test)]
 mod tests {
-    use crate::GridPlacement;
+    use bevy_math::Rect;
+
+    use crate::{ClipMask, GridPlacement, UiClipShape};
 
     #[test]
     fn invalid_grid_placement_values() {
@@ -2196,6 +2278,34 @@ mod tests {
         assert_eq!(GridPlacement::start_span(3, 5).get_end(), None);
         assert_eq!(GridPlacement::end_span(-4, 12).get_start(), None);
     }
+
+    #[test]
+    fn clip_shape_masks() {
+        let node_rect = Rect::new(10., 20., 110., 60.);
+        assert_eq!(UiClipShape::Rect.mask(node_rect), None);
+        assert_eq!(
+            UiClipShape::RoundedRect(8.).mask(node_rect),
+            Some(ClipMask {
+                rect: node_rect,
+                radius: 8.
+            })
+        );
+        // The radius can't exceed half of the smallest side
+        assert_eq!(
+            UiClipShape::RoundedRect(100.)
+                .mask(node_rect)
+                .unwrap()
+                .radius,
+            20.
+        );
+        assert_eq!(
+            UiClipShape::Circle.mask(node_rect),
+            Some(ClipMask {
+                rect: Rect::new(40., 20., 80., 60.),
+                radius: 20.
+            })
+        );
+    }
 }
 
 /// Indicates that this root [`Node`] entity should be rendered to a specific camera.
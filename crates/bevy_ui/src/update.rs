@@ -1,32 +1,55 @@
 //! This module contains systems that update the UI when something changes
 
-use crate::{CalculatedClip, Display, OverflowAxis, Style, TargetCamera};
+use crate::{
+    CalculatedClip, DefaultUiCamera, Display, OverflowAxis, Style, TargetCamera, UiClipShape,
+    UiScale,
+};
 
 use super::Node;
 use bevy_ecs::{
     entity::Entity,
     query::{Changed, With, Without},
-    system::{Commands, Query},
+    system::{Commands, Query, Res},
 };
 use bevy_hierarchy::{Children, Parent};
-use bevy_math::Rect;
+use bevy_math::{Rect, RectRounding};
+use bevy_render::camera::Camera;
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashSet;
 
 /// Updates clipping for all nodes
 pub fn update_clipping_system(
     mut commands: Commands,
-    root_node_query: Query<Entity, (With<Node>, Without<Parent>)>,
-    mut node_query: Query<(&Node, &GlobalTransform, &Style, Option<&mut CalculatedClip>)>,
+    root_node_query: Query<(Entity, Option<&TargetCamera>), (With<Node>, Without<Parent>)>,
+    camera_query: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: Res<UiScale>,
+    mut node_query: Query<(
+        &Node,
+        &GlobalTransform,
+        &Style,
+        Option<&UiClipShape>,
+        Option<&mut CalculatedClip>,
+    )>,
     children_query: Query<&Children>,
 ) {
-    for root_node in &root_node_query {
+    for (root_node, target_camera) in &root_node_query {
+        // The scale factor from the logical sizes of the nodes to the physical pixels they are
+        // rounded to by the layout
+        let scale_factor = target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera| camera_query.get(camera).ok())
+            .and_then(Camera::target_scaling_factor)
+            .unwrap_or(1.0)
+            * ui_scale.0;
         update_clipping(
             &mut commands,
             &children_query,
             &mut node_query,
             root_node,
             None,
+            scale_factor,
         );
     }
 }
@@ -34,38 +57,42 @@ pub fn update_clipping_system(
 fn update_clipping(
     commands: &mut Commands,
     children_query: &Query<&Children>,
-    node_query: &mut Query<(&Node, &GlobalTransform, &Style, Option<&mut CalculatedClip>)>,
+    node_query: &mut Query<(
+        &Node,
+        &GlobalTransform,
+        &Style,
+        Option<&UiClipShape>,
+        Option<&mut CalculatedClip>,
+    )>,
     entity: Entity,
-    mut maybe_inherited_clip: Option<Rect>,
+    mut maybe_inherited_clip: Option<CalculatedClip>,
+    scale_factor: f32,
 ) {
-    let Ok((node, global_transform, style, maybe_calculated_clip)) = node_query.get_mut(entity)
+    let Ok((node, global_transform, style, clip_shape, maybe_calculated_clip)) =
+        node_query.get_mut(entity)
     else {
         return;
     };
 
     // If `display` is None, clip the entire node and all its descendants by replacing the inherited clip with a default rect (which is empty)
     if style.display == Display::None {
-        maybe_inherited_clip = Some(Rect::default());
+        maybe_inherited_clip = Some(CalculatedClip::default());
     }
 
     // Update this node's CalculatedClip component
     if let Some(mut calculated_clip) = maybe_calculated_clip {
         if let Some(inherited_clip) = maybe_inherited_clip {
-            // Replace the previous calculated clip with the inherited clipping rect
-            if calculated_clip.clip != inherited_clip {
-                *calculated_clip = CalculatedClip {
-                    clip: inherited_clip,
-                };
+            // Replace the previous calculated clip with the inherited clip
+            if *calculated_clip != inherited_clip {
+                *calculated_clip = inherited_clip;
             }
         } else {
             // No inherited clipping rect, remove the component
             commands.entity(entity).remove::<CalculatedClip>();
         }
     } else if let Some(inherited_clip) = maybe_inherited_clip {
-        // No previous calculated clip, add a new CalculatedClip component with the inherited clipping rect
-        commands.entity(entity).try_insert(CalculatedClip {
-            clip: inherited_clip,
-        });
+        // No previous calculated clip, add a new CalculatedClip component with the inherited clip
+        commands.entity(entity).try_insert(inherited_clip);
     }
 
     // Calculate new clip rectangle for children nodes
@@ -80,7 +107,27 @@ fn update_clipping(
         // current node's clip and the inherited clip. This handles the case
         // of nested `Overflow::Hidden` nodes. If parent `clip` is not
         // defined, use the current node's clip.
-        let mut node_rect = node.logical_rect(global_transform);
+        // The layout rounds nodes to whole physical pixels, so snap the clip the same way to keep
+        // floating point errors in the transform from clipping or leaking a pixel.
+        let logical_rect = node.logical_rect(global_transform);
+        let physical_rect = Rect {
+            min: logical_rect.min * scale_factor,
+            max: logical_rect.max * scale_factor,
+        }
+        .round(RectRounding::Round);
+        let mut node_rect = Rect {
+            min: physical_rect.min / scale_factor,
+            max: physical_rect.max / scale_factor,
+        };
+        // The shape replaces the mask of the ancestors, and is computed from the whole node
+        // even along a visible axis
+        let mask = match clip_shape.and_then(|shape| shape.mask(node_rect)) {
+            Some(mask) => {
+                node_rect = node_rect.intersect(mask.rect);
+                Some(mask)
+            }
+            None => maybe_inherited_clip.and_then(|clip| clip.mask),
+        };
         if style.overflow.x == OverflowAxis::Visible {
             node_rect.min.x = -f32::INFINITY;
             node_rect.max.x = f32::INFINITY;
@@ -89,12 +136,22 @@ fn update_clipping(
             node_rect.min.y = -f32::INFINITY;
             node_rect.max.y = f32::INFINITY;
         }
-        Some(maybe_inherited_clip.map_or(node_rect, |c| c.intersect(node_rect)))
+        Some(CalculatedClip {
+            clip: maybe_inherited_clip.map_or(node_rect, |c| c.clip.intersect(node_rect)),
+            mask,
+        })
     };
 
     if let Ok(children) = children_query.get(entity) {
         for &child in children {
-            update_clipping(commands, children_query, node_query, child, children_clip);
+            update_clipping(
+                commands,
+                children_query,
+                node_query,
+                child,
+                children_clip,
+                scale_factor,
+            );
         }
     }
 }
@@ -6,7 +6,7 @@ use bevy_ecs::prelude::*;
 use bevy_math::{UVec2, Vec2};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::texture::Image;
-use bevy_sprite::{TextureAtlas, TextureAtlasLayout};
+use bevy_sprite::{TextureAtlas, TextureAtlasAnimation, TextureAtlasLayout};
 use bevy_window::{PrimaryWindow, Window};
 use taffy::{MaybeMath, MaybeResolve};
 
@@ -29,6 +29,13 @@ impl UiImageSize {
     }
 }
 
+/// Plays the frames of the [`TextureAtlas`] of a UI image as a flipbook, such as a loading
+/// spinner or an animated portrait.
+///
+/// It is the [`TextureAtlasAnimation`] of sprites, advanced by the
+/// [`SpritePlugin`](bevy_sprite::SpritePlugin) for both.
+pub type UiImageAnimation = TextureAtlasAnimation;
+
 #[derive(Clone)]
 /// Used to calculate the size of UI image nodes
 pub struct ImageMeasure {
@@ -0,0 +1,224 @@
+use crate::{DefaultUiCamera, Interaction, Node, RelativeCursorPosition, TargetCamera, UiScale};
+use bevy_ecs::{
+    bundle::Bundle,
+    change_detection::DetectChangesMut,
+    prelude::Component,
+    query::{Changed, Or},
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::camera::Camera;
+use bevy_text::{PositionedGlyph, Text, TextLayoutInfo};
+
+use super::text_scale_factor;
+
+/// A line drawn along the glyphs of a text section.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum TextDecoration {
+    /// No decoration.
+    #[default]
+    None,
+    /// A line under the glyphs of the section.
+    Underline,
+}
+
+/// The [`TextDecoration`] of each section of a UI [`Text`], by section index.
+///
+/// Sections past the end of the list aren't decorated.
+/// This is kept apart from [`Text`] since changing a decoration doesn't require the text to be laid out again.
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct TextDecorations(pub Vec<TextDecoration>);
+
+impl TextDecorations {
+    /// Returns the decoration of the section at `section_index`.
+    pub fn get(&self, section_index: usize) -> TextDecoration {
+        self.0.get(section_index).copied().unwrap_or_default()
+    }
+}
+
+/// Describes what type of input interaction has occurred for a section of a UI [`Text`].
+///
+/// Updated by [`text_span_interaction_system`] for text entities that also have an
+/// [`Interaction`] and a [`RelativeCursorPosition`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub enum TextSpanInteraction {
+    /// The section with the given index has been pressed.
+    Pressed(usize),
+    /// The section with the given index is hovered over.
+    Hovered(usize),
+    /// No section is interacted with.
+    #[default]
+    None,
+}
+
+impl TextSpanInteraction {
+    /// Returns the index of the section that is hovered or pressed, if any.
+    pub fn section(&self) -> Option<usize> {
+        match *self {
+            TextSpanInteraction::Pressed(section) | TextSpanInteraction::Hovered(section) => {
+                Some(section)
+            }
+            TextSpanInteraction::None => None,
+        }
+    }
+}
+
+/// The [`TextDecoration`]s of the sections of a link text, depending on whether they're hovered.
+///
+/// Applied to [`TextDecorations`] by [`update_link_decorations`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct LinkStyles {
+    /// The decoration of sections that aren't hovered or pressed.
+    pub normal: TextDecoration,
+    /// The decoration of the hovered or pressed section.
+    pub hovered: TextDecoration,
+}
+
+impl Default for LinkStyles {
+    /// Underlines the hovered section only.
+    fn default() -> Self {
+        Self {
+            normal: TextDecoration::None,
+            hovered: TextDecoration::Underline,
+        }
+    }
+}
+
+/// The components needed to make the sections of a [`TextBundle`](crate::node_bundles::TextBundle)
+/// behave as links.
+#[derive(Bundle, Clone, Debug, Default)]
+pub struct TextLinkBundle {
+    /// The decorations of the sections, depending on their interaction
+    pub link_styles: LinkStyles,
+    /// The decorations currently drawn for each section
+    pub decorations: TextDecorations,
+    /// Which section is interacted with
+    pub span_interaction: TextSpanInteraction,
+    /// Whether the text node is interacted with
+    pub interaction: Interaction,
+    /// The cursor position, used to find the section under it
+    pub relative_cursor_position: RelativeCursorPosition,
+}
+
+/// The system that sets [`TextSpanInteraction`] from the node's [`Interaction`],
+/// using the glyph under the cursor to find the section.
+pub fn text_span_interaction_system(
+    camera_query: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: Res<UiScale>,
+    mut query: Query<(
+        &Node,
+        &Interaction,
+        &RelativeCursorPosition,
+        &TextLayoutInfo,
+        Option<&TargetCamera>,
+        &mut TextSpanInteraction,
+    )>,
+) {
+    for (node, interaction, relative_cursor_position, text_layout_info, camera, mut span) in
+        &mut query
+    {
+        let section = (*interaction != Interaction::None)
+            .then_some(relative_cursor_position.normalized)
+            .flatten()
+            .and_then(|normalized| {
+                let scale_factor =
+                    text_scale_factor(camera, &default_ui_camera, &camera_query, ui_scale.0);
+                // Glyphs are positioned in physical pixels, relative to the top-left corner of the
+                // content box of the node
+                let inset = node.content_inset();
+                let cursor =
+                    (normalized * node.size() - Vec2::new(inset.left, inset.top)) * scale_factor;
+                section_at(&text_layout_info.glyphs, cursor)
+            });
+
+        let new_span = match (section, interaction) {
+            (Some(section), Interaction::Pressed) => TextSpanInteraction::Pressed(section),
+            (Some(section), _) => TextSpanInteraction::Hovered(section),
+            (None, _) => TextSpanInteraction::None,
+        };
+        span.set_if_neq(new_span);
+    }
+}
+
+fn section_at(glyphs: &[PositionedGlyph], position: Vec2) -> Option<usize> {
+    glyphs
+        .iter()
+        .find(|glyph| Rect::from_center_size(glyph.position, glyph.size).contains(position))
+        .map(|glyph| glyph.section_index)
+}
+
+/// Writes the [`LinkStyles`] of each text into its [`TextDecorations`], depending on its [`TextSpanInteraction`].
+pub fn update_link_decorations(
+    mut query: Query<
+        (
+            &LinkStyles,
+            &TextSpanInteraction,
+            &Text,
+            &mut TextDecorations,
+        ),
+        Or<(
+            Changed<LinkStyles>,
+            Changed<TextSpanInteraction>,
+            Changed<Text>,
+        )>,
+    >,
+) {
+    for (link_styles, span, text, mut decorations) in &mut query {
+        let hovered = span.section();
+        let new_decorations = (0..text.sections.len())
+            .map(|index| {
+                if hovered == Some(index) {
+                    link_styles.hovered
+                } else {
+                    link_styles.normal
+                }
+            })
+            .collect();
+        decorations.set_if_neq(TextDecorations(new_decorations));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_ecs::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn hovered_section_is_decorated() {
+        let mut app = App::new();
+        app.add_systems(Update, update_link_decorations);
+
+        let text = Text::from_sections(["FPS: ".into(), "60".into()]);
+        let entity = app
+            .world_mut()
+            .spawn((text, TextLinkBundle::default()))
+            .id();
+
+        let decorations = |app: &App| app.world().get::<TextDecorations>(entity).unwrap().clone();
+
+        app.update();
+        assert_eq!(decorations(&app).get(0), TextDecoration::None);
+
+        *app.world_mut()
+            .get_mut::<TextSpanInteraction>(entity)
+            .unwrap() = TextSpanInteraction::Hovered(0);
+        app.update();
+        assert_eq!(decorations(&app).get(0), TextDecoration::Underline);
+        assert_eq!(decorations(&app).get(1), TextDecoration::None);
+
+        *app.world_mut()
+            .get_mut::<TextSpanInteraction>(entity)
+            .unwrap() = TextSpanInteraction::None;
+        app.update();
+        assert_eq!(decorations(&app).get(0), TextDecoration::None);
+    }
+}
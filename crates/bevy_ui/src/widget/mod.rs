@@ -4,10 +4,43 @@ mod button;
 mod image;
 mod label;
 #[cfg(feature = "bevy_text")]
+mod link;
+#[cfg(feature = "bevy_text")]
+mod selectable_text;
+mod slider;
+#[cfg(feature = "bevy_text")]
 mod text;
+#[cfg(feature = "bevy_text")]
+mod text_atlas_icon;
+mod toggle_visibility;
 
 pub use button::*;
 pub use image::*;
 pub use label::*;
 #[cfg(feature = "bevy_text")]
+pub use link::*;
+#[cfg(feature = "bevy_text")]
+pub use selectable_text::*;
+pub use slider::*;
+#[cfg(feature = "bevy_text")]
 pub use text::*;
+#[cfg(feature = "bevy_text")]
+pub use text_atlas_icon::*;
+pub use toggle_visibility::*;
+
+/// Returns the scale factor from the logical size of a UI node to the physical positions of its glyphs.
+#[cfg(feature = "bevy_text")]
+fn text_scale_factor(
+    camera: Option<&crate::TargetCamera>,
+    default_ui_camera: &crate::DefaultUiCamera,
+    camera_query: &bevy_ecs::system::Query<&bevy_render::camera::Camera>,
+    ui_scale: f32,
+) -> f32 {
+    camera
+        .map(crate::TargetCamera::entity)
+        .or(default_ui_camera.get())
+        .and_then(|entity| camera_query.get(entity).ok())
+        .and_then(bevy_render::camera::Camera::target_scaling_factor)
+        .unwrap_or(1.0)
+        * ui_scale
+}
@@ -0,0 +1,277 @@
+use crate::{DefaultUiCamera, Interaction, Node, RelativeCursorPosition, TargetCamera, UiScale};
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    bundle::Bundle,
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    event::{Event, EventWriter},
+    prelude::Component,
+    reflect::{ReflectComponent, ReflectResource},
+    system::{Query, Res, ResMut, Resource},
+};
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton, ButtonInput};
+use bevy_math::Vec2;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::camera::Camera;
+use bevy_text::{PositionedGlyph, Text, TextLayoutInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::text_scale_factor;
+
+/// A position between two graphemes of a [`Text`], as a byte index in one of its sections.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub struct TextPosition {
+    /// The index of the section.
+    pub section: usize,
+    /// The byte index in the [displayed value](bevy_text::Text::displayed_values) of the section.
+    pub byte: usize,
+}
+
+/// Makes a UI [`Text`] selectable by dragging the mouse over its glyphs, and copyable with Ctrl+C.
+///
+/// The selection is updated by [`text_selection_system`], for text entities that also have an
+/// [`Interaction`] and a [`RelativeCursorPosition`] (see [`SelectableTextBundle`]).
+/// Copied text is written to the in-process [`TextClipboard`] resource, not to the clipboard of the
+/// operating system, and sent in a [`TextCopied`] event.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct SelectableText {
+    /// The color drawn behind the selected glyphs.
+    pub highlight: Color,
+    anchor: Option<TextPosition>,
+    focus: Option<TextPosition>,
+    dragging: bool,
+}
+
+impl Default for SelectableText {
+    fn default() -> Self {
+        Self {
+            highlight: Color::Srgba(Srgba::new(0.2, 0.4, 0.9, 0.6)),
+            anchor: None,
+            focus: None,
+            dragging: false,
+        }
+    }
+}
+
+impl SelectableText {
+    /// Returns the selected range, from its first to its last position, if the selection isn't empty.
+    pub fn selection(&self) -> Option<(TextPosition, TextPosition)> {
+        let (anchor, focus) = (self.anchor?, self.focus?);
+        (anchor != focus).then(|| (anchor.min(focus), anchor.max(focus)))
+    }
+
+    /// Selects the text from `anchor` to `focus`.
+    pub fn select(&mut self, anchor: TextPosition, focus: TextPosition) {
+        self.anchor = Some(anchor);
+        self.focus = Some(focus);
+    }
+
+    /// Clears the selection.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+        self.focus = None;
+        self.dragging = false;
+    }
+
+    /// Returns `true` if the byte at `position` is selected.
+    pub fn is_selected(&self, position: TextPosition) -> bool {
+        self.selection()
+            .is_some_and(|(start, end)| start <= position && position < end)
+    }
+
+    /// Returns the selected substring of `text`, if the selection isn't empty.
+    pub fn selected_text(&self, text: &Text) -> Option<String> {
+        let (start, end) = self.selection()?;
+        let mut selected = String::new();
+        // Positions are in the displayed values, after the truncation and the whitespace handling
+        for (index, value) in text.displayed_values().iter().enumerate() {
+            if index < start.section || index > end.section {
+                continue;
+            }
+            let from = if index == start.section {
+                start.byte
+            } else {
+                0
+            };
+            let to = if index == end.section {
+                end.byte
+            } else {
+                value.len()
+            };
+            selected.push_str(value.get(from..to)?);
+        }
+        Some(selected)
+    }
+}
+
+/// The components needed to make a [`TextBundle`](crate::node_bundles::TextBundle) selectable.
+#[derive(Bundle, Clone, Debug, Default)]
+pub struct SelectableTextBundle {
+    /// The selection of the text
+    pub selectable: SelectableText,
+    /// Whether the text node is interacted with
+    pub interaction: Interaction,
+    /// The cursor position, used to find the glyph under it
+    pub relative_cursor_position: RelativeCursorPosition,
+}
+
+/// An in-process clipboard, holding the text copied from a [`SelectableText`].
+///
+/// This isn't the clipboard of the operating system, which Bevy doesn't access: pasting in other
+/// applications doesn't see the copied text. Read [`TextCopied`] events or this resource to
+/// forward copied text to the clipboard of the operating system.
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource, Default)]
+pub struct TextClipboard {
+    /// The last copied text.
+    pub contents: String,
+}
+
+/// Sent when the selection of a [`SelectableText`] is copied.
+#[derive(Event, Debug, Clone)]
+pub struct TextCopied {
+    /// The text entity.
+    pub entity: Entity,
+    /// The copied text.
+    pub text: String,
+}
+
+/// The system that updates the selection of [`SelectableText`] from mouse drags,
+/// and copies it on Ctrl+C.
+#[allow(clippy::too_many_arguments)]
+pub fn text_selection_system(
+    camera_query: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: Res<UiScale>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut clipboard: ResMut<TextClipboard>,
+    mut copied: EventWriter<TextCopied>,
+    mut query: Query<(
+        Entity,
+        &Node,
+        &Interaction,
+        &RelativeCursorPosition,
+        &Text,
+        &TextLayoutInfo,
+        Option<&TargetCamera>,
+        &mut SelectableText,
+    )>,
+) {
+    let copy = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) && keys.just_pressed(KeyCode::KeyC);
+
+    for (entity, node, interaction, cursor, text, text_layout_info, camera, mut current) in
+        &mut query
+    {
+        // Updated on a copy, so that the component is only marked as changed when it changes
+        let mut selectable = current.clone();
+        if mouse.just_pressed(MouseButton::Left) {
+            if *interaction == Interaction::Pressed {
+                selectable.dragging = true;
+                selectable.anchor = None;
+            } else {
+                selectable.clear_selection();
+            }
+        }
+        if !mouse.pressed(MouseButton::Left) {
+            selectable.dragging = false;
+        }
+
+        if selectable.dragging {
+            if let Some(normalized) = cursor.normalized {
+                let scale_factor =
+                    text_scale_factor(camera, &default_ui_camera, &camera_query, ui_scale.0);
+                // Glyphs are positioned in physical pixels, relative to the top-left corner of the
+                // content box of the node
+                let inset = node.content_inset();
+                let position =
+                    (normalized * node.size() - Vec2::new(inset.left, inset.top)) * scale_factor;
+                if let Some(position) = position_at(text, &text_layout_info.glyphs, position) {
+                    let anchor = selectable.anchor.unwrap_or(position);
+                    selectable.select(anchor, position);
+                }
+            }
+        }
+
+        if copy {
+            if let Some(selected) = selectable.selected_text(text) {
+                clipboard.contents.clone_from(&selected);
+                copied.send(TextCopied {
+                    entity,
+                    text: selected,
+                });
+            }
+        }
+        current.set_if_neq(selectable);
+    }
+}
+
+/// Returns the [`TextPosition`] closest to `position`, snapped to the grapheme boundaries.
+fn position_at(text: &Text, glyphs: &[PositionedGlyph], position: Vec2) -> Option<TextPosition> {
+    // Prefer the glyphs of the line under the position, then the closest one
+    let glyph = glyphs.iter().min_by(|a, b| {
+        let distance = |glyph: &PositionedGlyph| {
+            let half_size = 0.5 * glyph.size;
+            let offset = (position - glyph.position).abs() - half_size;
+            (offset.y.max(0.), offset.x.max(0.))
+        };
+        distance(a)
+            .partial_cmp(&distance(b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })?;
+
+    let values = text.displayed_values();
+    let value = values.get(glyph.section_index)?;
+    let (start, grapheme) = value
+        .grapheme_indices(true)
+        .find(|(start, grapheme)| glyph.byte_index < start + grapheme.len())?;
+    // Select up to the grapheme if the position is on its left half, and past it otherwise
+    let byte = if position.x < glyph.position.x {
+        start
+    } else {
+        start + grapheme.len()
+    };
+    Some(TextPosition {
+        section: glyph.section_index,
+        byte,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selected_text_spans_sections() {
+        let text = Text::from_sections(["FPS: ".into(), "60.00".into()]);
+        let mut selectable = SelectableText::default();
+        assert_eq!(selectable.selected_text(&text), None);
+
+        selectable.select(
+            TextPosition {
+                section: 1,
+                byte: 2,
+            },
+            TextPosition {
+                section: 0,
+                byte: 2,
+            },
+        );
+        assert_eq!(selectable.selected_text(&text).as_deref(), Some("S: 60"));
+        assert!(selectable.is_selected(TextPosition {
+            section: 0,
+            byte: 4,
+        }));
+        assert!(!selectable.is_selected(TextPosition {
+            section: 1,
+            byte: 2,
+        }));
+    }
+}
@@ -0,0 +1,394 @@
+use crate::{Interaction, RelativeCursorPosition, Style, Val};
+use bevy_ecs::{
+    bundle::Bundle,
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    event::{Event, EventWriter},
+    prelude::Component,
+    query::{Changed, With},
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_hierarchy::Children;
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton, touch::Touches, ButtonInput};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "bevy_text")]
+use {
+    bevy_ecs::{change_detection::DetectChanges, world::Ref},
+    bevy_text::Text,
+};
+
+/// A slider picking a value in a range, by dragging the pointer over its node or with the
+/// arrow keys.
+///
+/// The node of the slider is its track, styled like any other node. Its children with a
+/// [`SliderHandle`] are moved along it to the value, and texts with a [`SliderValueText`] show the
+/// value.
+///
+/// The value is updated by [`slider_system`], for slider entities that also have an
+/// [`Interaction`] and a [`RelativeCursorPosition`] (see [`SliderBundle`]). Pressing the track
+/// drags the value to the pointer and focuses the slider: the arrow keys then move the value by
+/// its step, and Home and End move it to its bounds, until the pointer is pressed outside of it.
+/// The changes of the value from these inputs are sent as [`SliderChanged`] events.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_hierarchy::BuildChildren;
+/// # use bevy_ui::{node_bundles::NodeBundle, widget::{Slider, SliderBundle, SliderHandle}, PositionType, Style, UiRect, Val};
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn((
+///             NodeBundle {
+///                 style: Style {
+///                     width: Val::Px(200.),
+///                     height: Val::Px(8.),
+///                     ..Default::default()
+///                 },
+///                 ..Default::default()
+///             },
+///             SliderBundle {
+///                 slider: Slider::new(0., 10.).with_step(0.5).with_value(5.),
+///                 ..Default::default()
+///             },
+///         ))
+///         .with_children(|track| {
+///             track.spawn((
+///                 NodeBundle {
+///                     style: Style {
+///                         position_type: PositionType::Absolute,
+///                         width: Val::Px(16.),
+///                         height: Val::Px(16.),
+///                         top: Val::Px(-4.),
+///                         // Centers the handle on the value
+///                         margin: UiRect::left(Val::Px(-8.)),
+///                         ..Default::default()
+///                     },
+///                     ..Default::default()
+///                 },
+///                 SliderHandle,
+///             ));
+///         });
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct Slider {
+    /// The value, from `min` to `max`. Set it with [`Slider::set_value`] to keep it in the range
+    /// and on a step.
+    pub value: f32,
+    /// The lowest value.
+    pub min: f32,
+    /// The highest value.
+    pub max: f32,
+    /// The increment the value snaps to from `min`, and is moved by with the arrow keys.
+    ///
+    /// With `0.`, the value is continuous and the arrow keys move it by a hundredth of the range.
+    pub step: f32,
+    focused: bool,
+    dragging: bool,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self::new(0., 1.)
+    }
+}
+
+impl Slider {
+    /// Creates a continuous [`Slider`] from `min` to `max`, at `min`.
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            value: min,
+            min,
+            max,
+            step: 0.,
+            focused: false,
+            dragging: false,
+        }
+    }
+
+    /// Returns this [`Slider`] with a new [`step`](Self::step), snapping its value to it.
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
+        self.set_value(self.value);
+        self
+    }
+
+    /// Returns this [`Slider`] at a new value, clamped and snapped like with [`Slider::set_value`].
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the value, clamped to the range and snapped to the nearest step.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self.snap(value);
+    }
+
+    /// Returns `value` clamped to the range and snapped to the nearest step from `min`.
+    ///
+    /// The value snaps to `max` rather than to a step past it.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max.max(self.min));
+        if self.step > 0. {
+            let steps = ((value - self.min) / self.step).round();
+            (self.min + steps * self.step).min(self.max)
+        } else {
+            value
+        }
+    }
+
+    /// Returns the position of the value in the range, from `0.` at `min` to `1.` at `max`.
+    pub fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
+
+    /// Returns `true` if the slider is moved by the arrow keys.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Focuses or unfocuses the slider, for the arrow keys to move it.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns `true` while the pointer drags the value.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    fn key_step(&self) -> f32 {
+        if self.step > 0. {
+            self.step
+        } else {
+            (self.max - self.min) / 100.
+        }
+    }
+}
+
+/// Marks a child of a [`Slider`] node moved along it to the value.
+///
+/// Its [`Style::left`] is set to the position of the value in the range, as a percentage of the
+/// track. Give it a [`PositionType::Absolute`](crate::PositionType::Absolute) and a negative left
+/// margin of half its width to center it on the value.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct SliderHandle;
+
+/// The components needed to make a [`NodeBundle`](crate::node_bundles::NodeBundle) a slider.
+#[derive(Bundle, Clone, Debug, Default)]
+pub struct SliderBundle {
+    /// The value of the slider
+    pub slider: Slider,
+    /// Whether the slider is pressed
+    pub interaction: Interaction,
+    /// The cursor position, used to drag the value
+    pub relative_cursor_position: RelativeCursorPosition,
+}
+
+/// Sent when the value of a [`Slider`] is changed by the pointer or the keyboard.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SliderChanged {
+    /// The slider entity.
+    pub entity: Entity,
+    /// The new value.
+    pub value: f32,
+}
+
+/// The system that updates the value and the focus of the [`Slider`]s from pointer drags and the
+/// arrow keys.
+pub fn slider_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut changed: EventWriter<SliderChanged>,
+    mut query: Query<(Entity, &Interaction, &RelativeCursorPosition, &mut Slider)>,
+) {
+    let just_pressed = mouse.just_pressed(MouseButton::Left) || touches.any_just_pressed();
+    let pressed = mouse.pressed(MouseButton::Left) || touches.iter().next().is_some();
+
+    for (entity, interaction, cursor, mut slider) in &mut query {
+        // Only mark the slider as changed when its value or its focus changes
+        let state = slider.bypass_change_detection();
+        let (value, focused) = (state.value, state.focused);
+
+        if just_pressed {
+            state.focused = *interaction == Interaction::Pressed;
+            state.dragging = state.focused;
+        }
+        if !pressed {
+            state.dragging = false;
+        }
+
+        if state.dragging {
+            if let Some(normalized) = cursor.normalized {
+                let fraction = normalized.x.clamp(0., 1.);
+                state.set_value(state.min + fraction * (state.max - state.min));
+            }
+        }
+
+        if state.focused {
+            let step = state.key_step();
+            let mut new_value = state.value;
+            if keys.any_just_pressed([KeyCode::ArrowLeft, KeyCode::ArrowDown]) {
+                new_value -= step;
+            }
+            if keys.any_just_pressed([KeyCode::ArrowRight, KeyCode::ArrowUp]) {
+                new_value += step;
+            }
+            if keys.just_pressed(KeyCode::Home) {
+                new_value = state.min;
+            }
+            if keys.just_pressed(KeyCode::End) {
+                new_value = state.max;
+            }
+            state.set_value(new_value);
+        }
+
+        let new_value = state.value;
+        if new_value != value || state.focused != focused {
+            slider.set_changed();
+        }
+        if new_value != value {
+            changed.send(SliderChanged {
+                entity,
+                value: new_value,
+            });
+        }
+    }
+}
+
+/// Moves the [`SliderHandle`]s of the changed [`Slider`]s to their value.
+pub fn update_slider_handles(
+    sliders: Query<(&Slider, &Children), Changed<Slider>>,
+    mut handles: Query<&mut Style, With<SliderHandle>>,
+) {
+    for (slider, children) in &sliders {
+        let left = Val::Percent(slider.fraction() * 100.);
+        let mut children = handles.iter_many_mut(children);
+        while let Some(mut style) = children.fetch_next() {
+            if style.left != left {
+                style.left = left;
+            }
+        }
+    }
+}
+
+/// Shows the value of a [`Slider`] in the first section of the [`Text`] of its entity.
+#[cfg(feature = "bevy_text")]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct SliderValueText {
+    /// The slider entity.
+    pub slider: Entity,
+    /// The number of decimals of the value.
+    pub precision: usize,
+}
+
+#[cfg(feature = "bevy_text")]
+impl SliderValueText {
+    /// Creates a [`SliderValueText`] showing the value of `slider` with 2 decimals.
+    pub fn new(slider: Entity) -> Self {
+        Self {
+            slider,
+            precision: 2,
+        }
+    }
+
+    /// Returns this [`SliderValueText`] with a new number of decimals.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+/// Writes the values of the changed [`Slider`]s into the texts of their [`SliderValueText`]s.
+#[cfg(feature = "bevy_text")]
+pub fn update_slider_value_texts(
+    sliders: Query<Ref<Slider>>,
+    mut texts: Query<(Ref<SliderValueText>, &mut Text)>,
+) {
+    for (value_text, mut text) in &mut texts {
+        let Ok(slider) = sliders.get(value_text.slider) else {
+            continue;
+        };
+        if !slider.is_changed() && !value_text.is_changed() {
+            continue;
+        }
+        if let Some(section) = text.sections.first_mut() {
+            section.value = format!("{:.*}", value_text.precision, slider.value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{event::Events, system::RunSystemOnce, world::World};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn values_are_clamped_and_snapped() {
+        let slider = Slider::new(1., 2.).with_step(0.25);
+        assert_eq!(slider.snap(1.3), 1.25);
+        assert_eq!(slider.snap(1.9), 2.);
+        assert_eq!(slider.snap(-5.), 1.);
+        assert_eq!(slider.snap(5.), 2.);
+        // The last step stops at the end of the range
+        let slider = Slider::new(0., 1.).with_step(0.4);
+        assert_eq!(slider.snap(0.95), 1.);
+        assert_eq!(Slider::new(0., 1.).snap(0.3), 0.3);
+    }
+
+    #[test]
+    fn pointer_and_keys_move_the_value() {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<Events<SliderChanged>>();
+        let entity = world
+            .spawn(SliderBundle {
+                slider: Slider::new(0., 10.).with_step(1.),
+                interaction: Interaction::Pressed,
+                relative_cursor_position: RelativeCursorPosition {
+                    normalized_visible_node_rect: Default::default(),
+                    normalized: Some(Vec2::new(0.42, 0.5)),
+                },
+            })
+            .id();
+
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        world.run_system_once(slider_system);
+        let slider = world.get::<Slider>(entity).unwrap();
+        assert_eq!(slider.value, 4.);
+        assert!(slider.is_focused() && slider.is_dragging());
+
+        world.resource_mut::<ButtonInput<MouseButton>>().clear();
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowRight);
+        world.run_system_once(slider_system);
+        let slider = world.get::<Slider>(entity).unwrap();
+        assert_eq!(slider.value, 5.);
+        assert!(slider.is_focused() && !slider.is_dragging());
+
+        let events = world.resource::<Events<SliderChanged>>();
+        let values: Vec<f32> = events
+            .get_reader()
+            .read(events)
+            .map(|event| event.value)
+            .collect();
+        assert_eq!(values, [4., 5.]);
+    }
+}
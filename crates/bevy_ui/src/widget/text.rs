@@ -1,13 +1,16 @@
 use crate::{
-    ContentSize, DefaultUiCamera, FixedMeasure, Measure, Node, NodeMeasure, TargetCamera, UiScale,
+    BackgroundColor, ContentSize, DefaultUiCamera, FixedMeasure, Measure, Node, NodeMeasure,
+    TargetCamera, UiScale,
 };
 use bevy_asset::Assets;
+use bevy_color::Alpha;
 use bevy_ecs::{
-    entity::{Entity, EntityHashMap},
+    entity::{Entity, EntityHashMap, EntityHashSet},
     prelude::{Component, DetectChanges},
     query::With,
     reflect::ReflectComponent,
-    system::{Local, Query, Res, ResMut},
+    removal_detection::RemovedComponents,
+    system::{Commands, Local, Query, Res, ResMut},
     world::{Mut, Ref},
 };
 use bevy_math::Vec2;
@@ -15,10 +18,12 @@ use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{camera::Camera, texture::Image};
 use bevy_sprite::TextureAtlasLayout;
 use bevy_text::{
-    scale_value, BreakLineOn, Font, FontAtlasSets, Text, TextError, TextLayoutInfo,
-    TextMeasureInfo, TextPipeline, TextSettings, YAxisOrientation,
+    scale_value, BreakLineOn, DefaultFont, Font, FontAtlasSets, GlyphAntialiasing, JustifyText,
+    Text, TextAtlasSettings, TextError, TextLayoutInfo, TextLayoutOptions, TextMeasureInfo,
+    TextPipeline, TextRenderError, TextRenderSettings, TextScale, TextScaleOverride, TextSettings,
+    YAxisOrientation,
 };
-use bevy_utils::Entry;
+use bevy_utils::{tracing::error, Entry};
 use taffy::style::AvailableSpace;
 
 /// Text system flags
@@ -42,6 +47,42 @@ impl Default for TextFlags {
     }
 }
 
+/// Scales the rendering of a UI text node around its center, such as to "pop" it on hover.
+///
+/// The scale is only applied to the vertices of the glyphs when rendering: the text isn't laid
+/// out or rasterized again, and its node keeps its size in the layout. Add
+/// [`TextRenderScalePicking`] for the node to be picked within its scaled bounds.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct TextRenderScale(pub f32);
+
+impl Default for TextRenderScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Makes the [`Interaction`](crate::Interaction) and
+/// [`RelativeCursorPosition`](crate::RelativeCursorPosition) of a UI node account for its
+/// [`TextRenderScale`].
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct TextRenderScalePicking;
+
+/// Marks a UI text node whose rendering rarely changes, such as a label, so that it's extracted to
+/// the render world once and reused on the following frames.
+///
+/// The extracted nodes are kept in the render world and queued as a single item per texture, so
+/// reusing them doesn't depend on the number of glyphs. Their vertices are still prepared every
+/// frame.
+///
+/// The text is extracted again when it changes: when its [`Text`], layout, node, transform, clip,
+/// camera, decorations, colors, selection, render scale or background change. Text that changes
+/// every frame shouldn't be marked, since it would be copied to the cache every frame.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct StaticText;
+
 #[derive(Clone)]
 pub struct TextMeasure {
     pub info: TextMeasureInfo,
@@ -64,16 +105,26 @@ impl Measure for TextMeasure {
 
                 x.max(self.info.min.x).min(self.info.max.x)
             }
+            // The widest word, which can't be broken
             AvailableSpace::MinContent => self.info.min.x,
+            // The widest line, without any soft wrapping
             AvailableSpace::MaxContent => self.info.max.x,
         });
 
         height
             .map_or_else(
-                || match available_width {
-                    AvailableSpace::Definite(_) => self.info.compute_size(Vec2::new(x, f32::MAX)),
-                    AvailableSpace::MinContent => Vec2::new(x, self.info.min.y),
-                    AvailableSpace::MaxContent => Vec2::new(x, self.info.max.y),
+                || {
+                    // The text is only laid out again if the width is between its min and max
+                    // content widths, since it otherwise wraps like at either of them
+                    let size = if x >= self.info.max.x {
+                        self.info.max
+                    } else if x <= self.info.min.x {
+                        self.info.min
+                    } else {
+                        self.info.compute_size(Vec2::new(x, f32::MAX))
+                    };
+                    // Without a known width, the node hugs the wrapped text
+                    Vec2::new(width.map_or(size.x, |_| x), size.y)
                 },
                 |y| Vec2::new(x, y),
             )
@@ -81,18 +132,35 @@ impl Measure for TextMeasure {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 #[inline]
 fn create_text_measure(
+    commands: &mut Commands,
+    entity: Entity,
+    render_error: Option<&TextRenderError>,
     fonts: &Assets<Font>,
+    default_font: &DefaultFont,
     scale_factor: f32,
+    window_scale_factor: f32,
+    text_scale: f32,
     text: Ref<Text>,
     mut content_size: Mut<ContentSize>,
     mut text_flags: Mut<TextFlags>,
 ) {
-    match TextMeasureInfo::from_text(&text, fonts, scale_factor) {
+    match TextMeasureInfo::from_text(
+        &text,
+        fonts,
+        default_font,
+        scale_factor * text_scale,
+        window_scale_factor,
+    ) {
         Ok(measure) => {
             if text.linebreak_behavior == BreakLineOn::NoWrap {
                 content_size.set(NodeMeasure::Fixed(FixedMeasure { size: measure.max }));
+            } else if let Some(wrap_width) = text.wrap_width {
+                // The text wraps the same whatever the width of the node, which hugs it
+                let size = measure.compute_size(Vec2::new(wrap_width * scale_factor, f32::MAX));
+                content_size.set(NodeMeasure::Fixed(FixedMeasure { size }));
             } else {
                 content_size.set(NodeMeasure::Text(TextMeasure { info: measure }));
             }
@@ -104,9 +172,32 @@ fn create_text_measure(
         Err(TextError::NoSuchFont) => {
             // Try again next frame
             text_flags.needs_new_measure_func = true;
+            TextRenderError::update(
+                commands,
+                entity,
+                render_error,
+                Some(TextRenderError::FontNotLoaded),
+            );
+        }
+        // Only laying out the text needs its inline images and font atlases, measuring it uses
+        // their size, so these aren't expected here. Try again next frame, like a missing font.
+        Err(TextError::FailedToAddGlyph(_)) => {
+            text_flags.needs_new_measure_func = true;
+            TextRenderError::update(
+                commands,
+                entity,
+                render_error,
+                Some(TextRenderError::AtlasFull),
+            );
         }
-        Err(e @ TextError::FailedToAddGlyph(_)) => {
-            panic!("Fatal error when processing text: {e}.");
+        Err(TextError::NoSuchImage) => {
+            text_flags.needs_new_measure_func = true;
+            TextRenderError::update(
+                commands,
+                entity,
+                render_error,
+                Some(TextRenderError::ImageNotLoaded),
+            );
         }
     };
 }
@@ -116,29 +207,46 @@ fn create_text_measure(
 /// to provide for the text given the fonts, the text itself and the constraints of the layout.
 ///
 /// * Measures are regenerated if the target camera's scale factor (or primary window if no specific target) or [`UiScale`] is changed.
+/// * Measures are regenerated if the [`TextScale`] factor, or the [`TextScaleOverride`] of the text, is changed.
+/// * Measures of text using the [`DefaultFont`] are regenerated when it changes.
+/// * Text whose font isn't loaded gets a [`TextRenderError`] until the font is loaded.
 /// * Changes that only modify the colors of a `Text` do not require a new `Measure`. This system
 ///     is only able to detect that a `Text` component has changed and will regenerate the `Measure` on
 ///     color changes. This can be expensive, particularly for large blocks of text, and the [`bypass_change_detection`](bevy_ecs::change_detection::DetectChangesMut::bypass_change_detection)
 ///     method should be called when only changing the `Text`'s colors.
+#[allow(clippy::too_many_arguments)]
 pub fn measure_text_system(
+    mut commands: Commands,
     mut last_scale_factors: Local<EntityHashMap<f32>>,
     fonts: Res<Assets<Font>>,
+    default_font: Res<DefaultFont>,
     camera_query: Query<(Entity, &Camera)>,
     default_ui_camera: DefaultUiCamera,
     ui_scale: Res<UiScale>,
+    text_scale: Res<TextScale>,
+    mut last_text_scale: Local<Option<f32>>,
+    mut removed_text_scale_overrides: RemovedComponents<TextScaleOverride>,
     mut text_query: Query<
         (
+            Entity,
             Ref<Text>,
             &mut ContentSize,
             &mut TextFlags,
             Option<&TargetCamera>,
+            Option<&TextRenderError>,
+            Option<Ref<TextScaleOverride>>,
         ),
         With<Node>,
     >,
 ) {
     let mut scale_factors: EntityHashMap<f32> = EntityHashMap::default();
+    // Compared by value, so that a slider setting the same factor every frame doesn't relayout
+    let text_scale_changed = last_text_scale.replace(text_scale.0) != Some(text_scale.0);
+    let removed_text_scale_overrides: EntityHashSet = removed_text_scale_overrides.read().collect();
 
-    for (text, content_size, text_flags, camera) in &mut text_query {
+    for (entity, text, content_size, text_flags, camera, render_error, text_scale_override) in
+        &mut text_query
+    {
         let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
         else {
             continue;
@@ -155,11 +263,30 @@ pub fn measure_text_system(
             ),
         };
         if last_scale_factors.get(&camera_entity) != Some(&scale_factor)
+            || (text_scale_changed && text_scale_override.is_none())
+            || text_scale_override
+                .as_ref()
+                .is_some_and(DetectChanges::is_changed)
+            || removed_text_scale_overrides.contains(&entity)
             || text.is_changed()
+            || (default_font.is_changed() && text.uses_default_font())
             || text_flags.needs_new_measure_func
             || content_size.is_added()
         {
-            create_text_measure(&fonts, scale_factor, text, content_size, text_flags);
+            create_text_measure(
+                &mut commands,
+                entity,
+                render_error,
+                &fonts,
+                &default_font,
+                scale_factor,
+                // The font size clamp applies after `UiScale`, which `scale_factor` includes
+                scale_factor / ui_scale.0,
+                text_scale.resolve(text_scale_override.as_deref()),
+                text,
+                content_size,
+                text_flags,
+            );
         }
     }
     *last_scale_factors = scale_factors;
@@ -168,14 +295,21 @@ pub fn measure_text_system(
 #[allow(clippy::too_many_arguments)]
 #[inline]
 fn queue_text(
+    commands: &mut Commands,
+    entity: Entity,
+    render_error: Option<&TextRenderError>,
     fonts: &Assets<Font>,
+    default_font: &DefaultFont,
     text_pipeline: &mut TextPipeline,
     font_atlas_sets: &mut FontAtlasSets,
     texture_atlases: &mut Assets<TextureAtlasLayout>,
     textures: &mut Assets<Image>,
     text_settings: &TextSettings,
+    antialiasing: GlyphAntialiasing,
     scale_factor: f32,
+    window_scale_factor: f32,
     inverse_scale_factor: f32,
+    text_scale: f32,
     text: &Text,
     node: Ref<Node>,
     mut text_flags: Mut<TextFlags>,
@@ -183,38 +317,47 @@ fn queue_text(
 ) {
     // Skip the text node if it is waiting for a new measure func
     if !text_flags.needs_new_measure_func {
-        let physical_node_size = if text.linebreak_behavior == BreakLineOn::NoWrap {
-            // With `NoWrap` set, no constraints are placed on the width of the text.
-            Vec2::splat(f32::INFINITY)
-        } else {
-            // `scale_factor` is already multiplied by `UiScale`
-            Vec2::new(
-                node.unrounded_size.x * scale_factor,
-                node.unrounded_size.y * scale_factor,
-            )
-        };
+        let TextBounds {
+            physical_node_width,
+            physical_wrap_width,
+            physical_node_size,
+        } = TextBounds::new(text, &node, scale_factor);
 
-        match text_pipeline.queue_text(
+        // The text scale only scales the font sizes, not the node
+        let result = text_pipeline.queue_text(
             fonts,
-            &text.sections,
-            scale_factor,
-            text.justify,
-            text.linebreak_behavior,
-            physical_node_size,
+            default_font,
+            text,
+            TextLayoutOptions {
+                scale_factor: scale_factor * text_scale,
+                window_scale_factor,
+                bounds: physical_node_size,
+                // Lines overflowing the node are truncated even without wrapping
+                max_line_width: physical_wrap_width.unwrap_or(physical_node_width),
+                antialiasing,
+                y_axis_orientation: YAxisOrientation::TopToBottom,
+            },
             font_atlas_sets,
             texture_atlases,
             textures,
             text_settings,
-            YAxisOrientation::TopToBottom,
-        ) {
-            Err(TextError::NoSuchFont) => {
+        );
+        let error = TextRenderError::from_layout(&result, fonts, default_font, &text.sections);
+        TextRenderError::update(commands, entity, render_error, error);
+        match result {
+            Err(TextError::NoSuchFont | TextError::NoSuchImage) => {
                 // There was an error processing the text layout, try again next frame
                 text_flags.needs_recompute = true;
             }
             Err(e @ TextError::FailedToAddGlyph(_)) => {
-                panic!("Fatal error when processing text: {e}.");
+                // The text is laid out again when it changes
+                error!("Failed to lay out text {entity:?}: {e}.");
+                text_flags.needs_recompute = false;
             }
             Ok(mut info) => {
+                if let Some(wrap_width) = physical_wrap_width {
+                    align_in_content_box(&mut info, text.justify, physical_node_width, wrap_width);
+                }
                 info.logical_size.x = scale_value(info.logical_size.x, inverse_scale_factor);
                 info.logical_size.y = scale_value(info.logical_size.y, inverse_scale_factor);
                 *text_layout_info = info;
@@ -224,9 +367,74 @@ fn queue_text(
     }
 }
 
+/// The physical bounds a UI text node is laid out within.
+struct TextBounds {
+    /// The width of the content box of the node
+    physical_node_width: f32,
+    /// The width the text wraps at when set on the [`Text`], instead of the content box width
+    physical_wrap_width: Option<f32>,
+    /// The size passed to the [`TextPipeline`]
+    physical_node_size: Vec2,
+}
+
+impl TextBounds {
+    /// `scale_factor` is already multiplied by `UiScale`.
+    fn new(text: &Text, node: &Node, scale_factor: f32) -> Self {
+        // The text is laid out within the content box of the node, inside its border and padding.
+        let content_size = node.content_size();
+        let physical_node_width = content_size.x * scale_factor;
+        let physical_wrap_width = text
+            .wrap_width
+            .filter(|_| text.linebreak_behavior != BreakLineOn::NoWrap)
+            .map(|wrap_width| wrap_width * scale_factor);
+        let physical_node_size = if text.linebreak_behavior == BreakLineOn::NoWrap {
+            // With `NoWrap` set, no constraints are placed on the width of the text.
+            Vec2::splat(f32::INFINITY)
+        } else {
+            Vec2::new(
+                physical_wrap_width.unwrap_or(physical_node_width),
+                content_size.y * scale_factor,
+            )
+        };
+        Self {
+            physical_node_width,
+            physical_wrap_width,
+            physical_node_size,
+        }
+    }
+}
+
+/// Moves the glyphs, lines and backgrounds of `info`, laid out and justified within `wrap_width`,
+/// to be justified within the content box of the node instead, like the pipeline anchors them.
+fn align_in_content_box(
+    info: &mut TextLayoutInfo,
+    justify: JustifyText,
+    physical_node_width: f32,
+    wrap_width: f32,
+) {
+    let alignment = match justify {
+        JustifyText::Left => 0.0,
+        JustifyText::Center => 0.5,
+        JustifyText::Right => 1.0,
+    };
+    let offset = (physical_node_width * alignment).floor() - (wrap_width * alignment).floor();
+    for glyph in &mut info.glyphs {
+        glyph.position.x += offset;
+    }
+    for line in &mut info.lines {
+        line.rect.min.x += offset;
+        line.rect.max.x += offset;
+    }
+    for background in &mut info.backgrounds {
+        background.rect.min.x += offset;
+        background.rect.max.x += offset;
+    }
+}
+
 /// Updates the layout and size information for a UI text node on changes to the size value of its [`Node`] component,
 /// or when the `needs_recompute` field of [`TextFlags`] is set to true.
 /// This information is computed by the [`TextPipeline`] and then stored in [`TextLayoutInfo`].
+/// Text that fails to render gets a [`TextRenderError`], removed once it is laid out without error.
 ///
 /// ## World Resources
 ///
@@ -234,27 +442,47 @@ fn queue_text(
 /// It does not modify or observe existing ones. The exception is when adding new glyphs to a [`bevy_text::FontAtlas`].
 #[allow(clippy::too_many_arguments)]
 pub fn text_system(
+    mut commands: Commands,
     mut textures: ResMut<Assets<Image>>,
     mut last_scale_factors: Local<EntityHashMap<f32>>,
     fonts: Res<Assets<Font>>,
+    default_font: Res<DefaultFont>,
     camera_query: Query<(Entity, &Camera)>,
     default_ui_camera: DefaultUiCamera,
     text_settings: Res<TextSettings>,
+    text_render_settings: Res<TextRenderSettings>,
+    text_atlas_settings: Res<TextAtlasSettings>,
     ui_scale: Res<UiScale>,
+    text_scale: Res<TextScale>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
     mut font_atlas_sets: ResMut<FontAtlasSets>,
     mut text_pipeline: ResMut<TextPipeline>,
     mut text_query: Query<(
+        Entity,
         Ref<Node>,
         &Text,
         &mut TextLayoutInfo,
         &mut TextFlags,
         Option<&TargetCamera>,
+        Option<&TextRenderError>,
+        Option<&BackgroundColor>,
+        Option<&TextScaleOverride>,
     )>,
 ) {
     let mut scale_factors: EntityHashMap<f32> = EntityHashMap::default();
 
-    for (node, text, text_layout_info, text_flags, camera) in &mut text_query {
+    for (
+        entity,
+        node,
+        text,
+        text_layout_info,
+        text_flags,
+        camera,
+        render_error,
+        background_color,
+        text_scale_override,
+    ) in &mut text_query
+    {
         let Some(camera_entity) = camera.map(TargetCamera::entity).or(default_ui_camera.get())
         else {
             continue;
@@ -272,19 +500,37 @@ pub fn text_system(
         };
         let inverse_scale_factor = scale_factor.recip();
 
+        // Subpixel coverage can only be blended with an opaque background of the text node
+        let antialiasing = if background_color.map_or(false, |color| color.0.alpha() >= 1.0) {
+            text_render_settings.antialiasing
+        } else {
+            text_render_settings.antialiasing.without_subpixel()
+        };
+
         if last_scale_factors.get(&camera_entity) != Some(&scale_factor)
             || node.is_changed()
             || text_flags.needs_recompute
+            || text_layout_info.antialiasing != antialiasing
+            // The font atlases may have been repacked
+            || text_atlas_settings.is_changed()
         {
             queue_text(
+                &mut commands,
+                entity,
+                render_error,
                 &fonts,
+                &default_font,
                 &mut text_pipeline,
                 &mut font_atlas_sets,
                 &mut texture_atlases,
                 &mut textures,
                 &text_settings,
+                antialiasing,
                 scale_factor,
+                // The font size clamp applies after `UiScale`, which `scale_factor` includes
+                scale_factor / ui_scale.0,
                 inverse_scale_factor,
+                text_scale.resolve(text_scale_override),
                 text,
                 node,
                 text_flags,
@@ -294,3 +540,117 @@ pub fn text_system(
     }
     *last_scale_factors = scale_factors;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_asset::AssetId;
+    use bevy_math::Rect;
+    use bevy_sprite::BorderRect;
+    use bevy_text::{LineMetrics, SectionBackground, TextStyle};
+
+    /// The fonts with the default font, used by the default [`TextStyle`].
+    fn fonts() -> Assets<Font> {
+        let mut fonts = Assets::<Font>::default();
+        let font = Font::try_from_bytes(
+            include_bytes!("../../../bevy_text/src/FiraMono-subset.ttf").to_vec(),
+        )
+        .unwrap();
+        fonts.insert(AssetId::default(), font);
+        fonts
+    }
+
+    #[test]
+    fn text_wraps_at_the_content_width() {
+        let fonts = fonts();
+        let text = Text::from_section("hello bevy", TextStyle::default());
+        let measure =
+            TextMeasureInfo::from_text(&text, &fonts, &DefaultFont::default(), 2.0, 2.0).unwrap();
+
+        // A node wide enough for the whole line, but not once its padding is taken away
+        let logical_width = measure.max.x / 2.0;
+        let node = Node {
+            unrounded_size: Vec2::new(logical_width + 10.0, 100.0),
+            content_inset: BorderRect {
+                left: 20.0,
+                right: 5.0,
+                top: 10.0,
+                bottom: 0.0,
+            },
+            ..Default::default()
+        };
+        let bounds = TextBounds::new(&text, &node, 2.0);
+        assert_eq!(bounds.physical_node_width, (logical_width - 15.0) * 2.0);
+        assert_eq!(
+            bounds.physical_node_size,
+            Vec2::new((logical_width - 15.0) * 2.0, 180.0)
+        );
+
+        // So the text wraps onto a second line
+        let size = measure.compute_size(bounds.physical_node_size);
+        assert!(size.x < measure.max.x);
+        assert!((size.y / measure.max.y - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn text_wraps_at_its_wrap_width() {
+        let fonts = fonts();
+        let text = Text::from_section("hello bevy", TextStyle::default());
+        let measure =
+            TextMeasureInfo::from_text(&text, &fonts, &DefaultFont::default(), 2.0, 2.0).unwrap();
+
+        // A wrap width too narrow for the whole line, in a node wide enough for it
+        let logical_width = measure.max.x / 2.0;
+        let text = text.with_wrap_width(logical_width - 10.0);
+        let node = Node {
+            unrounded_size: Vec2::new(logical_width * 2.0, 100.0),
+            ..Default::default()
+        };
+        let bounds = TextBounds::new(&text, &node, 2.0);
+        assert_eq!(bounds.physical_node_width, logical_width * 4.0);
+        assert_eq!(
+            bounds.physical_wrap_width,
+            Some((logical_width - 10.0) * 2.0)
+        );
+        assert_eq!(
+            bounds.physical_node_size,
+            Vec2::new((logical_width - 10.0) * 2.0, 200.0)
+        );
+
+        // So the text wraps onto a second line, whatever the width of the node
+        let size = measure.compute_size(bounds.physical_node_size);
+        assert!(size.x <= (logical_width - 10.0) * 2.0);
+        assert!((size.y / measure.max.y - 2.0).abs() < 0.1);
+
+        // Without soft wrapping, the wrap width is ignored
+        let bounds = TextBounds::new(&text.with_no_wrap(), &node, 2.0);
+        assert_eq!(bounds.physical_wrap_width, None);
+        assert_eq!(bounds.physical_node_size, Vec2::splat(f32::INFINITY));
+    }
+
+    #[test]
+    fn wrapped_text_is_justified_in_the_content_box() {
+        // A line centered within a wrap width of 200, with a highlighted section
+        let line = Rect::new(50., 0., 150., 20.);
+        let mut info = TextLayoutInfo {
+            backgrounds: vec![SectionBackground {
+                section_index: 0,
+                rect: line,
+            }],
+            lines: vec![LineMetrics {
+                baseline: 16.,
+                ascent: 16.,
+                descent: 4.,
+                rect: line,
+                glyphs: 0..0,
+            }],
+            ..Default::default()
+        };
+
+        // Centered within a content box of 400 instead
+        align_in_content_box(&mut info, JustifyText::Center, 400., 200.);
+        let centered = Rect::new(150., 0., 250., 20.);
+        assert_eq!(info.lines[0].rect, centered);
+        assert_eq!(info.backgrounds[0].rect, centered);
+    }
+}
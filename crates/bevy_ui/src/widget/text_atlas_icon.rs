@@ -0,0 +1,138 @@
+use crate::UiImage;
+use bevy_asset::{AssetId, Assets, Handle};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::Component,
+    query::Has,
+    reflect::ReflectComponent,
+    system::{Commands, Query, ResMut},
+};
+use bevy_reflect::Reflect;
+use bevy_render::texture::Image;
+use bevy_sprite::{TextureAtlas, TextureAtlasLayout};
+use bevy_text::{FontAtlasSets, TextLayoutInfo};
+use bevy_utils::warn_once;
+
+/// Packs the [`UiImage`] of the node into the glyph atlas of a text, so that the image and the
+/// glyphs of the text are drawn in the same batch when they're next to each other in the UI.
+///
+/// Once the image is packed, [`pack_text_atlas_icons`] sets the texture of the [`UiImage`] to the
+/// atlas texture, and adds a [`TextureAtlas`] selecting the image in it. The image is packed into
+/// the atlas of the first glyph of the text, and stays in it: it isn't packed again when the text
+/// changes.
+///
+/// Only small images can be packed, see [`TextAtlasIcon::MAX_SIZE`]. Their data has to be kept in
+/// the main world, and their format has to be the one of the glyph atlases,
+/// [`TextureFormat::Rgba8UnormSrgb`](bevy_render::render_resource::TextureFormat::Rgba8UnormSrgb).
+/// Nodes which already have a [`TextureAtlas`] are ignored.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct TextAtlasIcon {
+    /// The text entity whose glyph atlas the image is packed into.
+    pub text: Entity,
+}
+
+impl TextAtlasIcon {
+    /// The largest width and height of the images packed into the glyph atlases, in pixels.
+    ///
+    /// Larger images would quickly fill the atlases, which are 512 pixels wide by default.
+    pub const MAX_SIZE: u32 = 128;
+
+    /// Creates a [`TextAtlasIcon`] packing the image of the node into the glyph atlas of `text`.
+    pub fn new(text: Entity) -> Self {
+        Self { text }
+    }
+}
+
+/// The image of a [`TextAtlasIcon`] node before it was packed into a glyph atlas, and the atlas it
+/// was packed into.
+///
+/// Added by [`pack_text_atlas_icons`], which packs the image again if the text moves to another
+/// atlas, or if the atlases are packed again with a new glyph padding.
+#[derive(Component, Debug, Clone)]
+pub struct PackedTextAtlasIcon {
+    /// The image of the node, before it was packed.
+    pub image: Handle<Image>,
+    /// The texture of the glyph atlas the image was packed into.
+    pub atlas: AssetId<Image>,
+    /// The glyph padding of the atlas when the image was packed into it.
+    pub glyph_padding: u16,
+}
+
+/// Packs the images of the [`TextAtlasIcon`] nodes into the glyph atlases of their texts.
+///
+/// The images which aren't loaded yet, and the texts which have no glyphs yet, are retried in the
+/// next frames.
+#[allow(clippy::type_complexity)]
+pub fn pack_text_atlas_icons(
+    mut commands: Commands,
+    mut icon_query: Query<(
+        Entity,
+        &TextAtlasIcon,
+        &mut UiImage,
+        Has<TextureAtlas>,
+        Option<&PackedTextAtlasIcon>,
+    )>,
+    text_query: Query<&TextLayoutInfo>,
+    mut font_atlas_sets: ResMut<FontAtlasSets>,
+    mut textures: ResMut<Assets<Image>>,
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let glyph_padding = font_atlas_sets.glyph_padding();
+    for (entity, icon, mut image, has_texture_atlas, packed) in &mut icon_query {
+        // The atlases of nodes which already had one are the user's
+        if has_texture_atlas && packed.is_none() {
+            continue;
+        }
+        let Some(glyph) = text_query
+            .get(icon.text)
+            .ok()
+            .and_then(|text_layout_info| text_layout_info.glyphs.first())
+        else {
+            continue;
+        };
+        let atlas = glyph.atlas_info.texture.id();
+        let source = match packed {
+            // Replaced since it was packed, the new image is packed
+            Some(packed) if image.texture.id() != packed.atlas => image.texture.clone(),
+            Some(packed) if packed.atlas == atlas && packed.glyph_padding == glyph_padding => {
+                continue;
+            }
+            Some(packed) => packed.image.clone(),
+            None => image.texture.clone(),
+        };
+        let Some(size) = textures.get(&source).map(Image::size) else {
+            continue;
+        };
+        if size.max_element() > TextAtlasIcon::MAX_SIZE {
+            warn_once!(
+                "An image of {}x{} pixels is too large to be packed into a glyph atlas, the maximum size is {}x{}.",
+                size.x,
+                size.y,
+                TextAtlasIcon::MAX_SIZE,
+                TextAtlasIcon::MAX_SIZE
+            );
+            continue;
+        }
+        let Some(atlas_info) = font_atlas_sets.add_image_to_atlas(
+            atlas,
+            source.id(),
+            &mut texture_atlases,
+            &mut textures,
+        ) else {
+            continue;
+        };
+        image.texture = atlas_info.texture;
+        commands.entity(entity).insert((
+            TextureAtlas {
+                layout: atlas_info.texture_atlas,
+                index: atlas_info.glyph_index,
+            },
+            PackedTextAtlasIcon {
+                image: source,
+                atlas,
+                glyph_padding,
+            },
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+use bevy_ecs::{prelude::Component, query::With, reflect::ReflectComponent, system::Query};
+use bevy_input::action::{ActionInput, InputAction};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::view::Visibility;
+
+use crate::Node;
+
+/// Shows and hides the [`Visibility`] of the UI node when its [`InputAction`] is just pressed.
+///
+/// This only applies to UI nodes, such as the root node of a debug panel: it has no effect on
+/// entities without a [`Node`].
+///
+/// Hidden entities are shown with [`Visibility::Inherited`], and shown entities are hidden with
+/// [`Visibility::Hidden`]. This only changes the visibility of the entity, so it can be combined
+/// with components managing its lifetime, such as `StateScoped`.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::{action::InputAction, gamepad::GamepadButtonType, keyboard::KeyCode};
+/// # use bevy_ui::{node_bundles::NodeBundle, widget::ToggleVisibility};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         ToggleVisibility::new(InputAction::new(KeyCode::F1).or(GamepadButtonType::Select)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ToggleVisibility {
+    /// The action toggling the visibility.
+    pub action: InputAction,
+}
+
+impl ToggleVisibility {
+    /// Creates a [`ToggleVisibility`] toggled by `action`.
+    pub fn new(action: impl Into<InputAction>) -> Self {
+        Self {
+            action: action.into(),
+        }
+    }
+}
+
+/// Flips the [`Visibility`] of the UI nodes whose [`ToggleVisibility`] action was just pressed.
+pub fn toggle_visibility_system(
+    input: ActionInput,
+    mut query: Query<(&ToggleVisibility, &mut Visibility), With<Node>>,
+) {
+    for (toggle, mut visibility) in &mut query {
+        if input.just_pressed(&toggle.action) {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                Visibility::Inherited | Visibility::Visible => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+
+    #[test]
+    fn toggles_on_just_pressed() {
+        let mut world = World::new();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        let entity = world
+            .spawn((
+                Node::default(),
+                ToggleVisibility::new(KeyCode::KeyF),
+                Visibility::Visible,
+            ))
+            .id();
+        // Entities that aren't UI nodes are left alone
+        let sprite = world
+            .spawn((ToggleVisibility::new(KeyCode::KeyF), Visibility::Visible))
+            .id();
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyF);
+        world.run_system_once(toggle_visibility_system);
+        assert_eq!(world.get::<Visibility>(entity), Some(&Visibility::Hidden));
+        assert_eq!(world.get::<Visibility>(sprite), Some(&Visibility::Visible));
+
+        // Holding the key doesn't toggle the visibility again
+        world.resource_mut::<ButtonInput<KeyCode>>().clear();
+        world.run_system_once(toggle_visibility_system);
+        assert_eq!(world.get::<Visibility>(entity), Some(&Visibility::Hidden));
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::KeyF);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyF);
+        world.run_system_once(toggle_visibility_system);
+        assert_eq!(
+            world.get::<Visibility>(entity),
+            Some(&Visibility::Inherited)
+        );
+    }
+}
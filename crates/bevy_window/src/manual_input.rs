@@ -0,0 +1,242 @@
+use bevy_ecs::{
+    entity::Entity,
+    event::EventWriter,
+    query::With,
+    system::{Query, SystemParam},
+};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey},
+    mouse::{MouseButton, MouseButtonInput},
+    ButtonState,
+};
+use bevy_math::Vec2;
+
+use crate::{CursorMoved, PrimaryWindow, Window};
+
+/// A [`SystemParam`] sending synthetic input to the primary window, such as to script
+/// interactions in tests.
+///
+/// The input goes through the same events as the input of the windowing backend, such as
+/// [`KeyboardInput`] and [`CursorMoved`], so it's indistinguishable from real input downstream:
+/// [`ButtonInput`](bevy_input::ButtonInput) and UI interactions are updated the next time the
+/// `PreUpdate` input systems run.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::{keyboard::KeyCode, mouse::MouseButton};
+/// # use bevy_math::Vec2;
+/// # use bevy_window::ManualInput;
+/// fn click_start_button(mut input: ManualInput) {
+///     input.click_at(Vec2::new(400.0, 300.0), MouseButton::Left);
+///     input.tap(KeyCode::Space);
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct ManualInput<'w, 's> {
+    primary_window: Query<'w, 's, (Entity, &'static mut Window), With<PrimaryWindow>>,
+    keyboard: EventWriter<'w, KeyboardInput>,
+    mouse_buttons: EventWriter<'w, MouseButtonInput>,
+    cursor_moved: EventWriter<'w, CursorMoved>,
+}
+
+impl ManualInput<'_, '_> {
+    /// The primary window, or [`Entity::PLACEHOLDER`] if there is none.
+    fn window(&self) -> Entity {
+        self.primary_window
+            .get_single()
+            .map_or(Entity::PLACEHOLDER, |(window, _)| window)
+    }
+
+    /// Sends a [`KeyboardInput`] for `key_code`, with the logical key of a US keyboard layout.
+    pub fn send_key(&mut self, key_code: KeyCode, state: ButtonState) {
+        let window = self.window();
+        self.keyboard.send(KeyboardInput {
+            key_code,
+            logical_key: logical_key(key_code),
+            state,
+            window,
+        });
+    }
+
+    /// Presses `key_code`.
+    pub fn press(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Pressed);
+    }
+
+    /// Releases `key_code`.
+    pub fn release(&mut self, key_code: KeyCode) {
+        self.send_key(key_code, ButtonState::Released);
+    }
+
+    /// Presses and releases `key_code`, which is both just pressed and just released in the next
+    /// update.
+    pub fn tap(&mut self, key_code: KeyCode) {
+        self.press(key_code);
+        self.release(key_code);
+    }
+
+    /// Sends a [`MouseButtonInput`] for `button`.
+    pub fn send_mouse_button(&mut self, button: MouseButton, state: ButtonState) {
+        let window = self.window();
+        self.mouse_buttons.send(MouseButtonInput {
+            button,
+            state,
+            window,
+        });
+    }
+
+    /// Presses the mouse `button`.
+    pub fn press_mouse(&mut self, button: MouseButton) {
+        self.send_mouse_button(button, ButtonState::Pressed);
+    }
+
+    /// Releases the mouse `button`.
+    pub fn release_mouse(&mut self, button: MouseButton) {
+        self.send_mouse_button(button, ButtonState::Released);
+    }
+
+    /// Presses and releases the mouse `button`.
+    pub fn click(&mut self, button: MouseButton) {
+        self.press_mouse(button);
+        self.release_mouse(button);
+    }
+
+    /// Sends a [`CursorMoved`] event to `position` in the primary window, in logical pixels.
+    ///
+    /// [`Window::cursor_position`] is set too, with [`Window::set_synthetic_cursor_position`], so
+    /// that the cursor of the OS isn't warped by the windowing backend.
+    pub fn move_cursor(&mut self, position: Vec2) {
+        let Ok((window, mut primary_window)) = self.primary_window.get_single_mut() else {
+            return;
+        };
+        let delta = primary_window
+            .cursor_position()
+            .map(|last_position| position - last_position);
+        primary_window.set_synthetic_cursor_position(Some(position));
+        self.cursor_moved.send(CursorMoved {
+            window,
+            position,
+            delta,
+        });
+    }
+
+    /// Moves the cursor to `position`, in logical pixels, and clicks the mouse `button` there.
+    pub fn click_at(&mut self, position: Vec2, button: MouseButton) {
+        self.move_cursor(position);
+        self.click(button);
+    }
+}
+
+/// The logical key of `key_code` on a US keyboard layout, for the keys that are the same on most
+/// layouts.
+fn logical_key(key_code: KeyCode) -> Key {
+    match key_code {
+        KeyCode::Space => Key::Space,
+        KeyCode::Enter | KeyCode::NumpadEnter => Key::Enter,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Escape => Key::Escape,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::ArrowUp => Key::ArrowUp,
+        KeyCode::ArrowDown => Key::ArrowDown,
+        KeyCode::ArrowLeft => Key::ArrowLeft,
+        KeyCode::ArrowRight => Key::ArrowRight,
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => Key::Shift,
+        KeyCode::ControlLeft | KeyCode::ControlRight => Key::Control,
+        KeyCode::AltLeft | KeyCode::AltRight => Key::Alt,
+        KeyCode::SuperLeft | KeyCode::SuperRight => Key::Super,
+        _ => {
+            // Letters and digits, such as `KeyA` and `Digit1`
+            let name = format!("{key_code:?}");
+            match name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+            {
+                Some(character) if character.len() == 1 => {
+                    Key::Character(character.to_lowercase().into())
+                }
+                _ => Key::Unidentified(NativeKey::Unidentified),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RawHandleWrapper, WindowWrapper};
+    use bevy_app::App;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_input::{ButtonInput, InputPlugin};
+    use raw_window_handle::{
+        DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawWindowHandle,
+        WebWindowHandle, WindowHandle,
+    };
+
+    #[test]
+    fn manual_input_updates_button_input() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin).add_event::<CursorMoved>();
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+
+        app.world_mut().run_system_once(|mut input: ManualInput| {
+            input.tap(KeyCode::KeyA);
+            input.click_at(Vec2::new(10.0, 20.0), MouseButton::Left);
+        });
+        app.update();
+
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.just_pressed(KeyCode::KeyA) && keys.just_released(KeyCode::KeyA));
+        let mouse = app.world().resource::<ButtonInput<MouseButton>>();
+        assert!(mouse.just_pressed(MouseButton::Left));
+
+        let mut windows = app.world_mut().query::<&Window>();
+        assert_eq!(
+            windows.single(app.world()).cursor_position(),
+            Some(Vec2::new(10.0, 20.0))
+        );
+        assert_eq!(
+            logical_key(KeyCode::KeyA),
+            Key::Character("a".into()),
+            "Letters have the logical key of a US layout"
+        );
+    }
+
+    /// A window of the windowing backend, with a web canvas handle.
+    struct BackendWindow;
+
+    impl HasWindowHandle for BackendWindow {
+        fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+            // SAFETY: The handle is never used to reach an actual window.
+            Ok(unsafe { WindowHandle::borrow_raw(RawWindowHandle::Web(WebWindowHandle::new(1))) })
+        }
+    }
+
+    impl HasDisplayHandle for BackendWindow {
+        fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+            Ok(DisplayHandle::web())
+        }
+    }
+
+    #[test]
+    fn synthetic_cursor_moves_backend_windows() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin).add_event::<CursorMoved>();
+        let handle = RawHandleWrapper::new(&WindowWrapper::new(BackendWindow)).unwrap();
+        app.world_mut()
+            .spawn((Window::default(), PrimaryWindow, handle));
+
+        app.world_mut().run_system_once(|mut input: ManualInput| {
+            input.move_cursor(Vec2::new(10.0, 20.0));
+        });
+        app.update();
+
+        let mut windows = app.world_mut().query::<&Window>();
+        let window = windows.single(app.world());
+        assert_eq!(window.cursor_position(), Some(Vec2::new(10.0, 20.0)));
+        assert!(
+            window.internal.is_cursor_position_synthetic(),
+            "The cursor of the OS isn't warped to a synthetic position"
+        );
+    }
+}
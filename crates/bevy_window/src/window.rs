@@ -457,6 +457,7 @@ impl Window {
     pub fn set_cursor_position(&mut self, position: Option<Vec2>) {
         self.internal.physical_cursor_position =
             position.map(|p| p.as_dvec2() * self.scale_factor() as f64);
+        self.internal.synthetic_cursor_position = false;
     }
 
     /// Set the cursor position in this window in physical pixels.
@@ -464,6 +465,16 @@ impl Window {
     /// See [`WindowResolution`] for an explanation about logical/physical sizes.
     pub fn set_physical_cursor_position(&mut self, position: Option<DVec2>) {
         self.internal.physical_cursor_position = position;
+        self.internal.synthetic_cursor_position = false;
+    }
+
+    /// Set the cursor position in this window in logical pixels, without moving the cursor of the OS.
+    ///
+    /// This is used for synthetic input, such as [`ManualInput`](crate::ManualInput): systems reading
+    /// [`Window::cursor_position`] see the new position until the cursor of the OS moves again.
+    pub fn set_synthetic_cursor_position(&mut self, position: Option<Vec2>) {
+        self.set_cursor_position(position);
+        self.internal.synthetic_cursor_position = true;
     }
 }
 
@@ -833,6 +844,10 @@ impl WindowResolution {
     ///
     /// This can change the logical and physical sizes if the resulting physical
     /// size is not within the limits.
+    ///
+    /// A [`WindowScaleFactorChanged`](crate::WindowScaleFactorChanged) event is sent when the
+    /// effective scale factor changes, so that the UI is laid out and its text rasterized again at
+    /// the new scale factor. Pass `None` to go back to the scale factor of the backend.
     #[inline]
     pub fn set_scale_factor_override(&mut self, scale_factor_override: Option<f32>) {
         self.scale_factor_override = scale_factor_override;
@@ -910,6 +925,8 @@ pub struct InternalWindowState {
     maximize_request: Option<bool>,
     /// Unscaled cursor position.
     physical_cursor_position: Option<DVec2>,
+    /// If this is true then the cursor position was set by synthetic input, and the cursor of the OS isn't moved to it.
+    synthetic_cursor_position: bool,
 }
 
 impl InternalWindowState {
@@ -922,6 +939,12 @@ impl InternalWindowState {
     pub fn take_minimize_request(&mut self) -> Option<bool> {
         self.minimize_request.take()
     }
+
+    /// Whether the cursor position was set by [`Window::set_synthetic_cursor_position`]. Window backends
+    /// shouldn't move the cursor of the OS to a synthetic position.
+    pub fn is_cursor_position_synthetic(&self) -> bool {
+        self.synthetic_cursor_position
+    }
 }
 
 /// References a screen monitor.
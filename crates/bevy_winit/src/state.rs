@@ -584,6 +584,11 @@ impl<T: Event> ApplicationHandler<T> for WinitAppRunnerState<T> {
     }
 
     fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        // Run the `Shutdown` schedule while the windows and non-send resources still exist
+        if let Some(app_exit) = self.app_exit.take() {
+            self.app_exit = Some(self.app.shutdown(app_exit));
+        }
+
         let world = self.world_mut();
         world.clear_all();
     }
@@ -785,6 +790,8 @@ pub(crate) fn react_to_scale_factor_change(
     window_backend_scale_factor_changed: &mut EventWriter<WindowBackendScaleFactorChanged>,
     window_scale_factor_changed: &mut EventWriter<WindowScaleFactorChanged>,
 ) {
+    // Read before updating the base scale factor, so that a change is detected
+    let prior_factor = window.resolution.scale_factor();
     window.resolution.set_scale_factor(scale_factor as f32);
 
     window_backend_scale_factor_changed.send(WindowBackendScaleFactorChanged {
@@ -792,7 +799,6 @@ pub(crate) fn react_to_scale_factor_change(
         scale_factor,
     });
 
-    let prior_factor = window.resolution.scale_factor();
     let scale_factor_override = window.resolution.scale_factor_override();
 
     if scale_factor_override.is_none() && !relative_eq!(scale_factor as f32, prior_factor) {
@@ -9,7 +9,7 @@ use bevy_ecs::{
 use bevy_utils::tracing::{error, info, warn};
 use bevy_window::{
     ClosingWindow, RawHandleWrapper, Window, WindowClosed, WindowClosing, WindowCreated,
-    WindowMode, WindowResized, WindowWrapper,
+    WindowMode, WindowResized, WindowScaleFactorChanged, WindowWrapper,
 };
 
 use winit::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
@@ -179,6 +179,7 @@ pub(crate) fn changed_windows(
     mut changed_windows: Query<(Entity, &mut Window, &mut CachedWindow), Changed<Window>>,
     winit_windows: NonSendMut<WinitWindows>,
     mut window_resized: EventWriter<WindowResized>,
+    mut window_scale_factor_changed: EventWriter<WindowScaleFactorChanged>,
 ) {
     for (entity, mut window, mut cache) in &mut changed_windows {
         let Some(winit_window) = winit_windows.get_window(entity) else {
@@ -260,6 +261,16 @@ pub(crate) fn changed_windows(
                 }
             }
 
+            // Setting or clearing `scale_factor_override` changes the effective scale factor
+            // without `winit` noticing, so the cameras, UI layout and text are told here. This
+            // matters when the physical size stays the same, such as when the window is maximized.
+            if scale_factor != cached_scale_factor {
+                window_scale_factor_changed.send(WindowScaleFactorChanged {
+                    window: entity,
+                    scale_factor: scale_factor as f64,
+                });
+            }
+
             if physical_size != cached_physical_size {
                 if let Some(new_physical_size) = winit_window.request_inner_size(physical_size) {
                     react_to_resize(entity, &mut window, new_physical_size, &mut window_resized);
@@ -267,7 +278,9 @@ pub(crate) fn changed_windows(
             }
         }
 
-        if window.physical_cursor_position() != cache.window.physical_cursor_position() {
+        if window.physical_cursor_position() != cache.window.physical_cursor_position()
+            && !window.internal.is_cursor_position_synthetic()
+        {
             if let Some(physical_position) = window.physical_cursor_position() {
                 let position = PhysicalPosition::new(physical_position.x, physical_position.y);
 
@@ -7,8 +7,7 @@
 
 use bevy::{
     color::palettes::basic::YELLOW,
-    core_pipeline::core_2d::Transparent2d,
-    math::FloatOrd,
+    core_pipeline::core_2d::{Sort2dKey, Transparent2d},
     prelude::*,
     render::{
         mesh::{GpuMesh, Indices, MeshVertexAttribute},
@@ -393,7 +392,7 @@ pub fn queue_colored_mesh2d(
                     pipeline: pipeline_id,
                     // The 2d render items are sorted according to their z value before rendering,
                     // in order to get correct transparency
-                    sort_key: FloatOrd(mesh_z),
+                    sort_key: Sort2dKey::from_depth(mesh_z),
                     // This material is not batched
                     batch_range: 0..1,
                     extra_index: PhaseItemExtraIndex::NONE,
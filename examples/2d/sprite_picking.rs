@@ -0,0 +1,123 @@
+//! Picks sprites and 2D text with the pointer, using the [`SpritePickingPlugin`] backend.
+//!
+//! Hover the sprites to tint them, and click them to print their name. The birds are only hit
+//! where their image is opaque, and the last drawn sprite is picked first.
+
+use bevy::{
+    picking::{
+        events::{Click, Out, Over, Pointer},
+        DefaultPickingPlugins, Pickable,
+    },
+    prelude::*,
+    sprite::{Anchor, SpritePickingPlugin},
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultPickingPlugins, SpritePickingPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+const HOVERED: Color = Color::srgb(1.0, 0.6, 0.6);
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2dBundle::default());
+    let bird = asset_server.load("branding/bevy_bird_dark.png");
+
+    // Overlapping birds, the one with the highest `Z` is drawn over the other one
+    for (name, translation, rotation) in [
+        ("Back bird", Vec3::new(-150.0, 0.0, 0.0), 0.0),
+        ("Front bird", Vec3::new(-50.0, -50.0, 1.0), 0.4),
+    ] {
+        commands
+            .spawn((
+                Name::new(name),
+                SpriteBundle {
+                    texture: bird.clone(),
+                    transform: Transform::from_translation(translation)
+                        .with_rotation(Quat::from_rotation_z(rotation))
+                        .with_scale(Vec3::splat(0.5)),
+                    ..default()
+                },
+            ))
+            .observe(tint_sprite_on::<Over>(HOVERED))
+            .observe(tint_sprite_on::<Out>(Color::WHITE))
+            .observe(print_click);
+    }
+
+    // A sprite anchored by its bottom left corner, with a custom size
+    commands
+        .spawn((
+            Name::new("Anchored square"),
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::splat(120.0)),
+                    anchor: Anchor::BottomLeft,
+                    ..default()
+                },
+                transform: Transform::from_xyz(150.0, -100.0, 0.0),
+                ..default()
+            },
+        ))
+        .observe(tint_sprite_on::<Over>(HOVERED))
+        .observe(tint_sprite_on::<Out>(Color::WHITE))
+        .observe(print_click);
+
+    // A sprite over the square that the pointer goes through
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.2, 0.4, 1.0, 0.5),
+                custom_size: Some(Vec2::splat(60.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(210.0, -40.0, 1.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+    ));
+
+    // Text is hit anywhere in its layout bounds
+    commands
+        .spawn((
+            Name::new("Label"),
+            Text2dBundle {
+                text: Text::from_section("Click me", TextStyle::default()),
+                transform: Transform::from_xyz(150.0, 150.0, 0.0),
+                ..default()
+            },
+        ))
+        .observe(
+            |trigger: Trigger<Pointer<Over>>, mut texts: Query<&mut Text>| {
+                if let Ok(mut text) = texts.get_mut(trigger.entity()) {
+                    text.sections[0].style.color = HOVERED;
+                }
+            },
+        )
+        .observe(
+            |trigger: Trigger<Pointer<Out>>, mut texts: Query<&mut Text>| {
+                if let Ok(mut text) = texts.get_mut(trigger.entity()) {
+                    text.sections[0].style.color = Color::WHITE;
+                }
+            },
+        )
+        .observe(print_click);
+}
+
+/// Returns an observer tinting the sprite it targets with `color` on a `Pointer<E>`.
+fn tint_sprite_on<E: std::fmt::Debug + Clone + Send + Sync + 'static>(
+    color: Color,
+) -> impl Fn(Trigger<Pointer<E>>, Query<&mut Sprite>) {
+    move |trigger, mut sprites| {
+        if let Ok(mut sprite) = sprites.get_mut(trigger.entity()) {
+            sprite.color = color;
+        }
+    }
+}
+
+fn print_click(trigger: Trigger<Pointer<Click>>, names: Query<&Name>) {
+    if let Ok(name) = names.get(trigger.entity()) {
+        info!("Clicked {name} with {:?}", trigger.event().event.button);
+    }
+}
@@ -0,0 +1,109 @@
+//! Create and play an animation defined by code that operates on the fields of UI components.
+
+use bevy::animation::{AnimatedField, AnimationTarget, AnimationTargetId};
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut animations: ResMut<Assets<AnimationClip>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    // UI camera
+    commands.spawn(Camera2dBundle::default());
+
+    let panel = Name::new("panel");
+    let panel_animation_target_id = AnimationTargetId::from_name(&panel);
+
+    // Creating the animation
+    let mut animation = AnimationClip::default();
+    // The panel slides in from the left, waits, and slides out.
+    // Any reflected field of a component can be animated, here `Style::left`.
+    animation.add_curve_to_target(
+        panel_animation_target_id,
+        VariableCurve {
+            keyframe_timestamps: vec![0.0, 1.0, 2.5, 3.5],
+            keyframes: Keyframes::Field(
+                AnimatedField::new::<Style>(
+                    "left",
+                    vec![
+                        Val::Px(-300.0),
+                        Val::Px(40.0),
+                        Val::Px(40.0),
+                        Val::Px(-300.0),
+                    ],
+                )
+                // The type of the keyframes is checked against the type of the field
+                .expect("`Style::left` is a `Val`"),
+            ),
+            interpolation: Interpolation::Linear,
+        },
+    );
+    // Its background fades in while it slides, and turns red before leaving.
+    // `BackgroundColor` is a tuple struct, so its color is its field `.0`.
+    animation.add_curve_to_target(
+        panel_animation_target_id,
+        VariableCurve {
+            keyframe_timestamps: vec![0.0, 1.0, 2.5, 3.5],
+            keyframes: Keyframes::Field(
+                AnimatedField::new::<BackgroundColor>(
+                    ".0",
+                    vec![
+                        Color::srgba(0.2, 0.3, 0.8, 0.0),
+                        Color::srgba(0.2, 0.3, 0.8, 1.0),
+                        Color::srgba(0.8, 0.2, 0.2, 1.0),
+                        Color::srgba(0.8, 0.2, 0.2, 0.0),
+                    ],
+                )
+                .expect("`BackgroundColor.0` is a `Color`"),
+            ),
+            interpolation: Interpolation::Linear,
+        },
+    );
+
+    // Create the animation graph
+    let (graph, animation_index) = AnimationGraph::from_clip(animations.add(animation));
+
+    // Create the animation player, and set it to repeat
+    let mut player = AnimationPlayer::default();
+    player.play(animation_index).repeat();
+
+    // The animated panel, which is its own animation player
+    let mut panel_entity = commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(-300.0),
+                top: Val::Px(40.0),
+                width: Val::Px(300.0),
+                height: Val::Px(200.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        },
+        panel,
+        graphs.add(graph),
+        player,
+    ));
+    panel_entity.insert(AnimationTarget {
+        id: panel_animation_target_id,
+        player: panel_entity.id(),
+    });
+    panel_entity.with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            "Animated panel",
+            TextStyle {
+                font_size: 30.0,
+                ..default()
+            },
+        ));
+    });
+}
@@ -4,7 +4,7 @@
 //! playing animations by clicking and dragging left or right within the nodes.
 
 #[cfg(not(target_arch = "wasm32"))]
-use std::{fs::File, path::Path};
+use std::path::Path;
 
 use bevy::{
     animation::animate_targets,
@@ -21,8 +21,6 @@ use argh::FromArgs;
 use bevy::asset::io::file::FileAssetReader;
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::tasks::IoTaskPool;
-#[cfg(not(target_arch = "wasm32"))]
-use ron::ser::PrettyConfig;
 
 /// Where to find the serialized animation graph.
 static ANIMATION_GRAPH_PATH: &str = "animation_graphs/Fox.animgraph.ron";
@@ -159,21 +157,21 @@ fn setup_assets_programmatically(
     // Create the nodes.
     let mut animation_graph = AnimationGraph::new();
     let blend_node = animation_graph.add_blend(0.5, animation_graph.root);
-    animation_graph.add_clip(
-        asset_server.load(GltfAssetLabel::Animation(0).from_asset("models/animated/Fox.glb")),
-        1.0,
-        animation_graph.root,
-    );
-    animation_graph.add_clip(
-        asset_server.load(GltfAssetLabel::Animation(1).from_asset("models/animated/Fox.glb")),
-        1.0,
-        blend_node,
-    );
-    animation_graph.add_clip(
-        asset_server.load(GltfAssetLabel::Animation(2).from_asset("models/animated/Fox.glb")),
-        1.0,
-        blend_node,
-    );
+    // The names identify the nodes when the saved graph is edited and
+    // hot-reloaded, so the fox keeps playing them.
+    for (name, animation, parent) in [
+        ("Idle", 0, animation_graph.root),
+        ("Walk", 1, blend_node),
+        ("Run", 2, blend_node),
+    ] {
+        let node = animation_graph.add_clip(
+            asset_server
+                .load(GltfAssetLabel::Animation(animation).from_asset("models/animated/Fox.glb")),
+            1.0,
+            parent,
+        );
+        animation_graph[node].name = Some(Name::new(name));
+    }
 
     // If asked to save, do so.
     #[cfg(not(target_arch = "wasm32"))]
@@ -182,17 +180,12 @@ fn setup_assets_programmatically(
 
         IoTaskPool::get()
             .spawn(async move {
-                let mut animation_graph_writer = File::create(Path::join(
-                    &FileAssetReader::get_base_path(),
-                    Path::join(Path::new("assets"), Path::new(ANIMATION_GRAPH_PATH)),
-                ))
-                .expect("Failed to open the animation graph asset");
-                ron::ser::to_writer_pretty(
-                    &mut animation_graph_writer,
-                    &animation_graph,
-                    PrettyConfig::default(),
-                )
-                .expect("Failed to serialize the animation graph");
+                animation_graph
+                    .save_to_path(Path::join(
+                        &FileAssetReader::get_base_path(),
+                        Path::join(Path::new("assets"), Path::new(ANIMATION_GRAPH_PATH)),
+                    ))
+                    .expect("Failed to save the animation graph");
             })
             .detach();
     }
@@ -22,11 +22,12 @@ fn my_runner(mut app: App) -> AppExit {
         app.update();
 
         if let Some(exit) = app.should_exit() {
-            return exit;
+            // Runners run the `Shutdown` schedule once they stop updating the app
+            return app.shutdown(exit);
         }
     }
 
-    AppExit::Success
+    app.shutdown(AppExit::Success)
 }
 
 fn print_system(input: Res<Input>) {
@@ -39,11 +40,16 @@ fn exit_system(input: Res<Input>, mut exit_event: EventWriter<AppExit>) {
     }
 }
 
+fn goodbye_system() {
+    println!("Goodbye!");
+}
+
 // AppExit implements `Termination` so we can return it from main.
 fn main() -> AppExit {
     App::new()
         .insert_resource(Input(String::new()))
         .set_runner(my_runner)
         .add_systems(Update, (print_system, exit_system))
+        .add_systems(Shutdown, goodbye_system)
         .run()
 }
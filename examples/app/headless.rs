@@ -7,6 +7,9 @@
 //! bevy = { version = "*", default-features = false }
 //! # replace "*" with the most recent version of bevy
 //! ```
+//!
+//! For a game server, `ServerPlugins::with_tick_rate` adds logging, diagnostics and asset
+//! loading on top of these, runs `FixedUpdate` once per tick, and exits gracefully on Ctrl+C.
 
 use bevy::{app::ScheduleRunnerPlugin, prelude::*, utils::Duration};
 
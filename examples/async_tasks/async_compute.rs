@@ -1,5 +1,8 @@
 //! This example shows how to use the ECS and the [`AsyncComputeTaskPool`]
 //! to spawn, poll, and complete tasks across systems and system ticks.
+//!
+//! When the output of a task only needs to be inserted on an entity, sent as an event or
+//! triggered, `commands.spawn_task(future)` and `app.handle_tasks::<T>()` do the polling.
 
 use bevy::{
     ecs::system::SystemState,
@@ -0,0 +1,78 @@
+//! Graphs the frame rate with a diagnostic graph, next to its current value.
+//!
+//! Hover the graph to read the frame rate of past frames.
+
+use bevy::{
+    dev_tools::diagnostic_graph::{DiagnosticGraph, DiagnosticGraphPlugin},
+    diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            FrameTimeDiagnosticsPlugin,
+            DiagnosticGraphPlugin,
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_fps_text)
+        .run();
+}
+
+#[derive(Component)]
+struct FpsText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            // The graph of the last 120 frames fills its node
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(360.0),
+                        height: Val::Px(120.0),
+                        ..default()
+                    },
+                    background_color: Color::srgba(0.1, 0.1, 0.1, 0.8).into(),
+                    ..default()
+                },
+                DiagnosticGraph::new(FrameTimeDiagnosticsPlugin::FPS, 120),
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 40.0,
+                        ..default()
+                    },
+                ),
+                FpsText,
+            ));
+        });
+}
+
+fn update_fps_text(diagnostics: Res<DiagnosticsStore>, mut texts: Query<&mut Text, With<FpsText>>) {
+    let Some(fps) = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.value())
+    else {
+        return;
+    };
+    for mut text in &mut texts {
+        text.sections[0].value = format!("{fps:.0} FPS");
+    }
+}
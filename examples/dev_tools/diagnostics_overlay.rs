@@ -0,0 +1,69 @@
+//! Showcase how to use and configure the diagnostics overlay.
+
+use bevy::{
+    dev_tools::diagnostics_overlay::{
+        DiagnosticsOverlayConfig, DiagnosticsOverlayPlugin, OverlayCorner,
+    },
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            DiagnosticsOverlayPlugin {
+                config: DiagnosticsOverlayConfig {
+                    // Show the overlay in the top right corner
+                    corner: OverlayCorner::TopRight,
+                    // Hold the left control key and press F12 to show or hide the overlay
+                    toggle_chord: vec![KeyCode::ControlLeft, KeyCode::F12],
+                    ..default()
+                },
+            },
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_overlay)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    // We need to spawn a camera (2d or 3d) to see the overlay
+    commands.spawn(Camera2dBundle::default());
+
+    // Instruction text
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|c| {
+            c.spawn(TextBundle::from_section(
+                concat!(
+                    "Press Left Control + F12 to toggle the overlay.\n",
+                    "Press Space to move the overlay to the next corner."
+                ),
+                TextStyle {
+                    font_size: 25.0,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn move_overlay(input: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<DiagnosticsOverlayConfig>) {
+    if input.just_pressed(KeyCode::Space) {
+        // Changing resource will affect overlay
+        overlay.corner = match overlay.corner {
+            OverlayCorner::TopLeft => OverlayCorner::TopRight,
+            OverlayCorner::TopRight => OverlayCorner::BottomRight,
+            OverlayCorner::BottomRight => OverlayCorner::BottomLeft,
+            OverlayCorner::BottomLeft => OverlayCorner::TopLeft,
+        };
+    }
+}
@@ -17,6 +17,8 @@ fn main() {
             // bevy::diagnostic::EntityCountDiagnosticsPlugin::default(),
             // Uncomment this to add an asset count diagnostics:
             // bevy::asset::diagnostic::AssetCountDiagnosticsPlugin::<Texture>::default(),
+            // Uncomment this to add task pool queue depth and latency diagnostics:
+            // bevy::diagnostic::TaskPoolDiagnosticsPlugin,
             // Uncomment this to add system info diagnostics:
             // bevy::diagnostic::SystemInformationDiagnosticsPlugin::default()
         ))
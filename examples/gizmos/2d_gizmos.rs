@@ -69,6 +69,15 @@ fn draw_example_collection(
     gizmos.rect_2d(Vec2::ZERO, 0., Vec2::splat(650.), BLACK);
 
     gizmos.cross_2d(Vec2::new(-160., 120.), 0., 12., FUCHSIA);
+    gizmos.text_2d(
+        Vec2::new(-160., 145.),
+        "waypoint",
+        TextStyle {
+            font_size: 16.,
+            color: FUCHSIA.into(),
+            ..default()
+        },
+    );
 
     my_gizmos
         .rounded_rect_2d(Vec2::ZERO, 0., Vec2::splat(630.), BLACK)
@@ -88,6 +97,14 @@ fn draw_example_collection(
     // Arcs default resolution is linearly interpolated between
     // 1 and 32, using the arc length as scalar.
     my_gizmos.arc_2d(Vec2::ZERO, sin / 10., PI / 2., 310., ORANGE_RED);
+    // Filled gizmos take the alpha of their color into account.
+    my_gizmos.arc_2d_filled(
+        Vec2::ZERO,
+        sin / 10.,
+        PI / 2.,
+        310.,
+        ORANGE_RED.with_alpha(0.2),
+    );
 
     gizmos.arrow_2d(
         Vec2::ZERO,
@@ -1,6 +1,6 @@
 //! This example demonstrates Bevy's immediate mode drawing API intended for visual debugging.
 
-use std::f32::consts::PI;
+use std::{f32::consts::PI, time::Duration};
 
 use bevy::{color::palettes::css::*, prelude::*};
 
@@ -10,7 +10,15 @@ fn main() {
         .init_gizmo_group::<MyRoundGizmos>()
         .add_systems(Startup, setup)
         .add_systems(Update, rotate_camera)
-        .add_systems(Update, (draw_example_collection, update_config))
+        .add_systems(
+            Update,
+            (
+                draw_example_collection,
+                draw_labels,
+                draw_retained_rays,
+                update_config,
+            ),
+        )
         .run();
 }
 
@@ -18,6 +26,10 @@ fn main() {
 #[derive(Default, Reflect, GizmoConfigGroup)]
 struct MyRoundGizmos {}
 
+// A label drawn above an entity with text gizmos
+#[derive(Component)]
+struct EntityLabel(String);
+
 fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -34,12 +46,32 @@ fn setup(
         ..default()
     });
     // cube
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(1.0, 1.0, 1.0)),
+            material: materials.add(Color::srgb(0.8, 0.7, 0.6)),
+            transform: Transform::from_xyz(0.0, 0.5, 0.0),
+            ..default()
+        },
+        EntityLabel("cube".to_string()),
+    ));
+    // wall, hiding the labels behind it when they are occluded
     commands.spawn(PbrBundle {
-        mesh: meshes.add(Cuboid::new(1.0, 1.0, 1.0)),
-        material: materials.add(Color::srgb(0.8, 0.7, 0.6)),
-        transform: Transform::from_xyz(0.0, 0.5, 0.0),
+        mesh: meshes.add(Cuboid::new(3.0, 2.0, 0.2)),
+        material: materials.add(Color::srgb(0.5, 0.5, 0.6)),
+        transform: Transform::from_xyz(0.0, 1.0, -1.4),
         ..default()
     });
+    // beacon behind the wall
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Sphere::new(0.3)),
+            material: materials.add(Color::srgb(0.9, 0.3, 0.2)),
+            transform: Transform::from_xyz(0.0, 0.3, -2.2),
+            ..default()
+        },
+        EntityLabel("beacon".to_string()),
+    ));
     // light
     commands.spawn(PointLightBundle {
         point_light: PointLight {
@@ -60,7 +92,11 @@ fn setup(
             Press '1' or '2' to toggle the visibility of straight gizmos or round gizmos\n\
             Press 'A' to show all AABB boxes\n\
             Press 'U' or 'I' to cycle through line styles for straight or round gizmos\n\
-            Press 'J' or 'K' to cycle through line joins for straight or round gizmos",
+            Press 'J' or 'K' to cycle through line joins for straight or round gizmos\n\
+            Press 'O' to toggle hiding the labels behind geometry\n\
+            Press 'S' to toggle shrinking the labels with their distance\n\
+            Press 'F' to toggle fading out distant labels\n\
+            Press 'R' to keep drawing the blue rays for 3 seconds, or 'C' to clear them",
             TextStyle::default(),
         )
         .with_style(Style {
@@ -106,6 +142,17 @@ fn draw_example_collection(
     gizmos.cross(Vec3::new(-1., 1., 1.), Quat::IDENTITY, 0.5, FUCHSIA);
 
     my_gizmos.sphere(Vec3::new(1., 0.5, 0.), Quat::IDENTITY, 0.5, RED);
+    // Filled gizmos take the alpha of their color into account.
+    my_gizmos.sphere_filled(
+        Vec3::new(1., 0.5, 0.),
+        Quat::IDENTITY,
+        0.5,
+        RED.with_alpha(0.2),
+    );
+    gizmos.cuboid_filled(
+        Transform::from_xyz(-1., 0.25, 1.5).with_scale(Vec3::splat(0.5)),
+        FUCHSIA.with_alpha(0.4),
+    );
 
     my_gizmos
         .rounded_cuboid(
@@ -137,6 +184,7 @@ fn draw_example_collection(
 
     // Circles have 32 line-segments by default.
     my_gizmos.circle(Vec3::ZERO, Dir3::Y, 3., BLACK);
+    my_gizmos.circle_filled(Vec3::ZERO, Dir3::Y, 3., NAVY.with_alpha(0.1));
     // You may want to increase this for larger circles or spheres.
     my_gizmos
         .circle(Vec3::ZERO, Dir3::Y, 3.1, NAVY)
@@ -154,6 +202,36 @@ fn draw_example_collection(
         .with_tip_length(0.5);
 }
 
+fn draw_labels(mut gizmos: Gizmos, labels: Query<(&GlobalTransform, &EntityLabel)>) {
+    for (transform, label) in &labels {
+        gizmos.text(
+            transform.translation() + Vec3::Y * 0.8,
+            &label.0,
+            TextStyle {
+                font_size: 24.,
+                ..default()
+            },
+        );
+    }
+}
+
+fn draw_retained_rays(mut gizmos: Gizmos, keyboard: Res<ButtonInput<KeyCode>>, time: Res<Time>) {
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        // Retained gizmos keep being drawn after this frame, until they expire
+        for y in [0., 0.5, 1.] {
+            gizmos.ray_for(
+                Vec3::new(1., y, 0.),
+                Vec3::new(-3., (time.elapsed_seconds() * 3.).sin(), 0.),
+                AQUA,
+                Duration::from_secs(3),
+            );
+        }
+    }
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        gizmos.clear_retained();
+    }
+}
+
 fn update_config(
     mut config_store: ResMut<GizmoConfigStore>,
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -174,6 +252,23 @@ fn update_config(
     }
 
     let (config, _) = config_store.config_mut::<DefaultGizmoConfigGroup>();
+    if keyboard.just_pressed(KeyCode::KeyO) {
+        config.text_occluded ^= true;
+    }
+    if keyboard.just_pressed(KeyCode::KeyS) {
+        // Labels have their size at 6 units from the camera, which is about the distance of the cube
+        config.text_scale = match config.text_scale {
+            GizmoTextScale::Fixed => GizmoTextScale::Perspective(6.),
+            GizmoTextScale::Perspective(_) => GizmoTextScale::Fixed,
+        };
+    }
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        // The beacon is further than 7 units from the camera when the wall is in front of it
+        config.text_max_distance = match config.text_max_distance {
+            Some(_) => None,
+            None => Some(7.),
+        };
+    }
     if keyboard.pressed(KeyCode::ArrowRight) {
         config.line_width += 5. * time.delta_seconds();
         config.line_width = config.line_width.clamp(0., 50.);
@@ -0,0 +1,66 @@
+//! Assigns gamepads to players with [`GamepadAssignments`], and reads the inputs of each player.
+//!
+//! Connecting gamepads fill the empty player slots. Disconnect a gamepad and connect it again to
+//! see it get its slot back. Press Select on a gamepad to leave its slot.
+
+use bevy::{
+    input::gamepad_assignment::{GamepadAssignmentEvent, GamepadAssignments, PlayerGamepads},
+    prelude::*,
+};
+
+const PLAYERS: usize = 2;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .insert_resource(GamepadAssignments::new(PLAYERS))
+        .add_systems(Update, (log_assignments, player_input, leave_slot))
+        .run();
+}
+
+fn log_assignments(mut events: EventReader<GamepadAssignmentEvent>) {
+    for event in events.read() {
+        match event {
+            GamepadAssignmentEvent::Assigned { slot, gamepad } => {
+                info!("Player {} joined with {gamepad:?}", slot + 1);
+            }
+            GamepadAssignmentEvent::Reconnected { slot, gamepad } => {
+                info!("Player {} is back with {gamepad:?}", slot + 1);
+            }
+            GamepadAssignmentEvent::Disconnected { slot, .. } => {
+                info!("Waiting for the gamepad of player {}", slot + 1);
+            }
+            GamepadAssignmentEvent::Unassigned { slot, .. } => {
+                info!("Player {} left", slot + 1);
+            }
+        }
+    }
+}
+
+fn player_input(players: PlayerGamepads) {
+    for slot in 0..PLAYERS {
+        if players.just_pressed(slot, GamepadButtonType::South) {
+            info!("Player {} jumps", slot + 1);
+        }
+        let left_stick_x = players.axis(slot, GamepadAxisType::LeftStickX);
+        if left_stick_x.abs() > 0.01 {
+            info!("Player {} moves by {left_stick_x}", slot + 1);
+        }
+    }
+}
+
+fn leave_slot(
+    mut assignments: ResMut<GamepadAssignments>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+) {
+    let leaving: Vec<usize> = assignments
+        .iter()
+        .filter(|&(_, gamepad)| {
+            buttons.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::Select))
+        })
+        .map(|(slot, _)| slot)
+        .collect();
+    for slot in leaving {
+        assignments.unassign(slot);
+    }
+}
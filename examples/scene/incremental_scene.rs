@@ -0,0 +1,198 @@
+//! Spawns a large UI scene over several frames, showing its progress with a progress bar.
+//!
+//! A scene of 10,000 UI nodes is built at startup, then spawned with
+//! [`SceneSpawnMode::Incremental`]: a few hundred nodes are spawned per frame, so the frame rate
+//! stays smooth, and a [`SceneSpawnProgress`] event moves the progress bar after each frame.
+//!
+//! Press Space to spawn the scene again, or Enter to spawn it all at once, stalling a frame.
+
+use bevy::{
+    diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+    prelude::*,
+    scene::{InstanceId, SceneSpawnProgress},
+};
+
+const COLUMNS: u16 = 100;
+const ROWS: u16 = 100;
+const ENTITIES_PER_FRAME: usize = 500;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, FrameTimeDiagnosticsPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (respawn_grid, update_progress_bar, update_fps))
+        .run();
+}
+
+/// The scene of the grid of nodes, and its instance.
+#[derive(Resource)]
+struct Grid {
+    scene: Handle<DynamicScene>,
+    instance: Option<InstanceId>,
+}
+
+#[derive(Component)]
+struct ProgressBar;
+
+#[derive(Component)]
+struct ProgressText;
+
+#[derive(Component)]
+struct FpsText;
+
+fn setup(
+    mut commands: Commands,
+    type_registry: Res<AppTypeRegistry>,
+    mut scenes: ResMut<Assets<DynamicScene>>,
+    mut scene_spawner: ResMut<SceneSpawner>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    // The grid is built in a world of its own, and captured as a scene
+    let mut world = World::new();
+    world.insert_resource(type_registry.clone());
+    let root = world
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::flex(COLUMNS, 1.),
+                grid_template_rows: RepeatedGridTrack::flex(ROWS, 1.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for y in 0..ROWS {
+                for x in 0..COLUMNS {
+                    let hue = 360. * f32::from(x) / f32::from(COLUMNS);
+                    let lightness = 0.3 + 0.4 * f32::from(y) / f32::from(ROWS);
+                    parent.spawn(NodeBundle {
+                        style: Style {
+                            margin: UiRect::all(Val::Px(1.)),
+                            ..default()
+                        },
+                        background_color: Color::hsl(hue, 0.7, lightness).into(),
+                        ..default()
+                    });
+                }
+            }
+        })
+        .id();
+    let scene = scenes.add(DynamicScene::from_subtree(&world, root));
+
+    let instance = scene_spawner.spawn_dynamic_with_mode(
+        scene.clone(),
+        SceneSpawnMode::Incremental {
+            entities_per_frame: ENTITIES_PER_FRAME,
+        },
+    );
+    commands.insert_resource(Grid {
+        scene,
+        instance: Some(instance),
+    });
+
+    // The progress bar and the frame rate, drawn over the grid
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(12.),
+                right: Val::Px(12.),
+                bottom: Val::Px(12.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.),
+                ..default()
+            },
+            z_index: ZIndex::Global(1),
+            ..default()
+        })
+        .with_children(|parent| {
+            let style = TextStyle {
+                font_size: 20.,
+                ..default()
+            };
+            parent.spawn((TextBundle::from_section("", style.clone()), FpsText));
+            parent.spawn((TextBundle::from_section("", style), ProgressText));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        height: Val::Px(12.),
+                        ..default()
+                    },
+                    background_color: Color::BLACK.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(0.),
+                                height: Val::Percent(100.),
+                                ..default()
+                            },
+                            background_color: Color::WHITE.into(),
+                            ..default()
+                        },
+                        ProgressBar,
+                    ));
+                });
+        });
+}
+
+fn respawn_grid(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut grid: ResMut<Grid>,
+    mut scene_spawner: ResMut<SceneSpawner>,
+    mut texts: Query<&mut Text, With<ProgressText>>,
+) {
+    let mode = if keys.just_pressed(KeyCode::Space) {
+        SceneSpawnMode::Incremental {
+            entities_per_frame: ENTITIES_PER_FRAME,
+        }
+    } else if keys.just_pressed(KeyCode::Enter) {
+        SceneSpawnMode::AllAtOnce
+    } else {
+        return;
+    };
+    if let Some(instance) = grid.instance.take() {
+        scene_spawner.despawn_instance(instance);
+    }
+    grid.instance = Some(scene_spawner.spawn_dynamic_with_mode(grid.scene.clone(), mode));
+
+    // Only incremental spawns report their progress
+    if mode == SceneSpawnMode::AllAtOnce {
+        for mut text in &mut texts {
+            text.sections[0].value = "Spawned all at once".to_string();
+        }
+    }
+}
+
+fn update_progress_bar(
+    mut progress: EventReader<SceneSpawnProgress>,
+    mut bars: Query<&mut Style, With<ProgressBar>>,
+    mut texts: Query<&mut Text, With<ProgressText>>,
+) {
+    let Some(progress) = progress.read().last() else {
+        return;
+    };
+    for mut style in &mut bars {
+        style.width = Val::Percent(100. * progress.fraction());
+    }
+    for mut text in &mut texts {
+        text.sections[0].value = format!("{} / {} nodes", progress.spawned, progress.total);
+    }
+}
+
+fn update_fps(diagnostics: Res<DiagnosticsStore>, mut texts: Query<&mut Text, With<FpsText>>) {
+    let Some(fps) = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+    else {
+        return;
+    };
+    for mut text in &mut texts {
+        text.sections[0].value = format!("FPS: {fps:.0}");
+    }
+}
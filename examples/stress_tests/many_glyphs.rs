@@ -52,9 +52,11 @@ fn setup(mut commands: Commands) {
                 font_size: 4.,
                 ..default()
             },
+            ..default()
         }],
         justify: JustifyText::Left,
         linebreak_behavior: BreakLineOn::AnyCharacter,
+        ..default()
     };
 
     commands
@@ -0,0 +1,112 @@
+//! Renders a grid of small `Text2d` labels, such as the scores of a scoreboard, to test the
+//! performance of drawing many texts.
+//!
+//! The glyphs of each label are drawn as instances of a single item, and labels sharing a font
+//! atlas texture are batched into the same draw call. The draw calls and instances of each frame
+//! are logged along with the frame time.
+//!
+//! Add the `--fonts` arg to alternate between two fonts, splitting the labels into more batches.
+//! Takes an unsigned integer argument for the number of labels to spawn. Defaults to 5000.
+
+use argh::FromArgs;
+use bevy::{
+    diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
+    prelude::*,
+    sprite::SpriteDiagnosticsPlugin,
+    window::{PresentMode, WindowResolution},
+    winit::{UpdateMode, WinitSettings},
+};
+
+#[derive(FromArgs, Resource)]
+/// `many_text2d_labels` stress test
+struct Args {
+    /// how many labels to spawn.
+    #[argh(positional, default = "5000")]
+    count: usize,
+
+    /// whether to alternate between two fonts.
+    #[argh(switch)]
+    fonts: bool,
+}
+
+#[derive(Component)]
+struct Score(u32);
+
+fn main() {
+    // `from_env` panics on the web
+    #[cfg(not(target_arch = "wasm32"))]
+    let args: Args = argh::from_env();
+    #[cfg(target_arch = "wasm32")]
+    let args = Args::from_args(&[], &[]).unwrap();
+
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    present_mode: PresentMode::AutoNoVsync,
+                    resolution: WindowResolution::new(1920.0, 1080.0)
+                        .with_scale_factor_override(1.0),
+                    ..default()
+                }),
+                ..default()
+            }),
+            FrameTimeDiagnosticsPlugin,
+            LogDiagnosticsPlugin::default(),
+            SpriteDiagnosticsPlugin,
+        ))
+        .insert_resource(WinitSettings {
+            focused_mode: UpdateMode::Continuous,
+            unfocused_mode: UpdateMode::Continuous,
+        })
+        .insert_resource(args)
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_scores)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>, args: Res<Args>) {
+    warn!(include_str!("warning_string.txt"));
+
+    commands.spawn(Camera2dBundle::default());
+
+    let fonts = [
+        asset_server.load("fonts/FiraSans-Bold.ttf"),
+        asset_server.load("fonts/FiraMono-Medium.ttf"),
+    ];
+
+    let columns = (args.count as f32).sqrt().ceil() as usize;
+    let spacing = Vec2::new(1900.0, 1060.0) / columns as f32;
+    let labels: Vec<_> = (0..args.count)
+        .map(|index| {
+            let cell = Vec2::new((index % columns) as f32, (index / columns) as f32);
+            let font = if args.fonts { index % 2 } else { 0 };
+            (
+                Text2dBundle {
+                    text: Text::from_section(
+                        index.to_string(),
+                        TextStyle {
+                            font: fonts[font].clone(),
+                            font_size: 10.0,
+                            ..default()
+                        },
+                    ),
+                    transform: Transform::from_translation(
+                        ((cell + 0.5) * spacing - Vec2::new(950.0, 530.0)).extend(0.0),
+                    ),
+                    ..default()
+                },
+                Score(index as u32),
+            )
+        })
+        .collect();
+    commands.spawn_batch(labels);
+}
+
+// Changes the content of a few labels each frame, as a scoreboard would
+fn update_scores(mut labels: Query<(&mut Text, &mut Score)>, time: Res<Time>) {
+    let step = (time.elapsed_seconds() * 60.0) as usize;
+    for (mut text, mut score) in labels.iter_mut().skip(step % 100).step_by(100) {
+        score.0 += 1;
+        text.sections[0].value = score.0.to_string();
+    }
+}
@@ -17,12 +17,14 @@
 //! | `humanoids_active`   | 4000 active humanoid rigs.                                        |
 //! | `humanoids_inactive` | 4000 humanoid rigs. Only 10 are active.                           |
 //! | `humanoids_mixed`    | 2000 active and 2000 inactive humanoid rigs.                      |
+//! | `scenery`            | 10 active humanoid rigs and 500k entities of inactive ones.       |
+//! | `static_scenery`     | Same as `scenery`, but inactive rigs are marked as static.        |
 
 use bevy::prelude::*;
 use rand::Rng;
 
 /// pre-defined test configurations with name
-const CONFIGS: [(&str, Cfg); 9] = [
+const CONFIGS: [(&str, Cfg); 11] = [
     (
         "large_tree",
         Cfg {
@@ -113,6 +115,7 @@ const CONFIGS: [(&str, Cfg); 9] = [
             test_case: TestCase::Humanoids {
                 active: 4000,
                 inactive: 0,
+                static_inactive: false,
             },
             update_filter: UpdateFilter {
                 probability: 1.0,
@@ -127,6 +130,7 @@ const CONFIGS: [(&str, Cfg); 9] = [
             test_case: TestCase::Humanoids {
                 active: 10,
                 inactive: 3990,
+                static_inactive: false,
             },
             update_filter: UpdateFilter {
                 probability: 1.0,
@@ -141,6 +145,37 @@ const CONFIGS: [(&str, Cfg); 9] = [
             test_case: TestCase::Humanoids {
                 active: 2000,
                 inactive: 2000,
+                static_inactive: false,
+            },
+            update_filter: UpdateFilter {
+                probability: 1.0,
+                min_depth: 0,
+                max_depth: u32::MAX,
+            },
+        },
+    ),
+    (
+        "scenery",
+        Cfg {
+            test_case: TestCase::Humanoids {
+                active: 10,
+                inactive: 7400,
+                static_inactive: false,
+            },
+            update_filter: UpdateFilter {
+                probability: 1.0,
+                min_depth: 0,
+                max_depth: u32::MAX,
+            },
+        },
+    ),
+    (
+        "static_scenery",
+        Cfg {
+            test_case: TestCase::Humanoids {
+                active: 10,
+                inactive: 7400,
+                static_inactive: true,
             },
             update_filter: UpdateFilter {
                 probability: 1.0,
@@ -224,6 +259,8 @@ enum TestCase {
         active: u32,
         /// number of inactive instances (always inactive)
         inactive: u32,
+        /// whether inactive instances are marked with [`StaticTransform`]
+        static_inactive: bool,
     },
 }
 
@@ -271,16 +308,20 @@ fn setup(mut commands: Commands, cfg: Res<Cfg>) {
             branch_width,
         } => {
             let tree = gen_tree(depth, branch_width);
-            spawn_tree(&tree, &mut commands, &cfg.update_filter, default())
+            spawn_tree(&tree, &mut commands, &cfg.update_filter, default(), false)
         }
         TestCase::NonUniformTree {
             depth,
             branch_width,
         } => {
             let tree = gen_non_uniform_tree(depth, branch_width);
-            spawn_tree(&tree, &mut commands, &cfg.update_filter, default())
+            spawn_tree(&tree, &mut commands, &cfg.update_filter, default(), false)
         }
-        TestCase::Humanoids { active, inactive } => {
+        TestCase::Humanoids {
+            active,
+            inactive,
+            static_inactive,
+        } => {
             let mut result = InsertResult::default();
             let mut rng = rand::thread_rng();
 
@@ -294,6 +335,7 @@ fn setup(mut commands: Commands, cfg: Res<Cfg>) {
                         rng.gen::<f32>() * 500.0 - 250.0,
                         0.0,
                     ),
+                    false,
                 ));
             }
 
@@ -311,6 +353,7 @@ fn setup(mut commands: Commands, cfg: Res<Cfg>) {
                         rng.gen::<f32>() * 500.0 - 250.0,
                         0.0,
                     ),
+                    static_inactive,
                 ));
             }
 
@@ -348,6 +391,7 @@ fn spawn_tree(
     commands: &mut Commands,
     update_filter: &UpdateFilter,
     root_transform: Transform,
+    is_static: bool,
 ) -> InsertResult {
     // total count (# of nodes + root)
     let count = parent_map.len() + 1;
@@ -368,7 +412,12 @@ fn spawn_tree(
     }
 
     // insert root
-    ents.push(commands.spawn(TransformBundle::from(root_transform)).id());
+    let mut root = commands.spawn(TransformBundle::from(root_transform));
+    if is_static {
+        // skip propagating the transforms of the whole tree when nothing changed in it
+        root.insert(StaticTransform);
+    }
+    ents.push(root.id());
 
     let mut result = InsertResult::default();
     let mut rng = rand::thread_rng();
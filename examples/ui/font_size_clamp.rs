@@ -0,0 +1,125 @@
+//! This example sizes a title with the height of the window, like the CSS `vh` unit, and clamps
+//! its font size with [`TextStyle::font_size_clamp`] so it stays readable in a small window and
+//! doesn't take over a large one.
+//!
+//! Resize the window to see the clamp, and press the up and down arrow keys to change the
+//! [`TextScale`], which applies before the clamp.
+
+use bevy::{prelude::*, text::TextSettings};
+
+/// The font size of the title, as a fraction of the height of the window.
+const TITLE_VH: f32 = 0.15;
+/// The minimum and maximum font size of the title, in logical pixels.
+const TITLE_FONT_SIZE_CLAMP: (f32, f32) = (24.0, 96.0);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // The font size changes with every resize of the window
+        .insert_resource(TextSettings {
+            allow_dynamic_font_size: true,
+            ..default()
+        })
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (change_text_scale, resize_title, update_caption).chain(),
+        )
+        .run();
+}
+
+#[derive(Component)]
+struct Title;
+
+#[derive(Component)]
+struct Caption;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "Clamped title",
+                    TextStyle {
+                        font_size_clamp: Some(TITLE_FONT_SIZE_CLAMP),
+                        ..default()
+                    },
+                ),
+                Title,
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                ),
+                Caption,
+            ));
+        });
+}
+
+fn change_text_scale(keys: Res<ButtonInput<KeyCode>>, mut text_scale: ResMut<TextScale>) {
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        text_scale.0 = (text_scale.0 + 0.25).min(3.0);
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        text_scale.0 = (text_scale.0 - 0.25).max(0.25);
+    }
+}
+
+fn resize_title(windows: Query<&Window>, mut titles: Query<&mut Text, With<Title>>) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let font_size = window.height() * TITLE_VH;
+    for mut text in &mut titles {
+        // Only relayout the title when the window height changed
+        if text.sections[0].style.font_size != font_size {
+            text.sections[0].style.font_size = font_size;
+        }
+    }
+}
+
+fn update_caption(
+    windows: Query<&Window>,
+    text_scale: Res<TextScale>,
+    titles: Query<&Text, (With<Title>, Without<Caption>)>,
+    mut captions: Query<&mut Text, With<Caption>>,
+) {
+    let (Ok(window), Ok(title)) = (windows.get_single(), titles.get_single()) else {
+        return;
+    };
+    let style = &title.sections[0].style;
+    let (min, max) = TITLE_FONT_SIZE_CLAMP;
+    let value = format!(
+        "Window height: {:.0}px\nTitle font size: {:.1}px ({:.0}vh) x {:.2} text scale\n\
+         Clamped between {min}px and {max}px: {:.1}px",
+        window.height(),
+        style.font_size,
+        TITLE_VH * 100.0,
+        text_scale.0,
+        // The window scale factor applies after the clamp, so it is left out of logical pixels
+        style.scaled_font_size(text_scale.0, 1.0),
+    );
+    for mut caption in &mut captions {
+        if caption.sections[0].value != value {
+            caption.sections[0].value.clone_from(&value);
+        }
+    }
+}
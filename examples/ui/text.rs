@@ -1,33 +1,354 @@
 //! This example illustrates how to create UI text and update it in a system.
 //!
-//! It displays the current FPS in the top left corner, as well as text that changes color
+//! It displays the current FPS in the top left corner, as well as rainbow text that changes color
 //! in the bottom right. For text within a scene, please see the text2d example.
+//!
+//! The FPS value isn't updated by a system: it's derived from the [`DiagnosticsStore`] by a
+//! [`DerivedText`], which runs again whenever the diagnostics change.
+//!
+//! The FPS text only lives in the menu state: press space to start the game and it goes away.
+//! Its color comes from the [`Theme`]: press T to switch between the light and dark themes.
+//! Its sections behave as links, and are underlined while hovered.
+//! The FPS value casts a faint shadow: a copy of it in a lower layer, drawn behind it with an offset.
+//! Press F, or the north button of a gamepad, to show or hide it.
+//! The FPS number is also shown in a circular badge, which clips it with a [`UiClipShape`].
+//! Press P to capture it as a prefab [`DynamicScene`], and spawn three copies of it.
+//!
+//! With the `bevy_debug_stepping` feature, press S to step through the systems of the `Update`
+//! schedule: the FPS text shows the next system to run. Press Period to run it, and Enter to
+//! continue to the end of the frame, or to the breakpoint on `text_color_system`.
+//!
+//! The rainbow text waves on the virtual clock, `Time<Virtual>`, which is the default `Time` in
+//! `Update`: press 1, 2 and 3 to play it at 0.5x, 1x and 2x speed, and 0 or the button next to
+//! the FPS text to pause it. Tests can click the button with [`ManualInput`](bevy::window::ManualInput).
+//! The icon of the button is packed into the glyph atlas of its label with a [`TextAtlasIcon`],
+//! so that they're drawn together: the FPS text shows the number of UI draw calls.
+//! The FPS text keeps updating on the real clock, `Time<Real>`, which is never scaled or paused.
+//! In CI, the example runs with the fixed frame time of its CI testing configuration, so that the
+//! frames it renders are identical from one run to the next.
+//!
+//! A long file name is truncated to its end, keeping the name of the file.
+//!
+//! The prompt "Press → to jump" lays out an icon inside its line with an [`InlineImage`]: the
+//! icon advances the text like a glyph, and is centered on the glyphs around it.
+//!
+//! The tooltip below it wraps at 300px with [`Text::wrap_width`], and its node hugs the wrapped
+//! lines rather than the width available to it.
+//!
+//! The "Text size" [`Slider`] scales the font size of all the text with [`TextScale`]: drag it, or
+//! click it and press the arrow keys, Home and End. Its value snaps to steps of 0.1, and is shown
+//! next to it with a [`SliderValueText`].
+//!
+//! A search match is highlighted with [`TextStyle::background_color`], drawn behind its glyphs
+//! over the full height of the line. The end of the line fades to gold with a
+//! [`TextStyle::color_gradient`], which the [`TextGlyphColor`] of the rainbow text would override.
+//!
+//! A line is underlined at the underline position and thickness of its font, read with
+//! [`Font::metrics`].
+//!
+//! Sparkles rise from the glyphs of the FPS value, placed by iterating over the positioned glyphs
+//! of its [`TextLayoutInfo`].
+//!
+//! A line of dialogue is revealed one character at a time, pausing briefly after commas and
+//! longer after periods, as set by the `TypewriterTiming`: press Escape to reveal it all at
+//! once, and R to replay it. Above it, "hello bevy world" is revealed one word at a time, the
+//! words being found with Unicode word segmentation. The hidden text is still laid out, but its
+//! glyphs are transparent, and so not drawn at all. The console logs when a line is fully revealed.
+//!
+//! Text without a font uses the [`DefaultFont`]: press N to switch it between two fonts at runtime.
+//!
+//! Text that fails to render gets a [`TextRenderError`], such as the text using a missing font:
+//! the errors are logged to the console.
+//!
+//! A console in the top right corner keeps the last 200 lines logged to it:
+//! press L to log 10000 lines.
+//!
+//! The rainbow text is selectable: drag the mouse over "bevy" and press Ctrl+C to copy it to the console.
+//! It pops to 1.2x its size while hovered, which only scales its rendering: it's not laid out again.
+//!
+//! With the `bevy_dev_tools` feature, press D to outline the UI nodes, and M to outline their
+//! margins and paddings. The size of the hovered node is shown next to it.
+//! Press K to outline the lines of the texts, such as the two lines of "hello\nbevy!", with their
+//! baselines and heights.
+//! Press B to cycle through simulations of color blindness over the whole frame, such as
+//! deuteranopia, and check that the gold FPS value stays distinguishable from its label.
+//!
+//! The rainbow text is marked [`StaticText`]: it's only extracted to the render world on the
+//! frames it changes. Pause the virtual clock and the FPS text shows that only its own glyphs
+//! are extracted, while the rainbow text is still drawn.
+//!
+//! The text is only spawned once its three fonts finished loading as an [`AssetGroup`],
+//! which reports the missing one.
+//!
+//! On high-DPI displays, assets are read from their `@2x` variant when it exists,
+//! such as `fonts/FiraSans-Bold@2x.ttf`, and from the base asset otherwise.
+//! Press O to override the scale factor of the window with 1.5, whatever the one of the display:
+//! the text is laid out and rasterized again at that scale factor, and stays crisp.
+//! Press O again to go back to the scale factor of the display.
+//!
+//! Press A to cycle the antialiasing of the glyphs between grayscale, subpixel and none. Subpixel
+//! antialiasing is sharper on horizontal RGB LCD displays, and only applies to the text with an
+//! opaque background, the "Press → to jump" prompt: the other text falls back to grayscale.
+//!
+//! The glyphs are packed into their atlases with 2 pixels of transparent padding around them, set
+//! with [`TextAtlasSettings::glyph_padding`], so that the tiny text above the dialogue doesn't
+//! sample its neighbors in the atlas when it's filtered. Press G to switch the padding between 1
+//! and 2 pixels: the atlases are packed again.
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use bevy::{
+    asset::{AssetGroup, AssetGroupEvent, AssetGroups},
     color::palettes::css::GOLD,
     diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+    ecs::{entity::EntityHashMap, schedule::Stepping},
     prelude::*,
+    text::{update_themed_text_colors, InlineImage, InlineImageAlign, TextLayoutInfo},
+    ui::{
+        widget::{
+            text_system, SelectableTextBundle, Slider, SliderBundle, SliderChanged, SliderHandle,
+            SliderValueText, StaticText, TextAtlasIcon, TextCopied, TextLinkBundle,
+            TextRenderScale, TextRenderScalePicking, ToggleVisibility,
+        },
+        UiDiagnosticsPlugin,
+    },
+    utils::HashMap,
+    window::PrimaryWindow,
 };
 
 fn main() {
-    App::new()
-        .add_plugins((DefaultPlugins, FrameTimeDiagnosticsPlugin))
-        .add_systems(Startup, setup)
-        .add_systems(Update, (text_update_system, text_color_system))
+    let mut app = App::new();
+    app.add_plugins((
+        DefaultPlugins.set(AssetPlugin {
+            // Select the `@2x` variants of assets when the window scale factor is 2 or more
+            max_scale_variant: Some(2),
+            ..default()
+        }),
+        FrameTimeDiagnosticsPlugin,
+        UiDiagnosticsPlugin,
+    ));
+
+    // Start loading the fonts right away, and only spawn the text once they're all ready.
+    // Spawning it in `Startup` would show a frame without the text while the fonts load.
+    let asset_server = app.world().resource::<AssetServer>();
+    let fonts: AssetGroup = [
+        "fonts/FiraSans-Bold.ttf",
+        "fonts/FiraMono-Medium.ttf",
+        "fonts/Missing.ttf",
+    ]
+    .into_iter()
+    .map(|path| asset_server.load::<Font>(path))
+    .collect();
+    app.world_mut().resource_mut::<AssetGroups>().add(fonts);
+
+    #[cfg(feature = "bevy_dev_tools")]
+    {
+        app.add_plugins((
+            bevy::dev_tools::ui_debug_overlay::DebugUiPlugin,
+            bevy::dev_tools::color_blindness::ColorBlindnessPlugin,
+        ))
+        .add_systems(Update, (toggle_ui_debug, cycle_color_blindness));
+    }
+
+    app.init_state::<AppState>()
+        .enable_state_scoped_entities::<AppState>()
+        .insert_resource(stepping())
+        .init_resource::<TypewriterTiming>()
+        .insert_resource(TextAtlasSettings { glyph_padding: 2 })
+        .add_event::<TypewriterFinished>()
+        .add_systems(Update, setup.run_if(on_event::<AssetGroupEvent>()))
+        // Stepping only pauses the `Update` schedule, so these systems keep running
+        .add_systems(PreUpdate, control_stepping)
+        .add_systems(PostUpdate, show_next_system)
+        .add_systems(
+            PostUpdate,
+            fps_shadow_system
+                .after(text_system)
+                .after(update_themed_text_colors),
+        )
+        .add_systems(
+            Update,
+            (
+                text_color_system,
+                text_wave_system,
+                text_pop_system,
+                typewriter_system,
+                log_typewriter_finished.after(typewriter_system),
+                clock_text_system,
+                control_time_speed,
+                pause_button_system,
+                start_game.run_if(in_state(AppState::Menu)),
+                toggle_theme,
+                switch_default_font,
+                spam_console,
+                log_copied_text,
+                log_text_render_errors,
+                spawn_fps_text_copies,
+                toggle_scale_factor_override,
+                font_underline_system,
+                cycle_glyph_antialiasing,
+                toggle_glyph_padding,
+                fps_sparkle_system,
+            ),
+        )
+        .add_systems(Update, text_scale_slider_system)
         .run();
 }
 
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AppState {
+    #[default]
+    Menu,
+    InGame,
+}
+
 // A unit struct to help identify the FPS UI component, since there may be many Text components
 #[derive(Component)]
 struct FpsText;
 
+// A sparkle rising from a glyph of the FPS value, and fading out
+#[derive(Component)]
+struct GlyphSparkle {
+    velocity: Vec2,
+    age: f32,
+}
+
+const SPARKLE_LIFETIME: f32 = 0.8;
+
+// A unit struct to help identify the button pausing the virtual clock
+#[derive(Component)]
+struct PauseButton;
+
+// Reveals the text of its first section one unit at a time, hiding the rest in its second
+// section. The hidden text is laid out too, so that the revealed words don't jump between lines.
+#[derive(Component)]
+struct TypewriterText {
+    text: String,
+    unit: RevealUnit,
+    // The length of the revealed text, in bytes
+    revealed: usize,
+    // The time since the last unit was revealed. The remainder is carried over to the next
+    // frames, so that short frames don't lose time and long frames reveal several units.
+    elapsed: f32,
+}
+
+impl TypewriterText {
+    fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            unit: RevealUnit::Char,
+            revealed: 0,
+            elapsed: 0.0,
+        }
+    }
+
+    fn with_unit(mut self, unit: RevealUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    fn is_finished(&self) -> bool {
+        self.revealed == self.text.len()
+    }
+
+    // The length in bytes of the next unit to reveal, if any
+    fn next_unit_len(&self) -> Option<usize> {
+        let hidden = &self.text[self.revealed..];
+        match self.unit {
+            RevealUnit::Char => hidden.chars().next().map(char::len_utf8),
+            RevealUnit::Grapheme => hidden.graphemes(true).next().map(str::len),
+            // A word along with the spaces and punctuation before it, so that each step reveals
+            // a word
+            RevealUnit::Word => {
+                let mut len = 0;
+                for segment in hidden.split_word_bounds() {
+                    len += segment.len();
+                    if segment.chars().any(char::is_alphanumeric) {
+                        break;
+                    }
+                }
+                (len > 0).then_some(len)
+            }
+        }
+    }
+}
+
+// The unit by which a `TypewriterText` is revealed
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RevealUnit {
+    Char,
+    // A word, by the Unicode word boundaries
+    Word,
+    // A user-perceived character, such as an emoji made of several chars
+    Grapheme,
+}
+
+// Sent when all of a `TypewriterText` is revealed
+#[derive(Event)]
+struct TypewriterFinished(Entity);
+
+// The pacing of the typewriter text
+#[derive(Resource)]
+struct TypewriterTiming {
+    // The delay between two characters or graphemes, in seconds
+    char_delay: f32,
+    // The delay between two words, in seconds
+    word_delay: f32,
+    // The extra delay after some characters, such as punctuation, in seconds
+    pauses: HashMap<char, f32>,
+}
+
+impl Default for TypewriterTiming {
+    fn default() -> Self {
+        Self {
+            char_delay: 0.04,
+            word_delay: 0.3,
+            pauses: HashMap::from([(',', 0.2), (';', 0.2), ('.', 0.5), ('!', 0.5), ('?', 0.5)]),
+        }
+    }
+}
+
+impl TypewriterTiming {
+    // The delay before revealing the unit after the revealed text ending with `previous`
+    fn delay_after(&self, unit: RevealUnit, previous: Option<char>) -> f32 {
+        let delay = match unit {
+            RevealUnit::Char | RevealUnit::Grapheme => self.char_delay,
+            RevealUnit::Word => self.word_delay,
+        };
+        let pause = previous.and_then(|previous| self.pauses.get(&previous));
+        delay + pause.copied().unwrap_or(0.0)
+    }
+}
+
+// An underline placed with the metrics of the font of its parent text
+#[derive(Component)]
+struct FontUnderline;
+
 // A unit struct to help identify the color-changing Text component
 #[derive(Component)]
 struct ColorText;
 
-fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut font_events: EventReader<AssetGroupEvent>,
+) {
+    for event in font_events.read() {
+        if let AssetGroupEvent::Failed { failed, .. } = event {
+            for font in failed {
+                warn!("Font {:?} failed to load", font.path());
+            }
+        }
+    }
+
     // UI camera
     commands.spawn(Camera2dBundle::default());
+
+    // The default font is a minimal subset of FiraMono with the "default_font" feature.
+    // Without it, load a font to use instead.
+    #[cfg(not(feature = "default_font"))]
+    commands.insert_resource(DefaultFont(asset_server.load("fonts/FiraMono-Medium.ttf")));
+
     // Text with one section
     commands.spawn((
         // Create a TextBundle that has a Text with a single section.
@@ -49,9 +370,48 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
             right: Val::Px(5.0),
             ..default()
         }),
+        // Color each glyph of the text procedurally
+        rainbow(0.0),
+        // Select glyphs by dragging the mouse over them
+        SelectableTextBundle::default(),
+        // Scaled around its center when rendering, and hovered within its scaled bounds
+        TextRenderScale::default(),
+        TextRenderScalePicking,
         ColorText,
+        // Only extracted to the render world again on the frames it changes
+        StaticText,
     ));
 
+    // A console, keeping its last lines only
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(5.0),
+                right: Val::Px(5.0),
+                width: Val::Px(300.0),
+                height: Val::Px(150.0),
+                // Keep the newest line at the bottom in view, hiding the older ones
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexEnd,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::default(),
+                LogText::new(
+                    200,
+                    TextStyle {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                ),
+            ));
+        });
+
     // Text with multiple sections
     commands.spawn((
         // Create a TextBundle that has a Text with a list of sections.
@@ -65,25 +425,414 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 },
             ),
-            TextSection::from_style(if cfg!(feature = "default_font") {
+            TextSection::from_style(TextStyle {
+                font_size: 60.0,
+                color: GOLD.into(),
+                // If no font is specified, the `DefaultFont` will be used.
+                ..default()
+            }),
+            // The shadow of the FPS value, drawn behind it. Its offset is set once the text is laid out
+            TextSection::from_style(TextStyle {
+                font_size: 60.0,
+                ..default()
+            })
+            .with_layer(-1),
+            // The next system to run while stepping
+            TextSection::from_style(TextStyle {
+                font_size: 20.0,
+                ..default()
+            }),
+            // The real and virtual clocks
+            TextSection::from_style(TextStyle {
+                font_size: 20.0,
+                ..default()
+            }),
+        ]),
+        FpsText,
+        // Derive the FPS value and its shadow from the diagnostics, whenever they change.
+        // The text is only laid out again if the rounded values changed.
+        DerivedText::new(fps_value)
+            .reads::<DiagnosticsStore>()
+            .in_sections([1, 2]),
+        // Take the color of the FPS text from the theme
+        ThemedColor(ColorRole::Primary),
+        // Despawn the FPS text when leaving the menu
+        StateScoped(AppState::Menu),
+        // Underline the hovered section, like a link
+        TextLinkBundle::default(),
+        // Show and hide the FPS text with the keyboard or a gamepad
+        ToggleVisibility::new(InputAction::new(KeyCode::KeyF).or(GamepadButtonType::North)),
+    ));
+
+    // A button next to the FPS text, pausing the virtual clock
+    commands
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(5.0),
+                    left: Val::Px(520.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+                ..default()
+            },
+            PauseButton,
+            StateScoped(AppState::Menu),
+        ))
+        .with_children(|parent| {
+            let label = parent
+                .spawn(TextBundle::from_section(
+                    "Pause",
+                    TextStyle {
+                        font_size: 30.0,
+                        ..default()
+                    },
+                ))
+                .id();
+            // An icon packed into the glyph atlas of the label, so that both are drawn with a
+            // single draw call
+            parent.spawn((
+                ImageBundle {
+                    style: Style {
+                        width: Val::Px(30.0),
+                        height: Val::Px(30.0),
+                        margin: UiRect::left(Val::Px(8.0)),
+                        ..default()
+                    },
+                    image: UiImage::new(asset_server.load("textures/Game Icons/wrench.png")),
+                    ..default()
+                },
+                TextAtlasIcon::new(label),
+            ));
+        });
+
+    // A circular badge next to the button, clipping the FPS number overflowing it.
+    // Glyphs crossing its edge are antialiased against the circle.
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(5.0),
+                    left: Val::Px(680.0),
+                    width: Val::Px(64.0),
+                    height: Val::Px(64.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                background_color: Color::srgb(0.15, 0.15, 0.15).into(),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            UiClipShape::Circle,
+            StateScoped(AppState::Menu),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 48.0,
+                        color: GOLD.into(),
+                        ..default()
+                    },
+                ),
+                DerivedText::new(fps_badge_value).reads::<DiagnosticsStore>(),
+            ));
+        });
+
+    // A long file name, truncated to its last 20 graphemes
+    commands.spawn(
+        TextBundle::from_sections([
+            TextSection::new(
+                "Loading ",
                 TextStyle {
-                    font_size: 60.0,
-                    color: GOLD.into(),
-                    // If no font is specified, the default font (a minimal subset of FiraMono) will be used.
+                    font: asset_server.load("fonts/FiraMono-Medium.ttf"),
                     ..default()
-                }
-            } else {
-                // "default_font" feature is unavailable, load a font to use instead.
+                },
+            ),
+            TextSection::new(
+                "assets/textures/rpg/chars/mani/mani.png",
                 TextStyle {
                     font: asset_server.load("fonts/FiraMono-Medium.ttf"),
-                    font_size: 60.0,
                     color: GOLD.into(),
-                }
+                    ..default()
+                },
+            )
+            // The marker is displayed with the style of the section
+            .with_truncation(SectionTruncation::keep_end(20, "…")),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(40.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+
+    // A line of dialogue, typed one character at a time
+    let dialogue_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 24.0,
+        ..default()
+    };
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::from_style(dialogue_style.clone()),
+            TextSection::from_style(TextStyle {
+                color: Color::NONE,
+                ..dialogue_style
             }),
-        ]),
-        FpsText,
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(110.0),
+            left: Val::Px(15.0),
+            max_width: Val::Px(500.0),
+            ..default()
+        }),
+        TypewriterText::new(
+            "Well, traveler, you made it. The road was long, and the night is cold. Sit, rest, \
+            and tell me: what brings you here?",
+        ),
+    ));
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::from_style(dialogue_style.clone()),
+            TextSection::from_style(TextStyle {
+                color: Color::NONE,
+                ..dialogue_style.clone()
+            }),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(215.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+        TypewriterText::new("hello bevy world").with_unit(RevealUnit::Word),
     ));
 
+    // Tiny text, whose glyphs are sampled from their atlas with filtering
+    commands.spawn(
+        TextBundle::from_section(
+            "Tiny text keeps clear of its neighbors in the glyph atlas",
+            TextStyle {
+                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                font_size: 9.0,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(255.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+
+    // Text with a custom underline, placed at the underline position of its font
+    commands
+        .spawn(
+            TextBundle::from_section(
+                "Underlined by the font",
+                TextStyle {
+                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                    font_size: 30.0,
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(80.0),
+                left: Val::Px(520.0),
+                ..default()
+            }),
+        )
+        .with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: GOLD.into(),
+                    ..default()
+                },
+                FontUnderline,
+            ));
+        });
+
+    // An arrow key icon laid out inside the line, centered on its glyphs
+    let prompt_style = TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 30.0,
+        ..default()
+    };
+    commands.spawn(
+        TextBundle::from_sections([
+            TextSection::new("Press ", prompt_style.clone()),
+            TextSection::inline_image(
+                InlineImage::new(
+                    asset_server.load("textures/Game Icons/right.png"),
+                    Vec2::splat(36.0),
+                )
+                .with_align(InlineImageAlign::Center),
+                prompt_style.clone(),
+            ),
+            TextSection::new(" to jump", prompt_style),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(130.0),
+            left: Val::Px(520.0),
+            ..default()
+        })
+        // Subpixel antialiasing needs an opaque background behind the text
+        .with_background_color(Color::srgb(0.1, 0.1, 0.1)),
+    );
+
+    // A tooltip wrapping at 300px, whose background hugs the wrapped lines
+    commands.spawn(
+        TextBundle::from_section(
+            "Tooltips wrap at a fixed width, while their node shrinks to the wrapped text instead \
+            of stretching to the width it's given.",
+            TextStyle {
+                font_size: 20.0,
+                ..default()
+            },
+        )
+        .with_wrap_width(300.0)
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(180.0),
+            left: Val::Px(520.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        })
+        .with_background_color(Color::srgb(0.1, 0.1, 0.1)),
+    );
+
+    // A search match highlighted behind its glyphs, spanning the full height of the line
+    let result_style = TextStyle {
+        font_size: 20.0,
+        ..default()
+    };
+    commands.spawn(
+        TextBundle::from_sections([
+            TextSection::new("Found a ", result_style.clone()),
+            TextSection::new(
+                "highlighted",
+                TextStyle {
+                    color: Color::BLACK,
+                    background_color: Some(GOLD.into()),
+                    ..result_style.clone()
+                },
+            ),
+            // Fades into the color of the highlight, glyph by glyph
+            TextSection::new(
+                " match",
+                TextStyle {
+                    color_gradient: Some(GOLD.into()),
+                    ..result_style
+                },
+            ),
+        ])
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(280.0),
+            left: Val::Px(520.0),
+            ..default()
+        }),
+    );
+
+    // A slider scaling the font size of all the text, with its value next to it
+    let slider_style = TextStyle {
+        font_size: 20.0,
+        ..default()
+    };
+    let label = commands
+        .spawn(TextBundle::from_section("Text size", slider_style.clone()))
+        .id();
+    let slider = commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(150.0),
+                    height: Val::Px(6.0),
+                    margin: UiRect::horizontal(Val::Px(12.0)),
+                    ..default()
+                },
+                background_color: Color::srgb(0.3, 0.3, 0.3).into(),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            SliderBundle {
+                slider: Slider::new(0.5, 2.0).with_step(0.1).with_value(1.0),
+                ..default()
+            },
+        ))
+        .with_children(|track| {
+            track.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(14.0),
+                        height: Val::Px(14.0),
+                        top: Val::Px(-4.0),
+                        // Centers the handle on the value
+                        margin: UiRect::left(Val::Px(-7.0)),
+                        ..default()
+                    },
+                    background_color: Color::WHITE.into(),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                },
+                SliderHandle,
+            ));
+        })
+        .id();
+    let value = commands
+        .spawn((
+            TextBundle::from_section("", slider_style),
+            SliderValueText::new(slider).with_precision(1),
+        ))
+        .id();
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(330.0),
+                left: Val::Px(520.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .push_children(&[label, slider, value]);
+
+    // Text using a font that doesn't exist, which is never rendered
+    commands.spawn(
+        TextBundle::from_section(
+            "This font is missing",
+            TextStyle {
+                font: asset_server.load("fonts/Missing.ttf"),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(75.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+
     #[cfg(feature = "default_font")]
     commands.spawn(
         // Here we are able to call the `From` method instead of creating a new `TextSection`.
@@ -116,29 +865,567 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     );
 }
 
-fn text_color_system(time: Res<Time>, mut query: Query<&mut Text, With<ColorText>>) {
+// Places the underlines at the underline position of the font of their text, and gives them its
+// thickness, instead of guessing them from the font size
+fn font_underline_system(
+    fonts: Res<Assets<Font>>,
+    texts: Query<&Text>,
+    mut underlines: Query<(&Parent, &mut Style), With<FontUnderline>>,
+) {
+    for (parent, mut style) in &mut underlines {
+        let Ok(text) = texts.get(parent.get()) else {
+            continue;
+        };
+        let text_style = &text.sections[0].style;
+        let Some(font) = fonts.get(&text_style.font) else {
+            continue;
+        };
+        let metrics = font.metrics(text_style.font_size);
+        let (Some(position), Some(thickness)) =
+            (metrics.underline_position, metrics.underline_thickness)
+        else {
+            continue;
+        };
+        // The baseline of the first line is at the ascent of the font below the top of the text,
+        // and the underline position is relative to it, upwards
+        let top = Val::Px(metrics.ascent - position);
+        let height = Val::Px(thickness);
+        if style.top != top || style.height != height {
+            style.top = top;
+            style.height = height;
+        }
+    }
+}
+
+fn start_game(keys: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<AppState>>) {
+    if keys.just_pressed(KeyCode::Space) {
+        next_state.set(AppState::InGame);
+    }
+}
+
+fn spawn_fps_text_copies(world: &mut World) {
+    if !world
+        .resource::<ButtonInput<KeyCode>>()
+        .just_pressed(KeyCode::KeyP)
+    {
+        return;
+    }
+    let mut query = world.query_filtered::<Entity, With<FpsText>>();
+    let Some(fps_text) = query.iter(world).next() else {
+        return;
+    };
+
+    // Capture the FPS text and its descendants, with their styles.
+    // `FpsText` isn't reflected, so it is dropped with a warning: the copies aren't updated.
+    let prefab = DynamicScene::from_subtree(world, fps_text);
+    for i in 1..=3 {
+        // Each instantiation spawns new entities, with an empty entity map
+        let mut entity_map = EntityHashMap::default();
+        prefab.write_to_world(world, &mut entity_map).unwrap();
+        if let Some(mut style) = world.get_mut::<Style>(entity_map[&fps_text]) {
+            style.position_type = PositionType::Absolute;
+            style.top = Val::Px(70.0 * i as f32);
+        }
+    }
+}
+
+fn stepping() -> Stepping {
+    let mut stepping = Stepping::new();
+    stepping
+        .add_schedule(Update)
+        .set_breakpoint_by_name(Update, "text_color_system");
+    stepping
+}
+
+fn control_stepping(keys: Res<ButtonInput<KeyCode>>, mut stepping: ResMut<Stepping>) {
+    if keys.just_pressed(KeyCode::KeyS) {
+        if stepping.is_enabled() {
+            stepping.disable();
+        } else {
+            stepping.enable();
+        }
+    }
+    if keys.just_pressed(KeyCode::Period) {
+        stepping.step_frame();
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        stepping.continue_frame();
+    }
+}
+
+fn show_next_system(stepping: Res<Stepping>, mut query: Query<&mut Text, With<FpsText>>) {
+    let next_system = match stepping.cursor_system_name() {
+        Some(name) => format!("\nnext system: {name}"),
+        None => String::new(),
+    };
     for mut text in &mut query {
-        let seconds = time.elapsed_seconds();
+        text.set_section_if_changed(3, &next_system);
+    }
+}
+
+fn switch_default_font(
+    keys: Res<ButtonInput<KeyCode>>,
+    asset_server: Res<AssetServer>,
+    mut default_font: ResMut<DefaultFont>,
+    mut use_sans: Local<bool>,
+) {
+    if keys.just_pressed(KeyCode::KeyN) {
+        *use_sans = !*use_sans;
+        // Text using the default font is laid out again with the new font
+        default_font.0 = asset_server.load(if *use_sans {
+            "fonts/FiraSans-Bold.ttf"
+        } else {
+            "fonts/FiraMono-Medium.ttf"
+        });
+    }
+}
+
+fn spam_console(keys: Res<ButtonInput<KeyCode>>, mut query: Query<&mut LogText>) {
+    if keys.just_pressed(KeyCode::KeyL) {
+        for mut log in &mut query {
+            // Only the last 200 lines are kept, so the memory used by the console stays bounded,
+            // and it's laid out again once, with those 200 lines
+            for i in 0..10_000 {
+                log.push_line(&format!("log line {i}"));
+            }
+        }
+    }
+}
+
+fn log_copied_text(mut copied: EventReader<TextCopied>, mut query: Query<&mut LogText>) {
+    for event in copied.read() {
+        for mut log in &mut query {
+            log.push_line(&format!("copied: {:?}", event.text));
+        }
+    }
+}
+
+fn log_text_render_errors(
+    failed: Query<(Entity, &TextRenderError), (FailedText, Changed<TextRenderError>)>,
+    mut query: Query<&mut LogText>,
+) {
+    for (entity, error) in &failed {
+        for mut log in &mut query {
+            // The error is removed once the text renders, such as when its font is loaded
+            log.push_line(&format!("{entity:?} failed to render: {error:?}"));
+        }
+    }
+}
 
-        // Update the color of the first and only section.
-        text.sections[0].style.color = Color::srgb(
-            (1.25 * seconds).sin() / 2.0 + 0.5,
-            (0.75 * seconds).sin() / 2.0 + 0.5,
-            (0.50 * seconds).sin() / 2.0 + 0.5,
+#[cfg(feature = "bevy_dev_tools")]
+fn toggle_ui_debug(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut options: ResMut<bevy::dev_tools::ui_debug_overlay::UiDebugOptions>,
+) {
+    if keys.just_pressed(KeyCode::KeyD) {
+        options.toggle();
+    }
+    if keys.just_pressed(KeyCode::KeyM) {
+        options.show_margins = !options.show_margins;
+    }
+    if keys.just_pressed(KeyCode::KeyK) {
+        options.show_text_lines = !options.show_text_lines;
+    }
+}
+
+#[cfg(feature = "bevy_dev_tools")]
+fn cycle_color_blindness(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    cameras: Query<Entity, With<Camera>>,
+    mut mode: Local<bevy::dev_tools::color_blindness::ColorBlindnessMode>,
+) {
+    use bevy::dev_tools::color_blindness::{ColorBlindnessMode, ColorBlindnessSimulation};
+
+    if keys.just_pressed(KeyCode::KeyB) {
+        *mode = match *mode {
+            ColorBlindnessMode::None => ColorBlindnessMode::Protanopia,
+            ColorBlindnessMode::Protanopia => ColorBlindnessMode::Deuteranopia,
+            ColorBlindnessMode::Deuteranopia => ColorBlindnessMode::Tritanopia,
+            ColorBlindnessMode::Tritanopia => ColorBlindnessMode::None,
+        };
+        info!("Simulating color blindness: {:?}", *mode);
+        // The simulation is per camera
+        for camera in &cameras {
+            commands
+                .entity(camera)
+                .insert(ColorBlindnessSimulation { mode: *mode });
+        }
+    }
+}
+
+fn toggle_scale_factor_override(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyO) {
+        return;
+    }
+    for mut window in &mut windows {
+        let scale_factor_override = match window.resolution.scale_factor_override() {
+            Some(_) => None,
+            None => Some(1.5),
+        };
+        // The UI layout, the text and the hovered buttons all follow the effective scale factor
+        window
+            .resolution
+            .set_scale_factor_override(scale_factor_override);
+        info!(
+            "Scale factor: {} (override: {:?})",
+            window.scale_factor(),
+            scale_factor_override
         );
     }
 }
 
-fn text_update_system(
-    diagnostics: Res<DiagnosticsStore>,
+fn cycle_glyph_antialiasing(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut text_render_settings: ResMut<TextRenderSettings>,
+) {
+    if !keys.just_pressed(KeyCode::KeyA) {
+        return;
+    }
+    // The text is laid out again, rasterizing its glyphs with the new antialiasing
+    text_render_settings.antialiasing = match text_render_settings.antialiasing {
+        GlyphAntialiasing::Grayscale => GlyphAntialiasing::SubpixelRgb,
+        GlyphAntialiasing::SubpixelRgb => GlyphAntialiasing::None,
+        GlyphAntialiasing::None => GlyphAntialiasing::Grayscale,
+    };
+    info!(
+        "Glyph antialiasing: {:?}",
+        text_render_settings.antialiasing
+    );
+}
+
+fn toggle_glyph_padding(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut text_atlas_settings: ResMut<TextAtlasSettings>,
+) {
+    if !keys.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+    // The font atlases are cleared, and the glyphs packed again with the new padding
+    text_atlas_settings.glyph_padding = if text_atlas_settings.glyph_padding == 1 {
+        2
+    } else {
+        1
+    };
+    info!("Glyph padding: {}px", text_atlas_settings.glyph_padding);
+}
+
+fn toggle_theme(keys: Res<ButtonInput<KeyCode>>, mut theme: ResMut<Theme>) {
+    if keys.just_pressed(KeyCode::KeyT) {
+        *theme = if *theme == Theme::DARK {
+            Theme::LIGHT
+        } else {
+            Theme::DARK
+        };
+    }
+}
+
+// Systems animating the game read `Time`, which is the virtual clock in `Update`:
+// they slow down, speed up and pause along with the game.
+fn text_color_system(time: Res<Time>, mut query: Query<&mut TextGlyphColor, With<ColorText>>) {
+    // Leave the colors unchanged while paused, so that the static text isn't extracted again
+    if time.delta_seconds() == 0.0 {
+        return;
+    }
+    for mut glyph_color in &mut query {
+        // Only the color of the glyphs changes, so the text doesn't need to be laid out again.
+        *glyph_color = rainbow(time.elapsed_seconds() * 90.0);
+    }
+}
+
+fn text_wave_system(time: Res<Time>, mut query: Query<&mut Style, With<ColorText>>) {
+    for style in &mut query {
+        let bottom = Val::Px(15.0 + 10.0 * (time.elapsed_seconds() * 3.0).sin());
+        // The text is only laid out again when it moves, not while the virtual clock is paused
+        style
+            .map_unchanged(|style| &mut style.bottom)
+            .set_if_neq(bottom);
+    }
+}
+
+// Hover feedback keeps responding while the game is paused, so it reads `Time<Real>`.
+fn text_pop_system(
+    time: Res<Time<Real>>,
+    mut query: Query<(&Interaction, &mut TextRenderScale), With<ColorText>>,
+) {
+    for (interaction, mut render_scale) in &mut query {
+        let target = if *interaction == Interaction::None {
+            1.0
+        } else {
+            1.2
+        };
+        // Ease towards the target scale
+        let scale = render_scale
+            .0
+            .lerp(target, 1.0 - (-15.0 * time.delta_seconds()).exp());
+        render_scale.set_if_neq(TextRenderScale(scale));
+    }
+}
+
+fn typewriter_system(
+    time: Res<Time>,
+    timing: Res<TypewriterTiming>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(Entity, &mut TypewriterText, &mut Text)>,
+    mut finished: EventWriter<TypewriterFinished>,
+) {
+    for (entity, mut typewriter, mut text) in &mut query {
+        let typewriter = &mut *typewriter;
+        if keys.just_pressed(KeyCode::KeyR) {
+            typewriter.revealed = 0;
+            typewriter.elapsed = 0.0;
+        }
+        let was_finished = typewriter.is_finished();
+
+        if keys.just_pressed(KeyCode::Escape) {
+            // Skip to the end
+            typewriter.revealed = typewriter.text.len();
+        } else {
+            typewriter.elapsed += time.delta_seconds();
+            while let Some(len) = typewriter.next_unit_len() {
+                let previous = typewriter.text[..typewriter.revealed].chars().next_back();
+                let delay = timing.delay_after(typewriter.unit, previous);
+                if typewriter.elapsed < delay {
+                    break;
+                }
+                typewriter.elapsed -= delay;
+                typewriter.revealed += len;
+            }
+        }
+        if typewriter.is_finished() {
+            typewriter.elapsed = 0.0;
+            if !was_finished {
+                finished.send(TypewriterFinished(entity));
+            }
+        }
+
+        // The hidden section is transparent, so its glyphs are laid out but not drawn
+        let (revealed, hidden) = typewriter.text.split_at(typewriter.revealed);
+        text.set_section_if_changed(0, revealed);
+        text.set_section_if_changed(1, hidden);
+    }
+}
+
+fn log_typewriter_finished(
+    mut finished: EventReader<TypewriterFinished>,
+    typewriters: Query<&TypewriterText>,
+    mut query: Query<&mut LogText>,
+) {
+    for TypewriterFinished(entity) in finished.read() {
+        let Ok(typewriter) = typewriters.get(*entity) else {
+            continue;
+        };
+        for mut log in &mut query {
+            log.push_line(&format!("revealed: {:?}", typewriter.text));
+        }
+    }
+}
+
+// Systems that must keep running while the game is paused, such as debug displays, read
+// `Time<Real>` instead.
+fn clock_text_system(
+    real_time: Res<Time<Real>>,
+    virtual_time: Res<Time<Virtual>>,
     mut query: Query<&mut Text, With<FpsText>>,
 ) {
+    let speed = if virtual_time.is_paused() {
+        "paused".to_string()
+    } else {
+        format!("{}x", virtual_time.relative_speed())
+    };
+    let clocks = format!(
+        "\nreal: {:.1}s, virtual: {:.1}s ({speed})",
+        real_time.elapsed_seconds(),
+        virtual_time.elapsed_seconds(),
+    );
     for mut text in &mut query {
-        if let Some(fps) = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS) {
-            if let Some(value) = fps.smoothed() {
-                // Update the value of the second section
-                text.sections[1].value = format!("{value:.2}");
+        text.set_section_if_changed(4, &clocks);
+    }
+}
+
+fn control_time_speed(keys: Res<ButtonInput<KeyCode>>, mut time: ResMut<Time<Virtual>>) {
+    for (key, speed) in [
+        (KeyCode::Digit1, 0.5),
+        (KeyCode::Digit2, 1.0),
+        (KeyCode::Digit3, 2.0),
+    ] {
+        if keys.just_pressed(key) {
+            time.set_relative_speed(speed);
+        }
+    }
+    if keys.just_pressed(KeyCode::Digit0) {
+        if time.is_paused() {
+            time.unpause();
+        } else {
+            time.pause();
+        }
+    }
+}
+
+fn pause_button_system(
+    query: Query<&Interaction, (Changed<Interaction>, With<PauseButton>)>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    for interaction in &query {
+        if *interaction == Interaction::Pressed {
+            if time.is_paused() {
+                time.unpause();
+            } else {
+                time.pause();
             }
         }
     }
 }
+
+fn text_scale_slider_system(
+    mut changed: EventReader<SliderChanged>,
+    mut text_scale: ResMut<TextScale>,
+) {
+    for event in changed.read() {
+        text_scale.0 = event.value;
+    }
+}
+
+// A rainbow, where the hue of each glyph depends on its index.
+fn rainbow(hue_offset: f32) -> TextGlyphColor {
+    TextGlyphColor::new(move |index, _| {
+        Color::hsl((hue_offset + index as f32 * 36.0) % 360.0, 1.0, 0.5)
+    })
+}
+
+// The shadow is laid out after the FPS value, so it's moved back over it, down and to the right.
+// Neither its color nor its offset affect the layout, so they're set without change detection.
+fn fps_shadow_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    mut query: Query<(&mut Text, &TextLayoutInfo), With<FpsText>>,
+) {
+    let scale_factor = windows
+        .get_single()
+        .map_or(1.0, |window| window.scale_factor())
+        * ui_scale.0;
+    for (mut text, layout) in &mut query {
+        let first_glyph = |section| {
+            layout
+                .glyphs
+                .iter()
+                .find(|glyph| glyph.section_index == section)
+                .map(|glyph| glyph.position)
+        };
+        let (Some(value), Some(shadow)) = (first_glyph(1), first_glyph(2)) else {
+            continue;
+        };
+        let shadow_section = &mut text.bypass_change_detection().sections[2];
+        shadow_section.offset = (value - shadow) / scale_factor + Vec2::new(4.0, 4.0);
+        shadow_section.style.color = Color::BLACK.with_alpha(0.3);
+    }
+}
+
+// Every half second, spawns a sparkle at each glyph of the FPS value, found by iterating over
+// the glyphs of its layout. The glyph positions are in physical pixels, from the top left corner
+// of the text node.
+fn fps_sparkle_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut since_spawn: Local<f32>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    texts: Query<(&Node, &GlobalTransform, &TextLayoutInfo), With<FpsText>>,
+    mut sparkles: Query<(Entity, &mut GlyphSparkle, &mut Style, &mut BackgroundColor)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut sparkle, mut style, mut color) in &mut sparkles {
+        sparkle.age += delta;
+        if sparkle.age >= SPARKLE_LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let (Val::Px(left), Val::Px(top)) = (style.left, style.top) else {
+            continue;
+        };
+        style.left = Val::Px(left + sparkle.velocity.x * delta);
+        style.top = Val::Px(top + sparkle.velocity.y * delta);
+        color.0.set_alpha(1.0 - sparkle.age / SPARKLE_LIFETIME);
+    }
+
+    *since_spawn += delta;
+    if *since_spawn < 0.5 {
+        return;
+    }
+    *since_spawn = 0.0;
+    let scale_factor = windows
+        .get_single()
+        .map_or(1.0, |window| window.scale_factor())
+        * ui_scale.0;
+    for (node, transform, layout) in &texts {
+        let top_left = transform.translation().truncate() - node.size() / 2.0;
+        for (index, glyph) in layout
+            .iter_glyphs()
+            .filter(|glyph| glyph.section_index == 1)
+            .enumerate()
+        {
+            let position = top_left + glyph.position / scale_factor;
+            commands.spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(position.x - 2.0),
+                        top: Val::Px(position.y - 2.0),
+                        width: Val::Px(4.0),
+                        height: Val::Px(4.0),
+                        ..default()
+                    },
+                    background_color: GOLD.into(),
+                    z_index: ZIndex::Global(1),
+                    ..default()
+                },
+                GlyphSparkle {
+                    velocity: Vec2::new((index as f32 - 1.5) * 8.0, -40.0),
+                    age: 0.0,
+                },
+            ));
+        }
+    }
+}
+
+// The FPS value of the badge, rounded to fit in it
+fn fps_badge_value(world: &World) -> String {
+    world
+        .resource::<DiagnosticsStore>()
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+        .map(|value| format!("{value:.0}"))
+        .unwrap_or_default()
+}
+
+// The FPS value is derived from the diagnostics, along with the "1% low" fps, which shows
+// stutter that the smoothed value hides, the number of draw calls of the UI, and the number of
+// glyphs extracted to the render world.
+fn fps_value(world: &World) -> String {
+    let diagnostics = world.resource::<DiagnosticsStore>();
+    let Some(value) = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+    else {
+        return String::new();
+    };
+    let low = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS_1PERCENT_LOW)
+        .and_then(|low| low.value())
+        .unwrap_or(value);
+    let draw_calls = diagnostics
+        .get(&UiDiagnosticsPlugin::DRAW_CALLS)
+        .and_then(|draw_calls| draw_calls.value())
+        .unwrap_or_default();
+    let extracted_glyphs = diagnostics
+        .get(&UiDiagnosticsPlugin::EXTRACTED_GLYPHS)
+        .and_then(|glyphs| glyphs.value())
+        .unwrap_or_default();
+    format!(
+        "{value:.2} (1% low: {low:.2}, UI draw calls: {draw_calls}, \
+        extracted glyphs: {extracted_glyphs})"
+    )
+}
@@ -68,6 +68,7 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                     font: font.clone(),
                     font_size: 30.0,
                     color: YELLOW.into(),
+                    ..default()
                 },
             )
             .with_text_justify(JustifyText::Right)
@@ -76,6 +77,22 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ..default()
             })
         );
+        let variant_style = |variant| TextStyle {
+            font: font.clone(),
+            font_size: 30.0,
+            variant,
+            ..default()
+        };
+        builder.spawn(
+            TextBundle::from_sections([
+                TextSection::new("E = mc", variant_style(TextVariant::Normal)),
+                TextSection::new("2", variant_style(TextVariant::superscript())),
+                TextSection::new(", H", variant_style(TextVariant::Normal)),
+                TextSection::new("2", variant_style(TextVariant::subscript())),
+                TextSection::new("O and ", variant_style(TextVariant::Normal)),
+                TextSection::new("Small Caps", variant_style(TextVariant::small_caps())),
+            ])
+        );
         builder.spawn(
             TextBundle::from_section(
                 "This\ntext has\nline breaks and also a set width in the bottom left.",
@@ -110,6 +127,7 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                     font: font.clone(),
                     font_size: 40.0,
                     color: Color::srgb(0.8, 0.2, 0.7),
+                    ..default()
                 },
             )
             .with_text_justify(JustifyText::Center)
@@ -126,6 +144,7 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                     font: font.clone(),
                     font_size: 35.0,
                     color: YELLOW.into(),
+                    ..default()
                 },
             )
             .with_text_justify(JustifyText::Left)
@@ -151,12 +170,14 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                         font: font.clone(),
                         font_size: 25.0,
                         color: RED.into(),
+                        ..default()
                     },
                 ),
                 TextSection::from_style(TextStyle {
                     font: font.clone(),
                     font_size: 25.0,
                     color: ORANGE_RED.into(),
+                    ..default()
                 }),
                 TextSection::new(
                     " fps, ",
@@ -164,12 +185,14 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                         font: font.clone(),
                         font_size: 25.0,
                         color: YELLOW.into(),
+                        ..default()
                     },
                 ),
                 TextSection::from_style(TextStyle {
                     font: font.clone(),
                     font_size: 25.0,
                     color: LIME.into(),
+                    ..default()
                 }),
                 TextSection::new(
                     " ms/frame",
@@ -177,6 +200,7 @@ fn infotext_system(mut commands: Commands, asset_server: Res<AssetServer>) {
                         font: font.clone(),
                         font_size: 25.0,
                         color: BLUE.into(),
+                        ..default()
                     },
                 ),
             ]),
@@ -0,0 +1,143 @@
+//! This example truncates text that overflows its node with [`TextOverflow`], replacing the end
+//! or the middle of its lines with an ellipsis.
+//!
+//! Press the left and right arrow keys to change the width of the nodes.
+
+use bevy::prelude::*;
+
+const PATH: &str = "/home/user/projects/game/assets/textures/environment/forest/oak_bark.png";
+const PARAGRAPH: &str = "Lines are wrapped before they are truncated, so only the line with \
+    https://example.com/a/very/long/address/that/cannot/be/wrapped overflows the node.";
+
+/// The width of the nodes, in logical pixels.
+const INITIAL_WIDTH: f32 = 360.0;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .add_systems(Update, change_width)
+        .run();
+}
+
+#[derive(Component)]
+struct Truncated;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            let text_style = TextStyle {
+                font_size: 20.0,
+                ..default()
+            };
+            let texts = [
+                (
+                    "TextOverflow::Clip",
+                    Text::from_section(PATH, text_style.clone()).with_no_wrap(),
+                ),
+                (
+                    "TextOverflow::Ellipsis",
+                    Text::from_section(PATH, text_style.clone())
+                        .with_no_wrap()
+                        .with_overflow(TextOverflow::Ellipsis),
+                ),
+                (
+                    "TextOverflow::EllipsisMiddle",
+                    Text::from_section(PATH, text_style.clone())
+                        .with_no_wrap()
+                        .with_overflow(TextOverflow::EllipsisMiddle),
+                ),
+                (
+                    "TextOverflow::EllipsisMiddle with a custom ellipsis",
+                    Text::from_sections([
+                        TextSection::new("/home/user/", text_style.clone()),
+                        TextSection::new(
+                            "projects/game/assets/textures/environment/forest/",
+                            TextStyle {
+                                color: Color::srgb(0.6, 0.8, 1.0),
+                                ..text_style.clone()
+                            },
+                        ),
+                        TextSection::new("oak_bark.png", text_style.clone()),
+                    ])
+                    .with_no_wrap()
+                    .with_overflow(TextOverflow::EllipsisMiddle)
+                    .with_ellipsis("/.../"),
+                ),
+                (
+                    "TextOverflow::Ellipsis with wrapping",
+                    Text::from_section(PARAGRAPH, text_style.clone())
+                        .with_overflow(TextOverflow::Ellipsis),
+                ),
+            ];
+
+            for (label, text) in texts {
+                parent.spawn(TextBundle::from_section(
+                    label,
+                    TextStyle {
+                        font_size: 16.0,
+                        color: Color::srgb(0.7, 0.7, 0.7),
+                        ..default()
+                    },
+                ));
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(INITIAL_WIDTH),
+                                padding: UiRect::all(Val::Px(4.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                overflow: Overflow::clip_x(),
+                                ..default()
+                            },
+                            border_color: Color::srgb(0.5, 0.5, 0.5).into(),
+                            ..default()
+                        },
+                        Truncated,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            text,
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                ..default()
+                            },
+                            ..default()
+                        });
+                    });
+            }
+        });
+}
+
+fn change_width(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut query: Query<&mut Style, With<Truncated>>,
+) {
+    let direction = if keyboard_input.pressed(KeyCode::ArrowLeft) {
+        -1.0
+    } else if keyboard_input.pressed(KeyCode::ArrowRight) {
+        1.0
+    } else {
+        return;
+    };
+    for mut style in &mut query {
+        if let Val::Px(width) = style.width {
+            style.width = Val::Px((width + direction * 200.0 * time.delta_seconds()).max(40.0));
+        }
+    }
+}
@@ -124,9 +124,11 @@ fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
                     sections: vec![TextSection {
                         value: message.clone(),
                         style: text_style.clone(),
+                        ..default()
                     }],
                     justify: JustifyText::Left,
                     linebreak_behavior,
+                    ..default()
                 };
                 let text_id = commands
                     .spawn(TextBundle {
@@ -1,8 +1,11 @@
-//! This example illustrates the [`UiScale`] resource from `bevy_ui`.
+//! This example illustrates the [`UiScale`] resource from `bevy_ui`, and the [`TextScale`]
+//! resource from `bevy_text`, which only scales text, like a "text size" accessibility setting.
 
 use bevy::{color::palettes::css::*, prelude::*, text::TextSettings, utils::Duration};
 
 const SCALE_TIME: u64 = 400;
+const MIN_TEXT_SCALE: f32 = 0.8;
+const MAX_TEXT_SCALE: f32 = 1.5;
 
 fn main() {
     App::new()
@@ -19,7 +22,11 @@ fn main() {
         .add_systems(Startup, setup)
         .add_systems(
             Update,
-            (change_scaling, apply_scaling.after(change_scaling)),
+            (
+                change_scaling,
+                apply_scaling.after(change_scaling),
+                change_text_scale,
+            ),
         )
         .run();
 }
@@ -81,6 +88,101 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ..default()
             });
         });
+
+    // A slider for the text scale, moved with the left and right arrows
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(12.0),
+                bottom: Val::Px(12.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "Text size: 1.0x (left/right)",
+                    TextStyle {
+                        font_size: 16.,
+                        ..default()
+                    },
+                ),
+                TextScaleLabel,
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(200.0),
+                        height: Val::Px(8.0),
+                        ..default()
+                    },
+                    background_color: DARK_GRAY.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(text_scale_percent(1.0)),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: LIME.into(),
+                            ..default()
+                        },
+                        TextScaleSliderFill,
+                    ));
+                });
+            // Keeps its size whatever the text scale
+            parent.spawn((
+                TextBundle::from_section(
+                    "This text ignores the text size",
+                    TextStyle {
+                        font_size: 16.,
+                        ..default()
+                    },
+                ),
+                TextScaleOverride(1.0),
+            ));
+        });
+}
+
+#[derive(Component)]
+struct TextScaleLabel;
+
+#[derive(Component)]
+struct TextScaleSliderFill;
+
+/// Returns the position of `scale` on the text scale slider, in percent.
+fn text_scale_percent(scale: f32) -> f32 {
+    (scale - MIN_TEXT_SCALE) / (MAX_TEXT_SCALE - MIN_TEXT_SCALE) * 100.0
+}
+
+/// System that moves the text scale slider when pressing left or right on the keyboard.
+fn change_text_scale(
+    input: Res<ButtonInput<KeyCode>>,
+    mut text_scale: ResMut<TextScale>,
+    mut labels: Query<&mut Text, With<TextScaleLabel>>,
+    mut fills: Query<&mut Style, With<TextScaleSliderFill>>,
+) {
+    let step = if input.just_pressed(KeyCode::ArrowRight) {
+        0.1
+    } else if input.just_pressed(KeyCode::ArrowLeft) {
+        -0.1
+    } else {
+        return;
+    };
+    text_scale.0 = (text_scale.0 + step).clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE);
+    for mut label in &mut labels {
+        label.sections[0].value = format!("Text size: {:.1}x (left/right)", text_scale.0);
+    }
+    for mut fill in &mut fills {
+        fill.width = Val::Percent(text_scale_percent(text_scale.0));
+    }
 }
 
 /// System that changes the scale of the ui when pressing up or down on the keyboard.
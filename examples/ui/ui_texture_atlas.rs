@@ -1,6 +1,7 @@
-//! This example illustrates how to use `TextureAtlases` within ui
+//! This example illustrates how to use `TextureAtlases` within ui, playing their frames with a
+//! [`UiImageAnimation`].
 
-use bevy::{color::palettes::css::*, prelude::*, winit::WinitSettings};
+use bevy::{color::palettes::css::*, prelude::*};
 
 fn main() {
     App::new()
@@ -10,10 +11,8 @@ fn main() {
             // by linear filtering.
             ImagePlugin::default_nearest(),
         ))
-        // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
-        .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup)
-        .add_systems(Update, increment_atlas_index)
+        .add_systems(Update, (toggle_animation, log_animation_frames))
         .run();
 }
 
@@ -56,7 +55,9 @@ fn setup(
                     image: UiImage::new(texture_handle),
                     ..default()
                 },
-                TextureAtlas::from(texture_atlas_handle),
+                TextureAtlas::from(texture_atlas_handle.clone()),
+                // The run cycle of the atlas, at 10 frames per second
+                UiImageAnimation::new(texture_atlas_handle, 1..7, 10.0),
                 BackgroundColor(ANTIQUE_WHITE.into()),
                 Outline::new(Val::Px(8.0), Val::ZERO, CRIMSON.into()),
             ));
@@ -69,18 +70,31 @@ fn setup(
                         ..text_style.clone()
                     },
                 ),
-                TextSection::new(" to advance frames".to_string(), text_style),
+                TextSection::new(" to pause or play the animation".to_string(), text_style),
             ]));
         });
 }
 
-fn increment_atlas_index(
-    mut atlas_images: Query<&mut TextureAtlas>,
+fn toggle_animation(
+    mut animations: Query<&mut UiImageAnimation>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
     if keyboard.just_pressed(KeyCode::Space) {
-        for mut atlas_image in &mut atlas_images {
-            atlas_image.index = (atlas_image.index + 1) % 6;
+        for mut animation in &mut animations {
+            if animation.paused {
+                animation.play();
+            } else {
+                animation.pause();
+            }
+        }
+    }
+}
+
+// The frame events can be used to play a sound in sync with the animation, such as footsteps
+fn log_animation_frames(mut frames: EventReader<AnimationFrameReached>) {
+    for frame in frames.read() {
+        if frame.index == 1 {
+            info!("The run cycle starts again");
         }
     }
 }
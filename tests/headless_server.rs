@@ -0,0 +1,69 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::{log::LogPlugin, prelude::*, time::TimeUpdateStrategy};
+
+const TICK_RATE: f64 = 1000.0;
+const TICKS: u32 = 100;
+
+#[derive(Resource, Default)]
+struct Ticks(u32);
+
+/// What the app saw when it exited, written from `Last` and shared with the test since
+/// [`App::run`] consumes the world.
+#[derive(Resource, Clone, Default)]
+struct ExitReport(Arc<Mutex<Option<(u32, Duration)>>>);
+
+fn tick(mut ticks: ResMut<Ticks>) {
+    ticks.0 += 1;
+}
+
+fn exit_after_ticks(ticks: Res<Ticks>, mut exit: EventWriter<AppExit>) {
+    if ticks.0 == TICKS {
+        exit.send(AppExit::Success);
+    }
+}
+
+fn report_on_exit(
+    mut exits: EventReader<AppExit>,
+    ticks: Res<Ticks>,
+    time: Res<Time<Fixed>>,
+    report: Res<ExitReport>,
+) {
+    if exits.read().next().is_some() {
+        *report.0.lock().unwrap() = Some((ticks.0, time.elapsed()));
+    }
+}
+
+#[test]
+fn server_ticks_and_exits_cleanly() {
+    let timestep = Duration::from_secs_f64(TICK_RATE.recip());
+    let report = ExitReport::default();
+
+    let mut app = App::new();
+    app.add_plugins(
+        ServerPlugins::with_tick_rate(TICK_RATE)
+            .build()
+            .disable::<LogPlugin>(),
+    )
+    // Advance time by exactly one tick per update, so that each update runs `FixedUpdate` once
+    .insert_resource(TimeUpdateStrategy::ManualDuration(timestep))
+    .insert_resource(report.clone())
+    .init_resource::<Ticks>()
+    .add_systems(FixedUpdate, tick)
+    .add_systems(Update, exit_after_ticks)
+    .add_systems(Last, report_on_exit);
+
+    assert_eq!(app.world().resource::<Time<Fixed>>().timestep(), timestep);
+    assert_eq!(app.run(), AppExit::Success);
+
+    let (ticks, fixed_elapsed) = report
+        .0
+        .lock()
+        .unwrap()
+        .expect("`Last` should run before the app exits");
+    assert_eq!(ticks, TICKS);
+    assert_eq!(fixed_elapsed, timestep * TICKS);
+}
@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::RunSystemOnce, input::InputPlugin, prelude::*, window::ManualInput};
 
 #[derive(Component, Default)]
 struct Enemy {
@@ -171,3 +171,22 @@ fn update_score_on_event() {
     // Check resulting changes
     assert_eq!(app.world().resource::<Score>().0, 3);
 }
+
+#[test]
+fn spawn_enemy_using_manual_input() {
+    // Setup app, with a headless primary window
+    let mut app = App::new();
+    app.add_plugins((InputPlugin, WindowPlugin::default()));
+    app.add_systems(Update, spawn_enemy);
+
+    // Press space through the same events as real input
+    app.world_mut().run_system_once(|mut input: ManualInput| {
+        input.tap(KeyCode::Space);
+    });
+    app.update();
+    assert_eq!(app.world_mut().query::<&Enemy>().iter(app.world()).len(), 1);
+
+    // The key press is only handled once
+    app.update();
+    assert_eq!(app.world_mut().query::<&Enemy>().iter(app.world()).len(), 1);
+}